@@ -2,6 +2,7 @@ use core::hint::black_box;
 
 use bevy_ecs::{
     component::Component,
+    schedule::{ExecutorKind, Schedule},
     system::{Command, Commands},
     world::{CommandQueue, World},
 };
@@ -62,6 +63,69 @@ pub fn spawn_commands(criterion: &mut Criterion) {
     group.finish();
 }
 
+/// Applies the commands of many systems at a single sync point, like spawning a large level
+/// across several systems in one frame.
+pub fn sync_point_commands(criterion: &mut Criterion) {
+    const SYSTEMS: u32 = 8;
+
+    fn spawn_system<T: Component + Default>(
+        entity_count: u32,
+    ) -> impl FnMut(Commands) + Send + Sync + 'static {
+        move |mut commands: Commands| {
+            for _ in 0..entity_count / SYSTEMS {
+                commands.spawn(T::default());
+            }
+        }
+    }
+
+    let mut group = criterion.benchmark_group("sync_point_commands");
+    group.warm_up_time(core::time::Duration::from_millis(500));
+    group.measurement_time(core::time::Duration::from_secs(4));
+
+    for entity_count in [1_000, 10_000, 50_000] {
+        group.bench_function(format!("{}_entities", entity_count), |bencher| {
+            let mut world = World::default();
+            let mut schedule = Schedule::default();
+            schedule.set_executor_kind(ExecutorKind::MultiThreaded);
+            schedule.add_systems((
+                spawn_system::<Matrix>(entity_count),
+                spawn_system::<Vec3>(entity_count),
+                spawn_system::<Position>(entity_count),
+                spawn_system::<Velocity>(entity_count),
+                spawn_system::<Health>(entity_count),
+                spawn_system::<Target>(entity_count),
+                spawn_system::<Team>(entity_count),
+                spawn_system::<Name>(entity_count),
+            ));
+
+            bencher.iter(|| {
+                schedule.run(&mut world);
+                world.clear_entities();
+            });
+        });
+    }
+
+    group.finish();
+}
+
+#[derive(Default, Component)]
+struct Position([f32; 3]);
+
+#[derive(Default, Component)]
+struct Velocity([f32; 3]);
+
+#[derive(Default, Component)]
+struct Health(u32);
+
+#[derive(Default, Component)]
+struct Target(u64);
+
+#[derive(Default, Component)]
+struct Team(u8);
+
+#[derive(Default, Component)]
+struct Name([u8; 16]);
+
 #[derive(Default, Component)]
 struct Matrix([[f32; 4]; 4]);
 
@@ -1,9 +1,14 @@
 //! Traits and type for interpolating between values.
 
 use crate::util;
+use alloc::boxed::Box;
+use bevy_app::App;
 use bevy_color::{Laba, LinearRgba, Oklaba, Srgba, Xyza};
 use bevy_math::*;
-use bevy_reflect::Reflect;
+use bevy_reflect::{
+    DynamicStruct, DynamicTuple, DynamicTupleStruct, FromType, GetTypeRegistration, PartialReflect,
+    Reflect, ReflectRef, TypeInfo, TypePath, TypeRegistry,
+};
 use bevy_transform::prelude::Transform;
 
 /// An individual input for [`Animatable::blend`].
@@ -270,3 +275,266 @@ where
     let p1p2p3 = T::interpolate(&p1p2, &p2p3, t);
     T::interpolate(&p0p1p2, &p1p2p3, t)
 }
+
+/// Type data for interpolating between two reflected values of an [`Animatable`] type.
+///
+/// This is registered by [`AnimationPlugin`] for the math and color types that implement
+/// [`Animatable`]. To blend values whose types aren't known at compile time, such as component
+/// fields found through a reflect path, see [`interpolate_reflect`].
+///
+/// [`AnimationPlugin`]: crate::AnimationPlugin
+#[derive(Clone)]
+pub struct ReflectInterpolate {
+    interpolate: fn(&dyn PartialReflect, &dyn PartialReflect, f32) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectInterpolate {
+    /// Interpolates between `a` and `b` with an interpolation factor of `time`, as in
+    /// [`Animatable::interpolate`].
+    ///
+    /// Returns `None` if either value isn't of the type this type data was created for.
+    pub fn interpolate(
+        &self,
+        a: &dyn PartialReflect,
+        b: &dyn PartialReflect,
+        time: f32,
+    ) -> Option<Box<dyn Reflect>> {
+        (self.interpolate)(a, b, time)
+    }
+}
+
+impl<T: Animatable> FromType<T> for ReflectInterpolate {
+    fn from_type() -> Self {
+        Self {
+            interpolate: |a, b, time| {
+                let a = a.try_downcast_ref::<T>()?;
+                let b = b.try_downcast_ref::<T>()?;
+                Some(Box::new(T::interpolate(a, b, time)))
+            },
+        }
+    }
+}
+
+/// Registers [`ReflectInterpolate`] for the built-in [`Animatable`] types.
+pub(crate) fn register_interpolate_types(app: &mut App) {
+    fn register<T: Animatable + GetTypeRegistration + TypePath>(app: &mut App) {
+        app.register_type::<T>()
+            .register_type_data::<T, ReflectInterpolate>();
+    }
+
+    register::<f32>(app);
+    register::<Vec2>(app);
+    register::<Vec3>(app);
+    register::<Vec3A>(app);
+    register::<Vec4>(app);
+    register::<f64>(app);
+    register::<DVec2>(app);
+    register::<DVec3>(app);
+    register::<DVec4>(app);
+    register::<Quat>(app);
+    register::<bool>(app);
+    register::<Transform>(app);
+    register::<LinearRgba>(app);
+    register::<Laba>(app);
+    register::<Oklaba>(app);
+    register::<Srgba>(app);
+    register::<Xyza>(app);
+}
+
+/// Interpolates between two reflected values `a` and `b` with an interpolation factor of `t`.
+///
+/// Values whose type registers [`ReflectInterpolate`] are interpolated with it. Otherwise, structs,
+/// tuple structs and tuples are interpolated field by field, producing a dynamic value which can be
+/// applied to a concrete value with [`PartialReflect::apply`].
+///
+/// Booleans step from `a` to `b` at the midpoint, when `t >= 0.5`, so that each field takes the
+/// value of whichever end is nearer. This differs from [`Animatable::interpolate`] for `bool`,
+/// which only switches to `b` once `t >= 1.0`, as keyframe animation expects.
+///
+/// Returns `None` if `a` and `b` have different types, or if any field can't be interpolated.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_animation::{animatable::ReflectInterpolate, prelude::interpolate_reflect};
+/// # use bevy_math::Vec3;
+/// # use bevy_reflect::{GetPath, PartialReflect, TypeRegistry};
+/// # use bevy_transform::components::Transform;
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Vec3>();
+/// registry.register_type_data::<Vec3, ReflectInterpolate>();
+///
+/// let a = Transform::from_xyz(0.0, 0.0, 0.0);
+/// let b = Transform::from_xyz(2.0, 4.0, 0.0);
+/// let mut target = Transform::default();
+///
+/// let translation = interpolate_reflect(
+///     a.reflect_path("translation").unwrap(),
+///     b.reflect_path("translation").unwrap(),
+///     0.5,
+///     &registry,
+/// )
+/// .unwrap();
+/// target
+///     .reflect_path_mut("translation")
+///     .unwrap()
+///     .apply(&*translation);
+///
+/// assert_eq!(target.translation, Vec3::new(1.0, 2.0, 0.0));
+/// ```
+pub fn interpolate_reflect(
+    a: &dyn PartialReflect,
+    b: &dyn PartialReflect,
+    t: f32,
+    registry: &TypeRegistry,
+) -> Option<Box<dyn PartialReflect>> {
+    let type_info = a.get_represented_type_info();
+    if type_info.map(TypeInfo::type_id) != b.get_represented_type_info().map(TypeInfo::type_id) {
+        return None;
+    }
+
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<bool>(), b.try_downcast_ref::<bool>()) {
+        return Some(Box::new(if t < 0.5 { *a } else { *b }));
+    }
+
+    if let Some(value) = type_info
+        .and_then(|info| registry.get_type_data::<ReflectInterpolate>(info.type_id()))
+        .and_then(|reflect_interpolate| reflect_interpolate.interpolate(a, b, t))
+    {
+        return Some(value.into_partial_reflect());
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+            let mut value = DynamicStruct::default();
+            for (i, a_field) in a.iter_fields().enumerate() {
+                let name = a.name_at(i)?;
+                let field = interpolate_reflect(a_field, b.field(name)?, t, registry)?;
+                value.insert_boxed(name, field);
+            }
+            value.set_represented_type(type_info);
+            Some(Box::new(value))
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            let mut value = DynamicTupleStruct::default();
+            for (i, a_field) in a.iter_fields().enumerate() {
+                value.insert_boxed(interpolate_reflect(a_field, b.field(i)?, t, registry)?);
+            }
+            value.set_represented_type(type_info);
+            Some(Box::new(value))
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            let mut value = DynamicTuple::default();
+            for (i, a_field) in a.iter_fields().enumerate() {
+                value.insert_boxed(interpolate_reflect(a_field, b.field(i)?, t, registry)?);
+            }
+            // Inserting into a `DynamicTuple` clears its represented type, so set it afterwards
+            value.set_represented_type(type_info);
+            Some(Box::new(value))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_reflect::{GetPath, TypeRegistry};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Tween {
+        position: Vec3,
+        color: (LinearRgba, f32),
+        visible: bool,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Tween>();
+        registry.register::<Vec3>();
+        registry.register::<f32>();
+        registry.register::<bool>();
+        registry.register_type_data::<Vec3, ReflectInterpolate>();
+        registry.register_type_data::<f32, ReflectInterpolate>();
+        registry.register_type_data::<bool, ReflectInterpolate>();
+        registry.register::<LinearRgba>();
+        registry.register_type_data::<LinearRgba, ReflectInterpolate>();
+        registry
+    }
+
+    #[test]
+    fn should_interpolate_reflected_struct() {
+        let registry = registry();
+        let a = Tween {
+            position: Vec3::ZERO,
+            color: (LinearRgba::BLACK, 0.0),
+            visible: false,
+        };
+        let b = Tween {
+            position: Vec3::new(2.0, 4.0, 8.0),
+            color: (LinearRgba::WHITE, 1.0),
+            visible: true,
+        };
+
+        let value = interpolate_reflect(&a, &b, 0.75, &registry).unwrap();
+        let mut output = Tween {
+            position: Vec3::ZERO,
+            color: (LinearRgba::NONE, 0.0),
+            visible: false,
+        };
+        output.apply(&*value);
+
+        assert_eq!(output.position, Vec3::new(1.5, 3.0, 6.0));
+        assert_eq!(output.color.0, LinearRgba::new(0.75, 0.75, 0.75, 1.0));
+        assert_eq!(output.color.1, 0.75);
+        assert!(output.visible);
+    }
+
+    #[test]
+    fn should_interpolate_reflected_field_by_path() {
+        let registry = registry();
+        let a = Tween {
+            position: Vec3::ZERO,
+            color: (LinearRgba::BLACK, 0.0),
+            visible: false,
+        };
+        let b = Tween {
+            position: Vec3::new(2.0, 4.0, 8.0),
+            color: (LinearRgba::WHITE, 1.0),
+            visible: true,
+        };
+
+        let value = interpolate_reflect(
+            a.reflect_path("color.1").unwrap(),
+            b.reflect_path("color.1").unwrap(),
+            0.25,
+            &registry,
+        )
+        .unwrap();
+        assert_eq!(value.try_downcast_ref::<f32>(), Some(&0.25));
+
+        // Mismatched types can't be interpolated
+        assert!(interpolate_reflect(
+            a.reflect_path("position").unwrap(),
+            b.reflect_path("visible").unwrap(),
+            0.5,
+            &registry,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn should_step_reflected_bools_at_midpoint() {
+        let registry = registry();
+        let step = |t| {
+            let value = interpolate_reflect(&false, &true, t, &registry).unwrap();
+            *value.try_downcast_ref::<bool>().unwrap()
+        };
+        assert!(!step(0.25));
+        assert!(step(0.5));
+
+        // Keyframe animation of `bool` still only switches at the next keyframe
+        assert!(!bool::interpolate(&false, &true, 0.75));
+        assert!(bool::interpolate(&false, &true, 1.0));
+    }
+}
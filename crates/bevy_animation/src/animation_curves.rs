@@ -86,6 +86,7 @@
 use core::{
     any::TypeId,
     fmt::{self, Debug, Formatter},
+    hash::BuildHasher,
     marker::PhantomData,
 };
 
@@ -100,7 +101,7 @@ use bevy_math::curve::{
     iterable::IterableCurve,
     Curve, Interval,
 };
-use bevy_platform_support::hash::Hashed;
+use bevy_platform_support::hash::{FixedHasher, Hashed};
 use bevy_reflect::{FromReflect, Reflect, Reflectable, TypeInfo, Typed};
 use bevy_render::mesh::morph::MorphWeights;
 use downcast_rs::{impl_downcast, Downcast};
@@ -315,6 +316,17 @@ pub struct AnimatableCurveEvaluator<A: Animatable> {
     property: Box<dyn AnimatableProperty<Property = A>>,
 }
 
+impl<A: Animatable> AnimatableCurveEvaluator<A> {
+    /// Returns the value most recently pushed onto the evaluation stack, before
+    /// it has been blended with any other values.
+    pub(crate) fn last_value_mut(&mut self) -> Option<&mut A> {
+        self.evaluator
+            .stack
+            .last_mut()
+            .map(|element| &mut element.value)
+    }
+}
+
 impl<P, C> AnimatableCurve<P, C>
 where
     P: AnimatableProperty,
@@ -635,6 +647,76 @@ impl AnimationCurveEvaluator for WeightsCurveEvaluator {
     }
 }
 
+/// An [`AnimatableProperty`] which animates the weight of a single morph target in
+/// [`MorphWeights`], found by the morph target's name.
+///
+/// [`WeightsCurve`] animates every weight by its index, which stops matching the
+/// right morph targets whenever their order changes, such as when a model is
+/// re-exported from a modelling tool. This property looks up the weight using
+/// [`MorphWeights::target_names`] instead, so it keeps animating the same morph target.
+///
+/// ```
+/// # use bevy_animation::{AnimationClip, AnimationTargetId, animation_curves::*};
+/// # use bevy_ecs::name::Name;
+/// # let target_id = AnimationTargetId::from_name(&Name::new("face"));
+/// let mut clip = AnimationClip::default();
+/// clip.add_curve_to_target(
+///     target_id,
+///     AnimatableCurve::new(
+///         AnimatedMorphWeight::new("smile"),
+///         AnimatableKeyframeCurve::new([(0.0, 0.0), (1.0, 0.7)]).unwrap(),
+///     ),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct AnimatedMorphWeight {
+    name: String,
+    /// A pre-hashed (property-type-id, name-hash) pair, so that each morph target gets its own
+    /// [`AnimationCurveEvaluator`]
+    evaluator_id: Hashed<(TypeId, usize)>,
+}
+
+impl AnimatedMorphWeight {
+    /// Creates a property which animates the weight of the morph target called `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        let name_hash = FixedHasher.hash_one(&name) as usize;
+        Self {
+            name,
+            evaluator_id: Hashed::new((TypeId::of::<Self>(), name_hash)),
+        }
+    }
+
+    /// The name of the animated morph target.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl AnimatableProperty for AnimatedMorphWeight {
+    type Property = f32;
+
+    fn get_mut<'a>(
+        &self,
+        entity: &'a mut AnimationEntityMut,
+    ) -> Result<&'a mut f32, AnimationEvaluationError> {
+        entity
+            .get_mut::<MorphWeights>()
+            .ok_or_else(|| {
+                AnimationEvaluationError::ComponentNotPresent(TypeId::of::<MorphWeights>())
+            })?
+            .into_inner()
+            .get_mut(&self.name)
+            .ok_or_else(|| {
+                AnimationEvaluationError::PropertyNotPresent(TypeId::of::<MorphWeights>())
+            })
+    }
+
+    fn evaluator_id(&self) -> EvaluatorId {
+        EvaluatorId::ComponentField(&self.evaluator_id)
+    }
+}
+
 #[derive(Reflect)]
 struct BasicAnimationCurveEvaluator<A>
 where
@@ -1012,6 +1094,17 @@ macro_rules! animated_field {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_animated_morph_weight_evaluator_ids() {
+        let id = |property: &AnimatedMorphWeight| match property.evaluator_id() {
+            EvaluatorId::ComponentField(id) => **id,
+            EvaluatorId::Type(_) => unreachable!(),
+        };
+        let smile = AnimatedMorphWeight::new("smile");
+        assert_eq!(id(&smile), id(&AnimatedMorphWeight::new("smile")));
+        assert_ne!(id(&smile), id(&AnimatedMorphWeight::new("frown")));
+    }
+
     #[test]
     fn test_animated_field_tuple_struct_simple_uses() {
         #[derive(Clone, Debug, Component, Reflect)]
@@ -0,0 +1,259 @@
+//! Binding the targets of animation clips to entities by their name paths.
+
+use bevy_asset::Assets;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::Children,
+    name::Name,
+    reflect::ReflectComponent,
+    system::{Commands, Query, Res},
+};
+use bevy_platform_support::collections::HashSet;
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use tracing::warn;
+
+use crate::{
+    graph::{AnimationGraph, AnimationGraphHandle, AnimationNodeType},
+    AnimationClip, AnimationPlayer, AnimationTarget, AnimationTargetId,
+};
+
+/// Binds the targets of the animation clips played by the [`AnimationPlayer`] on this entity to
+/// the entities below it, by their name paths.
+///
+/// Once the [`AnimationGraph`] and all of its clips have loaded, every descendant of this
+/// entity, and this entity itself, gets an [`AnimationTarget`] whose ID is made from the
+/// [`Name`]s on the path from this entity to it, as with [`AnimationTargetId::from_names`].
+/// For example, with this entity named `"Turret"`, its child named `"Barrel"` is bound to the
+/// path `"Turret/Barrel"`. Entities which already have an [`AnimationTarget`] keep it, and
+/// entities below another [`AnimationPlayer`] aren't bound.
+///
+/// Targets of the clips which aren't found are logged as warnings, and can be read with
+/// [`AnimationTargetBinding::missing_targets`].
+/// Clips created with [`AnimationClip::add_curve_to_path`] report missing targets by their path.
+///
+/// Binding happens once. If the hierarchy changes, call [`AnimationTargetBinding::rebind`].
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct AnimationTargetBinding {
+    bound: bool,
+    missing_targets: Vec<AnimationTargetId>,
+}
+
+impl AnimationTargetBinding {
+    /// Creates a binding, which binds targets once the animation graph and clips have loaded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if targets have been bound.
+    pub fn is_bound(&self) -> bool {
+        self.bound
+    }
+
+    /// Returns the targets of the clips which weren't found when binding.
+    pub fn missing_targets(&self) -> &[AnimationTargetId] {
+        &self.missing_targets
+    }
+
+    /// Binds targets again, the next time bindings are updated.
+    pub fn rebind(&mut self) {
+        self.bound = false;
+        self.missing_targets.clear();
+    }
+}
+
+/// A system that binds the targets of every unbound [`AnimationTargetBinding`].
+pub fn bind_animation_targets(
+    mut commands: Commands,
+    mut bindings: Query<(
+        Entity,
+        &mut AnimationTargetBinding,
+        &AnimationGraphHandle,
+        Option<&Name>,
+    )>,
+    nodes: Query<(
+        Option<&Name>,
+        Option<&Children>,
+        Option<&AnimationTarget>,
+        Option<&AnimationPlayer>,
+    )>,
+    graphs: Res<Assets<AnimationGraph>>,
+    clips: Res<Assets<AnimationClip>>,
+) {
+    for (player, mut binding, graph_handle, name) in &mut bindings {
+        if binding.bound {
+            continue;
+        }
+        let Some(graph) = graphs.get(graph_handle) else {
+            continue;
+        };
+
+        // Wait until all clips have loaded, so that missing targets can be reported.
+        let graph_clips = graph
+            .graph
+            .node_weights()
+            .filter_map(|node| match &node.node_type {
+                AnimationNodeType::Clip(handle) => Some(clips.get(handle)),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(graph_clips) = graph_clips else {
+            continue;
+        };
+
+        let mut bound_targets = HashSet::default();
+        let mut path = Vec::new();
+        if let Some(name) = name {
+            path.push(name.clone());
+        }
+        bind_recursive(
+            &mut commands,
+            &nodes,
+            player,
+            player,
+            &mut path,
+            &mut bound_targets,
+        );
+
+        let mut missing_targets = Vec::new();
+        for clip in graph_clips {
+            for target in clip.curves().keys() {
+                if bound_targets.contains(target) || missing_targets.contains(target) {
+                    continue;
+                }
+                match clip.target_path(*target) {
+                    Some(path) => {
+                        warn!("animation target `{path}` was not found below entity {player}");
+                    }
+                    None => {
+                        warn!("animation target {target:?} was not found below entity {player}");
+                    }
+                }
+                missing_targets.push(*target);
+            }
+        }
+
+        binding.bound = true;
+        binding.missing_targets = missing_targets;
+    }
+}
+
+fn bind_recursive(
+    commands: &mut Commands,
+    nodes: &Query<(
+        Option<&Name>,
+        Option<&Children>,
+        Option<&AnimationTarget>,
+        Option<&AnimationPlayer>,
+    )>,
+    player: Entity,
+    entity: Entity,
+    path: &mut Vec<Name>,
+    bound_targets: &mut HashSet<AnimationTargetId>,
+) {
+    let Ok((_, children, target, _)) = nodes.get(entity) else {
+        return;
+    };
+
+    match target {
+        Some(target) => {
+            if target.player == player {
+                bound_targets.insert(target.id);
+            }
+        }
+        None if !path.is_empty() => {
+            let id = AnimationTargetId::from_names(path.iter());
+            commands
+                .entity(entity)
+                .insert(AnimationTarget { id, player });
+            bound_targets.insert(id);
+        }
+        None => {}
+    }
+
+    for &child in children.into_iter().flatten() {
+        let Ok((name, _, _, child_player)) = nodes.get(child) else {
+            continue;
+        };
+        // Entities below another player are animated by that player.
+        if child_player.is_some() {
+            continue;
+        }
+        let Some(name) = name else {
+            continue;
+        };
+        path.push(name.clone());
+        bind_recursive(commands, nodes, player, child, path, bound_targets);
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_math::{
+        curve::{ConstantCurve, Interval},
+        Vec3,
+    };
+    use bevy_transform::components::Transform;
+
+    use super::*;
+    use crate::{
+        animated_field,
+        animation_curves::{AnimatableCurve, AnimatedField},
+    };
+
+    #[test]
+    fn binds_targets_by_path() {
+        let mut app = App::new();
+        app.init_resource::<Assets<AnimationGraph>>()
+            .init_resource::<Assets<AnimationClip>>()
+            .add_systems(Update, bind_animation_targets);
+
+        let mut clip = AnimationClip::default();
+        for path in ["Turret/Barrel", "Turret/Missing"] {
+            clip.add_curve_to_path(
+                path,
+                AnimatableCurve::new(
+                    animated_field!(Transform::scale),
+                    ConstantCurve::new(Interval::UNIT, Vec3::ONE),
+                ),
+            );
+        }
+        let clip = app
+            .world_mut()
+            .resource_mut::<Assets<AnimationClip>>()
+            .add(clip);
+        let (graph, _) = AnimationGraph::from_clip(clip);
+        let graph = app
+            .world_mut()
+            .resource_mut::<Assets<AnimationGraph>>()
+            .add(graph);
+
+        let barrel = app.world_mut().spawn(Name::new("Barrel")).id();
+        let turret = app
+            .world_mut()
+            .spawn((
+                Name::new("Turret"),
+                AnimationPlayer::default(),
+                AnimationGraphHandle(graph),
+                AnimationTargetBinding::new(),
+            ))
+            .add_child(barrel)
+            .id();
+
+        app.update();
+
+        let target = app.world().get::<AnimationTarget>(barrel).unwrap();
+        assert_eq!(target.id, AnimationTargetId::from_path("Turret/Barrel"));
+        assert_eq!(target.player, turret);
+
+        let binding = app.world().get::<AnimationTargetBinding>(turret).unwrap();
+        assert!(binding.is_bound());
+        assert_eq!(
+            binding.missing_targets(),
+            &[AnimationTargetId::from_path("Turret/Missing")]
+        );
+    }
+}
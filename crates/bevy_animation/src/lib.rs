@@ -11,8 +11,13 @@ extern crate alloc;
 
 pub mod animatable;
 pub mod animation_curves;
+pub mod binding;
 pub mod gltf_curves;
 pub mod graph;
+pub mod modifier;
+pub mod retarget;
+pub mod socket;
+pub mod state_machine;
 pub mod transition;
 mod util;
 
@@ -57,14 +62,33 @@ use uuid::Uuid;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        animatable::*, animation_curves::*, graph::*, transition::*, AnimationClip,
-        AnimationPlayer, AnimationPlugin, VariableCurve,
+        animatable::*,
+        animation_curves::*,
+        binding::AnimationTargetBinding,
+        graph::*,
+        modifier::{PoseModifier, PoseModifiers},
+        retarget::HumanoidBoneMap,
+        socket::BoneSocket,
+        state_machine::{
+            AnimationStateMachine, AnimationStateMachineHandle, StateMachineParams,
+            TransitionCondition,
+        },
+        transition::*,
+        AnimationClip, AnimationPlayer, AnimationPlugin, VariableCurve,
     };
 }
 
 use crate::{
     animation_curves::AnimationCurve,
+    binding::{bind_animation_targets, AnimationTargetBinding},
     graph::{AnimationGraph, AnimationGraphAssetLoader, AnimationNodeIndex},
+    modifier::apply_pose_modifiers,
+    retarget::{HumanoidBoneMap, RetargetedCurve},
+    socket::{update_bone_sockets, BoneSocket},
+    state_machine::{
+        advance_state_machines, AnimationStateMachine, AnimationStateMachineHandle,
+        AnimationStateMachineState, StateMachineParams,
+    },
     transition::{advance_transitions, expire_completed_transitions, AnimationTransitions},
 };
 use alloc::sync::Arc;
@@ -107,6 +131,9 @@ pub struct AnimationClip {
     curves: AnimationCurves,
     events: AnimationEvents,
     duration: f32,
+    /// The paths of targets added with [`AnimationClip::add_curve_to_path`], used to report
+    /// missing targets.
+    target_paths: HashMap<AnimationTargetId, String>,
 }
 
 #[derive(Reflect, Debug, Clone)]
@@ -303,6 +330,28 @@ impl AnimationClip {
             .push(VariableCurve::new(curve));
     }
 
+    /// Like [`add_curve_to_target`], but targets the entity at the given path of names
+    /// separated by `/`, like `"Turret/Barrel"`.
+    ///
+    /// The path starts at the entity with the [`AnimationPlayer`], so the first name is the
+    /// name of that entity. Entities can be bound to their paths automatically with
+    /// [`AnimationTargetBinding`], which also reports paths that don't exist using the paths
+    /// given here.
+    ///
+    /// [`add_curve_to_target`]: AnimationClip::add_curve_to_target
+    pub fn add_curve_to_path(&mut self, path: &str, curve: impl AnimationCurve) {
+        let target_id = AnimationTargetId::from_path(path);
+        self.target_paths
+            .entry(target_id)
+            .or_insert_with(|| path.to_string());
+        self.add_curve_to_target(target_id, curve);
+    }
+
+    /// Returns the path of a target added with [`AnimationClip::add_curve_to_path`].
+    pub fn target_path(&self, target_id: AnimationTargetId) -> Option<&str> {
+        self.target_paths.get(&target_id).map(String::as_str)
+    }
+
     /// Like [`add_curve_to_target`], but adding a [`VariableCurve`] directly.
     ///
     /// Under normal circumstances, that method is generally more convenient.
@@ -406,6 +455,85 @@ impl AnimationClip {
         self.add_event_internal(AnimationEventTarget::Node(target_id), time, func);
     }
 
+    /// Creates a copy of this clip which animates the skeleton described by
+    /// `target`, instead of the skeleton described by `source`.
+    ///
+    /// The curves and events of each [`HumanoidBone`] mapped by both skeletons
+    /// are moved from the source bone's [`AnimationTargetId`] to the target
+    /// bone's. Curves which animate the bone's [`Transform`] are wrapped in a
+    /// [`RetargetedCurve`], which compensates for the difference between the
+    /// bones' rest poses. Curves and events of bones which aren't mapped by
+    /// both skeletons are dropped, while untargeted events are kept as-is.
+    ///
+    /// ```
+    /// # use bevy_animation::{AnimationClip, AnimationTargetId, retarget::{HumanoidBone, HumanoidBoneMap}};
+    /// # use bevy_ecs::name::Name;
+    /// # use bevy_transform::components::Transform;
+    /// # let walk = AnimationClip::default();
+    /// let source = HumanoidBoneMap::default().with_bone(
+    ///     HumanoidBone::Hips,
+    ///     AnimationTargetId::from_name(&Name::new("mixamorig:Hips")),
+    ///     Transform::from_xyz(0.0, 1.0, 0.0),
+    /// );
+    /// let target = HumanoidBoneMap::default().with_bone(
+    ///     HumanoidBone::Hips,
+    ///     AnimationTargetId::from_name(&Name::new("pelvis")),
+    ///     Transform::from_xyz(0.0, 0.9, 0.0),
+    /// );
+    /// let retargeted_walk = walk.retarget(&source, &target);
+    /// ```
+    ///
+    /// [`HumanoidBone`]: retarget::HumanoidBone
+    /// [`Transform`]: bevy_transform::components::Transform
+    pub fn retarget(&self, source: &HumanoidBoneMap, target: &HumanoidBoneMap) -> AnimationClip {
+        let mut clip = AnimationClip {
+            duration: self.duration,
+            ..Default::default()
+        };
+        if let Some(events) = self.events.get(&AnimationEventTarget::Root) {
+            clip.events
+                .insert(AnimationEventTarget::Root, events.clone());
+        }
+
+        for (bone, source_bone) in source.iter() {
+            let Some(target_bone) = target.get(bone) else {
+                continue;
+            };
+
+            if let Some(curves) = self.curves.get(&source_bone.target) {
+                let curves = curves.iter().map(|curve| {
+                    match RetargetedCurve::new(
+                        curve.clone(),
+                        source_bone.rest_pose,
+                        target_bone.rest_pose,
+                    ) {
+                        Some(retargeted) => VariableCurve::new(retargeted),
+                        None => curve.clone(),
+                    }
+                });
+                clip.curves
+                    .entry(target_bone.target)
+                    .or_default()
+                    .extend(curves);
+            }
+
+            if let Some(events) = self
+                .events
+                .get(&AnimationEventTarget::Node(source_bone.target))
+            {
+                let target_events = clip
+                    .events
+                    .entry(AnimationEventTarget::Node(target_bone.target))
+                    .or_default();
+                target_events.extend(events.iter().cloned());
+                // Several humanoid bones may map to the same target
+                target_events.sort_by_key(|event| FloatOrd(event.time));
+            }
+        }
+
+        clip
+    }
+
     fn add_event_internal(
         &mut self,
         target: AnimationEventTarget,
@@ -1231,12 +1359,21 @@ impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<AnimationClip>()
             .init_asset::<AnimationGraph>()
+            .init_asset::<HumanoidBoneMap>()
+            .init_asset::<AnimationStateMachine>()
             .init_asset_loader::<AnimationGraphAssetLoader>()
             .register_asset_reflect::<AnimationClip>()
             .register_asset_reflect::<AnimationGraph>()
+            .register_asset_reflect::<HumanoidBoneMap>()
+            .register_asset_reflect::<AnimationStateMachine>()
             .register_type::<AnimationPlayer>()
             .register_type::<AnimationTarget>()
+            .register_type::<AnimationTargetBinding>()
+            .register_type::<BoneSocket>()
             .register_type::<AnimationTransitions>()
+            .register_type::<AnimationStateMachineHandle>()
+            .register_type::<AnimationStateMachineState>()
+            .register_type::<StateMachineParams>()
             .register_type::<AnimationGraphHandle>()
             .register_type::<NodeIndex>()
             .register_type::<ThreadedAnimationGraphs>()
@@ -1244,8 +1381,10 @@ impl Plugin for AnimationPlugin {
             .add_systems(
                 PostUpdate,
                 (
+                    bind_animation_targets,
                     graph::thread_animation_graphs,
                     advance_transitions,
+                    advance_state_machines,
                     advance_animations,
                     // TODO: `animate_targets` can animate anything, so
                     // ambiguity testing currently considers it ambiguous with
@@ -1262,7 +1401,16 @@ impl Plugin for AnimationPlugin {
                     .chain()
                     .in_set(Animation)
                     .before(TransformSystem::TransformPropagate),
+            )
+            .add_systems(
+                PostUpdate,
+                (apply_pose_modifiers, update_bone_sockets)
+                    .chain()
+                    .after(Animation)
+                    .before(TransformSystem::TransformPropagate),
             );
+
+        animatable::register_interpolate_types(app);
     }
 }
 
@@ -1285,6 +1433,14 @@ impl AnimationTargetId {
     pub fn from_name(name: &Name) -> Self {
         Self::from_names(iter::once(name))
     }
+
+    /// Creates a new [`AnimationTargetId`] from a path of names separated by `/`, like
+    /// `"Turret/Barrel"`.
+    ///
+    /// This is the same as [`AnimationTargetId::from_names`] with each name in the path.
+    pub fn from_path(path: &str) -> Self {
+        path.split('/').collect()
+    }
 }
 
 impl<T: AsRef<str>> FromIterator<T> for AnimationTargetId {
@@ -0,0 +1,334 @@
+//! Pose modifiers, which procedurally adjust the pose of an animated armature after animations
+//! have been applied.
+//!
+//! Modifiers are added to the entity with the [`AnimationPlayer`](crate::AnimationPlayer), in a
+//! [`PoseModifiers`] stack. Every frame, once animations have been applied, each modifier in
+//! the stack is run in order, and can read and change the [`Transform`]s of the player's
+//! [`AnimationTarget`]s. This happens before transforms are propagated, so the changes are
+//! included in the skinning matrices of the same frame.
+//!
+//! This crate provides the [`LookAt`] and [`BoneScaleOverride`] modifiers. Other modifiers,
+//! like physics-driven jiggle bones, can be written by implementing [`PoseModifier`].
+
+use alloc::boxed::Box;
+
+use bevy_ecs::{
+    change_detection::Mut,
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    system::{Query, Res},
+};
+use bevy_math::{Dir3, Quat, Vec3};
+use bevy_platform_support::collections::HashMap;
+use bevy_time::Time;
+use bevy_transform::components::{GlobalTransform, Transform};
+
+use crate::{AnimationTarget, AnimationTargetId};
+
+/// A procedural adjustment to the pose of an animated armature, run after animations have
+/// been applied.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_animation::{modifier::{Pose, PoseModifier}, AnimationTargetId};
+/// # use bevy_math::ops;
+/// /// Bobs a bone up and down.
+/// struct Bob {
+///     bone: AnimationTargetId,
+///     time: f32,
+/// }
+///
+/// impl PoseModifier for Bob {
+///     fn apply(&mut self, pose: &mut Pose) {
+///         self.time += pose.delta_secs();
+///         if let Some(mut transform) = pose.transform_mut(self.bone) {
+///             transform.translation.y += ops::sin(self.time) * 0.1;
+///         }
+///     }
+/// }
+/// ```
+pub trait PoseModifier: Send + Sync + 'static {
+    /// Adjusts the pose of the armature.
+    fn apply(&mut self, pose: &mut Pose);
+}
+
+/// An ordered stack of [`PoseModifier`]s, run on the armature animated by the
+/// [`AnimationPlayer`](crate::AnimationPlayer) on the same entity.
+#[derive(Component, Default)]
+pub struct PoseModifiers {
+    modifiers: Vec<Box<dyn PoseModifier>>,
+}
+
+impl PoseModifiers {
+    /// Creates an empty stack of modifiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a modifier to the end of the stack.
+    pub fn with(mut self, modifier: impl PoseModifier) -> Self {
+        self.push(modifier);
+        self
+    }
+
+    /// Adds a modifier to the end of the stack.
+    pub fn push(&mut self, modifier: impl PoseModifier) -> &mut Self {
+        self.modifiers.push(Box::new(modifier));
+        self
+    }
+
+    /// Removes all modifiers.
+    pub fn clear(&mut self) {
+        self.modifiers.clear();
+    }
+
+    /// Returns the number of modifiers in the stack.
+    pub fn len(&self) -> usize {
+        self.modifiers.len()
+    }
+
+    /// Returns `true` if the stack has no modifiers.
+    pub fn is_empty(&self) -> bool {
+        self.modifiers.is_empty()
+    }
+}
+
+impl core::fmt::Debug for PoseModifiers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PoseModifiers")
+            .field("len", &self.modifiers.len())
+            .finish()
+    }
+}
+
+/// The components of any entity which a [`Pose`] can access.
+type PoseData = (
+    Option<&'static mut Transform>,
+    Option<&'static GlobalTransform>,
+    Option<&'static ChildOf>,
+);
+
+/// Access to the pose of an armature, given to [`PoseModifier::apply`].
+pub struct Pose<'a, 'w, 's> {
+    player: Entity,
+    targets: &'a HashMap<AnimationTargetId, Entity>,
+    entities: &'a mut Query<'w, 's, PoseData>,
+    delta_secs: f32,
+}
+
+impl Pose<'_, '_, '_> {
+    /// Returns the entity with the [`AnimationPlayer`](crate::AnimationPlayer).
+    pub fn player(&self) -> Entity {
+        self.player
+    }
+
+    /// Returns the time since the last frame, in seconds.
+    pub fn delta_secs(&self) -> f32 {
+        self.delta_secs
+    }
+
+    /// Returns the entity of the bone with the given [`AnimationTargetId`], if it exists.
+    pub fn entity(&self, target: AnimationTargetId) -> Option<Entity> {
+        self.targets.get(&target).copied()
+    }
+
+    /// Returns the animated local transform of a bone.
+    pub fn transform(&self, target: AnimationTargetId) -> Option<&Transform> {
+        self.entities.get(self.entity(target)?).ok()?.0
+    }
+
+    /// Returns the local transform of a bone, to be modified.
+    pub fn transform_mut(&mut self, target: AnimationTargetId) -> Option<Mut<Transform>> {
+        let entity = self.entity(target)?;
+        self.entities.get_mut(entity).ok()?.0
+    }
+
+    /// Returns the global transform of any entity.
+    ///
+    /// Transforms haven't been propagated yet when modifiers run, so this is the global
+    /// transform from the previous frame.
+    pub fn global_transform(&self, entity: Entity) -> Option<&GlobalTransform> {
+        self.entities.get(entity).ok()?.1
+    }
+
+    /// Returns the global transform of the parent of a bone, or the identity transform if it
+    /// has no parent.
+    ///
+    /// Like [`Pose::global_transform`], this is from the previous frame.
+    pub fn parent_global_transform(&self, target: AnimationTargetId) -> GlobalTransform {
+        self.entity(target)
+            .and_then(|entity| self.entities.get(entity).ok()?.2)
+            .and_then(|child_of| self.global_transform(child_of.get()))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// A [`PoseModifier`] which rotates a bone to face an entity, like a head following a target.
+#[derive(Clone, Debug)]
+pub struct LookAt {
+    /// The bone to rotate.
+    pub bone: AnimationTargetId,
+    /// The entity to face.
+    pub target: Entity,
+    /// The direction the bone faces, in its local space.
+    pub forward: Dir3,
+    /// How much the bone is rotated, from `0.0` (the animated rotation) to `1.0` (fully facing
+    /// the target).
+    pub weight: f32,
+}
+
+impl LookAt {
+    /// Creates a modifier which fully rotates `bone` so that its local `-Z` axis faces `target`.
+    pub fn new(bone: AnimationTargetId, target: Entity) -> Self {
+        Self {
+            bone,
+            target,
+            forward: Dir3::NEG_Z,
+            weight: 1.0,
+        }
+    }
+
+    /// Sets the direction the bone faces, in its local space.
+    pub fn with_forward(mut self, forward: Dir3) -> Self {
+        self.forward = forward;
+        self
+    }
+
+    /// Sets how much the bone is rotated towards the target.
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl PoseModifier for LookAt {
+    fn apply(&mut self, pose: &mut Pose) {
+        let Some(target) = pose.global_transform(self.target).map(|t| t.translation()) else {
+            return;
+        };
+        let parent = pose.parent_global_transform(self.bone);
+        let (_, parent_rotation, _) = parent.to_scale_rotation_translation();
+        let Some(mut transform) = pose.transform_mut(self.bone) else {
+            return;
+        };
+
+        let position = parent.transform_point(transform.translation);
+        let Ok(direction) = Dir3::new(target - position) else {
+            return;
+        };
+        let rotation = parent_rotation * transform.rotation;
+        let forward = rotation * self.forward;
+        let facing = Quat::from_rotation_arc(*forward, *direction) * rotation;
+
+        let local_facing = parent_rotation.inverse() * facing;
+        transform.rotation = transform
+            .rotation
+            .slerp(local_facing, self.weight.clamp(0.0, 1.0));
+    }
+}
+
+/// A [`PoseModifier`] which replaces the animated scale of a bone.
+#[derive(Clone, Debug)]
+pub struct BoneScaleOverride {
+    /// The bone to scale.
+    pub bone: AnimationTargetId,
+    /// The scale of the bone.
+    pub scale: Vec3,
+}
+
+impl BoneScaleOverride {
+    /// Creates a modifier which sets the scale of `bone` to `scale`.
+    pub fn new(bone: AnimationTargetId, scale: Vec3) -> Self {
+        Self { bone, scale }
+    }
+}
+
+impl PoseModifier for BoneScaleOverride {
+    fn apply(&mut self, pose: &mut Pose) {
+        if let Some(mut transform) = pose.transform_mut(self.bone) {
+            transform.scale = self.scale;
+        }
+    }
+}
+
+/// A system that runs the [`PoseModifiers`] of every animation player.
+pub fn apply_pose_modifiers(
+    mut players: Query<(Entity, &mut PoseModifiers)>,
+    targets: Query<(Entity, &AnimationTarget)>,
+    mut entities: Query<PoseData>,
+    time: Res<Time>,
+) {
+    if players.is_empty() {
+        return;
+    }
+
+    let mut player_targets = HashMap::<Entity, HashMap<AnimationTargetId, Entity>>::default();
+    for (entity, target) in &targets {
+        if players.contains(target.player) {
+            player_targets
+                .entry(target.player)
+                .or_default()
+                .insert(target.id, entity);
+        }
+    }
+
+    let no_targets = HashMap::default();
+    for (player, mut modifiers) in &mut players {
+        let mut pose = Pose {
+            player,
+            targets: player_targets.get(&player).unwrap_or(&no_targets),
+            entities: &mut entities,
+            delta_secs: time.delta_secs(),
+        };
+        for modifier in &mut modifiers.modifiers {
+            modifier.apply(&mut pose);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_ecs::name::Name;
+    use bevy_time::TimePlugin;
+
+    use super::*;
+
+    #[test]
+    fn modifiers_apply_in_order() {
+        struct Double(AnimationTargetId);
+
+        impl PoseModifier for Double {
+            fn apply(&mut self, pose: &mut Pose) {
+                if let Some(mut transform) = pose.transform_mut(self.0) {
+                    transform.scale *= 2.0;
+                }
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_systems(Update, apply_pose_modifiers);
+
+        let bone = AnimationTargetId::from_name(&Name::new("bone"));
+        let player = app.world_mut().spawn_empty().id();
+        let target = app
+            .world_mut()
+            .spawn((Transform::default(), AnimationTarget { id: bone, player }))
+            .id();
+        app.world_mut().entity_mut(player).insert(
+            PoseModifiers::new()
+                .with(BoneScaleOverride::new(bone, Vec3::splat(3.0)))
+                .with(Double(bone)),
+        );
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Transform>(target).unwrap().scale,
+            Vec3::splat(6.0)
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! Retargeting of animation clips between skeletons.
+//!
+//! Animation clips refer to the bones they animate by [`AnimationTargetId`], which
+//! is usually derived from the names of the bones. A clip authored for one
+//! skeleton therefore can't drive a skeleton with differently named bones, and
+//! even when the names match, differences in the skeletons' rest poses cause
+//! the animation to look wrong.
+//!
+//! A [`HumanoidBoneMap`] describes a skeleton in terms of a common set of
+//! [`HumanoidBone`]s. Given the maps for two skeletons,
+//! [`AnimationClip::retarget`](crate::AnimationClip::retarget) creates a copy
+//! of a clip which animates the second skeleton instead of the first.
+
+use bevy_asset::Asset;
+use bevy_math::{curve::Interval, Quat, Vec3};
+use bevy_platform_support::collections::HashMap;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::Transform;
+
+use crate::{
+    animated_field,
+    animation_curves::{
+        AnimatableCurveEvaluator, AnimatableProperty, AnimatedField, AnimationCurve,
+        AnimationCurveEvaluator, EvaluatorId,
+    },
+    graph::AnimationNodeIndex,
+    AnimationEvaluationError, AnimationTargetId, VariableCurve,
+};
+
+/// A bone of a humanoid skeleton.
+///
+/// Skeletons authored in different tools use different names and hierarchies
+/// for their bones. These are used as a common vocabulary by
+/// [`HumanoidBoneMap`], so that animations can be shared between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum HumanoidBone {
+    /// The root bone of the body, usually located at the pelvis.
+    Hips,
+    /// The lower spine, directly above the hips.
+    Spine,
+    /// The chest, above the spine.
+    Chest,
+    /// The upper chest, above the chest.
+    UpperChest,
+    /// The neck.
+    Neck,
+    /// The head.
+    Head,
+    /// The left shoulder, or clavicle.
+    LeftShoulder,
+    /// The left upper arm.
+    LeftUpperArm,
+    /// The left forearm.
+    LeftLowerArm,
+    /// The left hand.
+    LeftHand,
+    /// The right shoulder, or clavicle.
+    RightShoulder,
+    /// The right upper arm.
+    RightUpperArm,
+    /// The right forearm.
+    RightLowerArm,
+    /// The right hand.
+    RightHand,
+    /// The left thigh.
+    LeftUpperLeg,
+    /// The left shin.
+    LeftLowerLeg,
+    /// The left foot.
+    LeftFoot,
+    /// The toes of the left foot.
+    LeftToes,
+    /// The right thigh.
+    RightUpperLeg,
+    /// The right shin.
+    RightLowerLeg,
+    /// The right foot.
+    RightFoot,
+    /// The toes of the right foot.
+    RightToes,
+}
+
+impl HumanoidBone {
+    /// All humanoid bones, ordered from the root of the body outwards.
+    pub const ALL: [Self; 22] = [
+        Self::Hips,
+        Self::Spine,
+        Self::Chest,
+        Self::UpperChest,
+        Self::Neck,
+        Self::Head,
+        Self::LeftShoulder,
+        Self::LeftUpperArm,
+        Self::LeftLowerArm,
+        Self::LeftHand,
+        Self::RightShoulder,
+        Self::RightUpperArm,
+        Self::RightLowerArm,
+        Self::RightHand,
+        Self::LeftUpperLeg,
+        Self::LeftLowerLeg,
+        Self::LeftFoot,
+        Self::LeftToes,
+        Self::RightUpperLeg,
+        Self::RightLowerLeg,
+        Self::RightFoot,
+        Self::RightToes,
+    ];
+}
+
+/// A bone of a specific skeleton, as mapped by a [`HumanoidBoneMap`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct MappedBone {
+    /// The ID used by animation clips to refer to this bone.
+    pub target: AnimationTargetId,
+    /// The local transform of this bone when the skeleton is in its rest pose.
+    pub rest_pose: Transform,
+}
+
+/// Maps the [`HumanoidBone`]s of a humanoid skeleton to the bones of a specific
+/// skeleton, along with their rest poses.
+///
+/// Skeletons don't need to map every humanoid bone; curves and events for bones
+/// which are missing from either skeleton are dropped when retargeting.
+///
+/// See [`AnimationClip::retarget`](crate::AnimationClip::retarget) for how these are used.
+#[derive(Asset, Reflect, Clone, Debug, Default)]
+#[reflect(Default, Debug)]
+pub struct HumanoidBoneMap {
+    bones: HashMap<HumanoidBone, MappedBone>,
+}
+
+impl HumanoidBoneMap {
+    /// Maps a humanoid bone to a bone of this skeleton, returning the bone it
+    /// was previously mapped to, if any.
+    pub fn insert(
+        &mut self,
+        bone: HumanoidBone,
+        target: AnimationTargetId,
+        rest_pose: Transform,
+    ) -> Option<MappedBone> {
+        self.bones.insert(bone, MappedBone { target, rest_pose })
+    }
+
+    /// Returns this map with the given humanoid bone mapped to a bone of this
+    /// skeleton.
+    pub fn with_bone(
+        mut self,
+        bone: HumanoidBone,
+        target: AnimationTargetId,
+        rest_pose: Transform,
+    ) -> Self {
+        self.insert(bone, target, rest_pose);
+        self
+    }
+
+    /// Removes the mapping for a humanoid bone, returning it if it existed.
+    pub fn remove(&mut self, bone: HumanoidBone) -> Option<MappedBone> {
+        self.bones.remove(&bone)
+    }
+
+    /// Returns the bone of this skeleton which a humanoid bone is mapped to.
+    pub fn get(&self, bone: HumanoidBone) -> Option<&MappedBone> {
+        self.bones.get(&bone)
+    }
+
+    /// Returns an iterator over all mapped humanoid bones.
+    pub fn iter(&self) -> impl Iterator<Item = (HumanoidBone, &MappedBone)> {
+        self.bones.iter().map(|(bone, mapped)| (*bone, mapped))
+    }
+
+    /// Returns the number of mapped humanoid bones.
+    pub fn len(&self) -> usize {
+        self.bones.len()
+    }
+
+    /// Returns `true` if no humanoid bones are mapped.
+    pub fn is_empty(&self) -> bool {
+        self.bones.is_empty()
+    }
+}
+
+/// The field of a [`Transform`] animated by a [`RetargetedCurve`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TransformField {
+    Translation,
+    Rotation,
+    Scale,
+}
+
+impl TransformField {
+    fn of(curve: &dyn AnimationCurve) -> Option<Self> {
+        let EvaluatorId::ComponentField(field) = curve.evaluator_id() else {
+            return None;
+        };
+        let is =
+            |id: EvaluatorId| matches!(id, EvaluatorId::ComponentField(other) if other == field);
+
+        if is(animated_field!(Transform::translation).evaluator_id()) {
+            Some(Self::Translation)
+        } else if is(animated_field!(Transform::rotation).evaluator_id()) {
+            Some(Self::Rotation)
+        } else if is(animated_field!(Transform::scale).evaluator_id()) {
+            Some(Self::Scale)
+        } else {
+            None
+        }
+    }
+}
+
+/// An [`AnimationCurve`] which animates a field of a [`Transform`] using a curve
+/// authored for a bone with a different rest pose.
+///
+/// The value sampled from the inner curve is treated as an offset from the
+/// source bone's rest pose, which is then applied to the target bone's rest pose:
+/// - Rotations are applied in the space of the bone's parent, so the target
+///   bone is rotated away from its rest pose in the same direction as the source
+///   bone.
+/// - Translations are scaled by the ratio between the lengths of the bones'
+///   rest translations, so that differences in proportions (such as the height
+///   of the hips) don't cause the target's feet to slide or float.
+/// - Scales are multiplied by the ratio between the bones' rest scales. Axes on which the
+///   source bone's rest scale is zero are left unscaled.
+///
+/// When the source bone is in its rest pose, the target bone is therefore also
+/// in its rest pose.
+///
+/// These are created by [`AnimationClip::retarget`](crate::AnimationClip::retarget).
+#[derive(Debug, Clone)]
+pub struct RetargetedCurve {
+    curve: VariableCurve,
+    field: TransformField,
+    source_rest_pose: Transform,
+    target_rest_pose: Transform,
+    translation_ratio: f32,
+    scale_ratio: Vec3,
+}
+
+impl RetargetedCurve {
+    /// Creates a curve which retargets the values of `curve` from a bone with the
+    /// rest pose `source_rest_pose` to a bone with the rest pose `target_rest_pose`.
+    ///
+    /// Returns `None` if `curve` doesn't animate the translation, rotation or
+    /// scale of a [`Transform`].
+    pub fn new(
+        curve: VariableCurve,
+        source_rest_pose: Transform,
+        target_rest_pose: Transform,
+    ) -> Option<Self> {
+        let field = TransformField::of(&*curve.0)?;
+        let source_length = source_rest_pose.translation.length();
+        let translation_ratio = if source_length > f32::EPSILON {
+            target_rest_pose.translation.length() / source_length
+        } else {
+            1.0
+        };
+        let scale_ratio = Vec3::select(
+            source_rest_pose
+                .scale
+                .abs()
+                .cmpgt(Vec3::splat(f32::EPSILON)),
+            target_rest_pose.scale / source_rest_pose.scale,
+            Vec3::ONE,
+        );
+
+        Some(Self {
+            curve,
+            field,
+            source_rest_pose,
+            target_rest_pose,
+            translation_ratio,
+            scale_ratio,
+        })
+    }
+
+    /// The curve whose values are being retargeted.
+    pub fn inner(&self) -> &VariableCurve {
+        &self.curve
+    }
+
+    fn retarget_translation(&self, translation: Vec3) -> Vec3 {
+        self.target_rest_pose.translation
+            + (translation - self.source_rest_pose.translation) * self.translation_ratio
+    }
+
+    fn retarget_rotation(&self, rotation: Quat) -> Quat {
+        (rotation * self.source_rest_pose.rotation.inverse() * self.target_rest_pose.rotation)
+            .normalize()
+    }
+
+    fn retarget_scale(&self, scale: Vec3) -> Vec3 {
+        scale * self.scale_ratio
+    }
+}
+
+impl AnimationCurve for RetargetedCurve {
+    fn clone_value(&self) -> Box<dyn AnimationCurve> {
+        Box::new(self.clone())
+    }
+
+    fn domain(&self) -> Interval {
+        self.curve.0.domain()
+    }
+
+    fn evaluator_id(&self) -> EvaluatorId {
+        self.curve.0.evaluator_id()
+    }
+
+    fn create_evaluator(&self) -> Box<dyn AnimationCurveEvaluator> {
+        self.curve.0.create_evaluator()
+    }
+
+    fn apply(
+        &self,
+        curve_evaluator: &mut dyn AnimationCurveEvaluator,
+        t: f32,
+        weight: f32,
+        graph_node: AnimationNodeIndex,
+    ) -> Result<(), AnimationEvaluationError> {
+        self.curve.0.apply(curve_evaluator, t, weight, graph_node)?;
+
+        // The inner curve has just pushed its sampled value, so correct it before
+        // it gets blended with any other values.
+        match self.field {
+            TransformField::Translation => {
+                if let Some(value) = curve_evaluator
+                    .downcast_mut::<AnimatableCurveEvaluator<Vec3>>()
+                    .and_then(AnimatableCurveEvaluator::last_value_mut)
+                {
+                    *value = self.retarget_translation(*value);
+                }
+            }
+            TransformField::Rotation => {
+                if let Some(value) = curve_evaluator
+                    .downcast_mut::<AnimatableCurveEvaluator<Quat>>()
+                    .and_then(AnimatableCurveEvaluator::last_value_mut)
+                {
+                    *value = self.retarget_rotation(*value);
+                }
+            }
+            TransformField::Scale => {
+                if let Some(value) = curve_evaluator
+                    .downcast_mut::<AnimatableCurveEvaluator<Vec3>>()
+                    .and_then(AnimatableCurveEvaluator::last_value_mut)
+                {
+                    *value = self.retarget_scale(*value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{AnimatableCurve, AnimatableKeyframeCurve},
+        AnimationClip,
+    };
+    use bevy_ecs::name::Name;
+    use core::f32::consts::FRAC_PI_2;
+
+    fn sample<A: crate::animatable::Animatable + Copy>(curve: &VariableCurve, t: f32) -> A {
+        let mut evaluator = curve.0.create_evaluator();
+        curve
+            .0
+            .apply(&mut *evaluator, t, 1.0, AnimationNodeIndex::new(0))
+            .unwrap();
+        *evaluator
+            .downcast_mut::<AnimatableCurveEvaluator<A>>()
+            .unwrap()
+            .last_value_mut()
+            .unwrap()
+    }
+
+    #[test]
+    fn retarget_remaps_targets() {
+        let source_hips = AnimationTargetId::from_name(&Name::new("mixamorig:Hips"));
+        let target_hips = AnimationTargetId::from_name(&Name::new("pelvis"));
+        let unmapped = AnimationTargetId::from_name(&Name::new("tail"));
+
+        let source = HumanoidBoneMap::default().with_bone(
+            HumanoidBone::Hips,
+            source_hips,
+            Transform::from_xyz(0.0, 1.0, 0.0),
+        );
+        let target = HumanoidBoneMap::default().with_bone(
+            HumanoidBone::Hips,
+            target_hips,
+            Transform::from_xyz(0.0, 2.0, 0.0),
+        );
+
+        let mut clip = AnimationClip::default();
+        for id in [source_hips, unmapped] {
+            clip.add_curve_to_target(
+                id,
+                AnimatableCurve::new(
+                    animated_field!(Transform::translation),
+                    AnimatableKeyframeCurve::new([
+                        (0.0, Vec3::new(0.0, 1.0, 0.0)),
+                        (1.0, Vec3::new(1.0, 1.5, 0.0)),
+                    ])
+                    .unwrap(),
+                ),
+            );
+        }
+
+        let retargeted = clip.retarget(&source, &target);
+        assert_eq!(retargeted.duration(), clip.duration());
+        assert!(retargeted.curves_for_target(source_hips).is_none());
+        assert!(retargeted.curves_for_target(unmapped).is_none());
+
+        let curves = retargeted.curves_for_target(target_hips).unwrap();
+        assert_eq!(curves.len(), 1);
+        // The hips are twice as high, so they should move twice as far
+        assert_eq!(sample::<Vec3>(&curves[0], 0.0), Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(sample::<Vec3>(&curves[0], 1.0), Vec3::new(2.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn retarget_compensates_rest_rotation() {
+        let source_rest = Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_2));
+        let target_rest = Transform::IDENTITY;
+        let lift = Quat::from_rotation_x(FRAC_PI_2);
+
+        let curve = RetargetedCurve::new(
+            VariableCurve::new(AnimatableCurve::new(
+                animated_field!(Transform::rotation),
+                AnimatableKeyframeCurve::new([
+                    (0.0, source_rest.rotation),
+                    (1.0, lift * source_rest.rotation),
+                ])
+                .unwrap(),
+            )),
+            source_rest,
+            target_rest,
+        )
+        .unwrap();
+        let curve = VariableCurve::new(curve);
+
+        assert!(sample::<Quat>(&curve, 0.0).abs_diff_eq(Quat::IDENTITY, 1e-5));
+        assert!(sample::<Quat>(&curve, 1.0).abs_diff_eq(lift, 1e-5));
+    }
+
+    #[test]
+    fn retarget_ignores_zero_rest_scale() {
+        let source_rest = Transform::from_scale(Vec3::new(0.0, 1.0, 2.0));
+        let target_rest = Transform::from_scale(Vec3::splat(2.0));
+
+        let curve = RetargetedCurve::new(
+            VariableCurve::new(AnimatableCurve::new(
+                animated_field!(Transform::scale),
+                AnimatableKeyframeCurve::new([(0.0, Vec3::ONE), (1.0, Vec3::ONE)]).unwrap(),
+            )),
+            source_rest,
+            target_rest,
+        )
+        .unwrap();
+        let curve = VariableCurve::new(curve);
+
+        assert_eq!(sample::<Vec3>(&curve, 0.0), Vec3::new(1.0, 2.0, 1.0));
+    }
+
+    #[test]
+    fn retarget_ignores_non_transform_curves() {
+        let curve = VariableCurve::new(AnimatableCurve::new(
+            animated_field!(Transform::translation),
+            AnimatableKeyframeCurve::new([(0.0, Vec3::ZERO), (1.0, Vec3::ONE)]).unwrap(),
+        ));
+        assert!(RetargetedCurve::new(curve, Transform::IDENTITY, Transform::IDENTITY).is_some());
+
+        #[derive(Clone, Debug, bevy_ecs::component::Component, Reflect)]
+        struct Speed(f32);
+        let curve = VariableCurve::new(AnimatableCurve::new(
+            animated_field!(Speed::0),
+            AnimatableKeyframeCurve::new([(0.0, 1.0), (1.0, 2.0)]).unwrap(),
+        ));
+        assert!(RetargetedCurve::new(curve, Transform::IDENTITY, Transform::IDENTITY).is_none());
+    }
+}
@@ -0,0 +1,183 @@
+//! Attaching entities to the joints of animated armatures.
+
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::{require, Component},
+    entity::{Entity, VisitEntities, VisitEntitiesMut},
+    hierarchy::ChildOf,
+    name::Name,
+    reflect::{ReflectComponent, ReflectVisitEntities, ReflectVisitEntitiesMut},
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_reflect::Reflect;
+use bevy_transform::components::Transform;
+
+use crate::{AnimationTarget, AnimationTargetId};
+
+/// Identifies a joint of an armature which a [`BoneSocket`] is attached to.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub enum SocketJoint {
+    /// The joint whose [`AnimationTarget`] has this ID.
+    Target(AnimationTargetId),
+    /// The joint whose [`Name`] is this name.
+    ///
+    /// If there are several joints with this name, an arbitrary one is chosen.
+    Name(Name),
+}
+
+impl SocketJoint {
+    fn matches(&self, target: &AnimationTarget, name: Option<&Name>) -> bool {
+        match self {
+            SocketJoint::Target(id) => target.id == *id,
+            SocketJoint::Name(joint_name) => name == Some(joint_name),
+        }
+    }
+}
+
+/// Attaches this entity to a joint of an armature, such as a character's hand, so that it
+/// follows the joint as it's animated.
+///
+/// This is useful for weapons, props and other items which are held or worn by an animated
+/// character.
+///
+/// The joint is found among the entities with an [`AnimationTarget`] whose
+/// [`player`](AnimationTarget::player) is [`BoneSocket::player`]. Once it's found, this entity
+/// is made a child of the joint using [`ChildOf`], and its [`Transform`] is set to
+/// [`BoneSocket::offset`] relative to the joint. If the joint is despawned or stops matching,
+/// a new joint is searched for the next time sockets are updated.
+///
+/// Sockets are updated after the [`Animation`](bevy_app::Animation) system set, and before
+/// transforms are propagated, so the entity follows the joint's pose on the same frame.
+///
+/// Changing the [`Transform`] of this entity directly has no lasting effect, as it's reset to
+/// [`BoneSocket::offset`] whenever the socket changes or is attached to a new joint.
+#[derive(Component, Clone, Debug, Reflect, VisitEntities, VisitEntitiesMut)]
+#[reflect(Component, VisitEntities, VisitEntitiesMut)]
+#[require(Transform)]
+pub struct BoneSocket {
+    /// The entity containing the [`AnimationPlayer`](crate::AnimationPlayer) which animates
+    /// the armature.
+    pub player: Entity,
+    /// The joint to attach to.
+    #[visit_entities(ignore)]
+    pub joint: SocketJoint,
+    /// The transform of this entity relative to the joint.
+    #[visit_entities(ignore)]
+    pub offset: Transform,
+}
+
+impl BoneSocket {
+    /// Creates a socket attached to the joint with the given [`AnimationTargetId`].
+    pub fn new(player: Entity, target: AnimationTargetId) -> Self {
+        Self {
+            player,
+            joint: SocketJoint::Target(target),
+            offset: Transform::IDENTITY,
+        }
+    }
+
+    /// Creates a socket attached to the joint with the given [`Name`].
+    pub fn from_name(player: Entity, name: impl Into<Name>) -> Self {
+        Self {
+            player,
+            joint: SocketJoint::Name(name.into()),
+            offset: Transform::IDENTITY,
+        }
+    }
+
+    /// Sets the transform of the attached entity relative to the joint.
+    pub fn with_offset(mut self, offset: Transform) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn matches(&self, target: &AnimationTarget, name: Option<&Name>) -> bool {
+        target.player == self.player && self.joint.matches(target, name)
+    }
+}
+
+/// A system that attaches every [`BoneSocket`] to its joint, and keeps its [`Transform`]
+/// up to date with its offset.
+pub fn update_bone_sockets(
+    mut commands: Commands,
+    mut sockets: Query<(Entity, Ref<BoneSocket>, Option<&ChildOf>, &mut Transform)>,
+    targets: Query<(Entity, &AnimationTarget, Option<&Name>)>,
+) {
+    for (entity, socket, child_of, mut transform) in &mut sockets {
+        let attached = child_of.is_some_and(|child_of| {
+            targets
+                .get(child_of.get())
+                .is_ok_and(|(_, target, name)| socket.matches(target, name))
+        });
+
+        if !attached {
+            let Some((joint, ..)) = targets
+                .iter()
+                .find(|(_, target, name)| socket.matches(target, *name))
+            else {
+                continue;
+            };
+            commands.entity(entity).insert(ChildOf(joint));
+        }
+
+        if !attached || socket.is_changed() {
+            transform.set_if_neq(socket.offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_math::Vec3;
+
+    #[test]
+    fn attaches_socket_to_joint() {
+        let mut world = World::new();
+        let player = world.spawn_empty().id();
+        let hand_id = AnimationTargetId::from_name(&Name::new("Hand"));
+        let hand = world
+            .spawn((
+                Name::new("Hand"),
+                AnimationTarget {
+                    id: hand_id,
+                    player,
+                },
+            ))
+            .id();
+        let foot = world
+            .spawn((
+                Name::new("Foot"),
+                AnimationTarget {
+                    id: AnimationTargetId::from_name(&Name::new("Foot")),
+                    player,
+                },
+            ))
+            .id();
+
+        let offset = Transform::from_translation(Vec3::new(0.0, 1.0, 0.0));
+        let by_id = world
+            .spawn(BoneSocket::new(player, hand_id).with_offset(offset))
+            .id();
+        let by_name = world.spawn(BoneSocket::from_name(player, "Foot")).id();
+        let other_player = world
+            .spawn(BoneSocket::new(Entity::PLACEHOLDER, hand_id))
+            .id();
+
+        world.run_system_once(update_bone_sockets).unwrap();
+
+        assert_eq!(world.get::<ChildOf>(by_id).map(ChildOf::get), Some(hand));
+        assert_eq!(world.get::<Transform>(by_id), Some(&offset));
+        assert_eq!(world.get::<ChildOf>(by_name).map(ChildOf::get), Some(foot));
+        assert!(world.get::<ChildOf>(other_player).is_none());
+
+        // Retargeting the socket moves it to the new joint
+        world.get_mut::<BoneSocket>(by_id).unwrap().joint = SocketJoint::Name(Name::new("Foot"));
+        world.run_system_once(update_bone_sockets).unwrap();
+        assert_eq!(world.get::<ChildOf>(by_id).map(ChildOf::get), Some(foot));
+        assert_eq!(world.get::<Transform>(by_id), Some(&offset));
+    }
+}
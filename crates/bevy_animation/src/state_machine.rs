@@ -0,0 +1,505 @@
+//! Animation state machines, which switch between animations in an
+//! [`AnimationGraph`](crate::graph::AnimationGraph) based on gameplay parameters.
+//!
+//! An [`AnimationStateMachine`] is an asset made of states, each of which plays a node of the
+//! animation graph, and transitions between them. Each transition has conditions on the
+//! parameters in the [`StateMachineParams`] component, which gameplay code sets to values like
+//! `"speed"` or `"grounded"`. Every frame, the first transition out of the current state whose
+//! conditions all hold is taken, blending from the old animation to the new one over the
+//! transition's blend duration.
+//!
+//! To use a state machine, add an [`AnimationStateMachineHandle`] to the entity with the
+//! [`AnimationPlayer`] and [`AnimationGraphHandle`](crate::AnimationGraphHandle). The state
+//! machine takes responsibility for playing animations and setting their weights, so, like with
+//! [`AnimationTransitions`](crate::transition::AnimationTransitions), animations shouldn't be
+//! played through the [`AnimationPlayer`] directly.
+
+use core::time::Duration;
+
+use bevy_asset::{Asset, Assets, Handle};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    component::{require, Component},
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_platform_support::collections::HashMap;
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_time::Time;
+use derive_more::derive::From;
+
+use crate::{graph::AnimationNodeIndex, AnimationPlayer};
+
+/// An index of a state in an [`AnimationStateMachine`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub struct AnimationStateIndex(pub usize);
+
+/// An asset describing the states of an animated entity, the animation played in each state,
+/// and the transitions between them.
+///
+/// The first state added is the initial state.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_animation::{graph::AnimationNodeIndex, state_machine::*};
+/// # use core::time::Duration;
+/// # let (idle_node, run_node) = (AnimationNodeIndex::new(1), AnimationNodeIndex::new(2));
+/// let mut machine = AnimationStateMachine::new();
+/// let idle = machine.add_state("idle", idle_node);
+/// let run = machine.add_state("run", run_node);
+/// machine
+///     .add_transition(idle, run, Duration::from_millis(200))
+///     .with_condition(TransitionCondition::GreaterThan("speed".into(), 0.1));
+/// machine
+///     .add_transition(run, idle, Duration::from_millis(300))
+///     .with_condition(TransitionCondition::LessThan("speed".into(), 0.1))
+///     .interruptible();
+/// ```
+#[derive(Asset, Clone, Debug, Default, Reflect)]
+#[reflect(Default)]
+pub struct AnimationStateMachine {
+    /// The states of the state machine.
+    pub states: Vec<AnimationState>,
+    /// The transitions between states, in order of priority.
+    pub transitions: Vec<StateTransition>,
+}
+
+/// A state of an [`AnimationStateMachine`].
+#[derive(Clone, Debug, Reflect)]
+pub struct AnimationState {
+    /// The name of the state, for debugging and editors.
+    pub name: String,
+    /// The animation graph node played while in this state.
+    pub node: AnimationNodeIndex,
+    /// Whether the animation repeats while in this state.
+    pub repeat: bool,
+}
+
+/// A transition between two states of an [`AnimationStateMachine`].
+#[derive(Clone, Debug, Reflect)]
+pub struct StateTransition {
+    /// The state this transition starts from, or [`None`] to allow it from any state.
+    pub from: Option<AnimationStateIndex>,
+    /// The state this transition leads to.
+    pub to: AnimationStateIndex,
+    /// The conditions which must all hold for this transition to be taken.
+    pub conditions: Vec<TransitionCondition>,
+    /// How long the old state's animation takes to blend into the new state's.
+    pub blend_duration: Duration,
+    /// Whether other transitions may be taken before this one finishes blending.
+    pub interruption: InterruptionPolicy,
+}
+
+impl StateTransition {
+    /// Adds a condition which must hold for this transition to be taken.
+    pub fn with_condition(&mut self, condition: TransitionCondition) -> &mut Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Allows other transitions to be taken before this one finishes blending.
+    pub fn interruptible(&mut self) -> &mut Self {
+        self.interruption = InterruptionPolicy::Interruptible;
+        self
+    }
+}
+
+/// Whether a [`StateTransition`] can be interrupted while it's blending.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum InterruptionPolicy {
+    /// No other transitions are taken until this one finishes blending.
+    #[default]
+    Uninterruptible,
+    /// Transitions out of the new state may be taken while blending, blending from the current
+    /// pose of the interrupted transition.
+    Interruptible,
+}
+
+/// A condition on the [`StateMachineParams`] of an entity, which must hold for a
+/// [`StateTransition`] to be taken.
+///
+/// Conditions on missing parameters, or parameters of the wrong type, never hold.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub enum TransitionCondition {
+    /// The boolean parameter with this name is `true`.
+    IsTrue(String),
+    /// The boolean parameter with this name is `false`.
+    IsFalse(String),
+    /// The float parameter with this name is greater than the value.
+    GreaterThan(String, f32),
+    /// The float parameter with this name is less than the value.
+    LessThan(String, f32),
+    /// The animation of the current state has finished playing.
+    Finished,
+}
+
+impl TransitionCondition {
+    fn holds(&self, params: &StateMachineParams, finished: bool) -> bool {
+        match self {
+            TransitionCondition::IsTrue(name) => params.get_bool(name) == Some(true),
+            TransitionCondition::IsFalse(name) => params.get_bool(name) == Some(false),
+            TransitionCondition::GreaterThan(name, value) => {
+                params.get_float(name).is_some_and(|param| param > *value)
+            }
+            TransitionCondition::LessThan(name, value) => {
+                params.get_float(name).is_some_and(|param| param < *value)
+            }
+            TransitionCondition::Finished => finished,
+        }
+    }
+}
+
+impl AnimationStateMachine {
+    /// Creates an empty state machine.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a state which plays the given animation graph node on repeat, returning its index.
+    ///
+    /// The first state added is the initial state.
+    pub fn add_state(
+        &mut self,
+        name: impl Into<String>,
+        node: AnimationNodeIndex,
+    ) -> AnimationStateIndex {
+        self.states.push(AnimationState {
+            name: name.into(),
+            node,
+            repeat: true,
+        });
+        AnimationStateIndex(self.states.len() - 1)
+    }
+
+    /// Adds a transition between two states, returning it so that conditions can be added.
+    ///
+    /// A transition without conditions is taken as soon as it's in the `from` state.
+    pub fn add_transition(
+        &mut self,
+        from: AnimationStateIndex,
+        to: AnimationStateIndex,
+        blend_duration: Duration,
+    ) -> &mut StateTransition {
+        self.push_transition(Some(from), to, blend_duration)
+    }
+
+    /// Adds a transition from any state to the given state, returning it so that conditions
+    /// can be added.
+    pub fn add_transition_from_any(
+        &mut self,
+        to: AnimationStateIndex,
+        blend_duration: Duration,
+    ) -> &mut StateTransition {
+        self.push_transition(None, to, blend_duration)
+    }
+
+    fn push_transition(
+        &mut self,
+        from: Option<AnimationStateIndex>,
+        to: AnimationStateIndex,
+        blend_duration: Duration,
+    ) -> &mut StateTransition {
+        self.transitions.push(StateTransition {
+            from,
+            to,
+            conditions: Vec::new(),
+            blend_duration,
+            interruption: InterruptionPolicy::default(),
+        });
+        self.transitions.last_mut().unwrap()
+    }
+
+    /// Returns the state with the given index, if it exists.
+    pub fn state(&self, index: AnimationStateIndex) -> Option<&AnimationState> {
+        self.states.get(index.0)
+    }
+
+    /// Returns the index of the state with the given name, if it exists.
+    pub fn state_by_name(&self, name: &str) -> Option<AnimationStateIndex> {
+        self.states
+            .iter()
+            .position(|state| state.name == name)
+            .map(AnimationStateIndex)
+    }
+
+    /// Returns the first transition out of `current` whose conditions all hold.
+    fn find_transition(
+        &self,
+        current: AnimationStateIndex,
+        params: &StateMachineParams,
+        finished: bool,
+    ) -> Option<&StateTransition> {
+        self.transitions.iter().find(|transition| {
+            transition.from.is_none_or(|from| from == current)
+                && transition.to != current
+                && transition
+                    .conditions
+                    .iter()
+                    .all(|condition| condition.holds(params, finished))
+        })
+    }
+}
+
+/// The value of a parameter in [`StateMachineParams`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub enum ParamValue {
+    /// A boolean parameter, like `"grounded"`.
+    Bool(bool),
+    /// A float parameter, like `"speed"`.
+    Float(f32),
+}
+
+/// The parameters which the [`TransitionCondition`]s of an [`AnimationStateMachine`] are
+/// evaluated against.
+///
+/// Gameplay code sets these every frame, or whenever they change.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct StateMachineParams {
+    values: HashMap<String, ParamValue>,
+}
+
+impl StateMachineParams {
+    /// Sets a boolean parameter.
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) -> &mut Self {
+        self.values.insert(name.into(), ParamValue::Bool(value));
+        self
+    }
+
+    /// Sets a float parameter.
+    pub fn set_float(&mut self, name: impl Into<String>, value: f32) -> &mut Self {
+        self.values.insert(name.into(), ParamValue::Float(value));
+        self
+    }
+
+    /// Returns the value of a parameter, if it's set.
+    pub fn get(&self, name: &str) -> Option<ParamValue> {
+        self.values.get(name).copied()
+    }
+
+    /// Returns the value of a boolean parameter, if it's set.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.get(name)? {
+            ParamValue::Bool(value) => Some(value),
+            ParamValue::Float(_) => None,
+        }
+    }
+
+    /// Returns the value of a float parameter, if it's set.
+    pub fn get_float(&self, name: &str) -> Option<f32> {
+        match self.get(name)? {
+            ParamValue::Float(value) => Some(value),
+            ParamValue::Bool(_) => None,
+        }
+    }
+
+    /// Removes a parameter.
+    pub fn remove(&mut self, name: &str) -> Option<ParamValue> {
+        self.values.remove(name)
+    }
+}
+
+/// A [`Handle`] to the [`AnimationStateMachine`] which drives the [`AnimationPlayer`] on the
+/// same entity.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect, PartialEq, Eq, From)]
+#[reflect(Component, Default)]
+#[require(StateMachineParams, AnimationStateMachineState)]
+pub struct AnimationStateMachineHandle(pub Handle<AnimationStateMachine>);
+
+/// The runtime state of the [`AnimationStateMachine`] on an entity.
+///
+/// This is added automatically with an [`AnimationStateMachineHandle`].
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct AnimationStateMachineState {
+    current: Option<AnimationStateIndex>,
+    blend: Option<StateBlend>,
+}
+
+/// A transition which is blending from the animation of the previous state.
+#[derive(Clone, Copy, Debug, Reflect)]
+struct StateBlend {
+    /// The animation being blended out.
+    from: AnimationNodeIndex,
+    /// The weight of `from` when the blend started.
+    from_weight: f32,
+    /// How long the blend has been going, in seconds.
+    elapsed: f32,
+    /// How long the blend takes, in seconds.
+    duration: f32,
+    interruption: InterruptionPolicy,
+}
+
+impl AnimationStateMachineState {
+    /// Returns the current state, or [`None`] if the state machine hasn't started yet.
+    pub fn current(&self) -> Option<AnimationStateIndex> {
+        self.current
+    }
+
+    /// Returns `true` if a transition is currently blending between two states.
+    pub fn is_blending(&self) -> bool {
+        self.blend.is_some()
+    }
+
+    /// Takes `transition`, blending out the animation of the current state.
+    fn transition(
+        &mut self,
+        player: &mut AnimationPlayer,
+        machine: &AnimationStateMachine,
+        transition: &StateTransition,
+    ) {
+        let Some(to) = machine.state(transition.to) else {
+            return;
+        };
+
+        let from = self
+            .current
+            .and_then(|current| machine.state(current))
+            .map(|state| state.node);
+
+        // An interrupted blend is cut short, and the interrupted state blended out instead.
+        if let Some(blend) = self.blend.take() {
+            if Some(blend.from) != from && blend.from != to.node {
+                player.stop(blend.from);
+            }
+        }
+
+        self.blend = from.filter(|from| *from != to.node).map(|from| StateBlend {
+            from,
+            from_weight: player
+                .animation(from)
+                .map_or(0.0, |animation| animation.weight()),
+            elapsed: 0.0,
+            duration: transition.blend_duration.as_secs_f32(),
+            interruption: transition.interruption,
+        });
+        self.current = Some(transition.to);
+
+        let animation = player.start(to.node);
+        if to.repeat {
+            animation.repeat();
+        }
+    }
+}
+
+/// A system that evaluates the transitions of every [`AnimationStateMachine`], and sets the
+/// weights of the animations of the current and previous states.
+pub fn advance_state_machines(
+    mut query: Query<(
+        &AnimationStateMachineHandle,
+        &StateMachineParams,
+        &mut AnimationStateMachineState,
+        &mut AnimationPlayer,
+    )>,
+    machines: Res<Assets<AnimationStateMachine>>,
+    time: Res<Time>,
+) {
+    for (handle, params, mut state, mut player) in &mut query {
+        let Some(machine) = machines.get(&handle.0) else {
+            continue;
+        };
+        let state = &mut *state;
+
+        match state.current.and_then(|current| machine.state(current)) {
+            Some(current) => {
+                let can_transition = state
+                    .blend
+                    .is_none_or(|blend| blend.interruption == InterruptionPolicy::Interruptible);
+                if can_transition {
+                    let finished = player
+                        .animation(current.node)
+                        .is_none_or(|animation| animation.is_finished());
+                    if let Some(transition) =
+                        machine.find_transition(state.current.unwrap(), params, finished)
+                    {
+                        state.transition(&mut player, machine, transition);
+                    }
+                }
+            }
+            None => {
+                // Enter the initial state.
+                if machine.states.is_empty() {
+                    continue;
+                }
+                let initial = StateTransition {
+                    from: None,
+                    to: AnimationStateIndex(0),
+                    conditions: Vec::new(),
+                    blend_duration: Duration::ZERO,
+                    interruption: InterruptionPolicy::default(),
+                };
+                state.transition(&mut player, machine, &initial);
+            }
+        }
+
+        let Some(current) = state.current.and_then(|current| machine.state(current)) else {
+            continue;
+        };
+
+        let mut current_weight = 1.0;
+        if let Some(blend) = &mut state.blend {
+            blend.elapsed += time.delta_secs();
+            let progress = if blend.duration > 0.0 {
+                (blend.elapsed / blend.duration).min(1.0)
+            } else {
+                1.0
+            };
+            let from_weight = blend.from_weight * (1.0 - progress);
+            if let Some(animation) = player.animation_mut(blend.from) {
+                animation.set_weight(from_weight);
+            }
+            current_weight = 1.0 - from_weight;
+
+            if progress >= 1.0 {
+                player.stop(blend.from);
+                state.blend = None;
+            }
+        }
+
+        if let Some(animation) = player.animation_mut(current.node) {
+            animation.set_weight(current_weight);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_conditions() {
+        let mut machine = AnimationStateMachine::new();
+        let idle = machine.add_state("idle", AnimationNodeIndex::new(1));
+        let run = machine.add_state("run", AnimationNodeIndex::new(2));
+        let jump = machine.add_state("jump", AnimationNodeIndex::new(3));
+        machine
+            .add_transition(idle, run, Duration::ZERO)
+            .with_condition(TransitionCondition::GreaterThan("speed".into(), 0.1))
+            .with_condition(TransitionCondition::IsTrue("grounded".into()));
+        machine
+            .add_transition_from_any(jump, Duration::ZERO)
+            .with_condition(TransitionCondition::IsFalse("grounded".into()));
+
+        let mut params = StateMachineParams::default();
+        assert!(machine.find_transition(idle, &params, false).is_none());
+
+        params.set_float("speed", 1.0).set_bool("grounded", true);
+        let transition = machine.find_transition(idle, &params, false).unwrap();
+        assert_eq!(transition.to, run);
+
+        params.set_bool("grounded", false);
+        let transition = machine.find_transition(run, &params, false).unwrap();
+        assert_eq!(transition.to, jump);
+        assert!(machine.find_transition(jump, &params, false).is_none());
+
+        // Parameters of the wrong type never satisfy a condition.
+        params.set_bool("speed", true).set_bool("grounded", true);
+        assert!(machine.find_transition(idle, &params, false).is_none());
+        assert_eq!(machine.state_by_name("jump"), Some(jump));
+    }
+}
@@ -24,6 +24,8 @@ use thiserror::Error;
 #[cfg(feature = "trace")]
 use tracing::info_span;
 
+#[cfg(feature = "std")]
+use bevy_ecs::system::NonSendThread;
 #[cfg(feature = "std")]
 use std::{
     panic::{catch_unwind, resume_unwind},
@@ -109,6 +111,8 @@ impl Default for App {
             app.register_type::<Name>();
             app.register_type::<ChildOf>();
             app.register_type::<Children>();
+            app.register_type::<bevy_ecs::entity_disabling::Disabled>();
+            app.register_type::<bevy_ecs::entity_disabling::InheritedDisabled>();
         }
 
         #[cfg(feature = "reflect_functions")]
@@ -364,6 +368,39 @@ impl App {
         self
     }
 
+    /// Initializes `T` event handling like [`add_event`](Self::add_event), and registers `T` to be
+    /// recorded by the [`EventRecorder`](crate::EventRecorder) and replayed by an
+    /// [`EventReplay`](crate::EventReplay).
+    ///
+    /// The event type is also registered in the [`AppTypeRegistry`], so that recordings can be
+    /// serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::Reflect;
+    /// #
+    /// # #[derive(Event, Reflect, Clone)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_recorded_event::<MyEvent>();
+    /// ```
+    #[cfg(feature = "bevy_reflect")]
+    pub fn add_recorded_event<T>(&mut self) -> &mut Self
+    where
+        T: Event
+            + Clone
+            + bevy_reflect::FromReflect
+            + bevy_reflect::TypePath
+            + bevy_reflect::GetTypeRegistration,
+    {
+        crate::event_recorder::add_recorded_event::<T>(self);
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -456,6 +493,55 @@ impl App {
         self
     }
 
+    /// Inserts a [`!Send`](Send) resource created by `func` on the [`NonSendThread`], replacing
+    /// any existing instance of `R` there. The thread is spawned if it does not exist yet.
+    ///
+    /// Unlike [`insert_non_send_resource`](Self::insert_non_send_resource), systems access the
+    /// resource through a [`NonSendProxy`](bevy_ecs::system::NonSendProxy), which does not force
+    /// them onto the main thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, system::NonSendProxy};
+    /// # use std::rc::Rc;
+    /// #
+    /// struct MyCounter {
+    ///     counter: Rc<usize>,
+    /// }
+    ///
+    /// fn read_counter(counter: NonSendProxy<MyCounter>) {
+    ///     let _value = counter.call(|counter| *counter.counter);
+    /// }
+    ///
+    /// App::new()
+    ///     .insert_proxied_non_send_resource_with(|| MyCounter { counter: Rc::new(0) })
+    ///     .add_systems(Update, read_counter);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn insert_proxied_non_send_resource_with<R: 'static>(
+        &mut self,
+        func: impl FnOnce() -> R + Send + 'static,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<NonSendThread>()
+            .insert_with(func);
+        self
+    }
+
+    /// Inserts the [`!Send`](Send) resource on the [`NonSendThread`] if there is no existing
+    /// instance of `R` there. The thread is spawned if it does not exist yet.
+    ///
+    /// See [`insert_proxied_non_send_resource_with`](Self::insert_proxied_non_send_resource_with).
+    #[cfg(feature = "std")]
+    pub fn init_proxied_non_send_resource<R: 'static + Default>(&mut self) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<NonSendThread>()
+            .init_with(R::default);
+        self
+    }
+
     pub(crate) fn add_boxed_plugin(
         &mut self,
         plugin: Box<dyn Plugin>,
@@ -1034,6 +1120,95 @@ impl App {
             .try_register_required_components_with::<T, R>(constructor)
     }
 
+    /// Registers the given component `R` as a [required component] for `T` if `condition`
+    /// returns `true` for the current [`World`].
+    ///
+    /// This allows choosing requirements from configuration, like settings stored in resources.
+    /// The requirement is registered with [`World::override_required_components`], so it replaces
+    /// any existing direct requirement of `R` by `T`, including one declared with the `require`
+    /// attribute.
+    ///
+    /// [required component]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if the condition is met and `R` requires `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, PartialEq, Eq)]
+    /// enum Difficulty {
+    ///     Normal,
+    ///     Hard,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// #[require(Brain)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Brain;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Flanking;
+    ///
+    /// # let mut app = App::new();
+    /// app.insert_resource(Difficulty::Hard)
+    ///     .register_required_components_if::<Enemy, Flanking>(|world| {
+    ///         *world.resource::<Difficulty>() == Difficulty::Hard
+    ///     });
+    ///
+    /// let id = app.world_mut().spawn(Enemy).id();
+    /// assert!(app.world().entity(id).contains::<Flanking>());
+    /// ```
+    pub fn register_required_components_if<T: Component, R: Component + Default>(
+        &mut self,
+        condition: impl FnOnce(&World) -> bool,
+    ) -> &mut Self {
+        if condition(self.world()) {
+            self.world_mut()
+                .override_required_components::<T, R>(R::default);
+        }
+        self
+    }
+
+    /// Overrides the given component `R` as a directly [required component] for `T`, replacing
+    /// any existing direct requirement, including one declared with the `require` attribute.
+    ///
+    /// See [`World::override_required_components`] for more info.
+    ///
+    /// [required component]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` requires `T`.
+    pub fn override_required_components<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) -> &mut Self {
+        self.world_mut()
+            .override_required_components::<T, R>(constructor);
+        self
+    }
+
+    /// Removes the given component `R` from the direct [required components] of `T`, including
+    /// a requirement declared with the `require` attribute.
+    ///
+    /// See [`World::remove_required_components`] for more info.
+    ///
+    /// [required components]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is not a directly required component for `T`.
+    pub fn remove_required_components<T: Component, R: Component>(&mut self) -> &mut Self {
+        self.world_mut().remove_required_components::<T, R>();
+        self
+    }
+
     /// Returns a reference to the main [`SubApp`]'s [`World`]. This is the same as calling
     /// [`app.main().world()`].
     ///
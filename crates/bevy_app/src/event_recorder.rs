@@ -0,0 +1,472 @@
+use crate::{App, First, Last};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_ecs::{
+    event::{Event, EventUpdates, Events},
+    prelude::*,
+};
+use bevy_reflect::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    FromReflect, GetTypeRegistration, PartialReflect, TypePath, TypeRegistry,
+};
+use core::{any::TypeId, fmt};
+use log::warn;
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserializer, Serialize, Serializer,
+};
+
+/// Records the events of every type registered with [`App::add_recorded_event`] into an
+/// [`EventLog`], so that a session can be saved and [replayed](EventReplay) later.
+///
+/// Events are stamped with the frame they were sent in, counted from when the recording was
+/// [started](Self::start). They're collected at the end of each frame, in [`Last`], so events sent
+/// later in [`Last`] are recorded as part of the next frame.
+///
+/// The recorder uses [`Events::start_recording`] to capture every event of the registered types,
+/// even ones which are never read, so recordings of those [`Events`] shouldn't be stopped manually.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::{App, EventRecorder, EventReplay};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// #[derive(Event, Reflect, Clone)]
+/// struct Jump(f32);
+///
+/// let mut app = App::new();
+/// app.add_recorded_event::<Jump>();
+/// app.world_mut().resource_mut::<EventRecorder>().start();
+///
+/// app.world_mut().send_event(Jump(2.0));
+/// app.update();
+///
+/// let log = app.world_mut().resource_mut::<EventRecorder>().stop();
+/// assert_eq!(log.len(), 1);
+///
+/// // Replay the events in a fresh app, on the same frames they were recorded in.
+/// let mut replay_app = App::new();
+/// replay_app
+///     .add_recorded_event::<Jump>()
+///     .insert_resource(EventReplay::new(log));
+/// replay_app.update();
+/// assert_eq!(replay_app.world().resource::<Events<Jump>>().len(), 1);
+/// ```
+#[derive(Resource, Default)]
+pub struct EventRecorder {
+    recording: bool,
+    frame: u64,
+    log: EventLog,
+    event_types: Vec<RecordedEventType>,
+}
+
+/// The functions used to record and replay a registered event type.
+struct RecordedEventType {
+    type_id: TypeId,
+    record: fn(&mut World, u64, Option<&mut EventLog>),
+    send: fn(&mut World, &dyn PartialReflect) -> bool,
+}
+
+impl EventRecorder {
+    /// Starts recording events, discarding any previous recording.
+    pub fn start(&mut self) {
+        self.recording = true;
+        self.frame = 0;
+        self.log = EventLog::default();
+    }
+
+    /// Stops recording events, returning what was recorded.
+    pub fn stop(&mut self) -> EventLog {
+        self.recording = false;
+        core::mem::take(&mut self.log)
+    }
+
+    /// Returns `true` if events are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Returns the events recorded so far.
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
+
+    /// Returns `true` if events of type `E` are recorded.
+    pub fn is_registered<E: Event>(&self) -> bool {
+        self.get_event_type(TypeId::of::<E>()).is_some()
+    }
+
+    /// Registers `E` as a recorded event type.
+    ///
+    /// This is done by [`App::add_recorded_event`], which also sets up the [`Events<E>`] resource.
+    pub(crate) fn register<E: Event + Clone + FromReflect>(&mut self) {
+        if self.is_registered::<E>() {
+            return;
+        }
+        self.event_types.push(RecordedEventType {
+            type_id: TypeId::of::<E>(),
+            record: record_events::<E>,
+            send: send_event::<E>,
+        });
+    }
+
+    fn get_event_type(&self, type_id: TypeId) -> Option<&RecordedEventType> {
+        self.event_types
+            .iter()
+            .find(|event_type| event_type.type_id == type_id)
+    }
+
+    /// Adds the systems which record and replay events to the `app`.
+    pub(crate) fn add_systems(app: &mut App) {
+        app.add_systems(First, replay_events.after(EventUpdates))
+            .add_systems(Last, record_events_system);
+    }
+}
+
+/// Moves the events of type `E` recorded during the last frame into the `log`, or discards them
+/// if there is no `log`.
+fn record_events<E: Event + Clone + FromReflect>(
+    world: &mut World,
+    frame: u64,
+    log: Option<&mut EventLog>,
+) {
+    let Some(mut events) = world.get_resource_mut::<Events<E>>() else {
+        return;
+    };
+    let recording = events.stop_recording();
+    events.start_recording();
+
+    let (Some(log), Some(recording)) = (log, recording) else {
+        return;
+    };
+    log.events.extend(
+        recording
+            .into_events()
+            .into_iter()
+            .map(|recorded| LoggedEvent {
+                frame,
+                event: Box::new(recorded.event),
+            }),
+    );
+}
+
+fn send_event<E: Event + FromReflect>(world: &mut World, event: &dyn PartialReflect) -> bool {
+    let Some(event) = E::from_reflect(event) else {
+        return false;
+    };
+    world.send_event(event);
+    true
+}
+
+fn record_events_system(world: &mut World) {
+    world.resource_scope(|world, mut recorder: Mut<EventRecorder>| {
+        let EventRecorder {
+            recording,
+            frame,
+            log,
+            event_types,
+        } = &mut *recorder;
+        for event_type in event_types.iter() {
+            (event_type.record)(world, *frame, recording.then_some(&mut *log));
+        }
+        if *recording {
+            *frame += 1;
+        }
+    });
+}
+
+/// A recording of events made by an [`EventRecorder`].
+///
+/// The log is type-erased, so it can be saved using [`EventLogSerializer`] and loaded using
+/// [`EventLogDeserializer`], as long as the event types are registered in the [`TypeRegistry`].
+#[derive(Debug, Default)]
+pub struct EventLog {
+    events: Vec<LoggedEvent>,
+}
+
+/// An event in an [`EventLog`].
+#[derive(Debug)]
+pub struct LoggedEvent {
+    /// The frame the event was sent in, counted from the start of the recording.
+    pub frame: u64,
+    /// The event itself.
+    pub event: Box<dyn PartialReflect>,
+}
+
+impl EventLog {
+    /// Returns an iterator over the logged events, ordered by frame.
+    ///
+    /// Events of the same type are in the order they were sent in.
+    pub fn iter(&self) -> impl Iterator<Item = &LoggedEvent> {
+        self.events.iter()
+    }
+
+    /// Returns the number of logged events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events were logged.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a EventLog {
+    type Item = &'a LoggedEvent;
+    type IntoIter = core::slice::Iter<'a, LoggedEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.iter()
+    }
+}
+
+/// Replays an [`EventLog`], sending each event at the start of the frame it was recorded in.
+///
+/// Frames are counted from when this resource is inserted, and events are sent in [`First`], after
+/// events are updated. Every event type in the log must be registered with
+/// [`App::add_recorded_event`]; events of other types are skipped with a warning.
+///
+/// When all events have been sent, the resource is removed.
+#[derive(Resource)]
+pub struct EventReplay {
+    log: EventLog,
+    frame: u64,
+    next: usize,
+}
+
+impl EventReplay {
+    /// Creates a replay of the given `log`, starting from its first frame.
+    pub fn new(log: EventLog) -> Self {
+        Self {
+            log,
+            frame: 0,
+            next: 0,
+        }
+    }
+
+    /// Returns the frame which will be replayed next.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Returns the number of events which haven't been sent yet.
+    pub fn remaining(&self) -> usize {
+        self.log.len() - self.next
+    }
+}
+
+fn replay_events(world: &mut World) {
+    let Some(mut replay) = world.remove_resource::<EventReplay>() else {
+        return;
+    };
+    world.resource_scope(|world, recorder: Mut<EventRecorder>| {
+        while let Some(logged) = replay.log.events.get(replay.next) {
+            if logged.frame > replay.frame {
+                break;
+            }
+            replay.next += 1;
+
+            let type_path = logged.event.reflect_type_path();
+            let Some(event_type) = logged
+                .event
+                .get_represented_type_info()
+                .and_then(|info| recorder.get_event_type(info.type_id()))
+            else {
+                warn!("Skipped replaying event {type_path}, as it isn't a recorded event type");
+                continue;
+            };
+            if !(event_type.send)(world, &*logged.event) {
+                warn!("Skipped replaying event {type_path}, as it couldn't be converted from its reflected value");
+            }
+        }
+    });
+    replay.frame += 1;
+    if replay.remaining() > 0 {
+        world.insert_resource(replay);
+    }
+}
+
+/// Serializes an [`EventLog`] as a sequence of `(frame, event)` pairs, using reflection to
+/// serialize the events.
+pub struct EventLogSerializer<'a> {
+    log: &'a EventLog,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> EventLogSerializer<'a> {
+    /// Creates a serializer for the `log`, using the `registry` to serialize its events.
+    pub fn new(log: &'a EventLog, registry: &'a TypeRegistry) -> Self {
+        Self { log, registry }
+    }
+}
+
+impl Serialize for EventLogSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.log.len()))?;
+        for logged in &self.log.events {
+            seq.serialize_element(&(
+                logged.frame,
+                ReflectSerializer::new(&*logged.event, self.registry),
+            ))?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes an [`EventLog`] which was serialized by an [`EventLogSerializer`].
+pub struct EventLogDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> EventLogDeserializer<'a> {
+    /// Creates a deserializer, using the `registry` to deserialize the events.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for EventLogDeserializer<'_> {
+    type Value = EventLog;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for EventLogDeserializer<'_> {
+    type Value = EventLog;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of logged events")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut events = Vec::new();
+        while let Some(logged) = seq.next_element_seed(LoggedEventDeserializer {
+            registry: self.registry,
+        })? {
+            events.push(logged);
+        }
+        // The log may have been edited by hand, and replaying relies on the events being ordered.
+        events.sort_by_key(|logged: &LoggedEvent| logged.frame);
+        Ok(EventLog { events })
+    }
+}
+
+struct LoggedEventDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for LoggedEventDeserializer<'_> {
+    type Value = LoggedEvent;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for LoggedEventDeserializer<'_> {
+    type Value = LoggedEvent;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a (frame, event) pair")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let frame = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let event = seq
+            .next_element_seed(ReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(LoggedEvent { frame, event })
+    }
+}
+
+/// Registers the reflection and recording of the event type `E` on the `app`.
+pub(crate) fn add_recorded_event<E>(app: &mut App)
+where
+    E: Event + Clone + FromReflect + TypePath + GetTypeRegistration,
+{
+    app.add_event::<E>().register_type::<E>();
+    if !app.world().contains_resource::<EventRecorder>() {
+        app.init_resource::<EventRecorder>();
+        EventRecorder::add_systems(app);
+    }
+    app.world_mut()
+        .resource_mut::<EventRecorder>()
+        .register::<E>();
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .start_recording();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{App, EventLogDeserializer, EventLogSerializer, EventRecorder, EventReplay};
+    use alloc::{string::String, vec::Vec};
+    use bevy_ecs::{
+        event::{Event, EventCursor, Events},
+        reflect::AppTypeRegistry,
+    };
+    use bevy_reflect::Reflect;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Event, Reflect, Clone, PartialEq, Debug)]
+    struct Jump(f32);
+
+    #[derive(Event, Reflect, Clone, PartialEq, Debug)]
+    struct Say(String);
+
+    fn read<E: Event + Clone>(app: &App) -> Vec<E> {
+        let events = app.world().resource::<Events<E>>();
+        EventCursor::<E>::default().read(events).cloned().collect()
+    }
+
+    #[test]
+    fn record_and_replay_serialized() {
+        let mut app = App::new();
+        app.add_recorded_event::<Jump>().add_recorded_event::<Say>();
+
+        // Events sent before the recording starts aren't recorded.
+        app.world_mut().send_event(Jump(0.0));
+        app.update();
+
+        app.world_mut().resource_mut::<EventRecorder>().start();
+        app.world_mut().send_event(Jump(1.0));
+        app.world_mut().send_event(Say("hi".into()));
+        app.update();
+        app.update();
+        app.world_mut().send_event(Jump(2.0));
+        app.update();
+        let log = app.world_mut().resource_mut::<EventRecorder>().stop();
+
+        let frames: Vec<u64> = log.iter().map(|logged| logged.frame).collect();
+        assert_eq!(frames, [0, 0, 2]);
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let serialized = ron::to_string(&EventLogSerializer::new(&log, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let log = EventLogDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        drop(registry);
+
+        let mut replay_app = App::new();
+        replay_app
+            .add_recorded_event::<Jump>()
+            .add_recorded_event::<Say>()
+            .insert_resource(EventReplay::new(log));
+
+        replay_app.update();
+        assert_eq!(read::<Jump>(&replay_app), [Jump(1.0)]);
+        assert_eq!(read::<Say>(&replay_app), [Say("hi".into())]);
+
+        replay_app.update();
+        replay_app.update();
+        assert_eq!(read::<Jump>(&replay_app), [Jump(2.0)]);
+        assert!(!replay_app.world().contains_resource::<EventReplay>());
+    }
+}
@@ -21,6 +21,8 @@ extern crate std;
 extern crate alloc;
 
 mod app;
+#[cfg(feature = "bevy_reflect")]
+mod event_recorder;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
@@ -33,6 +35,8 @@ mod task_pool_plugin;
 mod terminal_ctrl_c_handler;
 
 pub use app::*;
+#[cfg(feature = "bevy_reflect")]
+pub use event_recorder::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
@@ -3,6 +3,7 @@ use crate::{
     plugin::Plugin,
     PluginsState,
 };
+use bevy_ecs::resource::Resource;
 use bevy_platform_support::time::Instant;
 use core::time::Duration;
 
@@ -26,6 +27,14 @@ pub enum RunMode {
     },
     /// Indicates that the [`App`]'s schedule should run only once.
     Once,
+    /// Indicates that the [`App`]'s schedule should run repeatedly at a fixed tick rate,
+    /// catching up on ticks which were missed because previous ticks took too long.
+    ///
+    /// This is intended for dedicated servers and simulations, which don't render frames and
+    /// want their updates to happen at a steady rate.
+    ///
+    /// The given settings are inserted as a resource, which can be changed at runtime.
+    FixedTick(FixedTickSettings),
 }
 
 impl Default for RunMode {
@@ -34,6 +43,99 @@ impl Default for RunMode {
     }
 }
 
+/// Settings for running an [`App`] with [`RunMode::FixedTick`].
+///
+/// This is inserted as a resource by the [`ScheduleRunnerPlugin`], and read before every
+/// tick, so the tick rate can be changed at runtime.
+#[derive(Resource, Copy, Clone, Debug, PartialEq)]
+pub struct FixedTickSettings {
+    /// The amount of time between the start of each tick.
+    pub timestep: Duration,
+    /// The maximum number of ticks to run in a row when catching up, before handling
+    /// the remaining missed ticks according to [`catch_up`](Self::catch_up).
+    ///
+    /// A value of `1` never catches up on missed ticks.
+    pub max_ticks_per_frame: u32,
+    /// What to do with missed ticks that couldn't be caught up on within
+    /// [`max_ticks_per_frame`](Self::max_ticks_per_frame).
+    pub catch_up: CatchUpPolicy,
+    /// How long before a tick is due to stop sleeping, and instead wait for it by yielding
+    /// to the OS scheduler.
+    ///
+    /// Sleeping is only as precise as the OS timer, which may wake the thread up to several
+    /// milliseconds late. Waking up early by this margin allows ticks to start on time,
+    /// at the cost of some CPU usage while waiting.
+    /// A value of [`Duration::ZERO`] always sleeps until the tick is due.
+    pub sleep_margin: Duration,
+}
+
+impl FixedTickSettings {
+    /// Creates settings which tick at the given rate, in ticks per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_rate` is not positive and finite.
+    pub fn from_tick_rate(tick_rate: f64) -> Self {
+        Self {
+            timestep: Self::timestep_for(tick_rate),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the tick rate, in ticks per second.
+    pub fn tick_rate(&self) -> f64 {
+        1.0 / self.timestep.as_secs_f64()
+    }
+
+    /// Sets the tick rate, in ticks per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tick_rate` is not positive and finite.
+    pub fn set_tick_rate(&mut self, tick_rate: f64) {
+        self.timestep = Self::timestep_for(tick_rate);
+    }
+
+    fn timestep_for(tick_rate: f64) -> Duration {
+        assert!(
+            tick_rate > 0.0 && tick_rate.is_finite(),
+            "tick rate must be positive and finite, but was {tick_rate}"
+        );
+        Duration::from_secs_f64(1.0 / tick_rate)
+    }
+}
+
+impl Default for FixedTickSettings {
+    /// Ticks 64 times per second, catching up on at most 8 ticks at once before dropping
+    /// the rest, with a sleep margin of 1 millisecond.
+    fn default() -> Self {
+        Self {
+            timestep: Duration::from_micros(15625),
+            max_ticks_per_frame: 8,
+            catch_up: CatchUpPolicy::default(),
+            sleep_margin: Duration::from_millis(1),
+        }
+    }
+}
+
+/// Determines what [`RunMode::FixedTick`] does with missed ticks that it couldn't catch up on
+/// within [`FixedTickSettings::max_ticks_per_frame`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CatchUpPolicy {
+    /// The remaining missed ticks are dropped, and the tick schedule restarts from the
+    /// current time.
+    ///
+    /// This prevents an overloaded app from falling further and further behind, at the cost
+    /// of the simulation running slower than real time while it's overloaded.
+    #[default]
+    Drop,
+    /// The remaining missed ticks are kept, and caught up on after the next break.
+    ///
+    /// This keeps the simulation in step with real time in the long run, but an app that's
+    /// consistently overloaded will never catch up.
+    Keep,
+}
+
 /// Configures an [`App`] to run its [`Schedule`](bevy_ecs::schedule::Schedule) according to a given
 /// [`RunMode`].
 ///
@@ -68,11 +170,21 @@ impl ScheduleRunnerPlugin {
             },
         }
     }
+
+    /// See [`RunMode::FixedTick`].
+    pub fn run_fixed_tick(settings: FixedTickSettings) -> Self {
+        ScheduleRunnerPlugin {
+            run_mode: RunMode::FixedTick(settings),
+        }
+    }
 }
 
 impl Plugin for ScheduleRunnerPlugin {
     fn build(&self, app: &mut App) {
         let run_mode = self.run_mode;
+        if let RunMode::FixedTick(settings) = run_mode {
+            app.insert_resource(settings);
+        }
         app.set_runner(move |mut app: App| {
             let plugins_state = app.plugins_state();
             if plugins_state != PluginsState::Cleaned {
@@ -118,61 +230,163 @@ impl Plugin for ScheduleRunnerPlugin {
                         Ok(None)
                     };
 
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                        loop {
-                            match tick(&mut app, wait) {
-                                Ok(Some(_delay)) => {
-                                    #[cfg(feature = "std")]
-                                    std::thread::sleep(_delay);
-                                }
-                                Ok(None) => continue,
-                                Err(exit) => return exit,
-                            }
-                        }
-                    }
+                    run_loop(app, move |app| tick(app, wait))
+                }
+                RunMode::FixedTick(settings) => {
+                    let mut next_tick = None;
+                    run_loop(app, move |app| fixed_tick(app, settings, &mut next_tick))
+                }
+            }
+        });
+    }
+}
 
-                    #[cfg(target_arch = "wasm32")]
-                    {
-                        fn set_timeout(callback: &Closure<dyn FnMut()>, dur: Duration) {
-                            web_sys::window()
-                                .unwrap()
-                                .set_timeout_with_callback_and_timeout_and_arguments_0(
-                                    callback.as_ref().unchecked_ref(),
-                                    dur.as_millis() as i32,
-                                )
-                                .expect("Should register `setTimeout`.");
-                        }
-                        let asap = Duration::from_millis(1);
-
-                        let exit = Rc::new(RefCell::new(AppExit::Success));
-                        let closure_exit = exit.clone();
-
-                        let mut app = Rc::new(app);
-                        let moved_tick_closure = Rc::new(RefCell::new(None));
-                        let base_tick_closure = moved_tick_closure.clone();
-
-                        let tick_app = move || {
-                            let app = Rc::get_mut(&mut app).unwrap();
-                            let delay = tick(app, wait);
-                            match delay {
-                                Ok(delay) => set_timeout(
-                                    moved_tick_closure.borrow().as_ref().unwrap(),
-                                    delay.unwrap_or(asap),
-                                ),
-                                Err(code) => {
-                                    closure_exit.replace(code);
-                                }
-                            }
-                        };
-                        *base_tick_closure.borrow_mut() =
-                            Some(Closure::wrap(Box::new(tick_app) as Box<dyn FnMut()>));
-                        set_timeout(base_tick_closure.borrow().as_ref().unwrap(), asap);
+/// Repeatedly runs `tick`, waiting for the [`Duration`] it returns in between.
+fn run_loop(
+    app: App,
+    mut tick: impl FnMut(&mut App) -> Result<Option<Duration>, AppExit> + 'static,
+) -> AppExit {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut app = app;
+        loop {
+            match tick(&mut app) {
+                Ok(Some(_delay)) => {
+                    #[cfg(feature = "std")]
+                    std::thread::sleep(_delay);
+                }
+                Ok(None) => continue,
+                Err(exit) => return exit,
+            }
+        }
+    }
 
-                        exit.take()
-                    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        fn set_timeout(callback: &Closure<dyn FnMut()>, dur: Duration) {
+            web_sys::window()
+                .unwrap()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    callback.as_ref().unchecked_ref(),
+                    dur.as_millis() as i32,
+                )
+                .expect("Should register `setTimeout`.");
+        }
+        let asap = Duration::from_millis(1);
+
+        let exit = Rc::new(RefCell::new(AppExit::Success));
+        let closure_exit = exit.clone();
+
+        let mut app = Rc::new(app);
+        let moved_tick_closure = Rc::new(RefCell::new(None));
+        let base_tick_closure = moved_tick_closure.clone();
+
+        let tick_app = move || {
+            let app = Rc::get_mut(&mut app).unwrap();
+            let delay = tick(app);
+            match delay {
+                Ok(delay) => set_timeout(
+                    moved_tick_closure.borrow().as_ref().unwrap(),
+                    delay.unwrap_or(asap),
+                ),
+                Err(code) => {
+                    closure_exit.replace(code);
                 }
             }
-        });
+        };
+        *base_tick_closure.borrow_mut() =
+            Some(Closure::wrap(Box::new(tick_app) as Box<dyn FnMut()>));
+        set_timeout(base_tick_closure.borrow().as_ref().unwrap(), asap);
+
+        exit.take()
+    }
+}
+
+/// Runs the ticks of [`RunMode::FixedTick`] which are due, and returns how long to sleep for
+/// until the next one.
+fn fixed_tick(
+    app: &mut App,
+    initial_settings: FixedTickSettings,
+    next_tick: &mut Option<Instant>,
+) -> Result<Option<Duration>, AppExit> {
+    let current_settings = |app: &App| {
+        app.world()
+            .get_resource::<FixedTickSettings>()
+            .copied()
+            .unwrap_or(initial_settings)
+    };
+    let next_tick = next_tick.get_or_insert_with(Instant::now);
+
+    let now = Instant::now();
+    if now < *next_tick {
+        let sleep = sleep_until(*next_tick, now, current_settings(app).sleep_margin);
+        if sleep.is_some() {
+            return Ok(sleep);
+        }
+        while Instant::now() < *next_tick {
+            #[cfg(feature = "std")]
+            std::thread::yield_now();
+            #[cfg(not(feature = "std"))]
+            core::hint::spin_loop();
+        }
+    }
+
+    let mut ticks = 0;
+    loop {
+        app.update();
+
+        if let Some(exit) = app.should_exit() {
+            return Err(exit);
+        }
+
+        ticks += 1;
+        // The settings may have been changed by the tick
+        let settings = current_settings(app);
+        *next_tick += settings.timestep;
+
+        let now = Instant::now();
+        if now < *next_tick {
+            // If the next tick is within the sleep margin, it's waited for when called again
+            return Ok(sleep_until(*next_tick, now, settings.sleep_margin));
+        }
+        if ticks >= settings.max_ticks_per_frame {
+            if settings.catch_up == CatchUpPolicy::Drop {
+                *next_tick = now;
+            }
+            return Ok(None);
+        }
+    }
+}
+
+/// Returns how long to sleep for to wake up `sleep_margin` before `next_tick`, or `None` if
+/// that time has already passed.
+fn sleep_until(next_tick: Instant, now: Instant, sleep_margin: Duration) -> Option<Duration> {
+    next_tick
+        .saturating_duration_since(now)
+        .checked_sub(sleep_margin)
+        .filter(|sleep| !sleep.is_zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sleep_until;
+    use bevy_platform_support::time::Instant;
+    use core::time::Duration;
+
+    #[test]
+    fn sleep_until_wakes_up_early_by_the_margin() {
+        let now = Instant::now();
+        let next_tick = now + Duration::from_millis(10);
+        assert_eq!(
+            sleep_until(next_tick, now, Duration::from_millis(1)),
+            Some(Duration::from_millis(9))
+        );
+        assert_eq!(
+            sleep_until(next_tick, now, Duration::ZERO),
+            Some(Duration::from_millis(10))
+        );
+        // Within the margin, the tick is waited for without sleeping
+        assert_eq!(sleep_until(next_tick, now, Duration::from_millis(10)), None);
+        assert_eq!(sleep_until(now, next_tick, Duration::ZERO), None);
     }
 }
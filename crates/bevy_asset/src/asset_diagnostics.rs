@@ -0,0 +1,95 @@
+use crate::{Asset, Assets};
+use alloc::format;
+use bevy_app::{App, Plugin, Update};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::system::Res;
+use core::marker::PhantomData;
+
+/// An [`Asset`] which can report how much memory it uses.
+///
+/// This is used by [`AssetMemoryDiagnosticsPlugin`] to measure the memory used by all assets of
+/// a type.
+pub trait AssetSize: Asset {
+    /// Returns the number of bytes used by this asset's data.
+    ///
+    /// This doesn't need to be exact: large buffers like pixels or vertices are what matter.
+    fn asset_size(&self) -> usize;
+}
+
+/// Adds a "memory usage" diagnostic for the assets of type `A` to an App.
+///
+/// The total size of all loaded `A` assets, as reported by [`AssetSize::asset_size`], is
+/// measured under `memory/assets/<type path of A>`. Assets which have been unloaded from the
+/// main world after being sent to the render world aren't included.
+pub struct AssetMemoryDiagnosticsPlugin<A: AssetSize> {
+    marker: PhantomData<fn() -> A>,
+}
+
+impl<A: AssetSize> Default for AssetMemoryDiagnosticsPlugin<A> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: AssetSize> Plugin for AssetMemoryDiagnosticsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::path()).with_suffix("B"))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl<A: AssetSize> AssetMemoryDiagnosticsPlugin<A> {
+    /// The [`DiagnosticPath`] under which the memory used by `A` assets is measured.
+    pub fn path() -> DiagnosticPath {
+        DiagnosticPath::new(format!("memory/assets/{}", A::type_path()))
+    }
+
+    fn diagnostic_system(mut diagnostics: Diagnostics, assets: Res<Assets<A>>) {
+        diagnostics.add_measurement(&Self::path(), || {
+            assets
+                .iter()
+                .map(|(_, asset)| asset.asset_size())
+                .sum::<usize>() as f64
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_asset;
+    use bevy_diagnostic::{DiagnosticsPlugin, DiagnosticsStore};
+    use bevy_reflect::TypePath;
+
+    #[derive(Asset, TypePath)]
+    struct Blob(alloc::vec::Vec<u8>);
+
+    impl AssetSize for Blob {
+        fn asset_size(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    fn measures_asset_memory() {
+        let mut app = App::new();
+        app.add_plugins((
+            DiagnosticsPlugin,
+            AssetMemoryDiagnosticsPlugin::<Blob>::default(),
+        ))
+        .init_resource::<Assets<Blob>>();
+
+        let mut assets = app.world_mut().resource_mut::<Assets<Blob>>();
+        assets.add(Blob(alloc::vec![0; 100]));
+        assets.add(Blob(alloc::vec![0; 28]));
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let diagnostic = store
+            .get(&AssetMemoryDiagnosticsPlugin::<Blob>::path())
+            .unwrap();
+        assert_eq!(diagnostic.value(), Some(128.0));
+    }
+}
@@ -157,13 +157,13 @@ impl<A: Asset> DenseAssetStorage<A> {
                 *value = Some(asset);
                 Ok(exists)
             } else {
-                Err(InvalidGenerationError {
+                Err(InvalidGenerationError::Occupied {
                     index,
                     current_generation: *generation,
                 })
             }
         } else {
-            unreachable!("entries should always be valid after a flush");
+            Err(InvalidGenerationError::Removed { index })
         }
     }
 
@@ -292,6 +292,9 @@ pub struct Assets<A: Asset> {
     /// Assets managed by the `Assets` struct with live strong `Handle`s
     /// originating from `get_strong_handle`.
     duplicate_handles: HashMap<AssetId<A>, u16>,
+    /// Assets which were retargeted at another asset using `retarget`, along with a strong
+    /// handle to the asset they now resolve to.
+    redirects: HashMap<AssetId<A>, Handle<A>>,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -305,6 +308,7 @@ impl<A: Asset> Default for Assets<A> {
             hash_map: Default::default(),
             queued_events: Default::default(),
             duplicate_handles: Default::default(),
+            redirects: Default::default(),
         }
     }
 }
@@ -349,7 +353,7 @@ impl<A: Asset> Assets<A> {
 
     /// Returns `true` if the `id` exists in this collection. Otherwise it returns `false`.
     pub fn contains(&self, id: impl Into<AssetId<A>>) -> bool {
-        match id.into() {
+        match self.resolve(id.into()) {
             AssetId::Index { index, .. } => self.dense_storage.get(index).is_some(),
             AssetId::Uuid { uuid } => self.hash_map.contains_key(&uuid),
         }
@@ -416,7 +420,7 @@ impl<A: Asset> Assets<A> {
     /// Note that this supports anything that implements `Into<AssetId<A>>`, which includes [`Handle`] and [`AssetId`].
     #[inline]
     pub fn get(&self, id: impl Into<AssetId<A>>) -> Option<&A> {
-        match id.into() {
+        match self.resolve(id.into()) {
             AssetId::Index { index, .. } => self.dense_storage.get(index),
             AssetId::Uuid { uuid } => self.hash_map.get(&uuid),
         }
@@ -426,7 +430,7 @@ impl<A: Asset> Assets<A> {
     /// Note that this supports anything that implements `Into<AssetId<A>>`, which includes [`Handle`] and [`AssetId`].
     #[inline]
     pub fn get_mut(&mut self, id: impl Into<AssetId<A>>) -> Option<&mut A> {
-        let id: AssetId<A> = id.into();
+        let id = self.resolve(id.into());
         let result = match id {
             AssetId::Index { index, .. } => self.dense_storage.get_mut(index),
             AssetId::Uuid { uuid } => self.hash_map.get_mut(&uuid),
@@ -437,6 +441,89 @@ impl<A: Asset> Assets<A> {
         result
     }
 
+    /// Replaces the [`Asset`] with the given `id`, and returns the previous value if it existed.
+    ///
+    /// Unlike removing the asset and adding a new one, every existing [`Handle`] for `id` keeps
+    /// working and resolves to the new value. An [`AssetEvent::Modified`] is queued for `id`, so
+    /// anything derived from the previous value, such as render assets, is rebuilt.
+    ///
+    /// If `id` was [retargeted](Self::retarget), the asset it resolves to is replaced instead.
+    ///
+    /// Returns an error without changing anything if `id` has a stale generation, because the
+    /// asset it referred to was dropped.
+    pub fn swap(
+        &mut self,
+        id: impl Into<AssetId<A>>,
+        asset: A,
+    ) -> Result<Option<A>, InvalidGenerationError> {
+        match self.resolve(id.into()) {
+            AssetId::Index { index, .. } => {
+                let previous = self.dense_storage.remove_still_alive(index);
+                if let Err(error) = self.dense_storage.insert(index, asset) {
+                    // `remove_still_alive` only returns an asset for a valid generation.
+                    debug_assert!(previous.is_none());
+                    return Err(error);
+                }
+                let id = index.into();
+                self.queued_events.push(if previous.is_some() {
+                    AssetEvent::Modified { id }
+                } else {
+                    AssetEvent::Added { id }
+                });
+                Ok(previous)
+            }
+            AssetId::Uuid { uuid } => Ok(self.insert_with_uuid(uuid, asset)),
+        }
+    }
+
+    /// Retargets `id` at the asset of `target`, so that every existing [`Handle`] for `id`
+    /// resolves to that asset instead, and queues an [`AssetEvent::Modified`] for `id`.
+    ///
+    /// This allows swapping out assets that are referenced from many places, such as skins or
+    /// texture packs, without touching every component that stores a handle to them. The asset
+    /// stored for `id` itself is kept, and is used again after [`reset_target`](Self::reset_target).
+    ///
+    /// `target` is kept alive for as long as `id` is retargeted at it. Retargeting is transitive:
+    /// ids retargeted at `id` are retargeted at `target` as well, and retargeting `id` at itself
+    /// resets it.
+    pub fn retarget(&mut self, id: impl Into<AssetId<A>>, target: Handle<A>) {
+        let id = id.into();
+        let target = match self.redirects.get(&target.id()) {
+            Some(target) => target.clone(),
+            None => target,
+        };
+        if target.id() == id {
+            self.reset_target(id);
+            return;
+        }
+
+        for (redirected, redirect) in &mut self.redirects {
+            if redirect.id() == id {
+                *redirect = target.clone();
+                self.queued_events
+                    .push(AssetEvent::Modified { id: *redirected });
+            }
+        }
+        self.redirects.insert(id, target);
+        self.queued_events.push(AssetEvent::Modified { id });
+    }
+
+    /// Undoes a [`retarget`](Self::retarget) of `id`, so that its handles resolve to its own
+    /// asset again. Returns the handle `id` was retargeted at, if it was.
+    pub fn reset_target(&mut self, id: impl Into<AssetId<A>>) -> Option<Handle<A>> {
+        let id = id.into();
+        let target = self.redirects.remove(&id)?;
+        self.queued_events.push(AssetEvent::Modified { id });
+        Some(target)
+    }
+
+    /// Returns the id of the asset that `id` resolves to, which is `id` itself unless it was
+    /// [retargeted](Self::retarget).
+    pub fn resolve(&self, id: impl Into<AssetId<A>>) -> AssetId<A> {
+        let id = id.into();
+        self.redirects.get(&id).map_or(id, Handle::id)
+    }
+
     /// Removes (and returns) the [`Asset`] with the given `id`, if it exists.
     /// Note that this supports anything that implements `Into<AssetId<A>>`, which includes [`Handle`] and [`AssetId`].
     pub fn remove(&mut self, id: impl Into<AssetId<A>>) -> Option<A> {
@@ -472,6 +559,7 @@ impl<A: Asset> Assets<A> {
             AssetId::Index { index, .. } => self.dense_storage.remove_dropped(index).is_some(),
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid).is_some(),
         };
+        self.redirects.remove(&id);
         if existed {
             self.queued_events.push(AssetEvent::Removed { id });
         }
@@ -569,6 +657,24 @@ impl<A: Asset> Assets<A> {
     ) {
         use AssetEvent::{Added, LoadedWithDependencies, Modified, Removed};
 
+        // Changes to an asset are also changes to every asset retargeted at it
+        if !assets.redirects.is_empty() {
+            let assets = &mut *assets;
+            let mut redirected_events = Vec::new();
+            for event in &assets.queued_events {
+                if let Added { id } | Modified { id } = event {
+                    redirected_events.extend(
+                        assets
+                            .redirects
+                            .iter()
+                            .filter(|(_, target)| target.id() == *id)
+                            .map(|(redirected, _)| Modified { id: *redirected }),
+                    );
+                }
+            }
+            assets.queued_events.extend(redirected_events);
+        }
+
         if let Some(mut asset_changes) = asset_changes {
             for new_event in &assets.queued_events {
                 match new_event {
@@ -633,15 +739,23 @@ impl<'a, A: Asset> Iterator for AssetsMutIterator<'a, A> {
 }
 
 #[derive(Error, Debug)]
-#[error("AssetIndex {index:?} has an invalid generation. The current generation is: '{current_generation}'.")]
-pub struct InvalidGenerationError {
-    index: AssetIndex,
-    current_generation: u32,
+pub enum InvalidGenerationError {
+    #[error("AssetIndex {index:?} has an invalid generation. The current generation is: '{current_generation}'.")]
+    Occupied {
+        index: AssetIndex,
+        current_generation: u32,
+    },
+    #[error("AssetIndex {index:?} has been removed")]
+    Removed { index: AssetIndex },
 }
 
 #[cfg(test)]
 mod test {
-    use crate::AssetIndex;
+    use crate::{self as bevy_asset, Asset, AssetIndex, Assets};
+    use bevy_reflect::TypePath;
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset(u32);
 
     #[test]
     fn asset_index_round_trip() {
@@ -652,4 +766,15 @@ mod test {
         let roundtripped = AssetIndex::from_bits(asset_index.to_bits());
         assert_eq!(asset_index, roundtripped);
     }
+
+    #[test]
+    fn swap_with_stale_id() {
+        let mut assets = Assets::<TestAsset>::default();
+        let id = assets.add(TestAsset(0)).id();
+        assert_eq!(assets.swap(id, TestAsset(1)).unwrap().unwrap().0, 0);
+
+        assets.remove_dropped(id);
+        assert!(assets.swap(id, TestAsset(2)).is_err());
+        assert_eq!(assets.len(), 0);
+    }
 }
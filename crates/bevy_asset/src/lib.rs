@@ -170,6 +170,7 @@ pub mod prelude {
 }
 
 mod asset_changed;
+mod asset_diagnostics;
 mod assets;
 mod direct_access_ext;
 mod event;
@@ -183,6 +184,7 @@ mod reflect;
 mod render_asset;
 mod server;
 
+pub use asset_diagnostics::*;
 pub use assets::*;
 pub use bevy_asset_macros::Asset;
 pub use direct_access_ext::DirectAssetAccessExt;
@@ -1436,6 +1438,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn swap_and_retarget_assets() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let path = "dep.cool.ron";
+        dir.insert_asset_text(Path::new(path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<StoredEvents>()
+            .register_asset_loader(CoolTextLoader)
+            .add_systems(Update, store_asset_events);
+
+        let text = |text: &str| CoolText {
+            text: text.to_string(),
+            ..Default::default()
+        };
+
+        // Swapping keeps the id of the asset
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .add(text("a"));
+        let previous = app
+            .world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .swap(&handle, text("b"))
+            .unwrap();
+        assert_eq!(previous.unwrap().text, "a");
+        assert_eq!(get::<CoolText>(app.world(), handle.id()).unwrap().text, "b");
+
+        // Replacing retargets the handles of the loaded asset
+        let loaded = app.world().resource::<AssetServer>().load::<CoolText>(path);
+        gate_opener.open(path);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, loaded.id()).map(|_| ())
+        });
+
+        assert!(app.world().resource::<AssetServer>().replace(path, &handle));
+        // Events are sent at the end of the frame, and read in the next one
+        app.update();
+        app.update();
+        assert_eq!(get::<CoolText>(app.world(), loaded.id()).unwrap().text, "b");
+        let events = core::mem::take(&mut app.world_mut().resource_mut::<StoredEvents>().0);
+        assert!(events.contains(&AssetEvent::Modified { id: loaded.id() }));
+
+        // Modifying the target also modifies the retargeted asset
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .get_mut(&handle)
+            .unwrap()
+            .text = "c".to_string();
+        app.update();
+        app.update();
+        assert_eq!(get::<CoolText>(app.world(), loaded.id()).unwrap().text, "c");
+        let events = core::mem::take(&mut app.world_mut().resource_mut::<StoredEvents>().0);
+        assert!(events.contains(&AssetEvent::Modified { id: handle.id() }));
+        assert!(events.contains(&AssetEvent::Modified { id: loaded.id() }));
+
+        // The target stays alive while it is retargeted at
+        let target = handle.id();
+        drop(handle);
+        app.update();
+        assert!(get::<CoolText>(app.world(), target).is_some());
+
+        let mut texts = app.world_mut().resource_mut::<Assets<CoolText>>();
+        assert_eq!(texts.reset_target(&loaded).unwrap().id(), target);
+        assert_eq!(texts.get(&loaded).unwrap().text, "dep");
+    }
+
     #[test]
     fn manual_asset_management() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
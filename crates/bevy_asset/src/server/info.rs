@@ -85,6 +85,7 @@ pub(crate) struct AssetInfos {
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId)>,
     pub(crate) dependency_failed_event_sender:
         TypeIdMap<fn(&mut World, UntypedAssetId, AssetPath<'static>, AssetLoadError)>,
+    pub(crate) retarget_asset: TypeIdMap<fn(&mut World, UntypedAssetId, UntypedHandle)>,
     pub(crate) pending_tasks: HashMap<UntypedAssetId, Task<()>>,
 }
 
@@ -170,6 +170,11 @@ impl AssetServer {
                     error,
                 });
         }
+        fn retarget<A: Asset>(world: &mut World, id: UntypedAssetId, target: UntypedHandle) {
+            world
+                .resource_mut::<Assets<A>>()
+                .retarget(id.typed::<A>(), target.typed::<A>());
+        }
 
         let mut infos = self.data.infos.write();
 
@@ -180,6 +185,10 @@ impl AssetServer {
         infos
             .dependency_failed_event_sender
             .insert(TypeId::of::<A>(), failed_sender::<A>);
+
+        infos
+            .retarget_asset
+            .insert(TypeId::of::<A>(), retarget::<A>);
     }
 
     pub(crate) fn register_handle_provider(&self, handle_provider: AssetHandleProvider) {
@@ -948,6 +957,31 @@ impl AssetServer {
             .detach();
     }
 
+    /// Retargets every existing handle to the asset of type `A` loaded from `path` at the asset of
+    /// `handle`, for example to switch skins or texture packs without touching every component
+    /// that stores a handle to the asset.
+    ///
+    /// The asset loaded from `path` keeps its id, and an [`AssetEvent::Modified`] is sent for it
+    /// once the change is applied in [`handle_internal_asset_events`]. Loading `path` again returns
+    /// a handle that resolves to the new asset for as long as handles to `path` are alive. Use
+    /// [`Assets::reset_target`] with the id of the path to undo this.
+    ///
+    /// Returns `false` without doing anything if no asset of type `A` is loaded from `path`.
+    pub fn replace<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        handle: &Handle<A>,
+    ) -> bool {
+        let Some(path_handle) = self.get_handle::<A>(path) else {
+            return false;
+        };
+        self.send_asset_event(InternalAssetEvent::Retarget {
+            id: path_handle.id().untyped(),
+            target: handle.clone().untyped(),
+        });
+        true
+    }
+
     fn send_asset_event(&self, event: InternalAssetEvent) {
         self.data.asset_event_sender.send(event).unwrap();
     }
@@ -1519,6 +1553,13 @@ pub fn handle_internal_asset_events(world: &mut World) {
                         .expect("Asset failed event sender should exist");
                     sender(world, id, path, error);
                 }
+                InternalAssetEvent::Retarget { id, target } => {
+                    let retarget = infos
+                        .retarget_asset
+                        .get(&id.type_id())
+                        .expect("Asset retarget function should exist");
+                    retarget(world, id, target);
+                }
             }
         }
 
@@ -1621,6 +1662,10 @@ pub(crate) enum InternalAssetEvent {
         path: AssetPath<'static>,
         error: AssetLoadError,
     },
+    Retarget {
+        id: UntypedAssetId,
+        target: UntypedHandle,
+    },
 }
 
 /// The load state of an asset.
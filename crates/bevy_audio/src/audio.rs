@@ -56,6 +56,13 @@ pub struct PlaybackSettings {
     /// Optional scale factor applied to the positions of this audio source and the listener,
     /// overriding the default value configured on [`AudioPlugin::default_spatial_scale`](crate::AudioPlugin::default_spatial_scale).
     pub spatial_scale: Option<SpatialScale>,
+    /// The priority of this sound when [virtualizing](crate::AudioVirtualization) spatial audio.
+    ///
+    /// When too many spatial sounds are playing, sounds with a higher priority keep playing
+    /// over sounds with a lower priority, regardless of how loud they are.
+    ///
+    /// Unlike the other settings, changes to this are applied to already-playing audio.
+    pub priority: i32,
 }
 
 impl Default for PlaybackSettings {
@@ -80,6 +87,7 @@ impl PlaybackSettings {
         muted: false,
         spatial: false,
         spatial_scale: None,
+        priority: 0,
     };
 
     /// Will play the associated audio source in a loop.
@@ -135,6 +143,12 @@ impl PlaybackSettings {
         self.spatial_scale = Some(spatial_scale);
         self
     }
+
+    /// Helper to set the priority when virtualizing spatial audio.
+    pub const fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
 }
 
 /// Settings for the listener for spatial audio sources.
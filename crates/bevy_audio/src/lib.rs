@@ -34,6 +34,7 @@ mod audio_output;
 mod audio_source;
 mod pitch;
 mod sinks;
+mod virtualization;
 mod volume;
 
 /// The audio prelude.
@@ -50,6 +51,7 @@ pub mod prelude {
 pub use audio::*;
 pub use audio_source::*;
 pub use pitch::*;
+pub use virtualization::*;
 pub use volume::*;
 
 pub use rodio::{cpal::Sample as CpalSample, source::Source, Sample};
@@ -76,6 +78,8 @@ pub struct AudioPlugin {
     /// The scale factor applied to the positions of audio sources and listeners for
     /// spatial audio.
     pub default_spatial_scale: SpatialScale,
+    /// Limits how many spatial sounds are played at once.
+    pub virtualization: AudioVirtualization,
 }
 
 impl Plugin for AudioPlugin {
@@ -86,7 +90,9 @@ impl Plugin for AudioPlugin {
             .register_type::<DefaultSpatialScale>()
             .register_type::<PlaybackMode>()
             .register_type::<PlaybackSettings>()
+            .register_type::<AudioVirtualization>()
             .insert_resource(self.global_volume)
+            .insert_resource(self.virtualization)
             .insert_resource(DefaultSpatialScale(self.default_spatial_scale))
             .configure_sets(
                 PostUpdate,
@@ -96,7 +102,12 @@ impl Plugin for AudioPlugin {
             )
             .add_systems(
                 PostUpdate,
-                (update_emitter_positions, update_listener_positions).in_set(AudioPlaySet),
+                (
+                    update_emitter_positions,
+                    update_listener_positions,
+                    virtualize_spatial_audio,
+                )
+                    .in_set(AudioPlaySet),
             )
             .init_resource::<AudioOutput>();
 
@@ -1,6 +1,8 @@
 use bevy_ecs::component::Component;
 use bevy_math::Vec3;
 use bevy_transform::prelude::Transform;
+use core::time::Duration;
+pub use rodio::source::SeekError;
 use rodio::{Sink, SpatialSink};
 
 /// Common interactions with an audio sink.
@@ -75,6 +77,21 @@ pub trait AudioSinkPlayback {
     /// Sinks can be paused and resumed using [`pause`](Self::pause) and [`play`](Self::play).
     fn is_paused(&self) -> bool;
 
+    /// Returns the position of the sound that's being played.
+    ///
+    /// This takes into account any speedup or delay applied.
+    fn position(&self) -> Duration;
+
+    /// Attempts to seek to the given position in the sound that's being played.
+    ///
+    /// This blocks for up to a few milliseconds, until the sound has seeked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sound doesn't support seeking, such as a looping sound, or if
+    /// seeking failed.
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError>;
+
     /// Stops the sink.
     ///
     /// It won't be possible to restart it afterwards.
@@ -178,6 +195,14 @@ impl AudioSinkPlayback for AudioSink {
         self.sink.is_paused()
     }
 
+    fn position(&self) -> Duration {
+        self.sink.get_pos()
+    }
+
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError> {
+        self.sink.try_seek(position)
+    }
+
     fn stop(&self) {
         self.sink.stop();
     }
@@ -273,6 +298,14 @@ impl AudioSinkPlayback for SpatialAudioSink {
         self.sink.is_paused()
     }
 
+    fn position(&self) -> Duration {
+        self.sink.get_pos()
+    }
+
+    fn try_seek(&self, position: Duration) -> Result<(), SeekError> {
+        self.sink.try_seek(position)
+    }
+
     fn stop(&self) {
         self.sink.stop();
     }
@@ -325,6 +358,9 @@ mod tests {
     use super::*;
 
     fn test_audio_sink_playback<T: AudioSinkPlayback>(mut audio_sink: T) {
+        // Test position
+        assert_eq!(audio_sink.position(), Duration::ZERO);
+
         // Test volume
         assert_eq!(audio_sink.volume(), 1.0); // default volume
         audio_sink.set_volume(0.5);
@@ -0,0 +1,136 @@
+use crate::{
+    audio_output::EarPositions, AudioSinkPlayback, DefaultSpatialScale, PlaybackSettings,
+    SpatialAudioSink,
+};
+use alloc::vec::Vec;
+use bevy_ecs::prelude::*;
+use bevy_platform_support::time::Instant;
+use bevy_reflect::prelude::*;
+use bevy_transform::prelude::GlobalTransform;
+use core::time::Duration;
+
+/// Use this [`Resource`] to limit how many spatial sounds are played at once.
+///
+/// When more than [`max_audible`](Self::max_audible) spatial sounds are playing, the ones with
+/// the lowest [`PlaybackSettings::priority`], and then the quietest ones, are *virtualized*:
+/// they are paused and marked with [`VirtualizedAudio`]. Once enough of the other sounds have
+/// finished or moved away, they are resumed.
+///
+/// While a sound is virtualized, its playback position keeps advancing, so that it resumes
+/// where it would have been if it had kept playing. Sounds that can't seek, such as looping
+/// sounds, resume from where they were paused.
+///
+/// Sounds paused by the user aren't virtualized.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct AudioVirtualization {
+    /// The maximum number of spatial sounds that play at once.
+    ///
+    /// Default is `usize::MAX`, which disables virtualization.
+    pub max_audible: usize,
+}
+
+impl Default for AudioVirtualization {
+    fn default() -> Self {
+        Self {
+            max_audible: usize::MAX,
+        }
+    }
+}
+
+impl AudioVirtualization {
+    /// Create a new [`AudioVirtualization`] which plays up to `max_audible` spatial sounds at once.
+    pub const fn new(max_audible: usize) -> Self {
+        Self { max_audible }
+    }
+}
+
+/// Marks a spatial sound which has been paused by [`AudioVirtualization`], because too many
+/// spatial sounds were playing.
+///
+/// Bevy inserts and removes this component when virtualizing and resuming sounds.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct VirtualizedAudio {
+    position: Duration,
+    speed: f32,
+    since: Instant,
+}
+
+impl VirtualizedAudio {
+    /// Returns the position the sound would be at if it had kept playing.
+    pub fn position(&self) -> Duration {
+        self.position + self.since.elapsed().mul_f32(self.speed)
+    }
+}
+
+/// Pauses the least important spatial sounds when more than
+/// [`AudioVirtualization::max_audible`] are playing, and resumes them when there's room.
+pub(crate) fn virtualize_spatial_audio(
+    mut commands: Commands,
+    virtualization: Res<AudioVirtualization>,
+    sinks: Query<(
+        Entity,
+        &SpatialAudioSink,
+        &PlaybackSettings,
+        &GlobalTransform,
+        Option<&VirtualizedAudio>,
+    )>,
+    ear_positions: EarPositions,
+    default_spatial_scale: Res<DefaultSpatialScale>,
+) {
+    let (left_ear, right_ear) = ear_positions.get();
+    let listener = (left_ear + right_ear) / 2.0;
+
+    let mut sounds = sinks
+        .iter()
+        .filter(|(_, sink, _, _, virtualized)| {
+            virtualized.is_some() || !(sink.is_paused() || sink.empty())
+        })
+        .map(|(entity, sink, settings, transform, virtualized)| {
+            let scale = settings.spatial_scale.unwrap_or(default_spatial_scale.0).0;
+            let distance_squared = ((transform.translation() - listener) * scale).length_squared();
+            // Spatial sinks attenuate with the inverse square of the distance.
+            let loudness = if sink.is_muted() {
+                0.0
+            } else {
+                sink.volume() * (1.0 / distance_squared).min(1.0)
+            };
+            (entity, sink, settings.priority, loudness, virtualized)
+        })
+        .collect::<Vec<_>>();
+
+    if sounds.len() <= virtualization.max_audible
+        && sounds.iter().all(|(.., virtualized)| virtualized.is_none())
+    {
+        return;
+    }
+
+    sounds.sort_by(
+        |(_, _, priority_a, loudness_a, _), (_, _, priority_b, loudness_b, _)| {
+            priority_b
+                .cmp(priority_a)
+                .then(loudness_b.total_cmp(loudness_a))
+        },
+    );
+
+    for (index, (entity, sink, _, _, virtualized)) in sounds.into_iter().enumerate() {
+        let audible = index < virtualization.max_audible;
+        match (audible, virtualized) {
+            (true, Some(virtualized)) => {
+                // If the sound can't seek, it resumes from where it was paused.
+                let _ = sink.try_seek(virtualized.position());
+                sink.play();
+                commands.entity(entity).remove::<VirtualizedAudio>();
+            }
+            (false, None) => {
+                sink.pause();
+                commands.entity(entity).insert(VirtualizedAudio {
+                    position: sink.position(),
+                    speed: sink.speed(),
+                    since: Instant::now(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
@@ -116,6 +116,7 @@ mod srgba;
 mod test_colors;
 #[cfg(test)]
 mod testing;
+mod working_space;
 mod xyza;
 
 /// The color prelude.
@@ -142,6 +143,7 @@ pub use linear_rgba::*;
 pub use oklaba::*;
 pub use oklcha::*;
 pub use srgba::*;
+pub use working_space::*;
 pub use xyza::*;
 
 /// Describes the traits that a color should implement for consistency.
@@ -0,0 +1,104 @@
+use crate::LinearRgba;
+use bevy_math::{Mat3, Vec3};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+/// The linear RGB color space in which lighting and blending computations are done.
+///
+/// Colors only need converting when they move between color spaces, at the boundaries of the
+/// renderer: when images are loaded, when material and light colors are given, and when the
+/// final image is presented. Mixing up color spaces at these boundaries is what makes colors
+/// look washed out or oversaturated.
+///
+/// [`LinearRgba`] colors are in linear Rec. 709 (the primaries of sRGB). Use
+/// [`from_linear_rec709`](Self::from_linear_rec709) to convert them into the working space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum WorkingColorSpace {
+    /// Linear Rec. 709, which uses the same primaries as sRGB.
+    #[default]
+    LinearRec709,
+    /// [ACEScg](https://en.wikipedia.org/wiki/Academy_Color_Encoding_System), which uses the
+    /// wider AP1 primaries and is common in film and VFX pipelines.
+    AcesCg,
+}
+
+/// Converts linear Rec. 709 to ACEScg, including the chromatic adaptation from D65 to D60.
+const REC709_TO_ACESCG: Mat3 = Mat3::from_cols(
+    Vec3::new(0.613_097_3, 0.070_194_22, 0.020_615_6),
+    Vec3::new(0.339_522_85, 0.916_355_6, 0.109_569_83),
+    Vec3::new(0.047_379_28, 0.013_452_59, 0.869_815_1),
+);
+
+/// Converts ACEScg to linear Rec. 709, including the chromatic adaptation from D60 to D65.
+const ACESCG_TO_REC709: Mat3 = Mat3::from_cols(
+    Vec3::new(1.705_051, -0.130_256_42, -0.024_003_36),
+    Vec3::new(-0.621_792_1, 1.140_804_7, -0.128_968_98),
+    Vec3::new(-0.083_258_87, -0.010_548_32, 1.152_972_3),
+);
+
+impl WorkingColorSpace {
+    /// Converts a color from linear Rec. 709 into this working space.
+    pub fn from_linear_rec709(self, color: LinearRgba) -> LinearRgba {
+        match self {
+            WorkingColorSpace::LinearRec709 => color,
+            WorkingColorSpace::AcesCg => transform(REC709_TO_ACESCG, color),
+        }
+    }
+
+    /// Converts a color from this working space into linear Rec. 709.
+    pub fn to_linear_rec709(self, color: LinearRgba) -> LinearRgba {
+        match self {
+            WorkingColorSpace::LinearRec709 => color,
+            WorkingColorSpace::AcesCg => transform(ACESCG_TO_REC709, color),
+        }
+    }
+
+    /// Converts a color from the `from` working space into this one.
+    pub fn convert_from(self, from: WorkingColorSpace, color: LinearRgba) -> LinearRgba {
+        if self == from {
+            color
+        } else {
+            self.from_linear_rec709(from.to_linear_rec709(color))
+        }
+    }
+}
+
+fn transform(matrix: Mat3, color: LinearRgba) -> LinearRgba {
+    let rgb = matrix * Vec3::new(color.red, color.green, color.blue);
+    LinearRgba::new(rgb.x, rgb.y, rgb.z, color.alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_approx_eq;
+
+    #[test]
+    fn acescg_round_trip() {
+        let color = LinearRgba::new(0.8, 0.2, 0.05, 0.5);
+        let acescg = WorkingColorSpace::AcesCg.from_linear_rec709(color);
+        let back = WorkingColorSpace::AcesCg.to_linear_rec709(acescg);
+        assert_approx_eq!(color.red, back.red, 1e-4);
+        assert_approx_eq!(color.green, back.green, 1e-4);
+        assert_approx_eq!(color.blue, back.blue, 1e-4);
+        assert_eq!(acescg.alpha, 0.5);
+    }
+
+    #[test]
+    fn white_is_preserved() {
+        let white = WorkingColorSpace::AcesCg.from_linear_rec709(LinearRgba::WHITE);
+        assert_approx_eq!(white.red, 1.0, 1e-4);
+        assert_approx_eq!(white.green, 1.0, 1e-4);
+        assert_approx_eq!(white.blue, 1.0, 1e-4);
+    }
+}
@@ -31,9 +31,17 @@ struct AutoExposure {
     high_percent: f32,
     speed_up: f32,
     speed_down: f32,
-    exponential_transition_distance: f32,
+    exponential_transition_distance_up: f32,
+    exponential_transition_distance_down: f32,
+    metering_mode: u32,
+    metering_radius: f32,
+    compensation: f32,
 }
 
+const METERING_MODE_AVERAGE: u32 = 0u;
+const METERING_MODE_CENTER_WEIGHTED: u32 = 1u;
+const METERING_MODE_SPOT: u32 = 2u;
+
 struct CompensationCurve {
     min_log_lum: f32,
     inv_log_lum_range: f32,
@@ -86,7 +94,23 @@ fn color_to_bin(hdr: vec3<f32>) -> u32 {
 fn metering_weight(coords: vec2<f32>) -> u32 {
     let pos = vec2<i32>(coords * vec2<f32>(textureDimensions(tex_mask)));
     let mask = textureLoad(tex_mask, pos, 0).r;
-    return u32(mask * 16.0);
+    return u32(mask * metering_mode_weight(coords) * 16.0);
+}
+
+// Returns the weight of the pixel at the given UV coordinates according to the metering mode.
+fn metering_mode_weight(coords: vec2<f32>) -> f32 {
+    let distance_from_center = distance(coords, vec2(0.5));
+    switch settings.metering_mode {
+        case METERING_MODE_CENTER_WEIGHTED: {
+            return saturate(1.0 - distance_from_center / settings.metering_radius);
+        }
+        case METERING_MODE_SPOT: {
+            return select(0.0, 1.0, distance_from_center <= settings.metering_radius);
+        }
+        default: {
+            return 1.0;
+        }
+    }
 }
 
 @compute @workgroup_size(16, 16, 1)
@@ -173,17 +197,18 @@ fn compute_average(@builtin(local_invocation_index) local_index: u32) {
     let target_exposure = textureLoad(tex_compensation, i32(saturate(u) * 255.0), 0).r
         * compensation_curve.compensation_range
         + compensation_curve.min_compensation
+        + settings.compensation
         - avg_lum;
 
     // Smoothly adjust the `exposure` towards the `target_exposure`
     let delta = target_exposure - exposure;
     if target_exposure > exposure {
         let speed_down = settings.speed_down * globals.delta_time;
-        let exp_down = speed_down / settings.exponential_transition_distance;
+        let exp_down = speed_down / settings.exponential_transition_distance_down;
         exposure = exposure + min(speed_down, delta * exp_down);
     } else {
         let speed_up = settings.speed_up * globals.delta_time;
-        let exp_up = speed_up / settings.exponential_transition_distance;
+        let exp_up = speed_up / settings.exponential_transition_distance_up;
         exposure = exposure + max(-speed_up, delta * exp_up);
     }
 
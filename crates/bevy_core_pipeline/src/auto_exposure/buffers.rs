@@ -49,6 +49,7 @@ pub(super) fn prepare_buffers(
         let (min_log_lum, max_log_lum) = settings.range.into_inner();
         let (low_percent, high_percent) = settings.filter.into_inner();
         let initial_state = 0.0f32.clamp(min_log_lum, max_log_lum);
+        let (metering_mode, metering_radius) = settings.metering_mode.to_uniform();
 
         let settings = AutoExposureUniform {
             min_log_lum,
@@ -58,7 +59,11 @@ pub(super) fn prepare_buffers(
             high_percent,
             speed_up: settings.speed_brighten,
             speed_down: settings.speed_darken,
-            exponential_transition_distance: settings.exponential_transition_distance,
+            exponential_transition_distance_up: settings.exponential_transition_distance_brighten,
+            exponential_transition_distance_down: settings.exponential_transition_distance_darken,
+            metering_mode,
+            metering_radius,
+            compensation: settings.compensation,
         };
 
         match buffers.buffers.entry(entity) {
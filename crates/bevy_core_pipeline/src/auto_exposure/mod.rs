@@ -24,7 +24,7 @@ use node::AutoExposureNode;
 use pipeline::{
     AutoExposurePass, AutoExposurePipeline, ViewAutoExposurePipeline, METERING_SHADER_HANDLE,
 };
-pub use settings::AutoExposure;
+pub use settings::{AutoExposure, MeteringMode};
 
 use crate::{
     auto_exposure::compensation_curve::GpuAutoExposureCompensationCurve,
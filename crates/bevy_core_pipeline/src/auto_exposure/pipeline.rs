@@ -35,7 +35,11 @@ pub struct AutoExposureUniform {
     pub(super) high_percent: f32,
     pub(super) speed_up: f32,
     pub(super) speed_down: f32,
-    pub(super) exponential_transition_distance: f32,
+    pub(super) exponential_transition_distance_up: f32,
+    pub(super) exponential_transition_distance_down: f32,
+    pub(super) metering_mode: u32,
+    pub(super) metering_radius: f32,
+    pub(super) compensation: f32,
 }
 
 #[derive(PartialEq, Eq, Hash, Clone)]
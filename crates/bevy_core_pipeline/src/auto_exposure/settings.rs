@@ -46,9 +46,10 @@ pub struct AutoExposure {
     pub speed_darken: f32,
 
     /// The distance in F-stops from the target exposure from where to transition from animating
-    /// in linear fashion to animating exponentially. This helps against jittering when the
-    /// target exposure keeps on changing slightly from frame to frame, while still maintaining
-    /// a relatively slow animation for big changes in scene brightness.
+    /// in linear fashion to animating exponentially, when adapting from dark to bright scenes.
+    /// This helps against jittering when the target exposure keeps on changing slightly from
+    /// frame to frame, while still maintaining a relatively slow animation for big changes in
+    /// scene brightness.
     ///
     /// ```text
     /// ev
@@ -61,8 +62,33 @@ pub struct AutoExposure {
     /// ●───────────────────────── time
     /// ```
     ///
+    /// Larger values make the adaptation ease in over a longer range, which feels more like the
+    /// slow adaptation of the human eye.
+    ///
+    /// The default value is 1.5.
+    pub exponential_transition_distance_brighten: f32,
+
+    /// The same as [`AutoExposure::exponential_transition_distance_brighten`], but used when
+    /// adapting from bright to dark scenes.
+    ///
     /// The default value is 1.5.
-    pub exponential_transition_distance: f32,
+    pub exponential_transition_distance_darken: f32,
+
+    /// How pixels are weighted based on their position on the screen when metering.
+    ///
+    /// This is applied on top of [`AutoExposure::metering_mask`].
+    /// The default value is [`MeteringMode::Average`].
+    pub metering_mode: MeteringMode,
+
+    /// Exposure compensation in F-stops, added on top of [`AutoExposure::compensation_curve`].
+    ///
+    /// Positive values make the final image brighter, negative values make it darker. Unlike the
+    /// compensation curve, this is a plain reflected field, so it can be animated by an animation
+    /// clip (e.g. with `animated_field!(AutoExposure::compensation)` in `bevy_animation`) to
+    /// script exposure changes in cutscenes.
+    ///
+    /// The default value is 0.0.
+    pub compensation: f32,
 
     /// The mask to apply when metering. The mask will cover the entire screen, where:
     /// * `(0.0, 0.0)` is the top-left corner,
@@ -95,9 +121,47 @@ impl Default for AutoExposure {
             filter: 0.10..=0.90,
             speed_brighten: 3.0,
             speed_darken: 1.0,
-            exponential_transition_distance: 1.5,
+            exponential_transition_distance_brighten: 1.5,
+            exponential_transition_distance_darken: 1.5,
+            metering_mode: MeteringMode::default(),
+            compensation: 0.0,
             metering_mask: default(),
             compensation_curve: default(),
         }
     }
 }
+
+/// Determines how pixels are weighted based on their position on the screen when metering for
+/// [`AutoExposure`].
+///
+/// Distances are measured in UV space from the center of the screen, so a radius of `0.5` reaches
+/// the middle of each edge of the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum MeteringMode {
+    /// All pixels contribute equally.
+    #[default]
+    Average,
+    /// Pixels contribute less the further they are from the center of the screen, falling off
+    /// linearly until they stop contributing at `radius`.
+    CenterWeighted {
+        /// The distance from the center at which pixels stop contributing.
+        radius: f32,
+    },
+    /// Only pixels within `radius` of the center of the screen contribute.
+    Spot {
+        /// The radius of the metered spot.
+        radius: f32,
+    },
+}
+
+impl MeteringMode {
+    /// Returns the mode index and radius used by the metering shader.
+    pub(super) fn to_uniform(self) -> (u32, f32) {
+        match self {
+            MeteringMode::Average => (0, 0.0),
+            MeteringMode::CenterWeighted { radius } => (1, radius),
+            MeteringMode::Spot { radius } => (2, radius),
+        }
+    }
+}
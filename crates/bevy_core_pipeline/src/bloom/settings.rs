@@ -226,7 +226,7 @@ impl ExtractComponent for Bloom {
             camera.physical_viewport_size(),
             camera.physical_target_size(),
             camera.is_active,
-            camera.hdr,
+            camera.is_hdr(),
         ) {
             (Some(URect { min: origin, .. }), Some(size), Some(target_size), true, true)
                 if size.x != 0 && size.y != 0 =>
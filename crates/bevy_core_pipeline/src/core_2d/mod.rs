@@ -342,9 +342,56 @@ impl CachedRenderPipelinePhaseItem for AlphaMask2d {
     }
 }
 
+/// The sort key of a [`Transparent2d`] phase item.
+///
+/// Items are drawn in ascending order of `layer`, then `order` within a layer,
+/// then `depth`. Items that don't specify a layer or order use `0` for both, so
+/// they are sorted purely by depth, as before sorting layers were introduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Transparent2dSortKey {
+    /// The sorting layer of the item. Higher layers are drawn on top of lower ones.
+    pub layer: i32,
+    /// The order of the item within its sorting layer. Higher values are drawn on top.
+    pub order: i32,
+    /// The depth of the item within its layer and order, usually the `z` translation.
+    pub depth: FloatOrd,
+}
+
+impl Transparent2dSortKey {
+    /// A sort key that places the item above everything else, used for overlays
+    /// such as gizmos.
+    pub const ON_TOP: Self = Self {
+        layer: i32::MAX,
+        order: i32::MAX,
+        depth: FloatOrd(f32::INFINITY),
+    };
+
+    /// Creates a sort key in the default layer and order, sorted only by `depth`.
+    #[inline]
+    pub const fn from_depth(depth: f32) -> Self {
+        Self {
+            layer: 0,
+            order: 0,
+            depth: FloatOrd(depth),
+        }
+    }
+
+    /// Packs `layer` and `order` into a single integer which sorts the same way.
+    #[inline]
+    fn layer_and_order(&self) -> i64 {
+        ((self.layer as i64) << 32) + (self.order as i64 - i32::MIN as i64)
+    }
+}
+
+impl Default for Transparent2dSortKey {
+    fn default() -> Self {
+        Self::from_depth(0.0)
+    }
+}
+
 /// Transparent 2D [`SortedPhaseItem`]s.
 pub struct Transparent2d {
-    pub sort_key: FloatOrd,
+    pub sort_key: Transparent2dSortKey,
     pub entity: (Entity, MainEntity),
     pub pipeline: CachedRenderPipelineId,
     pub draw_function: DrawFunctionId,
@@ -393,7 +440,7 @@ impl PhaseItem for Transparent2d {
 }
 
 impl SortedPhaseItem for Transparent2d {
-    type SortKey = FloatOrd;
+    type SortKey = Transparent2dSortKey;
 
     #[inline]
     fn sort_key(&self) -> Self::SortKey {
@@ -403,7 +450,16 @@ impl SortedPhaseItem for Transparent2d {
     #[inline]
     fn sort(items: &mut [Self]) {
         // radsort is a stable radix sort that performed better than `slice::sort_by_key` or `slice::sort_unstable_by_key`.
-        radsort::sort_by_key(items, |item| item.sort_key().0);
+        radsort::sort_by_key(items, |item| item.sort_key.depth.0);
+        // Since the sort is stable, sorting by layer and order afterwards keeps
+        // the depth ordering within each layer. Most scenes don't use layers,
+        // so skip the second pass when it can't change anything.
+        if items
+            .iter()
+            .any(|item| item.sort_key.layer != 0 || item.sort_key.order != 0)
+        {
+            radsort::sort_by_key(items, |item| item.sort_key.layer_and_order());
+        }
     }
 
     fn indexed(&self) -> bool {
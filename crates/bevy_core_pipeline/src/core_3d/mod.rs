@@ -119,9 +119,10 @@ use crate::{
     dof::DepthOfFieldNode,
     prepass::{
         node::{EarlyPrepassNode, LatePrepassNode},
-        AlphaMask3dPrepass, DeferredPrepass, DepthPrepass, MotionVectorPrepass, NormalPrepass,
-        Opaque3dPrepass, OpaqueNoLightmap3dBatchSetKey, OpaqueNoLightmap3dBinKey,
-        ViewPrepassTextures, MOTION_VECTOR_PREPASS_FORMAT, NORMAL_PREPASS_FORMAT,
+        AlphaMask3dPrepass, DeferredPrepass, DepthPrepass, EntityIdPrepass, MotionVectorPrepass,
+        NormalPrepass, Opaque3dPrepass, OpaqueNoLightmap3dBatchSetKey, OpaqueNoLightmap3dBinKey,
+        ViewPrepassTextures, ENTITY_ID_PREPASS_FORMAT, MOTION_VECTOR_PREPASS_FORMAT,
+        NORMAL_PREPASS_FORMAT,
     },
     skybox::SkyboxPlugin,
     tonemapping::TonemappingNode,
@@ -662,6 +663,7 @@ pub fn extract_camera_prepass_phase(
                 Has<NormalPrepass>,
                 Has<MotionVectorPrepass>,
                 Has<DeferredPrepass>,
+                Has<EntityIdPrepass>,
             ),
             With<Camera3d>,
         >,
@@ -680,6 +682,7 @@ pub fn extract_camera_prepass_phase(
         normal_prepass,
         motion_vector_prepass,
         deferred_prepass,
+        entity_id_prepass,
     ) in cameras_3d.iter()
     {
         if !camera.is_active {
@@ -697,7 +700,7 @@ pub fn extract_camera_prepass_phase(
         // This is the main 3D camera, so we use the first subview index (0).
         let retained_view_entity = RetainedViewEntity::new(main_entity.into(), None, 0);
 
-        if depth_prepass || normal_prepass || motion_vector_prepass {
+        if depth_prepass || normal_prepass || motion_vector_prepass || entity_id_prepass {
             opaque_3d_prepass_phases.insert_or_clear(retained_view_entity, gpu_preprocessing_mode);
             alpha_mask_3d_prepass_phases
                 .insert_or_clear(retained_view_entity, gpu_preprocessing_mode);
@@ -722,7 +725,8 @@ pub fn extract_camera_prepass_phase(
             .insert_if(DepthPrepass, || depth_prepass)
             .insert_if(NormalPrepass, || normal_prepass)
             .insert_if(MotionVectorPrepass, || motion_vector_prepass)
-            .insert_if(DeferredPrepass, || deferred_prepass);
+            .insert_if(DeferredPrepass, || deferred_prepass)
+            .insert_if(EntityIdPrepass, || entity_id_prepass);
     }
 
     opaque_3d_prepass_phases.retain(|view_entity, _| live_entities.contains(view_entity));
@@ -930,13 +934,25 @@ fn configure_occlusion_culling_view_targets(
     }
 }
 
-// Disable MSAA and warn if using deferred rendering
-pub fn check_msaa(mut deferred_views: Query<&mut Msaa, (With<Camera>, With<DeferredPrepass>)>) {
-    for mut msaa in deferred_views.iter_mut() {
+// Disable MSAA and warn if using deferred rendering or the entity ID prepass
+pub fn check_msaa(
+    mut views: Query<
+        (&mut Msaa, Has<DeferredPrepass>),
+        (
+            With<Camera>,
+            Or<(With<DeferredPrepass>, With<EntityIdPrepass>)>,
+        ),
+    >,
+) {
+    for (mut msaa, deferred) in views.iter_mut() {
         match *msaa {
             Msaa::Off => (),
             _ => {
-                warn!("MSAA is incompatible with deferred rendering and has been disabled.");
+                if deferred {
+                    warn!("MSAA is incompatible with deferred rendering and has been disabled.");
+                } else {
+                    warn!("MSAA is incompatible with the entity ID prepass and has been disabled.");
+                }
                 *msaa = Msaa::Off;
             }
         };
@@ -961,6 +977,7 @@ pub fn prepare_prepass_textures(
         Has<NormalPrepass>,
         Has<MotionVectorPrepass>,
         Has<DeferredPrepass>,
+        Has<EntityIdPrepass>,
     )>,
 ) {
     let mut depth_textures = <HashMap<_, _>>::default();
@@ -968,6 +985,7 @@ pub fn prepare_prepass_textures(
     let mut deferred_textures = <HashMap<_, _>>::default();
     let mut deferred_lighting_id_textures = <HashMap<_, _>>::default();
     let mut motion_vectors_textures = <HashMap<_, _>>::default();
+    let mut entity_id_textures = <HashMap<_, _>>::default();
     for (
         entity,
         camera,
@@ -977,6 +995,7 @@ pub fn prepare_prepass_textures(
         normal_prepass,
         motion_vector_prepass,
         deferred_prepass,
+        entity_id_prepass,
     ) in &views_3d
     {
         if !opaque_3d_prepass_phases.contains_key(&view.retained_view_entity)
@@ -1106,6 +1125,29 @@ pub fn prepare_prepass_textures(
                 .clone()
         });
 
+        let cached_entity_id_texture = entity_id_prepass.then(|| {
+            entity_id_textures
+                .entry(camera.target.clone())
+                .or_insert_with(|| {
+                    texture_cache.get(
+                        &render_device,
+                        TextureDescriptor {
+                            label: Some("prepass_entity_id_texture"),
+                            size,
+                            mip_level_count: 1,
+                            sample_count: 1,
+                            dimension: TextureDimension::D2,
+                            format: ENTITY_ID_PREPASS_FORMAT,
+                            usage: TextureUsages::RENDER_ATTACHMENT
+                                | TextureUsages::TEXTURE_BINDING
+                                | TextureUsages::COPY_SRC,
+                            view_formats: &[],
+                        },
+                    )
+                })
+                .clone()
+        });
+
         commands.entity(entity).insert(ViewPrepassTextures {
             depth: cached_depth_texture
                 .map(|t| ColorAttachment::new(t, None, Some(LinearRgba::BLACK))),
@@ -1120,6 +1162,9 @@ pub fn prepare_prepass_textures(
                 .map(|t| ColorAttachment::new(t, None, Some(LinearRgba::BLACK))),
             deferred_lighting_pass_id: cached_deferred_lighting_pass_id_texture
                 .map(|t| ColorAttachment::new(t, None, Some(LinearRgba::BLACK))),
+            // Cleared to zero, which is never a valid entity
+            entity_id: cached_entity_id_texture
+                .map(|t| ColorAttachment::new(t, None, Some(LinearRgba::NONE))),
             size,
         });
     }
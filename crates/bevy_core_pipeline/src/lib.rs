@@ -22,6 +22,7 @@ pub mod msaa_writeback;
 pub mod oit;
 pub mod post_process;
 pub mod prepass;
+pub mod screen_id_buffer;
 mod skybox;
 pub mod smaa;
 mod taa;
@@ -52,7 +53,8 @@ use crate::{
     motion_blur::MotionBlurPlugin,
     msaa_writeback::MsaaWritebackPlugin,
     post_process::PostProcessingPlugin,
-    prepass::{DeferredPrepass, DepthPrepass, MotionVectorPrepass, NormalPrepass},
+    prepass::{DeferredPrepass, DepthPrepass, EntityIdPrepass, MotionVectorPrepass, NormalPrepass},
+    screen_id_buffer::ScreenIdBufferPlugin,
     smaa::SmaaPlugin,
     tonemapping::TonemappingPlugin,
     upscaling::UpscalingPlugin,
@@ -78,7 +80,13 @@ impl Plugin for CorePipelinePlugin {
             .register_type::<NormalPrepass>()
             .register_type::<MotionVectorPrepass>()
             .register_type::<DeferredPrepass>()
-            .add_plugins((Core2dPlugin, Core3dPlugin, CopyDeferredLightingIdPlugin))
+            .register_type::<EntityIdPrepass>()
+            .add_plugins((
+                Core2dPlugin,
+                Core3dPlugin,
+                CopyDeferredLightingIdPlugin,
+                ScreenIdBufferPlugin,
+            ))
             .add_plugins((
                 BlitPlugin,
                 MsaaWritebackPlugin,
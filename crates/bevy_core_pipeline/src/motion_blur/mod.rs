@@ -107,6 +107,31 @@ impl Default for MotionBlur {
     }
 }
 
+/// Add this component to a mesh entity to scale how much it's blurred by [`MotionBlur`].
+///
+/// The scale is applied to the entity's motion vectors when they are written in the motion vector
+/// prepass, by moving its previous frame transform towards its current one. A value of `0.0`
+/// excludes the entity from motion blur entirely, which is useful for first-person arms and
+/// weapons that move with the camera, while values between `0.0` and `1.0` soften the blur of
+/// fast-spinning objects such as wheels.
+///
+/// Note that motion vectors are also used by temporal anti-aliasing, so reducing the scale of an
+/// entity that moves quickly can cause ghosting on it when TAA is enabled.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct MotionBlurScale(pub f32);
+
+impl MotionBlurScale {
+    /// Excludes the entity from motion blur.
+    pub const EXCLUDED: Self = Self(0.0);
+}
+
+impl Default for MotionBlurScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 pub const MOTION_BLUR_SHADER_HANDLE: Handle<Shader> =
     Handle::weak_from_u128(987457899187986082347921);
 
@@ -120,7 +145,7 @@ impl Plugin for MotionBlurPlugin {
             "motion_blur.wgsl",
             Shader::from_wgsl
         );
-        app.add_plugins((
+        app.register_type::<MotionBlurScale>().add_plugins((
             ExtractComponentPlugin::<MotionBlur>::default(),
             UniformComponentPlugin::<MotionBlur>::default(),
         ));
@@ -8,6 +8,7 @@
 //! [`DepthPrepass`]
 //! [`NormalPrepass`]
 //! [`MotionVectorPrepass`]
+//! [`EntityIdPrepass`]
 //!
 //! The textures are automatically added to the default mesh view bindings. You can also get the raw textures
 //! by querying the [`ViewPrepassTextures`] component on any camera with a prepass component.
@@ -51,6 +52,7 @@ use bevy_render::{
 
 pub const NORMAL_PREPASS_FORMAT: TextureFormat = TextureFormat::Rgb10a2Unorm;
 pub const MOTION_VECTOR_PREPASS_FORMAT: TextureFormat = TextureFormat::Rg16Float;
+pub const ENTITY_ID_PREPASS_FORMAT: TextureFormat = TextureFormat::Rg32Uint;
 
 /// If added to a [`crate::prelude::Camera3d`] then depth values will be copied to a separate texture available to the main pass.
 #[derive(Component, Default, Reflect, Clone)]
@@ -68,6 +70,18 @@ pub struct NormalPrepass;
 #[reflect(Component, Default)]
 pub struct MotionVectorPrepass;
 
+/// If added to a [`crate::prelude::Camera3d`] then the bits of the main world entity of each mesh will be written
+/// to a separate texture, with the low 32 bits in the red channel and the high 32 bits in the green channel.
+/// Pixels not covered by any mesh are zero, which is never a valid entity.
+///
+/// Integer textures can't be multisampled, so MSAA is disabled on cameras with this component.
+/// Materials with a custom prepass fragment shader need to write the `entity_id` output themselves.
+///
+/// See [`ScreenIdBuffer`](crate::screen_id_buffer::ScreenIdBuffer) to look up entities on the CPU.
+#[derive(Component, Default, Reflect, Clone)]
+#[reflect(Component, Default)]
+pub struct EntityIdPrepass;
+
 /// If added to a [`crate::prelude::Camera3d`] then deferred materials will be rendered to the deferred gbuffer texture and will be available to subsequent passes.
 /// Note the default deferred lighting plugin also requires `DepthPrepass` to work correctly.
 #[derive(Component, Default, Reflect)]
@@ -111,6 +125,9 @@ pub struct ViewPrepassTextures {
     /// A texture that specifies the deferred lighting pass id for a material.
     /// Exists only if [`DeferredPrepass`] is added to the `ViewTarget`
     pub deferred_lighting_pass_id: Option<ColorAttachment>,
+    /// The main world entity of each pixel.
+    /// Exists only if [`EntityIdPrepass`] is added to the `ViewTarget`
+    pub entity_id: Option<ColorAttachment>,
     /// The size of the textures.
     pub size: Extent3d,
 }
@@ -133,6 +150,10 @@ impl ViewPrepassTextures {
     pub fn deferred_view(&self) -> Option<&TextureView> {
         self.deferred.as_ref().map(|t| &t.texture.default_view)
     }
+
+    pub fn entity_id_view(&self) -> Option<&TextureView> {
+        self.entity_id.as_ref().map(|t| &t.texture.default_view)
+    }
 }
 
 /// Opaque phase of the 3D prepass.
@@ -352,6 +373,7 @@ pub fn prepass_target_descriptors(
     normal_prepass: bool,
     motion_vector_prepass: bool,
     deferred_prepass: bool,
+    entity_id_prepass: bool,
 ) -> Vec<Option<ColorTargetState>> {
     vec![
         normal_prepass.then_some(ColorTargetState {
@@ -374,5 +396,10 @@ pub fn prepass_target_descriptors(
             blend: None,
             write_mask: ColorWrites::ALL,
         }),
+        entity_id_prepass.then_some(ColorTargetState {
+            format: ENTITY_ID_PREPASS_FORMAT,
+            blend: None,
+            write_mask: ColorWrites::ALL,
+        }),
     ]
 }
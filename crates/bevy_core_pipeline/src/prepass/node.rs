@@ -142,6 +142,10 @@ fn run_prepass<'w>(
         // Use None in place of deferred attachments
         None,
         None,
+        view_prepass_textures
+            .entity_id
+            .as_ref()
+            .map(|entity_id_texture| entity_id_texture.get_attachment()),
     ];
 
     // If all color attachments are none: clear the color attachment list so that no fragment shader is required
@@ -0,0 +1,150 @@
+//! Looks up the entity under a screen position using the GPU, without CPU raycasts.
+//!
+//! Add a [`ScreenIdBuffer`] to a [`Camera3d`](crate::core_3d::Camera3d). The camera then renders
+//! the [`EntityIdPrepass`] and reads it back to the CPU each frame, so that
+//! [`ScreenIdBuffer::entity_at`] can answer which entity is visible at a given viewport position.
+//! The answer lags behind rendering by the latency of the readback, usually a frame or two, which
+//! is fine for cursor highlighting or clicking on objects.
+//!
+//! Only opaque and alpha masked meshes are written to the entity ID prepass; transparent meshes
+//! are ignored, like in the rest of the prepass.
+
+use crate::{
+    core_3d::prepare_prepass_textures,
+    prepass::{EntityIdPrepass, ViewPrepassTextures, ENTITY_ID_PREPASS_FORMAT},
+};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_math::{URect, UVec2, Vec2};
+use bevy_render::{
+    camera::Camera,
+    extract_component::{ExtractComponent, ExtractComponentPlugin},
+    gpu_readback::{ImageReadbackComplete, ReadbackFrameLatency, TextureReadback},
+    Render, RenderApp, RenderSet,
+};
+
+/// Adds support for [`ScreenIdBuffer`].
+pub struct ScreenIdBufferPlugin;
+
+impl Plugin for ScreenIdBufferPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ExtractComponentPlugin::<ScreenIdBuffer>::default())
+            .add_observer(update_screen_id_buffers);
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.add_systems(
+            Render,
+            request_screen_id_buffer_readbacks
+                .in_set(RenderSet::PrepareResources)
+                .after(prepare_prepass_textures),
+        );
+    }
+}
+
+/// Reads back the [`EntityIdPrepass`] of a camera to look up the entity under a screen position.
+///
+/// The buffer is updated whenever a readback completes, which happens a frame or two after the
+/// frame it was rendered in. Until the first readback completes, no entity is found anywhere.
+/// By default at most two readbacks are in flight at once, see [`ReadbackFrameLatency`].
+///
+/// MSAA is disabled on cameras with this component, see [`EntityIdPrepass`].
+#[derive(Component, Clone, Debug, Default)]
+#[require(EntityIdPrepass, ReadbackFrameLatency(|| ReadbackFrameLatency(2)))]
+pub struct ScreenIdBuffer {
+    size: UVec2,
+    viewport: URect,
+    scale_factor: f32,
+    data: Vec<u8>,
+}
+
+impl ScreenIdBuffer {
+    /// Returns the entity visible at the given logical position relative to the camera's
+    /// viewport, as used by [`Camera::viewport_to_world`].
+    ///
+    /// Returns `None` if no mesh is visible there, if the position is outside of the viewport or
+    /// if no readback has completed yet.
+    pub fn entity_at(&self, viewport_pos: Vec2) -> Option<Entity> {
+        if viewport_pos.x < 0.0 || viewport_pos.y < 0.0 {
+            return None;
+        }
+        let position = self.viewport.min + (viewport_pos * self.scale_factor).as_uvec2();
+        if position.x >= self.viewport.max.x || position.y >= self.viewport.max.y {
+            return None;
+        }
+        self.entity_at_physical(position)
+    }
+
+    /// Returns the entity visible at the given physical position of the camera's render target.
+    ///
+    /// Returns `None` if no mesh is visible there, if the position is outside of the render
+    /// target or if no readback has completed yet.
+    pub fn entity_at_physical(&self, position: UVec2) -> Option<Entity> {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return None;
+        }
+        let offset = (position.y as usize * self.size.x as usize + position.x as usize)
+            * ENTITY_ID_PREPASS_FORMAT.block_copy_size(None)? as usize;
+        let bits = self.data.get(offset..offset + 8)?;
+        Entity::try_from_bits(u64::from_le_bytes(bits.try_into().ok()?)).ok()
+    }
+
+    /// The physical size of the last read back buffer, or zero if no readback has completed yet.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Whether a readback has completed, and [`entity_at`](Self::entity_at) can find entities.
+    pub fn is_ready(&self) -> bool {
+        !self.data.is_empty()
+    }
+}
+
+impl ExtractComponent for ScreenIdBuffer {
+    type QueryData = ();
+    type QueryFilter = With<ScreenIdBuffer>;
+    type Out = ScreenIdBufferReadback;
+
+    fn extract_component(_: ()) -> Option<Self::Out> {
+        Some(ScreenIdBufferReadback)
+    }
+}
+
+/// Marks the render world view of a camera with a [`ScreenIdBuffer`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ScreenIdBufferReadback;
+
+fn request_screen_id_buffer_readbacks(
+    mut commands: Commands,
+    views: Query<(Entity, &ViewPrepassTextures), With<ScreenIdBufferReadback>>,
+) {
+    for (entity, prepass_textures) in &views {
+        if let Some(entity_id) = &prepass_textures.entity_id {
+            commands
+                .entity(entity)
+                .insert(TextureReadback(entity_id.texture.texture.clone()));
+        }
+    }
+}
+
+fn update_screen_id_buffers(
+    trigger: Trigger<ImageReadbackComplete>,
+    mut buffers: Query<(&mut ScreenIdBuffer, &Camera)>,
+) {
+    let Ok((mut buffer, camera)) = buffers.get_mut(trigger.target()) else {
+        return;
+    };
+    let image = &trigger.event().0;
+    if image.texture_descriptor.format != ENTITY_ID_PREPASS_FORMAT {
+        return;
+    }
+
+    buffer.size = image.size();
+    buffer.viewport = camera
+        .physical_viewport_rect()
+        .unwrap_or(URect::from_corners(UVec2::ZERO, buffer.size));
+    buffer.scale_factor = camera.target_scaling_factor().unwrap_or(1.0);
+    buffer.data.clone_from(&image.data);
+}
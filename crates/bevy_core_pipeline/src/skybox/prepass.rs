@@ -24,8 +24,8 @@ use bevy_utils::prelude::default;
 use crate::{
     core_3d::CORE_3D_DEPTH_FORMAT,
     prepass::{
-        prepass_target_descriptors, MotionVectorPrepass, NormalPrepass, PreviousViewData,
-        PreviousViewUniforms,
+        prepass_target_descriptors, EntityIdPrepass, MotionVectorPrepass, NormalPrepass,
+        PreviousViewData, PreviousViewUniforms,
     },
     Skybox,
 };
@@ -47,6 +47,7 @@ pub struct SkyboxPrepassPipeline {
 pub struct SkyboxPrepassPipelineKey {
     samples: u32,
     normal_prepass: bool,
+    entity_id_prepass: bool,
 }
 
 /// Stores the ID for a camera's specialized pipeline, so it can be retrieved from the
@@ -104,7 +105,12 @@ impl SpecializedRenderPipeline for SkyboxPrepassPipeline {
                 shader: SKYBOX_PREPASS_SHADER_HANDLE,
                 shader_defs: vec![],
                 entry_point: "fragment".into(),
-                targets: prepass_target_descriptors(key.normal_prepass, true, false),
+                targets: prepass_target_descriptors(
+                    key.normal_prepass,
+                    true,
+                    false,
+                    key.entity_id_prepass,
+                ),
             }),
             zero_initialize_workgroup_memory: false,
         }
@@ -117,12 +123,16 @@ pub fn prepare_skybox_prepass_pipelines(
     pipeline_cache: Res<PipelineCache>,
     mut pipelines: ResMut<SpecializedRenderPipelines<SkyboxPrepassPipeline>>,
     pipeline: Res<SkyboxPrepassPipeline>,
-    views: Query<(Entity, Has<NormalPrepass>, &Msaa), (With<Skybox>, With<MotionVectorPrepass>)>,
+    views: Query<
+        (Entity, Has<NormalPrepass>, Has<EntityIdPrepass>, &Msaa),
+        (With<Skybox>, With<MotionVectorPrepass>),
+    >,
 ) {
-    for (entity, normal_prepass, msaa) in &views {
+    for (entity, normal_prepass, entity_id_prepass, msaa) in &views {
         let pipeline_key = SkyboxPrepassPipelineKey {
             samples: msaa.samples(),
             normal_prepass,
+            entity_id_prepass,
         };
 
         let render_skybox_prepass_pipeline =
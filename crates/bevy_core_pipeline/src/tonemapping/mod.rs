@@ -1,11 +1,12 @@
 use crate::fullscreen_vertex_shader::fullscreen_shader_vertex_state;
 use bevy_app::prelude::*;
 use bevy_asset::{load_internal_asset, Assets, Handle};
-use bevy_ecs::prelude::*;
+use bevy_color::WorkingColorSpace;
+use bevy_ecs::{entity::EntityBorrow, prelude::*};
 use bevy_image::{CompressedImageFormats, Image, ImageSampler, ImageType};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
-    camera::Camera,
+    camera::{Camera, ExtractedCamera, NormalizedRenderTarget},
     extract_component::{ExtractComponent, ExtractComponentPlugin},
     extract_resource::{ExtractResource, ExtractResourcePlugin},
     render_asset::{RenderAssetUsages, RenderAssets},
@@ -14,8 +15,8 @@ use bevy_render::{
         *,
     },
     renderer::RenderDevice,
-    texture::{FallbackImage, GpuImage},
-    view::{ExtractedView, ViewTarget, ViewUniform},
+    texture::{FallbackImage, GpuImage, RenderWorkingColorSpace},
+    view::{ExtractedView, ExtractedWindows, ViewTarget, ViewUniform},
     Render, RenderApp, RenderSet,
 };
 use bitflags::bitflags;
@@ -214,6 +215,17 @@ pub struct TonemappingPipelineKey {
     deband_dither: DebandDither,
     tonemapping: Tonemapping,
     flags: TonemappingPipelineKeyFlags,
+    hdr_output: Option<HdrOutputKey>,
+    working_color_space: WorkingColorSpace,
+}
+
+/// The brightness, in nits, of the HDR output of the view's window.
+///
+/// See [`Window::hdr_output`](bevy_window::Window::hdr_output).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct HdrOutputKey {
+    paper_white: u32,
+    peak_brightness: u32,
 }
 
 impl SpecializedRenderPipeline for TonemappingPipeline {
@@ -231,7 +243,22 @@ impl SpecializedRenderPipeline for TonemappingPipeline {
             4,
         ));
 
-        if let DebandDither::Enabled = key.deband_dither {
+        if key.working_color_space == WorkingColorSpace::AcesCg {
+            shader_defs.push("WORKING_COLOR_SPACE_ACESCG".into());
+        }
+
+        // Dithering is only needed to hide banding in 8-bit SDR outputs.
+        if let Some(hdr_output) = key.hdr_output {
+            shader_defs.push("HDR_OUTPUT".into());
+            shader_defs.push(ShaderDefVal::UInt(
+                "HDR_OUTPUT_PAPER_WHITE".into(),
+                hdr_output.paper_white,
+            ));
+            shader_defs.push(ShaderDefVal::UInt(
+                "HDR_OUTPUT_PEAK_BRIGHTNESS".into(),
+                hdr_output.peak_brightness,
+            ));
+        } else if let DebandDither::Enabled = key.deband_dither {
             shader_defs.push("DEBAND_DITHER".into());
         }
 
@@ -357,11 +384,18 @@ pub fn prepare_view_tonemapping_pipelines(
             &ExtractedView,
             Option<&Tonemapping>,
             Option<&DebandDither>,
+            Option<&ExtractedCamera>,
         ),
         With<ViewTarget>,
     >,
+    windows: Res<ExtractedWindows>,
+    working_color_space: Option<Res<RenderWorkingColorSpace>>,
 ) {
-    for (entity, view, tonemapping, dither) in view_targets.iter() {
+    let working_color_space = working_color_space
+        .map(|working_color_space| working_color_space.0)
+        .unwrap_or_default();
+
+    for (entity, view, tonemapping, dither, camera) in view_targets.iter() {
         // As an optimization, we omit parts of the shader that are unneeded.
         let mut flags = TonemappingPipelineKeyFlags::empty();
         flags.set(
@@ -379,10 +413,26 @@ pub fn prepare_view_tonemapping_pipelines(
                 .any(|section| *section != default()),
         );
 
+        let hdr_output = match camera.and_then(|camera| camera.target.as_ref()) {
+            Some(NormalizedRenderTarget::Window(window)) => windows
+                .get(&window.entity())
+                .and_then(|window| window.active_hdr_output()),
+            _ => None,
+        }
+        .map(|hdr_output| {
+            let paper_white = hdr_output.paper_white.max(1.0);
+            HdrOutputKey {
+                paper_white: paper_white as u32,
+                peak_brightness: hdr_output.peak_brightness.max(paper_white) as u32,
+            }
+        });
+
         let key = TonemappingPipelineKey {
             deband_dither: *dither.unwrap_or(&DebandDither::Disabled),
             tonemapping: *tonemapping.unwrap_or(&Tonemapping::None),
             flags,
+            hdr_output,
+            working_color_space,
         };
         let pipeline = pipelines.specialize(&pipeline_cache, &upscaling_pipeline, key);
 
@@ -482,5 +532,6 @@ pub fn lut_placeholder() -> Image {
         sampler: ImageSampler::Default,
         texture_view_descriptor: None,
         asset_usage: RenderAssetUsages::RENDER_WORLD,
+        generate_mips: false,
     }
 }
@@ -18,9 +18,31 @@
 
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
-    let hdr_color = textureSample(hdr_texture, hdr_sampler, in.uv);
+    var hdr_color = textureSample(hdr_texture, hdr_sampler, in.uv);
 
+#ifdef WORKING_COLOR_SPACE_ACESCG
+    // Tonemapping and the output surface expect Rec. 709 primaries, so convert back from ACEScg,
+    // including the chromatic adaptation from D60 to D65.
+    let acescg_to_rec709 = mat3x3<f32>(
+        vec3(1.705051, -0.13025642, -0.02400336),
+        vec3(-0.6217921, 1.1408047, -0.12896898),
+        vec3(-0.08325887, -0.01054832, 1.1529723),
+    );
+    hdr_color = vec4(acescg_to_rec709 * hdr_color.rgb, hdr_color.a);
+#endif
+
+#ifdef HDR_OUTPUT
+    // Tonemap relative to the display's peak brightness instead of SDR white, so that highlights
+    // are compressed towards the peak rather than clipped at paper white.
+    let paper_white = f32(#{HDR_OUTPUT_PAPER_WHITE}u);
+    let peak_brightness = f32(#{HDR_OUTPUT_PEAK_BRIGHTNESS}u);
+    let scaled_color = vec4(hdr_color.rgb * (paper_white / peak_brightness), hdr_color.a);
+    var output_rgb = tone_mapping(scaled_color, view.color_grading).rgb;
+    // The output is scRGB, where 1.0 is 80 nits.
+    output_rgb = output_rgb * (peak_brightness / 80.0);
+#else
     var output_rgb = tone_mapping(hdr_color, view.color_grading).rgb;
+#endif
 
 #ifdef DEBAND_DITHER
     output_rgb = powsafe(output_rgb.rgb, 1.0 / 2.2);
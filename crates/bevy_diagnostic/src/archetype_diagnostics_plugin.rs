@@ -0,0 +1,200 @@
+use alloc::{vec, vec::Vec};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{archetype::Archetypes, prelude::*};
+use log::warn;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds "archetype statistics" diagnostics to an App.
+///
+/// Every distinct set of components held by an entity creates a new archetype, and possibly a
+/// new table, which are never freed. Patterns such as inserting and removing many different
+/// marker components can create a large number of archetypes holding few entities each, which
+/// slows down queries and system initialization. These diagnostics help catch such patterns early:
+///
+/// - [`ARCHETYPE_COUNT`](Self::ARCHETYPE_COUNT): the number of archetypes.
+/// - [`EMPTY_ARCHETYPE_COUNT`](Self::EMPTY_ARCHETYPE_COUNT): the number of archetypes without
+///   any entities.
+/// - [`ENTITIES_PER_ARCHETYPE_MEAN`](Self::ENTITIES_PER_ARCHETYPE_MEAN),
+///   [`ENTITIES_PER_ARCHETYPE_MEDIAN`](Self::ENTITIES_PER_ARCHETYPE_MEDIAN) and
+///   [`ENTITIES_PER_ARCHETYPE_MAX`](Self::ENTITIES_PER_ARCHETYPE_MAX): the distribution of
+///   entities among non-empty archetypes.
+/// - [`TABLE_COUNT`](Self::TABLE_COUNT): the number of tables.
+/// - [`TABLE_FRAGMENTATION`](Self::TABLE_FRAGMENTATION): the number of non-empty tables divided
+///   by the number of entities stored in tables, from `0.0` when all entities share one table to
+///   `1.0` when every entity has a table of its own.
+///
+/// A warning is logged when one of the limits of the [`ArchetypeBudget`] is exceeded.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct ArchetypeDiagnosticsPlugin {
+    /// The budget which, when exceeded, logs a warning.
+    pub budget: ArchetypeBudget,
+}
+
+/// Limits on the number of archetypes and tables of the world, used by
+/// [`ArchetypeDiagnosticsPlugin`] to warn about archetype explosions.
+///
+/// A warning is logged once when a limit is exceeded, and again if it's exceeded after having
+/// gone back under the limit. This resource can be modified at runtime.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct ArchetypeBudget {
+    /// The maximum number of archetypes, or `None` for no limit.
+    pub max_archetypes: Option<usize>,
+    /// The maximum number of tables, or `None` for no limit.
+    pub max_tables: Option<usize>,
+}
+
+impl Plugin for ArchetypeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.budget.clone())
+            .register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::ENTITIES_PER_ARCHETYPE_MEAN))
+            .register_diagnostic(Diagnostic::new(Self::ENTITIES_PER_ARCHETYPE_MEDIAN))
+            .register_diagnostic(Diagnostic::new(Self::ENTITIES_PER_ARCHETYPE_MAX))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_FRAGMENTATION))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl ArchetypeDiagnosticsPlugin {
+    pub const ARCHETYPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("archetypes/count");
+    pub const EMPTY_ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("archetypes/empty_count");
+    pub const ENTITIES_PER_ARCHETYPE_MEAN: DiagnosticPath =
+        DiagnosticPath::const_new("archetypes/entities_per_archetype/mean");
+    pub const ENTITIES_PER_ARCHETYPE_MEDIAN: DiagnosticPath =
+        DiagnosticPath::const_new("archetypes/entities_per_archetype/median");
+    pub const ENTITIES_PER_ARCHETYPE_MAX: DiagnosticPath =
+        DiagnosticPath::const_new("archetypes/entities_per_archetype/max");
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("tables/count");
+    pub const TABLE_FRAGMENTATION: DiagnosticPath =
+        DiagnosticPath::const_new("tables/fragmentation");
+
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        archetypes: &Archetypes,
+        budget: Res<ArchetypeBudget>,
+        mut over_budget: Local<(bool, bool)>,
+    ) {
+        let mut archetype_sizes = archetypes
+            .iter()
+            .map(|archetype| archetype.len())
+            .collect::<Vec<_>>();
+        let archetype_count = archetype_sizes.len();
+        let empty_archetype_count = archetype_sizes.iter().filter(|&&len| len == 0).count();
+
+        let table_count = archetypes
+            .iter()
+            .map(|archetype| archetype.table_id().as_usize() + 1)
+            .max()
+            .unwrap_or(0);
+        let mut table_sizes = vec![0; table_count];
+        for archetype in archetypes.iter() {
+            table_sizes[archetype.table_id().as_usize()] += archetype.len();
+        }
+
+        diagnostics.add_measurement(&Self::ARCHETYPE_COUNT, || archetype_count as f64);
+        diagnostics.add_measurement(&Self::EMPTY_ARCHETYPE_COUNT, || {
+            empty_archetype_count as f64
+        });
+        diagnostics.add_measurement(&Self::TABLE_COUNT, || table_count as f64);
+        diagnostics.add_measurement(&Self::TABLE_FRAGMENTATION, || {
+            let entities = table_sizes.iter().sum::<usize>();
+            let non_empty = table_sizes.iter().filter(|&&len| len > 0).count();
+            if entities == 0 {
+                0.0
+            } else {
+                non_empty as f64 / entities as f64
+            }
+        });
+
+        archetype_sizes.retain(|&len| len > 0);
+        archetype_sizes.sort_unstable();
+        if !archetype_sizes.is_empty() {
+            diagnostics.add_measurement(&Self::ENTITIES_PER_ARCHETYPE_MEAN, || {
+                archetype_sizes.iter().sum::<usize>() as f64 / archetype_sizes.len() as f64
+            });
+            diagnostics.add_measurement(&Self::ENTITIES_PER_ARCHETYPE_MEDIAN, || {
+                let middle = archetype_sizes.len() / 2;
+                if archetype_sizes.len() % 2 == 0 {
+                    (archetype_sizes[middle - 1] + archetype_sizes[middle]) as f64 / 2.0
+                } else {
+                    archetype_sizes[middle] as f64
+                }
+            });
+            diagnostics.add_measurement(&Self::ENTITIES_PER_ARCHETYPE_MAX, || {
+                archetype_sizes[archetype_sizes.len() - 1] as f64
+            });
+        }
+
+        let (archetypes_over_budget, tables_over_budget) = &mut *over_budget;
+        check_budget(
+            "archetypes",
+            archetype_count,
+            budget.max_archetypes,
+            archetypes_over_budget,
+        );
+        check_budget("tables", table_count, budget.max_tables, tables_over_budget);
+    }
+}
+
+fn check_budget(name: &str, count: usize, max: Option<usize>, over_budget: &mut bool) {
+    let exceeded = max.is_some_and(|max| count > max);
+    if exceeded && !*over_budget {
+        warn!(
+            "The world has {count} {name}, exceeding the budget of {}. This is often caused by \
+            inserting and removing many different components on entities.",
+            max.unwrap_or_default()
+        );
+    }
+    *over_budget = exceeded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticsPlugin, DiagnosticsStore};
+    use bevy_ecs::component::Component;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn measures_archetypes() {
+        let mut app = App::new();
+        app.add_plugins((DiagnosticsPlugin, ArchetypeDiagnosticsPlugin::default()));
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let initial_archetypes = store
+            .get(&ArchetypeDiagnosticsPlugin::ARCHETYPE_COUNT)
+            .unwrap()
+            .value()
+            .unwrap();
+
+        for _ in 0..3 {
+            app.world_mut().spawn(A);
+        }
+        app.world_mut().spawn((A, B)).remove::<B>();
+        app.update();
+
+        let archetype_count = ArchetypeDiagnosticsPlugin::ARCHETYPE_COUNT;
+        let empty_archetype_count = ArchetypeDiagnosticsPlugin::EMPTY_ARCHETYPE_COUNT;
+        let entities_per_archetype_max = ArchetypeDiagnosticsPlugin::ENTITIES_PER_ARCHETYPE_MAX;
+        let store = app.world().resource::<DiagnosticsStore>();
+        let value = |path| store.get(path).unwrap().value().unwrap();
+        assert_eq!(value(&archetype_count), initial_archetypes + 2.0);
+        assert!(value(&empty_archetype_count) >= 1.0);
+        assert!(value(&entities_per_archetype_max) >= 4.0);
+    }
+}
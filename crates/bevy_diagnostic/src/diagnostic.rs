@@ -318,6 +318,19 @@ impl DiagnosticsStore {
         self.diagnostics.get_mut(path)
     }
 
+    /// Get the [`Diagnostic`] with the given path, adding the one returned by `f` if it doesn't
+    /// exist yet.
+    ///
+    /// This is useful for diagnostics whose paths are only known at runtime, such as one per
+    /// component type.
+    pub fn get_or_add(
+        &mut self,
+        path: &DiagnosticPath,
+        f: impl FnOnce() -> Diagnostic,
+    ) -> &mut Diagnostic {
+        self.diagnostics.entry(path.clone()).or_insert_with(f)
+    }
+
     /// Get the latest [`DiagnosticMeasurement`] from an enabled [`Diagnostic`].
     pub fn get_measurement(&self, path: &DiagnosticPath) -> Option<&DiagnosticMeasurement> {
         self.diagnostics
@@ -0,0 +1,124 @@
+use alloc::format;
+use core::mem::size_of;
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    component::{ComponentTicks, StorageType},
+    world::{Mut, World},
+};
+use bevy_platform_support::time::Instant;
+
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+
+/// Adds "memory usage" diagnostics for the ECS storage to an App.
+///
+/// This measures the number of bytes used to store components, in total under
+/// [`MemoryDiagnosticsPlugin::ECS_MEMORY`] and per component type under
+/// `memory/ecs/<component name>`. The change detection ticks of each component are included,
+/// while unused capacity of the storage isn't.
+///
+/// Other crates add memory diagnostics under `memory/` too, such as the
+/// `AssetMemoryDiagnosticsPlugin` of `bevy_asset` and the `RenderMemoryDiagnosticsPlugin` of
+/// `bevy_render`.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct MemoryDiagnosticsPlugin;
+
+impl Plugin for MemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ECS_MEMORY).with_suffix("B"))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl MemoryDiagnosticsPlugin {
+    pub const ECS_MEMORY: DiagnosticPath = DiagnosticPath::const_new("memory/ecs");
+
+    pub fn diagnostic_system(world: &mut World) {
+        world.resource_scope(|world, mut store: Mut<DiagnosticsStore>| {
+            let time = Instant::now();
+            let storages = world.storages();
+            let mut total = 0;
+
+            for info in world.components().iter() {
+                let count = match info.storage_type() {
+                    StorageType::Table => storages
+                        .tables
+                        .iter()
+                        .filter(|table| table.has_column(info.id()))
+                        .map(|table| table.entity_count())
+                        .sum(),
+                    StorageType::SparseSet => storages
+                        .sparse_sets
+                        .get(info.id())
+                        .map_or(0, |sparse_set| sparse_set.len()),
+                };
+                if count == 0 {
+                    continue;
+                }
+
+                let bytes = count * (info.layout().size() + size_of::<ComponentTicks>());
+                total += bytes;
+
+                let path = DiagnosticPath::new(format!("memory/ecs/{}", info.name()));
+                let diagnostic =
+                    store.get_or_add(&path, || Diagnostic::new(path.clone()).with_suffix("B"));
+                if diagnostic.is_enabled {
+                    diagnostic.add_measurement(DiagnosticMeasurement {
+                        time,
+                        value: bytes as f64,
+                    });
+                }
+            }
+
+            if let Some(diagnostic) = store
+                .get_mut(&Self::ECS_MEMORY)
+                .filter(|diagnostic| diagnostic.is_enabled)
+            {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: total as f64,
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::component::Component;
+
+    use super::*;
+    use crate::DiagnosticsPlugin;
+
+    #[derive(Component)]
+    struct Position(#[expect(dead_code, reason = "Only used for its size")] [f32; 3]);
+
+    #[test]
+    fn measures_component_memory() {
+        let mut app = App::new();
+        app.add_plugins((DiagnosticsPlugin, MemoryDiagnosticsPlugin));
+        for _ in 0..10 {
+            app.world_mut().spawn(Position([0.0; 3]));
+        }
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let path =
+            DiagnosticPath::new(format!("memory/ecs/{}", core::any::type_name::<Position>()));
+        let expected = 10 * (size_of::<Position>() + size_of::<ComponentTicks>());
+        assert_eq!(store.get(&path).unwrap().value(), Some(expected as f64));
+        assert!(
+            store
+                .get(&MemoryDiagnosticsPlugin::ECS_MEMORY)
+                .unwrap()
+                .value()
+                >= Some(expected as f64)
+        );
+    }
+}
@@ -71,71 +71,57 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
-    let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
-    let mut on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
-    let mut on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
-    let on_remove: Option<TokenStream2> =
-        hook_register_function_call(quote! {on_remove}, attrs.on_remove);
-    let mut on_despawn = hook_register_function_call(quote! {on_despawn}, attrs.on_despawn);
+    // Hooks defined by relationships and indexes are registered before the custom hooks of the
+    // component, so that custom hooks observe their effects.
+    let mut on_insert = Vec::new();
+    let mut on_replace = Vec::new();
+    let mut on_despawn = Vec::new();
 
     if relationship.is_some() {
-        if on_insert.is_some() {
-            return syn::Error::new(
-                ast.span(),
-                "Custom on_insert hooks are not supported as relationships already define an on_insert hook",
-            )
-            .into_compile_error()
-            .into();
-        }
-
-        on_insert = Some(
+        on_insert.push(
             quote!(hooks.on_insert(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert);),
         );
-
-        if on_replace.is_some() {
-            return syn::Error::new(
-                ast.span(),
-                "Custom on_replace hooks are not supported as Relationships already define an on_replace hook",
-            )
-            .into_compile_error()
-            .into();
-        }
-
-        on_replace = Some(
+        on_replace.push(
             quote!(hooks.on_replace(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace);),
         );
     }
 
-    if let Some(relationship_target) = &attrs.relationship_target {
-        if on_replace.is_some() {
-            return syn::Error::new(
-                ast.span(),
-                "Custom on_replace hooks are not supported as RelationshipTarget already defines an on_replace hook",
-            )
-            .into_compile_error()
-            .into();
-        }
+    if attrs.index {
+        on_insert.push(
+            quote!(hooks.on_insert(<Self as #bevy_ecs_path::index::IndexedComponent>::on_insert);),
+        );
+        on_replace.push(
+            quote!(hooks.on_replace(<Self as #bevy_ecs_path::index::IndexedComponent>::on_replace);),
+        );
+    }
 
-        on_replace = Some(
+    if let Some(relationship_target) = &attrs.relationship_target {
+        on_replace.push(
             quote!(hooks.on_replace(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace);),
         );
 
         if relationship_target.despawn_descendants {
-            if on_despawn.is_some() {
-                return syn::Error::new(
-                    ast.span(),
-                    "Custom on_despawn hooks are not supported as this RelationshipTarget already defines an on_despawn hook, via the despawn_descendants attribute",
-                )
-                .into_compile_error()
-                .into();
-            }
-
-            on_despawn = Some(
+            on_despawn.push(
                 quote!(hooks.on_despawn(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_despawn);),
             );
         }
     }
 
+    let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
+    on_insert.extend(hook_register_function_call(
+        quote! {on_insert},
+        attrs.on_insert,
+    ));
+    on_replace.extend(hook_register_function_call(
+        quote! {on_replace},
+        attrs.on_replace,
+    ));
+    let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
+    on_despawn.extend(hook_register_function_call(
+        quote! {on_despawn},
+        attrs.on_despawn,
+    ));
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -197,10 +183,16 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let mutable_type = (attrs.immutable || relationship.is_some())
+    let mutable_type = (attrs.immutable || attrs.index || relationship.is_some())
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
+    let indexed_component = attrs.index.then(|| {
+        quote! {
+            impl #impl_generics #bevy_ecs_path::index::IndexedComponent for #struct_name #type_generics #where_clause {}
+        }
+    });
+
     let clone_handler = if relationship_target.is_some() {
         quote!(#bevy_ecs_path::component::ComponentCloneHandler::ignore())
     } else {
@@ -235,10 +227,10 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             #[allow(unused_variables)]
             fn register_component_hooks(hooks: &mut #bevy_ecs_path::component::ComponentHooks) {
                 #on_add
-                #on_insert
-                #on_replace
+                #(#on_insert)*
+                #(#on_replace)*
                 #on_remove
-                #on_despawn
+                #(#on_despawn)*
             }
 
             fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
@@ -246,6 +238,8 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             }
         }
 
+        #indexed_component
+
         #relationship
 
         #relationship_target
@@ -293,6 +287,7 @@ pub const ON_REMOVE: &str = "on_remove";
 pub const ON_DESPAWN: &str = "on_despawn";
 
 pub const IMMUTABLE: &str = "immutable";
+pub const INDEX: &str = "index";
 
 struct Attrs {
     storage: StorageTy,
@@ -305,6 +300,7 @@ struct Attrs {
     relationship: Option<Relationship>,
     relationship_target: Option<RelationshipTarget>,
     immutable: bool,
+    index: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -348,6 +344,7 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         relationship: None,
         relationship_target: None,
         immutable: false,
+        index: false,
     };
 
     let mut require_paths = HashSet::new();
@@ -383,6 +380,9 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                 } else if nested.path.is_ident(IMMUTABLE) {
                     attrs.immutable = true;
                     Ok(())
+                } else if nested.path.is_ident(INDEX) {
+                    attrs.index = true;
+                    Ok(())
                 } else {
                     Err(nested.error("Unsupported attribute"))
                 }
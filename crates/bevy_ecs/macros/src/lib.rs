@@ -561,6 +561,21 @@ pub fn derive_schedule_label(input: TokenStream) -> TokenStream {
     derive_label(input, "ScheduleLabel", &trait_path, &dyn_eq_path)
 }
 
+/// Derive macro generating an impl of the trait `HookLabel`.
+///
+/// This does not work for unions.
+#[proc_macro_derive(HookLabel)]
+pub fn derive_hook_label(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let mut trait_path = bevy_ecs_path();
+    let mut dyn_eq_path = trait_path.clone();
+    trait_path.segments.push(format_ident!("component").into());
+    trait_path.segments.push(format_ident!("HookLabel").into());
+    dyn_eq_path.segments.push(format_ident!("label").into());
+    dyn_eq_path.segments.push(format_ident!("DynEq").into());
+    derive_label(input, "HookLabel", &trait_path, &dyn_eq_path)
+}
+
 /// Derive macro generating an impl of the trait `SystemSet`.
 ///
 /// This does not work for unions.
@@ -21,17 +21,25 @@
 
 use crate::{
     bundle::BundleId,
-    component::{ComponentId, Components, RequiredComponentConstructor, StorageType},
+    component::{ComponentId, Components, RequiredComponentConstructor, StorageType, Tick},
     entity::{Entity, EntityLocation},
     observer::Observers,
+    result::Error,
     storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSetIndex, TableId, TableRow},
+    world::{error::WorldCapacityError, World},
 };
-use alloc::{boxed::Box, vec::Vec};
-use bevy_platform_support::collections::HashMap;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform_support::collections::{HashMap, HashSet};
 use core::{
     hash::Hash,
     ops::{Index, IndexMut, RangeFrom},
 };
+use thiserror::Error;
 
 /// An opaque location within a [`Archetype`].
 ///
@@ -376,6 +384,7 @@ pub struct Archetype {
     entities: Vec<ArchetypeEntity>,
     components: ImmutableSparseSet<ComponentId, ArchetypeComponentInfo>,
     pub(crate) flags: ArchetypeFlags,
+    changed_tick: Tick,
 }
 
 impl Archetype {
@@ -438,6 +447,7 @@ impl Archetype {
             components: archetype_components.into_immutable(),
             edges: Default::default(),
             flags,
+            changed_tick: Tick::new(0),
         }
     }
 
@@ -467,6 +477,18 @@ impl Archetype {
         &self.entities
     }
 
+    /// Returns the change tick of the last time an entity was added to or removed from this archetype.
+    #[inline]
+    pub fn changed_tick(&self) -> Tick {
+        self.changed_tick
+    }
+
+    /// Returns `true` if an entity was added to or removed from this archetype after the system last ran.
+    #[inline]
+    pub fn is_changed(&self, last_run: Tick, this_run: Tick) -> bool {
+        self.changed_tick.is_newer_than(last_run, this_run)
+    }
+
     /// Gets an iterator of all of the components stored in [`Table`]s.
     ///
     /// All of the IDs are unique.
@@ -558,7 +580,7 @@ impl Archetype {
         self.entities[row.index()].table_row = table_row;
     }
 
-    /// Allocates an entity to the archetype.
+    /// Allocates an entity to the archetype, marking it as changed at `change_tick`.
     ///
     /// # Safety
     /// valid component values must be immediately written to the relevant storages
@@ -568,9 +590,11 @@ impl Archetype {
         &mut self,
         entity: Entity,
         table_row: TableRow,
+        change_tick: Tick,
     ) -> EntityLocation {
         let archetype_row = ArchetypeRow::new(self.entities.len());
         self.entities.push(ArchetypeEntity { entity, table_row });
+        self.changed_tick = change_tick;
 
         EntityLocation {
             archetype_id: self.id,
@@ -585,15 +609,20 @@ impl Archetype {
         self.entities.reserve(additional);
     }
 
-    /// Removes the entity at `row` by swapping it out. Returns the table row the entity is stored
-    /// in.
+    /// Removes the entity at `row` by swapping it out, marking the archetype as changed at
+    /// `change_tick`. Returns the table row the entity is stored in.
     ///
     /// # Panics
     /// This function will panic if `row >= self.entities.len()`
     #[inline]
-    pub(crate) fn swap_remove(&mut self, row: ArchetypeRow) -> ArchetypeSwapRemoveResult {
+    pub(crate) fn swap_remove(
+        &mut self,
+        row: ArchetypeRow,
+        change_tick: Tick,
+    ) -> ArchetypeSwapRemoveResult {
         let is_last = row.index() == self.entities.len() - 1;
         let entity = self.entities.swap_remove(row.index());
+        self.changed_tick = change_tick;
         ArchetypeSwapRemoveResult {
             swapped_entity: if is_last {
                 None
@@ -646,8 +675,11 @@ impl Archetype {
     }
 
     /// Clears all entities from the archetype.
-    pub(crate) fn clear_entities(&mut self) {
-        self.entities.clear();
+    pub(crate) fn clear_entities(&mut self, change_tick: Tick) {
+        if !self.entities.is_empty() {
+            self.entities.clear();
+            self.changed_tick = change_tick;
+        }
     }
 
     /// Returns true if any of the components in this archetype have `on_add` hooks
@@ -799,6 +831,98 @@ pub struct Archetypes {
     by_components: HashMap<ArchetypeComponents, ArchetypeId>,
     /// find all the archetypes that contain a component
     pub(crate) by_component: ComponentIndex,
+    /// rules that every new archetype is checked against, with the error handler for violations
+    invariants: Vec<(ArchetypeInvariant, fn(&mut World, Error))>,
+    /// violations of the invariants that haven't been passed to their error handler yet
+    pub(crate) invariant_errors: Vec<(ArchetypeInvariantError, fn(&mut World, Error))>,
+    /// the invariants that have already been reported as violated for each archetype
+    reported_invariants: HashSet<(ArchetypeInvariant, ArchetypeId)>,
+    /// the maximum number of archetypes, if any
+    max_len: Option<usize>,
+    /// the number of entities every new archetype is preallocated to hold
+    row_capacity: usize,
+}
+
+/// A rule for which components an entity can have together, see [`World::register_archetype_invariant`].
+///
+/// Invariants are checked whenever a new [`Archetype`] is created, so a violation is only reported
+/// the first time an entity ends up with a particular combination of components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArchetypeInvariant {
+    /// Entities with `component` must also have `required`.
+    ///
+    /// Unlike [required components](crate::component::Component#required-components), `required`
+    /// isn't inserted automatically, and removing it while `component` is still present is also a violation.
+    Requires {
+        /// The component that requires the other one.
+        component: ComponentId,
+        /// The component that must be present together with `component`.
+        required: ComponentId,
+    },
+    /// Entities can't have both `component` and `conflicting`.
+    ConflictsWith {
+        /// The component that conflicts with the other one.
+        component: ComponentId,
+        /// The component that can't be present together with `component`.
+        conflicting: ComponentId,
+    },
+}
+
+impl ArchetypeInvariant {
+    /// Checks if the components of `archetype` follow this invariant.
+    pub fn check(
+        &self,
+        archetype: &Archetype,
+        components: &Components,
+    ) -> Result<(), ArchetypeInvariantError> {
+        let name = |id: ComponentId| {
+            components
+                .get_name(id)
+                .map_or_else(|| format!("{id:?}"), ToString::to_string)
+        };
+        match *self {
+            Self::Requires {
+                component,
+                required,
+            } if archetype.contains(component) && !archetype.contains(required) => {
+                Err(ArchetypeInvariantError::MissingRequired {
+                    component: name(component),
+                    required: name(required),
+                })
+            }
+            Self::ConflictsWith {
+                component,
+                conflicting,
+            } if archetype.contains(component) && archetype.contains(conflicting) => {
+                Err(ArchetypeInvariantError::Conflicting {
+                    component: name(component),
+                    conflicting: name(conflicting),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An error that occurs when an entity has a combination of components that violates an [`ArchetypeInvariant`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ArchetypeInvariantError {
+    /// An entity has a component without another component that it requires.
+    #[error("An entity has the component {component} without {required}, which it requires")]
+    MissingRequired {
+        /// The name of the component that requires the missing one.
+        component: String,
+        /// The name of the missing component.
+        required: String,
+    },
+    /// An entity has two components that conflict with each other.
+    #[error("An entity has the component {component} together with {conflicting}, which it conflicts with")]
+    Conflicting {
+        /// The name of the component that conflicts with the other one.
+        component: String,
+        /// The name of the conflicting component.
+        conflicting: String,
+    },
 }
 
 /// Metadata about how a component is stored in an [`Archetype`].
@@ -819,6 +943,11 @@ impl Archetypes {
             by_components: Default::default(),
             by_component: Default::default(),
             archetype_component_count: 0,
+            invariants: Vec::new(),
+            invariant_errors: Vec::new(),
+            reported_invariants: HashSet::default(),
+            max_len: None,
+            row_capacity: 0,
         };
         // SAFETY: Empty archetype has no components
         unsafe {
@@ -833,6 +962,58 @@ impl Archetypes {
         archetypes
     }
 
+    /// Caps the number of archetypes, and preallocates the storage for that many archetypes,
+    /// each holding `row_capacity` entities.
+    ///
+    /// Only the list of archetypes is preallocated, so archetypes created later still
+    /// allocate their entity list when they're created.
+    pub(crate) fn set_limits(&mut self, max_len: Option<usize>, row_capacity: usize) {
+        self.max_len = max_len;
+        self.row_capacity = row_capacity;
+        if let Some(max_len) = max_len {
+            let additional = max_len.saturating_sub(self.archetypes.len());
+            self.archetypes.reserve(additional);
+            self.by_components.reserve(additional);
+        }
+        for archetype in &mut self.archetypes {
+            archetype
+                .entities
+                .reserve(row_capacity.saturating_sub(archetype.entities.len()));
+        }
+    }
+
+    /// Returns the maximum number of archetypes, if it's limited.
+    #[inline]
+    pub fn limit(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Checks that an archetype with the given components either exists already, or can be
+    /// created without exceeding the [limit](Self::limit).
+    ///
+    /// Both lists of components must be sorted.
+    pub(crate) fn check_limit(
+        &self,
+        table_components: &[ComponentId],
+        sparse_set_components: &[ComponentId],
+    ) -> Result<(), WorldCapacityError> {
+        let Some(max_len) = self
+            .max_len
+            .filter(|&max_len| self.archetypes.len() >= max_len)
+        else {
+            return Ok(());
+        };
+        let archetype_identity = ArchetypeComponents {
+            table_components: table_components.into(),
+            sparse_set_components: sparse_set_components.into(),
+        };
+        if self.by_components.contains_key(&archetype_identity) {
+            Ok(())
+        } else {
+            Err(WorldCapacityError::Archetypes(max_len))
+        }
+    }
+
     /// Returns the "generation", a handle to the current highest archetype ID.
     ///
     /// This can be used with the `Index` [`Archetypes`] implementation to
@@ -926,6 +1107,9 @@ impl Archetypes {
     /// # Safety
     /// [`TableId`] must exist in tables
     /// `table_components` and `sparse_set_components` must exist in `components`
+    ///
+    /// # Panics
+    /// Panics if a new archetype is needed but the [limit](Self::limit) has been reached.
     pub(crate) unsafe fn get_id_or_insert(
         &mut self,
         components: &Components,
@@ -939,9 +1123,12 @@ impl Archetypes {
             table_components: table_components.into_boxed_slice(),
         };
 
+        let archetypes_len = self.archetypes.len();
         let archetypes = &mut self.archetypes;
         let archetype_component_count = &mut self.archetype_component_count;
         let component_index = &mut self.by_component;
+        let max_len = self.max_len;
+        let row_capacity = self.row_capacity;
         let archetype_id = *self
             .by_components
             .entry(archetype_identity)
@@ -950,6 +1137,9 @@ impl Archetypes {
                     table_components,
                     sparse_set_components,
                 } = identity;
+                if let Some(max_len) = max_len.filter(|&max_len| archetypes.len() >= max_len) {
+                    panic!("{}", WorldCapacityError::Archetypes(max_len));
+                }
                 let id = ArchetypeId::new(archetypes.len());
                 let table_start = *archetype_component_count;
                 *archetype_component_count += table_components.len();
@@ -959,7 +1149,7 @@ impl Archetypes {
                 *archetype_component_count += sparse_set_components.len();
                 let sparse_set_archetype_components =
                     (sparse_start..*archetype_component_count).map(ArchetypeComponentId);
-                archetypes.push(Archetype::new(
+                let mut archetype = Archetype::new(
                     components,
                     component_index,
                     observers,
@@ -973,12 +1163,43 @@ impl Archetypes {
                         .iter()
                         .copied()
                         .zip(sparse_set_archetype_components),
-                ));
+                );
+                archetype.entities.reserve(row_capacity);
+                archetypes.push(archetype);
                 id
             });
+
+        if self.archetypes.len() > archetypes_len {
+            // The archetype was just created, so it hasn't been checked yet.
+            let archetype = &self.archetypes[archetype_id.index()];
+            for &(invariant, error_handler) in &self.invariants {
+                if let Err(error) = invariant.check(archetype, components) {
+                    if self.reported_invariants.insert((invariant, archetype_id)) {
+                        self.invariant_errors.push((error, error_handler));
+                    }
+                }
+            }
+        }
         archetype_id
     }
 
+    /// Adds an invariant that all archetypes are checked against, including the existing ones.
+    pub(crate) fn register_invariant(
+        &mut self,
+        invariant: ArchetypeInvariant,
+        error_handler: fn(&mut World, Error),
+        components: &Components,
+    ) {
+        for archetype in &self.archetypes {
+            if let Err(error) = invariant.check(archetype, components) {
+                if self.reported_invariants.insert((invariant, archetype.id())) {
+                    self.invariant_errors.push((error, error_handler));
+                }
+            }
+        }
+        self.invariants.push((invariant, error_handler));
+    }
+
     /// Returns the number of components that are stored in archetypes.
     /// Note that if some component `T` is stored in more than one archetype, it will be counted once for each archetype it's present in.
     #[inline]
@@ -986,10 +1207,26 @@ impl Archetypes {
         self.archetype_component_count
     }
 
+    /// Clears the cached transitions between all archetypes, so that they're computed again the
+    /// next time a bundle is inserted or removed.
+    pub(crate) fn clear_edges(&mut self) {
+        for archetype in &mut self.archetypes {
+            archetype.edges = Edges::default();
+        }
+    }
+
     /// Clears all entities from all archetypes.
-    pub(crate) fn clear_entities(&mut self) {
+    pub(crate) fn clear_entities(&mut self, change_tick: Tick) {
+        for archetype in &mut self.archetypes {
+            archetype.clear_entities(change_tick);
+        }
+    }
+
+    /// Clamps the change ticks of all archetypes, so that they don't become too old to be
+    /// compared with the current change tick.
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         for archetype in &mut self.archetypes {
-            archetype.clear_entities();
+            archetype.changed_tick.check_tick(change_tick);
         }
     }
 
@@ -1112,7 +1112,7 @@ impl<'w> BundleInserter<'w> {
                     (&mut world.storages.sparse_sets, &mut world.entities)
                 };
 
-                let result = archetype.swap_remove(location.archetype_row);
+                let result = archetype.swap_remove(location.archetype_row, self.change_tick);
                 if let Some(swapped_entity) = result.swapped_entity {
                     let swapped_location =
                         // SAFETY: If the swap was successful, swapped_entity must be valid.
@@ -1127,7 +1127,8 @@ impl<'w> BundleInserter<'w> {
                         },
                     );
                 }
-                let new_location = new_archetype.allocate(entity, result.table_row);
+                let new_location =
+                    new_archetype.allocate(entity, result.table_row, self.change_tick);
                 entities.set(entity.index(), new_location);
                 bundle_info.write_components(
                     table,
@@ -1162,7 +1163,7 @@ impl<'w> BundleInserter<'w> {
                         &mut world.entities,
                     )
                 };
-                let result = archetype.swap_remove(location.archetype_row);
+                let result = archetype.swap_remove(location.archetype_row, self.change_tick);
                 if let Some(swapped_entity) = result.swapped_entity {
                     let swapped_location =
                         // SAFETY: If the swap was successful, swapped_entity must be valid.
@@ -1180,7 +1181,8 @@ impl<'w> BundleInserter<'w> {
                 // PERF: store "non bundle" components in edge, then just move those to avoid
                 // redundant copies
                 let move_result = table.move_to_superset_unchecked(result.table_row, new_table);
-                let new_location = new_archetype.allocate(entity, move_result.new_row);
+                let new_location =
+                    new_archetype.allocate(entity, move_result.new_row, self.change_tick);
                 entities.set(entity.index(), new_location);
 
                 // If an entity was moved into this entity's table spot, update its table row.
@@ -1384,7 +1386,7 @@ impl<'w> BundleSpawner<'w> {
                 (&mut world.storages.sparse_sets, &mut world.entities)
             };
             let table_row = table.allocate(entity);
-            let location = archetype.allocate(entity, table_row);
+            let location = archetype.allocate(entity, table_row, self.change_tick);
             bundle_info.write_components(
                 table,
                 sparse_sets,
@@ -1584,6 +1586,41 @@ impl Bundles {
         }
     }
 
+    /// Recomputes the required components of every bundle that explicitly contains one of the
+    /// `changed` components, after the requirements of those components changed.
+    ///
+    /// # Safety
+    /// Every component required by the `changed` components must be valid within `components`
+    /// and have its storage initialized.
+    pub(crate) unsafe fn refresh_required_components(
+        &mut self,
+        components: &Components,
+        changed: &[ComponentId],
+    ) {
+        for bundle_info in &mut self.bundle_infos {
+            if !bundle_info
+                .iter_explicit_components()
+                .any(|id| changed.contains(&id))
+            {
+                continue;
+            }
+            let explicit_components = bundle_info.explicit_components().to_vec();
+            // SAFETY: The explicit components were already validated when the bundle was
+            // registered, and the caller ensures that their requirements are valid.
+            *bundle_info = unsafe {
+                BundleInfo::new(
+                    "refreshed bundle",
+                    components,
+                    explicit_components,
+                    bundle_info.id,
+                )
+            };
+        }
+        // Contributed bundles contain the previous required components, so they're registered
+        // again the next time they're used.
+        self.contributed_bundle_ids.clear();
+    }
+
     /// # Safety
     /// A [`BundleInfo`] with the given [`BundleId`] must have been initialized for this instance of `Bundles`.
     pub(crate) unsafe fn get_unchecked(&self, id: BundleId) -> &BundleInfo {
@@ -1705,7 +1742,11 @@ fn sorted_remove<T: Eq + Ord + Copy>(source: &mut Vec<T>, remove: &[T]) {
 #[cfg(test)]
 mod tests {
     use crate as bevy_ecs;
-    use crate::{component::HookContext, prelude::*, world::DeferredWorld};
+    use crate::{
+        component::{ComponentHookConfig, HookContext, HookLabel},
+        prelude::*,
+        world::DeferredWorld,
+    };
     use alloc::vec;
 
     #[derive(Component)]
@@ -1820,6 +1861,72 @@ mod tests {
         assert_eq!(2, world.resource::<R>().0);
     }
 
+    #[test]
+    fn component_hook_multiple_registrations() {
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world
+            .register_component_hooks::<A>()
+            .on_add(|mut world, _| world.resource_mut::<R>().assert_order(0))
+            .on_add(|mut world, _| world.resource_mut::<R>().assert_order(1))
+            .on_insert(|mut world, _| world.resource_mut::<R>().assert_order(2))
+            .on_insert(|mut world, _| world.resource_mut::<R>().assert_order(3));
+
+        world.spawn(A);
+        assert_eq!(4, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn component_hook_labeled_order() {
+        #[derive(HookLabel, Clone, PartialEq, Eq, Hash, Debug)]
+        enum Label {
+            First,
+            Last,
+            Missing,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<R>();
+        world
+            .register_component_hooks::<A>()
+            .on_add_with(
+                ComponentHookConfig::new(|mut world, _| world.resource_mut::<R>().assert_order(2))
+                    .label(Label::Last),
+            )
+            .on_add(|mut world, _| world.resource_mut::<R>().assert_order(0))
+            .on_add_with(
+                ComponentHookConfig::new(|mut world, _| world.resource_mut::<R>().assert_order(1))
+                    .label(Label::First)
+                    .before(Label::Last),
+            )
+            .on_add_with(
+                ComponentHookConfig::new(|mut world, _| world.resource_mut::<R>().assert_order(3))
+                    .after(Label::Last)
+                    .after(Label::Missing),
+            );
+
+        world.spawn(A);
+        assert_eq!(4, world.resource::<R>().0);
+    }
+
+    #[test]
+    fn component_hook_order_cycle() {
+        #[derive(HookLabel, Clone, PartialEq, Eq, Hash, Debug)]
+        struct Label;
+
+        let mut world = World::new();
+        let hooks = world.register_component_hooks::<A>();
+        hooks.on_add_with(ComponentHookConfig::new(|_, _| {}).label(Label));
+        assert!(hooks
+            .try_on_add_with(
+                ComponentHookConfig::new(|_, _| {})
+                    .label(Label)
+                    .before(Label)
+            )
+            .is_err());
+        assert_eq!(hooks.on_add.iter().count(), 1);
+    }
+
     #[test]
     fn component_hook_order_recursive() {
         let mut world = World::new();
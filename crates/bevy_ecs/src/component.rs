@@ -5,7 +5,9 @@ use crate::{
     archetype::ArchetypeFlags,
     bundle::BundleInfo,
     change_detection::MAX_CHANGE_AGE,
+    define_label,
     entity::{ComponentCloneCtx, Entity},
+    intern::Interned,
     query::DebugCheckedUnwrap,
     resource::Resource,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
@@ -14,8 +16,8 @@ use crate::{
 };
 #[cfg(feature = "bevy_reflect")]
 use alloc::boxed::Box;
-use alloc::{borrow::Cow, format, vec::Vec};
-pub use bevy_ecs_macros::Component;
+use alloc::{borrow::Cow, format, vec, vec::Vec};
+pub use bevy_ecs_macros::{Component, HookLabel};
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_platform_support::sync::Arc;
 use bevy_ptr::{OwningPtr, UnsafeCellDeref};
@@ -85,6 +87,10 @@ pub use bevy_ecs_macros::require;
 /// See the documentation for [`ComponentMutability`] for more details around this
 /// feature.
 ///
+/// Immutable components that are [`Eq`], [`Hash`](core::hash::Hash) and [`Clone`] can also be indexed by
+/// adding the `#[component(index)]` attribute, which implies `#[component(immutable)]`.
+/// Entities can then be looked up by the value of the component, see [`IndexedComponent`] for more details.
+///
 /// See the [`entity`] module level documentation to learn how to add or remove components from an entity.
 ///
 /// See the documentation for [`Query`] to learn how to access component data from a system.
@@ -92,6 +98,7 @@ pub use bevy_ecs_macros::require;
 /// [`entity`]: crate::entity#usage
 /// [`Query`]: crate::system::Query
 /// [`ComponentMutability`]: crate::component::ComponentMutability
+/// [`IndexedComponent`]: crate::index::IndexedComponent
 ///
 /// # Choosing a storage type
 ///
@@ -286,6 +293,11 @@ pub use bevy_ecs_macros::require;
 /// for the same component will result in a panic. This is done to prevent conflicting constructors
 /// and confusing ordering dependencies.
 ///
+/// To intentionally change an existing requirement, including one declared with the `require` attribute,
+/// use [`World::override_required_components`] to replace its constructor, or
+/// [`World::remove_required_components`] to stop requiring it. This allows swapping which components are
+/// added based on configuration, without changing the definition of the requiring component.
+///
 /// Note that requirements must currently be registered before the requiring component is inserted
 /// into the world for the first time. Registering requirements after this will lead to a panic.
 ///
@@ -565,13 +577,50 @@ pub struct HookContext {
 /// let tracked_entities = world.resource::<TrackedEntities>();
 /// assert!(!tracked_entities.0.contains(&entity));
 /// ```
+///
+/// # Ordering
+///
+/// Any number of hooks can be registered for each lifecycle event, such as by different plugins.
+/// By default they run in the order they were registered in. Using a [`ComponentHookConfig`],
+/// hooks can be labeled with a [`HookLabel`] and ordered relative to other labels,
+/// similar to ordering systems relative to [`SystemSet`](crate::schedule::SystemSet)s.
+///
+/// ```
+/// use bevy_ecs::{component::{ComponentHookConfig, HookLabel}, prelude::*};
+///
+/// #[derive(Component)]
+/// struct Position;
+///
+/// #[derive(HookLabel, Clone, PartialEq, Eq, Hash, Debug)]
+/// struct Physics;
+///
+/// #[derive(Resource, Default)]
+/// struct Order(Vec<&'static str>);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Order>();
+///
+/// world
+///     .register_component_hooks::<Position>()
+///     .on_add_with(
+///         ComponentHookConfig::new(|mut world, _| world.resource_mut::<Order>().0.push("render"))
+///             .after(Physics),
+///     )
+///     .on_add_with(
+///         ComponentHookConfig::new(|mut world, _| world.resource_mut::<Order>().0.push("physics"))
+///             .label(Physics),
+///     );
+///
+/// world.spawn(Position);
+/// assert_eq!(world.resource::<Order>().0, ["physics", "render"]);
+/// ```
 #[derive(Debug, Clone, Default)]
 pub struct ComponentHooks {
-    pub(crate) on_add: Option<ComponentHook>,
-    pub(crate) on_insert: Option<ComponentHook>,
-    pub(crate) on_replace: Option<ComponentHook>,
-    pub(crate) on_remove: Option<ComponentHook>,
-    pub(crate) on_despawn: Option<ComponentHook>,
+    pub(crate) on_add: ComponentHookList,
+    pub(crate) on_insert: ComponentHookList,
+    pub(crate) on_replace: ComponentHookList,
+    pub(crate) on_remove: ComponentHookList,
+    pub(crate) on_despawn: ComponentHookList,
 }
 
 impl ComponentHooks {
@@ -579,12 +628,10 @@ impl ComponentHooks {
     /// An `on_add` hook will always run before `on_insert` hooks. Spawning an entity counts as
     /// adding all of its components.
     ///
-    /// # Panics
-    ///
-    /// Will panic if the component already has an `on_add` hook
+    /// Multiple `on_add` hooks may be registered, and run in the order they were registered in,
+    /// unless ordered otherwise using [`on_add_with`](Self::on_add_with).
     pub fn on_add(&mut self, hook: ComponentHook) -> &mut Self {
-        self.try_on_add(hook)
-            .expect("Component already has an on_add hook")
+        self.on_add_with(hook)
     }
 
     /// Register a [`ComponentHook`] that will be run when this component is added (with `.insert`)
@@ -592,17 +639,15 @@ impl ComponentHooks {
     ///
     /// An `on_insert` hook always runs after any `on_add` hooks (if the entity didn't already have the component).
     ///
+    /// Multiple `on_insert` hooks may be registered, and run in the order they were registered in,
+    /// unless ordered otherwise using [`on_insert_with`](Self::on_insert_with).
+    ///
     /// # Warning
     ///
     /// The hook won't run if the component is already present and is only mutated, such as in a system via a query.
     /// As a result, this is *not* an appropriate mechanism for reliably updating indexes and other caches.
-    ///
-    /// # Panics
-    ///
-    /// Will panic if the component already has an `on_insert` hook
     pub fn on_insert(&mut self, hook: ComponentHook) -> &mut Self {
-        self.try_on_insert(hook)
-            .expect("Component already has an on_insert hook")
+        self.on_insert_with(hook)
     }
 
     /// Register a [`ComponentHook`] that will be run when this component is about to be dropped,
@@ -614,103 +659,338 @@ impl ComponentHooks {
     ///
     /// An `on_replace` hook always runs before any `on_remove` hooks (if the component is being removed from the entity).
     ///
+    /// Multiple `on_replace` hooks may be registered, and run in the order they were registered in,
+    /// unless ordered otherwise using [`on_replace_with`](Self::on_replace_with).
+    ///
     /// # Warning
     ///
     /// The hook won't run if the component is already present and is only mutated, such as in a system via a query.
     /// As a result, this is *not* an appropriate mechanism for reliably updating indexes and other caches.
-    ///
-    /// # Panics
-    ///
-    /// Will panic if the component already has an `on_replace` hook
     pub fn on_replace(&mut self, hook: ComponentHook) -> &mut Self {
-        self.try_on_replace(hook)
-            .expect("Component already has an on_replace hook")
+        self.on_replace_with(hook)
     }
 
     /// Register a [`ComponentHook`] that will be run when this component is removed from an entity.
     /// Despawning an entity counts as removing all of its components.
     ///
-    /// # Panics
-    ///
-    /// Will panic if the component already has an `on_remove` hook
+    /// Multiple `on_remove` hooks may be registered, and run in the order they were registered in,
+    /// unless ordered otherwise using [`on_remove_with`](Self::on_remove_with).
     pub fn on_remove(&mut self, hook: ComponentHook) -> &mut Self {
-        self.try_on_remove(hook)
-            .expect("Component already has an on_remove hook")
+        self.on_remove_with(hook)
     }
 
     /// Register a [`ComponentHook`] that will be run for each component on an entity when it is despawned.
     ///
+    /// Multiple `on_despawn` hooks may be registered, and run in the order they were registered in,
+    /// unless ordered otherwise using [`on_despawn_with`](Self::on_despawn_with).
+    pub fn on_despawn(&mut self, hook: ComponentHook) -> &mut Self {
+        self.on_despawn_with(hook)
+    }
+
+    /// Register an `on_add` hook, ordered relative to the other `on_add` hooks of this component
+    /// according to its [`ComponentHookConfig`].
+    ///
+    /// See [`Self::on_add`].
+    ///
     /// # Panics
     ///
-    /// Will panic if the component already has an `on_despawn` hook
-    pub fn on_despawn(&mut self, hook: ComponentHook) -> &mut Self {
-        self.try_on_despawn(hook)
-            .expect("Component already has an on_despawn hook")
+    /// Will panic if the ordering constraints of the `on_add` hooks form a cycle.
+    pub fn on_add_with(&mut self, config: impl Into<ComponentHookConfig>) -> &mut Self {
+        self.try_on_add_with(config)
+            .expect("on_add hooks have cyclic ordering constraints")
     }
 
-    /// Attempt to register a [`ComponentHook`] that will be run when this component is added to an entity.
+    /// Register an `on_insert` hook, ordered relative to the other `on_insert` hooks of this
+    /// component according to its [`ComponentHookConfig`].
+    ///
+    /// See [`Self::on_insert`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the ordering constraints of the `on_insert` hooks form a cycle.
+    pub fn on_insert_with(&mut self, config: impl Into<ComponentHookConfig>) -> &mut Self {
+        self.try_on_insert_with(config)
+            .expect("on_insert hooks have cyclic ordering constraints")
+    }
+
+    /// Register an `on_replace` hook, ordered relative to the other `on_replace` hooks of this
+    /// component according to its [`ComponentHookConfig`].
+    ///
+    /// See [`Self::on_replace`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the ordering constraints of the `on_replace` hooks form a cycle.
+    pub fn on_replace_with(&mut self, config: impl Into<ComponentHookConfig>) -> &mut Self {
+        self.try_on_replace_with(config)
+            .expect("on_replace hooks have cyclic ordering constraints")
+    }
+
+    /// Register an `on_remove` hook, ordered relative to the other `on_remove` hooks of this
+    /// component according to its [`ComponentHookConfig`].
+    ///
+    /// See [`Self::on_remove`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the ordering constraints of the `on_remove` hooks form a cycle.
+    pub fn on_remove_with(&mut self, config: impl Into<ComponentHookConfig>) -> &mut Self {
+        self.try_on_remove_with(config)
+            .expect("on_remove hooks have cyclic ordering constraints")
+    }
+
+    /// Register an `on_despawn` hook, ordered relative to the other `on_despawn` hooks of this
+    /// component according to its [`ComponentHookConfig`].
+    ///
+    /// See [`Self::on_despawn`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the ordering constraints of the `on_despawn` hooks form a cycle.
+    pub fn on_despawn_with(&mut self, config: impl Into<ComponentHookConfig>) -> &mut Self {
+        self.try_on_despawn_with(config)
+            .expect("on_despawn hooks have cyclic ordering constraints")
+    }
+
+    /// Attempt to register an `on_add` hook with the given [`ComponentHookConfig`].
+    ///
+    /// This is a fallible version of [`Self::on_add_with`]. If the ordering constraints would form
+    /// a cycle, the hook is not registered.
+    pub fn try_on_add_with(
+        &mut self,
+        config: impl Into<ComponentHookConfig>,
+    ) -> Result<&mut Self, ComponentHookCycleError> {
+        self.on_add.insert(config.into())?;
+        Ok(self)
+    }
+
+    /// Attempt to register an `on_insert` hook with the given [`ComponentHookConfig`].
+    ///
+    /// This is a fallible version of [`Self::on_insert_with`]. If the ordering constraints would
+    /// form a cycle, the hook is not registered.
+    pub fn try_on_insert_with(
+        &mut self,
+        config: impl Into<ComponentHookConfig>,
+    ) -> Result<&mut Self, ComponentHookCycleError> {
+        self.on_insert.insert(config.into())?;
+        Ok(self)
+    }
+
+    /// Attempt to register an `on_replace` hook with the given [`ComponentHookConfig`].
+    ///
+    /// This is a fallible version of [`Self::on_replace_with`]. If the ordering constraints would
+    /// form a cycle, the hook is not registered.
+    pub fn try_on_replace_with(
+        &mut self,
+        config: impl Into<ComponentHookConfig>,
+    ) -> Result<&mut Self, ComponentHookCycleError> {
+        self.on_replace.insert(config.into())?;
+        Ok(self)
+    }
+
+    /// Attempt to register an `on_remove` hook with the given [`ComponentHookConfig`].
+    ///
+    /// This is a fallible version of [`Self::on_remove_with`]. If the ordering constraints would
+    /// form a cycle, the hook is not registered.
+    pub fn try_on_remove_with(
+        &mut self,
+        config: impl Into<ComponentHookConfig>,
+    ) -> Result<&mut Self, ComponentHookCycleError> {
+        self.on_remove.insert(config.into())?;
+        Ok(self)
+    }
+
+    /// Attempt to register an `on_despawn` hook with the given [`ComponentHookConfig`].
     ///
-    /// This is a fallible version of [`Self::on_add`].
+    /// This is a fallible version of [`Self::on_despawn_with`]. If the ordering constraints would
+    /// form a cycle, the hook is not registered.
+    pub fn try_on_despawn_with(
+        &mut self,
+        config: impl Into<ComponentHookConfig>,
+    ) -> Result<&mut Self, ComponentHookCycleError> {
+        self.on_despawn.insert(config.into())?;
+        Ok(self)
+    }
+
+    /// Attempt to register a [`ComponentHook`] that will be run when this component is added to an entity.
     ///
     /// Returns `None` if the component already has an `on_add` hook.
     pub fn try_on_add(&mut self, hook: ComponentHook) -> Option<&mut Self> {
-        if self.on_add.is_some() {
+        if !self.on_add.is_empty() {
             return None;
         }
-        self.on_add = Some(hook);
-        Some(self)
+        Some(self.on_add(hook))
     }
 
     /// Attempt to register a [`ComponentHook`] that will be run when this component is added (with `.insert`)
     ///
-    /// This is a fallible version of [`Self::on_insert`].
-    ///
     /// Returns `None` if the component already has an `on_insert` hook.
     pub fn try_on_insert(&mut self, hook: ComponentHook) -> Option<&mut Self> {
-        if self.on_insert.is_some() {
+        if !self.on_insert.is_empty() {
             return None;
         }
-        self.on_insert = Some(hook);
-        Some(self)
+        Some(self.on_insert(hook))
     }
 
     /// Attempt to register a [`ComponentHook`] that will be run when this component is replaced (with `.insert`) or removed
     ///
-    /// This is a fallible version of [`Self::on_replace`].
-    ///
     /// Returns `None` if the component already has an `on_replace` hook.
     pub fn try_on_replace(&mut self, hook: ComponentHook) -> Option<&mut Self> {
-        if self.on_replace.is_some() {
+        if !self.on_replace.is_empty() {
             return None;
         }
-        self.on_replace = Some(hook);
-        Some(self)
+        Some(self.on_replace(hook))
     }
 
     /// Attempt to register a [`ComponentHook`] that will be run when this component is removed from an entity.
     ///
-    /// This is a fallible version of [`Self::on_remove`].
-    ///
     /// Returns `None` if the component already has an `on_remove` hook.
     pub fn try_on_remove(&mut self, hook: ComponentHook) -> Option<&mut Self> {
-        if self.on_remove.is_some() {
+        if !self.on_remove.is_empty() {
             return None;
         }
-        self.on_remove = Some(hook);
-        Some(self)
+        Some(self.on_remove(hook))
     }
 
     /// Attempt to register a [`ComponentHook`] that will be run for each component on an entity when it is despawned.
     ///
-    /// This is a fallible version of [`Self::on_despawn`].
-    ///
     /// Returns `None` if the component already has an `on_despawn` hook.
     pub fn try_on_despawn(&mut self, hook: ComponentHook) -> Option<&mut Self> {
-        if self.on_despawn.is_some() {
+        if !self.on_despawn.is_empty() {
             return None;
         }
-        self.on_despawn = Some(hook);
-        Some(self)
+        Some(self.on_despawn(hook))
+    }
+}
+
+define_label!(
+    /// A strongly-typed class of labels used to order the [`ComponentHook`]s registered for the
+    /// same lifecycle event of a component, see [`ComponentHookConfig`].
+    #[diagnostic::on_unimplemented(
+        note = "consider annotating `{Self}` with `#[derive(HookLabel)]`"
+    )]
+    HookLabel,
+    HOOK_LABEL_INTERNER
+);
+
+/// A shorthand for `Interned<dyn HookLabel>`.
+pub type InternedHookLabel = Interned<dyn HookLabel>;
+
+/// A [`ComponentHook`] along with its ordering relative to the other hooks registered for the
+/// same lifecycle event of a component.
+///
+/// Like system sets, any number of hooks may share a [`HookLabel`], and ordering constraints apply
+/// to all hooks with that label. Constraints on labels without any hooks are ignored, so plugins can
+/// order their hooks relative to hooks that may or may not be registered. Hooks without constraints
+/// between them run in the order they were registered in.
+#[derive(Debug, Clone)]
+pub struct ComponentHookConfig {
+    hook: ComponentHook,
+    labels: Vec<InternedHookLabel>,
+    before: Vec<InternedHookLabel>,
+    after: Vec<InternedHookLabel>,
+}
+
+impl ComponentHookConfig {
+    /// Creates a config for the given hook, without any label or ordering constraints.
+    pub fn new(hook: ComponentHook) -> Self {
+        Self {
+            hook,
+            labels: Vec::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+
+    /// Adds a label to the hook, which other hooks can be ordered relative to.
+    pub fn label(mut self, label: impl HookLabel) -> Self {
+        self.labels.push(label.intern());
+        self
+    }
+
+    /// Runs the hook before all hooks with the given label.
+    pub fn before(mut self, label: impl HookLabel) -> Self {
+        self.before.push(label.intern());
+        self
+    }
+
+    /// Runs the hook after all hooks with the given label.
+    pub fn after(mut self, label: impl HookLabel) -> Self {
+        self.after.push(label.intern());
+        self
+    }
+
+    /// Whether this hook must run before `other`.
+    fn runs_before(&self, other: &ComponentHookConfig) -> bool {
+        self.before.iter().any(|label| other.labels.contains(label))
+            || other.after.iter().any(|label| self.labels.contains(label))
+    }
+}
+
+impl From<ComponentHook> for ComponentHookConfig {
+    fn from(hook: ComponentHook) -> Self {
+        Self::new(hook)
+    }
+}
+
+/// An error returned when the ordering constraints of [`ComponentHookConfig`]s form a cycle.
+#[derive(Error, Debug, Clone)]
+#[error("Component hooks have cyclic ordering constraints between labels {labels:?}")]
+pub struct ComponentHookCycleError {
+    labels: Vec<InternedHookLabel>,
+}
+
+/// The hooks registered for a single lifecycle event of a component, sorted in the order they run.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ComponentHookList {
+    configs: Vec<ComponentHookConfig>,
+    sorted: Vec<ComponentHook>,
+}
+
+impl ComponentHookList {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sorted.is_empty()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = ComponentHook> + '_ {
+        self.sorted.iter().copied()
+    }
+
+    fn insert(&mut self, config: ComponentHookConfig) -> Result<(), ComponentHookCycleError> {
+        self.configs.push(config);
+
+        // Kahn's algorithm, always picking the earliest registered hook that is ready to run.
+        let len = self.configs.len();
+        let mut dependencies = (0..len)
+            .map(|i| {
+                (0..len)
+                    .filter(|&j| self.configs[j].runs_before(&self.configs[i]))
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        let mut done = vec![false; len];
+        let mut sorted = Vec::with_capacity(len);
+        while let Some(next) = (0..len).find(|&i| !done[i] && dependencies[i] == 0) {
+            done[next] = true;
+            sorted.push(self.configs[next].hook);
+            for (i, config) in self.configs.iter().enumerate() {
+                if self.configs[next].runs_before(config) {
+                    dependencies[i] -= 1;
+                }
+            }
+        }
+
+        if sorted.len() < len {
+            let mut labels = Vec::new();
+            for (config, _) in self.configs.iter().zip(&done).filter(|(_, done)| !**done) {
+                labels.extend(config.labels.iter().copied());
+            }
+            self.configs.pop();
+            return Err(ComponentHookCycleError { labels });
+        }
+
+        self.sorted = sorted;
+        Ok(())
     }
 }
 
@@ -795,19 +1075,19 @@ impl ComponentInfo {
     /// Update the given flags to include any [`ComponentHook`] registered to self
     #[inline]
     pub(crate) fn update_archetype_flags(&self, flags: &mut ArchetypeFlags) {
-        if self.hooks().on_add.is_some() {
+        if !self.hooks().on_add.is_empty() {
             flags.insert(ArchetypeFlags::ON_ADD_HOOK);
         }
-        if self.hooks().on_insert.is_some() {
+        if !self.hooks().on_insert.is_empty() {
             flags.insert(ArchetypeFlags::ON_INSERT_HOOK);
         }
-        if self.hooks().on_replace.is_some() {
+        if !self.hooks().on_replace.is_empty() {
             flags.insert(ArchetypeFlags::ON_REPLACE_HOOK);
         }
-        if self.hooks().on_remove.is_some() {
+        if !self.hooks().on_remove.is_empty() {
             flags.insert(ArchetypeFlags::ON_REMOVE_HOOK);
         }
-        if self.hooks().on_despawn.is_some() {
+        if !self.hooks().on_despawn.is_empty() {
             flags.insert(ArchetypeFlags::ON_DESPAWN_HOOK);
         }
     }
@@ -1371,6 +1651,222 @@ impl Components {
         Ok(())
     }
 
+    /// Registers the given component `R` as directly required by the `requiree`, replacing the
+    /// constructor of any existing direct requirement, and updates the [required components] of every
+    /// component that requires the `requiree`.
+    ///
+    /// Unlike [`Components::register_required_components`], this also overrides requirements
+    /// declared with the `require` attribute.
+    ///
+    /// [required components]: Component#required-components
+    ///
+    /// # Safety
+    ///
+    /// The given component IDs `required` and `requiree` must be valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RequiredComponentsError`] if the `required` component requires the `requiree`.
+    pub(crate) unsafe fn override_required_components<R: Component>(
+        &mut self,
+        requiree: ComponentId,
+        required: ComponentId,
+        constructor: fn() -> R,
+    ) -> Result<(), RequiredComponentsError> {
+        if required == requiree
+            || self
+                .get_required_by(requiree)
+                .is_some_and(|required_by| required_by.contains(&required))
+        {
+            return Err(RequiredComponentsError::CyclicRequirement(
+                requiree, required,
+            ));
+        }
+
+        let mut erased = RequiredComponents::default();
+        erased.register_by_id(required, constructor, 0);
+        let constructor = erased.0.remove(&required).unwrap().constructor;
+
+        // SAFETY: The caller ensures that the `requiree` is valid.
+        let mut direct = unsafe { self.direct_required_components(requiree) };
+        direct.retain(|(id, _)| *id != required);
+        direct.push((required, constructor));
+
+        // SAFETY: The caller ensures that the component IDs are valid,
+        //         and `constructor` was created for `required` above.
+        unsafe { self.rebuild_required_components(requiree, direct) };
+        Ok(())
+    }
+
+    /// Removes the given component `required` from the direct requirements of the `requiree`, and
+    /// updates the [required components] of every component that requires the `requiree`.
+    ///
+    /// The `required` component may still be required indirectly, through other requirements.
+    ///
+    /// [required components]: Component#required-components
+    ///
+    /// # Safety
+    ///
+    /// The given component IDs `required` and `requiree` must be valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RequiredComponentsError`] if the `required` component is not a directly required component for the `requiree`.
+    pub(crate) unsafe fn remove_required_components(
+        &mut self,
+        requiree: ComponentId,
+        required: ComponentId,
+    ) -> Result<(), RequiredComponentsError> {
+        // SAFETY: The caller ensures that the `requiree` is valid.
+        let mut direct = unsafe { self.direct_required_components(requiree) };
+        let len = direct.len();
+        direct.retain(|(id, _)| *id != required);
+        if direct.len() == len {
+            return Err(RequiredComponentsError::NotDirectlyRequired(
+                requiree, required,
+            ));
+        }
+
+        // SAFETY: The caller ensures that the component IDs are valid,
+        //         and the constructors are taken from the existing requirements.
+        unsafe { self.rebuild_required_components(requiree, direct) };
+        Ok(())
+    }
+
+    /// Returns the components directly required by the given component, along with their constructors.
+    ///
+    /// # Safety
+    ///
+    /// The given component ID must be valid.
+    unsafe fn direct_required_components(
+        &self,
+        id: ComponentId,
+    ) -> Vec<(ComponentId, RequiredComponentConstructor)> {
+        // SAFETY: The caller ensures that the component ID is valid.
+        let info = unsafe { self.get_info(id).debug_checked_unwrap() };
+        info.required_components()
+            .0
+            .iter()
+            .filter(|(_, component)| component.inheritance_depth == 0)
+            .map(|(id, component)| (*id, component.constructor.clone()))
+            .collect()
+    }
+
+    /// Replaces the direct requirements of the `requiree` with `direct`, and recomputes the
+    /// required components of the `requiree` and of every component that requires it.
+    ///
+    /// # Safety
+    ///
+    /// All of the given component IDs must be valid, each constructor must match its component ID,
+    /// and none of the `direct` components may require the `requiree`.
+    unsafe fn rebuild_required_components(
+        &mut self,
+        requiree: ComponentId,
+        direct: Vec<(ComponentId, RequiredComponentConstructor)>,
+    ) {
+        let mut affected: Vec<ComponentId> = self
+            .get_required_by(requiree)
+            .map(|required_by| required_by.iter().copied().collect())
+            .unwrap_or_default();
+        affected.push(requiree);
+
+        let mut rebuilt = HashMap::default();
+        // SAFETY: Upheld by the caller.
+        let required_components =
+            unsafe { self.collect_required_components(&direct, &affected, &mut rebuilt) };
+        rebuilt.insert(requiree, required_components);
+        for &id in &affected {
+            // SAFETY: Every affected component requires the `requiree`, so it is valid.
+            unsafe { self.rebuild_required_components_of(id, &affected, &mut rebuilt) };
+        }
+
+        for (id, required_components) in rebuilt {
+            // SAFETY: Every rebuilt component is affected, so it is valid.
+            let current = unsafe { self.get_required_components_mut(id).debug_checked_unwrap() };
+            let old = core::mem::replace(current, required_components);
+            for required in old.0.keys() {
+                // SAFETY: The component was required, so it must exist.
+                unsafe { self.get_required_by_mut(*required).debug_checked_unwrap() }.remove(&id);
+            }
+            let new: Vec<ComponentId> = self.components[id.index()]
+                .required_components
+                .0
+                .keys()
+                .copied()
+                .collect();
+            for required in new {
+                // SAFETY: The component is required, so it must exist.
+                unsafe { self.get_required_by_mut(required).debug_checked_unwrap() }.insert(id);
+            }
+        }
+    }
+
+    /// Recomputes the required components of an affected component `id` from its direct requirements,
+    /// storing the result in `rebuilt`.
+    ///
+    /// # Safety
+    ///
+    /// The given component ID must be valid.
+    unsafe fn rebuild_required_components_of(
+        &self,
+        id: ComponentId,
+        affected: &[ComponentId],
+        rebuilt: &mut HashMap<ComponentId, RequiredComponents>,
+    ) {
+        if rebuilt.contains_key(&id) {
+            return;
+        }
+        // SAFETY: The caller ensures that the component ID is valid.
+        let direct = unsafe { self.direct_required_components(id) };
+        // SAFETY: The direct requirements are taken from the existing required components.
+        let required_components =
+            unsafe { self.collect_required_components(&direct, affected, rebuilt) };
+        rebuilt.insert(id, required_components);
+    }
+
+    /// Collects the given direct requirements and the requirements inherited from them, using the
+    /// already rebuilt requirements of affected components.
+    ///
+    /// # Safety
+    ///
+    /// The given component IDs must be valid, and each constructor must match its component ID.
+    unsafe fn collect_required_components(
+        &self,
+        direct: &[(ComponentId, RequiredComponentConstructor)],
+        affected: &[ComponentId],
+        rebuilt: &mut HashMap<ComponentId, RequiredComponents>,
+    ) -> RequiredComponents {
+        let mut required_components = RequiredComponents::default();
+        for (required, constructor) in direct {
+            // SAFETY: The caller ensures that the constructor matches the component ID.
+            unsafe { required_components.register_dynamic(*required, constructor.clone(), 0) };
+
+            if affected.contains(required) {
+                // SAFETY: The caller ensures that the component ID is valid.
+                unsafe { self.rebuild_required_components_of(*required, affected, rebuilt) };
+            }
+            let inherited = match rebuilt.get(required) {
+                Some(inherited) => inherited,
+                None => {
+                    // SAFETY: The caller ensures that the component ID is valid.
+                    let info = unsafe { self.get_info(*required).debug_checked_unwrap() };
+                    info.required_components()
+                }
+            };
+            for (id, component) in inherited.0.iter() {
+                // SAFETY: Component ID and constructor match the ones on the original requirement.
+                unsafe {
+                    required_components.register_dynamic(
+                        *id,
+                        component.constructor.clone(),
+                        component.inheritance_depth + 1,
+                    );
+                }
+            }
+        }
+        required_components
+    }
+
     /// Registers the components inherited from `required` for the given `requiree`,
     /// returning the requirements in a list.
     ///
@@ -1463,6 +1959,18 @@ impl Components {
         let requiree = self.register_component_internal::<T>(storages, recursion_check_stack);
         let required = self.register_component_internal::<R>(storages, recursion_check_stack);
 
+        // Requirements inherited through `T` follow its current requirements, which may have
+        // been removed at runtime after `T` was registered.
+        if inheritance_depth > 0
+            && !self.components[requiree.index()]
+                .required_components
+                .0
+                .get(&required)
+                .is_some_and(|component| component.inheritance_depth == 0)
+        {
+            return;
+        }
+
         // SAFETY: We just created the components.
         unsafe {
             self.register_required_components_manual_unchecked::<R>(
@@ -1933,6 +2441,12 @@ pub enum RequiredComponentsError {
     /// An archetype with the component that requires other components already exists
     #[error("An archetype with the component {0:?} that requires other components already exists")]
     ArchetypeExists(ComponentId),
+    /// The component is not a directly required component for the requiree.
+    #[error("Component {0:?} does not directly require component {1:?}")]
+    NotDirectlyRequired(ComponentId, ComponentId),
+    /// The required component already requires the requiree, directly or indirectly.
+    #[error("Component {0:?} cannot require component {1:?}, as it is already required by it")]
+    CyclicRequirement(ComponentId, ComponentId),
 }
 
 /// A Required Component constructor. See [`Component`] for details.
@@ -75,6 +75,7 @@ use crate::{
         Identifier,
     },
     storage::{SparseSetIndex, TableId, TableRow},
+    world::error::WorldCapacityError,
 };
 use alloc::vec::Vec;
 use bevy_platform_support::sync::atomic::Ordering;
@@ -575,6 +576,8 @@ pub struct Entities {
     free_cursor: AtomicIdCursor,
     /// Stores the number of free entities for [`len`](Entities::len)
     len: u32,
+    /// The maximum number of entities that can be allocated at once, if any.
+    max_len: Option<u32>,
 }
 
 impl Entities {
@@ -584,6 +587,37 @@ impl Entities {
             pending: Vec::new(),
             free_cursor: AtomicIdCursor::new(0),
             len: 0,
+            max_len: None,
+        }
+    }
+
+    /// Caps the number of entities that can be allocated at once, and preallocates the storage
+    /// for that many entities.
+    pub(crate) fn set_limit(&mut self, max_len: Option<u32>) {
+        self.max_len = max_len;
+        if let Some(max_len) = max_len {
+            let max_len = max_len as usize;
+            self.meta.reserve(max_len.saturating_sub(self.meta.len()));
+            self.pending
+                .reserve(max_len.saturating_sub(self.pending.len()));
+        }
+    }
+
+    /// Returns the maximum number of entities that can be allocated at once, if it's limited.
+    #[inline]
+    pub fn limit(&self) -> Option<u32> {
+        self.max_len
+    }
+
+    /// Checks that `additional` more entities can be allocated without exceeding the
+    /// [limit](Self::limit).
+    #[inline]
+    pub fn check_limit(&self, additional: u32) -> Result<(), WorldCapacityError> {
+        match self.max_len {
+            Some(max_len) if self.len.saturating_add(additional) > max_len => {
+                Err(WorldCapacityError::Entities(max_len))
+            }
+            _ => Ok(()),
         }
     }
 
@@ -672,18 +706,43 @@ impl Entities {
         );
     }
 
+    /// Panics if another entity can't be allocated without exceeding the limit.
+    fn verify_within_limit(&self) {
+        if let Err(error) = self.check_limit(1) {
+            panic!("{error}");
+        }
+    }
+
     /// Allocate an entity ID directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [limit](Self::limit) of entities has been reached.
+    /// See [`try_alloc`](Self::try_alloc) for a fallible version.
     pub fn alloc(&mut self) -> Entity {
+        match self.try_alloc() {
+            Ok(entity) => entity,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Allocate an entity ID directly, or returns an error if the [limit](Self::limit) of
+    /// entities has been reached.
+    pub fn try_alloc(&mut self) -> Result<Entity, WorldCapacityError> {
         self.verify_flushed();
+        self.check_limit(1)?;
         self.len += 1;
         if let Some(index) = self.pending.pop() {
             let new_free_cursor = self.pending.len() as IdCursor;
             *self.free_cursor.get_mut() = new_free_cursor;
-            Entity::from_raw_and_generation(index, self.meta[index as usize].generation)
+            Ok(Entity::from_raw_and_generation(
+                index,
+                self.meta[index as usize].generation,
+            ))
         } else {
             let index = u32::try_from(self.meta.len()).expect("too many entities");
             self.meta.push(EntityMeta::EMPTY);
-            Entity::from_raw(index)
+            Ok(Entity::from_raw(index))
         }
     }
 
@@ -695,6 +754,7 @@ impl Entities {
         self.verify_flushed();
 
         let loc = if entity.index() as usize >= self.meta.len() {
+            self.verify_within_limit();
             self.pending
                 .extend((self.meta.len() as u32)..entity.index());
             let new_free_cursor = self.pending.len() as IdCursor;
@@ -704,6 +764,7 @@ impl Entities {
             self.len += 1;
             None
         } else if let Some(index) = self.pending.iter().position(|item| *item == entity.index()) {
+            self.verify_within_limit();
             self.pending.swap_remove(index);
             let new_free_cursor = self.pending.len() as IdCursor;
             *self.free_cursor.get_mut() = new_free_cursor;
@@ -731,6 +792,7 @@ impl Entities {
         self.verify_flushed();
 
         let result = if entity.index() as usize >= self.meta.len() {
+            self.verify_within_limit();
             self.pending
                 .extend((self.meta.len() as u32)..entity.index());
             let new_free_cursor = self.pending.len() as IdCursor;
@@ -740,6 +802,7 @@ impl Entities {
             self.len += 1;
             AllocAtWithoutReplacement::DidNotExist
         } else if let Some(index) = self.pending.iter().position(|item| *item == entity.index()) {
+            self.verify_within_limit();
             self.pending.swap_remove(index);
             let new_free_cursor = self.pending.len() as IdCursor;
             *self.free_cursor.get_mut() = new_free_cursor;
@@ -910,9 +973,19 @@ impl Entities {
     ///
     /// Note: freshly-allocated entities (ones which don't come from the pending list) are guaranteed
     /// to be initialized with the invalid archetype.
+    ///
+    /// # Panics
+    ///
+    /// Panics if flushing the reserved entities would exceed the [limit](Self::limit).
     pub unsafe fn flush(&mut self, mut init: impl FnMut(Entity, &mut EntityLocation)) {
+        let current_free_cursor = *self.free_cursor.get_mut();
+        let reserved = self.pending.len() as IdCursor - current_free_cursor.max(0)
+            + (-current_free_cursor).max(0);
+        if let Err(error) = self.check_limit(u32::try_from(reserved).unwrap_or(u32::MAX)) {
+            panic!("{error}");
+        }
+
         let free_cursor = self.free_cursor.get_mut();
-        let current_free_cursor = *free_cursor;
 
         let new_free_cursor = if current_free_cursor >= 0 {
             current_free_cursor as usize
@@ -2,9 +2,19 @@
 //!
 //! Disabled entities do not show up in queries unless the query explicitly mentions them.
 //!
-//! If for example we have `Disabled` as an entity disabling component, when you add `Disabled`
-//! to an entity, the entity will only be visible to queries with a filter like
-//! [`With`]`<Disabled>` or query data like [`Has`]`<Disabled>`.
+//! When you add [`Disabled`] to an entity, the entity will only be visible to queries with a filter
+//! like [`With`]`<Disabled>` or [`Allows`]`<Disabled>`, or query data like [`Has`]`<Disabled>`.
+//!
+//! Disabling an entity also disables all of its descendants in the [`ChildOf`] hierarchy,
+//! including children added while it is disabled. These descendants are marked with
+//! [`InheritedDisabled`], and enabling the entity again by removing [`Disabled`] only enables
+//! them, so descendants that were disabled on their own stay disabled.
+//!
+//! [`OnAdd`] and [`OnInsert`] observers don't run for components added to an entity that is
+//! already disabled. They run once the entity is enabled again, for the components it still
+//! has. [`OnReplace`], [`OnRemove`] and [`OnDespawn`] observers always run, so cleanup logic
+//! isn't skipped for disabled entities. Component hooks always run, because they keep
+//! relationships and other engine-maintained data consistent.
 //!
 //! ### Note
 //!
@@ -17,17 +27,185 @@
 //! See [`Query` performance] for more info.
 //!
 //! [`With`]: crate::prelude::With
+//! [`Allows`]: crate::query::Allows
 //! [`Has`]: crate::prelude::Has
+//! [`ChildOf`]: crate::hierarchy::ChildOf
+//! [`OnAdd`]: crate::world::OnAdd
+//! [`OnInsert`]: crate::world::OnInsert
+//! [`OnReplace`]: crate::world::OnReplace
+//! [`OnRemove`]: crate::world::OnRemove
+//! [`OnDespawn`]: crate::world::OnDespawn
 //! [`World`]: crate::prelude::World
 //! [`Query` performance]: crate::prelude::Query#performance
 
 use crate as bevy_ecs;
 use crate::{
-    component::{ComponentId, Components, StorageType},
+    component::{Component, ComponentId, Components, HookContext, StorageType},
+    entity::{hash_map::EntityHashMap, Entity},
+    hierarchy::{ChildOf, Children},
     query::FilteredAccess,
+    system::error_handler,
+    world::{DeferredWorld, EntityWorldMut, World, ON_ADD, ON_INSERT},
 };
+use alloc::vec::Vec;
 use bevy_ecs_macros::Resource;
 
+#[cfg(feature = "track_location")]
+use core::panic::Location;
+
+#[cfg(feature = "bevy_reflect")]
+use {crate::reflect::ReflectComponent, bevy_reflect::std_traits::ReflectDefault};
+
+/// [`ComponentId`] for [`Disabled`]
+pub const DISABLED: ComponentId = ComponentId::new(5);
+
+/// A marker component for disabled entities. See [the module docs] for more info.
+///
+/// [the module docs]: crate::entity_disabling
+#[derive(Component, Clone, Debug, Default)]
+#[component(on_add = disable_children, on_remove = enable_children)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component, Default, Debug))]
+pub struct Disabled;
+
+/// A marker component for entities that are [`Disabled`] because an ancestor is disabled,
+/// rather than on their own. See [the module docs] for more info.
+///
+/// [the module docs]: crate::entity_disabling
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Component, Default, Debug))]
+pub struct InheritedDisabled;
+
+fn disable_children(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let Some(children) = world.entity(entity).get::<Children>() else {
+        return;
+    };
+    let children = children.to_vec();
+    let mut commands = world.commands();
+    for child in children {
+        commands
+            .entity(child)
+            .queue_handled(inherit_disabled, error_handler::silent());
+    }
+}
+
+fn enable_children(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let children = world
+        .entity(entity)
+        .get::<Children>()
+        .map(|children| children.to_vec());
+    let mut commands = world.commands();
+    commands.queue(move |world: &mut World| resume_observers(world, entity));
+    commands.entity(entity).try_remove::<InheritedDisabled>();
+    for child in children.into_iter().flatten() {
+        commands
+            .entity(child)
+            .queue_handled(uninherit_disabled, error_handler::silent());
+    }
+}
+
+/// Disables a newly added child if its parent is disabled.
+pub(crate) fn disable_new_child(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let parent = world.entity(entity).get::<ChildOf>().unwrap().get();
+    if world
+        .get_entity(parent)
+        .is_ok_and(|parent| parent.contains::<Disabled>())
+    {
+        world
+            .commands()
+            .entity(entity)
+            .queue_handled(inherit_disabled, error_handler::silent());
+    }
+}
+
+fn inherit_disabled(mut entity: EntityWorldMut) {
+    let disabled_parent = entity
+        .get::<ChildOf>()
+        .is_some_and(|child_of| entity.world().entity(child_of.get()).contains::<Disabled>());
+    if disabled_parent && !entity.contains::<Disabled>() {
+        entity.insert((InheritedDisabled, Disabled));
+    }
+}
+
+fn uninherit_disabled(mut entity: EntityWorldMut) {
+    if entity.contains::<InheritedDisabled>() {
+        entity.remove::<(Disabled, InheritedDisabled)>();
+    }
+}
+
+/// The [`OnAdd`] and [`OnInsert`] observers that were suspended because their entity was
+/// disabled, to run once the entity is enabled again.
+///
+/// [`OnAdd`]: crate::world::OnAdd
+/// [`OnInsert`]: crate::world::OnInsert
+#[derive(Resource, Default)]
+struct SuspendedObservers(EntityHashMap<Vec<(ComponentId, ComponentId)>>);
+
+/// Records that the `event` observers for `components` of the disabled `entity` were suspended.
+pub(crate) fn suspend_observers(
+    world: &mut World,
+    event: ComponentId,
+    entity: Entity,
+    components: impl IntoIterator<Item = ComponentId>,
+) {
+    let mut suspended = world.get_resource_or_init::<SuspendedObservers>();
+    let suspended = suspended.0.entry(entity).or_default();
+    for component_id in components {
+        if !suspended.contains(&(event, component_id)) {
+            suspended.push((event, component_id));
+        }
+    }
+}
+
+/// Runs the observers that were suspended while `entity` was disabled, for the components it
+/// still has.
+fn resume_observers(world: &mut World, entity: Entity) {
+    let Some(suspended) = world
+        .get_resource_mut::<SuspendedObservers>()
+        .and_then(|mut suspended| suspended.0.remove(&entity))
+    else {
+        return;
+    };
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+    if entity_ref.contains::<Disabled>() {
+        // The entity was disabled again before this ran, so keep waiting.
+        for (event, component_id) in suspended {
+            suspend_observers(world, event, entity, [component_id]);
+        }
+        return;
+    }
+    let archetype = entity_ref.archetype();
+    let [added, inserted] = [ON_ADD, ON_INSERT].map(|event| {
+        suspended
+            .iter()
+            .filter(|&&(suspended_event, component_id)| {
+                suspended_event == event && archetype.contains(component_id)
+            })
+            .map(|&(_, component_id)| component_id)
+            .collect::<Vec<_>>()
+    });
+    let mut deferred_world = DeferredWorld::from(&mut *world);
+    for (event, components) in [(ON_ADD, added), (ON_INSERT, inserted)] {
+        if components.is_empty() {
+            continue;
+        }
+        // SAFETY: `OnAdd` and `OnInsert` observers accept ZST pointers.
+        unsafe {
+            deferred_world.trigger_observers(
+                event,
+                entity,
+                components.into_iter(),
+                #[cfg(feature = "track_location")]
+                Location::caller(),
+            );
+        }
+    }
+    world.flush();
+}
+
 /// The default filters for all queries, these are used to globally exclude entities from queries.
 /// See the [module docs](crate::entity_disabling) for more info.
 #[derive(Resource, Default, Debug)]
@@ -37,10 +215,6 @@ pub struct DefaultQueryFilters {
 }
 
 impl DefaultQueryFilters {
-    #[cfg_attr(
-        not(test),
-        expect(dead_code, reason = "No Disabled component exist yet")
-    )]
     /// Set the [`ComponentId`] for the entity disabling marker
     pub(crate) fn set_disabled(&mut self, component_id: ComponentId) -> Option<()> {
         if self.disabled.is_some() {
@@ -76,8 +250,22 @@ impl DefaultQueryFilters {
 mod tests {
 
     use super::*;
+    use crate::{
+        hierarchy::ChildOf,
+        observer::Trigger,
+        prelude::{Allows, Has, With},
+        resource::Resource,
+        system::ResMut,
+        world::{OnAdd, OnDespawn, OnRemove, World},
+    };
     use alloc::{vec, vec::Vec};
 
+    #[derive(Component)]
+    struct Marker;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
     #[test]
     fn test_set_filters() {
         let mut filters = DefaultQueryFilters::default();
@@ -149,4 +337,134 @@ mod tests {
         );
         assert_eq!(0, applied_access.without_filters().count());
     }
+
+    #[test]
+    fn disabled_entities_are_filtered() {
+        let mut world = World::new();
+        world.spawn(Marker);
+        world.spawn((Marker, Disabled));
+
+        let mut query = world.query::<&Marker>();
+        assert_eq!(1, query.iter(&world).count());
+
+        let mut query = world.query_filtered::<&Marker, Allows<Disabled>>();
+        assert_eq!(2, query.iter(&world).count());
+
+        let mut query = world.query_filtered::<&Marker, With<Disabled>>();
+        assert_eq!(1, query.iter(&world).count());
+
+        let mut query = world.query::<(&Marker, Has<Disabled>)>();
+        assert_eq!(2, query.iter(&world).count());
+    }
+
+    #[test]
+    fn disabling_propagates_to_children() {
+        let mut world = World::new();
+        let parent = world.spawn_empty().id();
+        let child = world.spawn(ChildOf(parent)).id();
+        let grandchild = world.spawn(ChildOf(child)).id();
+
+        world.entity_mut(parent).insert(Disabled);
+        world.flush();
+        assert!(world.entity(child).contains::<Disabled>());
+        assert!(world.entity(grandchild).contains::<Disabled>());
+
+        world.entity_mut(parent).remove::<Disabled>();
+        world.flush();
+        assert!(!world.entity(child).contains::<Disabled>());
+        assert!(!world.entity(grandchild).contains::<Disabled>());
+    }
+
+    #[test]
+    fn disabled_entities_suspend_observers() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.add_observer(|_: Trigger<OnAdd, Marker>, mut counter: ResMut<Counter>| {
+            counter.0 += 1;
+        });
+        world.add_observer(
+            |_: Trigger<OnAdd, Disabled>, mut counter: ResMut<Counter>| {
+                counter.0 += 10;
+            },
+        );
+
+        world.spawn(Marker);
+        assert_eq!(1, world.resource::<Counter>().0);
+
+        // The entity isn't disabled before this, so both observers run.
+        world.spawn((Marker, Disabled));
+        assert_eq!(12, world.resource::<Counter>().0);
+
+        let entity = world.spawn(Disabled).id();
+        world.entity_mut(entity).insert(Marker);
+        assert_eq!(22, world.resource::<Counter>().0);
+
+        // Suspended observers run once the entity is enabled again.
+        world.entity_mut(entity).remove::<Disabled>();
+        assert_eq!(23, world.resource::<Counter>().0);
+        world.entity_mut(entity).insert(Disabled);
+        world.entity_mut(entity).remove::<Disabled>();
+        assert_eq!(33, world.resource::<Counter>().0);
+    }
+
+    #[test]
+    fn disabled_entities_run_removal_observers() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.add_observer(
+            |_: Trigger<OnRemove, Marker>, mut counter: ResMut<Counter>| {
+                counter.0 += 1;
+            },
+        );
+        world.add_observer(
+            |_: Trigger<OnDespawn, Marker>, mut counter: ResMut<Counter>| {
+                counter.0 += 10;
+            },
+        );
+
+        let entity = world.spawn((Marker, Disabled)).id();
+        world.entity_mut(entity).remove::<Marker>();
+        assert_eq!(1, world.resource::<Counter>().0);
+
+        let entity = world.spawn((Marker, Disabled)).id();
+        world.despawn(entity);
+        assert_eq!(12, world.resource::<Counter>().0);
+    }
+
+    #[test]
+    fn enabling_keeps_explicitly_disabled_children() {
+        let mut world = World::new();
+        let parent = world.spawn_empty().id();
+        let child = world.spawn((ChildOf(parent), Disabled)).id();
+        let grandchild = world.spawn(ChildOf(child)).id();
+        assert!(world.entity(grandchild).contains::<InheritedDisabled>());
+
+        world.entity_mut(parent).insert(Disabled);
+        assert!(!world.entity(child).contains::<InheritedDisabled>());
+
+        world.entity_mut(parent).remove::<Disabled>();
+        assert!(world.entity(child).contains::<Disabled>());
+        assert!(world.entity(grandchild).contains::<Disabled>());
+
+        world.entity_mut(child).remove::<Disabled>();
+        assert!(!world.entity(grandchild).contains::<Disabled>());
+        assert!(!world.entity(grandchild).contains::<InheritedDisabled>());
+    }
+
+    #[test]
+    fn children_added_to_disabled_parents_are_disabled() {
+        let mut world = World::new();
+        let parent = world.spawn(Disabled).id();
+        let child = world.spawn(ChildOf(parent)).id();
+        assert!(world.entity(child).contains::<Disabled>());
+        assert!(world.entity(child).contains::<InheritedDisabled>());
+
+        let other = world.spawn_empty().id();
+        world.entity_mut(other).insert(ChildOf(parent));
+        assert!(world.entity(other).contains::<Disabled>());
+
+        world.entity_mut(parent).remove::<Disabled>();
+        assert!(!world.entity(child).contains::<Disabled>());
+        assert!(!world.entity(other).contains::<Disabled>());
+    }
 }
@@ -1,7 +1,9 @@
 use crate as bevy_ecs;
 use alloc::vec::Vec;
 use bevy_ecs::{
-    event::{Event, EventCursor, EventId, EventInstance},
+    event::{
+        Event, EventCursor, EventId, EventInstance, EventRecording, EventSender, RecordedEvent,
+    },
     resource::Resource,
 };
 #[cfg(feature = "track_location")]
@@ -85,6 +87,21 @@ use {
 /// but can be done by adding your event as a resource instead of using
 /// [`add_event`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event).
 ///
+/// # Ordering
+///
+/// Systems which send the same type of event can't run at the same time, but if they aren't
+/// [ordered](https://github.com/bevyengine/bevy/blob/main/examples/ecs/ecs_guide.rs) with each
+/// other, the multi-threaded executor may run them in a different order on every run, and their
+/// events will be sent in a different order.
+/// When this matters, such as for replays or networked simulations, [ordered sending] can be
+/// enabled with [`set_ordered`](Events::set_ordered). Sent events are then held back until
+/// they're [applied](Events::apply_pending), which sorts them by their [`EventSender`], keeping
+/// the order of events from the same sender.
+///
+/// To debug the stream of events, it can be recorded and replayed using an [`EventRecording`].
+///
+/// [ordered sending]: Events::set_ordered
+///
 /// [Example usage.](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/event.rs)
 /// [Example usage standalone.](https://github.com/bevyengine/bevy/blob/latest/crates/bevy_ecs/examples/events.rs)
 ///
@@ -100,6 +117,11 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     pub(crate) events_b: EventSequence<E>,
     pub(crate) event_count: usize,
+    /// The events waiting to be applied, if ordered sending is enabled.
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    pending: Option<Vec<PendingEvent<E>>>,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    recorder: Option<EventRecorder<E>>,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -109,6 +131,8 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            pending: None,
+            recorder: None,
         }
     }
 }
@@ -131,11 +155,79 @@ impl<E: Event> Events<E> {
         )
     }
 
+    /// "Sends" an `event` on behalf of `sender`, by writing it to the current event buffer.
+    ///
+    /// This is the same as [`send`](Self::send), but when [ordered sending](Self::set_ordered)
+    /// is enabled, the event is ordered according to its sender, and recordings store the sender.
+    /// [`EventWriter`](super::EventWriter) uses this to send events on behalf of its system.
+    #[track_caller]
+    pub fn send_by(&mut self, event: E, sender: &EventSender) -> EventId<E> {
+        self.send_by_with_caller(
+            event,
+            sender,
+            #[cfg(feature = "track_location")]
+            Location::caller(),
+        )
+    }
+
     pub(crate) fn send_with_caller(
         &mut self,
         event: E,
         #[cfg(feature = "track_location")] caller: &'static Location<'static>,
     ) -> EventId<E> {
+        self.send_by_with_caller(
+            event,
+            &EventSender::UNKNOWN,
+            #[cfg(feature = "track_location")]
+            caller,
+        )
+    }
+
+    fn send_by_with_caller(
+        &mut self,
+        event: E,
+        sender: &EventSender,
+        #[cfg(feature = "track_location")] caller: &'static Location<'static>,
+    ) -> EventId<E> {
+        let Some(pending) = &mut self.pending else {
+            return self.apply_event(
+                event,
+                sender,
+                #[cfg(feature = "track_location")]
+                caller,
+            );
+        };
+
+        // The final ID is only known once pending events are sorted and applied
+        let event_id = EventId {
+            id: self.event_count + pending.len(),
+            #[cfg(feature = "track_location")]
+            caller,
+            _marker: PhantomData,
+        };
+        pending.push(PendingEvent {
+            event,
+            sender: sender.clone(),
+            #[cfg(feature = "track_location")]
+            caller,
+        });
+        event_id
+    }
+
+    fn apply_event(
+        &mut self,
+        event: E,
+        sender: &EventSender,
+        #[cfg(feature = "track_location")] caller: &'static Location<'static>,
+    ) -> EventId<E> {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.recording.events.push(RecordedEvent {
+                update: recorder.recording.update_count,
+                sender: sender.clone(),
+                event: (recorder.clone_event)(&event),
+            });
+        }
+
         let event_id = EventId {
             id: self.event_count,
             #[cfg(feature = "track_location")]
@@ -158,17 +250,142 @@ impl<E: Event> Events<E> {
     /// This method returns the [IDs](`EventId`) of the sent `events`.
     #[track_caller]
     pub fn send_batch(&mut self, events: impl IntoIterator<Item = E>) -> SendBatchIds<E> {
-        let last_count = self.event_count;
+        let last_count = self.next_event_count();
 
         self.extend(events);
 
         SendBatchIds {
             last_count,
-            event_count: self.event_count,
+            event_count: self.next_event_count(),
             _marker: PhantomData,
         }
     }
 
+    /// Sends a list of `events` all at once on behalf of `sender`.
+    ///
+    /// This is the same as [`send_batch`](Self::send_batch), but with a sender like
+    /// [`send_by`](Self::send_by).
+    #[track_caller]
+    pub fn send_batch_by(
+        &mut self,
+        events: impl IntoIterator<Item = E>,
+        sender: &EventSender,
+    ) -> SendBatchIds<E> {
+        if self.pending.is_none() && self.recorder.is_none() {
+            // The sender isn't used, so send the events efficiently
+            return self.send_batch(events);
+        }
+
+        let last_count = self.next_event_count();
+        for event in events {
+            self.send_by_with_caller(
+                event,
+                sender,
+                #[cfg(feature = "track_location")]
+                Location::caller(),
+            );
+        }
+
+        SendBatchIds {
+            last_count,
+            event_count: self.next_event_count(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The ID which the next sent event will be given.
+    fn next_event_count(&self) -> usize {
+        self.event_count + self.pending.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Enables or disables ordered sending.
+    ///
+    /// When enabled, sent events are held back until they're [applied](Self::apply_pending),
+    /// which happens automatically when the events are [updated](Self::update).
+    /// Applying sorts the events by their [`EventSender`], keeping the order in which events
+    /// from the same sender were sent, so that the order of events doesn't depend on the order
+    /// in which unordered systems happened to run.
+    /// Events sent by an unknown sender, such as with [`World::send_event`], come first.
+    ///
+    /// Note that as the events aren't readable until they're applied, they're only read by
+    /// [`EventReader`]s after the next update, and the [`EventId`]s returned when sending them
+    /// may not match the IDs they're given when they're applied.
+    ///
+    /// Disabling ordered sending applies any pending events.
+    ///
+    /// [`World::send_event`]: crate::world::World::send_event
+    /// [`EventReader`]: super::EventReader
+    pub fn set_ordered(&mut self, ordered: bool) {
+        if ordered {
+            self.pending.get_or_insert_with(Vec::new);
+        } else {
+            self.apply_pending();
+            self.pending = None;
+        }
+    }
+
+    /// Returns `true` if [ordered sending](Self::set_ordered) is enabled.
+    pub fn is_ordered(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Sorts the events held back by [ordered sending](Self::set_ordered) by their
+    /// [`EventSender`], and writes them to the current event buffer so they can be read.
+    ///
+    /// This is called automatically by [`update`](Self::update).
+    pub fn apply_pending(&mut self) {
+        let Some(pending) = &mut self.pending else {
+            return;
+        };
+        if pending.is_empty() {
+            return;
+        }
+
+        let mut pending = core::mem::take(pending);
+        // Stable sort, so events from the same sender keep the order they were sent in
+        pending.sort_by(|a, b| a.sender.cmp(&b.sender));
+        for PendingEvent {
+            event,
+            sender,
+            #[cfg(feature = "track_location")]
+            caller,
+        } in pending.drain(..)
+        {
+            self.apply_event(
+                event,
+                &sender,
+                #[cfg(feature = "track_location")]
+                caller,
+            );
+        }
+        // Reuse the allocation
+        self.pending = Some(pending);
+    }
+
+    /// Starts recording the events that are sent, replacing any existing recording.
+    ///
+    /// See [`EventRecording`] for details.
+    pub fn start_recording(&mut self)
+    where
+        E: Clone,
+    {
+        self.recorder = Some(EventRecorder {
+            recording: EventRecording::default(),
+            clone_event: E::clone,
+        });
+    }
+
+    /// Returns the current recording, if one was [started](Self::start_recording).
+    pub fn recording(&self) -> Option<&EventRecording<E>> {
+        self.recorder.as_ref().map(|recorder| &recorder.recording)
+    }
+
+    /// Stops recording the events that are sent, returning the recording if one was
+    /// [started](Self::start_recording).
+    pub fn stop_recording(&mut self) -> Option<EventRecording<E>> {
+        self.recorder.take().map(|recorder| recorder.recording)
+    }
+
     /// Sends the default value of the event. Useful when the event is an empty struct.
     /// This method returns the [ID](`EventId`) of the sent `event`.
     #[track_caller]
@@ -198,6 +415,8 @@ impl<E: Event> Events<E> {
     ///
     /// If you need access to the events that were removed, consider using [`Events::update_drain`].
     pub fn update(&mut self) {
+        self.apply_pending();
+        self.count_recorded_update();
         core::mem::swap(&mut self.events_a, &mut self.events_b);
         self.events_b.clear();
         self.events_b.start_event_count = self.event_count;
@@ -213,6 +432,8 @@ impl<E: Event> Events<E> {
     /// If you do not need to take ownership of the removed events, use [`Events::update`] instead.
     #[must_use = "If you do not need the returned events, call .update() instead."]
     pub fn update_drain(&mut self) -> impl Iterator<Item = E> + '_ {
+        self.apply_pending();
+        self.count_recorded_update();
         core::mem::swap(&mut self.events_a, &mut self.events_b);
         let iter = self.events_b.events.drain(..);
         self.events_b.start_event_count = self.event_count;
@@ -224,15 +445,24 @@ impl<E: Event> Events<E> {
         iter.map(|e| e.event)
     }
 
+    fn count_recorded_update(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.recording.update_count += 1;
+        }
+    }
+
     #[inline]
     fn reset_start_event_count(&mut self) {
         self.events_a.start_event_count = self.event_count;
         self.events_b.start_event_count = self.event_count;
     }
 
-    /// Removes all events.
+    /// Removes all events, including any which haven't been [applied](Self::apply_pending) yet.
     #[inline]
     pub fn clear(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            pending.clear();
+        }
         self.reset_start_event_count();
         self.events_a.clear();
         self.events_b.clear();
@@ -251,7 +481,10 @@ impl<E: Event> Events<E> {
     }
 
     /// Creates a draining iterator that removes all events.
+    ///
+    /// Any events which haven't been [applied](Self::apply_pending) yet are applied first.
     pub fn drain(&mut self) -> impl Iterator<Item = E> + '_ {
+        self.apply_pending();
         self.reset_start_event_count();
 
         // Drain the oldest events first, then the newest
@@ -303,6 +536,17 @@ impl<E: Event> Extend<E> for Events<E> {
     where
         I: IntoIterator<Item = E>,
     {
+        if self.pending.is_some() || self.recorder.is_some() {
+            for event in iter {
+                self.send_with_caller(
+                    event,
+                    #[cfg(feature = "track_location")]
+                    Location::caller(),
+                );
+            }
+            return;
+        }
+
         let old_count = self.event_count;
         let mut event_count = self.event_count;
         let events = iter.into_iter().map(|event| {
@@ -331,6 +575,22 @@ impl<E: Event> Extend<E> for Events<E> {
     }
 }
 
+/// An event held back by ordered sending, until it's applied.
+#[derive(Debug)]
+struct PendingEvent<E: Event> {
+    event: E,
+    sender: EventSender,
+    #[cfg(feature = "track_location")]
+    caller: &'static Location<'static>,
+}
+
+#[derive(Debug)]
+struct EventRecorder<E: Event> {
+    recording: EventRecording<E>,
+    // Stored so that sending events doesn't require `E: Clone`
+    clone_event: fn(&E) -> E,
+}
+
 #[derive(Debug)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub(crate) struct EventSequence<E: Event> {
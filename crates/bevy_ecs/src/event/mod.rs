@@ -6,7 +6,9 @@ mod iterators;
 mod mut_iterators;
 mod mutator;
 mod reader;
+mod recording;
 mod registry;
+mod sender;
 mod update;
 mod writer;
 
@@ -23,7 +25,9 @@ pub use mut_iterators::EventMutParIter;
 pub use mut_iterators::{EventMutIterator, EventMutIteratorWithId};
 pub use mutator::EventMutator;
 pub use reader::EventReader;
+pub use recording::{EventRecording, RecordedEvent};
 pub use registry::{EventRegistry, ShouldUpdateEvents};
+pub use sender::EventSender;
 pub use update::{
     event_update_condition, event_update_system, signal_event_update_system, EventUpdates,
 };
@@ -619,4 +623,130 @@ mod tests {
         });
         schedule.run(&mut world);
     }
+
+    #[test]
+    fn test_ordered_events_are_sorted_by_sender() {
+        let mut events = Events::<TestEvent>::default();
+        events.set_ordered(true);
+        let mut reader = events.get_cursor();
+
+        let sender_a = EventSender::system("a");
+        let sender_b = EventSender::system("b");
+        events.send_by(TestEvent { i: 0 }, &sender_b);
+        events.send_by(TestEvent { i: 1 }, &sender_a);
+        events.send_by(TestEvent { i: 2 }, &sender_b);
+        events.send(TestEvent { i: 3 });
+
+        // Events aren't readable until they're applied
+        assert!(events.is_empty());
+        assert_eq!(get_events(&events, &mut reader), vec![]);
+
+        events.update();
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![
+                TestEvent { i: 3 },
+                TestEvent { i: 1 },
+                TestEvent { i: 0 },
+                TestEvent { i: 2 },
+            ]
+        );
+
+        // Disabling ordering applies pending events, and sends events immediately afterwards
+        events.send_by(TestEvent { i: 4 }, &sender_b);
+        events.set_ordered(false);
+        events.send_by(TestEvent { i: 5 }, &sender_a);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![TestEvent { i: 4 }, TestEvent { i: 5 }]
+        );
+    }
+
+    #[test]
+    fn test_ordered_event_writers() {
+        use bevy_ecs::prelude::*;
+
+        fn send_from_b(mut writer: EventWriter<TestEvent>) {
+            writer.send(TestEvent { i: 0 });
+            writer.send_batch([TestEvent { i: 1 }, TestEvent { i: 2 }]);
+        }
+
+        fn send_from_a(mut writer: EventWriter<TestEvent>) {
+            writer.send(TestEvent { i: 3 });
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Events<TestEvent>>();
+        world.resource_mut::<Events<TestEvent>>().set_ordered(true);
+        let mut reader = world.resource::<Events<TestEvent>>().get_cursor();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((send_from_b, send_from_a).chain());
+        schedule.run(&mut world);
+
+        let mut events = world.resource_mut::<Events<TestEvent>>();
+        events.update();
+        assert_eq!(
+            get_events(&events, &mut reader),
+            vec![
+                TestEvent { i: 3 },
+                TestEvent { i: 0 },
+                TestEvent { i: 1 },
+                TestEvent { i: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_recording() {
+        let mut events = Events::<TestEvent>::default();
+        events.send(TestEvent { i: 0 });
+        events.start_recording();
+
+        let sender = EventSender::system("sender");
+        events.send_by(TestEvent { i: 1 }, &sender);
+        events.update();
+        events.update();
+        events.send_batch([TestEvent { i: 2 }, TestEvent { i: 3 }]);
+
+        let recording = events.stop_recording().unwrap();
+        assert_eq!(recording.update_count(), 2);
+        assert_eq!(
+            recording.iter().cloned().collect::<Vec<_>>(),
+            vec![
+                RecordedEvent {
+                    update: 0,
+                    sender: sender.clone(),
+                    event: TestEvent { i: 1 },
+                },
+                RecordedEvent {
+                    update: 2,
+                    sender: EventSender::UNKNOWN,
+                    event: TestEvent { i: 2 },
+                },
+                RecordedEvent {
+                    update: 2,
+                    sender: EventSender::UNKNOWN,
+                    event: TestEvent { i: 3 },
+                },
+            ]
+        );
+        assert_eq!(recording.events_in_update(1).count(), 0);
+
+        // Events sent after the recording stopped aren't recorded
+        events.send(TestEvent { i: 4 });
+        assert_eq!(recording.len(), 3);
+
+        let mut replayed = Events::<TestEvent>::default();
+        replayed.set_ordered(true);
+        replayed.start_recording();
+        recording.replay_update(0, &mut replayed);
+        replayed.update();
+        assert_eq!(
+            replayed.recording().unwrap().iter().next().unwrap().sender,
+            sender
+        );
+        let mut reader = replayed.get_cursor();
+        assert_eq!(get_events(&replayed, &mut reader), vec![TestEvent { i: 1 }]);
+    }
 }
@@ -0,0 +1,125 @@
+use crate as bevy_ecs;
+use alloc::vec::Vec;
+use bevy_ecs::event::{Event, EventSender, Events};
+use core::slice;
+
+/// A recording of the events sent to an [`Events`] collection, for debugging and replaying.
+///
+/// Recordings are started with [`Events::start_recording`], which records every event from then
+/// on along with its [`EventSender`] and the number of [`Events::update`]s since recording
+/// started. The recording can be inspected at any time with [`Events::recording`], and is
+/// returned by [`Events::stop_recording`].
+///
+/// Events are recorded in the order that they're applied, so when [ordered sending] is enabled,
+/// two runs which send the same events produce the same recording.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::event::{Event, EventSender, Events};
+///
+/// #[derive(Event, Clone, PartialEq, Debug)]
+/// struct MyEvent(u32);
+///
+/// let mut events = Events::<MyEvent>::default();
+/// events.start_recording();
+///
+/// events.send(MyEvent(1));
+/// events.update();
+/// events.send_by(MyEvent(2), &EventSender::system("my_system"));
+/// events.send(MyEvent(3));
+///
+/// let recording = events.stop_recording().unwrap();
+/// assert_eq!(recording.len(), 3);
+///
+/// // Replay the events sent during the second update
+/// let mut replayed = Events::<MyEvent>::default();
+/// recording.replay_update(1, &mut replayed);
+/// assert_eq!(
+///     replayed.iter_current_update_events().collect::<Vec<_>>(),
+///     [&MyEvent(2), &MyEvent(3)],
+/// );
+/// ```
+///
+/// [ordered sending]: Events::set_ordered
+#[derive(Debug, Clone)]
+pub struct EventRecording<E: Event> {
+    pub(super) events: Vec<RecordedEvent<E>>,
+    pub(super) update_count: usize,
+}
+
+// Derived Default impl would incorrectly require E: Default
+impl<E: Event> Default for EventRecording<E> {
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            update_count: 0,
+        }
+    }
+}
+
+/// An event in an [`EventRecording`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent<E: Event> {
+    /// The number of [`Events::update`]s between the start of the recording and this event.
+    pub update: usize,
+    /// Where the event was sent from.
+    pub sender: EventSender,
+    /// The event itself.
+    pub event: E,
+}
+
+impl<E: Event> EventRecording<E> {
+    /// Returns an iterator over the recorded events, in the order they were applied.
+    pub fn iter(&self) -> slice::Iter<'_, RecordedEvent<E>> {
+        self.events.iter()
+    }
+
+    /// Returns an iterator over the events which were recorded during the given update,
+    /// where `0` is the update in which the recording started.
+    pub fn events_in_update(&self, update: usize) -> impl Iterator<Item = &RecordedEvent<E>> {
+        let start = self.events.partition_point(|event| event.update < update);
+        self.events[start..]
+            .iter()
+            .take_while(move |event| event.update == update)
+    }
+
+    /// Returns the number of events in the recording.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the number of [`Events::update`]s which happened during the recording.
+    pub fn update_count(&self) -> usize {
+        self.update_count
+    }
+
+    /// Sends the events which were recorded during the given update to `events`, using the same
+    /// senders that they were originally sent with.
+    pub fn replay_update(&self, update: usize, events: &mut Events<E>)
+    where
+        E: Clone,
+    {
+        for recorded in self.events_in_update(update) {
+            events.send_by(recorded.event.clone(), &recorded.sender);
+        }
+    }
+
+    /// Consumes the recording, returning its events in the order they were applied.
+    pub fn into_events(self) -> Vec<RecordedEvent<E>> {
+        self.events
+    }
+}
+
+impl<'a, E: Event> IntoIterator for &'a EventRecording<E> {
+    type Item = &'a RecordedEvent<E>;
+    type IntoIter = slice::Iter<'a, RecordedEvent<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
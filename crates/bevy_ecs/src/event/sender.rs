@@ -0,0 +1,68 @@
+use crate::{
+    component::Tick,
+    prelude::World,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use alloc::borrow::Cow;
+
+/// Describes where an event was sent from.
+///
+/// Events sent by an [`EventWriter`] record the name of the system which sent them.
+/// When [ordered sending] is enabled, pending events are sorted by their sender before being
+/// applied, so that events sent by systems which ran in a different order still end up in the
+/// same order.
+///
+/// This can also be used as a [`SystemParam`] to get the sender of the current system, which is
+/// useful for sending events with [`Events::send_by`] from custom system params.
+///
+/// [`EventWriter`]: super::EventWriter
+/// [ordered sending]: super::Events::set_ordered
+/// [`Events::send_by`]: super::Events::send_by
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventSender {
+    system: Option<Cow<'static, str>>,
+}
+
+impl EventSender {
+    /// A sender which isn't known, such as when sending an event directly with
+    /// [`Events::send`](super::Events::send) or [`World::send_event`].
+    ///
+    /// Events from unknown senders are ordered before all others.
+    pub const UNKNOWN: Self = Self { system: None };
+
+    /// Creates a sender for the system with the given name.
+    pub fn system(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            system: Some(name.into()),
+        }
+    }
+
+    /// Returns the name of the system which sent the event, if it's known.
+    pub fn system_name(&self) -> Option<&str> {
+        self.system.as_deref()
+    }
+}
+
+// SAFETY: no component value access
+unsafe impl SystemParam for EventSender {
+    type State = EventSender;
+    type Item<'w, 's> = EventSender;
+
+    fn init_state(_world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        EventSender::system(system_meta.name.clone())
+    }
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        _world: UnsafeWorldCell<'w>,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        state.clone()
+    }
+}
+
+// SAFETY: Only reads internal system state
+unsafe impl ReadOnlySystemParam for EventSender {}
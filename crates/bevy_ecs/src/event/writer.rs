@@ -1,6 +1,6 @@
 use crate as bevy_ecs;
 use bevy_ecs::{
-    event::{Event, EventId, Events, SendBatchIds},
+    event::{Event, EventId, EventSender, Events, SendBatchIds},
     system::{ResMut, SystemParam},
 };
 
@@ -31,6 +31,10 @@ use bevy_ecs::{
 /// `EventWriter` param has [`ResMut<Events<T>>`](Events) inside. So two systems declaring `EventWriter<T>` params
 /// for the same event type won't be executed concurrently.
 ///
+/// Events are sent on behalf of the system's [`EventSender`], so if the order in which unordered
+/// systems send events matters, [ordered sending](Events::set_ordered) can be enabled to make it
+/// consistent between runs.
+///
 /// # Untyped events
 ///
 /// `EventWriter` can only send events of one specific type, which must be known at compile-time.
@@ -62,6 +66,7 @@ use bevy_ecs::{
 #[derive(SystemParam)]
 pub struct EventWriter<'w, E: Event> {
     events: ResMut<'w, Events<E>>,
+    sender: EventSender,
 }
 
 impl<'w, E: Event> EventWriter<'w, E> {
@@ -71,7 +76,7 @@ impl<'w, E: Event> EventWriter<'w, E> {
     /// See [`Events`] for details.
     #[track_caller]
     pub fn send(&mut self, event: E) -> EventId<E> {
-        self.events.send(event)
+        self.events.send_by(event, &self.sender)
     }
 
     /// Sends a list of `events` all at once, which can later be read by [`EventReader`](super::EventReader)s.
@@ -81,7 +86,7 @@ impl<'w, E: Event> EventWriter<'w, E> {
     /// See [`Events`] for details.
     #[track_caller]
     pub fn send_batch(&mut self, events: impl IntoIterator<Item = E>) -> SendBatchIds<E> {
-        self.events.send_batch(events)
+        self.events.send_batch_by(events, &self.sender)
     }
 
     /// Sends the default value of the event. Useful when the event is an empty struct.
@@ -93,6 +98,6 @@ impl<'w, E: Event> EventWriter<'w, E> {
     where
         E: Default,
     {
-        self.events.send_default()
+        self.events.send_by(E::default(), &self.sender)
     }
 }
@@ -16,6 +16,7 @@ use crate::{
     bundle::Bundle,
     component::{Component, HookContext},
     entity::{Entity, VisitEntities},
+    entity_disabling,
     relationship::{RelatedSpawner, RelatedSpawnerCommands},
     system::EntityCommands,
     world::{DeferredWorld, EntityWorldMut, FromWorld, World},
@@ -105,6 +106,7 @@ use log::warn;
     )
 )]
 #[relationship(relationship_target = Children)]
+#[component(on_insert = entity_disabling::disable_new_child)]
 pub struct ChildOf(pub Entity);
 
 impl ChildOf {
@@ -0,0 +1,224 @@
+//! Indexes for looking up entities by the value of one of their components.
+//! See the [`IndexedComponent`] trait for more info.
+
+use core::hash::Hash;
+
+use bevy_platform_support::collections::HashMap;
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, HookContext, Immutable},
+    entity::{hash_set::EntityHashSet, Entity},
+    query::{QueryData, QueryFilter, ROQueryItem},
+    resource::Resource,
+    system::Query,
+    world::{DeferredWorld, FromWorld, World},
+};
+
+/// An immutable [`Component`] whose values are tracked in a [`ComponentIndex`], so that entities with a given
+/// value can be found without iterating over every entity with the component.
+///
+/// The index is kept up to date by "component hooks" whenever the component is inserted, replaced or removed,
+/// which is why indexed components must be [immutable](crate::component::Immutable).
+///
+/// [`IndexedComponent`] should always be derived via the [`Component`] trait to ensure the hooks are set up properly.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// #[component(index)]
+/// enum Team {
+///     Red,
+///     Blue,
+/// }
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// fn count_red_health(index: Res<ComponentIndex<Team>>, units: Query<&Health>) {
+///     let total = units
+///         .iter_with_value(&index, &Team::Red)
+///         .map(|health| health.0)
+///         .sum::<u32>();
+///     println!("The red team has {total} health");
+/// }
+/// # bevy_ecs::system::assert_is_system(count_red_health);
+/// ```
+pub trait IndexedComponent: Component<Mutability = Immutable> + Clone + Eq + Hash {
+    /// The `on_insert` component hook that adds the entity to the [`ComponentIndex`].
+    fn on_insert(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let value = world.entity(entity).get::<Self>().unwrap().clone();
+        if let Some(mut index) = world.get_resource_mut::<ComponentIndex<Self>>() {
+            index.add(value, entity);
+        } else {
+            // The index is built from all existing entities when it's initialized.
+            world.commands().init_resource::<ComponentIndex<Self>>();
+        }
+    }
+
+    /// The `on_replace` component hook that removes the entity from the [`ComponentIndex`].
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+        let value = world.entity(entity).get::<Self>().unwrap().clone();
+        if let Some(mut index) = world.get_resource_mut::<ComponentIndex<Self>>() {
+            index.remove(&value, entity);
+        }
+    }
+}
+
+/// A [`Resource`] mapping each value of the [`IndexedComponent`] `C` to the entities that have it.
+///
+/// This is created the first time `C` is inserted on an entity. If it might be read before that happens,
+/// initialize it with [`World::init_resource`] beforehand, which indexes all existing entities.
+#[derive(Resource, Debug)]
+pub struct ComponentIndex<C: IndexedComponent> {
+    entities: HashMap<C, EntityHashSet>,
+}
+
+impl<C: IndexedComponent> ComponentIndex<C> {
+    /// Iterates the entities that have the component with the given `value`, in no particular order.
+    pub fn get(&self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.entities
+            .get(value)
+            .into_iter()
+            .flat_map(|entities| entities.iter().copied())
+    }
+
+    /// Returns `true` if any entity has the component with the given `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.entities.contains_key(value)
+    }
+
+    /// Returns the number of entities that have the component with the given `value`.
+    pub fn count(&self, value: &C) -> usize {
+        self.entities.get(value).map_or(0, EntityHashSet::len)
+    }
+
+    /// Iterates all values of the component that at least one entity has.
+    pub fn values(&self) -> impl Iterator<Item = &C> + '_ {
+        self.entities.keys()
+    }
+
+    fn add(&mut self, value: C, entity: Entity) {
+        self.entities.entry(value).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(value);
+            }
+        }
+    }
+}
+
+impl<C: IndexedComponent> FromWorld for ComponentIndex<C> {
+    fn from_world(world: &mut World) -> Self {
+        let mut index = Self {
+            entities: HashMap::default(),
+        };
+        for (entity, value) in world.query::<(Entity, &C)>().iter(world) {
+            index.add(value.clone(), entity);
+        }
+        index
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
+    /// Iterates the query items of all entities that have the [`IndexedComponent`] `C` with the given `value`,
+    /// using its [`ComponentIndex`] instead of checking every entity.
+    ///
+    /// Entities that don't match this query are skipped. To iterate mutably, pass [`ComponentIndex::get`]
+    /// to [`Query::iter_many_mut`].
+    pub fn iter_with_value<'a, C: IndexedComponent>(
+        &'a self,
+        index: &'a ComponentIndex<C>,
+        value: &C,
+    ) -> impl Iterator<Item = ROQueryItem<'a, D>> + 'a {
+        self.iter_many(index.get(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        index::ComponentIndex,
+        system::{Query, Res, RunSystemOnce},
+        world::World,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+    #[component(index)]
+    enum Team {
+        Red,
+        Blue,
+    }
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    #[test]
+    fn index_tracks_values() {
+        let mut world = World::new();
+        let a = world.spawn(Team::Red).id();
+        let b = world.spawn(Team::Red).id();
+        let c = world.spawn(Team::Blue).id();
+
+        let sorted = |world: &World, team| {
+            let mut entities = world
+                .resource::<ComponentIndex<Team>>()
+                .get(&team)
+                .collect::<Vec<_>>();
+            entities.sort();
+            entities
+        };
+        assert_eq!(sorted(&world, Team::Red), [a, b]);
+        assert_eq!(sorted(&world, Team::Blue), [c]);
+
+        world.entity_mut(a).insert(Team::Blue);
+        world.entity_mut(b).remove::<Team>();
+        world.despawn(c);
+        assert!(sorted(&world, Team::Red).is_empty());
+        assert_eq!(sorted(&world, Team::Blue), [a]);
+        assert!(!world
+            .resource::<ComponentIndex<Team>>()
+            .contains(&Team::Red));
+    }
+
+    #[test]
+    fn init_index_with_existing_entities() {
+        let mut world = World::new();
+        world.spawn(Team::Red);
+        world.remove_resource::<ComponentIndex<Team>>();
+        world.spawn(Team::Red);
+        // The index is rebuilt from both entities, not only the new one.
+        world.flush();
+        assert_eq!(
+            world.resource::<ComponentIndex<Team>>().count(&Team::Red),
+            2
+        );
+    }
+
+    #[test]
+    fn query_with_value() {
+        let mut world = World::new();
+        world.spawn((Team::Red, Health(1)));
+        world.spawn((Team::Red, Health(2)));
+        world.spawn((Team::Blue, Health(4)));
+        world.spawn(Team::Red);
+
+        let total = world
+            .run_system_once(|index: Res<ComponentIndex<Team>>, units: Query<&Health>| {
+                units
+                    .iter_with_value(&index, &Team::Red)
+                    .map(|health| health.0)
+                    .sum::<u32>()
+            })
+            .unwrap();
+        assert_eq!(total, 3);
+    }
+}
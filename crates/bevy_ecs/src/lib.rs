@@ -43,6 +43,7 @@ pub mod entity_disabling;
 pub mod event;
 pub mod hierarchy;
 pub mod identifier;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod name;
@@ -78,9 +79,10 @@ pub mod prelude {
         entity::{Entity, EntityBorrow, EntityMapper},
         event::{Event, EventMutator, EventReader, EventWriter, Events},
         hierarchy::{ChildOf, ChildSpawner, ChildSpawnerCommands, Children},
+        index::ComponentIndex,
         name::{Name, NameOrEntity},
         observer::{CloneEntityWithObserversExt, Observer, Trigger},
-        query::{Added, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
+        query::{Added, Allows, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
         removal_detection::RemovedComponents,
         resource::Resource,
         result::{Error, Result},
@@ -134,6 +136,7 @@ mod tests {
         change_detection::Ref,
         component::{require, Component, ComponentId, RequiredComponents, RequiredComponentsError},
         entity::Entity,
+        entity_disabling::DISABLED,
         prelude::Or,
         query::{Added, Changed, FilteredAccess, QueryFilter, With, Without},
         resource::Resource,
@@ -793,6 +796,19 @@ mod tests {
         assert_eq!(values, expected);
     }
 
+    #[test]
+    fn spawn_batch_with() {
+        let mut world = World::new();
+        world.spawn_batch_with(TableStored("abc"), (0..100).map(A));
+        let values = world
+            .query::<(&A, &TableStored)>()
+            .iter(&world)
+            .map(|(a, s)| (a.0, s.0))
+            .collect::<Vec<_>>();
+        let expected = (0..100).map(|x| (x, "abc")).collect::<Vec<_>>();
+        assert_eq!(values, expected);
+    }
+
     #[test]
     fn query_get() {
         let mut world = World::new();
@@ -1537,6 +1553,9 @@ mod tests {
         let b_id = world.components.get_id(TypeId::of::<B>()).unwrap();
         expected.add_component_write(a_id);
         expected.add_component_read(b_id);
+        // Every world registers `Disabled` as a default query filter, so queries that don't
+        // mention it implicitly exclude disabled entities.
+        expected.and_without(DISABLED);
         assert!(
             query.component_access.eq(&expected),
             "ComponentId access from query fetch and query filter should be combined"
@@ -1575,6 +1594,33 @@ mod tests {
         assert_eq!(world.resource::<A>().0, 1);
     }
 
+    #[test]
+    fn resource_scope_transactional() {
+        let mut world = World::default();
+        world.insert_resource(A(0));
+        world.increment_change_tick();
+
+        let result = world.resource_scope_transactional(|_, mut value: Mut<A>| {
+            value.0 += 1;
+            Ok::<_, ()>(value.0)
+        });
+        assert_eq!(result, Ok(1));
+        assert_eq!(world.resource::<A>().0, 1);
+
+        world.clear_trackers();
+        let mut entity = None;
+        let result = world.resource_scope_transactional(|world, mut value: Mut<A>| {
+            value.0 += 1;
+            entity = Some(world.spawn(B(1)).id());
+            Err::<(), _>("cancelled")
+        });
+        assert_eq!(result, Err("cancelled"));
+        assert_eq!(world.resource::<A>().0, 1);
+        assert!(!world.is_resource_changed::<A>());
+        // Only the resource is reverted.
+        assert_eq!(world.get::<B>(entity.unwrap()), Some(&B(1)));
+    }
+
     #[test]
     #[should_panic(
         expected = "Attempted to access or drop non-send resource bevy_ecs::tests::NonSendA from thread"
@@ -2561,6 +2607,118 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn runtime_required_components_override_propagates_up() {
+        #[derive(Component)]
+        #[require(B)]
+        struct A;
+
+        #[derive(Component, Default)]
+        #[require(Counter(|| Counter(1)))]
+        struct B;
+
+        #[derive(Component)]
+        struct Counter(i32);
+
+        let mut world = World::new();
+
+        // Overriding a `require` attribute doesn't fail like a duplicate registration,
+        // and is seen by `A`, which requires `B`.
+        world.override_required_components::<B, Counter>(|| Counter(2));
+
+        let id = world.spawn(A).id();
+        assert_eq!(world.entity(id).get::<Counter>().unwrap().0, 2);
+    }
+
+    #[test]
+    fn runtime_required_components_remove() {
+        #[derive(Component)]
+        #[require(B)]
+        struct A;
+
+        #[derive(Component, Default)]
+        #[require(C, D)]
+        struct B;
+
+        #[derive(Component, Default)]
+        struct C;
+
+        #[derive(Component, Default)]
+        #[require(C)]
+        struct D;
+
+        let mut world = World::new();
+
+        world.remove_required_components::<B, D>();
+        // `C` is still required directly by `B`.
+        let id = world.spawn(A).id();
+        assert!(world.entity(id).contains::<B>());
+        assert!(world.entity(id).contains::<C>());
+        assert!(!world.entity(id).contains::<D>());
+
+        // `D` is no longer directly required.
+        assert!(matches!(
+            world.try_remove_required_components::<B, D>(),
+            Err(RequiredComponentsError::NotDirectlyRequired(_, _))
+        ));
+    }
+
+    #[test]
+    fn runtime_required_components_override_fails() {
+        #[derive(Component, Default)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        #[derive(Component, Default)]
+        struct Z;
+
+        let mut world = World::new();
+
+        // `Y` can't require `X`, since `X` already requires `Y`.
+        assert!(matches!(
+            world.try_override_required_components::<Y, X>(X::default),
+            Err(RequiredComponentsError::CyclicRequirement(_, _))
+        ));
+    }
+
+    #[test]
+    fn runtime_required_components_change_after_spawn() {
+        #[derive(Component, Default)]
+        #[require(Y)]
+        struct X;
+
+        #[derive(Component, Default)]
+        struct Y;
+
+        #[derive(Component, Default)]
+        struct Z;
+
+        let mut world = World::new();
+        let existing = world.spawn(X).id();
+        let inserted = world.spawn_empty().id();
+        world.entity_mut(inserted).insert(X);
+
+        world.remove_required_components::<X, Y>();
+        world.override_required_components::<X, Z>(Z::default);
+
+        // Existing entities keep their components.
+        assert!(world.entity(existing).contains::<Y>());
+        assert!(!world.entity(existing).contains::<Z>());
+
+        // New entities use the new requirements, both for cached bundles and archetype edges.
+        let spawned = world.spawn(X).id();
+        assert!(!world.entity(spawned).contains::<Y>());
+        assert!(world.entity(spawned).contains::<Z>());
+
+        let inserted = world.spawn_empty().id();
+        world.entity_mut(inserted).insert(X);
+        assert!(!world.entity(inserted).contains::<Y>());
+        assert!(world.entity(inserted).contains::<Z>());
+    }
+
     #[test]
     fn required_components_inheritance_depth() {
         // Test that inheritance depths are computed correctly for requirements.
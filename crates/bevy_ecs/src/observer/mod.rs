@@ -142,6 +142,13 @@ impl<'w, E, B: Bundle> Trigger<'w, E, B> {
         *self.propagate
     }
 
+    /// Returns when the commands queued by this observer are applied.
+    ///
+    /// See [`ObserverFlush`] for more information.
+    pub fn flush(&self) -> ObserverFlush {
+        self.trigger.flush
+    }
+
     /// Returns the source code location that triggered this observer.
     #[cfg(feature = "track_location")]
     pub fn caller(&self) -> &'static Location<'static> {
@@ -277,6 +284,9 @@ pub struct ObserverDescriptor {
 
     /// The entities the observer is watching.
     entities: Vec<Entity>,
+
+    /// When the commands queued by the observer are applied.
+    flush: ObserverFlush,
 }
 
 impl ObserverDescriptor {
@@ -301,14 +311,72 @@ impl ObserverDescriptor {
         self
     }
 
+    /// Set when the commands queued by the observer are applied.
+    pub fn with_flush(mut self, flush: ObserverFlush) -> Self {
+        self.flush = flush;
+        self
+    }
+
+    /// Returns when the commands queued by the observer are applied.
+    pub fn flush(&self) -> ObserverFlush {
+        self.flush
+    }
+
     pub(crate) fn merge(&mut self, descriptor: &ObserverDescriptor) {
         self.events.extend(descriptor.events.iter().copied());
         self.components
             .extend(descriptor.components.iter().copied());
         self.entities.extend(descriptor.entities.iter().copied());
+        self.flush = descriptor.flush;
     }
 }
 
+/// Controls when the commands queued by an [`Observer`] are applied.
+///
+/// Observers can't access the [`World`] exclusively, so structural changes such as spawning and
+/// despawning entities are queued as [`Commands`]. By default, these are applied at the world's
+/// next flush, which may be in the middle of applying the commands that triggered the observer.
+/// Observers that would rather not interleave their changes with the rest of the trigger chain can
+/// defer them further.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, observer::ObserverFlush};
+/// # let mut world = World::default();
+/// #[derive(Event)]
+/// struct Explode;
+///
+/// #[derive(Component)]
+/// struct Debris;
+///
+/// world.spawn(
+///     Observer::new(|_: Trigger<Explode>, mut commands: Commands| {
+///         commands.spawn(Debris);
+///     })
+///     .with_flush(ObserverFlush::EndOfSchedule),
+/// );
+/// world.flush();
+///
+/// world.trigger(Explode);
+/// world.flush();
+/// assert_eq!(world.query::<&Debris>().iter(&world).count(), 0);
+///
+/// world.flush_end_of_schedule_commands();
+/// assert_eq!(world.query::<&Debris>().iter(&world).count(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ObserverFlush {
+    /// Commands are applied at the world's next flush, like any other commands queued on the
+    /// world.
+    #[default]
+    Immediate,
+    /// Commands are applied once the world's command queue has been fully flushed, after every
+    /// command and observer resulting from the outermost command being applied.
+    EndOfTriggerChain,
+    /// Commands are applied when the outermost running [`Schedule`](crate::schedule::Schedule)
+    /// completes, or by [`World::flush_end_of_schedule_commands`].
+    EndOfSchedule,
+}
+
 /// Event trigger metadata for a given [`Observer`],
 #[derive(Debug)]
 pub struct ObserverTrigger {
@@ -320,6 +388,8 @@ pub struct ObserverTrigger {
     components: SmallVec<[ComponentId; 2]>,
     /// The entity the trigger targeted.
     pub target: Entity,
+    /// When the commands queued by the observer are applied.
+    flush: ObserverFlush,
 
     /// The location of the source code that triggered the obserer.
     #[cfg(feature = "track_location")]
@@ -331,6 +401,11 @@ impl ObserverTrigger {
     pub fn components(&self) -> &[ComponentId] {
         &self.components
     }
+
+    /// Returns when the commands queued by the observer are applied.
+    pub fn flush(&self) -> ObserverFlush {
+        self.flush
+    }
 }
 
 // Map between an observer entity and its runner
@@ -425,6 +500,7 @@ impl Observers {
                     event_type,
                     components: components.clone().collect(),
                     target,
+                    flush: ObserverFlush::Immediate,
                     #[cfg(feature = "track_location")]
                     caller,
                 },
@@ -852,7 +928,7 @@ mod tests {
     use crate as bevy_ecs;
     use crate::component::ComponentId;
     use crate::{
-        observer::{Observer, ObserverDescriptor, ObserverState, OnReplace},
+        observer::{Observer, ObserverDescriptor, ObserverFlush, ObserverState, OnReplace},
         prelude::*,
         traversal::Traversal,
     };
@@ -1664,4 +1740,110 @@ mod tests {
         assert_eq!(4, *counter.0.get(&a_id).unwrap());
         assert_eq!(3, *counter.0.get(&b_id).unwrap());
     }
+
+    #[test]
+    fn observer_flush_end_of_trigger_chain() {
+        #[derive(Event)]
+        struct EventB;
+
+        fn observed(name: &'static str) -> impl FnOnce(&mut World) + Send + 'static {
+            move |world: &mut World| world.resource_mut::<Order>().observed(name)
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.add_observer(|_: Trigger<EventA>, mut commands: Commands| {
+            commands.trigger(EventB);
+            commands.queue(observed("a"));
+        });
+        world.add_observer(|_: Trigger<EventB>, mut commands: Commands| {
+            commands.queue(observed("b"));
+        });
+        world.spawn(
+            Observer::new(|trigger: Trigger<EventB>, mut commands: Commands| {
+                assert_eq!(trigger.flush(), ObserverFlush::EndOfTriggerChain);
+                commands.queue(observed("b_deferred"));
+            })
+            .with_flush(ObserverFlush::EndOfTriggerChain),
+        );
+        world.flush();
+
+        world.trigger(EventA);
+        world.flush();
+        assert_eq!(vec!["b", "a", "b_deferred"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    fn observer_flush_end_of_schedule() {
+        use crate::schedule::Schedule;
+
+        #[derive(Resource, Default)]
+        struct Counts(Vec<usize>);
+
+        let mut world = World::new();
+        world.init_resource::<Counts>();
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut commands: Commands| {
+                commands.spawn(A);
+            })
+            .with_flush(ObserverFlush::EndOfSchedule),
+        );
+        world.flush();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                |mut commands: Commands| commands.trigger(EventA),
+                |query: Query<&A>, mut counts: ResMut<Counts>| counts.0.push(query.iter().len()),
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+
+        assert_eq!(vec![0], world.resource::<Counts>().0);
+        assert_eq!(1, world.query::<&A>().iter(&world).len());
+    }
+
+    #[test]
+    fn observer_flush_end_of_outermost_schedule() {
+        use crate::schedule::{Schedule, ScheduleLabel};
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Inner;
+
+        #[derive(Resource, Default)]
+        struct Counts(Vec<usize>);
+
+        let mut world = World::new();
+        world.init_resource::<Counts>();
+        world.spawn(
+            Observer::new(|_: Trigger<EventA>, mut commands: Commands| {
+                commands.spawn(A);
+            })
+            .with_flush(ObserverFlush::EndOfSchedule),
+        );
+        world.flush();
+
+        let mut inner = Schedule::new(Inner);
+        inner.add_systems(|mut commands: Commands| commands.trigger(EventA));
+        world.add_schedule(inner);
+
+        // The commands are applied when the outer schedule completes, not the inner one.
+        let mut outer = Schedule::default();
+        outer.add_systems(
+            (
+                |world: &mut World| world.run_schedule(Inner),
+                |query: Query<&A>, mut counts: ResMut<Counts>| counts.0.push(query.iter().len()),
+            )
+                .chain(),
+        );
+        outer.run(&mut world);
+
+        assert_eq!(vec![0], world.resource::<Counts>().0);
+        assert_eq!(1, world.query::<&A>().iter(&world).len());
+
+        // Running the inner schedule on its own still applies them.
+        world.run_schedule(Inner);
+        assert_eq!(2, world.query::<&A>().iter(&world).len());
+    }
 }
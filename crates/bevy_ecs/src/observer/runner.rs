@@ -3,7 +3,7 @@ use core::any::Any;
 
 use crate::{
     component::{ComponentHook, ComponentHooks, ComponentId, HookContext, Mutable, StorageType},
-    observer::{ObserverDescriptor, ObserverTrigger},
+    observer::{ObserverDescriptor, ObserverFlush, ObserverTrigger},
     prelude::*,
     query::DebugCheckedUnwrap,
     system::{IntoObserverSystem, ObserverSystem},
@@ -59,6 +59,12 @@ impl ObserverState {
         self.descriptor.components.extend(components);
         self
     }
+
+    /// Apply the commands queued by the [`Observer`] at the given point. See [`ObserverFlush`] for more information.
+    pub fn with_flush(mut self, flush: ObserverFlush) -> Self {
+        self.descriptor.flush = flush;
+        self
+    }
 }
 
 impl Component for ObserverState {
@@ -312,6 +318,13 @@ impl Observer {
         self.descriptor.events.push(event);
         self
     }
+
+    /// Apply the commands queued by this [`Observer`] at the given point, instead of at the world's next flush.
+    /// See [`ObserverFlush`] for more information.
+    pub fn with_flush(mut self, flush: ObserverFlush) -> Self {
+        self.descriptor.flush = flush;
+        self
+    }
 }
 
 impl Component for Observer {
@@ -330,7 +343,7 @@ impl Component for Observer {
 
 fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
     mut world: DeferredWorld,
-    observer_trigger: ObserverTrigger,
+    mut observer_trigger: ObserverTrigger,
     ptr: PtrMut,
     propagate: &mut bool,
 ) {
@@ -354,6 +367,8 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
         return;
     }
     state.last_trigger_id = last_trigger;
+    let flush = state.descriptor.flush;
+    observer_trigger.flush = flush;
 
     let trigger: Trigger<E, B> = Trigger::new(
         // SAFETY: Caller ensures `ptr` is castable to `&mut T`
@@ -379,7 +394,18 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
         (*system).update_archetype_component_access(world);
         if (*system).validate_param_unsafe(world) {
             (*system).run_unsafe(trigger, world);
-            (*system).queue_deferred(world.into_deferred());
+            match flush {
+                ObserverFlush::Immediate => (*system).queue_deferred(world.into_deferred()),
+                ObserverFlush::EndOfTriggerChain | ObserverFlush::EndOfSchedule => {
+                    // Commands are always queued on the world's command queue, so the ones queued
+                    // by this observer are moved to the deferred queue afterwards.
+                    let mut command_queue = world.get_raw_command_queue();
+                    let start = command_queue.len_bytes();
+                    (*system).queue_deferred(world.into_deferred());
+                    command_queue
+                        .move_since(start, &mut world.get_raw_observer_command_queue(flush));
+                }
+            }
         }
     }
 }
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+use bevy_platform_support::sync::{Arc, Mutex, Weak};
+
+use crate::{
+    archetype::{ArchetypeEntity, ArchetypeId},
+    component::ComponentId,
+    entity::{hash_set::EntityHashSet, Entity, EntityIndexSet},
+    observer::{Observer, Trigger},
+    query::{QueryData, QueryFilter, QueryState},
+    system::Commands,
+    world::{unsafe_world_cell::UnsafeWorldCell, OnAdd, OnRemove, World},
+};
+
+/// Memoizes the entities matched by a [`QueryState`], so that iterating them does not need to
+/// visit every matched archetype.
+///
+/// A [`QueryState`] already caches the list of archetypes it matches, but iterating it still
+/// walks every one of them. For narrow filters in a world with thousands of archetypes, most of
+/// those are empty or hold few entities. A `QueryCache` instead keeps the list of matching entities
+/// itself, and only re-checks entities that gained or lost one of the components that decide
+/// whether they match. These are tracked by observers, which add a small cost to every insertion
+/// and removal of those components.
+///
+/// The cache only tracks archetypal matching. Non-archetypal filters such as
+/// [`Changed`](crate::query::Changed) are still evaluated for every cached entity when iterating.
+///
+/// If the query can match entities that have none of the components it accesses, such as
+/// `Query<Option<&T>>`, changes cannot be tracked and the cache is rebuilt on every update.
+///
+/// Most users should use [`CachedQuery`](crate::system::CachedQuery) instead of this type.
+///
+/// The observers spawned by a `QueryCache` stop recording changes once the cache is dropped,
+/// and despawn themselves the next time they are triggered.
+#[derive(Debug)]
+pub struct QueryCache {
+    entities: EntityIndexSet,
+    changed: Option<Arc<Mutex<EntityHashSet>>>,
+    initialized: bool,
+}
+
+impl QueryCache {
+    /// Creates an empty cache for the entities matched by `state`, spawning the observers that
+    /// track changes to them.
+    ///
+    /// The cache is filled by the first call to [`update`](Self::update).
+    pub fn new<D: QueryData, F: QueryFilter>(world: &mut World, state: &QueryState<D, F>) -> Self {
+        let access = state.component_access();
+
+        // An entity can only start or stop matching by gaining or losing one of these components,
+        // as long as every way of matching requires at least one component.
+        let trackable = !access.filter_sets.is_empty()
+            && access.filter_sets.iter().all(|filters| {
+                access.required.count_ones(..) > 0 || filters.with.count_ones(..) > 0
+            });
+        if !trackable {
+            return Self {
+                entities: EntityIndexSet::new(),
+                changed: None,
+                initialized: false,
+            };
+        }
+
+        let mut components = access.required.clone();
+        for filters in &access.filter_sets {
+            components.union_with(&filters.with);
+            components.union_with(&filters.without);
+        }
+        let components: Vec<ComponentId> = components.ones().map(ComponentId::new).collect();
+
+        let changed = Arc::new(Mutex::new(EntityHashSet::default()));
+        let on_add = Arc::downgrade(&changed);
+        let on_add = Observer::new(move |trigger: Trigger<OnAdd>, commands: Commands| {
+            record_change(&on_add, trigger.target(), trigger.observer(), commands);
+        });
+        let on_remove = Arc::downgrade(&changed);
+        let on_remove = Observer::new(move |trigger: Trigger<OnRemove>, commands: Commands| {
+            record_change(&on_remove, trigger.target(), trigger.observer(), commands);
+        });
+        let (on_add, on_remove) =
+            components
+                .iter()
+                .fold((on_add, on_remove), |(on_add, on_remove), &component| {
+                    (
+                        on_add.with_component(component),
+                        on_remove.with_component(component),
+                    )
+                });
+        world.spawn(on_add);
+        world.spawn(on_remove);
+
+        Self {
+            entities: EntityIndexSet::new(),
+            changed: Some(changed),
+            initialized: false,
+        }
+    }
+
+    /// Returns the cached entities, as of the last call to [`update`](Self::update).
+    pub fn entities(&self) -> &EntityIndexSet {
+        &self.entities
+    }
+
+    /// Brings the cached entities up to date with the `world`.
+    ///
+    /// `state` must be the state the cache was created for, and must be
+    /// [updated](QueryState::update_archetypes) for the archetypes of the `world`.
+    pub fn update<D: QueryData, F: QueryFilter>(
+        &mut self,
+        world: &World,
+        state: &QueryState<D, F>,
+    ) {
+        self.update_unsafe_world_cell(world.as_unsafe_world_cell_readonly(), state);
+    }
+
+    /// Brings the cached entities up to date with the `world`.
+    ///
+    /// This only reads the entity and archetype metadata of the `world`.
+    pub(crate) fn update_unsafe_world_cell<D: QueryData, F: QueryFilter>(
+        &mut self,
+        world: UnsafeWorldCell,
+        state: &QueryState<D, F>,
+    ) {
+        state.validate_world(world.id());
+
+        let changed = match &self.changed {
+            Some(changed) if self.initialized => changed,
+            _ => {
+                // Changes recorded before the first full scan are already included in it.
+                if let Some(changed) = &self.changed {
+                    changed.lock().unwrap().clear();
+                }
+                self.entities.clear();
+                for archetype_id in state.matched_archetypes.ones() {
+                    let archetype = &world.archetypes()[ArchetypeId::new(archetype_id)];
+                    self.entities
+                        .extend(archetype.entities().iter().map(ArchetypeEntity::id));
+                }
+                self.initialized = true;
+                return;
+            }
+        };
+
+        for entity in changed.lock().unwrap().drain() {
+            let matches = world.entities().get(entity).is_some_and(|location| {
+                state
+                    .matched_archetypes
+                    .contains(location.archetype_id.index())
+            });
+            if matches {
+                self.entities.insert(entity);
+            } else {
+                self.entities.swap_remove(&entity);
+            }
+        }
+    }
+}
+
+/// Records that `entity` gained or lost a tracked component, or despawns the `observer` if the
+/// [`QueryCache`] it records changes for has been dropped.
+fn record_change(
+    changed: &Weak<Mutex<EntityHashSet>>,
+    entity: Entity,
+    observer: Entity,
+    mut commands: Commands,
+) {
+    match changed.upgrade() {
+        Some(changed) => {
+            changed.lock().unwrap().insert(entity);
+        }
+        None => commands.entity(observer).despawn(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        observer::Observer,
+        prelude::*,
+        query::{QueryCache, QueryState},
+        system::CachedQuery,
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct C;
+
+    #[test]
+    fn cache_tracks_changes() {
+        let mut world = World::new();
+        let a = world.spawn(A).id();
+        let ab = world.spawn((A, B)).id();
+        world.spawn(B);
+
+        let mut state = QueryState::<Entity, (With<A>, Without<C>)>::new(&mut world);
+        let mut cache = QueryCache::new(&mut world, &state);
+        cache.update(&world, &state);
+        assert_eq!(cache.entities().len(), 2);
+
+        world.entity_mut(a).insert(C);
+        world.entity_mut(ab).remove::<A>();
+        let new = world.spawn((A, B)).id();
+        let despawned = world.spawn(A).id();
+        world.despawn(despawned);
+
+        state.update_archetypes(&world);
+        cache.update(&world, &state);
+        assert_eq!(cache.entities().iter().copied().collect::<Vec<_>>(), [new]);
+    }
+
+    #[test]
+    fn dropped_cache_despawns_observers() {
+        let mut world = World::new();
+        let mut observers = world.query_filtered::<(), With<Observer>>();
+
+        let state = QueryState::<Entity, With<A>>::new(&mut world);
+        let cache = QueryCache::new(&mut world, &state);
+        let count = observers.iter(&world).count();
+        assert!(count > 0);
+
+        drop(cache);
+        world.spawn(A).remove::<A>();
+        world.flush();
+        assert_eq!(observers.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn cache_without_required_components_rescans() {
+        let mut world = World::new();
+        world.spawn(A);
+
+        let mut state = QueryState::<Option<&A>>::new(&mut world);
+        let mut cache = QueryCache::new(&mut world, &state);
+        cache.update(&world, &state);
+        let count = cache.entities().len();
+
+        world.spawn(B);
+        state.update_archetypes(&world);
+        cache.update(&world, &state);
+        assert_eq!(cache.entities().len(), count + 1);
+    }
+
+    #[test]
+    fn cached_query_iterates_cached_entities() {
+        #[derive(Resource)]
+        struct Count(usize);
+
+        fn count(query: CachedQuery<&A, Without<C>>, mut count: ResMut<Count>) {
+            count.0 = query.iter().count();
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Count(0));
+        let mut system = IntoSystem::into_system(count);
+        system.initialize(&mut world);
+
+        world.spawn(A);
+        let ac = world.spawn(A).id();
+        system.run((), &mut world);
+        assert_eq!(world.resource::<Count>().0, 2);
+
+        world.entity_mut(ac).insert(C);
+        system.run((), &mut world);
+        assert_eq!(world.resource::<Count>().0, 1);
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     storage::{ComponentSparseSet, Table, TableRow},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
+use alloc::vec::Vec;
 use bevy_ptr::{ThinSlicePtr, UnsafeCellDeref};
 use core::{cell::UnsafeCell, marker::PhantomData};
 use variadics_please::all_tuples;
@@ -330,6 +331,109 @@ unsafe impl<T: Component> QueryFilter for Without<T> {
     }
 }
 
+/// Filter that allows entities with a component `T`, without requiring or excluding them.
+///
+/// Entities with a [default query filter](crate::entity_disabling::DefaultQueryFilters) component,
+/// such as [`Disabled`](crate::entity_disabling::Disabled), are skipped by every query that doesn't
+/// mention that component. Adding `Allows<T>` to a query includes those entities again, alongside
+/// all entities without `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::entity_disabling::Disabled;
+/// # use bevy_ecs::query::Allows;
+/// # use bevy_ecs::system::Query;
+/// # use bevy_ecs::component::Component;
+/// #
+/// # #[derive(Component)]
+/// # struct Name { name: &'static str };
+/// #
+/// fn list_all_entities_system(query: Query<&Name, Allows<Disabled>>) {
+///     for name in &query {
+///         println!("{} exists, but might be disabled", name.name);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(list_all_entities_system);
+/// ```
+pub struct Allows<T>(PhantomData<T>);
+
+/// SAFETY:
+/// `update_component_access` only adds archetypal access for `T`, which does not read it.
+/// This is sound because `fetch` does not access any components.
+/// `matches_component_set` always returns `true`, since `T` is neither required nor excluded.
+unsafe impl<T: Component> WorldQuery for Allows<T> {
+    type Item<'w> = ();
+    type Fetch<'w> = ();
+    type State = ComponentId;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(_: Self::Item<'wlong>) -> Self::Item<'wshort> {}
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(_: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {}
+
+    #[inline]
+    unsafe fn init_fetch(
+        _world: UnsafeWorldCell,
+        _state: &ComponentId,
+        _last_run: Tick,
+        _this_run: Tick,
+    ) {
+    }
+
+    const IS_DENSE: bool = true;
+
+    #[inline]
+    unsafe fn set_archetype(
+        _fetch: &mut (),
+        _state: &ComponentId,
+        _archetype: &Archetype,
+        _table: &Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table(_fetch: &mut (), _state: &Self::State, _table: &Table) {}
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+    }
+
+    #[inline]
+    fn update_component_access(&id: &ComponentId, access: &mut FilteredAccess<ComponentId>) {
+        access.access_mut().add_archetypal(id);
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.register_component::<T>()
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        components.component_id::<T>()
+    }
+
+    fn matches_component_set(_: &ComponentId, _: &impl Fn(ComponentId) -> bool) -> bool {
+        true
+    }
+}
+
+// SAFETY: WorldQuery impl performs no access at all
+unsafe impl<T: Component> QueryFilter for Allows<T> {
+    const IS_ARCHETYPAL: bool = true;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        _fetch: &mut Self::Fetch<'_>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        true
+    }
+}
+
 /// A filter that tests if any of the given filters apply.
 ///
 /// This is useful for example if a system with multiple components in a query only wants to run
@@ -1044,6 +1148,177 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
     }
 }
 
+/// A filter on a query that only retains results where any of a runtime-provided list of
+/// components have changed since the last time the system ran.
+///
+/// Unlike an [`Or`] of [`Changed`] filters, the components don't need to be known at compile
+/// time. This is useful for editors and replication, which need to know whether anything on an
+/// entity changed, for components that are only known at runtime.
+///
+/// The components are given as [`ComponentId`]s to [`QueryState::any_changed`](crate::query::QueryState::any_changed).
+/// Entities don't need to have all of the components to match.
+///
+/// `AnyChanged` must be the whole filter of the query, not part of a tuple or [`Or`].
+///
+/// # Panics
+///
+/// Panics if the list of components is empty. In particular, `AnyChanged` can't be used in a
+/// [`Query`](crate::system::Query) system parameter or a [`QueryBuilder`](crate::query::QueryBuilder),
+/// as there is no way to give it any components.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::AnyChanged;
+/// #
+/// # #[derive(Component)]
+/// # struct A;
+/// #
+/// # #[derive(Component)]
+/// # struct B;
+/// #
+/// let mut world = World::new();
+/// let a = world.register_component::<A>();
+/// let b = world.register_component::<B>();
+/// let entity = world.spawn(A).id();
+///
+/// let mut query = QueryState::<Entity, AnyChanged>::any_changed(&mut world, &[a, b]);
+///
+/// // Newly added components count as changed.
+/// assert_eq!(query.single(&world), entity);
+///
+/// world.clear_trackers();
+/// assert!(query.get_single(&world).is_err());
+///
+/// world.entity_mut(entity).insert(B);
+/// assert_eq!(query.single(&world), entity);
+/// ```
+pub struct AnyChanged;
+
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct AnyChangedFetch<'w> {
+    world: UnsafeWorldCell<'w>,
+    components: Vec<ComponentId>,
+    last_run: Tick,
+    this_run: Tick,
+}
+
+/// SAFETY:
+/// `fetch` reads the change ticks of the components in the state in a readonly way.
+/// This is sound because `update_component_access` adds read access for those components and panics when appropriate.
+/// `update_component_access` doesn't add any filters.
+/// This is sound because `matches_component_set` always returns `true`.
+unsafe impl WorldQuery for AnyChanged {
+    type Item<'w> = bool;
+    type Fetch<'w> = AnyChangedFetch<'w>;
+    type State = Vec<ComponentId>;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        fetch
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Vec<ComponentId>,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        AnyChangedFetch {
+            world,
+            components: state.clone(),
+            last_run,
+            this_run,
+        }
+    }
+
+    const IS_DENSE: bool = false;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _state: &Vec<ComponentId>,
+        _archetype: &'w Archetype,
+        _table: &'w Table,
+    ) {
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(
+        _fetch: &mut Self::Fetch<'w>,
+        _state: &Vec<ComponentId>,
+        _table: &'w Table,
+    ) {
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        _table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: `fetch` must be called with an entity that exists in the world
+        let cell = unsafe { fetch.world.get_entity(entity).debug_checked_unwrap() };
+        fetch.components.iter().any(|&id| {
+            // SAFETY: Read access to the component was registered in `update_component_access`.
+            // Filters are evaluated before any references to the components are created.
+            unsafe { cell.get_change_ticks_by_id(id) }
+                .is_some_and(|ticks| ticks.is_changed(fetch.last_run, fetch.this_run))
+        })
+    }
+
+    #[inline]
+    fn update_component_access(state: &Vec<ComponentId>, access: &mut FilteredAccess<ComponentId>) {
+        assert!(
+            !state.is_empty(),
+            "AnyChanged needs at least one component. Create the query with `QueryState::any_changed`."
+        );
+        for &id in state {
+            if access.access().has_component_write(id) {
+                panic!("AnyChanged conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.");
+            }
+            // Entities don't need to have the component, so don't add a `With` filter for it.
+            access.access_mut().add_component_read(id);
+        }
+    }
+
+    fn init_state(_world: &mut World) -> Vec<ComponentId> {
+        Vec::new()
+    }
+
+    fn get_state(_components: &Components) -> Option<Vec<ComponentId>> {
+        Some(Vec::new())
+    }
+
+    fn matches_component_set(
+        _state: &Vec<ComponentId>,
+        _set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        true
+    }
+}
+
+// SAFETY: WorldQuery impl performs only read access on ticks
+unsafe impl QueryFilter for AnyChanged {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The invariants are uphold by the caller.
+        unsafe { Self::fetch(fetch, entity, table_row) }
+    }
+}
+
 /// A marker trait to indicate that the filter works at an archetype level.
 ///
 /// This is needed to implement [`ExactSizeIterator`] for
@@ -1054,7 +1329,7 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
 /// [Tuples](prim@tuple) and [`Or`] filters are automatically implemented with the trait only if its containing types
 /// also implement the same trait.
 ///
-/// [`Added`] and [`Changed`] works with entities, and therefore are not archetypal. As such
+/// [`Added`], [`Changed`] and [`AnyChanged`] works with entities, and therefore are not archetypal. As such
 /// they do not implement [`ArchetypeFilter`].
 #[diagnostic::on_unimplemented(
     message = "`{Self}` is not a valid `Query` filter based on archetype information",
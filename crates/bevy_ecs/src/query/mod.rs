@@ -2,22 +2,26 @@
 
 mod access;
 mod builder;
+mod cache;
 mod error;
 mod fetch;
 mod filter;
 mod iter;
 mod par_iter;
+mod slice;
 mod state;
 mod world_query;
 
 pub use access::*;
 pub use bevy_ecs_macros::{QueryData, QueryFilter};
 pub use builder::*;
+pub use cache::*;
 pub use error::*;
 pub use fetch::*;
 pub use filter::*;
 pub use iter::*;
 pub use par_iter::*;
+pub use slice::*;
 pub use state::*;
 pub use world_query::*;
 
@@ -109,13 +113,13 @@ mod tests {
         component::{Component, ComponentId, Components, Tick},
         prelude::{AnyOf, Changed, Entity, Or, QueryState, Res, ResMut, Resource, With, Without},
         query::{
-            ArchetypeFilter, FilteredAccess, Has, QueryCombinationIter, QueryData,
+            AnyChanged, ArchetypeFilter, FilteredAccess, Has, QueryCombinationIter, QueryData,
             ReadOnlyQueryData, WorldQuery,
         },
         schedule::{IntoSystemConfigs, Schedule},
         storage::{Table, TableRow},
         system::{assert_is_system, IntoSystem, Query, System, SystemState},
-        world::{unsafe_world_cell::UnsafeWorldCell, World},
+        world::{unsafe_world_cell::UnsafeWorldCell, EntityRef, World},
     };
     use alloc::{vec, vec::Vec};
     use bevy_ecs_macros::QueryFilter;
@@ -494,6 +498,47 @@ mod tests {
         assert!(values.contains(&(&A(4), false)));
     }
 
+    #[test]
+    fn any_changed_query() {
+        let mut world = World::new();
+        let entity_a = world.spawn(A(0)).id();
+        let entity_b = world.spawn((A(0), B(0))).id();
+        world.spawn(C(0));
+        let component_id_a = world.register_component::<A>();
+        let component_id_b = world.register_component::<B>();
+
+        let mut query = QueryState::<Entity, AnyChanged>::any_changed(
+            &mut world,
+            &[component_id_a, component_id_b],
+        );
+        let mut changed = query.iter(&world).collect::<Vec<_>>();
+        changed.sort();
+        assert_eq!(changed, [entity_a, entity_b]);
+
+        // Only the given components are watched, not everything the query data reads.
+        let mut entity_ref_query =
+            QueryState::<EntityRef, AnyChanged>::any_changed(&mut world, &[component_id_b]);
+        assert_eq!(entity_ref_query.single(&world).id(), entity_b);
+
+        world.clear_trackers();
+        assert_eq!(query.iter(&world).count(), 0);
+        assert_eq!(entity_ref_query.iter(&world).count(), 0);
+
+        world.get_mut::<A>(entity_a).unwrap().0 = 1;
+        assert_eq!(query.single(&world), entity_a);
+        assert_eq!(entity_ref_query.iter(&world).count(), 0);
+    }
+
+    #[test]
+    #[should_panic = "AnyChanged needs at least one component."]
+    fn any_changed_without_components() {
+        fn system(_query: Query<&A, AnyChanged>) {}
+
+        let mut world = World::new();
+        let mut system = IntoSystem::into_system(system);
+        system.initialize(&mut world);
+    }
+
     #[test]
     #[should_panic = "&mut bevy_ecs::query::tests::A conflicts with a previous access in this query."]
     fn self_conflicting_worldquery() {
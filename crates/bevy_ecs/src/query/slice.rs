@@ -0,0 +1,401 @@
+use crate::{
+    component::{Component, ComponentId, Mutable, Tick},
+    entity::Entity,
+    query::{DebugCheckedUnwrap, QueryData, QueryFilter, QueryState, StorageId},
+    storage::{Table, Tables},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+use variadics_please::all_tuples;
+
+#[cfg(feature = "track_location")]
+use core::panic::Location;
+
+/// [`QueryData`] which can be fetched as contiguous slices of every matched table, using
+/// [`Query::iter_slices`](crate::system::Query::iter_slices).
+///
+/// This is implemented for [`Entity`], `&T`, `&mut T` and tuples of these.
+///
+/// # Safety
+///
+/// [`fetch_slices`](Self::fetch_slices) must only access the data that `Self` registers access
+/// to in [`WorldQuery::update_component_access`](crate::query::WorldQuery::update_component_access),
+/// and must only access it mutably if it registers write access.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can not be fetched as slices of table columns",
+    label = "invalid `Query` data for `iter_slices`",
+    note = "only `Entity`, `&T`, `&mut T` and tuples of them can be fetched as slices"
+)]
+pub unsafe trait QuerySliceData: QueryData {
+    /// The slices fetched for a single table.
+    type Slices<'w>;
+
+    /// Fetches the slices of every entity in `table`.
+    ///
+    /// # Safety
+    ///
+    /// - `table` must be matched by a query using `state`, and every component of `Self` must be
+    ///   stored in tables.
+    /// - The caller must have the access to `table` that `Self` registers, and no conflicting
+    ///   borrows of the data in `table` may exist for `'w`.
+    unsafe fn fetch_slices<'w>(
+        state: &Self::State,
+        table: &'w Table,
+        this_run: Tick,
+    ) -> Self::Slices<'w>;
+}
+
+// SAFETY: only the entities of the table are accessed, which are always readable
+unsafe impl QuerySliceData for Entity {
+    type Slices<'w> = &'w [Entity];
+
+    unsafe fn fetch_slices<'w>(_state: &(), table: &'w Table, _this_run: Tick) -> &'w [Entity] {
+        table.entities()
+    }
+}
+
+// SAFETY: only the column of `T` is read, which `&T` registers read access to
+unsafe impl<T: Component> QuerySliceData for &T {
+    type Slices<'w> = &'w [T];
+
+    unsafe fn fetch_slices<'w>(
+        &component_id: &ComponentId,
+        table: &'w Table,
+        _this_run: Tick,
+    ) -> &'w [T] {
+        // SAFETY: The caller ensures that `T` is stored in tables, and that the table is matched,
+        // so the column exists and stores values of `T`.
+        let values = unsafe {
+            table
+                .get_data_slice_for::<T>(component_id)
+                .debug_checked_unwrap()
+        };
+        // SAFETY: `UnsafeCell<T>` has the same layout as `T`, and the caller ensures that nothing
+        // is writing to the column.
+        unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<T>(), values.len()) }
+    }
+}
+
+// SAFETY: only the column of `T` and its change ticks are written, which `&mut T` registers write
+// access to
+unsafe impl<'__w, T: Component<Mutability = Mutable>> QuerySliceData for &'__w mut T {
+    type Slices<'w> = MutSlice<'w, T>;
+
+    unsafe fn fetch_slices<'w>(
+        &component_id: &ComponentId,
+        table: &'w Table,
+        this_run: Tick,
+    ) -> MutSlice<'w, T> {
+        // SAFETY: The caller ensures that `T` is stored in tables, and that the table is matched,
+        // so the column exists and stores values of `T`.
+        let (values, changed_ticks) = unsafe {
+            (
+                table
+                    .get_data_slice_for::<T>(component_id)
+                    .debug_checked_unwrap(),
+                table
+                    .get_changed_ticks_slice_for(component_id)
+                    .debug_checked_unwrap(),
+            )
+        };
+        MutSlice {
+            // SAFETY: `UnsafeCell<T>` has the same layout as `T`, and the caller ensures that
+            // nothing else is accessing the column.
+            values: unsafe {
+                core::slice::from_raw_parts_mut(UnsafeCell::raw_get(values.as_ptr()), values.len())
+            },
+            changed_ticks,
+            #[cfg(feature = "track_location")]
+            // SAFETY: The column exists, as checked above.
+            changed_by: unsafe {
+                table
+                    .get_changed_by_slice_for(component_id)
+                    .debug_checked_unwrap()
+            },
+            this_run,
+        }
+    }
+}
+
+macro_rules! impl_tuple_query_slice_data {
+    ($(#[$meta:meta])* $($name: ident),*) => {
+        $(#[$meta])*
+        #[expect(
+            clippy::allow_attributes,
+            reason = "This is a tuple-related macro; as such the lints below may not always apply."
+        )]
+        #[allow(
+            non_snake_case,
+            reason = "The names of some variables are provided by the macro's caller, not by us."
+        )]
+        #[allow(
+            unused_variables,
+            reason = "Zero-length tuples won't use any of the parameters."
+        )]
+        #[allow(
+            clippy::unused_unit,
+            reason = "Zero-length tuples will generate some function bodies equivalent to `()`; however, this macro is meant for all applicable tuples, and as such it makes no sense to rewrite it just for that case."
+        )]
+        // SAFETY: defers to the soundness of the `QuerySliceData` impls of the tuple items
+        unsafe impl<$($name: QuerySliceData),*> QuerySliceData for ($($name,)*) {
+            type Slices<'w> = ($($name::Slices<'w>,)*);
+
+            unsafe fn fetch_slices<'w>(
+                state: &Self::State,
+                table: &'w Table,
+                this_run: Tick,
+            ) -> Self::Slices<'w> {
+                let ($($name,)*) = state;
+                // SAFETY: The invariants are upheld by the caller.
+                ($(unsafe { $name::fetch_slices($name, table, this_run) },)*)
+            }
+        }
+    };
+}
+
+all_tuples!(
+    #[doc(fake_variadic)]
+    impl_tuple_query_slice_data,
+    0,
+    15,
+    F
+);
+
+/// A mutable slice of the values of a component in a table, returned by
+/// [`Query::iter_slices_mut`](crate::system::Query::iter_slices_mut).
+///
+/// Like [`Mut`](crate::world::Mut), this marks the values as changed when it is dereferenced
+/// mutably. Since the slice is accessed as a whole, every value in it is marked as changed.
+pub struct MutSlice<'w, T> {
+    values: &'w mut [T],
+    changed_ticks: &'w [UnsafeCell<Tick>],
+    #[cfg(feature = "track_location")]
+    changed_by: &'w [UnsafeCell<&'static Location<'static>>],
+    this_run: Tick,
+}
+
+impl<'w, T> MutSlice<'w, T> {
+    /// Returns the values without marking them as changed.
+    ///
+    /// Note that this can lead to systems which rely on change detection missing these changes.
+    pub fn bypass_change_detection(&mut self) -> &mut [T] {
+        self.values
+    }
+
+    /// Consumes this slice and returns the values, marking them as changed.
+    #[track_caller]
+    pub fn into_inner(mut self) -> &'w mut [T] {
+        self.set_changed();
+        self.values
+    }
+
+    #[track_caller]
+    fn set_changed(&mut self) {
+        for tick in self.changed_ticks {
+            // SAFETY: `MutSlice` has exclusive access to the ticks of its values.
+            unsafe { *tick.get() = self.this_run };
+        }
+        #[cfg(feature = "track_location")]
+        for changed_by in self.changed_by {
+            // SAFETY: `MutSlice` has exclusive access to the locations of its values.
+            unsafe { *changed_by.get() = Location::caller() };
+        }
+    }
+}
+
+impl<'w, T> Deref for MutSlice<'w, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.values
+    }
+}
+
+impl<'w, T> DerefMut for MutSlice<'w, T> {
+    #[track_caller]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.set_changed();
+        self.values
+    }
+}
+
+impl<'w, T: fmt::Debug> fmt::Debug for MutSlice<'w, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MutSlice").field(&self.values).finish()
+    }
+}
+
+/// An [`Iterator`] over the slices of every table matched by a query, returned by
+/// [`Query::iter_slices`](crate::system::Query::iter_slices) and
+/// [`Query::iter_slices_mut`](crate::system::Query::iter_slices_mut).
+///
+/// Empty tables are skipped.
+pub struct QuerySliceIter<'w, 's, D: QuerySliceData, F: QueryFilter> {
+    tables: &'w Tables,
+    table_ids: core::slice::Iter<'s, StorageId>,
+    state: &'s QueryState<D, F>,
+    this_run: Tick,
+}
+
+impl<'w, 's, D: QuerySliceData, F: QueryFilter> QuerySliceIter<'w, 's, D, F> {
+    /// # Panics
+    ///
+    /// Panics if the query is not dense, meaning that some of its components are stored in
+    /// sparse sets.
+    ///
+    /// # Safety
+    ///
+    /// - `world` must have permission to access the components registered in `state`.
+    /// - `world` must be the same one used to initialize `state`.
+    #[track_caller]
+    pub(crate) unsafe fn new(
+        world: UnsafeWorldCell<'w>,
+        state: &'s QueryState<D, F>,
+        this_run: Tick,
+    ) -> Self {
+        assert!(
+            state.is_dense,
+            "Query slices can only be fetched when every component of the query is stored in tables"
+        );
+        Self {
+            // SAFETY: The tables are only used to access the data that `state` has access to.
+            tables: unsafe { &world.storages().tables },
+            table_ids: state.matched_storage_ids.iter(),
+            state,
+            this_run,
+        }
+    }
+}
+
+impl<'w, 's, D: QuerySliceData, F: QueryFilter> Iterator for QuerySliceIter<'w, 's, D, F> {
+    type Item = D::Slices<'w>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // SAFETY: The state is dense, so `matched_storage_ids` stores `TableId`s.
+            let table_id = unsafe { self.table_ids.next()?.table_id };
+            let table = &self.tables[table_id];
+            if table.is_empty() {
+                continue;
+            }
+            // SAFETY:
+            // - The state is dense, so every component of `D` is stored in tables, and every
+            //   entity in its matched tables is matched.
+            // - The caller of `new` ensures that the world has access to the data of `D`, and
+            //   every table is only visited once.
+            return Some(unsafe { D::fetch_slices(&self.state.fetch_state, table, self.this_run) });
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.table_ids.len()))
+    }
+}
+
+impl<'w, 's, D: QuerySliceData, F: QueryFilter> core::iter::FusedIterator
+    for QuerySliceIter<'w, 's, D, F>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, component::Component, prelude::*, system::RunSystemOnce};
+
+    #[derive(Component, Clone, Copy, Debug, PartialEq)]
+    struct Position(f32);
+
+    #[derive(Component, Clone, Copy)]
+    struct Velocity(f32);
+
+    #[derive(Component)]
+    struct Frozen;
+
+    #[derive(Component)]
+    struct Other;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Sparse;
+
+    #[test]
+    fn iter_slices_per_table() {
+        fn integrate(
+            mut query: Query<(&mut Position, &Velocity), Without<Frozen>>,
+            mut tables: Local<usize>,
+        ) {
+            for (mut positions, velocities) in query.iter_slices_mut() {
+                for (position, velocity) in positions.iter_mut().zip(velocities) {
+                    position.0 += velocity.0;
+                }
+                *tables += 1;
+            }
+            assert_eq!(*tables, 2);
+        }
+
+        let mut world = World::new();
+        let a = world.spawn((Position(0.0), Velocity(1.0))).id();
+        let b = world.spawn((Position(1.0), Velocity(2.0))).id();
+        let c = world.spawn((Position(0.0), Velocity(3.0), Sparse)).id();
+        let d = world.spawn((Position(0.0), Velocity(1.0), Other)).id();
+        let frozen = world.spawn((Position(0.0), Velocity(1.0), Frozen)).id();
+
+        world.run_system_once(integrate).unwrap();
+
+        let position = |entity| *world.get::<Position>(entity).unwrap();
+        assert_eq!(position(a), Position(1.0));
+        assert_eq!(position(b), Position(3.0));
+        assert_eq!(position(c), Position(3.0));
+        assert_eq!(position(d), Position(1.0));
+        assert_eq!(position(frozen), Position(0.0));
+    }
+
+    #[test]
+    fn iter_slices_marks_changed() {
+        fn bypass(mut query: Query<&mut Position>) {
+            for mut positions in query.iter_slices_mut() {
+                positions.bypass_change_detection()[0].0 = 1.0;
+            }
+        }
+
+        fn set(mut query: Query<&mut Position>) {
+            for mut positions in query.iter_slices_mut() {
+                positions[0].0 = 2.0;
+            }
+        }
+
+        fn count_changed(query: Query<(), Changed<Position>>) -> usize {
+            query.iter().count()
+        }
+
+        let mut world = World::new();
+        world.spawn(Position(0.0));
+        world.spawn(Position(0.0));
+
+        // Run the counting system once, so that later runs only see changes made after it.
+        let mut count_changed = IntoSystem::into_system(count_changed);
+        count_changed.initialize(&mut world);
+        count_changed.run((), &mut world);
+
+        world.run_system_once(bypass).unwrap();
+        assert_eq!(count_changed.run((), &mut world), 0);
+
+        world.run_system_once(set).unwrap();
+        assert_eq!(count_changed.run((), &mut world), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_slices_sparse_panics() {
+        fn slices(query: Query<Entity, With<Sparse>>) {
+            query.iter_slices().count();
+        }
+
+        let mut world = World::new();
+        world.spawn(Sparse);
+        world.run_system_once(slices).unwrap();
+    }
+}
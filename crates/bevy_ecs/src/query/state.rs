@@ -6,8 +6,8 @@ use crate::{
     entity_disabling::DefaultQueryFilters,
     prelude::FromWorld,
     query::{
-        Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, QueryParIter,
-        WorldQuery,
+        Access, AnyChanged, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter,
+        QueryParIter, WorldQuery,
     },
     storage::{SparseSetIndex, TableId},
     world::{unsafe_world_cell::UnsafeWorldCell, World, WorldId},
@@ -1885,6 +1885,23 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     }
 }
 
+impl<D: QueryData> QueryState<D, AnyChanged> {
+    /// Creates a new [`QueryState`] that only matches entities where any of the given
+    /// `components` changed since the last time the system ran.
+    ///
+    /// See [`AnyChanged`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `components` is empty.
+    pub fn any_changed(world: &mut World, components: &[ComponentId]) -> Self {
+        let fetch_state = D::init_state(world);
+        let mut state = Self::from_states_uninitialized(world, fetch_state, components.to_vec());
+        state.update_archetypes(world);
+        state
+    }
+}
+
 impl<D: QueryData, F: QueryFilter> From<QueryBuilder<'_, D, F>> for QueryState<D, F> {
     fn from(mut value: QueryBuilder<D, F>) -> Self {
         QueryState::from_builder(&mut value)
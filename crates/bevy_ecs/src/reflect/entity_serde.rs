@@ -0,0 +1,126 @@
+use alloc::boxed::Box;
+use core::any::TypeId;
+
+use crate::{
+    entity::{hash_map::EntityHashMap, Entity, EntityMapper},
+    reflect::ReflectMapEntities,
+};
+use bevy_reflect::{
+    serde::{ReflectDeserializerProcessor, ReflectSerializerProcessor, TypedReflectDeserializer},
+    PartialReflect, TypeRegistration, TypeRegistry,
+};
+use serde::{de::DeserializeSeed, Deserialize, Deserializer, Serializer};
+
+/// A [`ReflectSerializerProcessor`] which writes every reflected [`Entity`] as a stable identifier.
+///
+/// An [`Entity`] is only meaningful inside the world it came from. When given an entity map,
+/// this processor replaces each entity with the identifier it is mapped to (for example, a
+/// scene-local ID) before writing it, so that the serialized data no longer depends on how the
+/// source world allocated its entities. Entities missing from the map are written unchanged.
+///
+/// Values are written in the same format as the [`Entity`] `Serialize` implementation, so the
+/// output can be read back with [`EntityDeserializerProcessor`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::{entity::{hash_map::EntityHashMap, Entity}, reflect::EntitySerializerProcessor};
+/// # use bevy_reflect::{serde::ReflectSerializer, TypeRegistry};
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Entity>();
+///
+/// let entity = Entity::from_raw(42);
+/// let mut stable_ids = EntityHashMap::default();
+/// stable_ids.insert(entity, Entity::from_raw(0));
+///
+/// let processor = EntitySerializerProcessor::with_entity_map(&stable_ids);
+/// let serializer = ReflectSerializer::with_processor(&entity, &registry, &processor);
+/// # let _ = serializer;
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EntitySerializerProcessor<'a> {
+    entity_map: Option<&'a EntityHashMap<Entity>>,
+}
+
+impl<'a> EntitySerializerProcessor<'a> {
+    /// Creates a processor which writes entities unchanged.
+    pub fn new() -> Self {
+        Self { entity_map: None }
+    }
+
+    /// Creates a processor which writes entities as the identifiers they are mapped to in
+    /// `entity_map`.
+    pub fn with_entity_map(entity_map: &'a EntityHashMap<Entity>) -> Self {
+        Self {
+            entity_map: Some(entity_map),
+        }
+    }
+}
+
+impl ReflectSerializerProcessor for EntitySerializerProcessor<'_> {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        _registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(&entity) = value.try_downcast_ref::<Entity>() else {
+            return Ok(Err(serializer));
+        };
+        let entity = self
+            .entity_map
+            .and_then(|map| map.get(&entity).copied())
+            .unwrap_or(entity);
+        serializer.serialize_u64(entity.to_bits()).map(Ok)
+    }
+}
+
+/// A [`ReflectDeserializerProcessor`] which remaps every deserialized [`Entity`] through an
+/// [`EntityMapper`].
+///
+/// Bare [`Entity`] values are mapped as soon as they are read. Values whose type registers
+/// [`ReflectMapEntities`] are deserialized as normal and then remapped as a whole using their
+/// [`MapEntities`] implementation, which also covers entities stored in opaque types.
+///
+/// Since entities are mapped during deserialization, values produced with this processor must
+/// not be mapped again afterwards, e.g. by [`ReflectMapEntities`].
+///
+/// [`MapEntities`]: crate::entity::MapEntities
+pub struct EntityDeserializerProcessor<'m> {
+    mapper: &'m mut dyn EntityMapper,
+}
+
+impl<'m> EntityDeserializerProcessor<'m> {
+    /// Creates a processor which remaps entities using `mapper`.
+    pub fn new(mapper: &'m mut dyn EntityMapper) -> Self {
+        Self { mapper }
+    }
+}
+
+impl ReflectDeserializerProcessor for EntityDeserializerProcessor<'_> {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if registration.type_id() == TypeId::of::<Entity>() {
+            let entity = Entity::deserialize(deserializer)?;
+            return Ok(Ok(Box::new(self.mapper.map_entity(entity))));
+        }
+
+        let Some(map_entities) = registration.data::<ReflectMapEntities>() else {
+            return Ok(Err(deserializer));
+        };
+        let mut value =
+            TypedReflectDeserializer::new(registration, registry).deserialize(deserializer)?;
+        map_entities.map_entities(value.as_mut(), self.mapper);
+        Ok(Ok(value))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::world::World;
+use bevy_reflect::func::{ArgError, ArgList};
+
+/// An extension trait for [`ArgList`] for providing the [`World`] to reflected functions.
+///
+/// Functions which need access to the world should declare it as a [`Capability`],
+/// which is validated before the function is called.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::reflect::ArgListWorldExt;
+/// # use bevy_reflect::func::{ArgList, Capability, DynamicFunction, IntoReturn, SignatureInfo};
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// // A function which can be called from a scripting console
+/// let spawn_enemy = DynamicFunction::new(
+///     |mut args| {
+///         let world = args.take_world()?;
+///         let health = args.take::<f32>()?;
+///         Ok(world.spawn(Health(health)).id().into_return())
+///     },
+///     SignatureInfo::named("spawn_enemy")
+///         .with_arg::<f32>("health")
+///         .with_return::<Entity>(),
+/// )
+/// .with_capability(Capability::write::<World>());
+///
+/// let mut world = World::new();
+/// let args = ArgList::new().with_owned(10.0_f32).with_world(&mut world);
+/// let enemy = spawn_enemy.call(args).unwrap().unwrap_owned();
+/// let enemy = enemy.try_take::<Entity>().unwrap();
+/// assert_eq!(world.get::<Health>(enemy).unwrap().0, 10.0);
+/// ```
+///
+/// [`Capability`]: bevy_reflect::func::Capability
+pub trait ArgListWorldExt<'a> {
+    /// Provide mutable access to the [`World`] alongside the arguments.
+    ///
+    /// This satisfies both `Capability::read::<World>()` and `Capability::write::<World>()`.
+    fn with_world(self, world: &'a mut World) -> Self;
+
+    /// Provide immutable access to the [`World`] alongside the arguments.
+    ///
+    /// This only satisfies `Capability::read::<World>()`.
+    fn with_world_ref(self, world: &'a World) -> Self;
+
+    /// Remove the [`World`] provided with [`with_world`] and return it as `Ok(&mut World)`.
+    ///
+    /// If the world wasn't provided mutably, returns an error.
+    ///
+    /// [`with_world`]: ArgListWorldExt::with_world
+    fn take_world(&mut self) -> Result<&'a mut World, ArgError>;
+
+    /// Remove the [`World`] provided with [`with_world`] or [`with_world_ref`]
+    /// and return it as `Ok(&World)`.
+    ///
+    /// If the world wasn't provided, returns an error.
+    ///
+    /// [`with_world`]: ArgListWorldExt::with_world
+    /// [`with_world_ref`]: ArgListWorldExt::with_world_ref
+    fn take_world_ref(&mut self) -> Result<&'a World, ArgError>;
+}
+
+impl<'a> ArgListWorldExt<'a> for ArgList<'a> {
+    fn with_world(self, world: &'a mut World) -> Self {
+        self.with_context_mut(world)
+    }
+
+    fn with_world_ref(self, world: &'a World) -> Self {
+        self.with_context_ref(world)
+    }
+
+    fn take_world(&mut self) -> Result<&'a mut World, ArgError> {
+        self.take_context_mut::<World>()
+    }
+
+    fn take_world_ref(&mut self) -> Result<&'a World, ArgError> {
+        self.take_context_ref::<World>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArgListWorldExt;
+    use crate::{resource::Resource, world::World};
+    use bevy_reflect::func::{
+        ArgList, Capability, DynamicFunction, FunctionError, IntoReturn, SignatureInfo,
+    };
+
+    #[derive(Resource)]
+    struct Score(u32);
+
+    #[test]
+    fn call_function_with_world() {
+        let get_score = DynamicFunction::new(
+            |mut args| {
+                let world = args.take_world_ref()?;
+                Ok(world.resource::<Score>().0.into_return())
+            },
+            SignatureInfo::named("get_score").with_return::<u32>(),
+        )
+        .with_capability(Capability::read::<World>());
+
+        let mut world = World::new();
+        world.insert_resource(Score(5));
+
+        assert_eq!(
+            get_score.call(ArgList::new()).unwrap_err(),
+            FunctionError::MissingCapability(Capability::read::<World>())
+        );
+
+        // Both immutable and mutable access satisfy the capability
+        let score = get_score
+            .call(ArgList::new().with_world_ref(&world))
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(score.try_take::<u32>().unwrap(), 5);
+
+        let score = get_score
+            .call(ArgList::new().with_world(&mut world))
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(score.try_take::<u32>().unwrap(), 5);
+    }
+}
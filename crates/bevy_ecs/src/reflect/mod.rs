@@ -15,7 +15,11 @@ use bevy_reflect::{
 mod bundle;
 mod component;
 mod entity_commands;
+#[cfg(feature = "serialize")]
+mod entity_serde;
 mod from_world;
+#[cfg(feature = "reflect_functions")]
+mod function;
 mod map_entities;
 mod resource;
 mod visit_entities;
@@ -23,7 +27,11 @@ mod visit_entities;
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
 pub use entity_commands::ReflectCommandExt;
+#[cfg(feature = "serialize")]
+pub use entity_serde::{EntityDeserializerProcessor, EntitySerializerProcessor};
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
+#[cfg(feature = "reflect_functions")]
+pub use function::ArgListWorldExt;
 pub use map_entities::ReflectMapEntities;
 pub use resource::{ReflectResource, ReflectResourceFns};
 pub use visit_entities::{ReflectVisitEntities, ReflectVisitEntitiesMut};
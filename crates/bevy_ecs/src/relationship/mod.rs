@@ -257,7 +257,12 @@ pub trait RelationshipTarget: Component<Mutability = Mutable> + Sized {
 mod tests {
     use crate as bevy_ecs;
     use crate::world::World;
-    use crate::{component::Component, entity::Entity};
+    use crate::{
+        component::Component,
+        entity::Entity,
+        query::Without,
+        system::{Query, RunSystemOnce},
+    };
     use alloc::vec::Vec;
 
     #[test]
@@ -276,4 +281,100 @@ mod tests {
         let c = world.spawn(Likes(a)).id();
         assert_eq!(world.entity(a).get::<LikedBy>().unwrap().0, &[b, c]);
     }
+
+    #[test]
+    fn iter_related() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Targeted)]
+        struct Targets(pub Entity);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = Targets)]
+        struct Targeted(Vec<Entity>);
+
+        #[derive(Component, Debug, PartialEq)]
+        struct Position(i32);
+
+        #[derive(Component)]
+        struct Dead;
+
+        let mut world = World::new();
+        let a = world.spawn(Position(1)).id();
+        let b = world.spawn((Position(2), Dead)).id();
+        let c = world.spawn((Targets(a), Position(10))).id();
+        let d = world.spawn((Targets(b), Position(20))).id();
+
+        world
+            .run_system_once(
+                |mut sources: Query<(&Targets, &mut Position)>,
+                 targets: Query<&Position, (Without<Targets>, Without<Dead>)>| {
+                    for ((_, mut source), target) in sources.iter_related_mut(&targets) {
+                        source.0 += target.0;
+                    }
+
+                    let related = sources
+                        .iter_related(&targets)
+                        .map(|((_, source), target)| (source.0, target.0))
+                        .collect::<Vec<_>>();
+                    assert_eq!(related, [(11, 1)]);
+                },
+            )
+            .unwrap();
+        assert_eq!(world.get::<Position>(c), Some(&Position(11)));
+        assert_eq!(world.get::<Position>(d), Some(&Position(20)));
+    }
+
+    #[test]
+    fn custom_relationship_stays_consistent() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Owns)]
+        struct OwnedBy(pub Entity);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = OwnedBy)]
+        struct Owns(Vec<Entity>);
+
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn(OwnedBy(a)).id();
+        let d = world.spawn(OwnedBy(a)).id();
+
+        // Changing the target moves the source to the new target's collection.
+        world.entity_mut(c).insert(OwnedBy(b));
+        world.flush();
+        assert_eq!(world.entity(a).get::<Owns>().unwrap().0, &[d]);
+        assert_eq!(world.entity(b).get::<Owns>().unwrap().0, &[c]);
+
+        // Despawning a source removes it from its target, and removes the emptied collection.
+        world.despawn(c);
+        world.flush();
+        assert!(!world.entity(b).contains::<Owns>());
+
+        // Despawning a target removes the relationship from its sources.
+        world.despawn(a);
+        world.flush();
+        assert!(!world.entity(d).contains::<OwnedBy>());
+    }
+
+    #[test]
+    fn custom_relationship_despawn_descendants() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Holds)]
+        struct HeldBy(pub Entity);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = HeldBy, despawn_descendants)]
+        struct Holds(Vec<Entity>);
+
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn(HeldBy(a)).id();
+        let c = world.spawn(HeldBy(b)).id();
+
+        world.despawn(a);
+        world.flush();
+        assert!(world.get_entity(b).is_err());
+        assert!(world.get_entity(c).is_err());
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     entity::Entity,
-    query::{QueryData, QueryFilter, WorldQuery},
+    query::{QueryData, QueryFilter, ROQueryItem, WorldQuery},
     relationship::{Relationship, RelationshipTarget},
     system::Query,
 };
@@ -51,6 +51,58 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Iterates the items of all entities in this query together with the items of the
+    /// target entities of their `R` [`Relationship`] in the `targets` query.
+    ///
+    /// This query's data must be `(&R, D)`. Entities whose target doesn't match `targets` are skipped.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// # #[derive(Component)]
+    /// # struct Label(&'static str);
+    /// fn print_parent_names(
+    ///     children: Query<(&ChildOf, &Health)>,
+    ///     parents: Query<&Label>,
+    /// ) {
+    ///     for ((_, health), parent_label) in children.iter_related(&parents) {
+    ///         println!("{} has a child with {} health", parent_label.0, health.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(print_parent_names);
+    /// ```
+    pub fn iter_related<R: Relationship, T, D2: QueryData, F2: QueryFilter>(
+        &'w self,
+        targets: &'w Query<'w, 's, D2, F2>,
+    ) -> impl Iterator<Item = ((&'w R, T), ROQueryItem<'w, D2>)> + 'w
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = (&'w R, T)>,
+    {
+        self.iter().filter_map(move |source| {
+            let target = targets.get(source.0.get()).ok()?;
+            Some((source, target))
+        })
+    }
+
+    /// Iterates the mutable items of all entities in this query together with the items of the
+    /// target entities of their `R` [`Relationship`] in the `targets` query.
+    ///
+    /// This query's data must be `(&R, D)`. Entities whose target doesn't match `targets` are skipped.
+    /// See [`Query::iter_related`] for the read-only version.
+    pub fn iter_related_mut<'a, R: Relationship, T, D2: QueryData, F2: QueryFilter>(
+        &'a mut self,
+        targets: &'a Query<'a, 'a, D2, F2>,
+    ) -> impl Iterator<Item = ((&'a R, T), ROQueryItem<'a, D2>)> + 'a
+    where
+        D: WorldQuery<Item<'a> = (&'a R, T)>,
+    {
+        self.iter_mut().filter_map(move |source| {
+            let target = targets.get(source.0.get()).ok()?;
+            Some((source, target))
+        })
+    }
+
     /// Iterates all "leaf entities" as defined by the [`RelationshipTarget`] hierarchy.
     ///
     /// # Warning
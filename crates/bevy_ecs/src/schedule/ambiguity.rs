@@ -0,0 +1,375 @@
+use alloc::{format, string::String, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{
+    self as bevy_ecs,
+    component::ComponentId,
+    resource::Resource,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+
+/// Resource containing the system order ambiguities of every [`Schedule`] in the world,
+/// as structured data rather than log output.
+///
+/// This resource is not present by default. Once it has been inserted (for example with
+/// [`World::init_resource`]), the ambiguities of a schedule are recorded every time that
+/// schedule is built, regardless of its [`ambiguity_detection`] setting.
+///
+/// Combined with an [`AmbiguityAllowlist`], this can be used to check in tests that no new
+/// ambiguities are introduced, while tolerating ones which are known about.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{AmbiguityAllowlist, AmbiguityReport, ScheduleLabel};
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// fn add_score(_score: ResMut<Score>) {}
+/// fn reset_score(_score: ResMut<Score>) {}
+///
+/// let mut world = World::new();
+/// world.insert_resource(Score(0));
+/// world.init_resource::<AmbiguityReport>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems((add_score, reset_score));
+/// schedule.initialize(&mut world).unwrap();
+///
+/// let report = world.resource::<AmbiguityReport>();
+/// let ambiguity = &report.get(Update).unwrap()[0];
+/// assert!(ambiguity.involves("add_score", "reset_score"));
+/// assert_eq!(ambiguity.component_ids, [world.resource_id::<Score>().unwrap()]);
+///
+/// // This ambiguity is known about, so it's allowed
+/// let allowlist = AmbiguityAllowlist::new().allow_in(Update, "add_score", "reset_score");
+/// report.assert_allowed(&allowlist);
+/// ```
+///
+/// [`Schedule`]: crate::schedule::Schedule
+/// [`World::init_resource`]: crate::world::World::init_resource
+/// [`ambiguity_detection`]: crate::schedule::ScheduleBuildSettings::ambiguity_detection
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AmbiguityReport {
+    schedules: HashMap<InternedScheduleLabel, Vec<SystemAmbiguity>>,
+}
+
+impl AmbiguityReport {
+    /// Returns the ambiguities of the schedule with the given label,
+    /// or `None` if the schedule hasn't been built since this resource was inserted.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&[SystemAmbiguity]> {
+        self.schedules.get(&label.intern()).map(Vec::as_slice)
+    }
+
+    /// Replaces the ambiguities recorded for the schedule with the given label.
+    pub fn insert(&mut self, label: impl ScheduleLabel, ambiguities: Vec<SystemAmbiguity>) {
+        self.schedules.insert(label.intern(), ambiguities);
+    }
+
+    /// Returns an iterator over every recorded ambiguity, along with the label of its schedule.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &SystemAmbiguity)> + '_ {
+        self.schedules
+            .iter()
+            .flat_map(|(label, ambiguities)| ambiguities.iter().map(|a| (*label, a)))
+    }
+
+    /// Returns the total number of ambiguities across all schedules.
+    pub fn len(&self) -> usize {
+        self.schedules.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if there are no ambiguities in any schedule.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the ambiguities which are not allowed by `allowlist`.
+    pub fn unexpected<'a>(
+        &'a self,
+        allowlist: &'a AmbiguityAllowlist,
+    ) -> impl Iterator<Item = (InternedScheduleLabel, &'a SystemAmbiguity)> + 'a {
+        self.iter()
+            .filter(|(label, ambiguity)| !allowlist.allows(*label, ambiguity))
+    }
+
+    /// Returns an [`AmbiguityAllowlist`] which allows every ambiguity in this report.
+    ///
+    /// This is useful for creating a baseline to check future reports against.
+    pub fn to_allowlist(&self) -> AmbiguityAllowlist {
+        let mut allowlist = AmbiguityAllowlist::new();
+        for (label, ambiguity) in self.iter() {
+            allowlist = allowlist.allow_in(
+                label,
+                ambiguity.system_a.clone(),
+                ambiguity.system_b.clone(),
+            );
+        }
+        allowlist
+    }
+
+    /// Panics if there are any ambiguities which are not allowed by `allowlist`.
+    ///
+    /// The panic message lists the new ambiguities as [`AmbiguityAllowlist::allow_in`] calls,
+    /// which can be copied into the allowlist if the ambiguities are intended.
+    #[track_caller]
+    pub fn assert_allowed(&self, allowlist: &AmbiguityAllowlist) {
+        let mut unexpected: Vec<_> = self.unexpected(allowlist).collect();
+        if unexpected.is_empty() {
+            return;
+        }
+        unexpected.sort_by(|(label_a, a), (label_b, b)| {
+            format!("{label_a:?}")
+                .cmp(&format!("{label_b:?}"))
+                .then_with(|| a.system_a.cmp(&b.system_a))
+                .then_with(|| a.system_b.cmp(&b.system_b))
+        });
+
+        let mut message = format!(
+            "{} system order ambiguities are not in the allowlist:\n",
+            unexpected.len()
+        );
+        for (label, ambiguity) in &unexpected {
+            writeln!(message, " -- in {label:?}: {ambiguity}").unwrap();
+        }
+        message.push_str("\nIf these are intended, add them to the allowlist:\n");
+        for (label, ambiguity) in &unexpected {
+            writeln!(
+                message,
+                "    .allow_in({label:?}, {:?}, {:?})",
+                ambiguity.system_a, ambiguity.system_b
+            )
+            .unwrap();
+        }
+        panic!("{message}");
+    }
+}
+
+/// A pair of systems in a schedule with conflicting data access and an indeterminate execution
+/// order, as recorded in an [`AmbiguityReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemAmbiguity {
+    /// The name of the first system.
+    pub system_a: String,
+    /// The name of the second system.
+    pub system_b: String,
+    /// The names of the system sets the first system is part of.
+    pub sets_a: Vec<String>,
+    /// The names of the system sets the second system is part of.
+    pub sets_b: Vec<String>,
+    /// The components and resources that both systems access, where at least one accesses
+    /// them mutably.
+    ///
+    /// If this is empty, the systems conflict on access to the whole world,
+    /// for example because one of them is exclusive.
+    pub component_ids: Vec<ComponentId>,
+    /// The names of the components and resources in [`component_ids`](Self::component_ids).
+    pub conflicts: Vec<String>,
+}
+
+impl SystemAmbiguity {
+    /// Returns `true` if the systems conflict on access to the whole world,
+    /// rather than on specific components or resources.
+    pub fn conflicts_on_world(&self) -> bool {
+        self.component_ids.is_empty()
+    }
+
+    /// Returns `true` if one of the systems is named or part of a set named `a`,
+    /// and the other is named or part of a set named `b`.
+    pub fn involves(&self, a: &str, b: &str) -> bool {
+        let matches_a = |name: &str| self.system_a == name || self.sets_a.iter().any(|s| s == name);
+        let matches_b = |name: &str| self.system_b == name || self.sets_b.iter().any(|s| s == name);
+        (matches_a(a) && matches_b(b)) || (matches_a(b) && matches_b(a))
+    }
+}
+
+impl Display for SystemAmbiguity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} and {}", self.system_a, self.system_b)?;
+        if self.conflicts_on_world() {
+            write!(
+                f,
+                " conflict on: {}",
+                core::any::type_name::<crate::world::World>()
+            )
+        } else {
+            write!(f, " conflict on: {:?}", self.conflicts)
+        }
+    }
+}
+
+/// A list of system order ambiguities which are known about and tolerated,
+/// for checking an [`AmbiguityReport`] against with [`AmbiguityReport::assert_allowed`].
+///
+/// Entries refer to systems by name, which are shortened unless
+/// [`use_shortnames`](crate::schedule::ScheduleBuildSettings::use_shortnames) is disabled.
+/// An entry may also name a system set, in which case it allows ambiguities with any system in
+/// that set.
+#[derive(Debug, Clone, Default)]
+pub struct AmbiguityAllowlist {
+    entries: Vec<AllowedAmbiguity>,
+}
+
+#[derive(Debug, Clone)]
+struct AllowedAmbiguity {
+    schedule: Option<InternedScheduleLabel>,
+    a: String,
+    b: String,
+}
+
+impl AmbiguityAllowlist {
+    /// Creates an empty allowlist.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows ambiguities between `a` and `b` in any schedule.
+    pub fn allow(mut self, a: impl Into<String>, b: impl Into<String>) -> Self {
+        self.entries.push(AllowedAmbiguity {
+            schedule: None,
+            a: a.into(),
+            b: b.into(),
+        });
+        self
+    }
+
+    /// Allows ambiguities between `a` and `b` in the schedule with the given label.
+    pub fn allow_in(
+        mut self,
+        schedule: impl ScheduleLabel,
+        a: impl Into<String>,
+        b: impl Into<String>,
+    ) -> Self {
+        self.entries.push(AllowedAmbiguity {
+            schedule: Some(schedule.intern()),
+            a: a.into(),
+            b: b.into(),
+        });
+        self
+    }
+
+    /// Returns `true` if the given ambiguity in the schedule with the given label is allowed.
+    pub fn allows(&self, schedule: impl ScheduleLabel, ambiguity: &SystemAmbiguity) -> bool {
+        let schedule = schedule.intern();
+        self.entries.iter().any(|entry| {
+            entry.schedule.is_none_or(|label| label == schedule)
+                && ambiguity.involves(&entry.a, &entry.b)
+        })
+    }
+
+    /// Returns the number of entries in the allowlist.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the allowlist has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct OtherSchedule;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(Resource)]
+    struct R;
+
+    #[derive(Component)]
+    struct A;
+
+    fn write_resource(_: ResMut<R>) {}
+    fn read_resource(_: Res<R>) {}
+    fn write_component(_: Query<&mut A>) {}
+    fn read_component(_: Query<&A>) {}
+    fn exclusive(_: &mut World) {}
+
+    fn report(add: impl FnOnce(&mut Schedule)) -> AmbiguityReport {
+        let mut world = World::new();
+        world.insert_resource(R);
+        world.init_resource::<AmbiguityReport>();
+        let mut schedule = Schedule::new(TestSchedule);
+        add(&mut schedule);
+        schedule.initialize(&mut world).unwrap();
+        world.remove_resource::<AmbiguityReport>().unwrap()
+    }
+
+    #[test]
+    fn records_ambiguities() {
+        let report = report(|schedule| {
+            schedule.add_systems((
+                (write_component, read_component).in_set(Physics),
+                write_resource,
+                read_resource.after(write_resource),
+            ));
+        });
+
+        let ambiguities = report.get(TestSchedule).unwrap();
+        assert_eq!(ambiguities.len(), 1);
+        let ambiguity = &ambiguities[0];
+        assert!(ambiguity.involves("write_component", "read_component"));
+        assert!(ambiguity.involves("Physics", "Physics"));
+        assert_eq!(ambiguity.sets_a, ["Physics"]);
+        assert_eq!(ambiguity.component_ids.len(), 1);
+        assert!(ambiguity.conflicts[0].ends_with("::A"));
+        assert!(report.get(OtherSchedule).is_none());
+    }
+
+    #[test]
+    fn records_world_conflicts() {
+        let report = report(|schedule| {
+            schedule.add_systems((exclusive, read_resource));
+        });
+
+        let ambiguity = &report.get(TestSchedule).unwrap()[0];
+        assert!(ambiguity.conflicts_on_world());
+        assert!(ambiguity.conflicts.is_empty());
+    }
+
+    #[test]
+    fn allowlist() {
+        let report = report(|schedule| {
+            schedule.add_systems((
+                write_resource,
+                read_resource,
+                write_component.in_set(Physics),
+            ));
+            schedule.add_systems(read_component.in_set(Physics));
+        });
+        assert_eq!(report.len(), 2);
+
+        let allowlist = AmbiguityAllowlist::new().allow("read_resource", "write_resource");
+        assert_eq!(report.unexpected(&allowlist).count(), 1);
+
+        let allowlist = allowlist.allow_in(OtherSchedule, "Physics", "Physics");
+        assert_eq!(report.unexpected(&allowlist).count(), 1);
+
+        let allowlist = allowlist.allow_in(TestSchedule, "Physics", "Physics");
+        assert_eq!(report.unexpected(&allowlist).count(), 0);
+        report.assert_allowed(&allowlist);
+        report.assert_allowed(&report.to_allowlist());
+    }
+
+    #[test]
+    #[should_panic = "1 system order ambiguities are not in the allowlist"]
+    fn assert_allowed_panics_on_new_ambiguities() {
+        let report = report(|schedule| {
+            schedule.add_systems((write_resource, read_resource));
+        });
+        report.assert_allowed(&AmbiguityAllowlist::new());
+    }
+}
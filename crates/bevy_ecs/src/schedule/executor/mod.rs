@@ -98,6 +98,10 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// The systems run by the executor, recorded when the [`SystemProfile`](super::SystemProfile)
+    /// resource exists.
+    #[cfg(feature = "system_profiling")]
+    pub(super) system_runs: Option<Vec<super::profiling::SystemRun>>,
 }
 
 impl SystemSchedule {
@@ -113,6 +117,8 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            #[cfg(feature = "system_profiling")]
+            system_runs: None,
         }
     }
 }
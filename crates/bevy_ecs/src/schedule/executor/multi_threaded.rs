@@ -13,6 +13,12 @@ use std::{
 #[cfg(feature = "trace")]
 use tracing::{info_span, Span};
 
+#[cfg(feature = "system_profiling")]
+use {
+    crate::schedule::profiling::SystemRun, bevy_platform_support::time::Instant,
+    core::time::Duration,
+};
+
 use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
@@ -77,6 +83,9 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// When the system started running and how long it took, if it's profiled.
+    #[cfg(feature = "system_profiling")]
+    run: Option<(Instant, Duration)>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -123,6 +132,9 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// The systems that have run, for the [`SystemProfile`](crate::schedule::SystemProfile).
+    #[cfg(feature = "system_profiling")]
+    system_runs: Vec<SystemRun>,
 }
 
 /// References to data required by the executor.
@@ -245,6 +257,13 @@ impl SystemExecutor for MultiThreadedExecutor {
             state.unapplied_systems.clear();
         }
 
+        #[cfg(feature = "system_profiling")]
+        if let Some(runs) = &mut schedule.system_runs {
+            runs.append(&mut state.system_runs);
+        } else {
+            state.system_runs.clear();
+        }
+
         // check to see if there was a panic
         let payload = self.panic_payload.get_mut().unwrap();
         if let Some(payload) = payload.take() {
@@ -267,7 +286,7 @@ impl SystemExecutor for MultiThreadedExecutor {
 impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
     fn system_completed(
         &self,
-        system_index: usize,
+        result: SystemResult,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
     ) {
@@ -275,7 +294,7 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(result)
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
@@ -349,6 +368,8 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            #[cfg(feature = "system_profiling")]
+            system_runs: Vec::new(),
         }
     }
 
@@ -597,6 +618,8 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            #[cfg(feature = "system_profiling")]
+            let started = Instant::now();
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -616,7 +639,12 @@ impl ExecutorState {
                     };
                 };
             }));
-            context.system_completed(system_index, res, system);
+            let result = SystemResult {
+                system_index,
+                #[cfg(feature = "system_profiling")]
+                run: Some((started, started.elapsed())),
+            };
+            context.system_completed(result, res, system);
         };
 
         self.active_access
@@ -647,7 +675,12 @@ impl ExecutorState {
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                let result = SystemResult {
+                    system_index,
+                    #[cfg(feature = "system_profiling")]
+                    run: None,
+                };
+                context.system_completed(result, res, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -656,6 +689,8 @@ impl ExecutorState {
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
+                #[cfg(feature = "system_profiling")]
+                let started = Instant::now();
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     // TODO: implement an error-handling API instead of panicking.
                     if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
@@ -666,7 +701,12 @@ impl ExecutorState {
                         );
                     };
                 }));
-                context.system_completed(system_index, res, system);
+                let result = SystemResult {
+                    system_index,
+                    #[cfg(feature = "system_profiling")]
+                    run: Some((started, started.elapsed())),
+                };
+                context.system_completed(result, res, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -679,6 +719,15 @@ impl ExecutorState {
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
         let SystemResult { system_index, .. } = result;
 
+        #[cfg(feature = "system_profiling")]
+        if let Some((started, duration)) = result.run {
+            self.system_runs.push(SystemRun {
+                system_index,
+                started,
+                duration,
+            });
+        }
+
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
         }
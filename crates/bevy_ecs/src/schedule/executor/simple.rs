@@ -14,6 +14,9 @@ use crate::{
     world::World,
 };
 
+#[cfg(feature = "system_profiling")]
+use crate::schedule::profiling::SystemRun;
+
 use super::__rust_begin_short_backtrace;
 
 /// A variant of [`SingleThreadedExecutor`](crate::schedule::SingleThreadedExecutor) that calls
@@ -103,6 +106,9 @@ impl SystemExecutor for SimpleExecutor {
                 continue;
             }
 
+            #[cfg(feature = "system_profiling")]
+            let started = bevy_platform_support::time::Instant::now();
+
             let f = AssertUnwindSafe(|| {
                 // TODO: implement an error-handling API instead of panicking.
                 if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
@@ -126,6 +132,15 @@ impl SystemExecutor for SimpleExecutor {
             {
                 (f)();
             }
+
+            #[cfg(feature = "system_profiling")]
+            if let Some(runs) = &mut schedule.system_runs {
+                runs.push(SystemRun {
+                    system_index,
+                    started,
+                    duration: started.elapsed(),
+                });
+            }
         }
 
         self.evaluated_sets.clear();
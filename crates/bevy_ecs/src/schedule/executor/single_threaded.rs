@@ -12,6 +12,9 @@ use crate::{
     world::World,
 };
 
+#[cfg(feature = "system_profiling")]
+use crate::schedule::profiling::SystemRun;
+
 use super::__rust_begin_short_backtrace;
 
 /// Runs the schedule using a single thread.
@@ -110,6 +113,9 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            #[cfg(feature = "system_profiling")]
+            let started = bevy_platform_support::time::Instant::now();
+
             let f = AssertUnwindSafe(|| {
                 if system.is_exclusive() {
                     // TODO: implement an error-handling API instead of panicking.
@@ -152,6 +158,15 @@ impl SystemExecutor for SingleThreadedExecutor {
                 (f)();
             }
 
+            #[cfg(feature = "system_profiling")]
+            if let Some(runs) = &mut schedule.system_runs {
+                runs.push(SystemRun {
+                    system_index,
+                    started,
+                    duration: started.elapsed(),
+                });
+            }
+
             self.unapplied_systems.insert(system_index);
         }
 
@@ -0,0 +1,322 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform_support::collections::{HashMap, HashSet};
+use core::fmt::Write;
+use disqualified::ShortName;
+
+use crate::schedule::{
+    graph::{Direction::Incoming, NodeId},
+    InternedSystemSet, Schedule, ScheduleGraph, SystemSet,
+};
+
+/// Specifies how a [`ScheduleGraph`] is exported with [`ScheduleGraph::export_dot`].
+///
+/// The defaults export every system in the schedule as its own node. For schedules with many
+/// systems, collapsing sets or crates into single nodes and filtering out crates you aren't
+/// interested in keeps the output readable.
+#[derive(Clone, Debug)]
+pub struct ScheduleExportSettings {
+    /// Sets that are drawn as a single node, which replaces all of the systems in them.
+    ///
+    /// Plugins usually put their systems in a set of their own, so collapsing those sets
+    /// shows how plugins are ordered relative to each other.
+    /// A system in several of these sets is merged into the first one listed.
+    ///
+    /// Defaults to no sets.
+    pub collapsed_sets: Vec<InternedSystemSet>,
+    /// If set to true, systems that aren't in one of the [`collapsed_sets`](Self::collapsed_sets)
+    /// are merged into a single node per crate.
+    ///
+    /// Defaults to `false`.
+    pub collapse_crates: bool,
+    /// If not empty, only systems from these crates are exported.
+    ///
+    /// Defaults to all crates.
+    pub include_crates: Vec<String>,
+    /// Systems from these crates aren't exported.
+    ///
+    /// Defaults to no crates.
+    pub exclude_crates: Vec<String>,
+    /// If set to true, node names will be shortened instead of the fully qualified type path.
+    ///
+    /// Defaults to `true`.
+    pub use_shortnames: bool,
+}
+
+impl Default for ScheduleExportSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScheduleExportSettings {
+    /// Default export settings.
+    /// See the field-level documentation for the default value of each field.
+    pub const fn new() -> Self {
+        Self {
+            collapsed_sets: Vec::new(),
+            collapse_crates: false,
+            include_crates: Vec::new(),
+            exclude_crates: Vec::new(),
+            use_shortnames: true,
+        }
+    }
+
+    /// Draws `set` as a single node. See [`collapsed_sets`](Self::collapsed_sets).
+    pub fn collapse_set(mut self, set: impl SystemSet) -> Self {
+        self.collapsed_sets.push(set.intern());
+        self
+    }
+
+    /// Only exports systems from `crate_name`. See [`include_crates`](Self::include_crates).
+    pub fn include_crate(mut self, crate_name: impl Into<String>) -> Self {
+        self.include_crates.push(crate_name.into());
+        self
+    }
+
+    /// Doesn't export systems from `crate_name`. See [`exclude_crates`](Self::exclude_crates).
+    pub fn exclude_crate(mut self, crate_name: impl Into<String>) -> Self {
+        self.exclude_crates.push(crate_name.into());
+        self
+    }
+
+    fn includes_crate(&self, crate_name: &str) -> bool {
+        (self.include_crates.is_empty() || self.include_crates.iter().any(|c| c == crate_name))
+            && !self.exclude_crates.iter().any(|c| c == crate_name)
+    }
+}
+
+/// A node in the exported graph.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum ExportNode {
+    System(NodeId),
+    Set(NodeId),
+    Crate(String),
+}
+
+impl Schedule {
+    /// Exports the graph of this schedule's systems and their ordering in the
+    /// [DOT](https://graphviz.org/doc/info/lang.html) format.
+    ///
+    /// See [`ScheduleGraph::export_dot`].
+    pub fn export_dot(&self, settings: &ScheduleExportSettings) -> String {
+        self.graph()
+            .export_dot(&format!("{:?}", self.label()), settings)
+    }
+}
+
+impl ScheduleGraph {
+    /// Exports the graph of systems and their ordering in the
+    /// [DOT](https://graphviz.org/doc/info/lang.html) format, which can be rendered with tools
+    /// like Graphviz.
+    ///
+    /// Ordering between sets is applied to the systems in them, and every edge between two nodes
+    /// is only exported once, even if it comes from several orderings between their systems.
+    /// Orderings with filtered out systems aren't exported.
+    pub fn export_dot(&self, name: &str, settings: &ScheduleExportSettings) -> String {
+        let collapsed_sets = settings
+            .collapsed_sets
+            .iter()
+            .filter_map(|set| {
+                self.system_sets()
+                    .find(|(_, other, _)| **other == **set)
+                    .map(|(id, ..)| id)
+            })
+            .collect::<Vec<_>>();
+
+        // Find the node each system is exported as, and the systems in each set.
+        let mut system_nodes = HashMap::<NodeId, ExportNode>::default();
+        let mut set_systems = HashMap::<NodeId, Vec<NodeId>>::default();
+        for (id, system, _) in self.systems() {
+            let name = system.name();
+            let crate_name = crate_name(&name);
+            if !settings.includes_crate(crate_name) {
+                continue;
+            }
+
+            let sets = self.sets_containing_node(id);
+            let node = if let Some(&set) = collapsed_sets.iter().find(|set| sets.contains(*set)) {
+                ExportNode::Set(set)
+            } else if settings.collapse_crates {
+                ExportNode::Crate(crate_name.to_owned())
+            } else {
+                ExportNode::System(id)
+            };
+            system_nodes.insert(id, node);
+            for set in sets {
+                set_systems.entry(set).or_default().push(id);
+            }
+        }
+
+        let export_nodes = |id: NodeId| -> HashSet<&ExportNode> {
+            match id {
+                NodeId::System(_) => system_nodes.get(&id).into_iter().collect(),
+                NodeId::Set(_) => set_systems
+                    .get(&id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|system| system_nodes.get(system))
+                    .collect(),
+            }
+        };
+
+        // Merge the edges between all nodes, dropping duplicate edges and edges inside one node.
+        let mut edges = HashSet::<(&ExportNode, &ExportNode)>::default();
+        for (a, b) in self.dependency().graph().all_edges() {
+            let b_nodes = export_nodes(b);
+            for a_node in export_nodes(a) {
+                for &b_node in &b_nodes {
+                    if a_node != b_node {
+                        edges.insert((a_node, b_node));
+                    }
+                }
+            }
+        }
+
+        let node_name = |node: &ExportNode| {
+            let name = match node {
+                ExportNode::System(id) => self.system_at(*id).name().to_string(),
+                ExportNode::Set(id) => format!("{:?}", self.set_at(*id)),
+                ExportNode::Crate(name) => return name.clone(),
+            };
+            if settings.use_shortnames {
+                ShortName(&name).to_string()
+            } else {
+                name
+            }
+        };
+
+        // Sort the output, so that it's stable between runs.
+        let mut nodes = system_nodes
+            .values()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|node| (node_name(node), node))
+            .collect::<Vec<_>>();
+        nodes.sort();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, (_, node))| (*node, index))
+            .collect::<HashMap<_, _>>();
+        let mut edges = edges
+            .into_iter()
+            .map(|(a, b)| (indices[a], indices[b]))
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+
+        let mut output = String::new();
+        writeln!(output, "digraph \"{}\" {{", escape(name)).unwrap();
+        writeln!(output, "    rankdir=LR;").unwrap();
+        writeln!(output, "    node [shape=box];").unwrap();
+        for (index, (name, node)) in nodes.iter().enumerate() {
+            let style = match node {
+                ExportNode::System(_) => "",
+                ExportNode::Set(_) | ExportNode::Crate(_) => ", style=bold",
+            };
+            writeln!(output, "    {index} [label=\"{}\"{style}];", escape(name)).unwrap();
+        }
+        for (a, b) in edges {
+            writeln!(output, "    {a} -> {b};").unwrap();
+        }
+        output.push('}');
+        output
+    }
+
+    /// Returns every set that contains `id`, directly or through other sets.
+    fn sets_containing_node(&self, id: NodeId) -> HashSet<NodeId> {
+        let mut sets = HashSet::default();
+        let mut stack = Vec::from([id]);
+        while let Some(id) = stack.pop() {
+            for set in self.hierarchy().graph().neighbors_directed(id, Incoming) {
+                if sets.insert(set) {
+                    stack.push(set);
+                }
+            }
+        }
+        sets
+    }
+}
+
+/// Returns the name of the crate that a system with the given `name` was defined in.
+fn crate_name(name: &str) -> &str {
+    let name = name.trim_start_matches('<');
+    name.split("::").next().unwrap_or(name)
+}
+
+/// Escapes `value` for use in a quoted DOT string.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schedule::{
+        IntoSystemConfigs, IntoSystemSetConfigs, Schedule, ScheduleExportSettings, SystemSet,
+    };
+
+    use crate as bevy_ecs;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct PluginSet;
+
+    fn a() {}
+    fn b() {}
+    fn c() {}
+    fn d() {}
+
+    #[test]
+    fn export_dot() {
+        let mut schedule = Schedule::default();
+        schedule.configure_sets(PluginSet.after(a)).add_systems((
+            a,
+            (b, c).in_set(PluginSet),
+            d.after(b).after(c),
+        ));
+
+        let settings = ScheduleExportSettings::default();
+        assert_eq!(
+            schedule.export_dot(&settings),
+            "digraph \"DefaultSchedule\" {
+    rankdir=LR;
+    node [shape=box];
+    0 [label=\"a\"];
+    1 [label=\"b\"];
+    2 [label=\"c\"];
+    3 [label=\"d\"];
+    0 -> 1;
+    0 -> 2;
+    1 -> 3;
+    2 -> 3;
+}"
+        );
+
+        // The orderings from `b` and `c` are merged into one edge.
+        let settings = ScheduleExportSettings::default().collapse_set(PluginSet);
+        assert_eq!(
+            schedule.export_dot(&settings),
+            "digraph \"DefaultSchedule\" {
+    rankdir=LR;
+    node [shape=box];
+    0 [label=\"PluginSet\", style=bold];
+    1 [label=\"a\"];
+    2 [label=\"d\"];
+    0 -> 2;
+    1 -> 0;
+}"
+        );
+
+        let settings = ScheduleExportSettings::default().exclude_crate("bevy_ecs");
+        assert_eq!(
+            schedule.export_dot(&settings),
+            "digraph \"DefaultSchedule\" {
+    rankdir=LR;
+    node [shape=box];
+}"
+        );
+    }
+}
@@ -1,17 +1,25 @@
 //! Contains APIs for ordering systems and executing them on a [`World`](crate::world::World)
 
+mod ambiguity;
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph;
+#[cfg(feature = "system_profiling")]
+mod profiling;
 mod schedule;
 mod set;
 mod stepping;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{
+    ambiguity::*, condition::*, config::*, executor::*, export::*, schedule::*, set::*,
+};
 
 pub use self::graph::NodeId;
+#[cfg(feature = "system_profiling")]
+pub use self::profiling::{SystemProfile, SystemTimings};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,194 @@
+use alloc::borrow::Cow;
+use bevy_platform_support::{collections::HashMap, time::Instant};
+use core::time::Duration;
+
+use crate::{
+    self as bevy_ecs,
+    resource::Resource,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+
+/// Resource containing the execution time of every system of every [`Schedule`] in the world,
+/// which can be used to display a live breakdown of system costs without an external profiler.
+///
+/// This resource is only available with the `system_profiling` feature, and is not present by
+/// default. Once it has been inserted (for example with [`World::init_resource`]), the
+/// executors record the timings of each system every time a schedule runs. Systems are
+/// identified by their [name](crate::system::System::name), so systems with the same name in a
+/// schedule share their timings.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ScheduleLabel, SystemProfile};
+/// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// fn physics() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<SystemProfile>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems(physics);
+/// schedule.run(&mut world);
+/// schedule.run(&mut world);
+///
+/// let profile = world.resource::<SystemProfile>();
+/// let (name, timings) = profile.schedule(Update).next().unwrap();
+/// assert!(name.ends_with("physics"));
+/// assert_eq!(timings.run_count, 2);
+/// ```
+///
+/// [`Schedule`]: crate::schedule::Schedule
+/// [`World::init_resource`]: crate::world::World::init_resource
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SystemProfile {
+    schedules: HashMap<InternedScheduleLabel, HashMap<Cow<'static, str>, SystemTimings>>,
+}
+
+impl SystemProfile {
+    /// Returns the timings of the system with the given name in the schedule with the given label,
+    /// or `None` if it hasn't run since this resource was inserted.
+    pub fn get(&self, label: impl ScheduleLabel, system_name: &str) -> Option<&SystemTimings> {
+        self.schedules.get(&label.intern())?.get(system_name)
+    }
+
+    /// Returns an iterator over the names and timings of the systems of the schedule with the
+    /// given label.
+    pub fn schedule(
+        &self,
+        label: impl ScheduleLabel,
+    ) -> impl Iterator<Item = (&str, &SystemTimings)> + '_ {
+        self.schedules
+            .get(&label.intern())
+            .into_iter()
+            .flatten()
+            .map(|(name, timings)| (&**name, timings))
+    }
+
+    /// Returns an iterator over the timings of every recorded system, along with the label of its
+    /// schedule and its name.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &str, &SystemTimings)> + '_ {
+        self.schedules.iter().flat_map(|(label, systems)| {
+            systems
+                .iter()
+                .map(|(name, timings)| (*label, &**name, timings))
+        })
+    }
+
+    /// Removes all recorded timings.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Records a single run of a system.
+    pub(crate) fn record(
+        &mut self,
+        label: InternedScheduleLabel,
+        system_name: Cow<'static, str>,
+        wait: Duration,
+        duration: Duration,
+    ) {
+        let timings = self
+            .schedules
+            .entry(label)
+            .or_default()
+            .entry(system_name)
+            .or_default();
+        timings.run_count += 1;
+        timings.last_duration = duration;
+        timings.total_duration += duration;
+        timings.last_wait = wait;
+        timings.total_wait += wait;
+    }
+}
+
+/// The execution statistics of a single system, recorded in the [`SystemProfile`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemTimings {
+    /// The number of times the system has run.
+    pub run_count: u64,
+    /// The time taken by the latest run of the system.
+    pub last_duration: Duration,
+    /// The time taken by all runs of the system.
+    pub total_duration: Duration,
+    /// The time between the start of the schedule and the start of the latest run of the system,
+    /// spent waiting on the systems it depends on or conflicts with.
+    pub last_wait: Duration,
+    /// The sum of [`last_wait`](Self::last_wait) over all runs of the system.
+    pub total_wait: Duration,
+}
+
+impl SystemTimings {
+    /// Returns the average time taken by a run of the system.
+    pub fn average_duration(&self) -> Duration {
+        average(self.total_duration, self.run_count)
+    }
+
+    /// Returns the average time the system waited before running.
+    pub fn average_wait(&self) -> Duration {
+        average(self.total_wait, self.run_count)
+    }
+}
+
+fn average(total: Duration, count: u64) -> Duration {
+    if count == 0 {
+        Duration::ZERO
+    } else {
+        total.div_f64(count as f64)
+    }
+}
+
+/// A single run of a system, recorded by the executor.
+pub(super) struct SystemRun {
+    /// The index of the system in the [`SystemSchedule`](super::SystemSchedule).
+    pub system_index: usize,
+    /// When the system started running.
+    pub started: Instant,
+    /// How long the system took to run.
+    pub duration: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, SystemProfile},
+    };
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    fn first() {}
+    fn second() {}
+
+    #[test]
+    fn records_system_runs() {
+        for executor in [
+            ExecutorKind::Simple,
+            ExecutorKind::SingleThreaded,
+            ExecutorKind::MultiThreaded,
+        ] {
+            let mut world = World::new();
+            let mut schedule = Schedule::new(TestSchedule);
+            schedule
+                .set_executor_kind(executor)
+                .add_systems((first, second.after(first)));
+
+            // Nothing is recorded without the resource.
+            schedule.run(&mut world);
+            world.init_resource::<SystemProfile>();
+            schedule.run(&mut world);
+            schedule.run(&mut world);
+
+            let profile = world.resource::<SystemProfile>();
+            assert_eq!(profile.schedule(TestSchedule).count(), 2);
+            for (name, timings) in profile.schedule(TestSchedule) {
+                assert_eq!(timings.run_count, 2, "{name} with {executor:?}");
+                assert!(timings.total_duration >= timings.last_duration);
+            }
+        }
+    }
+}
@@ -398,19 +398,63 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        #[cfg(feature = "system_profiling")]
+        let started = bevy_platform_support::time::Instant::now();
+        #[cfg(feature = "system_profiling")]
+        {
+            self.executable.system_runs = world.contains_resource::<SystemProfile>().then(Vec::new);
+        }
+
+        world.schedule_depth += 1;
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor.run(&mut self.executable, world, None);
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
-                None => None,
-                Some(mut stepping) => stepping.skipped_systems(self),
-            };
+            // Conditional breakpoints need to read the world, so the `Stepping`
+            // resource is temporarily removed while building the skip list.
+            let skip_systems = world
+                .try_resource_scope(|world, mut stepping: crate::world::Mut<Stepping>| {
+                    stepping.evaluate_break_conditions(self, world);
+                    stepping.skipped_systems(self)
+                })
+                .flatten();
 
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
         }
+
+        world.schedule_depth -= 1;
+        if world.schedule_depth == 0 {
+            world.flush_end_of_schedule_commands();
+        }
+
+        #[cfg(feature = "system_profiling")]
+        self.record_system_profile(world, started);
+    }
+
+    /// Adds the system runs recorded by the executor to the [`SystemProfile`].
+    #[cfg(feature = "system_profiling")]
+    fn record_system_profile(
+        &mut self,
+        world: &mut World,
+        started: bevy_platform_support::time::Instant,
+    ) {
+        let Some(runs) = self.executable.system_runs.take() else {
+            return;
+        };
+        let Some(mut profile) = world.get_resource_mut::<SystemProfile>() else {
+            return;
+        };
+        for run in runs {
+            profile.record(
+                self.label,
+                self.executable.systems[run.system_index].name(),
+                run.started.saturating_duration_since(started),
+                run.duration,
+            );
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -424,12 +468,19 @@ impl Schedule {
                 .get_resource_or_init::<Schedules>()
                 .ignored_scheduling_ambiguities
                 .clone();
-            self.graph.update_schedule(
+            let record_ambiguities = world.contains_resource::<AmbiguityReport>();
+            let ambiguities = self.graph.update_schedule(
                 &mut self.executable,
                 world.components(),
                 &ignored_ambiguities,
                 self.label,
+                record_ambiguities,
             )?;
+            if let Some(ambiguities) = ambiguities {
+                world
+                    .resource_mut::<AmbiguityReport>()
+                    .insert(self.label, ambiguities);
+            }
             self.graph.changed = false;
             self.executor_initialized = false;
         }
@@ -1500,17 +1551,22 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            #[cfg(feature = "system_profiling")]
+            system_runs: None,
         }
     }
 
     /// Updates the `SystemSchedule` from the `ScheduleGraph`.
+    ///
+    /// If `record_ambiguities` is set, returns the ambiguities found in the new schedule.
     fn update_schedule(
         &mut self,
         schedule: &mut SystemSchedule,
         components: &Components,
         ignored_ambiguities: &BTreeSet<ComponentId>,
         schedule_label: InternedScheduleLabel,
-    ) -> Result<(), ScheduleBuildError> {
+        record_ambiguities: bool,
+    ) -> Result<Option<Vec<SystemAmbiguity>>, ScheduleBuildError> {
         if !self.uninit.is_empty() {
             return Err(ScheduleBuildError::Uninitialized);
         }
@@ -1536,6 +1592,9 @@ impl ScheduleGraph {
 
         *schedule = self.build_schedule(components, schedule_label, ignored_ambiguities)?;
 
+        // system names are only available while the systems are in the graph
+        let ambiguities = record_ambiguities.then(|| self.system_ambiguities(components));
+
         // move systems into new schedule
         for &id in &schedule.system_ids {
             let system = self.systems[id.index()].inner.take().unwrap();
@@ -1549,7 +1608,7 @@ impl ScheduleGraph {
             schedule.set_conditions.push(conditions);
         }
 
-        Ok(())
+        Ok(ambiguities)
     }
 }
 
@@ -1902,6 +1961,25 @@ impl ScheduleGraph {
         message
     }
 
+    /// convert the conflicts found by the last call to [`ScheduleGraph::build_schedule`]
+    /// to structured data
+    fn system_ambiguities(&self, components: &Components) -> Vec<SystemAmbiguity> {
+        self.conflicting_systems
+            .iter()
+            .map(|(system_a, system_b, conflicts)| SystemAmbiguity {
+                system_a: self.get_node_name_inner(system_a, false),
+                system_b: self.get_node_name_inner(system_b, false),
+                sets_a: self.names_of_sets_containing_node(system_a),
+                sets_b: self.names_of_sets_containing_node(system_b),
+                component_ids: conflicts.clone(),
+                conflicts: conflicts
+                    .iter()
+                    .map(|id| components.get_name(*id).unwrap().to_string())
+                    .collect(),
+            })
+            .collect()
+    }
+
     /// convert conflicts to human readable format
     pub fn conflicts_to_string<'a>(
         &'a self,
@@ -2,9 +2,13 @@ use crate::{
     resource::Resource,
     schedule::{InternedScheduleLabel, NodeId, Schedule, ScheduleLabel},
     system::{IntoSystem, ResMut},
+    world::World,
 };
 use alloc::vec::Vec;
-use bevy_platform_support::collections::HashMap;
+use bevy_platform_support::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use bevy_utils::TypeIdMap;
 use core::any::TypeId;
 use fixedbitset::FixedBitSet;
@@ -37,7 +41,11 @@ enum Action {
     Step,
 }
 
-#[derive(Debug, Copy, Clone)]
+/// A predicate evaluated against the [`World`] to decide whether a conditional
+/// breakpoint should be hit
+type BreakCondition = Arc<dyn Fn(&World) -> bool + Send + Sync>;
+
+#[derive(Clone)]
 enum SystemBehavior {
     /// System will always run regardless of stepping action
     AlwaysRun,
@@ -55,6 +63,22 @@ enum SystemBehavior {
     /// When [`Action::Step`] this system will be stepped
     /// When [`Action::Continue`] this system will be run
     Continue,
+
+    /// Behaves like [`SystemBehavior::Break`] if the condition returned true
+    /// when it was last evaluated, otherwise like [`SystemBehavior::Continue`]
+    ConditionalBreak(BreakCondition),
+}
+
+impl core::fmt::Debug for SystemBehavior {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AlwaysRun => write!(f, "AlwaysRun"),
+            Self::NeverRun => write!(f, "NeverRun"),
+            Self::Break => write!(f, "Break"),
+            Self::Continue => write!(f, "Continue"),
+            Self::ConditionalBreak(_) => write!(f, "ConditionalBreak"),
+        }
+    }
 }
 
 // schedule_order index, and schedule start point
@@ -320,6 +344,66 @@ impl Stepping {
         self
     }
 
+    /// Add a breakpoint for system that is only hit when `condition` returns
+    /// true
+    ///
+    /// The condition is evaluated against the [`World`] each time the schedule
+    /// is run while stepping is enabled, before any of its systems run. This
+    /// is useful for stopping on rare states without having to step through
+    /// every frame by hand.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::{ScheduleLabel, Stepping};
+    /// # #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    /// # struct Update;
+    /// #[derive(Resource)]
+    /// struct Speed(f32);
+    ///
+    /// fn physics_step() {}
+    ///
+    /// let mut stepping = Stepping::new();
+    /// stepping
+    ///     .add_schedule(Update)
+    ///     .enable()
+    ///     .set_conditional_breakpoint(Update, physics_step, |world: &World| {
+    ///         world.get_resource::<Speed>().is_some_and(|speed| speed.0 > 100.0)
+    ///     });
+    /// ```
+    pub fn set_conditional_breakpoint<Marker>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        system: impl IntoSystem<(), (), Marker>,
+        condition: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        let type_id = system.system_type_id();
+        self.updates.push(Update::SetBehavior(
+            schedule.intern(),
+            SystemIdentifier::Type(type_id),
+            SystemBehavior::ConditionalBreak(Arc::new(condition)),
+        ));
+
+        self
+    }
+
+    /// Add a breakpoint for system instance that is only hit when `condition`
+    /// returns true
+    ///
+    /// See [`Stepping::set_conditional_breakpoint`] for details.
+    pub fn set_conditional_breakpoint_node(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        node: NodeId,
+        condition: impl Fn(&World) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.updates.push(Update::SetBehavior(
+            schedule.intern(),
+            SystemIdentifier::Node(node),
+            SystemBehavior::ConditionalBreak(Arc::new(condition)),
+        ));
+        self
+    }
+
     /// Clear a breakpoint for the system
     pub fn clear_breakpoint<Marker>(
         &mut self,
@@ -515,6 +599,20 @@ impl Stepping {
         }
     }
 
+    /// evaluate the conditions of any conditional breakpoints in this schedule
+    /// against the world
+    ///
+    /// The results are used by the next call to [`Stepping::skipped_systems`]
+    /// for this schedule.
+    pub fn evaluate_break_conditions(&mut self, schedule: &Schedule, world: &World) {
+        if self.action == Action::RunAll {
+            return;
+        }
+        if let Some(state) = self.schedule_states.get_mut(&schedule.label()) {
+            state.evaluate_break_conditions(schedule, world);
+        }
+    }
+
     /// get the list of systems this schedule should skip for this render
     /// frame
     pub fn skipped_systems(&mut self, schedule: &Schedule) -> Option<FixedBitSet> {
@@ -619,6 +717,10 @@ struct ScheduleState {
 
     /// This field contains the first steppable system in the schedule.
     first: Option<usize>,
+
+    /// systems with a [`SystemBehavior::ConditionalBreak`] whose condition
+    /// returned true the last time it was evaluated
+    conditions_met: HashSet<NodeId>,
 }
 
 impl ScheduleState {
@@ -656,9 +758,40 @@ impl ScheduleState {
     fn clear_behaviors(&mut self) {
         self.behaviors.clear();
         self.behavior_updates.clear();
+        self.conditions_met.clear();
         self.first = None;
     }
 
+    // sync the cached schedule state, and apply any pending behavior updates
+    fn sync_with_schedule(&mut self, schedule: &Schedule) {
+        // if our NodeId list hasn't been populated, copy it over from the
+        // schedule
+        if self.node_ids.len() != schedule.systems_len() {
+            self.node_ids.clone_from(&schedule.executable().system_ids);
+        }
+
+        // Now that we have the schedule, apply any pending system behavior
+        // updates.  The schedule is required to map from system `TypeId` to
+        // `NodeId`.
+        if !self.behavior_updates.is_empty() {
+            self.apply_behavior_updates(schedule);
+        }
+    }
+
+    // evaluate conditional breakpoints, recording which ones should be hit
+    fn evaluate_break_conditions(&mut self, schedule: &Schedule, world: &World) {
+        self.sync_with_schedule(schedule);
+
+        self.conditions_met.clear();
+        for (node_id, behavior) in &self.behaviors {
+            if let SystemBehavior::ConditionalBreak(condition) = behavior {
+                if condition(world) {
+                    self.conditions_met.insert(*node_id);
+                }
+            }
+        }
+    }
+
     // apply system behavior updates by looking up the node id of the system in
     // the schedule, and updating `systems`
     fn apply_behavior_updates(&mut self, schedule: &Schedule) {
@@ -675,7 +808,7 @@ impl ScheduleState {
                     self.behaviors.remove(&node_id);
                 }
                 Some(Some(behavior)) => {
-                    self.behaviors.insert(node_id, *behavior);
+                    self.behaviors.insert(node_id, behavior.clone());
                 }
             }
         }
@@ -693,18 +826,7 @@ impl ScheduleState {
     ) -> (FixedBitSet, Option<usize>) {
         use core::cmp::Ordering;
 
-        // if our NodeId list hasn't been populated, copy it over from the
-        // schedule
-        if self.node_ids.len() != schedule.systems_len() {
-            self.node_ids.clone_from(&schedule.executable().system_ids);
-        }
-
-        // Now that we have the schedule, apply any pending system behavior
-        // updates.  The schedule is required to map from system `TypeId` to
-        // `NodeId`.
-        if !self.behavior_updates.is_empty() {
-            self.apply_behavior_updates(schedule);
-        }
+        self.sync_with_schedule(schedule);
 
         // if we don't have a first system set, set it now
         if self.first.is_none() {
@@ -723,10 +845,19 @@ impl ScheduleState {
         let mut pos = start;
 
         for (i, (node_id, _system)) in schedule.systems().unwrap().enumerate() {
-            let behavior = self
-                .behaviors
-                .get(&node_id)
-                .unwrap_or(&SystemBehavior::Continue);
+            let behavior = match self.behaviors.get(&node_id) {
+                // conditional breakpoints act as a breakpoint only if their
+                // condition was met
+                Some(SystemBehavior::ConditionalBreak(_)) => {
+                    if self.conditions_met.contains(&node_id) {
+                        &SystemBehavior::Break
+                    } else {
+                        &SystemBehavior::Continue
+                    }
+                }
+                Some(behavior) => behavior,
+                None => &SystemBehavior::Continue,
+            };
 
             #[cfg(test)]
             debug!(
@@ -805,6 +936,9 @@ impl ScheduleState {
                 // should have never gotten into this method if stepping is
                 // disabled
                 (Action::RunAll, _) => unreachable!(),
+                // conditional breakpoints were resolved to `Break` or
+                // `Continue` above
+                (_, SystemBehavior::ConditionalBreak(_)) => unreachable!(),
             }
 
             // If we're at the cursor position, and not waiting, advance the
@@ -1148,6 +1282,47 @@ mod tests {
         assert_schedule_runs!(&schedule, &mut stepping, first_system);
     }
 
+    #[test]
+    fn continue_conditional_breakpoint() {
+        #[derive(Resource)]
+        struct Break(bool);
+
+        let (schedule, mut world) = setup();
+        world.insert_resource(Break(false));
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .set_conditional_breakpoint(TestSchedule, second_system, |world: &World| {
+                world.resource::<Break>().0
+            })
+            .continue_frame();
+
+        // condition not met; behaves like there is no breakpoint
+        stepping.next_frame();
+        stepping.evaluate_break_conditions(&schedule, &world);
+        assert_systems_run!(
+            &schedule,
+            stepping.skipped_systems(&schedule),
+            first_system,
+            second_system
+        );
+
+        // condition met; behaves like a breakpoint
+        world.resource_mut::<Break>().0 = true;
+        stepping.continue_frame().next_frame();
+        stepping.evaluate_break_conditions(&schedule, &world);
+        assert_systems_run!(&schedule, stepping.skipped_systems(&schedule), first_system);
+        stepping.continue_frame().next_frame();
+        stepping.evaluate_break_conditions(&schedule, &world);
+        assert_systems_run!(
+            &schedule,
+            stepping.skipped_systems(&schedule),
+            second_system
+        );
+    }
+
     /// regression test for issue encountered while writing `system_stepping`
     /// example
     #[test]
@@ -595,6 +595,8 @@ impl_sparse_set_index!(u8, u16, u32, u64, usize);
 #[derive(Default)]
 pub struct SparseSets {
     sets: SparseSet<ComponentId, ComponentSparseSet>,
+    /// The number of entities every new sparse set is preallocated to hold, if it's been set.
+    row_capacity: Option<usize>,
 }
 
 impl SparseSets {
@@ -616,6 +618,13 @@ impl SparseSets {
         self.sets.iter().map(|(id, data)| (*id, data))
     }
 
+    /// Sets the number of entities every [`ComponentSparseSet`] created from now on is
+    /// preallocated to hold, for worlds with
+    /// [`WorldLimits::preallocated_rows`](crate::world::WorldLimits::preallocated_rows).
+    pub(crate) fn set_row_capacity(&mut self, row_capacity: Option<usize>) {
+        self.row_capacity = row_capacity;
+    }
+
     /// Gets a reference to the [`ComponentSparseSet`] of a [`ComponentId`].
     #[inline]
     pub fn get(&self, component_id: ComponentId) -> Option<&ComponentSparseSet> {
@@ -629,10 +638,11 @@ impl SparseSets {
         component_info: &ComponentInfo,
     ) -> &mut ComponentSparseSet {
         if !self.sets.contains(component_info.id()) {
-            self.sets.insert(
-                component_info.id(),
-                ComponentSparseSet::new(component_info, 64),
-            );
+            let mut set = ComponentSparseSet::new(component_info, self.row_capacity.unwrap_or(64));
+            if let Some(row_capacity) = self.row_capacity {
+                set.sparse.values.reserve(row_capacity);
+            }
+            self.sets.insert(component_info.id(), set);
         }
 
         self.sets.get_mut(component_info.id()).unwrap()
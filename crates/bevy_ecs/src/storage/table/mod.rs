@@ -4,6 +4,7 @@ use crate::{
     entity::Entity,
     query::DebugCheckedUnwrap,
     storage::{blob_vec::BlobVec, ImmutableSparseSet, SparseSet},
+    world::error::WorldCapacityError,
 };
 use alloc::{boxed::Box, vec, vec::Vec};
 use bevy_platform_support::collections::HashMap;
@@ -679,6 +680,10 @@ impl Table {
 pub struct Tables {
     tables: Vec<Table>,
     table_ids: HashMap<Box<[ComponentId]>, TableId>,
+    /// The maximum number of tables, if any.
+    max_len: Option<usize>,
+    /// The number of rows every new table is preallocated to hold.
+    row_capacity: usize,
 }
 
 impl Default for Tables {
@@ -687,6 +692,8 @@ impl Default for Tables {
         Tables {
             tables: vec![empty_table],
             table_ids: HashMap::default(),
+            max_len: None,
+            row_capacity: 0,
         }
     }
 }
@@ -703,6 +710,48 @@ impl Tables {
         self.tables.len()
     }
 
+    /// Returns the maximum number of [`Table`]s this collection can contain, if it's limited.
+    #[inline]
+    pub fn limit(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Checks that a [`Table`] with the given sorted components either exists already, or can be
+    /// created without exceeding the [limit](Self::limit).
+    pub(crate) fn check_limit(
+        &self,
+        component_ids: &[ComponentId],
+    ) -> Result<(), WorldCapacityError> {
+        match self.max_len {
+            Some(max_len)
+                if self.tables.len() >= max_len
+                    && !component_ids.is_empty()
+                    && !self.table_ids.contains_key(component_ids) =>
+            {
+                Err(WorldCapacityError::Tables(max_len))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Caps the number of tables, and preallocates the storage for that many tables, each
+    /// holding `row_capacity` rows.
+    ///
+    /// Only the list of tables is preallocated, so tables created later still allocate their
+    /// columns when they're created.
+    pub(crate) fn set_limits(&mut self, max_len: Option<usize>, row_capacity: usize) {
+        self.max_len = max_len;
+        self.row_capacity = row_capacity;
+        if let Some(max_len) = max_len {
+            let additional = max_len.saturating_sub(self.tables.len());
+            self.tables.reserve(additional);
+            self.table_ids.reserve(additional);
+        }
+        for table in &mut self.tables {
+            table.reserve(row_capacity.saturating_sub(table.entity_count()));
+        }
+    }
+
     /// Returns true if this collection contains no [`Table`]s
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -738,6 +787,9 @@ impl Tables {
     ///
     /// # Safety
     /// `component_ids` must contain components that exist in `components`
+    ///
+    /// # Panics
+    /// Panics if a new table is needed but the [limit](Self::limit) has been reached.
     pub(crate) unsafe fn get_id_or_insert(
         &mut self,
         component_ids: &[ComponentId],
@@ -748,12 +800,17 @@ impl Tables {
         }
 
         let tables = &mut self.tables;
+        let max_len = self.max_len;
+        let row_capacity = self.row_capacity;
         let (_key, value) = self
             .table_ids
             .raw_entry_mut()
             .from_key(component_ids)
             .or_insert_with(|| {
-                let mut table = TableBuilder::with_capacity(0, component_ids.len());
+                if let Some(max_len) = max_len.filter(|&max_len| tables.len() >= max_len) {
+                    panic!("{}", WorldCapacityError::Tables(max_len));
+                }
+                let mut table = TableBuilder::with_capacity(row_capacity, component_ids.len());
                 for component_id in component_ids {
                     table = table.add_column(components.get_info_unchecked(*component_id));
                 }
@@ -125,6 +125,31 @@ where
     }
 }
 
+/// A [`Command`] that consumes an iterator of [`Bundles`](Bundle) to spawn a series of entities,
+/// each of which also gets a clone of the `shared` [`Bundle`].
+///
+/// This is more efficient than spawning the entities individually.
+#[track_caller]
+pub fn spawn_batch_with<S, I>(shared: S, bundles_iter: I) -> impl Command
+where
+    S: Bundle + Clone,
+    I: IntoIterator + Send + Sync + 'static,
+    I::Item: Bundle,
+{
+    #[cfg(feature = "track_location")]
+    let caller = Location::caller();
+    move |world: &mut World| {
+        SpawnBatchIter::new(
+            world,
+            bundles_iter
+                .into_iter()
+                .map(move |bundle| (shared.clone(), bundle)),
+            #[cfg(feature = "track_location")]
+            caller,
+        );
+    }
+}
+
 /// A [`Command`] that consumes an iterator to add a series of [`Bundles`](Bundle) to a set of entities.
 ///
 /// If any entities do not exist in the world, this command will return a
@@ -29,7 +29,7 @@ use crate::{
     schedule::ScheduleLabel,
     system::{
         command::HandleError, entity_command::CommandWithEntity, input::SystemInput, Deferred,
-        IntoObserverSystem, IntoSystem, RegisteredSystem, SystemId,
+        IntoObserverSystem, IntoSystem, RegisteredSystem, SystemId, SystemOutput,
     },
     world::{
         command_queue::RawCommandQueue, unsafe_world_cell::UnsafeWorldCell, CommandQueue,
@@ -545,6 +545,46 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue(command::spawn_batch(bundles_iter));
     }
 
+    /// Pushes a [`Command`] to the queue for creating entities that share a [`Bundle`],
+    /// alongside a per-entity [`Bundle`] from `bundles_iter`.
+    ///
+    /// This method is equivalent to calling [`spawn_batch`](Self::spawn_batch) with
+    /// `(shared.clone(), bundle)` for each bundle of `bundles_iter`,
+    /// but the shared part is only cloned for each entity as it's spawned.
+    /// This is useful when spawning many mostly identical entities, like particles or tiles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component, Clone)]
+    /// # struct Tile;
+    /// # #[derive(Component)]
+    /// # struct Position(u32, u32);
+    /// #
+    /// # fn system(mut commands: Commands) {
+    /// commands.spawn_batch_with(
+    ///     Tile,
+    ///     (0..64).flat_map(|x| (0..64).map(move |y| Position(x, y))),
+    /// );
+    /// # }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`spawn_batch`](Self::spawn_batch) to spawn entities with fully owned bundles.
+    #[track_caller]
+    pub fn spawn_batch_with<S, I>(&mut self, shared: S, bundles_iter: I)
+    where
+        S: Bundle + Clone,
+        I: IntoIterator + Send + Sync + 'static,
+        I::Item: Bundle,
+    {
+        self.queue(command::spawn_batch_with(shared, bundles_iter));
+    }
+
     /// Pushes a generic [`Command`] to the command queue.
     ///
     /// If the [`Command`] returns a [`Result`], it will be handled using the [default error handler](error_handler::default).
@@ -897,9 +937,8 @@ impl<'w, 's> Commands<'w, 's> {
     ///
     /// Calls [`World::run_system`](World::run_system).
     ///
-    /// There is no way to get the output of a system when run as a command, because the
-    /// execution of the system happens later. To get the output of a system, use
-    /// [`World::run_system`] or [`World::run_system_with`] instead of running the system as a command.
+    /// The output of the system is discarded. To get the output of a system, use
+    /// [`Commands::run_system_async`] or [`World::run_system`] instead.
     pub fn run_system(&mut self, id: SystemId) {
         self.queue(command::run_system(id).handle_error_with(error_handler::warn()));
     }
@@ -910,9 +949,8 @@ impl<'w, 's> Commands<'w, 's> {
     ///
     /// Calls [`World::run_system_with`](World::run_system_with).
     ///
-    /// There is no way to get the output of a system when run as a command, because the
-    /// execution of the system happens later. To get the output of a system, use
-    /// [`World::run_system`] or [`World::run_system_with`] instead of running the system as a command.
+    /// The output of the system is discarded. To get the output of a system, use
+    /// [`Commands::run_system_async_with`] or [`World::run_system_with`] instead.
     pub fn run_system_with<I>(&mut self, id: SystemId<I>, input: I::Inner<'static>)
     where
         I: SystemInput<Inner<'static>: Send> + 'static,
@@ -920,6 +958,66 @@ impl<'w, 's> Commands<'w, 's> {
         self.queue(command::run_system_with(id, input).handle_error_with(error_handler::warn()));
     }
 
+    /// Runs the system corresponding to the given [`SystemId`] the next time commands are
+    /// applied, returning a [`SystemOutput`] which resolves to the system's output.
+    ///
+    /// This allows code like UI callbacks to trigger work and await its result without
+    /// blocking the frame. The [`SystemOutput`] can be awaited as a future, or polled with
+    /// [`SystemOutput::try_take`].
+    ///
+    /// Calls [`World::run_system`](World::run_system).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::SystemOutput;
+    /// #[derive(Resource)]
+    /// struct PendingScore(SystemOutput<(), u32>);
+    ///
+    /// fn compute_score() -> u32 {
+    ///     42
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// let id = world.register_system(compute_score);
+    ///
+    /// let mut output = world.commands().run_system_async(id);
+    /// assert!(output.try_take().is_none());
+    ///
+    /// world.flush();
+    /// assert_eq!(output.try_take().unwrap().unwrap(), 42);
+    /// ```
+    pub fn run_system_async<O>(&mut self, id: SystemId<(), O>) -> SystemOutput<(), O>
+    where
+        O: Send + 'static,
+    {
+        let (output, resolve) = SystemOutput::new();
+        self.queue(move |world: &mut World| resolve(world.run_system(id)));
+        output
+    }
+
+    /// Runs the system corresponding to the given [`SystemId`] with the given input the next
+    /// time commands are applied, returning a [`SystemOutput`] which resolves to the system's
+    /// output.
+    ///
+    /// Calls [`World::run_system_with`](World::run_system_with).
+    ///
+    /// See [`Commands::run_system_async`] for more details.
+    pub fn run_system_async_with<I, O>(
+        &mut self,
+        id: SystemId<I, O>,
+        input: I::Inner<'static>,
+    ) -> SystemOutput<I, O>
+    where
+        I: SystemInput<Inner<'static>: Send> + 'static,
+        O: Send + 'static,
+    {
+        let (output, resolve) = SystemOutput::new();
+        self.queue(move |world: &mut World| resolve(world.run_system_with(id, input)));
+        output
+    }
+
     /// Registers a system and returns a [`SystemId`] so it can later be called by [`World::run_system`].
     ///
     /// It's possible to register the same systems more than once, they'll be stored separately.
@@ -2552,15 +2650,15 @@ mod tests {
 
         fn nothing() {}
 
-        assert!(world.iter_resources().count() == 0);
+        let resources = world.iter_resources().count();
         let id = world.register_system_cached(nothing);
-        assert!(world.iter_resources().count() == 1);
+        assert_eq!(world.iter_resources().count(), resources + 1);
         assert!(world.get_entity(id.entity).is_ok());
 
         let mut commands = Commands::new(&mut queue, &world);
         commands.unregister_system_cached(nothing);
         queue.apply(&mut world);
-        assert!(world.iter_resources().count() == 0);
+        assert_eq!(world.iter_resources().count(), resources);
         assert!(world.get_entity(id.entity).is_err());
     }
 
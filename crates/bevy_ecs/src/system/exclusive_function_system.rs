@@ -130,6 +130,11 @@ where
 
             world.flush();
             self.system_meta.last_run = world.increment_change_tick();
+            // Changes made by this system happened at the tick it just incremented from.
+            #[cfg(feature = "track_change_timestamps")]
+            world
+                .change_timestamps()
+                .record_system_run(self.system_meta.last_run, self.system_meta.name.clone());
 
             out
         })
@@ -820,6 +820,11 @@ where
         let _span_guard = self.system_meta.system_span.enter();
 
         let change_tick = world.increment_change_tick();
+        #[cfg(feature = "track_change_timestamps")]
+        // SAFETY: This only accesses the change timestamps, which is world metadata.
+        unsafe { world.world_metadata() }
+            .change_timestamps()
+            .record_system_run(change_tick, self.system_meta.name.clone());
 
         let param_state = &mut self.state.as_mut().expect(Self::ERROR_UNINITIALIZED).param;
         // SAFETY:
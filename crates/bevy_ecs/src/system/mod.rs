@@ -127,6 +127,8 @@ mod exclusive_function_system;
 mod exclusive_system_param;
 mod function_system;
 mod input;
+#[cfg(feature = "std")]
+mod non_send_proxy;
 mod observer_system;
 mod query;
 mod schedule_system;
@@ -145,6 +147,8 @@ pub use exclusive_function_system::*;
 pub use exclusive_system_param::*;
 pub use function_system::*;
 pub use input::*;
+#[cfg(feature = "std")]
+pub use non_send_proxy::*;
 pub use observer_system::*;
 pub use query::*;
 pub use schedule_system::*;
@@ -477,6 +481,28 @@ mod tests {
         assert_eq!(*world.resource::<SystemRan>(), SystemRan::Yes);
     }
 
+    #[test]
+    fn changed_archetypes() {
+        let mut world = World::default();
+        let entity = world.spawn(A).id();
+        world.spawn((A, B));
+
+        let mut system_state: SystemState<Query<&A>> = SystemState::new(&mut world);
+        let mut changed = |world: &World| system_state.get(world).iter_changed_archetypes().count();
+
+        // Archetypes always count as changed the first time the system runs.
+        assert_eq!(changed(&world), 2);
+        assert_eq!(changed(&world), 0);
+
+        // The entity moves from the `A` archetype to the `(A, C)` archetype.
+        world.entity_mut(entity).insert(C);
+        assert_eq!(changed(&world), 2);
+        assert_eq!(changed(&world), 0);
+
+        world.despawn(entity);
+        assert_eq!(changed(&world), 1);
+    }
+
     #[test]
     fn changed_resource_system() {
         use crate::resource::Resource;
@@ -0,0 +1,267 @@
+use alloc::boxed::Box;
+use core::{any::Any, any::TypeId, marker::PhantomData};
+use std::{
+    sync::mpsc::{self, Sender},
+    thread::{self, JoinHandle},
+};
+
+use bevy_utils::TypeIdMap;
+use disqualified::ShortName;
+use log::warn;
+
+use crate::{
+    self as bevy_ecs,
+    resource::Resource,
+    system::{Res, SystemParam},
+};
+
+type NonSendTask = Box<dyn FnOnce(&mut NonSendStorage) + Send>;
+
+#[derive(Default)]
+struct NonSendStorage {
+    values: TypeIdMap<Box<dyn Any>>,
+}
+
+impl NonSendStorage {
+    fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut())
+    }
+}
+
+/// A dedicated thread that owns [`!Send`](Send) resources, which systems access through a
+/// [`NonSendProxy`].
+///
+/// Systems accessing a [`NonSend`](crate::system::NonSend) resource are always run on the main
+/// thread, which serializes them with everything else the main thread is doing. Resources stored
+/// on this thread instead are never moved off it: systems send closures to the thread, which runs
+/// them in order. Since this resource is [`Send`], systems using a [`NonSendProxy`] can run on any
+/// thread of the multithreaded executor.
+///
+/// Resources are created on the thread as well, which is why they are inserted through a [`Send`]
+/// constructor rather than by value. They are dropped on the thread when this resource is dropped.
+///
+/// Spawning the thread requires platform support for threads, which is missing on `wasm32`.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, system::{NonSendProxy, NonSendThread}};
+/// # use std::rc::Rc;
+/// #[derive(Default)]
+/// struct Window {
+///     handle: Rc<()>,
+///     title: String,
+/// }
+///
+/// let mut world = World::new();
+/// let thread = NonSendThread::new();
+/// thread.insert_with(Window::default);
+/// world.insert_resource(thread);
+///
+/// fn set_title(window: NonSendProxy<Window>) {
+///     window.send(|window| window.title = "Hello".to_string());
+/// }
+///
+/// fn read_title(window: NonSendProxy<Window>) {
+///     let title = window.call(|window| window.title.clone());
+///     assert_eq!(title, "Hello");
+/// }
+///
+/// # use bevy_ecs::system::RunSystemOnce;
+/// world.run_system_once(set_title).unwrap();
+/// world.run_system_once(read_title).unwrap();
+/// ```
+#[derive(Resource)]
+pub struct NonSendThread {
+    sender: Option<Sender<NonSendTask>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl NonSendThread {
+    /// Spawns a new thread to store [`!Send`](Send) resources on.
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<NonSendTask>();
+        let thread = thread::Builder::new()
+            .name("non-send resources".into())
+            .spawn(move || {
+                let mut storage = NonSendStorage::default();
+                for task in receiver {
+                    task(&mut storage);
+                }
+            })
+            .expect("failed to spawn the thread for non-send resources");
+
+        Self {
+            sender: Some(sender),
+            thread: Some(thread),
+        }
+    }
+
+    /// Inserts a resource created by `func` on the thread, replacing any existing resource of
+    /// the same type.
+    pub fn insert_with<T: 'static>(&self, func: impl FnOnce() -> T + Send + 'static) {
+        self.run(move |storage| {
+            storage.values.insert(TypeId::of::<T>(), Box::new(func()));
+        });
+    }
+
+    /// Inserts a resource created by `func` on the thread, if there is no existing resource of
+    /// the same type.
+    pub fn init_with<T: 'static>(&self, func: impl FnOnce() -> T + Send + 'static) {
+        self.run(move |storage| {
+            storage
+                .values
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Box::new(func()));
+        });
+    }
+
+    /// Removes the resource of type `T` from the thread, dropping it there.
+    pub fn remove<T: 'static>(&self) {
+        self.run(|storage| {
+            storage.values.remove(&TypeId::of::<T>());
+        });
+    }
+
+    /// Queues `func` to run on the resource of type `T`, without waiting for it to complete.
+    ///
+    /// Queued closures run in the order they were sent. If the resource does not exist when
+    /// `func` runs, it is skipped and a warning is logged.
+    pub fn send<T: 'static>(&self, func: impl FnOnce(&mut T) + Send + 'static) {
+        self.run(move |storage| match storage.get_mut::<T>() {
+            Some(value) => func(value),
+            None => warn!(
+                "Non-send resource `{}` does not exist on the non-send thread",
+                ShortName::of::<T>()
+            ),
+        });
+    }
+
+    /// Runs `func` on the resource of type `T` and blocks until it returns its result.
+    ///
+    /// All closures sent before this one run first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist, or if the thread has panicked.
+    pub fn call<T: 'static, R: Send + 'static>(
+        &self,
+        func: impl FnOnce(&mut T) -> R + Send + 'static,
+    ) -> R {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.run(move |storage| {
+            // The receiver only goes away if the calling thread panicked.
+            let _ = sender.send(storage.get_mut::<T>().map(func));
+        });
+        match receiver.recv() {
+            Ok(Some(result)) => result,
+            Ok(None) => panic!(
+                "Non-send resource `{}` does not exist on the non-send thread",
+                ShortName::of::<T>()
+            ),
+            Err(_) => panic!("The non-send thread has panicked"),
+        }
+    }
+
+    fn run(&self, task: impl FnOnce(&mut NonSendStorage) + Send + 'static) {
+        // The sender is only taken when dropping.
+        let sender = self.sender.as_ref().unwrap();
+        if sender.send(Box::new(task)).is_err() {
+            panic!("The non-send thread has panicked");
+        }
+    }
+}
+
+impl Default for NonSendThread {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for NonSendThread {
+    fn drop(&mut self) {
+        // Closing the channel stops the thread once it has run all queued closures, dropping
+        // the resources it owns.
+        self.sender.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Access to a [`!Send`](Send) resource stored on the [`NonSendThread`].
+///
+/// Unlike [`NonSend`](crate::system::NonSend), this does not force the system onto the main
+/// thread. Instead, closures are sent to the thread owning the resource, either without waiting
+/// using [`send`](Self::send), or waiting for a result using [`call`](Self::call).
+///
+/// # Panics
+///
+/// Panics during system initialization if the [`NonSendThread`] resource does not exist.
+#[derive(SystemParam)]
+pub struct NonSendProxy<'w, T: 'static> {
+    thread: Res<'w, NonSendThread>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'w, T: 'static> NonSendProxy<'w, T> {
+    /// Queues `func` to run on the resource, without waiting for it to complete.
+    ///
+    /// See [`NonSendThread::send`].
+    pub fn send(&self, func: impl FnOnce(&mut T) + Send + 'static) {
+        self.thread.send(func);
+    }
+
+    /// Runs `func` on the resource and blocks until it returns its result.
+    ///
+    /// This blocks the thread running the system, so prefer [`send`](Self::send) when the
+    /// result is not needed.
+    ///
+    /// See [`NonSendThread::call`].
+    pub fn call<R: Send + 'static>(&self, func: impl FnOnce(&mut T) -> R + Send + 'static) -> R {
+        self.thread.call(func)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use crate::{
+        prelude::*,
+        system::{NonSendProxy, NonSendThread, RunSystemOnce},
+    };
+
+    #[derive(Default)]
+    struct Counter(Rc<Cell<u32>>);
+
+    #[test]
+    fn proxy_runs_in_order() {
+        let mut world = World::new();
+        let thread = NonSendThread::new();
+        thread.insert_with(Counter::default);
+        world.insert_resource(thread);
+
+        fn increment(counter: NonSendProxy<Counter>) {
+            counter.send(|counter| counter.0.set(counter.0.get() + 1));
+        }
+
+        fn read(counter: NonSendProxy<Counter>) -> u32 {
+            counter.call(|counter| counter.0.get())
+        }
+
+        world.run_system_once(increment).unwrap();
+        world.run_system_once(increment).unwrap();
+        assert_eq!(world.run_system_once(read).unwrap(), 2);
+    }
+
+    #[test]
+    fn missing_resource_is_skipped() {
+        let thread = NonSendThread::new();
+        thread.send::<Counter>(|_| unreachable!());
+        thread.init_with(Counter::default);
+        thread.init_with::<Counter>(|| unreachable!());
+        assert_eq!(thread.call(|counter: &mut Counter| counter.0.get()), 0);
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
+    archetype::Archetype,
     batching::BatchingStrategy,
     component::Tick,
-    entity::{Entity, EntityBorrow, EntitySet},
+    entity::{Entity, EntityBorrow, EntityIndexSet, EntitySet},
     query::{
-        QueryCombinationIter, QueryData, QueryEntityError, QueryFilter, QueryIter, QueryManyIter,
-        QueryManyUniqueIter, QueryParIter, QuerySingleError, QueryState, ROQueryItem,
-        ReadOnlyQueryData,
+        ArchetypeFilter, QueryCombinationIter, QueryData, QueryEntityError, QueryFilter, QueryIter,
+        QueryManyIter, QueryManyUniqueIter, QueryParIter, QuerySingleError, QuerySliceData,
+        QuerySliceIter, QueryState, ROQueryItem, ReadOnlyQueryData,
     },
     world::unsafe_world_cell::UnsafeWorldCell,
 };
@@ -535,6 +536,129 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the read-only query items of every matched table, as contiguous
+    /// slices with one element per entity.
+    ///
+    /// This allows processing component data in batches, for example with SIMD, instead of one
+    /// entity at a time. The slices of a table all have the same length, and the same order as the
+    /// entities in the table. Empty tables are skipped, and the iteration order is not guaranteed.
+    ///
+    /// Only [`Entity`], `&T`, `&mut T` and tuples of these can be fetched as slices, and only
+    /// archetypal filters such as [`With`](crate::query::With) and
+    /// [`Without`](crate::query::Without) can be used, as filters are applied to whole tables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Mass(f32);
+    /// fn total_mass_system(query: Query<&Mass>) {
+    ///     let total: f32 = query
+    ///         .iter_slices()
+    ///         .map(|masses| masses.iter().map(|mass| mass.0).sum::<f32>())
+    ///         .sum();
+    ///     println!("The total mass is {total}");
+    /// }
+    /// # bevy_ecs::system::assert_is_system(total_mass_system);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of the query, including components in its filters, is stored in
+    /// [sparse sets](crate::component::StorageType::SparseSet).
+    ///
+    /// # See also
+    ///
+    /// [`iter_slices_mut`](Self::iter_slices_mut) for mutable slices.
+    #[inline]
+    #[track_caller]
+    pub fn iter_slices(&self) -> QuerySliceIter<'_, 's, D::ReadOnly, F>
+    where
+        D::ReadOnly: QuerySliceData,
+        F: ArchetypeFilter,
+    {
+        // SAFETY:
+        // - `self.world` has permission to access the required components.
+        // - The query is read-only, so it can be aliased even if it was originally mutable.
+        unsafe { QuerySliceIter::new(self.world, self.state.as_readonly(), self.this_run) }
+    }
+
+    /// Returns an [`Iterator`] over the query items of every matched table, as contiguous slices
+    /// with one element per entity.
+    ///
+    /// Mutable components are returned as a [`MutSlice`](crate::query::MutSlice), which marks
+    /// every value in the slice as changed when it's mutably dereferenced.
+    ///
+    /// # Example
+    ///
+    /// Here, the `integrate_system` moves every particle by its velocity, one table at a time:
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Position(f32);
+    /// # #[derive(Component)]
+    /// # struct Velocity(f32);
+    /// fn integrate_system(mut query: Query<(&mut Position, &Velocity)>) {
+    ///     for (mut positions, velocities) in query.iter_slices_mut() {
+    ///         for (position, velocity) in positions.iter_mut().zip(velocities) {
+    ///             position.0 += velocity.0;
+    ///         }
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(integrate_system);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of the query, including components in its filters, is stored in
+    /// [sparse sets](crate::component::StorageType::SparseSet).
+    ///
+    /// # See also
+    ///
+    /// [`iter_slices`](Self::iter_slices) for read-only slices.
+    #[inline]
+    #[track_caller]
+    pub fn iter_slices_mut(&mut self) -> QuerySliceIter<'_, 's, D, F>
+    where
+        D: QuerySliceData,
+        F: ArchetypeFilter,
+    {
+        // SAFETY: `self.world` has permission to access the required components.
+        unsafe { QuerySliceIter::new(self.world, self.state, self.this_run) }
+    }
+
+    /// Returns an [`Iterator`] over the [`Archetype`]s matched by this query that had entities added or removed
+    /// since the last time the system ran.
+    ///
+    /// This only checks a single change tick per archetype, so it's much cheaper than iterating all query items
+    /// to find structural changes. Archetypes that lost all of their entities are included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Collider;
+    /// fn rebuild_colliders_system(query: Query<&Collider>) {
+    ///     for archetype in query.iter_changed_archetypes() {
+    ///         println!("Rebuilding {} colliders", archetype.len());
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(rebuild_colliders_system);
+    /// ```
+    pub fn iter_changed_archetypes(&self) -> impl Iterator<Item = &Archetype> + '_ {
+        let archetypes = self.world.archetypes();
+        self.state
+            .matched_archetypes()
+            .map(move |id| &archetypes[id])
+            .filter(move |archetype| archetype.is_changed(self.last_run, self.this_run))
+    }
+
     /// Returns a [`QueryCombinationIter`] over all combinations of `K` read-only query items without repetition.
     ///
     /// This iterator is always guaranteed to return results from each unique pair of matching entities.
@@ -1969,3 +2093,75 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Populated<'w, 's, D, F> {
         self.0
     }
 }
+
+/// [System parameter] that works very much like [`Query`], but remembers which entities it matches
+/// between runs of the system.
+///
+/// Iterating a [`Query`] visits every archetype it matches, which can be slow for narrow queries
+/// in worlds with many archetypes. A `CachedQuery` instead stores the matching entities in a
+/// [`QueryCache`], which is kept up to date by observers on the components that decide whether an
+/// entity matches. [`iter`](Self::iter) and [`iter_mut`](Self::iter_mut) then only visit those
+/// entities.
+///
+/// This trades iteration speed for a small cost whenever these components are added or removed,
+/// so it is best suited to queries that are iterated often but whose matches rarely change.
+/// All other methods are forwarded to the inner [`Query`], and do not use the cache.
+///
+/// See [`QueryCache`] for the limitations of the cache.
+///
+/// [System parameter]: crate::system::SystemParam
+/// [`QueryCache`]: crate::query::QueryCache
+pub struct CachedQuery<'w, 's, D: QueryData, F: QueryFilter = ()> {
+    pub(crate) query: Query<'w, 's, D, F>,
+    pub(crate) entities: &'s EntityIndexSet,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> Deref for CachedQuery<'w, 's, D, F> {
+    type Target = Query<'w, 's, D, F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl<D: QueryData, F: QueryFilter> DerefMut for CachedQuery<'_, '_, D, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.query
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter> CachedQuery<'w, 's, D, F> {
+    /// Returns an [`Iterator`] over the read-only query items of the cached entities.
+    ///
+    /// Items are returned in an unspecified order.
+    #[inline]
+    pub fn iter(
+        &self,
+    ) -> QueryManyUniqueIter<'_, 's, D::ReadOnly, F, <&'s EntityIndexSet as IntoIterator>::IntoIter>
+    {
+        self.query.iter_many_unique(self.entities)
+    }
+
+    /// Returns an [`Iterator`] over the query items of the cached entities.
+    ///
+    /// Items are returned in an unspecified order.
+    #[inline]
+    pub fn iter_mut(
+        &mut self,
+    ) -> QueryManyUniqueIter<'_, 's, D, F, <&'s EntityIndexSet as IntoIterator>::IntoIter> {
+        self.query.iter_many_unique_mut(self.entities)
+    }
+
+    /// Returns the entities matched by the archetypal part of the query.
+    ///
+    /// Entities rejected by non-archetypal filters such as [`Changed`](crate::query::Changed)
+    /// are included.
+    pub fn entities(&self) -> &'s EntityIndexSet {
+        self.entities
+    }
+
+    /// Returns the inner query with ownership.
+    pub fn into_inner(self) -> Query<'w, 's, D, F> {
+        self.query
+    }
+}
@@ -9,9 +9,15 @@ use crate::{
 };
 use alloc::boxed::Box;
 use bevy_ecs_macros::{require, Component, Resource};
+use bevy_platform_support::sync::{Arc, Mutex, MutexGuard};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use core::marker::PhantomData;
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
 use thiserror::Error;
 
 /// A small wrapper for [`BoxedSystem`] that also keeps track whether or not the system has been initialized.
@@ -496,6 +502,81 @@ impl<I: SystemInput, O> core::fmt::Debug for RegisteredSystemError<I, O> {
     }
 }
 
+/// A handle to the output of a system queued with
+/// [`Commands::run_system_async`](crate::system::Commands::run_system_async).
+///
+/// The system is run the next time commands are applied, at which point this resolves to
+/// the system's output, or the error that prevented it from running.
+/// The output can be awaited as a [`Future`], or polled each frame with [`SystemOutput::try_take`].
+///
+/// If the commands are dropped without being applied, this never resolves.
+pub struct SystemOutput<I: SystemInput = (), O = ()> {
+    state: Arc<Mutex<SystemOutputState<I, O>>>,
+}
+
+struct SystemOutputState<I: SystemInput, O> {
+    result: Option<Result<O, RegisteredSystemError<I, O>>>,
+    waker: Option<Waker>,
+}
+
+impl<I: SystemInput, O> SystemOutput<I, O> {
+    /// Creates a pending output, along with the function which resolves it.
+    pub(crate) fn new() -> (Self, impl FnOnce(Result<O, RegisteredSystemError<I, O>>)) {
+        let state = Arc::new(Mutex::new(SystemOutputState {
+            result: None,
+            waker: None,
+        }));
+        let sender = Arc::clone(&state);
+        let resolve = move |result| {
+            let mut state = sender.lock().unwrap_or_else(|err| err.into_inner());
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        };
+        (Self { state }, resolve)
+    }
+
+    /// Returns `true` if the system has run, and its output is ready to be taken.
+    pub fn is_ready(&self) -> bool {
+        self.lock().result.is_some()
+    }
+
+    /// Takes the output of the system if it has run, or returns [`None`] otherwise.
+    ///
+    /// Once the output has been taken, this returns [`None`].
+    pub fn try_take(&mut self) -> Option<Result<O, RegisteredSystemError<I, O>>> {
+        self.lock().result.take()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SystemOutputState<I, O>> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<I: SystemInput, O> Future for SystemOutput<I, O> {
+    type Output = Result<O, RegisteredSystemError<I, O>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.lock();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<I: SystemInput, O> core::fmt::Debug for SystemOutput<I, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SystemOutput")
+            .field("ready", &self.is_ready())
+            .finish()
+    }
+}
+
 mod tests {
     use crate::prelude::*;
     use crate::{self as bevy_ecs};
@@ -633,6 +714,37 @@ mod tests {
         assert_eq!(world.entities.len(), entity_count + 1);
     }
 
+    #[test]
+    fn async_output_values() {
+        fn increment_sys(In(amount): In<u8>, mut counter: ResMut<Counter>) -> u8 {
+            counter.0 += amount;
+            counter.0
+        }
+
+        let mut world = World::new();
+        world.insert_resource(Counter(1));
+        let id = world.register_system(increment_sys);
+
+        let mut output = world.commands().run_system_async_with(id, 2);
+        assert!(!output.is_ready());
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        world.flush();
+        assert!(output.is_ready());
+        assert_eq!(output.try_take().unwrap().unwrap(), 3);
+        assert!(output.try_take().is_none());
+
+        world.unregister_system(id).unwrap();
+        let mut output = world.commands().run_system_async_with(id, 2);
+        world.flush();
+        assert!(matches!(
+            output.try_take(),
+            Some(Err(
+                crate::system::RegisteredSystemError::SystemIdNotRegistered(_)
+            ))
+        ));
+    }
+
     #[test]
     fn nested_systems() {
         use crate::system::SystemId;
@@ -0,0 +1,322 @@
+use crate::component::{ComponentId, ComponentTicks, Tick};
+use alloc::vec::Vec;
+use core::slice;
+
+#[cfg(feature = "track_location")]
+use core::panic::Location;
+
+#[cfg(feature = "track_change_timestamps")]
+use {
+    crate::change_detection::MAX_CHANGE_AGE,
+    alloc::{borrow::Cow, collections::VecDeque},
+    bevy_platform_support::{
+        sync::{Mutex, PoisonError},
+        time::Instant,
+    },
+    core::time::Duration,
+};
+
+/// A summary of when each component of an entity was last added and changed.
+///
+/// This is returned by [`EntityRef::change_summary`], and is intended for inspector tooling
+/// which wants to show something like "changed 3 frames ago by system `move_player`".
+///
+/// The source code location which last changed each component is only available when the
+/// `track_location` feature is enabled, and the frame, time and system of each change are only
+/// available when the `track_change_timestamps` feature is enabled.
+///
+/// [`EntityRef::change_summary`]: crate::world::EntityRef::change_summary
+#[derive(Debug, Clone)]
+pub struct ChangeSummary<'w> {
+    pub(crate) components: Vec<ComponentChangeSummary<'w>>,
+    pub(crate) this_run: Tick,
+    #[cfg(feature = "track_change_timestamps")]
+    pub(crate) now: ChangeTimestamp,
+}
+
+impl<'w> ChangeSummary<'w> {
+    /// Returns the summaries of each component of the entity.
+    pub fn components(&self) -> &[ComponentChangeSummary<'w>] {
+        &self.components
+    }
+
+    /// Returns an iterator over the summaries of each component of the entity.
+    pub fn iter(&self) -> slice::Iter<'_, ComponentChangeSummary<'w>> {
+        self.components.iter()
+    }
+
+    /// Returns the summary of the component with the given [`ComponentId`],
+    /// or `None` if the entity doesn't have that component.
+    pub fn get(&self, id: ComponentId) -> Option<&ComponentChangeSummary<'w>> {
+        self.components.iter().find(|component| component.id == id)
+    }
+
+    /// Returns the change tick of the world when the summary was created.
+    pub fn this_run(&self) -> Tick {
+        self.this_run
+    }
+
+    /// Returns the number of ticks between the last change to the given component
+    /// and the creation of the summary.
+    pub fn ticks_since_changed(&self, component: &ComponentChangeSummary) -> u32 {
+        self.this_run
+            .relative_to(component.ticks.changed)
+            .get()
+            .min(Tick::MAX.get())
+    }
+
+    /// Returns the frame and time at which the summary was created.
+    #[cfg(feature = "track_change_timestamps")]
+    pub fn now(&self) -> ChangeTimestamp {
+        self.now
+    }
+
+    /// Returns the number of frames between the last change to the given component
+    /// and the creation of the summary.
+    ///
+    /// Returns `None` if the change is older than the [`ChangeTimestamps`] history.
+    #[cfg(feature = "track_change_timestamps")]
+    pub fn frames_since_changed(&self, component: &ComponentChangeSummary) -> Option<u64> {
+        component
+            .changed_at
+            .map(|changed_at| self.now.frame - changed_at.frame)
+    }
+
+    /// Returns the time between the start of the frame in which the given component was
+    /// last changed and the creation of the summary.
+    ///
+    /// Returns `None` if the change is older than the [`ChangeTimestamps`] history.
+    #[cfg(feature = "track_change_timestamps")]
+    pub fn time_since_changed(&self, component: &ComponentChangeSummary) -> Option<Duration> {
+        component
+            .changed_at
+            .map(|changed_at| self.now.time.saturating_duration_since(changed_at.time))
+    }
+}
+
+impl<'a, 'w> IntoIterator for &'a ChangeSummary<'w> {
+    type Item = &'a ComponentChangeSummary<'w>;
+    type IntoIter = slice::Iter<'a, ComponentChangeSummary<'w>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// When a single component of an entity was last added and changed, as part of a
+/// [`ChangeSummary`].
+#[derive(Debug, Clone)]
+pub struct ComponentChangeSummary<'w> {
+    /// The ID of the component.
+    pub id: ComponentId,
+    /// The name of the component.
+    pub name: &'w str,
+    /// The ticks at which the component was added and last changed.
+    pub ticks: ComponentTicks,
+    /// The source code location which last changed the component.
+    #[cfg(feature = "track_location")]
+    pub changed_by: &'static Location<'static>,
+    /// The frame and time at which the component was added.
+    ///
+    /// This is `None` if it was added before the oldest frame in the [`ChangeTimestamps`] history.
+    #[cfg(feature = "track_change_timestamps")]
+    pub added_at: Option<ChangeTimestamp>,
+    /// The frame and time at which the component was last changed.
+    ///
+    /// This is `None` if it was changed before the oldest frame in the [`ChangeTimestamps`]
+    /// history.
+    #[cfg(feature = "track_change_timestamps")]
+    pub changed_at: Option<ChangeTimestamp>,
+    /// The name of the system which last changed the component.
+    ///
+    /// This is `None` if it was changed before the oldest frame in the [`ChangeTimestamps`]
+    /// history, or outside of a system, like directly through the [`World`](crate::world::World).
+    #[cfg(feature = "track_change_timestamps")]
+    pub changed_by_system: Option<Cow<'static, str>>,
+}
+
+/// The frame in which a change happened, along with the wall-clock time at which that frame
+/// started.
+#[cfg(feature = "track_change_timestamps")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChangeTimestamp {
+    /// The number of frames since the [`World`](crate::world::World) was created.
+    ///
+    /// A frame ends every time [`World::clear_trackers`](crate::world::World::clear_trackers)
+    /// is called, which happens once per update in a Bevy app.
+    pub frame: u64,
+    /// The time at which the frame started.
+    pub time: Instant,
+}
+
+/// A history of the change ticks at which recent frames started, used to find the frame and
+/// time at which a component was changed, and the system which changed it.
+///
+/// Rather than storing a timestamp for every component, the world records the change tick and
+/// time at the start of every frame, which is enough to convert any recent change tick into a
+/// [`ChangeTimestamp`]. Every system run gets its own change tick, so recording the tick of each
+/// system run is enough to find the system which made a change.
+/// Only the most recent [`capacity`](Self::capacity) frames are kept.
+#[cfg(feature = "track_change_timestamps")]
+#[derive(Debug)]
+pub struct ChangeTimestamps {
+    /// The recorded frames, from oldest to newest.
+    frames: VecDeque<FrameRecord>,
+    /// The system runs of the current frame, which systems running in parallel record into.
+    current_system_runs: Mutex<Vec<(Tick, Cow<'static, str>)>>,
+    capacity: usize,
+}
+
+/// The start of a recorded frame, and the system runs in it once the frame has ended.
+#[cfg(feature = "track_change_timestamps")]
+#[derive(Debug)]
+struct FrameRecord {
+    start: Tick,
+    timestamp: ChangeTimestamp,
+    system_runs: Vec<(Tick, Cow<'static, str>)>,
+}
+
+#[cfg(feature = "track_change_timestamps")]
+impl ChangeTimestamps {
+    /// The default number of frames kept in the history, which is one minute at 60 frames
+    /// per second.
+    pub const DEFAULT_CAPACITY: usize = 3600;
+
+    pub(crate) fn new(tick: Tick) -> Self {
+        let mut frames = VecDeque::new();
+        frames.push_back(FrameRecord {
+            start: tick,
+            timestamp: ChangeTimestamp {
+                frame: 0,
+                time: Instant::now(),
+            },
+            system_runs: Vec::new(),
+        });
+        Self {
+            frames,
+            current_system_runs: Mutex::new(Vec::new()),
+            capacity: Self::DEFAULT_CAPACITY,
+        }
+    }
+
+    /// Returns the number of the current frame.
+    pub fn current_frame(&self) -> u64 {
+        self.latest().frame
+    }
+
+    /// Returns the current frame along with the current time.
+    pub fn now(&self) -> ChangeTimestamp {
+        ChangeTimestamp {
+            frame: self.current_frame(),
+            time: Instant::now(),
+        }
+    }
+
+    /// Returns the maximum number of frames kept in the history.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of frames kept in the history.
+    ///
+    /// The current frame is always kept, so the capacity is at least 1.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.truncate();
+    }
+
+    /// Returns the frame and time at which the given change tick happened.
+    ///
+    /// `this_run` is the current change tick, used as a reference to deal with wraparound.
+    ///
+    /// Returns `None` if the tick is older than the oldest recorded frame.
+    pub fn get(&self, tick: Tick, this_run: Tick) -> Option<ChangeTimestamp> {
+        self.frame_index(tick, this_run)
+            .map(|index| self.frames[index].timestamp)
+    }
+
+    /// Returns the name of the system which ran at the given change tick.
+    ///
+    /// `this_run` is the current change tick, used as a reference to deal with wraparound.
+    ///
+    /// Returns `None` if the tick is older than the oldest recorded frame, or if no system ran
+    /// at that tick, like for changes made directly through the [`World`](crate::world::World).
+    pub fn system(&self, tick: Tick, this_run: Tick) -> Option<Cow<'static, str>> {
+        let index = self.frame_index(tick, this_run)?;
+        let find = |system_runs: &[(Tick, Cow<'static, str>)]| {
+            system_runs
+                .iter()
+                .find(|(run, _)| *run == tick)
+                .map(|(_, name)| name.clone())
+        };
+        if index + 1 == self.frames.len() {
+            find(
+                &self
+                    .current_system_runs
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner),
+            )
+        } else {
+            find(&self.frames[index].system_runs)
+        }
+    }
+
+    /// Records that the system with the given name ran at the given change tick.
+    pub(crate) fn record_system_run(&self, tick: Tick, name: Cow<'static, str>) {
+        self.current_system_runs
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((tick, name));
+    }
+
+    /// Starts a new frame at the given change tick.
+    pub(crate) fn record_frame(&mut self, tick: Tick) {
+        let frame = self.current_frame() + 1;
+        let system_runs = core::mem::take(
+            self.current_system_runs
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        // There is always at least one frame
+        self.frames.back_mut().unwrap().system_runs = system_runs;
+        self.frames.push_back(FrameRecord {
+            start: tick,
+            timestamp: ChangeTimestamp {
+                frame,
+                time: Instant::now(),
+            },
+            system_runs: Vec::new(),
+        });
+        self.truncate();
+    }
+
+    /// Forgets any frames which are too old for their ticks to be compared to `change_tick`.
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+        while self.frames.len() > 1
+            && change_tick.relative_to(self.frames[0].start).get() > MAX_CHANGE_AGE
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Returns the index of the frame in which the given change tick happened.
+    fn frame_index(&self, tick: Tick, this_run: Tick) -> Option<usize> {
+        let age = this_run.relative_to(tick).get();
+        // Frames are stored from oldest to newest, so their ages are in descending order
+        let index = self
+            .frames
+            .partition_point(|frame| this_run.relative_to(frame.start).get() >= age);
+        index.checked_sub(1)
+    }
+
+    fn latest(&self) -> ChangeTimestamp {
+        // There is always at least one frame
+        self.frames.back().unwrap().timestamp
+    }
+
+    fn truncate(&mut self) {
+        while self.frames.len() > self.capacity {
+            self.frames.pop_front();
+        }
+    }
+}
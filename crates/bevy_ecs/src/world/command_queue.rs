@@ -142,6 +142,33 @@ impl RawCommandQueue {
         (unsafe { *self.cursor.as_ref() }) >= (unsafe { self.bytes.as_ref() }).len()
     }
 
+    /// Returns the length of the queue in bytes, including commands which have already been applied
+    /// by an ongoing [`apply_or_drop_queued`](Self::apply_or_drop_queued).
+    ///
+    /// # Safety
+    ///
+    /// * Caller ensures that `self` has not outlived the underlying queue
+    pub(crate) unsafe fn len_bytes(&self) -> usize {
+        // SAFETY: Caller ensures the pointer is valid
+        unsafe { self.bytes.as_ref() }.len()
+    }
+
+    /// Moves the commands pushed since the queue was `len` bytes long to the end of `other`,
+    /// without applying them.
+    ///
+    /// # Safety
+    ///
+    /// * Caller ensures that neither `self` nor `other` have outlived their underlying queues
+    /// * `len` must be a length previously returned by [`len_bytes`](Self::len_bytes), and no
+    ///   commands before it may have been applied or moved since
+    pub(crate) unsafe fn move_since(&mut self, len: usize, other: &mut RawCommandQueue) {
+        // SAFETY: Caller ensures the pointers are valid
+        let (bytes, other_bytes) = unsafe { (self.bytes.as_mut(), other.bytes.as_mut()) };
+        // Commands are read unaligned, so they can be moved byte by byte.
+        other_bytes.extend_from_slice(&bytes[len..]);
+        bytes.truncate(len);
+    }
+
     /// Push a [`Command`] onto the queue.
     ///
     /// # Safety
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::ops::Deref;
 #[cfg(feature = "track_location")]
 use core::panic::Location;
@@ -7,6 +8,7 @@ use crate::{
     change_detection::MutUntyped,
     component::{ComponentId, HookContext, Mutable},
     entity::Entity,
+    entity_disabling::{self, DISABLED},
     event::{Event, EventId, Events, SendBatchIds},
     observer::{Observers, TriggerTargets},
     prelude::{Component, QueryState},
@@ -17,7 +19,7 @@ use crate::{
     world::{error::EntityFetchError, WorldEntityFetch},
 };
 
-use super::{unsafe_world_cell::UnsafeWorldCell, Mut, World, ON_INSERT, ON_REPLACE};
+use super::{unsafe_world_cell::UnsafeWorldCell, Mut, World, ON_ADD, ON_INSERT, ON_REPLACE};
 
 /// A [`World`] reference that disallows structural ECS changes.
 /// This includes initializing resources, registering components or spawning entities.
@@ -535,7 +537,7 @@ impl<'w> DeferredWorld<'w> {
             for component_id in targets {
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
-                if let Some(hook) = hooks.on_add {
+                for hook in hooks.on_add.iter() {
                     hook(
                         DeferredWorld { world: self.world },
                         HookContext {
@@ -568,7 +570,7 @@ impl<'w> DeferredWorld<'w> {
             for component_id in targets {
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
-                if let Some(hook) = hooks.on_insert {
+                for hook in hooks.on_insert.iter() {
                     hook(
                         DeferredWorld { world: self.world },
                         HookContext {
@@ -601,7 +603,7 @@ impl<'w> DeferredWorld<'w> {
             for component_id in targets {
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
-                if let Some(hook) = hooks.on_replace {
+                for hook in hooks.on_replace.iter() {
                     hook(
                         DeferredWorld { world: self.world },
                         HookContext {
@@ -634,7 +636,7 @@ impl<'w> DeferredWorld<'w> {
             for component_id in targets {
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
-                if let Some(hook) = hooks.on_remove {
+                for hook in hooks.on_remove.iter() {
                     hook(
                         DeferredWorld { world: self.world },
                         HookContext {
@@ -667,7 +669,7 @@ impl<'w> DeferredWorld<'w> {
             for component_id in targets {
                 // SAFETY: Caller ensures that these components exist
                 let hooks = unsafe { self.components().get_info_unchecked(component_id) }.hooks();
-                if let Some(hook) = hooks.on_despawn {
+                for hook in hooks.on_despawn.iter() {
                     hook(
                         DeferredWorld { world: self.world },
                         HookContext {
@@ -696,6 +698,23 @@ impl<'w> DeferredWorld<'w> {
         components: impl Iterator<Item = ComponentId> + Clone,
         #[cfg(feature = "track_location")] caller: &'static Location<'static>,
     ) {
+        // Adding or inserting components on an entity that is already disabled suspends their
+        // observers until the entity is enabled again.
+        if (event == ON_ADD || event == ON_INSERT)
+            && self.world.entities().get(target).is_some_and(|location| {
+                self.world.archetypes()[location.archetype_id].contains(DISABLED)
+            })
+            && !components
+                .clone()
+                .any(|component_id| component_id == DISABLED)
+        {
+            let components: Vec<_> = components.collect();
+            self.commands().queue(move |world: &mut World| {
+                entity_disabling::suspend_observers(world, event, target, components);
+            });
+            return;
+        }
+
         Observers::invoke::<_>(
             self.reborrow(),
             event,
@@ -2,7 +2,7 @@ use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
     bundle::{Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle, InsertMode},
     change_detection::MutUntyped,
-    component::{Component, ComponentId, ComponentTicks, Components, Mutable, StorageType},
+    component::{Component, ComponentId, ComponentTicks, Components, Mutable, StorageType, Tick},
     entity::{
         Entities, Entity, EntityBorrow, EntityCloneBuilder, EntityLocation, TrustedEntityBorrow,
     },
@@ -14,8 +14,8 @@ use crate::{
     storage::Storages,
     system::IntoObserverSystem,
     world::{
-        error::EntityComponentError, unsafe_world_cell::UnsafeEntityCell, DeferredWorld, Mut, Ref,
-        World, ON_DESPAWN, ON_REMOVE, ON_REPLACE,
+        error::EntityComponentError, unsafe_world_cell::UnsafeEntityCell, ChangeSummary,
+        ComponentChangeSummary, DeferredWorld, Mut, Ref, World, ON_DESPAWN, ON_REMOVE, ON_REPLACE,
     },
 };
 use alloc::vec::Vec;
@@ -31,6 +31,16 @@ use core::{
     mem::MaybeUninit,
 };
 use thiserror::Error;
+#[cfg(feature = "bevy_reflect")]
+use {
+    crate::{
+        reflect::AppTypeRegistry,
+        world::reflect::{
+            component_by_type_path, entity_does_not_have_component, GetComponentReflectError,
+        },
+    },
+    bevy_reflect::{PartialReflect, Reflect, TypeRegistry},
+};
 
 /// A read-only reference to a particular [`Entity`] and all of its components.
 ///
@@ -294,6 +304,129 @@ impl<'w> EntityRef<'w> {
     pub fn spawned_by(&self) -> &'static Location<'static> {
         self.cell.spawned_by()
     }
+
+    /// Returns a summary of when each component of this entity was last added and changed,
+    /// which is useful for inspector tooling.
+    ///
+    /// With the `track_location` feature, this includes the source code location which last
+    /// changed each component. With the `track_change_timestamps` feature, this includes the
+    /// frame and time at which each component was added and last changed, and the system which
+    /// last changed it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Health(f32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Health(10.0)).id();
+    /// world.clear_trackers();
+    /// world.get_mut::<Health>(entity).unwrap().0 = 5.0;
+    ///
+    /// let summary = world.entity(entity).change_summary();
+    /// for component in &summary {
+    ///     println!(
+    ///         "{} changed {} ticks ago",
+    ///         component.name,
+    ///         summary.ticks_since_changed(component),
+    ///     );
+    /// }
+    ///
+    /// let health = summary.get(world.component_id::<Health>().unwrap()).unwrap();
+    /// assert_eq!(summary.ticks_since_changed(health), 0);
+    /// ```
+    pub fn change_summary(&self) -> ChangeSummary<'w> {
+        let world = self.cell.world();
+        let this_run = world.change_tick();
+        let components = world.components();
+        #[cfg(feature = "track_change_timestamps")]
+        // SAFETY: This is only used to access the change timestamps, which is world metadata.
+        let timestamps = unsafe { world.world_metadata() }.change_timestamps();
+
+        let summaries = self
+            .archetype()
+            .components()
+            .filter_map(|id| {
+                let ticks = self.get_change_ticks_by_id(id)?;
+                Some(ComponentChangeSummary {
+                    id,
+                    name: components.get_info(id)?.name(),
+                    ticks,
+                    #[cfg(feature = "track_location")]
+                    // SAFETY: We have read-only access to all components of this entity.
+                    changed_by: unsafe { self.cell.get_changed_by_id(id) }?,
+                    #[cfg(feature = "track_change_timestamps")]
+                    added_at: timestamps.get(ticks.added, this_run),
+                    #[cfg(feature = "track_change_timestamps")]
+                    changed_at: timestamps.get(ticks.changed, this_run),
+                    #[cfg(feature = "track_change_timestamps")]
+                    changed_by_system: timestamps.system(ticks.changed, this_run),
+                })
+            })
+            .collect();
+
+        ChangeSummary {
+            components: summaries,
+            this_run,
+            #[cfg(feature = "track_change_timestamps")]
+            now: timestamps.now(),
+        }
+    }
+}
+
+#[cfg(feature = "bevy_reflect")]
+impl<'w> EntityRef<'w> {
+    /// Gets the component with the given type path, such as `"my_game::Health"`, as a
+    /// [`Reflect`] reference.
+    ///
+    /// This lets scripting and remote-protocol layers access components by name, without
+    /// looking up their [`ComponentId`] and reflection type data themselves.
+    /// The component's type must be registered in `type_registry`, which is usually read from
+    /// the [`AppTypeRegistry`]. Use [`EntityWorldMut::get_reflect_by_path`] to use the world's
+    /// [`AppTypeRegistry`] instead.
+    ///
+    /// # Errors
+    ///
+    /// See [`GetComponentReflectError`] for the possible errors and their descriptions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_reflect::{Reflect, TypePath};
+    /// #[derive(Component, Reflect)]
+    /// struct Health(f32);
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<AppTypeRegistry>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Health>();
+    /// let entity = world.spawn(Health(10.0)).id();
+    ///
+    /// let type_registry = world.resource::<AppTypeRegistry>().read();
+    /// let health = world
+    ///     .entity(entity)
+    ///     .get_reflect_by_path(Health::type_path(), &type_registry)
+    ///     .unwrap();
+    /// assert_eq!(health.downcast_ref::<Health>().unwrap().0, 10.0);
+    /// ```
+    pub fn get_reflect_by_path(
+        &self,
+        type_path: &str,
+        type_registry: &TypeRegistry,
+    ) -> Result<&'w dyn Reflect, GetComponentReflectError> {
+        let components = self.cell.world().components();
+        let (component_id, reflect_from_ptr) =
+            component_by_type_path(components, type_registry, type_path)?;
+        let component = self.get_by_id(component_id).map_err(|_| {
+            entity_does_not_have_component(components, self.id(), component_id, reflect_from_ptr)
+        })?;
+
+        // SAFETY: `component_id` is the component of the type that `reflect_from_ptr` was
+        // constructed for.
+        Ok(unsafe { reflect_from_ptr.as_reflect(component) })
+    }
 }
 
 impl<'w> From<EntityWorldMut<'w>> for EntityRef<'w> {
@@ -887,6 +1020,76 @@ impl<'w> EntityMut<'w> {
     }
 }
 
+#[cfg(feature = "bevy_reflect")]
+impl<'w> EntityMut<'w> {
+    /// Gets the component with the given type path, such as `"my_game::Health"`, as a
+    /// [`Reflect`] reference.
+    ///
+    /// See [`EntityRef::get_reflect_by_path`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`GetComponentReflectError`] for the possible errors and their descriptions.
+    pub fn get_reflect_by_path(
+        &self,
+        type_path: &str,
+        type_registry: &TypeRegistry,
+    ) -> Result<&'_ dyn Reflect, GetComponentReflectError> {
+        self.as_readonly()
+            .get_reflect_by_path(type_path, type_registry)
+    }
+
+    /// Gets the component with the given type path, such as `"my_game::Health"`, as a mutable
+    /// [`Reflect`] reference.
+    ///
+    /// See [`EntityRef::get_reflect_by_path`] for more information.
+    ///
+    /// Just calling this method does not trigger [change detection](crate::change_detection).
+    ///
+    /// # Errors
+    ///
+    /// See [`GetComponentReflectError`] for the possible errors and their descriptions.
+    pub fn get_reflect_mut_by_path(
+        &mut self,
+        type_path: &str,
+        type_registry: &TypeRegistry,
+    ) -> Result<Mut<'_, dyn Reflect>, GetComponentReflectError> {
+        let entity = self.id();
+        let components = self.cell.world().components();
+        let (component_id, reflect_from_ptr) =
+            component_by_type_path(components, type_registry, type_path)?;
+        let component = self.get_mut_by_id(component_id).map_err(|_| {
+            entity_does_not_have_component(components, entity, component_id, reflect_from_ptr)
+        })?;
+
+        // SAFETY: `component_id` is the component of the type that `reflect_from_ptr` was
+        // constructed for.
+        Ok(component.map_unchanged(|ptr| unsafe { reflect_from_ptr.as_reflect_mut(ptr) }))
+    }
+
+    /// Sets the component with the given type path, such as `"my_game::Health"`, by applying
+    /// `value` to it with [`PartialReflect::try_apply`].
+    ///
+    /// `value` may be a dynamic value, such as a [`DynamicStruct`](bevy_reflect::DynamicStruct),
+    /// so scripts can set some of the component's fields without knowing its concrete type.
+    ///
+    /// See [`EntityRef::get_reflect_by_path`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`GetComponentReflectError`] for the possible errors and their descriptions.
+    pub fn set_reflect_by_path(
+        &mut self,
+        type_path: &str,
+        value: &dyn PartialReflect,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), GetComponentReflectError> {
+        self.get_reflect_mut_by_path(type_path, type_registry)?
+            .try_apply(value)?;
+        Ok(())
+    }
+}
+
 impl<'w> From<&'w mut EntityMut<'_>> for EntityMut<'w> {
     fn from(entity: &'w mut EntityMut<'_>) -> Self {
         entity.reborrow()
@@ -1754,6 +1957,7 @@ impl<'w> EntityWorldMut<'w> {
             );
         }
 
+        let change_tick = world.change_tick();
         let archetypes = &mut world.archetypes;
         let storages = &mut world.storages;
         let components = &mut world.components;
@@ -1796,6 +2000,7 @@ impl<'w> EntityWorldMut<'w> {
                 archetypes,
                 storages,
                 new_archetype_id,
+                change_tick,
             );
         }
         self.world.flush();
@@ -1821,9 +2026,10 @@ impl<'w> EntityWorldMut<'w> {
         archetypes: &mut Archetypes,
         storages: &mut Storages,
         new_archetype_id: ArchetypeId,
+        change_tick: Tick,
     ) {
         let old_archetype = &mut archetypes[old_archetype_id];
-        let remove_result = old_archetype.swap_remove(old_location.archetype_row);
+        let remove_result = old_archetype.swap_remove(old_location.archetype_row, change_tick);
         // if an entity was moved into this entity's archetype row, update its archetype row
         if let Some(swapped_entity) = remove_result.swapped_entity {
             let swapped_location = entities.get(swapped_entity).unwrap();
@@ -1843,7 +2049,7 @@ impl<'w> EntityWorldMut<'w> {
         let new_archetype = &mut archetypes[new_archetype_id];
 
         let new_location = if old_table_id == new_archetype.table_id() {
-            new_archetype.allocate(entity, old_table_row)
+            new_archetype.allocate(entity, old_table_row, change_tick)
         } else {
             let (old_table, new_table) = storages
                 .tables
@@ -1858,7 +2064,8 @@ impl<'w> EntityWorldMut<'w> {
             };
 
             // SAFETY: move_result.new_row is a valid position in new_archetype's table
-            let new_location = unsafe { new_archetype.allocate(entity, move_result.new_row) };
+            let new_location =
+                unsafe { new_archetype.allocate(entity, move_result.new_row, change_tick) };
 
             // if an entity was moved into this entity's table row, update its table row
             if let Some(swapped_entity) = move_result.swapped_entity {
@@ -1964,6 +2171,7 @@ impl<'w> EntityWorldMut<'w> {
         // SAFETY: `new_archetype_id` is a subset of the components in `old_location.archetype_id`
         // because it is created by removing a bundle from these components.
         let mut new_location = location;
+        let change_tick = world.change_tick();
         Self::move_entity_from_remove::<true>(
             entity,
             &mut new_location,
@@ -1973,6 +2181,7 @@ impl<'w> EntityWorldMut<'w> {
             &mut world.archetypes,
             &mut world.storages,
             new_archetype_id,
+            change_tick,
         );
 
         new_location
@@ -2333,8 +2542,9 @@ impl<'w> EntityWorldMut<'w> {
         let moved_entity;
 
         {
+            let change_tick = world.change_tick();
             let archetype = &mut world.archetypes[self.location.archetype_id];
-            let remove_result = archetype.swap_remove(location.archetype_row);
+            let remove_result = archetype.swap_remove(location.archetype_row, change_tick);
             if let Some(swapped_entity) = remove_result.swapped_entity {
                 let swapped_location = world.entities.get(swapped_entity).unwrap();
                 // SAFETY: swapped_entity is valid and the swapped entity's components are
@@ -2744,6 +2954,57 @@ impl<'w> EntityWorldMut<'w> {
     }
 }
 
+#[cfg(feature = "bevy_reflect")]
+impl<'w> EntityWorldMut<'w> {
+    /// Gets the component with the given type path, such as `"my_game::Health"`, as a
+    /// [`Reflect`] reference, using the world's [`AppTypeRegistry`].
+    ///
+    /// See [`EntityRef::get_reflect_by_path`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`GetComponentReflectError`] for the possible errors and their descriptions.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn get_reflect_by_path(
+        &self,
+        type_path: &str,
+    ) -> Result<&'_ dyn Reflect, GetComponentReflectError> {
+        let Some(app_type_registry) = self.world.get_resource::<AppTypeRegistry>() else {
+            return Err(GetComponentReflectError::MissingAppTypeRegistry);
+        };
+        self.as_readonly()
+            .get_reflect_by_path(type_path, &app_type_registry.read())
+    }
+
+    /// Sets the component with the given type path, such as `"my_game::Health"`, by applying
+    /// `value` to it, using the world's [`AppTypeRegistry`].
+    ///
+    /// See [`EntityMut::set_reflect_by_path`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// See [`GetComponentReflectError`] for the possible errors and their descriptions.
+    ///
+    /// # Panics
+    ///
+    /// If the entity has been despawned while this `EntityWorldMut` is still alive.
+    pub fn set_reflect_by_path(
+        &mut self,
+        type_path: &str,
+        value: &dyn PartialReflect,
+    ) -> Result<(), GetComponentReflectError> {
+        let Some(app_type_registry) = self.world.get_resource::<AppTypeRegistry>().cloned() else {
+            return Err(GetComponentReflectError::MissingAppTypeRegistry);
+        };
+        let type_registry = app_type_registry.read();
+        self.as_mutable()
+            .set_reflect_by_path(type_path, value, &type_registry)
+    }
+}
+
 /// # Safety
 /// All components in the archetype must exist in world
 unsafe fn trigger_on_replace_and_on_remove_hooks_and_observers(
@@ -4467,6 +4728,50 @@ mod tests {
         assert_eq!(test_component.0, 43);
     }
 
+    #[test]
+    fn entity_ref_change_summary() {
+        let mut world = World::new();
+        let entity = world.spawn((TestComponent(1), TestComponent2(2))).id();
+        world.clear_trackers();
+        world.clear_trackers();
+        world.get_mut::<TestComponent2>(entity).unwrap().0 = 3;
+
+        let id = world.component_id::<TestComponent>().unwrap();
+        let id2 = world.component_id::<TestComponent2>().unwrap();
+        let summary = world.entity(entity).change_summary();
+        assert_eq!(summary.components().len(), 2);
+
+        let component = summary.get(id).unwrap();
+        assert!(component.name.ends_with("TestComponent"));
+        assert_eq!(summary.ticks_since_changed(component), 2);
+        let component2 = summary.get(id2).unwrap();
+        assert_eq!(summary.ticks_since_changed(component2), 0);
+
+        #[cfg(feature = "track_change_timestamps")]
+        {
+            assert_eq!(summary.now().frame, 2);
+            assert_eq!(summary.frames_since_changed(component), Some(2));
+            assert_eq!(summary.frames_since_changed(component2), Some(0));
+
+            world.change_timestamps_mut().set_capacity(1);
+            let summary = world.entity(entity).change_summary();
+            assert_eq!(summary.get(id).unwrap().changed_at, None);
+            assert!(summary.get(id2).unwrap().changed_at.is_some());
+            assert_eq!(summary.get(id2).unwrap().changed_by_system, None);
+
+            fn update(mut query: Query<&mut TestComponent2>) {
+                for mut component in &mut query {
+                    component.0 = 4;
+                }
+            }
+
+            world.run_system_once(update).unwrap();
+            let summary = world.entity(entity).change_summary();
+            let changed_by_system = summary.get(id2).unwrap().changed_by_system.as_deref();
+            assert!(changed_by_system.is_some_and(|name| name.ends_with("update")));
+        }
+    }
+
     #[test]
     fn entity_ref_get_by_id_invalid_component_id() {
         let invalid_component_id = ComponentId::new(usize::MAX);
@@ -42,6 +42,25 @@ pub struct TryInsertBatchError {
     pub entities: Vec<Entity>,
 }
 
+/// The error type returned when a [`World`] created with [`World::with_limits`] has reached one
+/// of its [`WorldLimits`].
+///
+/// [`World`]: crate::world::World
+/// [`World::with_limits`]: crate::world::World::with_limits
+/// [`WorldLimits`]: crate::world::WorldLimits
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldCapacityError {
+    /// The world already holds its maximum number of entities.
+    #[error("The world can't hold more than {0} entities")]
+    Entities(u32),
+    /// The world already holds its maximum number of archetypes.
+    #[error("The world can't hold more than {0} archetypes")]
+    Archetypes(usize),
+    /// The world already holds its maximum number of tables.
+    #[error("The world can't hold more than {0} tables")]
+    Tables(usize),
+}
+
 /// An error that occurs when dynamically retrieving components from an entity.
 #[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EntityComponentError {
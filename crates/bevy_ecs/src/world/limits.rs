@@ -0,0 +1,48 @@
+/// Fixed capacities for the storages of a [`World`](super::World), for targets with strict
+/// allocation budgets such as consoles and embedded devices.
+///
+/// A world created with [`World::with_limits`](super::World::with_limits) preallocates its
+/// storages for these capacities up front, and never grows past them. Once a limit is reached,
+/// [`World::try_spawn`](super::World::try_spawn) and
+/// [`World::try_spawn_empty`](super::World::try_spawn_empty) return a
+/// [`WorldCapacityError`](super::error::WorldCapacityError), while operations without an error
+/// path panic.
+///
+/// Every archetype, table and sparse set is allocated to hold
+/// [`preallocated_rows`](Self::preallocated_rows) entities when it's created, so adding entities
+/// to it doesn't reallocate until it holds more than that. Archetypes, tables and sparse sets are
+/// only created when an entity first ends up with a new combination of components, or when a
+/// sparse set component is first used. To avoid allocating afterwards, spawn every combination of
+/// components that's needed at startup.
+///
+/// ```
+/// # use bevy_ecs::{prelude::*, world::WorldLimits};
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// let mut world = World::with_limits(WorldLimits {
+///     max_entities: Some(2),
+///     ..Default::default()
+/// });
+///
+/// let first = world.try_spawn(Enemy).unwrap().id();
+/// world.try_spawn(Enemy).unwrap();
+/// assert!(world.try_spawn(Enemy).is_err());
+///
+/// world.despawn(first);
+/// assert!(world.try_spawn(Enemy).is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorldLimits {
+    /// The maximum number of entities that can exist at once, or `None` for no limit.
+    pub max_entities: Option<u32>,
+    /// The maximum number of archetypes, including the empty archetype, or `None` for no limit.
+    pub max_archetypes: Option<usize>,
+    /// The maximum number of tables, including the empty table, or `None` for no limit.
+    pub max_tables: Option<usize>,
+    /// The number of entities every archetype, table and sparse set is preallocated to hold when
+    /// it's created.
+    ///
+    /// Archetypes, tables and sparse sets still grow past this if more entities are added to them.
+    pub preallocated_rows: usize,
+}
@@ -1,5 +1,6 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod change_summary;
 pub(crate) mod command_queue;
 mod component_constants;
 mod deferred_world;
@@ -8,6 +9,7 @@ mod entity_ref;
 pub mod error;
 mod filtered_resource;
 mod identifier;
+mod limits;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -19,6 +21,7 @@ pub use crate::{
     world::command_queue::CommandQueue,
 };
 pub use bevy_ecs_macros::FromWorld;
+pub use change_summary::*;
 pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
 pub use entity_fetch::WorldEntityFetch;
@@ -28,30 +31,35 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+pub use limits::WorldLimits;
 pub use spawn_batch::*;
 
 use crate::{
-    archetype::{ArchetypeId, ArchetypeRow, Archetypes},
-    bundle::{Bundle, BundleInfo, BundleInserter, BundleSpawner, Bundles, InsertMode},
+    archetype::{ArchetypeId, ArchetypeInvariant, ArchetypeRow, Archetypes},
+    bundle::{Bundle, BundleId, BundleInfo, BundleInserter, BundleSpawner, Bundles, InsertMode},
     change_detection::{MutUntyped, TicksMut},
     component::{
         Component, ComponentCloneHandlers, ComponentDescriptor, ComponentHooks, ComponentId,
         ComponentInfo, ComponentTicks, Components, Mutable, RequiredComponents,
-        RequiredComponentsError, Tick,
+        RequiredComponentsError, StorageType, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
+    entity_disabling::{DefaultQueryFilters, Disabled, DISABLED},
     event::{Event, EventId, Events, SendBatchIds},
     observer::Observers,
     query::{DebugCheckedUnwrap, QueryData, QueryFilter, QueryState},
     removal_detection::RemovedComponentEvents,
     resource::Resource,
-    result::Result,
+    result::{Error, Result},
     schedule::{Schedule, ScheduleLabel, Schedules},
     storage::{ResourceData, Storages},
-    system::Commands,
+    system::{error_handler, Commands},
     world::{
         command_queue::RawCommandQueue,
-        error::{EntityFetchError, TryDespawnError, TryInsertBatchError, TryRunScheduleError},
+        error::{
+            EntityFetchError, TryDespawnError, TryInsertBatchError, TryRunScheduleError,
+            WorldCapacityError,
+        },
     },
 };
 use alloc::{boxed::Box, vec::Vec};
@@ -99,6 +107,14 @@ pub struct World {
     pub(crate) last_check_tick: Tick,
     pub(crate) last_trigger_id: u32,
     pub(crate) command_queue: RawCommandQueue,
+    pub(crate) trigger_chain_command_queue: RawCommandQueue,
+    pub(crate) schedule_command_queue: RawCommandQueue,
+    /// The number of [`Schedule`](crate::schedule::Schedule)s that are currently running, so that
+    /// only the outermost one applies the [`schedule_command_queue`](Self::schedule_command_queue).
+    pub(crate) schedule_depth: u32,
+    #[cfg(feature = "track_change_timestamps")]
+    pub(crate) change_timestamps: ChangeTimestamps,
+    limits: WorldLimits,
 }
 
 impl Default for World {
@@ -119,6 +135,12 @@ impl Default for World {
             last_check_tick: Tick::new(0),
             last_trigger_id: 0,
             command_queue: RawCommandQueue::new(),
+            trigger_chain_command_queue: RawCommandQueue::new(),
+            schedule_command_queue: RawCommandQueue::new(),
+            schedule_depth: 0,
+            #[cfg(feature = "track_change_timestamps")]
+            change_timestamps: ChangeTimestamps::new(Tick::new(1)),
+            limits: WorldLimits::default(),
         };
         world.bootstrap();
         world
@@ -127,14 +149,20 @@ impl Default for World {
 
 impl Drop for World {
     fn drop(&mut self) {
-        // SAFETY: Not passing a pointer so the argument is always valid
-        unsafe { self.command_queue.apply_or_drop_queued(None) };
-        // SAFETY: Pointers in internal command queue are only invalidated here
-        drop(unsafe { Box::from_raw(self.command_queue.bytes.as_ptr()) });
-        // SAFETY: Pointers in internal command queue are only invalidated here
-        drop(unsafe { Box::from_raw(self.command_queue.cursor.as_ptr()) });
-        // SAFETY: Pointers in internal command queue are only invalidated here
-        drop(unsafe { Box::from_raw(self.command_queue.panic_recovery.as_ptr()) });
+        for command_queue in [
+            &mut self.command_queue,
+            &mut self.trigger_chain_command_queue,
+            &mut self.schedule_command_queue,
+        ] {
+            // SAFETY: Not passing a pointer so the argument is always valid
+            unsafe { command_queue.apply_or_drop_queued(None) };
+            // SAFETY: Pointers in internal command queues are only invalidated here
+            drop(unsafe { Box::from_raw(command_queue.bytes.as_ptr()) });
+            // SAFETY: Pointers in internal command queues are only invalidated here
+            drop(unsafe { Box::from_raw(command_queue.cursor.as_ptr()) });
+            // SAFETY: Pointers in internal command queues are only invalidated here
+            drop(unsafe { Box::from_raw(command_queue.panic_recovery.as_ptr()) });
+        }
     }
 }
 
@@ -158,6 +186,13 @@ impl World {
 
         let on_despawn = OnDespawn::register_component_id(self);
         assert_eq!(ON_DESPAWN, on_despawn);
+
+        let disabled = self.register_component::<Disabled>();
+        assert_eq!(DISABLED, disabled);
+
+        let mut filters = DefaultQueryFilters::default();
+        filters.set_disabled(disabled);
+        self.insert_resource(filters);
     }
     /// Creates a new empty [`World`].
     ///
@@ -171,6 +206,56 @@ impl World {
         World::default()
     }
 
+    /// Creates a new empty [`World`] whose storages are preallocated for, and can't grow past,
+    /// the given [`WorldLimits`].
+    ///
+    /// # Panics
+    ///
+    /// If [`usize::MAX`] [`World`]s have been created, or if the limits are too small to hold
+    /// the entities, archetypes and tables that every [`World`] starts with.
+    pub fn with_limits(limits: WorldLimits) -> World {
+        let mut world = World::new();
+        world.entities.set_limit(limits.max_entities);
+        world
+            .archetypes
+            .set_limits(limits.max_archetypes, limits.preallocated_rows);
+        world
+            .storages
+            .tables
+            .set_limits(limits.max_tables, limits.preallocated_rows);
+        world
+            .storages
+            .sparse_sets
+            .set_row_capacity((limits.preallocated_rows > 0).then_some(limits.preallocated_rows));
+        if let Err(error) = world.entities.check_limit(0) {
+            panic!("{error}");
+        }
+        if let Some(max_archetypes) = limits.max_archetypes {
+            assert!(
+                world.archetypes.len() <= max_archetypes,
+                "{}",
+                WorldCapacityError::Archetypes(max_archetypes)
+            );
+        }
+        if let Some(max_tables) = limits.max_tables {
+            assert!(
+                world.storages.tables.len() <= max_tables,
+                "{}",
+                WorldCapacityError::Tables(max_tables)
+            );
+        }
+        world.limits = limits;
+        world
+    }
+
+    /// Returns the [`WorldLimits`] this [`World`] was created with.
+    ///
+    /// See [`World::with_limits`].
+    #[inline]
+    pub fn limits(&self) -> &WorldLimits {
+        &self.limits
+    }
+
     /// Retrieves this [`World`]'s unique ID
     #[inline]
     pub fn id(&self) -> WorldId {
@@ -495,6 +580,159 @@ impl World {
         }
     }
 
+    /// Overrides the given component `R` as a directly [required component] for `T`.
+    ///
+    /// Unlike [`World::register_required_components_with`], this replaces any existing direct
+    /// requirement of `R` by `T`, including ones declared with the `require` attribute, so the
+    /// constructor for `R` can be changed without changing the definition of `T`. Components that
+    /// require `T` are updated accordingly.
+    ///
+    /// For the non-panicking version, see [`World::try_override_required_components`].
+    ///
+    /// Unlike other registrations, requirements can be overridden after `T` has been added to
+    /// entities. Entities that already have `T` keep their components, and the new requirement is
+    /// used the next time `T` or a component requiring it is added.
+    ///
+    /// [required component]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` requires `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// #[require(Brain(|| Brain(1)))]
+    /// struct Enemy;
+    ///
+    /// #[derive(Component, PartialEq, Eq, Debug)]
+    /// struct Brain(u32);
+    ///
+    /// # let mut world = World::default();
+    /// // Use a smarter brain on a harder difficulty.
+    /// world.override_required_components::<Enemy, Brain>(|| Brain(3));
+    ///
+    /// let id = world.spawn(Enemy).id();
+    /// assert_eq!(&Brain(3), world.entity(id).get::<Brain>().unwrap());
+    /// ```
+    pub fn override_required_components<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) {
+        self.try_override_required_components::<T, R>(constructor)
+            .unwrap();
+    }
+
+    /// Tries to override the given component `R` as a directly [required component] for `T`.
+    ///
+    /// For the panicking version, and more info, see [`World::override_required_components`].
+    ///
+    /// [required component]: Component#required-components
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RequiredComponentsError`] if `R` requires `T`.
+    pub fn try_override_required_components<T: Component, R: Component>(
+        &mut self,
+        constructor: fn() -> R,
+    ) -> Result<(), RequiredComponentsError> {
+        let requiree = self.register_component::<T>();
+        let required = self.register_component::<R>();
+
+        // SAFETY: We just created the `required` and `requiree` components.
+        unsafe {
+            self.components
+                .override_required_components::<R>(requiree, required, constructor)?;
+        }
+        self.refresh_required_components(requiree);
+        Ok(())
+    }
+
+    /// Removes the given component `R` from the direct [required components] of `T`.
+    ///
+    /// This also removes requirements declared with the `require` attribute, so that `R` is no
+    /// longer added along with `T`, unless it is still required through other components.
+    /// Combined with [`World::override_required_components`], this can swap which component is
+    /// required without changing the definition of `T`.
+    ///
+    /// For the non-panicking version, see [`World::try_remove_required_components`].
+    ///
+    /// [required components]: Component#required-components
+    ///
+    /// # Panics
+    ///
+    /// Panics if `R` is not a directly required component for `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// #[require(DefaultBrain)]
+    /// struct Enemy;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct DefaultBrain;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct HardBrain;
+    ///
+    /// # let mut world = World::default();
+    /// world.remove_required_components::<Enemy, DefaultBrain>();
+    /// world.override_required_components::<Enemy, HardBrain>(HardBrain::default);
+    ///
+    /// let id = world.spawn(Enemy).id();
+    /// assert!(!world.entity(id).contains::<DefaultBrain>());
+    /// assert!(world.entity(id).contains::<HardBrain>());
+    /// ```
+    pub fn remove_required_components<T: Component, R: Component>(&mut self) {
+        self.try_remove_required_components::<T, R>().unwrap();
+    }
+
+    /// Tries to remove the given component `R` from the direct [required components] of `T`.
+    ///
+    /// For the panicking version, and more info, see [`World::remove_required_components`].
+    ///
+    /// [required components]: Component#required-components
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RequiredComponentsError`] if `R` is not a directly required component for `T`.
+    pub fn try_remove_required_components<T: Component, R: Component>(
+        &mut self,
+    ) -> Result<(), RequiredComponentsError> {
+        let requiree = self.register_component::<T>();
+        let required = self.register_component::<R>();
+
+        // SAFETY: We just created the `required` and `requiree` components.
+        unsafe {
+            self.components
+                .remove_required_components(requiree, required)?;
+        }
+        self.refresh_required_components(requiree);
+        Ok(())
+    }
+
+    /// Updates the requirements cached by bundles and archetype edges after the required
+    /// components of `requiree` changed, so that the new requirements are used from now on.
+    fn refresh_required_components(&mut self, requiree: ComponentId) {
+        let mut changed: Vec<ComponentId> = self
+            .components
+            .get_required_by(requiree)
+            .map(|required_by| required_by.iter().copied().collect())
+            .unwrap_or_default();
+        changed.push(requiree);
+
+        // SAFETY: The requirements were registered with the components of this world.
+        unsafe {
+            self.bundles
+                .refresh_required_components(&self.components, &changed);
+        }
+        self.archetypes.clear_edges();
+    }
+
     /// Retrieves the [required components](RequiredComponents) for the given component type, if it exists.
     pub fn get_required_components<C: Component>(&self) -> Option<&RequiredComponents> {
         let id = self.components().component_id::<C>()?;
@@ -508,6 +746,93 @@ impl World {
         Some(component_info.required_components())
     }
 
+    /// Registers an [`ArchetypeInvariant`], a rule for which components an entity can have together.
+    ///
+    /// The invariant is checked against all existing archetypes, and against every new archetype
+    /// when it's created. Violations are passed to `error_handler` the next time the world is
+    /// [flushed](Self::flush), which happens after every spawn and every insertion and removal
+    /// of components.
+    /// Each combination of components is only reported once, the first time an entity has it.
+    ///
+    /// See [`World::register_component_dependency`] and [`World::register_component_conflict`]
+    /// for simpler ways to register the most common invariants.
+    pub fn register_archetype_invariant(
+        &mut self,
+        invariant: ArchetypeInvariant,
+        error_handler: fn(&mut World, Error),
+    ) {
+        self.archetypes
+            .register_invariant(invariant, error_handler, &self.components);
+        self.flush_archetype_invariant_errors();
+    }
+
+    /// Registers an [`ArchetypeInvariant`] that entities with `T` must also have `R`.
+    ///
+    /// Unlike with [required components](Component#required-components), `R` isn't inserted automatically.
+    /// Violations are passed to the [default error handler](crate::system::error_handler::default),
+    /// which panics unless configured otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Wheel;
+    ///
+    /// #[derive(Component)]
+    /// struct Vehicle;
+    ///
+    /// # let mut world = World::default();
+    /// world.register_component_dependency::<Wheel, Vehicle>();
+    ///
+    /// // This panics, because a wheel must belong to a vehicle.
+    /// world.spawn(Wheel);
+    /// ```
+    pub fn register_component_dependency<T: Component, R: Component>(&mut self) {
+        let component = self.register_component::<T>();
+        let required = self.register_component::<R>();
+        self.register_archetype_invariant(
+            ArchetypeInvariant::Requires {
+                component,
+                required,
+            },
+            error_handler::default(),
+        );
+    }
+
+    /// Registers an [`ArchetypeInvariant`] that entities can't have both `T` and `C`.
+    ///
+    /// Violations are passed to the [default error handler](crate::system::error_handler::default),
+    /// which panics unless configured otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Alive;
+    ///
+    /// #[derive(Component)]
+    /// struct Dead;
+    ///
+    /// # let mut world = World::default();
+    /// world.register_component_conflict::<Alive, Dead>();
+    ///
+    /// // This panics, because an entity can't be both alive and dead.
+    /// world.spawn((Alive, Dead));
+    /// ```
+    pub fn register_component_conflict<T: Component, C: Component>(&mut self) {
+        let component = self.register_component::<T>();
+        let conflicting = self.register_component::<C>();
+        self.register_archetype_invariant(
+            ArchetypeInvariant::ConflictsWith {
+                component,
+                conflicting,
+            },
+            error_handler::default(),
+        );
+    }
+
     /// Registers a new [`Component`] type and returns the [`ComponentId`] created for it.
     ///
     /// This method differs from [`World::register_component`] in that it uses a [`ComponentDescriptor`]
@@ -1014,6 +1339,22 @@ impl World {
         }
     }
 
+    /// Spawns a new [`Entity`] like [`World::spawn_empty`], or returns an error if the world
+    /// already holds its [maximum number](WorldLimits::max_entities) of entities.
+    #[track_caller]
+    pub fn try_spawn_empty(&mut self) -> Result<EntityWorldMut, WorldCapacityError> {
+        self.flush();
+        let entity = self.entities.try_alloc()?;
+        // SAFETY: entity was just allocated
+        Ok(unsafe {
+            self.spawn_at_empty_internal(
+                entity,
+                #[cfg(feature = "track_location")]
+                Location::caller(),
+            )
+        })
+    }
+
     /// Spawns a new [`Entity`] with a given [`Bundle`] of [components](`Component`) and returns
     /// a corresponding [`EntityWorldMut`], which can be used to add components to the entity or
     /// retrieve its id. In case large batches of entities need to be spawned, consider using
@@ -1083,6 +1424,61 @@ impl World {
         )
     }
 
+    /// Spawns a new [`Entity`] with a given [`Bundle`] like [`World::spawn`], or returns an
+    /// error if the world already holds its maximum number of entities, or if spawning it would
+    /// need a new archetype or table past the [`WorldLimits`].
+    ///
+    /// Only the entity itself is checked, so entities and archetypes created by hooks or
+    /// observers reacting to the spawn still panic when they reach a limit.
+    #[track_caller]
+    pub fn try_spawn<B: Bundle>(
+        &mut self,
+        bundle: B,
+    ) -> Result<EntityWorldMut, WorldCapacityError> {
+        self.flush();
+        self.entities.check_limit(1)?;
+        let bundle_id = self
+            .bundles
+            .register_info::<B>(&mut self.components, &mut self.storages);
+        self.check_spawn_limits(bundle_id)?;
+        Ok(self.spawn_with_caller(
+            bundle,
+            #[cfg(feature = "track_location")]
+            Location::caller(),
+        ))
+    }
+
+    /// Checks that spawning an entity with the given bundle doesn't need a new archetype or
+    /// table past the [`WorldLimits`].
+    fn check_spawn_limits(&self, bundle_id: BundleId) -> Result<(), WorldCapacityError> {
+        if self.archetypes[ArchetypeId::EMPTY]
+            .edges()
+            .get_archetype_after_bundle_insert(bundle_id)
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        let mut table_components = Vec::new();
+        let mut sparse_set_components = Vec::new();
+        // SAFETY: The bundle was registered by the caller.
+        let bundle_info = unsafe { self.bundles.get_unchecked(bundle_id) };
+        for component_id in bundle_info.iter_contributed_components() {
+            // SAFETY: Components of registered bundles are valid.
+            let component_info = unsafe { self.components.get_info_unchecked(component_id) };
+            match component_info.storage_type() {
+                StorageType::Table => table_components.push(component_id),
+                StorageType::SparseSet => sparse_set_components.push(component_id),
+            }
+        }
+        table_components.sort_unstable();
+        sparse_set_components.sort_unstable();
+
+        self.storages.tables.check_limit(&table_components)?;
+        self.archetypes
+            .check_limit(&table_components, &sparse_set_components)
+    }
+
     pub(crate) fn spawn_with_caller<B: Bundle>(
         &mut self,
         bundle: B,
@@ -1103,8 +1499,10 @@ impl World {
         };
 
         // SAFETY: command_queue is not referenced anywhere else
-        if !unsafe { self.command_queue.is_empty() } {
+        if !unsafe { self.command_queue.is_empty() } || !self.archetypes.invariant_errors.is_empty()
+        {
             self.flush_commands();
+            self.flush_archetype_invariant_errors();
             entity_location = self
                 .entities()
                 .get(entity)
@@ -1126,12 +1524,13 @@ impl World {
         entity: Entity,
         #[cfg(feature = "track_location")] caller: &'static Location,
     ) -> EntityWorldMut {
+        let change_tick = self.change_tick();
         let archetype = self.archetypes.empty_mut();
         // PERF: consider avoiding allocating entities in the empty archetype unless needed
         let table_row = self.storages.tables[archetype.table_id()].allocate(entity);
         // SAFETY: no components are allocated by archetype.allocate() because the archetype is
         // empty
-        let location = unsafe { archetype.allocate(entity, table_row) };
+        let location = unsafe { archetype.allocate(entity, table_row, change_tick) };
         self.entities.set(entity.index(), location);
 
         #[cfg(feature = "track_location")]
@@ -1177,6 +1576,46 @@ impl World {
         )
     }
 
+    /// Spawns a batch of entities that all share the same `shared` [`Bundle`], alongside a
+    /// per-entity [`Bundle`] from `iter`, and returns a corresponding [`Entity`] iterator.
+    ///
+    /// This works like [`World::spawn_batch`], but `shared` is cloned for each entity as it's
+    /// spawned, instead of having to be built into every bundle of the iterator up front.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, entity::Entity, world::World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Particle;
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// let mut world = World::new();
+    /// let entities = world
+    ///     .spawn_batch_with(Particle, (0..1000).map(|x| Position(x as f32)))
+    ///     .collect::<Vec<Entity>>();
+    ///
+    /// assert_eq!(entities.len(), 1000);
+    /// ```
+    #[track_caller]
+    pub fn spawn_batch_with<S, I>(
+        &mut self,
+        shared: S,
+        iter: I,
+    ) -> SpawnBatchIter<'_, impl Iterator<Item = (S, I::Item)>>
+    where
+        S: Bundle + Clone,
+        I: IntoIterator,
+        I::Item: Bundle,
+    {
+        SpawnBatchIter::new(
+            self,
+            iter.into_iter().map(move |bundle| (shared.clone(), bundle)),
+            #[cfg(feature = "track_location")]
+            Location::caller(),
+        )
+    }
+
     /// Retrieves a reference to the given `entity`'s [`Component`] of the given type.
     /// Returns `None` if the `entity` does not have a [`Component`] of the given type.
     /// ```
@@ -1401,6 +1840,25 @@ impl World {
     pub fn clear_trackers(&mut self) {
         self.removed_components.update();
         self.last_change_tick = self.increment_change_tick();
+        #[cfg(feature = "track_change_timestamps")]
+        self.change_timestamps.record_frame(self.read_change_tick());
+    }
+
+    /// Returns the history of frame start times used to find when components were changed.
+    ///
+    /// See [`EntityRef::change_summary`] for more information.
+    #[cfg(feature = "track_change_timestamps")]
+    #[inline]
+    pub fn change_timestamps(&self) -> &ChangeTimestamps {
+        &self.change_timestamps
+    }
+
+    /// Returns a mutable reference to the history of frame start times used to find when
+    /// components were changed, for example to change its capacity.
+    #[cfg(feature = "track_change_timestamps")]
+    #[inline]
+    pub fn change_timestamps_mut(&mut self) -> &mut ChangeTimestamps {
+        &mut self.change_timestamps
     }
 
     /// Returns [`QueryState`] for the given [`QueryData`], which is used to efficiently
@@ -2713,7 +3171,8 @@ impl World {
     /// assert_eq!(world.get_resource::<A>().unwrap().0, 2);
     /// ```
     ///
-    /// See also [`try_resource_scope`](Self::try_resource_scope).
+    /// See also [`try_resource_scope`](Self::try_resource_scope) and
+    /// [`resource_scope_transactional`](Self::resource_scope_transactional).
     #[track_caller]
     pub fn resource_scope<R: Resource, U>(&mut self, f: impl FnOnce(&mut World, Mut<R>) -> U) -> U {
         self.try_resource_scope(f)
@@ -2777,6 +3236,64 @@ impl World {
         Some(result)
     }
 
+    /// Like [`resource_scope`](Self::resource_scope), but the mutations made to the resource
+    /// inside the closure are reverted if it returns an error.
+    ///
+    /// The resource is cloned before running the closure. If the closure returns `Err`, the
+    /// resource is restored to that clone, along with its change ticks, so the reverted
+    /// mutations aren't detected as changes. This is useful for operations which need to be
+    /// cancellable, such as applying the edits of a settings screen.
+    ///
+    /// Only the resource is reverted: changes made to the rest of the [`World`] are kept.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Clone)]
+    /// struct Volume(f32);
+    /// let mut world = World::new();
+    /// world.insert_resource(Volume(0.5));
+    ///
+    /// let result = world.resource_scope_transactional(|_world, mut volume: Mut<Volume>| {
+    ///     volume.0 = 2.0;
+    ///     if volume.0 > 1.0 {
+    ///         return Err("volume out of range");
+    ///     }
+    ///     Ok(())
+    /// });
+    /// assert!(result.is_err());
+    /// assert_eq!(world.resource::<Volume>().0, 0.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resource does not exist in this [`World`].
+    #[track_caller]
+    pub fn resource_scope_transactional<R: Resource + Clone, U, E>(
+        &mut self,
+        f: impl FnOnce(&mut World, Mut<R>) -> Result<U, E>,
+    ) -> Result<U, E> {
+        self.resource_scope(|world, mut value: Mut<R>| {
+            let original = R::clone(&value);
+            let added = *value.ticks.added;
+            let changed = *value.ticks.changed;
+            #[cfg(feature = "track_location")]
+            let changed_by = *value.changed_by;
+
+            let result = f(world, value.reborrow());
+            if result.is_err() {
+                *value.value = original;
+                *value.ticks.added = added;
+                *value.ticks.changed = changed;
+                #[cfg(feature = "track_location")]
+                {
+                    *value.changed_by = changed_by;
+                }
+            }
+            result
+        })
+    }
+
     /// Sends an [`Event`].
     /// This method returns the [ID](`EventId`) of the sent `event`,
     /// or [`None`] if the `event` could not be sent.
@@ -2908,6 +3425,7 @@ impl World {
     /// This should be called before doing operations that might operate on queued entities,
     /// such as inserting a [`Component`].
     pub(crate) fn flush_entities(&mut self) {
+        let change_tick = self.change_tick();
         let empty_archetype = self.archetypes.empty_mut();
         let table = &mut self.storages.tables[empty_archetype.table_id()];
         // PERF: consider pre-allocating space for flushed entities
@@ -2916,7 +3434,7 @@ impl World {
             self.entities.flush(|entity, location| {
                 // SAFETY: no components are allocated by archetype.allocate() because the archetype
                 // is empty
-                *location = empty_archetype.allocate(entity, table.allocate(entity));
+                *location = empty_archetype.allocate(entity, table.allocate(entity), change_tick);
             });
         }
     }
@@ -2928,24 +3446,77 @@ impl World {
     /// This will panic if any of the queued commands are [`spawn`](Commands::spawn).
     /// If this is possible, you should instead use [`flush`](Self::flush).
     pub(crate) fn flush_commands(&mut self) {
-        // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
-        if !unsafe { self.command_queue.is_empty() } {
+        loop {
             // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
-            unsafe {
-                self.command_queue
-                    .clone()
-                    .apply_or_drop_queued(Some(self.into()));
+            if !unsafe { self.command_queue.is_empty() } {
+                // SAFETY: `self.command_queue` is only de-allocated in `World`'s `Drop`
+                unsafe {
+                    self.command_queue
+                        .clone()
+                        .apply_or_drop_queued(Some(self.into()));
+                };
+            }
+
+            // The commands of observers deferred to the end of the trigger chain are applied once
+            // the queue is fully flushed. Nested flushes happen while the queue is still being
+            // applied, which leaves its cursor past the start.
+            // SAFETY: Both queues are only de-allocated in `World`'s `Drop`
+            let deferred = unsafe {
+                *self.command_queue.cursor.as_ref() == 0
+                    && !self.trigger_chain_command_queue.is_empty()
             };
+            if !deferred {
+                break;
+            }
+            // SAFETY: Both queues are only de-allocated in `World`'s `Drop`
+            unsafe {
+                self.trigger_chain_command_queue
+                    .move_since(0, &mut self.command_queue.clone());
+            }
+        }
+    }
+
+    /// Applies the commands queued by observers with [`ObserverFlush::EndOfSchedule`]. If there are
+    /// any, the world is [flushed](Self::flush) as well.
+    ///
+    /// This is called when the outermost running [`Schedule`](crate::schedule::Schedule)
+    /// completes, so it only needs to be called manually when triggering observers outside of
+    /// schedules. Schedules run from within another schedule, such as `FixedUpdate`, leave the
+    /// commands to the schedule that runs them.
+    ///
+    /// [`ObserverFlush::EndOfSchedule`]: crate::observer::ObserverFlush::EndOfSchedule
+    pub fn flush_end_of_schedule_commands(&mut self) {
+        // SAFETY: `self.schedule_command_queue` is only de-allocated in `World`'s `Drop`
+        if unsafe { self.schedule_command_queue.is_empty() } {
+            return;
+        }
+        // SAFETY: Both queues are only de-allocated in `World`'s `Drop`
+        unsafe {
+            self.schedule_command_queue
+                .move_since(0, &mut self.command_queue.clone());
+        }
+        self.flush();
+    }
+
+    /// Passes the violations of [`ArchetypeInvariant`]s found since the last call to their error handlers.
+    fn flush_archetype_invariant_errors(&mut self) {
+        if self.archetypes.invariant_errors.is_empty() {
+            return;
+        }
+        for (error, error_handler) in core::mem::take(&mut self.archetypes.invariant_errors) {
+            error_handler(self, error.into());
         }
     }
 
     /// Flushes queued entities and commands.
     ///
     /// Queued entities will be spawned, and then commands will be applied.
+    /// Finally, any violations of [`ArchetypeInvariant`]s are passed to their error handlers.
     #[inline]
     pub fn flush(&mut self) {
         self.flush_entities();
         self.flush_commands();
+        self.flush_archetype_invariant_errors();
     }
 
     /// Increments the world's current change tick and returns the old value.
@@ -3134,6 +3705,7 @@ impl World {
         let _span = tracing::info_span!("check component ticks").entered();
         tables.check_change_ticks(change_tick);
         sparse_sets.check_change_ticks(change_tick);
+        self.archetypes.check_change_ticks(change_tick);
         resources.check_change_ticks(change_tick);
         non_send_resources.check_change_ticks(change_tick);
 
@@ -3141,6 +3713,9 @@ impl World {
             schedules.check_change_ticks(change_tick);
         }
 
+        #[cfg(feature = "track_change_timestamps")]
+        self.change_timestamps.check_change_ticks(change_tick);
+
         self.last_check_tick = change_tick;
     }
 
@@ -3153,9 +3728,10 @@ impl World {
 
     /// Despawns all entities in this [`World`].
     pub fn clear_entities(&mut self) {
+        let change_tick = self.change_tick();
         self.storages.tables.clear();
         self.storages.sparse_sets.clear_entities();
-        self.archetypes.clear_entities();
+        self.archetypes.clear_entities(change_tick);
         self.entities.clear();
     }
 
@@ -3261,6 +3837,7 @@ impl World {
     ///
     /// ```
     /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::entity_disabling::DefaultQueryFilters;
     /// # #[derive(Resource)]
     /// # struct A(u32);
     /// # #[derive(Resource)]
@@ -3276,7 +3853,7 @@ impl World {
     ///    total += info.layout().size();
     /// }
     /// println!("Total size: {} bytes", total);
-    /// # assert_eq!(total, size_of::<A>() + size_of::<B>());
+    /// # assert_eq!(total, size_of::<A>() + size_of::<B>() + size_of::<DefaultQueryFilters>());
     /// ```
     ///
     /// ## Dynamically running closures for resources matching specific `TypeId`s
@@ -3760,14 +4337,17 @@ impl<T: Default> FromWorld for T {
 
 #[cfg(test)]
 mod tests {
-    use super::{FromWorld, World};
+    use super::{FromWorld, World, WorldLimits};
     use crate::{
+        archetype::ArchetypeInvariant,
         change_detection::DetectChangesMut,
         component::{ComponentDescriptor, ComponentInfo, StorageType},
         entity::hash_set::EntityHashSet,
+        entity_disabling::DefaultQueryFilters,
         ptr::OwningPtr,
         resource::Resource,
-        world::error::EntityFetchError,
+        result::Error,
+        world::error::{EntityFetchError, WorldCapacityError},
     };
     use alloc::{
         borrow::ToOwned,
@@ -3958,6 +4538,8 @@ mod tests {
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
         world.remove_resource::<TestResource3>();
+        // Remove `DefaultQueryFilters` so that it doesn't show up in the iterator.
+        world.remove_resource::<DefaultQueryFilters>();
 
         let mut iter = world.iter_resources();
 
@@ -3984,6 +4566,8 @@ mod tests {
         world.insert_resource(TestResource2("Hello, world!".to_string()));
         world.insert_resource(TestResource3);
         world.remove_resource::<TestResource3>();
+        // Remove `DefaultQueryFilters` so that it doesn't show up in the iterator.
+        world.remove_resource::<DefaultQueryFilters>();
 
         let mut iter = world.iter_resources_mut();
 
@@ -4417,6 +5001,64 @@ mod tests {
             Err(EntityFetchError::NoSuchEntity(e, ..)) if e == e1));
     }
 
+    #[test]
+    fn archetype_invariants() {
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Component)]
+        struct B;
+
+        #[derive(Component)]
+        struct C;
+
+        #[derive(Resource, Default)]
+        struct Violations(Vec<String>);
+
+        let mut world = World::new();
+        world.init_resource::<Violations>();
+        let existing = world.spawn((A, C)).id();
+
+        let a = world.register_component::<A>();
+        let b = world.register_component::<B>();
+        let c = world.register_component::<C>();
+        let record = |world: &mut World, error: Error| {
+            world.resource_mut::<Violations>().0.push(error.to_string());
+        };
+        let violations = |world: &World| world.resource::<Violations>().0.len();
+
+        // Existing archetypes are checked when the invariant is registered.
+        world.register_archetype_invariant(
+            ArchetypeInvariant::Requires {
+                component: a,
+                required: b,
+            },
+            record,
+        );
+        assert_eq!(violations(&world), 1);
+        world.register_archetype_invariant(
+            ArchetypeInvariant::ConflictsWith {
+                component: b,
+                conflicting: c,
+            },
+            record,
+        );
+        assert_eq!(violations(&world), 1);
+
+        world.spawn((A, B));
+        assert_eq!(violations(&world), 1);
+
+        world.entity_mut(existing).insert(B);
+        assert_eq!(violations(&world), 2);
+
+        // Each combination of components is only reported once.
+        world.spawn((A, B, C));
+        assert_eq!(violations(&world), 2);
+
+        world.spawn(A);
+        assert_eq!(violations(&world), 3);
+    }
+
     #[cfg(feature = "track_location")]
     #[test]
     #[track_caller]
@@ -4446,4 +5088,131 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn entity_limit() {
+        #[derive(Component)]
+        struct A;
+
+        let mut world = World::with_limits(WorldLimits {
+            max_entities: Some(2),
+            preallocated_rows: 2,
+            ..Default::default()
+        });
+        let a = world.try_spawn(A).unwrap().id();
+        world.try_spawn_empty().unwrap();
+        assert_eq!(
+            world.try_spawn(A).map(|entity| entity.id()),
+            Err(WorldCapacityError::Entities(2))
+        );
+        assert!(world.try_spawn_empty().is_err());
+
+        world.despawn(a);
+        world.try_spawn(A).unwrap();
+        assert_eq!(world.entities().len(), 2);
+    }
+
+    #[test]
+    fn limited_storages_dont_grow() {
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Component)]
+        #[component(storage = "SparseSet")]
+        struct B;
+
+        let mut world = World::with_limits(WorldLimits {
+            max_entities: Some(64),
+            preallocated_rows: 8,
+            ..Default::default()
+        });
+        let entity = world.spawn((A, B)).id();
+        let location = world.entities().get(entity).unwrap();
+        // Storages are sized for `preallocated_rows`, not `max_entities`.
+        let table_capacity = world.storages().tables[location.table_id].capacity();
+        assert_eq!(table_capacity, 8);
+
+        for _ in 1..8 {
+            world.try_spawn((A, B)).unwrap();
+        }
+        assert_eq!(
+            world.storages().tables[location.table_id].capacity(),
+            table_capacity
+        );
+    }
+
+    #[test]
+    fn archetype_limit_try_spawn() {
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Component)]
+        struct B;
+
+        let mut world = World::with_limits(WorldLimits {
+            max_archetypes: Some(2),
+            max_tables: Some(3),
+            ..Default::default()
+        });
+        world.try_spawn(A).unwrap();
+        // The archetype with `A` already exists.
+        world.try_spawn(A).unwrap();
+        assert_eq!(
+            world.try_spawn(B).map(|entity| entity.id()),
+            Err(WorldCapacityError::Archetypes(2))
+        );
+        assert_eq!(world.entities().len(), 2);
+    }
+
+    #[test]
+    fn table_limit_try_spawn() {
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Component)]
+        struct B;
+
+        let mut world = World::with_limits(WorldLimits {
+            max_tables: Some(2),
+            ..Default::default()
+        });
+        world.try_spawn(A).unwrap();
+        assert_eq!(
+            world.try_spawn(B).map(|entity| entity.id()),
+            Err(WorldCapacityError::Tables(2))
+        );
+        assert_eq!(world.entities().len(), 1);
+    }
+
+    #[test]
+    #[should_panic = "The world can't hold more than 2 entities"]
+    fn entity_limit_commands() {
+        let mut world = World::with_limits(WorldLimits {
+            max_entities: Some(2),
+            ..Default::default()
+        });
+        let mut commands = world.commands();
+        commands.spawn_empty();
+        commands.spawn_empty();
+        commands.spawn_empty();
+        world.flush();
+    }
+
+    #[test]
+    #[should_panic = "The world can't hold more than 2 archetypes"]
+    fn archetype_limit() {
+        #[derive(Component)]
+        struct A;
+
+        #[derive(Component)]
+        struct B;
+
+        let mut world = World::with_limits(WorldLimits {
+            max_archetypes: Some(2),
+            ..Default::default()
+        });
+        world.spawn(A);
+        world.spawn(A);
+        world.spawn(B);
+    }
 }
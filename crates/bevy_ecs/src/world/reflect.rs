@@ -5,9 +5,9 @@ use core::any::TypeId;
 use thiserror::Error;
 
 use alloc::string::{String, ToString};
-use bevy_reflect::{Reflect, ReflectFromPtr};
+use bevy_reflect::{ApplyError, Reflect, ReflectFromPtr, TypeRegistry};
 
-use crate::{prelude::*, world::ComponentId};
+use crate::{component::Components, prelude::*, world::ComponentId};
 
 impl World {
     /// Retrieves a reference to the given `entity`'s [`Component`] of the given `type_id` using
@@ -198,7 +198,51 @@ impl World {
     }
 }
 
-/// The error type returned by [`World::get_reflect`] and [`World::get_reflect_mut`].
+/// Finds the [`ComponentId`] and [`ReflectFromPtr`] of the component with the given type path.
+pub(crate) fn component_by_type_path<'r>(
+    components: &Components,
+    type_registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<(ComponentId, &'r ReflectFromPtr), GetComponentReflectError> {
+    let Some(registration) = type_registry.get_with_type_path(type_path) else {
+        return Err(GetComponentReflectError::UnknownTypePath(
+            type_path.to_string(),
+        ));
+    };
+    let type_id = registration.type_id();
+
+    let Some(reflect_from_ptr) = registration.data::<ReflectFromPtr>() else {
+        return Err(GetComponentReflectError::MissingReflectFromPtrTypeData(
+            type_id,
+        ));
+    };
+
+    let Some(component_id) = components.get_id(type_id) else {
+        return Err(GetComponentReflectError::NoCorrespondingComponentId(
+            type_id,
+        ));
+    };
+
+    Ok((component_id, reflect_from_ptr))
+}
+
+/// Creates a [`GetComponentReflectError::EntityDoesNotHaveComponent`] for the given component.
+pub(crate) fn entity_does_not_have_component(
+    components: &Components,
+    entity: Entity,
+    component_id: ComponentId,
+    reflect_from_ptr: &ReflectFromPtr,
+) -> GetComponentReflectError {
+    GetComponentReflectError::EntityDoesNotHaveComponent {
+        entity,
+        type_id: reflect_from_ptr.type_id(),
+        component_id,
+        component_name: components.get_name(component_id).map(ToString::to_string),
+    }
+}
+
+/// The error type returned by [`World::get_reflect`], [`World::get_reflect_mut`], and the
+/// `*_reflect_by_path` methods of [`EntityRef`], [`EntityMut`] and [`EntityWorldMut`].
 #[derive(Error, Debug)]
 pub enum GetComponentReflectError {
     /// There is no [`ComponentId`] corresponding to the given [`TypeId`].
@@ -243,14 +287,27 @@ pub enum GetComponentReflectError {
     /// [`App::register_type`]: ../../../bevy_app/struct.App.html#method.register_type
     #[error("The `World`'s `TypeRegistry` did not contain `TypeData` for `ReflectFromPtr` for the given {0:?} (did you call `App::register_type()`?)")]
     MissingReflectFromPtrTypeData(TypeId),
+
+    /// No type with the given type path was registered in the [`TypeRegistry`].
+    ///
+    /// [`TypeRegistry`]: bevy_reflect::TypeRegistry
+    #[error(
+        "No type with the type path `{0}` was registered (did you call `App::register_type()`?)"
+    )]
+    UnknownTypePath(String),
+
+    /// The value given to a `set_reflect_by_path` method could not be applied to the component.
+    #[error("The value could not be applied to the component: {0}")]
+    ApplyFailed(#[from] ApplyError),
 }
 
 #[cfg(test)]
 mod tests {
     use core::any::TypeId;
 
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{Reflect, TypePath};
 
+    use super::GetComponentReflectError;
     use crate::{
         // For bevy_ecs_macros
         self as bevy_ecs,
@@ -334,4 +391,41 @@ mod tests {
             assert!(reflect_opt.is_err());
         }
     }
+
+    #[test]
+    fn get_and_set_component_by_type_path() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<RFoo>();
+
+        let entity = world.spawn(RFoo(42)).id();
+        let mut entity_mut = world.entity_mut(entity);
+
+        let comp_reflect = entity_mut.get_reflect_by_path(RFoo::type_path()).unwrap();
+        assert_eq!(comp_reflect.downcast_ref::<RFoo>().unwrap().0, 42);
+
+        entity_mut
+            .set_reflect_by_path(RFoo::type_path(), &RFoo(1337))
+            .unwrap();
+        assert!(matches!(
+            entity_mut.get_reflect_by_path("not::a::Type"),
+            Err(GetComponentReflectError::UnknownTypePath(_))
+        ));
+
+        let rfoo_ref = world.entity(entity).get_ref::<RFoo>().unwrap();
+        assert!(rfoo_ref.is_changed());
+        assert_eq!(rfoo_ref.0, 1337);
+
+        let entity_without_rfoo = world.spawn_empty().id();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        assert!(matches!(
+            world
+                .entity(entity_without_rfoo)
+                .get_reflect_by_path(RFoo::type_path(), &type_registry),
+            Err(GetComponentReflectError::EntityDoesNotHaveComponent { .. })
+        ));
+    }
 }
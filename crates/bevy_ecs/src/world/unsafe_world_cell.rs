@@ -7,7 +7,7 @@ use crate::{
     change_detection::{MaybeUnsafeCellLocation, MutUntyped, Ticks, TicksMut},
     component::{ComponentId, ComponentTicks, Components, Mutable, StorageType, Tick, TickCells},
     entity::{Entities, Entity, EntityBorrow, EntityLocation},
-    observer::Observers,
+    observer::{ObserverFlush, Observers},
     prelude::Component,
     query::{DebugCheckedUnwrap, ReadOnlyQueryData},
     removal_detection::RemovedComponentEvents,
@@ -623,6 +623,28 @@ impl<'w> UnsafeWorldCell<'w> {
         unsafe { (*self.0).command_queue.clone() }
     }
 
+    /// Returns the queue for the commands of observers with the given [`ObserverFlush`].
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeWorldCell`] has permission to access the queue mutably
+    /// - no mutable references to the queue exist at the same time
+    pub(crate) unsafe fn get_raw_observer_command_queue(
+        self,
+        flush: ObserverFlush,
+    ) -> RawCommandQueue {
+        // SAFETY:
+        // - caller ensures there are no existing mutable references
+        // - caller ensures that we have permission to access the queue
+        unsafe {
+            match flush {
+                ObserverFlush::Immediate => (*self.0).command_queue.clone(),
+                ObserverFlush::EndOfTriggerChain => (*self.0).trigger_chain_command_queue.clone(),
+                ObserverFlush::EndOfSchedule => (*self.0).schedule_command_queue.clone(),
+            }
+        }
+    }
+
     /// # Safety
     /// It is the callers responsibility to ensure that there are no outstanding
     /// references to `last_trigger_id`.
@@ -839,6 +861,36 @@ impl<'w> UnsafeEntityCell<'w> {
         }
     }
 
+    /// Retrieves the source code location which last changed the component with the given
+    /// [`ComponentId`].
+    ///
+    /// # Safety
+    /// It is the callers responsibility to ensure that
+    /// - the [`UnsafeEntityCell`] has permission to access the component
+    /// - no other mutable references to the component exist at the same time
+    #[cfg(feature = "track_location")]
+    #[inline]
+    pub unsafe fn get_changed_by_id(
+        &self,
+        component_id: ComponentId,
+    ) -> Option<&'static Location<'static>> {
+        let info = self.world.components().get_info(component_id)?;
+        // SAFETY:
+        // - entity location and entity is valid
+        // - world access is immutable, lifetime tied to `&self`
+        // - the storage type provided is correct for the component
+        unsafe {
+            get_component_and_ticks(
+                self.world,
+                component_id,
+                info.storage_type(),
+                self.entity,
+                self.location,
+            )
+            .map(|(_, _, caller)| *caller.deref())
+        }
+    }
+
     /// # Safety
     /// It is the callers responsibility to ensure that
     /// - the [`UnsafeEntityCell`] has permission to access the component mutably
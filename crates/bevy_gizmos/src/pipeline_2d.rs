@@ -5,7 +5,7 @@ use crate::{
     SetLineGizmoBindGroup, LINE_JOINT_SHADER_HANDLE, LINE_SHADER_HANDLE,
 };
 use bevy_app::{App, Plugin};
-use bevy_core_pipeline::core_2d::{Transparent2d, CORE_2D_DEPTH_FORMAT};
+use bevy_core_pipeline::core_2d::{Transparent2d, Transparent2dSortKey, CORE_2D_DEPTH_FORMAT};
 
 use bevy_ecs::{
     prelude::Entity,
@@ -15,7 +15,6 @@ use bevy_ecs::{
     world::{FromWorld, World},
 };
 use bevy_image::BevyDefault as _;
-use bevy_math::FloatOrd;
 use bevy_render::sync_world::MainEntity;
 use bevy_render::{
     render_asset::{prepare_assets, RenderAssets},
@@ -338,7 +337,7 @@ fn queue_line_gizmos_2d(
                     entity: (entity, *main_entity),
                     draw_function,
                     pipeline,
-                    sort_key: FloatOrd(f32::INFINITY),
+                    sort_key: Transparent2dSortKey::ON_TOP,
                     batch_range: 0..1,
                     extra_index: PhaseItemExtraIndex::None,
                     indexed: false,
@@ -359,7 +358,7 @@ fn queue_line_gizmos_2d(
                     entity: (entity, *main_entity),
                     draw_function: draw_function_strip,
                     pipeline,
-                    sort_key: FloatOrd(f32::INFINITY),
+                    sort_key: Transparent2dSortKey::ON_TOP,
                     batch_range: 0..1,
                     extra_index: PhaseItemExtraIndex::None,
                     indexed: false,
@@ -418,7 +417,7 @@ fn queue_line_joint_gizmos_2d(
                 entity: (entity, *main_entity),
                 draw_function,
                 pipeline,
-                sort_key: FloatOrd(f32::INFINITY),
+                sort_key: Transparent2dSortKey::ON_TOP,
                 batch_range: 0..1,
                 extra_index: PhaseItemExtraIndex::None,
                 indexed: false,
@@ -93,7 +93,14 @@
 extern crate alloc;
 
 #[cfg(feature = "bevy_animation")]
-use bevy_animation::AnimationClip;
+use bevy_animation::{
+    retarget::{HumanoidBone, HumanoidBoneMap},
+    AnimationClip, AnimationTargetId,
+};
+#[cfg(feature = "bevy_animation")]
+use bevy_asset::Assets;
+#[cfg(feature = "bevy_animation")]
+use bevy_ecs::name::Name;
 use bevy_platform_support::collections::HashMap;
 
 mod loader;
@@ -204,6 +211,56 @@ pub struct Gltf {
     pub source: Option<gltf::Gltf>,
 }
 
+#[cfg(feature = "bevy_animation")]
+impl Gltf {
+    /// Creates a [`HumanoidBoneMap`] for a skeleton in this glTF file, which can be used to
+    /// [retarget] animations between this file and others.
+    ///
+    /// `bones` pairs each [`HumanoidBone`] with the name of the node used for it.
+    /// The [`AnimationTargetId`] of each node is derived from the path of node names from its
+    /// scene's root node, the same way the [`GltfLoader`] assigns them, and its rest pose is the
+    /// node's local transform.
+    ///
+    /// Bones whose node doesn't exist (or whose [`GltfNode`] assets aren't loaded) are left
+    /// unmapped.
+    ///
+    /// [retarget]: AnimationClip::retarget
+    pub fn humanoid_bone_map<'a>(
+        &self,
+        nodes: &Assets<GltfNode>,
+        bones: impl IntoIterator<Item = (HumanoidBone, &'a str)>,
+    ) -> HumanoidBoneMap {
+        let mut parents = HashMap::<usize, &GltfNode>::default();
+        for node in self.nodes.iter().filter_map(|handle| nodes.get(handle)) {
+            for child in node.children.iter().filter_map(|handle| nodes.get(handle)) {
+                parents.insert(child.index, node);
+            }
+        }
+
+        let mut bone_map = HumanoidBoneMap::default();
+        for (bone, node_name) in bones {
+            let Some(node) = self
+                .named_nodes
+                .get(node_name)
+                .and_then(|handle| nodes.get(handle))
+            else {
+                continue;
+            };
+
+            let mut path = vec![Name::new(node.name.clone())];
+            let mut current = node;
+            while let Some(parent) = parents.get(&current.index) {
+                path.push(Name::new(parent.name.clone()));
+                current = parent;
+            }
+
+            let target = AnimationTargetId::from_names(path.iter().rev());
+            bone_map.insert(bone, target, node.transform);
+        }
+        bone_map
+    }
+}
+
 /// A glTF node with all of its child nodes, its [`GltfMesh`],
 /// [`Transform`](bevy_transform::prelude::Transform), its optional [`GltfSkin`]
 /// and an optional [`GltfExtras`].
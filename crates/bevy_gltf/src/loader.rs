@@ -16,8 +16,8 @@ use bevy_ecs::{
     world::World,
 };
 use bevy_image::{
-    CompressedImageFormats, Image, ImageAddressMode, ImageFilterMode, ImageLoaderSettings,
-    ImageSampler, ImageSamplerDescriptor, ImageType, TextureError,
+    CompressedImageFormats, Image, ImageAddressMode, ImageColorSpace, ImageFilterMode,
+    ImageLoaderSettings, ImageSampler, ImageSamplerDescriptor, ImageType, TextureError,
 };
 use bevy_math::{Affine2, Mat4, Vec3};
 use bevy_pbr::{
@@ -550,6 +550,13 @@ async fn load_gltf<'a, 'b, 'c>(
                 .loader()
                 .with_settings(move |settings: &mut ImageLoaderSettings| {
                     settings.is_srgb = is_srgb;
+                    // glTF only stores data like normals and roughness in linear textures, which
+                    // must never be converted into the working color space.
+                    settings.color_space = Some(if is_srgb {
+                        ImageColorSpace::Srgb
+                    } else {
+                        ImageColorSpace::NonColor
+                    });
                     settings.sampler = ImageSampler::Descriptor(sampler_descriptor.clone());
                 })
                 .load(path),
@@ -1694,7 +1701,15 @@ fn load_node(
             });
             let first_mesh =
                 primitive_label.map(|label| load_context.get_label_handle(label.to_string()));
-            node.insert(MorphWeights::new(weights, first_mesh)?);
+            let mut morph_weights = MorphWeights::new(weights, first_mesh)?;
+            if let Some(names) = mesh
+                .extras()
+                .as_ref()
+                .and_then(|extras| serde_json::from_str::<MorphTargetNames>(extras.get()).ok())
+            {
+                morph_weights.set_target_names(names.target_names);
+            }
+            node.insert(morph_weights);
         }
     }
 
@@ -62,8 +62,11 @@ impl AssetSaver for CompressedImageSaver {
         Ok(ImageLoaderSettings {
             format: ImageFormatSetting::Format(ImageFormat::Basis),
             is_srgb,
+            color_space: None,
             sampler: image.sampler.clone(),
             asset_usage: image.asset_usage,
+            max_mip_levels: None,
+            generate_mips: false,
         })
     }
 }
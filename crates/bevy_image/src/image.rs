@@ -7,8 +7,8 @@ use super::ktx2::*;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 
-use bevy_asset::{Asset, RenderAssetUsages};
-use bevy_color::{Color, ColorToComponents, Gray, LinearRgba, Srgba, Xyza};
+use bevy_asset::{Asset, AssetSize, RenderAssetUsages};
+use bevy_color::{Color, ColorToComponents, Gray, LinearRgba, Srgba, WorkingColorSpace, Xyza};
 use bevy_math::{AspectRatio, UVec2, UVec3, Vec2};
 use core::hash::Hash;
 use serde::{Deserialize, Serialize};
@@ -345,6 +345,57 @@ pub struct Image {
     pub sampler: ImageSampler,
     pub texture_view_descriptor: Option<TextureViewDescriptor<'static>>,
     pub asset_usage: RenderAssetUsages,
+    /// Whether to generate the full mip chain of this image on the GPU after it is uploaded.
+    ///
+    /// This is useful for images loaded from formats without mip levels, such as PNG or JPEG.
+    /// Mip levels are only generated for uncompressed 2D images with a single mip level whose
+    /// format can be rendered to and filtered; otherwise this flag is ignored.
+    pub generate_mips: bool,
+}
+
+/// How the data of an [`Image`] should be interpreted, used when loading images to convert their
+/// colors into the [`WorkingColorSpace`] of the renderer.
+///
+/// Textures holding colors, like base color and emissive maps, are usually [`Srgb`](Self::Srgb),
+/// while textures holding other data, like normal, metallic-roughness and occlusion maps, are
+/// [`NonColor`](Self::NonColor). Using the wrong color space makes colors look washed out or
+/// too dark.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Default)
+)]
+pub enum ImageColorSpace {
+    /// Colors with the sRGB transfer function and Rec. 709 primaries.
+    #[default]
+    Srgb,
+    /// Linear colors with Rec. 709 primaries.
+    LinearRec709,
+    /// Linear colors with the ACEScg (AP1) primaries.
+    AcesCg,
+    /// Linear data which doesn't represent colors, like normals or roughness, and is never
+    /// converted.
+    NonColor,
+}
+
+impl ImageColorSpace {
+    /// Returns whether the image data is encoded with the sRGB transfer function.
+    pub fn is_srgb(self) -> bool {
+        self == ImageColorSpace::Srgb
+    }
+
+    /// Returns the linear color space whose primaries the image uses, or `None` for
+    /// [`NonColor`](Self::NonColor) data.
+    pub fn primaries(self) -> Option<WorkingColorSpace> {
+        match self {
+            ImageColorSpace::Srgb | ImageColorSpace::LinearRec709 => {
+                Some(WorkingColorSpace::LinearRec709)
+            }
+            ImageColorSpace::AcesCg => Some(WorkingColorSpace::AcesCg),
+            ImageColorSpace::NonColor => None,
+        }
+    }
 }
 
 /// Used in [`Image`], this determines what image sampler to use when rendering. The default setting,
@@ -688,6 +739,34 @@ impl<'a> From<SamplerDescriptor<'a>> for ImageSamplerDescriptor {
     }
 }
 
+/// A sampler that is stored as an asset of its own, independently of any [`Image`].
+///
+/// This allows the same image to be sampled differently in different places without
+/// duplicating its data. For example, a material can override the sampler of an image it
+/// uses with the `sampler_asset` argument of the `sampler` attribute of `AsBindGroup`.
+#[derive(Asset, Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(opaque, Default, Debug)
+)]
+pub struct SamplerAsset {
+    /// The descriptor of the sampler.
+    pub descriptor: ImageSamplerDescriptor,
+}
+
+impl From<ImageSamplerDescriptor> for SamplerAsset {
+    fn from(descriptor: ImageSamplerDescriptor) -> Self {
+        Self { descriptor }
+    }
+}
+
+impl AssetSize for Image {
+    fn asset_size(&self) -> usize {
+        self.data.len()
+    }
+}
+
 impl Default for Image {
     /// default is a 1x1x1 all '1.0' texture
     fn default() -> Self {
@@ -712,6 +791,7 @@ impl Default for Image {
             sampler: ImageSampler::Default,
             texture_view_descriptor: None,
             asset_usage: RenderAssetUsages::default(),
+            generate_mips: false,
         }
     }
 }
@@ -774,6 +854,7 @@ impl Image {
             sampler: ImageSampler::Default,
             texture_view_descriptor: None,
             asset_usage: RenderAssetUsages::default(),
+            generate_mips: false,
         }
     }
 
@@ -920,6 +1001,52 @@ impl Image {
             .map(|(dyn_img, is_srgb)| Self::from_dynamic(dyn_img, is_srgb, self.asset_usage))
     }
 
+    /// Converts the colors of this image from the `from` color space to the `to` color space.
+    ///
+    /// The image is converted to [`TextureFormat::Rgba16Float`] so colors outside of the gamut of
+    /// either color space aren't clipped. Nothing is done if both color spaces are the same.
+    ///
+    /// Only uncompressed images with a single mip level and a format supported by
+    /// [`Image::get_color_at`] can be converted.
+    pub fn convert_color_space(
+        &mut self,
+        from: WorkingColorSpace,
+        to: WorkingColorSpace,
+    ) -> Result<(), TextureAccessError> {
+        if from == to {
+            return Ok(());
+        }
+        let format = self.texture_descriptor.format;
+        if self.is_compressed() || self.texture_descriptor.mip_level_count > 1 {
+            return Err(TextureAccessError::UnsupportedTextureFormat(format));
+        }
+
+        let size = self.texture_descriptor.size;
+        let depth = match self.texture_descriptor.dimension {
+            TextureDimension::D1 => 1,
+            TextureDimension::D2 | TextureDimension::D3 => size.depth_or_array_layers,
+        };
+        let height = match self.texture_descriptor.dimension {
+            TextureDimension::D1 => 1,
+            TextureDimension::D2 | TextureDimension::D3 => size.height,
+        };
+        let new_format = TextureFormat::Rgba16Float;
+        let mut converted = self.clone();
+        converted.data = vec![0; size.volume() * new_format.pixel_size()];
+        converted.texture_descriptor.format = new_format;
+        for z in 0..depth {
+            for y in 0..height {
+                for x in 0..size.width {
+                    let coords = UVec3::new(x, y, z);
+                    let color = self.get_color_at_internal(coords)?.to_linear();
+                    converted.set_color_at_internal(coords, to.convert_from(from, color).into())?;
+                }
+            }
+        }
+        *self = converted;
+        Ok(())
+    }
+
     /// Load a bytes buffer in a [`Image`], according to type `image_type`, using the `image`
     /// crate
     pub fn from_buffer(
@@ -1666,6 +1793,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn convert_color_space() {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[255, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::MAIN_WORLD,
+        );
+        image
+            .convert_color_space(
+                WorkingColorSpace::LinearRec709,
+                WorkingColorSpace::LinearRec709,
+            )
+            .unwrap();
+        assert_eq!(
+            image.texture_descriptor.format,
+            TextureFormat::Rgba8UnormSrgb
+        );
+
+        image
+            .convert_color_space(WorkingColorSpace::LinearRec709, WorkingColorSpace::AcesCg)
+            .unwrap();
+        assert_eq!(image.texture_descriptor.format, TextureFormat::Rgba16Float);
+        let color = image.get_color_at(1, 1).unwrap().to_linear();
+        let expected = WorkingColorSpace::AcesCg.from_linear_rec709(LinearRgba::RED);
+        assert!((color.red - expected.red).abs() < 1e-2);
+        assert!((color.green - expected.green).abs() < 1e-2);
+        assert!((color.blue - expected.blue).abs() < 1e-2);
+        assert_eq!(color.alpha, 1.0);
+    }
+
     #[test]
     fn image_default_size() {
         let image = Image::default();
@@ -1,6 +1,8 @@
-use crate::image::{Image, ImageFormat, ImageType, TextureError};
+use crate::image::{Image, ImageColorSpace, ImageFormat, ImageType, TextureError};
 use bevy_asset::{io::Reader, AssetLoader, LoadContext, RenderAssetUsages};
+use bevy_color::WorkingColorSpace;
 use thiserror::Error;
+use tracing::warn;
 
 use super::{CompressedImageFormats, ImageSampler};
 use serde::{Deserialize, Serialize};
@@ -9,6 +11,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone)]
 pub struct ImageLoader {
     supported_compressed_formats: CompressedImageFormats,
+    working_color_space: WorkingColorSpace,
 }
 
 impl ImageLoader {
@@ -77,6 +80,36 @@ impl ImageLoader {
     pub fn new(supported_compressed_formats: CompressedImageFormats) -> Self {
         Self {
             supported_compressed_formats,
+            working_color_space: WorkingColorSpace::default(),
+        }
+    }
+
+    /// Sets the [`WorkingColorSpace`] which the colors of loaded images are converted into.
+    ///
+    /// See [`ImageLoaderSettings::color_space`] for more info.
+    pub fn with_working_color_space(mut self, working_color_space: WorkingColorSpace) -> Self {
+        self.working_color_space = working_color_space;
+        self
+    }
+
+    /// Converts the colors of a loaded image into the working color space, if needed.
+    fn convert_to_working_space(
+        &self,
+        image: &mut Image,
+        settings: &ImageLoaderSettings,
+        load_context: &LoadContext<'_>,
+    ) {
+        let Some(primaries) = settings.color_space().primaries() else {
+            return;
+        };
+        if let Err(err) = image.convert_color_space(primaries, self.working_color_space) {
+            warn!(
+                "Could not convert {} from {:?} to the {:?} working color space, its colors \
+                will be wrong: {err}",
+                load_context.path().display(),
+                primaries,
+                self.working_color_space,
+            );
         }
     }
 }
@@ -93,8 +126,32 @@ pub enum ImageFormatSetting {
 pub struct ImageLoaderSettings {
     pub format: ImageFormatSetting,
     pub is_srgb: bool,
+    /// The color space in which the image data is stored.
+    ///
+    /// When `None`, the image is treated as [`ImageColorSpace::Srgb`] if [`is_srgb`] is set, and
+    /// as [`ImageColorSpace::LinearRec709`] otherwise. When set, this takes precedence over
+    /// [`is_srgb`].
+    ///
+    /// Images whose primaries differ from the [`WorkingColorSpace`] of the loader are converted to
+    /// it when loaded, and stored as [`Rgba16Float`](wgpu_types::TextureFormat::Rgba16Float).
+    /// [`ImageColorSpace::NonColor`] data is never converted.
+    ///
+    /// [`is_srgb`]: Self::is_srgb
+    #[serde(default)]
+    pub color_space: Option<ImageColorSpace>,
     pub sampler: ImageSampler,
     pub asset_usage: RenderAssetUsages,
+    /// The maximum number of mip levels to load, starting from the smallest.
+    ///
+    /// The largest mip levels beyond this count are skipped. This is only
+    /// supported by KTX2 images, and is ignored for other formats.
+    #[serde(default)]
+    pub max_mip_levels: Option<u32>,
+    /// Whether to generate the mip chain of the image on the GPU after it is uploaded.
+    ///
+    /// See [`Image::generate_mips`] for more info.
+    #[serde(default)]
+    pub generate_mips: bool,
 }
 
 impl Default for ImageLoaderSettings {
@@ -102,12 +159,27 @@ impl Default for ImageLoaderSettings {
         Self {
             format: ImageFormatSetting::default(),
             is_srgb: true,
+            color_space: None,
             sampler: ImageSampler::Default,
             asset_usage: RenderAssetUsages::default(),
+            max_mip_levels: None,
+            generate_mips: false,
         }
     }
 }
 
+impl ImageLoaderSettings {
+    /// Returns the color space in which the image data is stored, taking [`Self::is_srgb`] into
+    /// account when [`Self::color_space`] isn't set.
+    pub fn color_space(&self) -> ImageColorSpace {
+        self.color_space.unwrap_or(if self.is_srgb {
+            ImageColorSpace::Srgb
+        } else {
+            ImageColorSpace::LinearRec709
+        })
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Error)]
 pub enum ImageLoaderError {
@@ -149,20 +221,43 @@ impl AssetLoader for ImageLoader {
                 )?)
             }
         };
-        Ok(Image::from_buffer(
+        #[cfg(feature = "ktx2")]
+        if settings.max_mip_levels.is_some()
+            && matches!(image_type.to_image_format(), Ok(ImageFormat::Ktx2))
+        {
+            let mut image = crate::ktx2_buffer_to_image_with_max_mip_levels(
+                &bytes,
+                self.supported_compressed_formats,
+                settings.color_space().is_srgb(),
+                settings.max_mip_levels,
+            )
+            .map_err(|err| FileTextureError {
+                error: err,
+                path: format!("{}", load_context.path().display()),
+            })?;
+            image.sampler = settings.sampler.clone();
+            image.generate_mips = settings.generate_mips;
+            self.convert_to_working_space(&mut image, settings, load_context);
+            return Ok(image);
+        }
+
+        let mut image = Image::from_buffer(
             #[cfg(all(debug_assertions, feature = "dds"))]
             load_context.path().display().to_string(),
             &bytes,
             image_type,
             self.supported_compressed_formats,
-            settings.is_srgb,
+            settings.color_space().is_srgb(),
             settings.sampler.clone(),
             settings.asset_usage,
         )
         .map_err(|err| FileTextureError {
             error: err,
             path: format!("{}", load_context.path().display()),
-        })?)
+        })?;
+        image.generate_mips = settings.generate_mips;
+        self.convert_to_working_space(&mut image, settings, load_context);
+        Ok(image)
     }
 
     fn extensions(&self) -> &[&str] {
@@ -7,6 +7,7 @@ use basis_universal::{
 };
 use bevy_color::Srgba;
 use bevy_utils::default;
+use core::ops::Range;
 #[cfg(any(feature = "flate2", feature = "ruzstd"))]
 use ktx2::SupercompressionScheme;
 use ktx2::{
@@ -25,6 +26,21 @@ pub fn ktx2_buffer_to_image(
     buffer: &[u8],
     supported_compressed_formats: CompressedImageFormats,
     is_srgb: bool,
+) -> Result<Image, TextureError> {
+    ktx2_buffer_to_image_with_max_mip_levels(buffer, supported_compressed_formats, is_srgb, None)
+}
+
+/// Loads a KTX2 buffer into an [`Image`], keeping at most `max_mip_levels` of
+/// its smallest mip levels.
+///
+/// The larger mip levels are skipped without being decompressed or transcoded,
+/// and the resulting image has the size of its largest remaining mip level.
+/// `None` keeps every mip level, like [`ktx2_buffer_to_image`].
+pub fn ktx2_buffer_to_image_with_max_mip_levels(
+    buffer: &[u8],
+    supported_compressed_formats: CompressedImageFormats,
+    is_srgb: bool,
+    max_mip_levels: Option<u32>,
 ) -> Result<Image, TextureError> {
     let ktx2 = ktx2::Reader::new(buffer)
         .map_err(|err| TextureError::InvalidData(format!("Failed to parse ktx2 file: {err:?}")))?;
@@ -40,12 +56,24 @@ pub fn ktx2_buffer_to_image(
     } = ktx2.header();
     let layer_count = layer_count.max(1);
     let face_count = face_count.max(1);
-    let depth = depth.max(1);
+
+    // The texture and view dimensions are those of the full image, even if
+    // its largest mip levels are skipped
+    let (full_height, full_depth) = (height, depth.max(1));
+
+    // Skip the largest mip levels, treating the first kept level as the base
+    let skipped_levels = level_count
+        .max(1)
+        .saturating_sub(max_mip_levels.unwrap_or(u32::MAX).max(1));
+    let width = (width >> skipped_levels).max(1);
+    let height = (height >> skipped_levels).max(1);
+    let depth = (full_depth >> skipped_levels).max(1);
+    let level_count = level_count.saturating_sub(skipped_levels);
 
     // Handle supercompression
     let mut levels = Vec::new();
     if let Some(supercompression_scheme) = supercompression_scheme {
-        for (_level, _level_data) in ktx2.levels().enumerate() {
+        for (_level, _level_data) in ktx2.levels().skip(skipped_levels as usize).enumerate() {
             match supercompression_scheme {
                 #[cfg(feature = "flate2")]
                 SupercompressionScheme::ZLIB => {
@@ -79,7 +107,11 @@ pub fn ktx2_buffer_to_image(
             }
         }
     } else {
-        levels = ktx2.levels().map(<[u8]>::to_vec).collect();
+        levels = ktx2
+            .levels()
+            .skip(skipped_levels as usize)
+            .map(<[u8]>::to_vec)
+            .collect();
     }
 
     // Identify the format
@@ -279,9 +311,9 @@ pub fn ktx2_buffer_to_image(
     }
     .physical_size(texture_format);
     image.texture_descriptor.mip_level_count = level_count;
-    image.texture_descriptor.dimension = if depth > 1 {
+    image.texture_descriptor.dimension = if full_depth > 1 {
         TextureDimension::D3
-    } else if image.is_compressed() || height > 1 {
+    } else if image.is_compressed() || full_height > 1 {
         TextureDimension::D2
     } else {
         TextureDimension::D1
@@ -295,7 +327,7 @@ pub fn ktx2_buffer_to_image(
         });
     } else if layer_count > 1 {
         dimension = Some(TextureViewDimension::D2Array);
-    } else if depth > 1 {
+    } else if full_depth > 1 {
         dimension = Some(TextureViewDimension::D3);
     }
     if dimension.is_some() {
@@ -307,6 +339,95 @@ pub fn ktx2_buffer_to_image(
     Ok(image)
 }
 
+/// The length of the header at the start of a KTX2 file.
+pub const KTX2_HEADER_LENGTH: usize = 80;
+
+/// The length of each entry of the level index, which follows the header of a KTX2 file.
+const KTX2_LEVEL_INDEX_ENTRY_LENGTH: usize = 24;
+
+/// Returns the length of the header and level index at the start of a KTX2 file, given its
+/// first [`KTX2_HEADER_LENGTH`] bytes.
+pub fn ktx2_level_index_end(header: &[u8]) -> Result<usize, TextureError> {
+    let level_count = read_u32(header, 40)?.max(1) as usize;
+    Ok(KTX2_HEADER_LENGTH + level_count * KTX2_LEVEL_INDEX_ENTRY_LENGTH)
+}
+
+/// Returns the byte range of each mip level in a KTX2 file, from the largest to the smallest,
+/// given the header and level index at the start of the file.
+///
+/// See [`ktx2_level_index_end`] for how many bytes to read.
+pub fn ktx2_level_ranges(header: &[u8]) -> Result<Vec<Range<u64>>, TextureError> {
+    let level_index_end = ktx2_level_index_end(header)?;
+    (KTX2_HEADER_LENGTH..level_index_end)
+        .step_by(KTX2_LEVEL_INDEX_ENTRY_LENGTH)
+        .map(|entry| {
+            let offset = read_u64(header, entry)?;
+            let length = read_u64(header, entry + 8)?;
+            Ok(offset..offset + length)
+        })
+        .collect()
+}
+
+/// Loads a single mip level of a KTX2 file into an [`Image`], without needing the rest of the
+/// mip levels.
+///
+/// `metadata` is the start of the file up to its first mip level, which contains the header,
+/// the level index and the format descriptors. `level_data` is the data of mip `level`, as
+/// located by [`ktx2_level_ranges`]. Together, these let large files be read and decoded one mip
+/// level at a time. The resulting image has the size of the mip level, and a single mip level.
+pub fn ktx2_mip_level_to_image(
+    metadata: &[u8],
+    level: u32,
+    level_data: &[u8],
+    supported_compressed_formats: CompressedImageFormats,
+    is_srgb: bool,
+) -> Result<Image, TextureError> {
+    let level_index_end = ktx2_level_index_end(metadata)?;
+    let level_entry = KTX2_HEADER_LENGTH + level as usize * KTX2_LEVEL_INDEX_ENTRY_LENGTH;
+    if level_entry >= level_index_end || metadata.len() < level_index_end {
+        return Err(TextureError::InvalidData(format!(
+            "KTX2 file has no mip level {level}"
+        )));
+    }
+
+    // Rewrite the header into that of a file with just this mip level, which is appended right
+    // after the metadata. The format descriptors are left where they are.
+    let mut buffer = Vec::with_capacity(metadata.len() + level_data.len());
+    buffer.extend_from_slice(metadata);
+    for dimension_offset in [20, 24, 28] {
+        let size = read_u32(metadata, dimension_offset)?;
+        // A size of zero marks an unused dimension, so it has to stay zero
+        let level_size = if size == 0 { 0 } else { (size >> level).max(1) };
+        buffer[dimension_offset..dimension_offset + 4].copy_from_slice(&level_size.to_le_bytes());
+    }
+    buffer[40..44].copy_from_slice(&1u32.to_le_bytes());
+    let uncompressed_length = read_u64(metadata, level_entry + 16)?;
+    let level_offset = buffer.len() as u64;
+    for (offset, value) in [
+        (0, level_offset),
+        (8, level_data.len() as u64),
+        (16, uncompressed_length),
+    ] {
+        buffer[KTX2_HEADER_LENGTH + offset..KTX2_HEADER_LENGTH + offset + 8]
+            .copy_from_slice(&value.to_le_bytes());
+    }
+    buffer.extend_from_slice(level_data);
+
+    ktx2_buffer_to_image(&buffer, supported_compressed_formats, is_srgb)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, TextureError> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or_else(|| TextureError::InvalidData("Unexpected end of KTX2 header".to_string()))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, TextureError> {
+    data.get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or_else(|| TextureError::InvalidData("Unexpected end of KTX2 header".to_string()))
+}
+
 #[cfg(feature = "basis-universal")]
 pub fn get_transcoded_formats(
     supported_compressed_formats: CompressedImageFormats,
@@ -1498,31 +1619,77 @@ pub fn ktx2_format_to_texture_format(
 mod tests {
     use crate::CompressedImageFormats;
 
-    use super::ktx2_buffer_to_image;
+    use super::{
+        ktx2_buffer_to_image, ktx2_buffer_to_image_with_max_mip_levels, ktx2_level_index_end,
+        ktx2_level_ranges, ktx2_mip_level_to_image, KTX2_HEADER_LENGTH,
+    };
+
+    // R8UnormSrgb texture with 4x4 pixels data and 3 levels of mipmaps
+    const LEVELS_KTX2: &[u8] = &[
+        0xab, 0x4b, 0x54, 0x58, 0x20, 0x32, 0x30, 0xbb, 0x0d, 10, 0x1a, 10, 0x0f, 0, 0, 0, 1, 0, 0,
+        0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+        0x98, 0, 0, 0, 0x2c, 0, 0, 0, 0xc4, 0, 0, 0, 0x5c, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0x28, 1, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0,
+        0, 0, 0, 0x24, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0x20,
+        1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0x2c, 0, 0, 0, 0, 0,
+        0, 0, 2, 0, 0x28, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0xff, 0, 0, 0, 0x12, 0, 0, 0, 0x4b, 0x54, 0x58, 0x6f, 0x72, 0x69, 0x65,
+        0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0, 0x72, 0x64, 0, 0, 0, 0x10, 0, 0, 0, 0x4b,
+        0x54, 0x58, 0x73, 0x77, 0x69, 0x7a, 0x7a, 0x6c, 0x65, 0, 0x72, 0x72, 0x72, 0x31, 0, 0x2c,
+        0, 0, 0, 0x4b, 0x54, 0x58, 0x77, 0x72, 0x69, 0x74, 0x65, 0x72, 0, 0x74, 0x6f, 0x6b, 0x74,
+        0x78, 0x20, 0x76, 0x34, 0x2e, 0x33, 0x2e, 0x30, 0x7e, 0x32, 0x38, 0x20, 0x2f, 0x20, 0x6c,
+        0x69, 0x62, 0x6b, 0x74, 0x78, 0x20, 0x76, 0x34, 0x2e, 0x33, 0x2e, 0x30, 0x7e, 0x31, 0,
+        0x4a, 0, 0, 0, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a,
+        0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a,
+    ];
 
     #[test]
     fn test_ktx_levels() {
-        // R8UnormSrgb texture with 4x4 pixels data and 3 levels of mipmaps
-        let buffer = vec![
-            0xab, 0x4b, 0x54, 0x58, 0x20, 0x32, 0x30, 0xbb, 0x0d, 10, 0x1a, 10, 0x0f, 0, 0, 0, 1,
-            0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0,
-            0, 0, 0x98, 0, 0, 0, 0x2c, 0, 0, 0, 0xc4, 0, 0, 0, 0x5c, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0x28, 1, 0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0x10,
-            0, 0, 0, 0, 0, 0, 0, 0x24, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
-            0, 0, 0, 0x20, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
-            0x2c, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0x28, 0, 1, 1, 2, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0, 0, 0, 0x12, 0, 0, 0, 0x4b, 0x54, 0x58,
-            0x6f, 0x72, 0x69, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0, 0x72, 0x64, 0, 0,
-            0, 0x10, 0, 0, 0, 0x4b, 0x54, 0x58, 0x73, 0x77, 0x69, 0x7a, 0x7a, 0x6c, 0x65, 0, 0x72,
-            0x72, 0x72, 0x31, 0, 0x2c, 0, 0, 0, 0x4b, 0x54, 0x58, 0x77, 0x72, 0x69, 0x74, 0x65,
-            0x72, 0, 0x74, 0x6f, 0x6b, 0x74, 0x78, 0x20, 0x76, 0x34, 0x2e, 0x33, 0x2e, 0x30, 0x7e,
-            0x32, 0x38, 0x20, 0x2f, 0x20, 0x6c, 0x69, 0x62, 0x6b, 0x74, 0x78, 0x20, 0x76, 0x34,
-            0x2e, 0x33, 0x2e, 0x30, 0x7e, 0x31, 0, 0x4a, 0, 0, 0, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a,
-            0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a, 0x4a,
-            0x4a,
-        ];
         let supported_compressed_formats = CompressedImageFormats::empty();
-        let result = ktx2_buffer_to_image(&buffer, supported_compressed_formats, true);
+        let result = ktx2_buffer_to_image(LEVELS_KTX2, supported_compressed_formats, true);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_ktx_max_mip_levels() {
+        let supported_compressed_formats = CompressedImageFormats::empty();
+        let image = ktx2_buffer_to_image_with_max_mip_levels(
+            LEVELS_KTX2,
+            supported_compressed_formats,
+            true,
+            Some(2),
+        )
+        .unwrap();
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 2);
+        assert_eq!(image.texture_descriptor.mip_level_count, 2);
+        // 2x2 + 1x1 R8 pixels
+        assert_eq!(image.data.len(), 5);
+    }
+
+    #[test]
+    fn test_ktx_single_mip_level() {
+        let level_index_end = ktx2_level_index_end(&LEVELS_KTX2[..KTX2_HEADER_LENGTH]).unwrap();
+        let levels = ktx2_level_ranges(&LEVELS_KTX2[..level_index_end]).unwrap();
+        // The smallest mip level is stored first
+        assert_eq!(levels, vec![0x128..0x138, 0x124..0x128, 0x120..0x121]);
+
+        let metadata = &LEVELS_KTX2[..0x120];
+        let range = levels[1].start as usize..levels[1].end as usize;
+        let image = ktx2_mip_level_to_image(
+            metadata,
+            1,
+            &LEVELS_KTX2[range],
+            CompressedImageFormats::empty(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 2);
+        assert_eq!(image.texture_descriptor.mip_level_count, 1);
+        // The same data as when loading the whole file
+        let full_image =
+            ktx2_buffer_to_image(LEVELS_KTX2, CompressedImageFormats::empty(), true).unwrap();
+        assert_eq!(image.data, full_image.data[16..20]);
+    }
 }
@@ -295,6 +295,9 @@ pub mod iterable;
 #[cfg(feature = "alloc")]
 pub mod sample_curves;
 
+#[cfg(feature = "bevy_reflect")]
+pub mod serializable;
+
 // bevy_math::curve re-exports all commonly-needed curve-related items.
 pub use adaptors::*;
 pub use easing::*;
@@ -306,6 +309,9 @@ pub use {
     sample_curves::*,
 };
 
+#[cfg(feature = "bevy_reflect")]
+pub use serializable::*;
+
 use crate::VectorSpace;
 use core::{marker::PhantomData, ops::Deref};
 use interval::InvalidIntervalError;
@@ -0,0 +1,281 @@
+//! Type-erased curves which can be reflected and serialized, so that curves can be stored in
+//! assets and edited by tools.
+//!
+//! Any curve that implements [`Reflect`] and [`Clone`] is a [`ReflectableCurve`], and can be boxed
+//! into a [`SerializableCurve`]. Since a [`SerializableCurve`] is a curve itself, it can be used
+//! as the inner curve of the [adaptors], so that whole chains of adaptors can be serialized
+//! without naming their full type.
+//!
+//! A boxed curve is serialized together with the [type path] of its concrete curve, which is
+//! looked up in the [`TypeRegistry`] when deserializing. Concrete curve types must therefore be
+//! registered using [`CurveRegistry::register_curve`], and the adaptors using
+//! [`CurveRegistry::register_curve_adaptors`].
+//!
+//! ```
+//! # use bevy_math::curve::*;
+//! # use bevy_reflect::TypeRegistry;
+//! let mut registry = TypeRegistry::new();
+//! registry.register_curve_adaptors::<f32>();
+//! registry.register_curve::<f32, EasingCurve<f32>>();
+//!
+//! let curve: SerializableCurve<f32> = EasingCurve::new(0.0, 1.0, EaseFunction::CubicIn)
+//!     .into_serializable()
+//!     .ping_pong()
+//!     .unwrap()
+//!     .into_serializable();
+//! assert_eq!(curve.sample(1.5), Some(0.125));
+//!
+//! // Tools can list the registered curve types to choose from.
+//! assert!(registry
+//!     .iter_curves::<f32>()
+//!     .any(|(registration, _)| registration.type_info().type_path_table().ident()
+//!         == Some("PingPongCurve")));
+//! ```
+//!
+//! Curves containing functions, such as [`FunctionCurve`] and [`MapCurve`], can't be rebuilt
+//! from their reflected data, so they can't be deserialized.
+//!
+//! [adaptors]: super::adaptors
+//! [type path]: TypePath
+//! [`FunctionCurve`]: super::FunctionCurve
+//! [`MapCurve`]: super::MapCurve
+
+use super::{adaptors::*, Curve};
+
+use alloc::{boxed::Box, format};
+use bevy_reflect::{
+    utility::GenericTypePathCell, FromReflect, FromType, GetTypeRegistration, Reflect,
+    ReflectFromReflect, ReflectTraitObject, Reflectable, TypePath, TypeRegistration, TypeRegistry,
+};
+use core::fmt::{self, Debug};
+
+/// A [`Curve`] which can be reflected and cloned, and so can be stored in a
+/// [`SerializableCurve`].
+///
+/// This is implemented for every curve which implements [`Reflect`] and [`Clone`].
+pub trait ReflectableCurve<T>: Curve<T> + Reflect {
+    /// Returns a boxed clone of this curve.
+    fn clone_curve(&self) -> SerializableCurve<T>;
+
+    /// Boxes this curve into a [`SerializableCurve`].
+    fn into_serializable(self) -> SerializableCurve<T>
+    where
+        Self: Sized,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T, C> ReflectableCurve<T> for C
+where
+    C: Curve<T> + Reflect + Clone,
+{
+    fn clone_curve(&self) -> SerializableCurve<T> {
+        Box::new(self.clone())
+    }
+}
+
+/// A boxed [`ReflectableCurve`], whose concrete curve type is erased.
+///
+/// This can be reflected and serialized like any other field, as long as the concrete curve type
+/// is registered in the [`TypeRegistry`]. See the [module-level documentation] for details.
+///
+/// [module-level documentation]: self
+pub type SerializableCurve<T> = Box<dyn ReflectableCurve<T>>;
+
+impl<T: 'static> Clone for SerializableCurve<T> {
+    fn clone(&self) -> Self {
+        (**self).clone_curve()
+    }
+}
+
+impl<T: 'static> Debug for dyn ReflectableCurve<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.debug(f)
+    }
+}
+
+impl<T: TypePath> TypePath for dyn ReflectableCurve<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!("dyn bevy_math::curve::ReflectableCurve<{}>", T::type_path())
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("dyn ReflectableCurve<{}>", T::short_type_path()))
+    }
+}
+
+impl<T: TypePath> ReflectTraitObject for dyn ReflectableCurve<T> {
+    type TypeData = ReflectCurve<T>;
+
+    fn from_type_data(
+        data: &ReflectCurve<T>,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<Self>, Box<dyn Reflect>> {
+        data.get_boxed(value)
+    }
+
+    fn clone_boxed(&self) -> Box<Self> {
+        self.clone_curve()
+    }
+}
+
+/// Type data for a curve type with output `T`, which allows converting a reflected value of that
+/// type into a [`ReflectableCurve`].
+///
+/// This is registered by [`CurveRegistry::register_curve`].
+pub struct ReflectCurve<T> {
+    get: fn(&dyn Reflect) -> Option<&dyn ReflectableCurve<T>>,
+    get_boxed: fn(Box<dyn Reflect>) -> Result<SerializableCurve<T>, Box<dyn Reflect>>,
+}
+
+impl<T> ReflectCurve<T> {
+    /// Returns the given value as a curve.
+    ///
+    /// Returns `None` if the value isn't the type that this type data was created for.
+    pub fn get<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn ReflectableCurve<T>> {
+        (self.get)(value)
+    }
+
+    /// Converts the given boxed value into a [`SerializableCurve`].
+    ///
+    /// Returns the value as an error if it isn't the type that this type data was created for.
+    pub fn get_boxed(
+        &self,
+        value: Box<dyn Reflect>,
+    ) -> Result<SerializableCurve<T>, Box<dyn Reflect>> {
+        (self.get_boxed)(value)
+    }
+}
+
+impl<T> Clone for ReflectCurve<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReflectCurve<T> {}
+
+impl<T, C: ReflectableCurve<T>> FromType<C> for ReflectCurve<T> {
+    fn from_type() -> Self {
+        Self {
+            get: |value| {
+                value
+                    .downcast_ref::<C>()
+                    .map(|curve| curve as &dyn ReflectableCurve<T>)
+            },
+            get_boxed: |value| {
+                value
+                    .downcast::<C>()
+                    .map(|curve| curve as SerializableCurve<T>)
+            },
+        }
+    }
+}
+
+/// Extension trait for [`TypeRegistry`], to register curve types which can be stored in a
+/// [`SerializableCurve`].
+pub trait CurveRegistry {
+    /// Registers the curve type `C` with output `T`, so that it can be deserialized into a
+    /// [`SerializableCurve<T>`].
+    ///
+    /// This also registers [`SerializableCurve<T>`] itself.
+    fn register_curve<T, C>(&mut self)
+    where
+        T: TypePath,
+        C: ReflectableCurve<T> + FromReflect + GetTypeRegistration + TypePath;
+
+    /// Registers [`SerializableCurve<T>`], along with [`ConstantCurve<T>`] and the [adaptors]
+    /// with output `T` whose inner curves are [`SerializableCurve`]s.
+    ///
+    /// Adaptors which require more of `T`, such as [`ContinuationCurve`], have to be registered
+    /// separately with [`register_curve`](Self::register_curve).
+    ///
+    /// [adaptors]: super::adaptors
+    fn register_curve_adaptors<T>(&mut self)
+    where
+        T: FromReflect + Reflectable + Clone;
+
+    /// Returns the registrations of all curve types with output `T`, along with their
+    /// [`ReflectCurve`] type data.
+    fn iter_curves<T: 'static>(
+        &self,
+    ) -> impl Iterator<Item = (&TypeRegistration, &ReflectCurve<T>)>;
+}
+
+impl CurveRegistry for TypeRegistry {
+    fn register_curve<T, C>(&mut self)
+    where
+        T: TypePath,
+        C: ReflectableCurve<T> + FromReflect + GetTypeRegistration + TypePath,
+    {
+        self.register::<SerializableCurve<T>>();
+        self.register::<C>();
+        self.register_type_data::<C, ReflectCurve<T>>();
+        // Deserialized curves are converted from their dynamic representation using this
+        self.register_type_data::<C, ReflectFromReflect>();
+    }
+
+    fn register_curve_adaptors<T>(&mut self)
+    where
+        T: FromReflect + Reflectable + Clone,
+    {
+        self.register_curve::<T, ConstantCurve<T>>();
+        self.register_curve::<T, LinearReparamCurve<T, SerializableCurve<T>>>();
+        self.register_curve::<T, CurveReparamCurve<T, SerializableCurve<T>, SerializableCurve<f32>>>();
+        self.register_curve::<(f32, T), GraphCurve<T, SerializableCurve<T>>>();
+        self.register_curve::<T, ChainCurve<T, SerializableCurve<T>, SerializableCurve<T>>>();
+        self.register_curve::<T, ReverseCurve<T, SerializableCurve<T>>>();
+        self.register_curve::<T, RepeatCurve<T, SerializableCurve<T>>>();
+        self.register_curve::<T, ForeverCurve<T, SerializableCurve<T>>>();
+        self.register_curve::<T, PingPongCurve<T, SerializableCurve<T>>>();
+    }
+
+    fn iter_curves<T: 'static>(
+        &self,
+    ) -> impl Iterator<Item = (&TypeRegistration, &ReflectCurve<T>)> {
+        self.iter_with_data::<ReflectCurve<T>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::{CurveExt, EaseFunction, EasingCurve, Interval};
+
+    #[test]
+    fn boxed_curve_round_trip() {
+        let mut registry = TypeRegistry::new();
+        registry.register_curve_adaptors::<f32>();
+        registry.register_curve::<f32, EasingCurve<f32>>();
+
+        let curve = EasingCurve::new(0.0, 2.0, EaseFunction::Linear)
+            .into_serializable()
+            .reverse()
+            .unwrap()
+            .into_serializable();
+
+        // Rebuild the curve from its dynamic representation, as when deserializing
+        let concrete = (*curve).as_reflect();
+        let type_id = concrete.reflect_type_info().type_id();
+        let dynamic = concrete.clone_value();
+        let value = registry
+            .get_type_data::<ReflectFromReflect>(type_id)
+            .unwrap()
+            .from_reflect(&*dynamic)
+            .unwrap();
+        let rebuilt = registry
+            .get_type_data::<ReflectCurve<f32>>(type_id)
+            .unwrap()
+            .get_boxed(value)
+            .unwrap();
+
+        assert_eq!(rebuilt.domain(), Interval::UNIT);
+        assert_eq!(rebuilt.sample(0.25), Some(1.5));
+        assert_eq!(rebuilt.clone().sample(1.0), Some(0.0));
+    }
+}
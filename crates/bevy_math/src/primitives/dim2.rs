@@ -9,7 +9,13 @@ use crate::{
 };
 
 #[cfg(feature = "alloc")]
-use super::polygon::is_polygon_simple;
+use super::{
+    polygon::is_polygon_simple,
+    polygon_ops::{
+        decompose_convex_polygon, offset_polygon, polygon_boolean, triangulate_polygon, BooleanOp,
+        TriangulationError,
+    },
+};
 
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
@@ -1695,6 +1701,14 @@ impl<const N: usize> Polygon<N> {
     pub fn is_simple(&self) -> bool {
         is_polygon_simple(&self.vertices)
     }
+
+    /// Triangulates the polygon, returning triangles as indices into [`Self::vertices`].
+    ///
+    /// See [`BoxedPolygon::triangulate`].
+    #[cfg(feature = "alloc")]
+    pub fn triangulate(&self) -> Result<Vec<[u32; 3]>, TriangulationError> {
+        triangulate_polygon(&self.vertices)
+    }
 }
 
 impl<const N: usize> From<ConvexPolygon<N>> for Polygon<N> {
@@ -1819,6 +1833,78 @@ impl BoxedPolygon {
     pub fn is_simple(&self) -> bool {
         is_polygon_simple(&self.vertices)
     }
+
+    /// Triangulates the polygon, returning triangles as indices into [`Self::vertices`].
+    ///
+    /// The triangles have the same winding order as the polygon, and can be used directly as the
+    /// indices of a mesh with the polygon's vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TriangulationError`] if the polygon has fewer than three vertices or is not
+    /// [simple](Self::is_simple).
+    pub fn triangulate(&self) -> Result<Vec<[u32; 3]>, TriangulationError> {
+        triangulate_polygon(&self.vertices)
+    }
+
+    /// Splits the polygon into convex polygons, with the same winding order as the polygon.
+    ///
+    /// The number of pieces is not necessarily minimal, but at most four times the minimum.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TriangulationError`] if the polygon has fewer than three vertices or is not
+    /// [simple](Self::is_simple).
+    pub fn convex_decomposition(&self) -> Result<Vec<BoxedPolygon>, TriangulationError> {
+        Ok(decompose_convex_polygon(&self.vertices)?
+            .into_iter()
+            .map(BoxedPolygon::new)
+            .collect())
+    }
+
+    /// Inflates the polygon by moving its edges outwards by `distance`, or deflates it if
+    /// `distance` is negative.
+    ///
+    /// Sharp corners are beveled rather than extended to a point. The polygon should be
+    /// [simple](Self::is_simple), and deflating it by more than its local thickness can produce
+    /// a polygon that is not.
+    pub fn offset(&self, distance: f32) -> BoxedPolygon {
+        BoxedPolygon::new(offset_polygon(&self.vertices, distance))
+    }
+
+    /// Computes the area covered by either this polygon or `other`.
+    ///
+    /// See [`Self::boolean`].
+    pub fn union(&self, other: &BoxedPolygon) -> Vec<BoxedPolygon> {
+        self.boolean(other, BooleanOp::Union)
+    }
+
+    /// Computes the area covered by both this polygon and `other`.
+    ///
+    /// See [`Self::boolean`].
+    pub fn intersection(&self, other: &BoxedPolygon) -> Vec<BoxedPolygon> {
+        self.boolean(other, BooleanOp::Intersection)
+    }
+
+    /// Computes the area covered by this polygon but not by `other`.
+    ///
+    /// See [`Self::boolean`].
+    pub fn difference(&self, other: &BoxedPolygon) -> Vec<BoxedPolygon> {
+        self.boolean(other, BooleanOp::Difference)
+    }
+
+    /// Computes a boolean operation between this polygon and `other`, which should both be
+    /// [simple](Self::is_simple).
+    ///
+    /// The result is a list of boundaries: outer boundaries are in counterclockwise order,
+    /// while the boundaries of holes are in clockwise order. Polygons whose edges touch or overlap
+    /// without crossing are not supported, and may produce wrong results.
+    pub fn boolean(&self, other: &BoxedPolygon, op: BooleanOp) -> Vec<BoxedPolygon> {
+        polygon_boolean(&self.vertices, &other.vertices, op)
+            .into_iter()
+            .map(BoxedPolygon::new)
+            .collect()
+    }
 }
 
 /// A polygon centered on the origin where all vertices lie on a circle, equally far apart.
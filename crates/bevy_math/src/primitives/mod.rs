@@ -7,6 +7,10 @@ pub use dim2::*;
 mod dim3;
 pub use dim3::*;
 mod polygon;
+#[cfg(feature = "alloc")]
+mod polygon_ops;
+#[cfg(feature = "alloc")]
+pub use polygon_ops::{BooleanOp, TriangulationError};
 #[cfg(feature = "serialize")]
 mod serde;
 
@@ -0,0 +1,654 @@
+//! Algorithms operating on the vertices of simple polygons, used by [`BoxedPolygon`] and [`Polygon`].
+//!
+//! [`BoxedPolygon`]: super::BoxedPolygon
+//! [`Polygon`]: super::Polygon
+
+use alloc::{vec, vec::Vec};
+use thiserror::Error;
+
+use super::polygon::is_polygon_simple;
+use crate::Vec2;
+
+/// The ratio between the distance a corner may move and the offset distance, above which the
+/// corner is beveled by [`offset_polygon`] instead of mitered.
+const MITER_LIMIT: f32 = 2.0;
+
+/// An error that happens when triangulating a polygon.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangulationError {
+    /// The polygon has fewer than three vertices.
+    #[error("The polygon has fewer than three vertices")]
+    TooFewVertices,
+    /// The polygon is not simple, so its edges cross or touch each other.
+    #[error("The polygon is not simple")]
+    NotSimple,
+}
+
+/// Returns twice the signed area of the polygon, which is positive if the vertices are in
+/// counterclockwise order.
+fn double_signed_area(vertices: &[Vec2]) -> f32 {
+    let mut area = 0.0;
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        area += a.perp_dot(b);
+    }
+    area
+}
+
+/// Returns the indices of the vertices in counterclockwise order.
+fn counterclockwise_indices(vertices: &[Vec2]) -> Vec<usize> {
+    if double_signed_area(vertices) >= 0.0 {
+        (0..vertices.len()).collect()
+    } else {
+        (0..vertices.len()).rev().collect()
+    }
+}
+
+/// Returns the vertices in counterclockwise order.
+fn counterclockwise(vertices: &[Vec2]) -> Vec<Vec2> {
+    counterclockwise_indices(vertices)
+        .into_iter()
+        .map(|i| vertices[i])
+        .collect()
+}
+
+/// Positive if `c` is to the left of the line from `a` to `b`.
+#[inline]
+fn turn(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b - a).perp_dot(c - a)
+}
+
+/// Tests whether `p` lies inside or on the edges of the counterclockwise triangle `a`, `b`, `c`.
+fn triangle_contains(a: Vec2, b: Vec2, c: Vec2, p: Vec2) -> bool {
+    turn(a, b, p) >= 0.0 && turn(b, c, p) >= 0.0 && turn(c, a, p) >= 0.0
+}
+
+/// Tests whether `p` lies inside the polygon, using the even-odd rule.
+fn polygon_contains(vertices: &[Vec2], p: Vec2) -> bool {
+    let mut inside = false;
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (b.x - a.x) * (p.y - a.y) / (b.y - a.y) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Tests whether `p` lies on one of the polygon's edges.
+fn polygon_boundary_contains(vertices: &[Vec2], p: Vec2) -> bool {
+    vertices.iter().enumerate().any(|(i, &a)| {
+        let b = vertices[(i + 1) % vertices.len()];
+        let t = ((p - a).dot(b - a) / (b - a).length_squared()).clamp(0.0, 1.0);
+        (a + (b - a) * t).distance_squared(p) <= f32::EPSILON
+    })
+}
+
+/// Tests whether the polygon lies inside `other`, assuming their edges don't cross.
+///
+/// Vertices and edge midpoints on the boundary of `other` can't tell which side the polygon is
+/// on, so the first point which doesn't lie on the boundary is tested. Polygons which lie
+/// entirely on the boundary of `other` are considered to be inside it.
+fn polygon_inside(vertices: &[Vec2], other: &[Vec2]) -> bool {
+    vertices
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| [a, (a + vertices[(i + 1) % vertices.len()]) / 2.0])
+        .find(|&p| !polygon_boundary_contains(other, p))
+        .is_none_or(|p| polygon_contains(other, p))
+}
+
+/// Triangulates a simple polygon using ear clipping.
+///
+/// Returns triangles as indices into `vertices`, with the same winding order as the polygon.
+/// A polygon with `n` vertices is split into `n - 2` triangles.
+pub fn triangulate_polygon(vertices: &[Vec2]) -> Result<Vec<[u32; 3]>, TriangulationError> {
+    if vertices.len() < 3 {
+        return Err(TriangulationError::TooFewVertices);
+    }
+    if !is_polygon_simple(vertices) {
+        return Err(TriangulationError::NotSimple);
+    }
+
+    let counterclockwise = double_signed_area(vertices) >= 0.0;
+    let triangles = clip_ears(vertices, counterclockwise_indices(vertices))?;
+    Ok(triangles
+        .into_iter()
+        .map(|[a, b, c]| {
+            if counterclockwise {
+                [a as u32, b as u32, c as u32]
+            } else {
+                [c as u32, b as u32, a as u32]
+            }
+        })
+        .collect())
+}
+
+/// Clips ears off the polygon given by counterclockwise `remaining` indices until only a
+/// triangle is left, returning counterclockwise triangles.
+fn clip_ears(
+    vertices: &[Vec2],
+    mut remaining: Vec<usize>,
+) -> Result<Vec<[usize; 3]>, TriangulationError> {
+    let mut triangles = Vec::with_capacity(remaining.len() - 2);
+    let mut i = 0;
+    let mut attempts = 0;
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let prev = remaining[(i + len - 1) % len];
+        let curr = remaining[i];
+        let next = remaining[(i + 1) % len];
+        let (a, b, c) = (vertices[prev], vertices[curr], vertices[next]);
+
+        let is_ear = turn(a, b, c) > 0.0
+            && !remaining.iter().any(|&other| {
+                let p = vertices[other];
+                p != a && p != b && p != c && triangle_contains(a, b, c, p)
+            });
+
+        if is_ear {
+            triangles.push([prev, curr, next]);
+            remaining.remove(i);
+            i %= remaining.len();
+            attempts = 0;
+        } else {
+            i = (i + 1) % len;
+            attempts += 1;
+            // A simple polygon always has an ear, so this only happens for degenerate input.
+            if attempts > len {
+                return Err(TriangulationError::NotSimple);
+            }
+        }
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+    Ok(triangles)
+}
+
+/// Splits a simple polygon into convex polygons, using the Hertel-Mehlhorn algorithm.
+///
+/// The polygon is triangulated, after which neighboring pieces are merged as long as the result
+/// stays convex. This produces at most four times the minimal number of convex pieces.
+/// The pieces have the same winding order as the polygon.
+pub fn decompose_convex_polygon(vertices: &[Vec2]) -> Result<Vec<Vec<Vec2>>, TriangulationError> {
+    if vertices.len() < 3 {
+        return Err(TriangulationError::TooFewVertices);
+    }
+    if !is_polygon_simple(vertices) {
+        return Err(TriangulationError::NotSimple);
+    }
+
+    let counterclockwise = double_signed_area(vertices) >= 0.0;
+    let mut pieces: Vec<Vec<usize>> = clip_ears(vertices, counterclockwise_indices(vertices))?
+        .into_iter()
+        .map(Vec::from)
+        .collect();
+
+    let mut merged_any = true;
+    while merged_any {
+        merged_any = false;
+        'search: for p in 0..pieces.len() {
+            for q in (p + 1)..pieces.len() {
+                if let Some(merged) = merge_convex(vertices, &pieces[p], &pieces[q]) {
+                    pieces[p] = merged;
+                    pieces.swap_remove(q);
+                    merged_any = true;
+                    break 'search;
+                }
+            }
+        }
+    }
+
+    Ok(pieces
+        .into_iter()
+        .map(|piece| {
+            let mut piece: Vec<Vec2> = piece.into_iter().map(|i| vertices[i]).collect();
+            if !counterclockwise {
+                piece.reverse();
+            }
+            piece
+        })
+        .collect())
+}
+
+/// Merges two counterclockwise polygons sharing an edge, if the result is convex.
+fn merge_convex(vertices: &[Vec2], p: &[usize], q: &[usize]) -> Option<Vec<usize>> {
+    // Find an edge `a -> b` in `p` that is `b -> a` in `q`.
+    let (pa, qb) = (0..p.len()).find_map(|i| {
+        let (a, b) = (p[i], p[(i + 1) % p.len()]);
+        (0..q.len())
+            .find(|&j| q[j] == b && q[(j + 1) % q.len()] == a)
+            .map(|j| (i, j))
+    })?;
+
+    // Walk `p` from `b` around to `a`, then `q` from after `a` around to before `b`.
+    let mut merged = Vec::with_capacity(p.len() + q.len() - 2);
+    merged.extend((1..=p.len()).map(|k| p[(pa + k) % p.len()]));
+    merged.extend((2..q.len()).map(|k| q[(qb + k) % q.len()]));
+
+    let len = merged.len();
+    let convex = (0..len).all(|i| {
+        let a = vertices[merged[(i + len - 1) % len]];
+        let b = vertices[merged[i]];
+        let c = vertices[merged[(i + 1) % len]];
+        turn(a, b, c) >= 0.0
+    });
+    convex.then_some(merged)
+}
+
+/// Moves every edge of a simple polygon outwards by `distance`, or inwards if it is negative.
+///
+/// Corners are mitered, unless that would move them by more than twice `distance`, in which case
+/// they are beveled with an additional vertex. Deflating a polygon by more than its local
+/// thickness can produce a self-intersecting polygon.
+pub fn offset_polygon(vertices: &[Vec2], distance: f32) -> Vec<Vec2> {
+    let len = vertices.len();
+    if len < 3 || distance == 0.0 {
+        return vertices.to_vec();
+    }
+
+    // Outward normals are to the right of the edges for counterclockwise polygons.
+    let orientation = if double_signed_area(vertices) >= 0.0 {
+        1.0
+    } else {
+        -1.0
+    };
+    let normal = |i: usize| {
+        let edge = vertices[(i + 1) % len] - vertices[i];
+        Vec2::new(edge.y, -edge.x).normalize_or_zero() * orientation
+    };
+
+    let mut result = Vec::with_capacity(len);
+    for i in 0..len {
+        let vertex = vertices[i];
+        let n0 = normal((i + len - 1) % len);
+        let n1 = normal(i);
+
+        // The miter vector, whose projection onto both normals has unit length.
+        let denominator = 1.0 + n0.dot(n1);
+        let outer_corner = n0.perp_dot(n1) * orientation * distance.signum() > 0.0;
+        let miter_too_long = denominator < 2.0 / (MITER_LIMIT * MITER_LIMIT);
+        if outer_corner && miter_too_long {
+            result.push(vertex + n0 * distance);
+            result.push(vertex + n1 * distance);
+        } else if denominator > f32::EPSILON {
+            result.push(vertex + (n0 + n1) / denominator * distance);
+        } else {
+            result.push(vertex + n0 * distance);
+        }
+    }
+    result
+}
+
+/// A boolean operation between two polygons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// The area covered by either polygon.
+    Union,
+    /// The area covered by both polygons.
+    Intersection,
+    /// The area covered by the first polygon but not the second.
+    Difference,
+}
+
+/// A vertex of one of the polygons in [`polygon_boolean`].
+#[derive(Clone, Copy, Debug)]
+struct ClipVertex {
+    position: Vec2,
+    next: usize,
+    prev: usize,
+    /// The same intersection in the other polygon, if this is an intersection.
+    neighbor: Option<usize>,
+    /// Whether to walk forwards from this intersection.
+    forward: bool,
+    visited: bool,
+}
+
+/// An intersection between edge `subject_edge` of the subject and `clip_edge` of the clip polygon.
+struct EdgeIntersection {
+    position: Vec2,
+    subject_edge: usize,
+    subject_alpha: f32,
+    clip_edge: usize,
+    clip_alpha: f32,
+}
+
+/// Computes a boolean operation between two simple polygons, using the Greiner-Hormann algorithm.
+///
+/// Returns the boundaries of the resulting area. Outer boundaries are in counterclockwise order,
+/// while boundaries of holes are in clockwise order. Polygons which touch without crossing, such
+/// as a polygon nested in a corner of another, are supported, but edges touching each other or
+/// vertices lying on an edge of the other polygon where the polygons also cross may produce
+/// wrong results.
+pub fn polygon_boolean(subject: &[Vec2], clip: &[Vec2], op: BooleanOp) -> Vec<Vec<Vec2>> {
+    if subject.len() < 3 || clip.len() < 3 {
+        return match op {
+            BooleanOp::Union if subject.len() < 3 => vec![counterclockwise(clip)],
+            BooleanOp::Union | BooleanOp::Difference if clip.len() < 3 => {
+                vec![counterclockwise(subject)]
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    let subject = counterclockwise(subject);
+    let clip = counterclockwise(clip);
+
+    let mut intersections = Vec::new();
+    for i in 0..subject.len() {
+        let (a, b) = (subject[i], subject[(i + 1) % subject.len()]);
+        for j in 0..clip.len() {
+            let (c, d) = (clip[j], clip[(j + 1) % clip.len()]);
+            let denominator = (b - a).perp_dot(d - c);
+            if denominator == 0.0 {
+                continue;
+            }
+            let subject_alpha = (c - a).perp_dot(d - c) / denominator;
+            let clip_alpha = (c - a).perp_dot(b - a) / denominator;
+            if subject_alpha > 0.0 && subject_alpha < 1.0 && clip_alpha > 0.0 && clip_alpha < 1.0 {
+                intersections.push(EdgeIntersection {
+                    position: a + (b - a) * subject_alpha,
+                    subject_edge: i,
+                    subject_alpha,
+                    clip_edge: j,
+                    clip_alpha,
+                });
+            }
+        }
+    }
+
+    if intersections.is_empty() {
+        let subject_in_clip = polygon_inside(&subject, &clip);
+        let clip_in_subject = polygon_inside(&clip, &subject);
+        let reversed = |vertices: Vec<Vec2>| vertices.into_iter().rev().collect::<Vec<_>>();
+        return match op {
+            BooleanOp::Union if subject_in_clip => vec![clip],
+            BooleanOp::Union if clip_in_subject => vec![subject],
+            BooleanOp::Union => vec![subject, clip],
+            BooleanOp::Intersection if subject_in_clip => vec![subject],
+            BooleanOp::Intersection if clip_in_subject => vec![clip],
+            BooleanOp::Intersection => Vec::new(),
+            BooleanOp::Difference if subject_in_clip => Vec::new(),
+            BooleanOp::Difference if clip_in_subject => vec![subject, reversed(clip)],
+            BooleanOp::Difference => vec![subject],
+        };
+    }
+
+    let mut nodes = Vec::with_capacity(subject.len() + clip.len() + intersections.len() * 2);
+    let mut subject_nodes = vec![0; intersections.len()];
+    let mut clip_nodes = vec![0; intersections.len()];
+    let subject_start = push_ring(
+        &mut nodes,
+        &subject,
+        &intersections,
+        |intersection| (intersection.subject_edge, intersection.subject_alpha),
+        &mut subject_nodes,
+    );
+    let clip_start = push_ring(
+        &mut nodes,
+        &clip,
+        &intersections,
+        |intersection| (intersection.clip_edge, intersection.clip_alpha),
+        &mut clip_nodes,
+    );
+    for (&s, &c) in subject_nodes.iter().zip(&clip_nodes) {
+        nodes[s].neighbor = Some(c);
+        nodes[c].neighbor = Some(s);
+    }
+
+    // Walking forwards from an intersection stays inside the other polygon if it is an entry.
+    // For a union, the parts outside the other polygon are kept instead, and for a difference,
+    // the subject is kept outside the clip polygon while the clip polygon is kept inside.
+    let (subject_outside, clip_outside) = match op {
+        BooleanOp::Union => (true, true),
+        BooleanOp::Intersection => (false, false),
+        BooleanOp::Difference => (true, false),
+    };
+    mark_directions(&mut nodes, subject_start, &clip, subject_outside);
+    mark_directions(&mut nodes, clip_start, &subject, clip_outside);
+
+    // Each ring is walked with the resulting area on its left, so starting from intersections
+    // which walk forwards along the subject keeps outer boundaries counterclockwise.
+    let mut result = Vec::new();
+    while let Some(start) = subject_nodes
+        .iter()
+        .copied()
+        .find(|&i| !nodes[i].visited && nodes[i].forward)
+    {
+        let mut ring = Vec::new();
+        let mut current = start;
+        loop {
+            nodes[current].visited = true;
+            let neighbor = nodes[current].neighbor.unwrap();
+            nodes[neighbor].visited = true;
+            ring.push(nodes[current].position);
+
+            let forward = nodes[current].forward;
+            loop {
+                current = if forward {
+                    nodes[current].next
+                } else {
+                    nodes[current].prev
+                };
+                if nodes[current].neighbor.is_some() {
+                    break;
+                }
+                ring.push(nodes[current].position);
+            }
+
+            current = nodes[current].neighbor.unwrap();
+            if nodes[current].visited {
+                break;
+            }
+        }
+        result.push(ring);
+    }
+    result
+}
+
+/// Pushes a ring of the polygon's vertices with its intersections inserted along the edges,
+/// recording the node of each intersection. Returns the index of the first node.
+fn push_ring(
+    nodes: &mut Vec<ClipVertex>,
+    vertices: &[Vec2],
+    intersections: &[EdgeIntersection],
+    edge_alpha: impl Fn(&EdgeIntersection) -> (usize, f32),
+    intersection_nodes: &mut [usize],
+) -> usize {
+    let mut order: Vec<usize> = (0..intersections.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (edge_a, alpha_a) = edge_alpha(&intersections[a]);
+        let (edge_b, alpha_b) = edge_alpha(&intersections[b]);
+        edge_a.cmp(&edge_b).then(alpha_a.total_cmp(&alpha_b))
+    });
+
+    let start = nodes.len();
+    let push = |nodes: &mut Vec<ClipVertex>, position: Vec2| {
+        let index = nodes.len();
+        nodes.push(ClipVertex {
+            position,
+            next: index + 1,
+            prev: index.wrapping_sub(1),
+            neighbor: None,
+            forward: false,
+            visited: false,
+        });
+        index
+    };
+
+    let mut order = order.into_iter().peekable();
+    for (i, &vertex) in vertices.iter().enumerate() {
+        push(nodes, vertex);
+        while let Some(k) = order.next_if(|&k| edge_alpha(&intersections[k]).0 == i) {
+            intersection_nodes[k] = push(nodes, intersections[k].position);
+        }
+    }
+
+    let end = nodes.len() - 1;
+    nodes[start].prev = end;
+    nodes[end].next = start;
+    start
+}
+
+/// Marks in which direction to walk from each intersection of the ring starting at `start`.
+///
+/// An intersection enters `other` if the edge leading forwards from it lies inside `other`,
+/// which is tested at the edge's midpoint, since the intersection itself is on the boundary.
+fn mark_directions(nodes: &mut [ClipVertex], start: usize, other: &[Vec2], keep_outside: bool) {
+    let mut current = start;
+    loop {
+        let next = nodes[current].next;
+        if nodes[current].neighbor.is_some() {
+            let midpoint = (nodes[current].position + nodes[next].position) / 2.0;
+            let entry = polygon_contains(other, midpoint);
+            nodes[current].forward = entry != keep_outside;
+        }
+        current = next;
+        if current == start {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(vertices: &[Vec2]) -> f32 {
+        double_signed_area(vertices) / 2.0
+    }
+
+    fn square(min: Vec2, size: f32) -> Vec<Vec2> {
+        vec![
+            min,
+            min + Vec2::new(size, 0.0),
+            min + Vec2::splat(size),
+            min + Vec2::new(0.0, size),
+        ]
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        // An L shape, in clockwise order.
+        let vertices = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 0.0),
+        ];
+        let triangles = triangulate_polygon(&vertices).unwrap();
+        assert_eq!(triangles.len(), 4);
+
+        let mut total = 0.0;
+        for [a, b, c] in triangles {
+            let triangle = [
+                vertices[a as usize],
+                vertices[b as usize],
+                vertices[c as usize],
+            ];
+            // Triangles keep the clockwise winding of the polygon.
+            assert!(area(&triangle) < 0.0);
+            total += area(&triangle);
+        }
+        assert_eq!(total, area(&vertices));
+    }
+
+    #[test]
+    fn triangulate_invalid() {
+        assert_eq!(
+            triangulate_polygon(&[Vec2::ZERO, Vec2::X]),
+            Err(TriangulationError::TooFewVertices)
+        );
+        let bowtie = [Vec2::ZERO, Vec2::ONE, Vec2::X, Vec2::Y];
+        assert_eq!(
+            triangulate_polygon(&bowtie),
+            Err(TriangulationError::NotSimple)
+        );
+    }
+
+    #[test]
+    fn decompose_l_shape() {
+        let vertices = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        let pieces = decompose_convex_polygon(&vertices).unwrap();
+        assert_eq!(pieces.len(), 2);
+        let total: f32 = pieces.iter().map(|piece| area(piece)).sum();
+        assert_eq!(total, area(&vertices));
+
+        let convex = decompose_convex_polygon(&square(Vec2::ZERO, 1.0)).unwrap();
+        assert_eq!(convex.len(), 1);
+    }
+
+    #[test]
+    fn offset_square() {
+        let inflated = offset_polygon(&square(Vec2::ZERO, 2.0), 1.0);
+        assert_eq!(inflated.len(), 4);
+        assert!((area(&inflated) - 16.0).abs() < 1e-5);
+
+        let deflated = offset_polygon(&square(Vec2::ZERO, 2.0), -0.5);
+        assert!((area(&deflated) - 1.0).abs() < 1e-5);
+
+        // Clockwise polygons are inflated outwards as well.
+        let mut clockwise = square(Vec2::ZERO, 2.0);
+        clockwise.reverse();
+        let inflated = offset_polygon(&clockwise, 1.0);
+        assert!((area(&inflated) + 16.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn offset_bevels_sharp_corners() {
+        let spike = [Vec2::ZERO, Vec2::new(10.0, 0.5), Vec2::new(0.0, 1.0)];
+        let inflated = offset_polygon(&spike, 0.1);
+        // The sharp corner is beveled, the other two are not sharp enough.
+        assert_eq!(inflated.len(), 4);
+    }
+
+    #[test]
+    fn boolean_overlapping_squares() {
+        let a = square(Vec2::ZERO, 2.0);
+        let b = square(Vec2::ONE, 2.0);
+
+        let union = polygon_boolean(&a, &b, BooleanOp::Union);
+        assert_eq!(union.len(), 1);
+        assert!((area(&union[0]) - 7.0).abs() < 1e-5);
+
+        let intersection = polygon_boolean(&a, &b, BooleanOp::Intersection);
+        assert_eq!(intersection.len(), 1);
+        assert!((area(&intersection[0]) - 1.0).abs() < 1e-5);
+
+        let difference = polygon_boolean(&a, &b, BooleanOp::Difference);
+        assert_eq!(difference.len(), 1);
+        assert!((area(&difference[0]) - 3.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn boolean_nested_squares() {
+        let outer = square(Vec2::ZERO, 3.0);
+        let inner = square(Vec2::ONE, 1.0);
+
+        let difference = polygon_boolean(&outer, &inner, BooleanOp::Difference);
+        assert_eq!(difference.len(), 2);
+        assert!((area(&difference[0]) - 9.0).abs() < 1e-5);
+        assert!((area(&difference[1]) + 1.0).abs() < 1e-5);
+
+        let intersection = polygon_boolean(&outer, &inner, BooleanOp::Intersection);
+        assert_eq!(intersection, vec![inner]);
+
+        let disjoint = polygon_boolean(&outer, &square(Vec2::splat(5.0), 1.0), BooleanOp::Union);
+        assert_eq!(disjoint.len(), 2);
+
+        // The corner of this square lies on the boundary, so it can't decide containment.
+        let cornered = square(Vec2::ZERO, 1.0);
+        let union = polygon_boolean(&outer, &cornered, BooleanOp::Union);
+        assert_eq!(union, vec![outer.clone()]);
+        let intersection = polygon_boolean(&outer, &cornered, BooleanOp::Intersection);
+        assert_eq!(intersection, vec![cornered]);
+    }
+}
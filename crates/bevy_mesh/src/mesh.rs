@@ -9,7 +9,7 @@ use super::{
     VertexFormatSize,
 };
 use alloc::collections::BTreeMap;
-use bevy_asset::{Asset, Handle, RenderAssetUsages};
+use bevy_asset::{Asset, AssetSize, Handle, RenderAssetUsages};
 use bevy_image::Image;
 use bevy_math::{primitives::Triangle3d, *};
 use bevy_reflect::Reflect;
@@ -545,6 +545,15 @@ impl Mesh {
                 VertexAttributeValues::Snorm8x4(vec) => *vec = duplicate(vec, indices),
                 VertexAttributeValues::Uint8x4(vec) => *vec = duplicate(vec, indices),
                 VertexAttributeValues::Unorm8x4(vec) => *vec = duplicate(vec, indices),
+                VertexAttributeValues::Custom { format, bytes } => {
+                    let size = format.get_size() as usize;
+                    let source: &[u8] = bytes;
+                    let duplicated = indices
+                        .flat_map(|i| &source[i * size..(i + 1) * size])
+                        .copied()
+                        .collect();
+                    *bytes = duplicated;
+                }
             }
         }
     }
@@ -844,6 +853,16 @@ impl Mesh {
                     (Snorm8x4(vec1), Snorm8x4(vec2)) => vec1.extend(vec2),
                     (Uint8x4(vec1), Uint8x4(vec2)) => vec1.extend(vec2),
                     (Unorm8x4(vec1), Unorm8x4(vec2)) => vec1.extend(vec2),
+                    (
+                        Custom {
+                            format: format1,
+                            bytes: bytes1,
+                        },
+                        Custom {
+                            format: format2,
+                            bytes: bytes2,
+                        },
+                    ) if *format1 == *format2 => bytes1.extend(bytes2),
                     _ => {
                         return Err(MergeMeshError {
                             self_attribute: *attribute,
@@ -1094,6 +1113,19 @@ impl Mesh {
         self.morph_target_names.as_deref()
     }
 
+    /// Gets the index of the morph target with the given name, if it exists.
+    ///
+    /// This is also the index of its weight in [`MorphWeights`] and [`MeshMorphWeights`].
+    ///
+    /// [`MorphWeights`]: crate::morph::MorphWeights
+    /// [`MeshMorphWeights`]: crate::morph::MeshMorphWeights
+    pub fn morph_target_index(&self, name: &str) -> Option<usize> {
+        self.morph_target_names
+            .as_ref()?
+            .iter()
+            .position(|target_name| target_name == name)
+    }
+
     /// Normalize joint weights so they sum to 1.
     pub fn normalize_joint_weights(&mut self) {
         if let Some(joints) = self.attribute_mut(Self::ATTRIBUTE_JOINT_WEIGHT) {
@@ -1218,6 +1250,12 @@ impl Mesh {
     }
 }
 
+impl AssetSize for Mesh {
+    fn asset_size(&self) -> usize {
+        self.get_vertex_buffer_size() + self.get_index_buffer_bytes().map_or(0, <[u8]>::len)
+    }
+}
+
 impl core::ops::Mul<Mesh> for Transform {
     type Output = Mesh;
 
@@ -1238,11 +1276,14 @@ pub struct MergeMeshError {
 mod tests {
     use super::Mesh;
     use crate::mesh::{Indices, MeshWindingInvertError, VertexAttributeValues};
-    use crate::PrimitiveTopology;
+    use crate::{
+        MeshVertexAttribute, MeshVertexBufferLayouts, PrimitiveTopology, VertexAttributeMismatch,
+    };
     use bevy_asset::RenderAssetUsages;
     use bevy_math::primitives::Triangle3d;
     use bevy_math::Vec3;
     use bevy_transform::components::Transform;
+    use wgpu_types::VertexFormat;
 
     #[test]
     #[should_panic]
@@ -1541,4 +1582,96 @@ mod tests {
             mesh.triangles().unwrap().collect::<Vec<Triangle3d>>()
         );
     }
+
+    #[test]
+    fn custom_vertex_format() {
+        const ATTRIBUTE_PACKED_UV: MeshVertexAttribute =
+            MeshVertexAttribute::new("Vertex_PackedUv", 988540917, VertexFormat::Float16x2);
+
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        )
+        .with_inserted_attribute(
+            ATTRIBUTE_PACKED_UV,
+            VertexAttributeValues::custom(VertexFormat::Float16x2, (0..12).collect::<Vec<u8>>()),
+        )
+        .with_inserted_indices(Indices::U16(vec![0, 2, 1]));
+
+        assert_eq!(mesh.count_vertices(), 3);
+        assert_eq!(mesh.get_vertex_size(), 12 + 4);
+
+        mesh.duplicate_vertices();
+        let Some(VertexAttributeValues::Custom { bytes, .. }) = mesh.attribute(ATTRIBUTE_PACKED_UV)
+        else {
+            panic!("Expected custom vertex attribute values");
+        };
+        assert_eq!(bytes, &[0, 1, 2, 3, 8, 9, 10, 11, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn vertex_layout_lists_mismatches() {
+        let mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0, 0.0, 0.0]])
+        .with_inserted_attribute(
+            MeshVertexAttribute::new("Vertex_Uv", 2, VertexFormat::Unorm16x2),
+            VertexAttributeValues::Unorm16x2(vec![[0, 0]]),
+        )
+        .with_inserted_attribute(
+            MeshVertexAttribute::new("Vertex_Color", 5, VertexFormat::Uint32),
+            VertexAttributeValues::Uint32(vec![0]),
+        );
+        let layout = mesh.get_mesh_vertex_buffer_layout(&mut MeshVertexBufferLayouts::default());
+
+        // Normalized integers are read as floats, so they can stand in for UVs.
+        assert!(layout
+            .0
+            .get_layout(&[
+                Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+                Mesh::ATTRIBUTE_UV_0.at_shader_location(1),
+            ])
+            .is_ok());
+
+        let error = layout
+            .0
+            .get_layout(&[
+                Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+                Mesh::ATTRIBUTE_NORMAL.at_shader_location(1),
+                Mesh::ATTRIBUTE_COLOR.at_shader_location(2),
+            ])
+            .unwrap_err();
+        assert_eq!(
+            error.mismatches,
+            vec![
+                VertexAttributeMismatch::Missing {
+                    id: Mesh::ATTRIBUTE_NORMAL.id,
+                    name: Mesh::ATTRIBUTE_NORMAL.name,
+                },
+                VertexAttributeMismatch::Format {
+                    id: Mesh::ATTRIBUTE_COLOR.id,
+                    name: Mesh::ATTRIBUTE_COLOR.name,
+                    expected: VertexFormat::Float32x4,
+                    found: VertexFormat::Uint32,
+                },
+            ]
+        );
+
+        // The format check can be skipped.
+        assert!(layout
+            .0
+            .get_layout(&[
+                Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+                Mesh::ATTRIBUTE_COLOR
+                    .at_shader_location(2)
+                    .with_any_format(),
+            ])
+            .is_ok());
+    }
 }
@@ -115,6 +115,8 @@ impl MorphTargetImage {
 #[reflect(Debug, Component, Default)]
 pub struct MorphWeights {
     weights: Vec<f32>,
+    /// The names of each morph target, in the order of the weights
+    target_names: Option<Vec<String>>,
     /// The first mesh primitive assigned to these weights
     first_mesh: Option<Handle<Mesh>>,
 }
@@ -129,6 +131,7 @@ impl MorphWeights {
         }
         Ok(MorphWeights {
             weights,
+            target_names: None,
             first_mesh,
         })
     }
@@ -143,6 +146,67 @@ impl MorphWeights {
     pub fn weights_mut(&mut self) -> &mut [f32] {
         &mut self.weights
     }
+
+    /// Sets the names of each morph target. These should be in the same order as the weights,
+    /// which is the order of [`Mesh::morph_target_names`].
+    pub fn set_target_names(&mut self, names: Vec<String>) {
+        self.target_names = Some(names);
+    }
+
+    /// Consumes these weights and returns them with morph target names.
+    ///
+    /// (Alternatively, you can use [`MorphWeights::set_target_names`] to mutate existing weights in-place)
+    #[must_use]
+    pub fn with_target_names(mut self, names: Vec<String>) -> Self {
+        self.set_target_names(names);
+        self
+    }
+
+    /// Gets a list of all morph target names, if they exist.
+    ///
+    /// Morph weights loaded from a glTF file have the names of their mesh's morph targets,
+    /// if the file includes them.
+    pub fn target_names(&self) -> Option<&[String]> {
+        self.target_names.as_deref()
+    }
+
+    /// Gets the index of the weight of the morph target with the given name.
+    ///
+    /// Returns `None` if there are no target names, or none of them match.
+    pub fn target_index(&self, name: &str) -> Option<usize> {
+        self.target_names
+            .as_ref()?
+            .iter()
+            .position(|target_name| target_name == name)
+            .filter(|&index| index < self.weights.len())
+    }
+
+    /// Gets the weight of the morph target with the given name.
+    pub fn get(&self, name: &str) -> Option<f32> {
+        self.target_index(name).map(|index| self.weights[index])
+    }
+
+    /// Gets a mutable reference to the weight of the morph target with the given name.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut f32> {
+        self.target_index(name)
+            .map(|index| &mut self.weights[index])
+    }
+
+    /// Sets the weight of the morph target with the given name.
+    ///
+    /// Looking targets up by name keeps working when the order of the morph targets
+    /// changes, for example when a model is re-exported from a modelling tool.
+    ///
+    /// Returns `false`, without changing any weights, if there is no morph target with that name.
+    pub fn set(&mut self, name: &str, weight: f32) -> bool {
+        match self.get_mut(name) {
+            Some(target_weight) => {
+                *target_weight = weight;
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 /// Control a specific [`Mesh`] instance's [morph targets]. These control the weights of
@@ -252,3 +316,28 @@ fn lowest_2d(min_includes: u32, max_edge: u32) -> Option<(Rect, u32)> {
         .filter_map(|(rect, diff)| (rect.1 <= max_edge).then_some((rect, diff)))
         .min_by_key(|(_, diff)| *diff)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_weight_by_name() {
+        let mut weights = MorphWeights::new(vec![0.0, 0.0], None)
+            .unwrap()
+            .with_target_names(vec!["frown".to_string(), "smile".to_string()]);
+
+        assert!(weights.set("smile", 0.7));
+        assert!(!weights.set("wink", 1.0));
+        assert_eq!(weights.weights(), &[0.0, 0.7]);
+        assert_eq!(weights.get("smile"), Some(0.7));
+        assert_eq!(weights.target_index("frown"), Some(0));
+    }
+
+    #[test]
+    fn set_weight_without_names() {
+        let mut weights = MorphWeights::new(vec![0.0], None).unwrap();
+        assert!(!weights.set("smile", 0.7));
+        assert_eq!(weights.weights(), &[0.0]);
+    }
+}
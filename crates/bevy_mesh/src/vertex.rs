@@ -31,8 +31,17 @@ impl MeshVertexAttribute {
         }
     }
 
+    /// Creates a [`VertexAttributeDescriptor`] that requests this attribute at
+    /// `shader_location`.
+    ///
+    /// The descriptor expects the mesh to provide the attribute in a format that is read by the
+    /// shader the same way as this attribute's [`format`](Self::format), and specialization fails
+    /// if it doesn't. For example, [`VertexFormat::Unorm16x2`] values can be used where
+    /// [`VertexFormat::Float32x2`] values are expected, as both are read as `vec2<f32>`.
+    ///
+    /// Use [`VertexAttributeDescriptor::with_any_format`] to skip this check.
     pub const fn at_shader_location(&self, shader_location: u32) -> VertexAttributeDescriptor {
-        VertexAttributeDescriptor::new(shader_location, self.id, self.name)
+        VertexAttributeDescriptor::new(shader_location, self.id, self.name).with_format(self.format)
     }
 }
 
@@ -74,30 +83,58 @@ impl MeshVertexBufferLayout {
         &self.layout
     }
 
+    /// Builds the [`VertexBufferLayout`] that binds the requested attributes to their shader
+    /// locations.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MissingVertexAttributeError`] listing every requested attribute that this
+    /// layout either doesn't contain or contains in a different [`VertexFormat`] than the one
+    /// the descriptor expects.
     pub fn get_layout(
         &self,
         attribute_descriptors: &[VertexAttributeDescriptor],
     ) -> Result<VertexBufferLayout, MissingVertexAttributeError> {
         let mut attributes = Vec::with_capacity(attribute_descriptors.len());
+        let mut mismatches = Vec::new();
         for attribute_descriptor in attribute_descriptors {
-            if let Some(index) = self
+            let Some(index) = self
                 .attribute_ids
                 .iter()
                 .position(|id| *id == attribute_descriptor.id)
-            {
-                let layout_attribute = &self.layout.attributes[index];
-                attributes.push(VertexAttribute {
-                    format: layout_attribute.format,
-                    offset: layout_attribute.offset,
-                    shader_location: attribute_descriptor.shader_location,
-                });
-            } else {
-                return Err(MissingVertexAttributeError {
+            else {
+                mismatches.push(VertexAttributeMismatch::Missing {
                     id: attribute_descriptor.id,
                     name: attribute_descriptor.name,
-                    pipeline_type: None,
                 });
+                continue;
+            };
+
+            let layout_attribute = &self.layout.attributes[index];
+            if let Some(expected) = attribute_descriptor.format {
+                if shader_input_type(expected) != shader_input_type(layout_attribute.format) {
+                    mismatches.push(VertexAttributeMismatch::Format {
+                        id: attribute_descriptor.id,
+                        name: attribute_descriptor.name,
+                        expected,
+                        found: layout_attribute.format,
+                    });
+                    continue;
+                }
             }
+
+            attributes.push(VertexAttribute {
+                format: layout_attribute.format,
+                offset: layout_attribute.offset,
+                shader_location: attribute_descriptor.shader_location,
+            });
+        }
+
+        if !mismatches.is_empty() {
+            return Err(MissingVertexAttributeError {
+                pipeline_type: None,
+                mismatches,
+            });
         }
 
         Ok(VertexBufferLayout {
@@ -109,17 +146,99 @@ impl MeshVertexBufferLayout {
 }
 
 #[derive(Error, Debug)]
-#[error("Mesh is missing requested attribute: {name} ({id:?}, pipeline type: {pipeline_type:?})")]
+#[error(
+    "Mesh doesn't provide the requested attributes (pipeline type: {pipeline_type:?}): {}",
+    format_mismatches(.mismatches)
+)]
 pub struct MissingVertexAttributeError {
     pub pipeline_type: Option<&'static str>,
-    id: MeshVertexAttributeId,
-    name: &'static str,
+    /// Every requested attribute that the mesh doesn't provide as expected.
+    pub mismatches: Vec<VertexAttributeMismatch>,
+}
+
+/// A single requested attribute that a [`MeshVertexBufferLayout`] doesn't provide as expected.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VertexAttributeMismatch {
+    /// The mesh doesn't have the attribute at all.
+    #[error("{name} ({id:?}) is missing")]
+    Missing {
+        id: MeshVertexAttributeId,
+        name: &'static str,
+    },
+    /// The mesh has the attribute, but in a format that the shader reads differently.
+    #[error("{name} ({id:?}) has format {found:?}, which isn't compatible with {expected:?}")]
+    Format {
+        id: MeshVertexAttributeId,
+        name: &'static str,
+        expected: VertexFormat,
+        found: VertexFormat,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ShaderScalar {
+    F32,
+    F64,
+    I32,
+    U32,
+}
+
+/// Returns the scalar type and component count that a shader reads a vertex attribute of the
+/// given format as.
+fn shader_input_type(format: VertexFormat) -> (ShaderScalar, u32) {
+    match format {
+        VertexFormat::Uint8x2 | VertexFormat::Uint16x2 | VertexFormat::Uint32x2 => {
+            (ShaderScalar::U32, 2)
+        }
+        VertexFormat::Uint8x4 | VertexFormat::Uint16x4 | VertexFormat::Uint32x4 => {
+            (ShaderScalar::U32, 4)
+        }
+        VertexFormat::Uint32 => (ShaderScalar::U32, 1),
+        VertexFormat::Uint32x3 => (ShaderScalar::U32, 3),
+        VertexFormat::Sint8x2 | VertexFormat::Sint16x2 | VertexFormat::Sint32x2 => {
+            (ShaderScalar::I32, 2)
+        }
+        VertexFormat::Sint8x4 | VertexFormat::Sint16x4 | VertexFormat::Sint32x4 => {
+            (ShaderScalar::I32, 4)
+        }
+        VertexFormat::Sint32 => (ShaderScalar::I32, 1),
+        VertexFormat::Sint32x3 => (ShaderScalar::I32, 3),
+        VertexFormat::Unorm8x2
+        | VertexFormat::Snorm8x2
+        | VertexFormat::Unorm16x2
+        | VertexFormat::Snorm16x2
+        | VertexFormat::Float16x2
+        | VertexFormat::Float32x2 => (ShaderScalar::F32, 2),
+        VertexFormat::Unorm8x4
+        | VertexFormat::Snorm8x4
+        | VertexFormat::Unorm16x4
+        | VertexFormat::Snorm16x4
+        | VertexFormat::Float16x4
+        | VertexFormat::Float32x4
+        | VertexFormat::Unorm10_10_10_2 => (ShaderScalar::F32, 4),
+        VertexFormat::Float32 => (ShaderScalar::F32, 1),
+        VertexFormat::Float32x3 => (ShaderScalar::F32, 3),
+        VertexFormat::Float64 => (ShaderScalar::F64, 1),
+        VertexFormat::Float64x2 => (ShaderScalar::F64, 2),
+        VertexFormat::Float64x3 => (ShaderScalar::F64, 3),
+        VertexFormat::Float64x4 => (ShaderScalar::F64, 4),
+    }
+}
+
+fn format_mismatches(mismatches: &[VertexAttributeMismatch]) -> String {
+    mismatches
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 pub struct VertexAttributeDescriptor {
     pub shader_location: u32,
     pub id: MeshVertexAttributeId,
     name: &'static str,
+    /// The format the pipeline expects the attribute in, if any.
+    format: Option<VertexFormat>,
 }
 
 impl VertexAttributeDescriptor {
@@ -128,8 +247,27 @@ impl VertexAttributeDescriptor {
             shader_location,
             id,
             name,
+            format: None,
         }
     }
+
+    /// Requires the mesh to provide the attribute in a format compatible with the given
+    /// [`VertexFormat`], meaning one that the shader reads as the same type.
+    ///
+    /// Without this, any format is accepted and passed through to the pipeline as-is.
+    pub const fn with_format(mut self, format: VertexFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Accepts the attribute in any format, passing it through to the pipeline as-is.
+    ///
+    /// This undoes [`with_format`](Self::with_format), for shaders that read an attribute
+    /// differently than its [`MeshVertexAttribute::format`] suggests.
+    pub const fn with_any_format(mut self) -> Self {
+        self.format = None;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -177,7 +315,6 @@ impl VertexFormatSize for VertexFormat {
             VertexFormat::Uint8x4 | VertexFormat::Unorm8x4 => size_of::<u8>() * 4,
             VertexFormat::Sint8x2 | VertexFormat::Snorm8x2 => size_of::<i8>() * 2,
             VertexFormat::Sint8x4 | VertexFormat::Snorm8x4 => size_of::<i8>() * 4,
-            VertexFormat::Unorm10_10_10_2 => 10 + 10 + 10 + 2,
             VertexFormat::Uint16x2 | VertexFormat::Unorm16x2 => size_of::<u16>() * 2,
             VertexFormat::Uint16x4 | VertexFormat::Unorm16x4 => size_of::<u16>() * 4,
             VertexFormat::Sint16x2 | VertexFormat::Snorm16x2 => size_of::<i16>() * 2,
@@ -190,7 +327,7 @@ impl VertexFormatSize for VertexFormat {
             VertexFormat::Float32x2 => size_of::<f32>() * 2,
             VertexFormat::Float32x3 => size_of::<f32>() * 3,
             VertexFormat::Float32x4 => size_of::<f32>() * 4,
-            VertexFormat::Uint32 => size_of::<u32>(),
+            VertexFormat::Uint32 | VertexFormat::Unorm10_10_10_2 => size_of::<u32>(),
             VertexFormat::Uint32x2 => size_of::<u32>() * 2,
             VertexFormat::Uint32x3 => size_of::<u32>() * 3,
             VertexFormat::Uint32x4 => size_of::<u32>() * 4,
@@ -242,6 +379,15 @@ pub enum VertexAttributeValues {
     Snorm8x4(Vec<[i8; 4]>),
     Uint8x4(Vec<[u8; 4]>),
     Unorm8x4(Vec<[u8; 4]>),
+    /// Tightly packed values in any [`VertexFormat`], including the ones that have no dedicated
+    /// variant, such as [`VertexFormat::Float16x2`] or [`VertexFormat::Unorm10_10_10_2`].
+    ///
+    /// The bytes are uploaded to the GPU as-is. Create these with
+    /// [`VertexAttributeValues::custom`].
+    Custom {
+        format: VertexFormat,
+        bytes: Vec<u8>,
+    },
 }
 
 impl VertexAttributeValues {
@@ -281,9 +427,28 @@ impl VertexAttributeValues {
             VertexAttributeValues::Snorm8x4(values) => values.len(),
             VertexAttributeValues::Uint8x4(values) => values.len(),
             VertexAttributeValues::Unorm8x4(values) => values.len(),
+            VertexAttributeValues::Custom { format, bytes } => {
+                bytes.len() / format.get_size() as usize
+            }
         }
     }
 
+    /// Creates [`VertexAttributeValues::Custom`] values from tightly packed `bytes` in the given
+    /// `format`.
+    ///
+    /// # Panics
+    /// Panics if the length of `bytes` isn't a multiple of the size of `format`.
+    pub fn custom(format: VertexFormat, bytes: impl Into<Vec<u8>>) -> Self {
+        let bytes = bytes.into();
+        let size = format.get_size() as usize;
+        assert!(
+            bytes.len() % size == 0,
+            "Custom vertex attribute values of format {format:?} must be a multiple of {size} bytes long, but are {} bytes long",
+            bytes.len()
+        );
+        VertexAttributeValues::Custom { format, bytes }
+    }
+
     /// Returns `true` if there are no vertices in this [`VertexAttributeValues`].
     pub fn is_empty(&self) -> bool {
         self.len() == 0
@@ -334,6 +499,7 @@ impl VertexAttributeValues {
             VertexAttributeValues::Snorm8x4(values) => cast_slice(values),
             VertexAttributeValues::Uint8x4(values) => cast_slice(values),
             VertexAttributeValues::Unorm8x4(values) => cast_slice(values),
+            VertexAttributeValues::Custom { bytes, .. } => bytes,
         }
     }
 }
@@ -369,6 +535,7 @@ impl From<&VertexAttributeValues> for VertexFormat {
             VertexAttributeValues::Snorm8x4(_) => VertexFormat::Snorm8x4,
             VertexAttributeValues::Uint8x4(_) => VertexFormat::Uint8x4,
             VertexAttributeValues::Unorm8x4(_) => VertexFormat::Unorm8x4,
+            VertexAttributeValues::Custom { format, .. } => *format,
         }
     }
 }
@@ -23,10 +23,10 @@ use tracing::warn;
 use crate::{
     decal::{self, clustered::ClusteredDecal},
     prelude::EnvironmentMapLight,
-    ClusterConfig, ClusterFarZMode, Clusters, ExtractedPointLight, GlobalVisibleClusterableObjects,
-    LightProbe, PointLight, SpotLight, ViewClusterBindings, VisibleClusterableObjects,
-    VolumetricLight, CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT,
-    MAX_UNIFORM_BUFFER_CLUSTERABLE_OBJECTS,
+    ClusterConfig, ClusterDiagnostics, ClusterFarZMode, ClusterZSlicing, Clusters,
+    ExtractedPointLight, GlobalVisibleClusterableObjects, LightProbe, PointLight, SpotLight,
+    ViewClusterBindings, VisibleClusterableObjects, VolumetricLight,
+    CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT, MAX_UNIFORM_BUFFER_CLUSTERABLE_OBJECTS,
 };
 
 const NDC_MIN: Vec2 = Vec2::NEG_ONE;
@@ -351,7 +351,10 @@ pub(crate) fn assign_objects_to_clusters(
             }
         };
 
-        let mut requested_cluster_dimensions = config.dimensions_for_screen_size(screen_size);
+        let configured_cluster_dimensions = config.dimensions_for_screen_size(screen_size);
+        let mut requested_cluster_dimensions = configured_cluster_dimensions;
+        let z_slicing = config.z_slicing();
+        let max_lights_per_cluster = config.max_lights_per_cluster().unwrap_or(u32::MAX);
 
         let world_from_view = camera_transform.compute_matrix();
         let view_from_world_scale = camera_transform.compute_transform().scale.recip();
@@ -397,6 +400,7 @@ pub(crate) fn assign_objects_to_clusters(
             far_z,
             requested_cluster_dimensions.z as f32,
             is_orthographic,
+            z_slicing,
         );
 
         if config.dynamic_resizing() {
@@ -476,6 +480,7 @@ pub(crate) fn assign_objects_to_clusters(
         clusters.update(screen_size, requested_cluster_dimensions);
         clusters.near = first_slice_depth;
         clusters.far = far_z;
+        clusters.z_slicing = z_slicing;
 
         // NOTE: Maximum 4096 clusters due to uniform buffer size constraints
         debug_assert!(
@@ -549,12 +554,20 @@ pub(crate) fn assign_objects_to_clusters(
 
         let z_slices = clusters.dimensions.z;
         for z in 0..=z_slices {
-            let view_z = z_slice_to_view_z(first_slice_depth, far_z, z_slices, z, is_orthographic);
+            let view_z = z_slice_to_view_z(
+                first_slice_depth,
+                far_z,
+                z_slices,
+                z,
+                is_orthographic,
+                z_slicing,
+            );
             let normal = -Vec3::Z;
             let d = view_z * normal.z;
             z_planes.push(HalfSpace::new(normal.extend(d)));
         }
 
+        let mut dropped_lights = 0;
         let mut update_from_object_intersections = |visible_clusterable_objects: &mut Vec<
             Entity,
         >| {
@@ -755,6 +768,7 @@ pub(crate) fn assign_objects_to_clusters(
                                                 screen_size.as_vec2(),
                                                 view_from_clip,
                                                 is_orthographic,
+                                                z_slicing,
                                                 clusters.dimensions,
                                                 UVec3::new(x, y, z),
                                             );
@@ -787,12 +801,14 @@ pub(crate) fn assign_objects_to_clusters(
 
                                     if !angle_cull && !front_cull && !back_cull {
                                         // this cluster is affected by the spot light
-                                        clusters.clusterable_objects[cluster_index]
-                                            .entities
-                                            .push(clusterable_object.entity);
-                                        clusters.clusterable_objects[cluster_index]
-                                            .counts
-                                            .spot_lights += 1;
+                                        let cluster =
+                                            &mut clusters.clusterable_objects[cluster_index];
+                                        if cluster.counts.lights() < max_lights_per_cluster {
+                                            cluster.entities.push(clusterable_object.entity);
+                                            cluster.counts.spot_lights += 1;
+                                        } else {
+                                            dropped_lights += 1;
+                                        }
                                     }
                                     cluster_index += clusters.dimensions.z as usize;
                                 }
@@ -801,12 +817,13 @@ pub(crate) fn assign_objects_to_clusters(
                             ClusterableObjectType::PointLight { .. } => {
                                 for _ in min_x..=max_x {
                                     // all clusters within range are affected by point lights
-                                    clusters.clusterable_objects[cluster_index]
-                                        .entities
-                                        .push(clusterable_object.entity);
-                                    clusters.clusterable_objects[cluster_index]
-                                        .counts
-                                        .point_lights += 1;
+                                    let cluster = &mut clusters.clusterable_objects[cluster_index];
+                                    if cluster.counts.lights() < max_lights_per_cluster {
+                                        cluster.entities.push(clusterable_object.entity);
+                                        cluster.counts.point_lights += 1;
+                                    } else {
+                                        dropped_lights += 1;
+                                    }
                                     cluster_index += clusters.dimensions.z as usize;
                                 }
                             }
@@ -879,6 +896,23 @@ pub(crate) fn assign_objects_to_clusters(
                     ..Default::default()
                 });
         }
+
+        clusters.diagnostics = ClusterDiagnostics {
+            requested_dimensions: configured_cluster_dimensions,
+            resized: requested_cluster_dimensions != configured_cluster_dimensions,
+            total_indices: clusters
+                .clusterable_objects
+                .iter()
+                .map(|cluster| cluster.entities.len())
+                .sum(),
+            max_lights_in_cluster: clusters
+                .clusterable_objects
+                .iter()
+                .map(|cluster| cluster.counts.lights())
+                .max()
+                .unwrap_or(0),
+            dropped_lights,
+        };
     }
 }
 
@@ -889,9 +923,11 @@ fn compute_aabb_for_cluster(
     screen_size: Vec2,
     view_from_clip: Mat4,
     is_orthographic: bool,
+    z_slicing: ClusterZSlicing,
     cluster_dimensions: UVec3,
     ijk: UVec3,
 ) -> Aabb {
+    let z_slice = ijk.z;
     let ijk = ijk.as_vec3();
 
     // Calculate the minimum and maximum points in screen space
@@ -920,22 +956,25 @@ fn compute_aabb_for_cluster(
         let p_min = screen_to_view(screen_size, view_from_clip, p_min, 1.0);
         let p_max = screen_to_view(screen_size, view_from_clip, p_max, 1.0);
 
-        let z_far_over_z_near = -z_far / -z_near;
-        let cluster_near = if ijk.z == 0.0 {
-            0.0
-        } else {
-            -z_near
-                * ops::powf(
-                    z_far_over_z_near,
-                    (ijk.z - 1.0) / (cluster_dimensions.z - 1) as f32,
-                )
-        };
-        // NOTE: This could be simplified to:
-        // cluster_far = cluster_near * z_far_over_z_near;
+        let cluster_near = z_slice_to_view_z(
+            z_near,
+            z_far,
+            cluster_dimensions.z,
+            z_slice,
+            false,
+            z_slicing,
+        );
         let cluster_far = if cluster_dimensions.z == 1 {
             -z_far
         } else {
-            -z_near * ops::powf(z_far_over_z_near, ijk.z / (cluster_dimensions.z - 1) as f32)
+            z_slice_to_view_z(
+                z_near,
+                z_far,
+                cluster_dimensions.z,
+                z_slice + 1,
+                false,
+                z_slicing,
+            )
         };
 
         // Calculate the four intersection points of the min and max points with the cluster near and far planes
@@ -952,12 +991,13 @@ fn compute_aabb_for_cluster(
 }
 
 // NOTE: Keep in sync as the inverse of view_z_to_z_slice above
-fn z_slice_to_view_z(
+pub(super) fn z_slice_to_view_z(
     near: f32,
     far: f32,
     z_slices: u32,
     z_slice: u32,
     is_orthographic: bool,
+    z_slicing: ClusterZSlicing,
 ) -> f32 {
     if is_orthographic {
         return -near - (far - near) * z_slice as f32 / z_slices as f32;
@@ -966,6 +1006,8 @@ fn z_slice_to_view_z(
     // Perspective
     if z_slice == 0 {
         0.0
+    } else if z_slicing == ClusterZSlicing::Linear {
+        -near - (far - near) * (z_slice - 1) as f32 / (z_slices - 1).max(1) as f32
     } else {
         -near * ops::powf(far / near, (z_slice - 1) as f32 / (z_slices - 1) as f32)
     }
@@ -1095,7 +1137,7 @@ fn line_intersection_to_z_plane(origin: Vec3, p: Vec3, z: f32) -> Vec3 {
 }
 
 // NOTE: Keep in sync with bevy_pbr/src/render/pbr.wgsl
-fn view_z_to_z_slice(
+pub(super) fn view_z_to_z_slice(
     cluster_factors: Vec2,
     z_slices: u32,
     view_z: f32,
@@ -1104,6 +1146,14 @@ fn view_z_to_z_slice(
     let z_slice = if is_orthographic {
         // NOTE: view_z is correct in the orthographic case
         ((view_z - cluster_factors.x) * cluster_factors.y).floor() as u32
+    } else if cluster_factors.x < 0.0 {
+        // Linear depth slicing, with the first slice ending at the near plane
+        let depth = -view_z - cluster_factors.y;
+        if depth < 0.0 {
+            0
+        } else {
+            (depth * -cluster_factors.x) as u32 + 1
+        }
     } else {
         // NOTE: had to use -view_z to make it positive else log(negative) is nan
         (ops::ln(-view_z) * cluster_factors.x - cluster_factors.y + 1.0) as u32
@@ -12,7 +12,7 @@ use bevy_ecs::{
     system::{Commands, Query, Res},
     world::{FromWorld, World},
 };
-use bevy_math::{uvec4, AspectRatio, UVec2, UVec3, UVec4, Vec3Swizzles as _, Vec4};
+use bevy_math::{uvec4, AspectRatio, UVec2, UVec3, UVec4, Vec2, Vec3Swizzles as _, Vec4};
 use bevy_platform_support::collections::HashSet;
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
@@ -37,7 +37,7 @@ mod test;
 
 // NOTE: this must be kept in sync with the same constants in
 // `mesh_view_types.wgsl`.
-pub const MAX_UNIFORM_BUFFER_CLUSTERABLE_OBJECTS: usize = 204;
+pub const MAX_UNIFORM_BUFFER_CLUSTERABLE_OBJECTS: usize = 170;
 // Make sure that the clusterable object buffer doesn't overflow the maximum
 // size of a UBO on WebGL 2.
 const _: () =
@@ -76,6 +76,21 @@ pub enum ClusterFarZMode {
     Constant(f32),
 }
 
+/// Configure how the depth slices after the first one are distributed for clustered forward
+/// rendering with perspective projections. Orthographic projections always use linear slicing.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum ClusterZSlicing {
+    /// Depth slices grow exponentially with distance, so that clusters stay roughly cubic.
+    /// Suitable for most views, where lights are spread out over a large depth range.
+    #[default]
+    Exponential,
+    /// Depth slices all have the same depth. Suitable for views where the lights are within a
+    /// short depth range far from the camera, such as top-down views and minimaps, which would
+    /// otherwise pack most of them into a few large slices.
+    Linear,
+}
+
 /// Configure the depth-slicing strategy for clustered forward rendering
 #[derive(Debug, Copy, Clone, Reflect)]
 #[reflect(Default)]
@@ -84,6 +99,8 @@ pub struct ClusterZConfig {
     pub first_slice_depth: f32,
     /// Strategy for how to evaluate the far `Z` plane of the furthest depth slice
     pub far_z_mode: ClusterFarZMode,
+    /// Strategy for how to distribute the depth slices between the first and the furthest one
+    pub slicing: ClusterZSlicing,
 }
 
 /// Configuration of the clustering strategy for clustered forward rendering
@@ -102,6 +119,10 @@ pub enum ClusterConfig {
         /// Specify if clusters should automatically resize in `X/Y` if there is a risk of exceeding
         /// the available cluster-object index limit
         dynamic_resizing: bool,
+        /// The maximum number of point and spot lights affecting a single cluster, or `None` for
+        /// no limit. Lights past the limit are dropped from the cluster, and counted in
+        /// [`ClusterDiagnostics::dropped_lights`].
+        max_lights_per_cluster: Option<u32>,
     },
     /// Fixed number of `Z` slices, `X` and `Y` calculated to give square clusters
     /// with at most total clusters. For top-down games where lights will generally always be within a
@@ -115,6 +136,10 @@ pub enum ClusterConfig {
         /// Specify if clusters should automatically resize in `X/Y` if there is a risk of exceeding
         /// the available clusterable object index limit
         dynamic_resizing: bool,
+        /// The maximum number of point and spot lights affecting a single cluster, or `None` for
+        /// no limit. Lights past the limit are dropped from the cluster, and counted in
+        /// [`ClusterDiagnostics::dropped_lights`].
+        max_lights_per_cluster: Option<u32>,
     },
 }
 
@@ -128,7 +153,30 @@ pub struct Clusters {
     /// and explicitly-configured to avoid having unnecessarily many slices close to the camera.
     pub(crate) near: f32,
     pub(crate) far: f32,
+    pub(crate) z_slicing: ClusterZSlicing,
     pub(crate) clusterable_objects: Vec<VisibleClusterableObjects>,
+    pub(crate) diagnostics: ClusterDiagnostics,
+}
+
+/// Feedback about the last assignment of clusterable objects to the [`Clusters`] of a view, for
+/// tuning its [`ClusterConfig`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ClusterDiagnostics {
+    /// The number of clusters in `X` / `Y` / `Z` requested by the [`ClusterConfig`], before
+    /// dynamic resizing.
+    pub requested_dimensions: UVec3,
+    /// Whether the clusters were resized in `X/Y` to fit the clusterable object index limit.
+    pub resized: bool,
+    /// The total number of clusterable object indices in all clusters.
+    ///
+    /// On platforms without storage buffers, such as WebGL 2, indices past
+    /// [`ViewClusterBindings::MAX_INDICES`] are dropped.
+    pub total_indices: usize,
+    /// The largest number of point and spot lights affecting a single cluster.
+    pub max_lights_in_cluster: u32,
+    /// The number of times a light was dropped from a cluster because the cluster reached the
+    /// maximum number of lights of the [`ClusterConfig`].
+    pub dropped_lights: u32,
 }
 
 #[derive(Clone, Component, Debug, Default)]
@@ -158,11 +206,19 @@ pub struct GpuClusterableObject {
     pub(crate) flags: u32,
     pub(crate) shadow_depth_bias: f32,
     pub(crate) shadow_normal_bias: f32,
+    // For spot lights: the tangent of the outer angle
     pub(crate) spot_light_tan_angle: f32,
     pub(crate) soft_shadow_size: f32,
     pub(crate) shadow_map_near_z: f32,
-    pub(crate) pad_a: f32,
-    pub(crate) pad_b: f32,
+    pub(crate) contact_shadow_length: f32,
+    pub(crate) contact_shadow_thickness: f32,
+    // For point lights: the cube of the point light shadow map texture that holds the shadow map
+    pub(crate) shadow_atlas_layer: u32,
+    // For point lights: the fraction of each cube face that the shadow map occupies
+    pub(crate) shadow_atlas_scale: f32,
+    // For point lights: the center of the shadow map's square on each cube face, in face
+    // coordinates from -1 to 1
+    pub(crate) shadow_atlas_center: Vec2,
 }
 
 pub enum GpuClusterableObjects {
@@ -188,6 +244,7 @@ pub struct ExtractedClusterConfig {
     pub(crate) far: f32,
     /// Number of clusters in `X` / `Y` / `Z` in the view frustum
     pub(crate) dimensions: UVec3,
+    pub(crate) z_slicing: ClusterZSlicing,
 }
 
 /// Stores the number of each type of clusterable object in a single cluster.
@@ -208,6 +265,13 @@ struct ClusterableObjectCounts {
     decals: u32,
 }
 
+impl ClusterableObjectCounts {
+    /// The number of point and spot lights in the cluster.
+    fn lights(&self) -> u32 {
+        self.point_lights + self.spot_lights
+    }
+}
+
 enum ExtractedClusterableObjectElement {
     ClusterHeader(ClusterableObjectCounts),
     ClusterableObjectEntity(Entity),
@@ -263,6 +327,7 @@ impl Default for ClusterZConfig {
         Self {
             first_slice_depth: 5.0,
             far_z_mode: ClusterFarZMode::MaxClusterableObjectRange,
+            slicing: ClusterZSlicing::Exponential,
         }
     }
 }
@@ -276,6 +341,7 @@ impl Default for ClusterConfig {
             z_slices: 24,
             z_config: ClusterZConfig::default(),
             dynamic_resizing: true,
+            max_lights_per_cluster: None,
         }
     }
 }
@@ -349,9 +415,44 @@ impl ClusterConfig {
             } => *dynamic_resizing,
         }
     }
+
+    fn z_slicing(&self) -> ClusterZSlicing {
+        match self {
+            ClusterConfig::None | ClusterConfig::Single => ClusterZSlicing::Exponential,
+            ClusterConfig::XYZ { z_config, .. } | ClusterConfig::FixedZ { z_config, .. } => {
+                z_config.slicing
+            }
+        }
+    }
+
+    fn max_lights_per_cluster(&self) -> Option<u32> {
+        match self {
+            ClusterConfig::None | ClusterConfig::Single => None,
+            ClusterConfig::XYZ {
+                max_lights_per_cluster,
+                ..
+            }
+            | ClusterConfig::FixedZ {
+                max_lights_per_cluster,
+                ..
+            } => *max_lights_per_cluster,
+        }
+    }
 }
 
 impl Clusters {
+    /// Returns the number of clusters in `X` / `Y` / `Z` in the view frustum.
+    #[inline]
+    pub fn dimensions(&self) -> UVec3 {
+        self.dimensions
+    }
+
+    /// Returns feedback about the last assignment of clusterable objects to the clusters.
+    #[inline]
+    pub fn diagnostics(&self) -> &ClusterDiagnostics {
+        &self.diagnostics
+    }
+
     fn update(&mut self, screen_size: UVec2, requested_dimensions: UVec3) {
         debug_assert!(
             requested_dimensions.x > 0 && requested_dimensions.y > 0 && requested_dimensions.z > 0
@@ -377,6 +478,7 @@ impl Clusters {
         self.near = 0.0;
         self.far = 0.0;
         self.clusterable_objects.clear();
+        self.diagnostics = ClusterDiagnostics::default();
     }
 }
 
@@ -559,6 +661,7 @@ pub fn extract_clusters(
                 near: clusters.near,
                 far: clusters.far,
                 dimensions: clusters.dimensions,
+                z_slicing: clusters.z_slicing,
             },
         ));
     }
@@ -809,8 +912,8 @@ impl ViewClusterBuffers {
 // platform: typically, on WebGL 2.
 //
 // NOTE: With uniform buffer max binding size as 16384 bytes
-// that means we can fit 204 clusterable objects in one uniform
-// buffer, which means the count can be at most 204 so it
+// that means we can fit 170 clusterable objects in one uniform
+// buffer, which means the count can be at most 170 so it
 // needs 9 bits.
 // The array of indices can also use u8 and that means the
 // offset in to the array of indices needs to be able to address
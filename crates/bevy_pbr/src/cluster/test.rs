@@ -1,6 +1,7 @@
 use bevy_math::UVec2;
 
-use crate::{ClusterConfig, Clusters};
+use super::assign::{view_z_to_z_slice, z_slice_to_view_z};
+use crate::{calculate_cluster_factors, ClusterConfig, ClusterZSlicing, Clusters};
 
 fn test_cluster_tiling(config: ClusterConfig, screen_size: UVec2) -> Clusters {
     let dims = config.dimensions_for_screen_size(screen_size);
@@ -52,3 +53,30 @@ fn test_default_cluster_setup_small_x() {
         }
     }
 }
+
+#[test]
+// check the depth slices map back to themselves for both perspective slicing strategies
+fn test_z_slicing_round_trip() {
+    let (near, far, z_slices) = (5.0, 1000.0, 24);
+    for z_slicing in [ClusterZSlicing::Exponential, ClusterZSlicing::Linear] {
+        let cluster_factors =
+            calculate_cluster_factors(near, far, z_slices as f32, false, z_slicing);
+        for z_slice in 0..z_slices {
+            let slice_near = z_slice_to_view_z(near, far, z_slices, z_slice, false, z_slicing);
+            let slice_far = z_slice_to_view_z(near, far, z_slices, z_slice + 1, false, z_slicing);
+            let view_z = (slice_near + slice_far) * 0.5;
+            assert_eq!(
+                view_z_to_z_slice(cluster_factors, z_slices, view_z, false),
+                z_slice
+            );
+        }
+    }
+
+    // linear slices after the first all have the same depth
+    let linear = ClusterZSlicing::Linear;
+    let slice_depth = |z_slice| {
+        z_slice_to_view_z(near, far, z_slices, z_slice, false, linear)
+            - z_slice_to_view_z(near, far, z_slices, z_slice + 1, false, linear)
+    };
+    assert!((slice_depth(1) - slice_depth(z_slices - 1)).abs() < 1e-3);
+}
@@ -21,6 +21,10 @@
     #import bevy_pbr::pbr_prepass_functions::calculate_motion_vector
 #endif
 
+#ifdef ENTITY_ID_PREPASS
+    #import bevy_pbr::mesh_bindings::mesh
+#endif
+
 // Creates the deferred gbuffer from a PbrInput.
 fn deferred_gbuffer_from_pbr_input(in: PbrInput) -> vec4<u32> {
     // Only monochrome occlusion supported. May not be worth including at all.
@@ -146,6 +150,10 @@ fn deferred_output(in: VertexOutput, pbr_input: PbrInput) -> FragmentOutput {
 #else
     out.motion_vector = calculate_motion_vector(in.world_position, in.previous_world_position);
 #endif
+#endif
+    // entity ID if required
+#ifdef ENTITY_ID_PREPASS
+    out.entity_id = mesh[in.instance_index].main_entity;
 #endif
 
     return out;
@@ -301,6 +301,7 @@ impl Plugin for PbrPlugin {
             .register_type::<CascadeShadowConfig>()
             .register_type::<Cascades>()
             .register_type::<CascadesVisibleEntities>()
+            .register_type::<ContactShadows>()
             .register_type::<VisibleMeshEntities>()
             .register_type::<ClusterConfig>()
             .register_type::<CubemapVisibleEntities>()
@@ -310,12 +311,16 @@ impl Plugin for PbrPlugin {
             .register_type::<NotShadowReceiver>()
             .register_type::<PointLight>()
             .register_type::<PointLightShadowMap>()
+            .register_type::<PointLightShadowBudget>()
             .register_type::<SpotLight>()
+            .register_type::<ShadowBias>()
             .register_type::<ShadowFilteringMethod>()
+            .register_type::<ShadowMapPriority>()
             .init_resource::<AmbientLight>()
             .init_resource::<GlobalVisibleClusterableObjects>()
             .init_resource::<DirectionalLightShadowMap>()
             .init_resource::<PointLightShadowMap>()
+            .init_resource::<PointLightShadowBudget>()
             .register_type::<DefaultOpaqueRendererMethod>()
             .init_resource::<DefaultOpaqueRendererMethod>()
             .add_plugins((
@@ -103,6 +103,58 @@ impl Default for PointLightShadowMap {
     }
 }
 
+/// Controls how the [`PointLightShadowMap`] resolution is distributed between shadow-casting
+/// [`PointLight`]s.
+///
+/// Each frame, every shadow-casting point light is given a shadow map face resolution between
+/// [`Self::min_face_size`] and [`PointLightShadowMap::size`], in proportion to how much of the
+/// screen its range covers and its [`ShadowMapPriority`]. The resolutions are chosen so that the
+/// total number of texels rendered across all faces of all point light shadow maps stays within
+/// [`Self::texel_budget`], which makes dozens of shadow-casting point lights affordable.
+///
+/// Face resolutions are always powers of two, and are never smaller than
+/// [`Self::min_face_size`], so the budget may be exceeded when there are very many lights.
+///
+/// Lights that were given less than the full resolution share the cubes of the point light shadow
+/// map texture, so that e.g. four lights at half resolution only take up a single cube. Lights
+/// that don't fit into the cube limit of the device don't cast shadows.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct PointLightShadowBudget {
+    /// The smallest shadow map face resolution that a shadow-casting point light can be given.
+    ///
+    /// This is rounded up to a power of two, and to at least 1/256th of
+    /// [`PointLightShadowMap::size`].
+    pub min_face_size: usize,
+    /// The number of shadow map texels to distribute between all shadow-casting point lights.
+    pub texel_budget: usize,
+}
+
+impl Default for PointLightShadowBudget {
+    fn default() -> Self {
+        Self {
+            min_face_size: 128,
+            // Enough for eight point lights at the default `PointLightShadowMap` size.
+            texel_budget: 8 * 6 * 1024 * 1024,
+        }
+    }
+}
+
+/// Add this component to a [`PointLight`] to change how much of the [`PointLightShadowBudget`] it
+/// receives relative to other point lights.
+///
+/// The budget is distributed in proportion to each light's screen coverage multiplied by its
+/// priority. Lights without this component have a priority of `1.0`.
+#[derive(Component, Clone, Copy, Debug, Reflect, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct ShadowMapPriority(pub f32);
+
+impl Default for ShadowMapPriority {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 /// A convenient alias for `Or<(With<PointLight>, With<SpotLight>,
 /// With<DirectionalLight>)>`, for use with [`bevy_render::view::VisibleEntities`].
 pub type WithLight = Or<(With<PointLight>, With<SpotLight>, With<DirectionalLight>)>;
@@ -466,6 +518,63 @@ pub struct NotShadowReceiver;
 #[reflect(Component, Default, Debug)]
 pub struct TransmittedShadowReceiver;
 
+/// Add this component to a [`Mesh3d`] to scale the shadow biases of every light when sampling
+/// shadows on this mesh.
+///
+/// The shadow biases on lights are global, so a value that removes shadow acne on small, detailed
+/// meshes can cause "peter-panning" on large ones, and vice versa. This component lets individual
+/// meshes tune the biases of all lights without changing the lights themselves.
+///
+/// The scales are quantized to steps of `1/16` in the range `[0.0, 3.9375]`.
+#[derive(Debug, Component, Reflect, Clone, Copy, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct ShadowBias {
+    /// Multiplier for the light's `shadow_depth_bias` when sampling shadows on this mesh.
+    pub depth_bias_scale: f32,
+    /// Multiplier for the light's `shadow_normal_bias` when sampling shadows on this mesh.
+    pub normal_bias_scale: f32,
+}
+
+impl Default for ShadowBias {
+    fn default() -> Self {
+        Self {
+            depth_bias_scale: 1.0,
+            normal_bias_scale: 1.0,
+        }
+    }
+}
+
+/// Add this component to a [`PointLight`], [`SpotLight`] or [`DirectionalLight`] to enable
+/// screen-space contact shadows for it.
+///
+/// Contact shadows are computed by marching a short ray from each fragment towards the light
+/// through the depth prepass. They fill in the fine shadows that shadow maps miss because of their
+/// limited resolution and bias, such as where an object touches the ground.
+///
+/// Contact shadows require a [`DepthPrepass`](bevy_core_pipeline::prepass::DepthPrepass) on the
+/// camera. Without one, this component has no effect.
+#[derive(Debug, Component, Reflect, Clone, Copy, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct ContactShadows {
+    /// The length of the ray marched towards the light, in world units.
+    pub length: f32,
+    /// How thick on-screen geometry is assumed to be, in world units.
+    ///
+    /// Geometry that is further than this distance in front of the ray doesn't occlude it. Larger
+    /// values make contact shadows more pronounced, but cause thin objects to cast shadows that
+    /// are too long.
+    pub thickness: f32,
+}
+
+impl Default for ContactShadows {
+    fn default() -> Self {
+        Self {
+            length: 0.3,
+            thickness: 0.05,
+        }
+    }
+}
+
 /// Add this component to a [`Camera3d`](bevy_core_pipeline::core_3d::Camera3d)
 /// to control how to anti-alias shadow edges.
 ///
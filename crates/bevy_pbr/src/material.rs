@@ -32,7 +32,7 @@ use bevy_reflect::std_traits::ReflectDefault;
 use bevy_reflect::Reflect;
 use bevy_render::{
     batching::gpu_preprocessing::GpuPreprocessingSupport,
-    camera::TemporalJitter,
+    camera::{ExtractedClipPlanes, TemporalJitter},
     extract_resource::ExtractResource,
     mesh::{self, Mesh3d, MeshVertexBufferLayoutRef, RenderMesh},
     render_asset::{PrepareAssetError, RenderAsset, RenderAssetPlugin, RenderAssets},
@@ -708,6 +708,7 @@ pub fn queue_material_meshes<M: Material>(
             Has<RenderViewLightProbes<IrradianceVolume>>,
         ),
         Has<OrderIndependentTransparencySettings>,
+        Has<ExtractedClipPlanes>,
     )>,
 ) where
     M::Data: PartialEq + Eq + Hash + Clone,
@@ -727,6 +728,7 @@ pub fn queue_material_meshes<M: Material>(
         distance_fog,
         (has_environment_maps, has_irradiance_volumes),
         has_oit,
+        has_clip_planes,
     ) in &views
     {
         let (
@@ -819,6 +821,9 @@ pub fn queue_material_meshes<M: Material>(
         if distance_fog {
             view_key |= MeshPipelineKey::DISTANCE_FOG;
         }
+        if has_clip_planes {
+            view_key |= MeshPipelineKey::CLIP_PLANES;
+        }
         if let Some(camera_3d) = camera_3d {
             view_key |= screen_space_specular_transmission_pipeline_key(
                 camera_3d.screen_space_specular_transmission_quality,
@@ -869,6 +874,13 @@ pub fn queue_material_meshes<M: Material>(
                 mesh_key |= MeshPipelineKey::VISIBILITY_RANGE_DITHER;
             }
 
+            if mesh_instance
+                .flags
+                .contains(RenderMeshInstanceFlags::HAS_CLIP_PLANE)
+            {
+                mesh_key |= MeshPipelineKey::CLIP_PLANES;
+            }
+
             if motion_vector_prepass {
                 // If the previous frame have skins or morph targets, note that.
                 if mesh_instance
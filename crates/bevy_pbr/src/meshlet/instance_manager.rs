@@ -110,6 +110,7 @@ impl InstanceManager {
             world_from_local: (&transform).into(),
             previous_world_from_local: (&previous_transform).into(),
             flags: flags.bits(),
+            clip_plane: Vec4::W,
         };
 
         let mesh_material = mesh_material_ids.mesh_material(instance);
@@ -125,6 +126,7 @@ impl InstanceManager {
             None,
             None,
             None,
+            instance,
         );
 
         // Append instance data
@@ -1,5 +1,6 @@
 use bevy_asset::Asset;
 use bevy_color::{Alpha, ColorToComponents};
+use bevy_image::SamplerAsset;
 use bevy_math::{Affine2, Affine3, Mat2, Mat3, Vec2, Vec3, Vec4};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
@@ -62,10 +63,16 @@ pub struct StandardMaterial {
     ///
     /// [`base_color`]: StandardMaterial::base_color
     #[texture(1)]
-    #[sampler(2)]
+    #[sampler(2, sampler_asset = "base_color_sampler")]
     #[dependency]
     pub base_color_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::base_color_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[dependency]
+    pub base_color_sampler: Option<Handle<SamplerAsset>>,
+
     // Use a color for user friendliness even though we technically don't use the alpha channel
     // Might be used in the future for exposure correction in HDR
     /// Color the material "emits" to the camera.
@@ -113,10 +120,16 @@ pub struct StandardMaterial {
     ///
     /// [`emissive`]: StandardMaterial::emissive
     #[texture(3)]
-    #[sampler(4)]
+    #[sampler(4, sampler_asset = "emissive_sampler")]
     #[dependency]
     pub emissive_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::emissive_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[dependency]
+    pub emissive_sampler: Option<Handle<SamplerAsset>>,
+
     /// Linear perceptual roughness, clamped to `[0.089, 1.0]` in the shader.
     ///
     /// Defaults to `0.5`.
@@ -166,10 +179,16 @@ pub struct StandardMaterial {
     /// [`metallic`]: StandardMaterial::metallic
     /// [`perceptual_roughness`]: StandardMaterial::perceptual_roughness
     #[texture(5)]
-    #[sampler(6)]
+    #[sampler(6, sampler_asset = "metallic_roughness_sampler")]
     #[dependency]
     pub metallic_roughness_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::metallic_roughness_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[dependency]
+    pub metallic_roughness_sampler: Option<Handle<SamplerAsset>>,
+
     /// Specular intensity for non-metals on a linear scale of `[0.0, 1.0]`.
     ///
     /// Use the value as a way to control the intensity of the
@@ -230,10 +249,19 @@ pub struct StandardMaterial {
     /// **Important:** The [`StandardMaterial::diffuse_transmission`] property must be set to a value higher than 0.0,
     /// or this texture won't have any effect.
     #[cfg_attr(feature = "pbr_transmission_textures", texture(19))]
-    #[cfg_attr(feature = "pbr_transmission_textures", sampler(20))]
+    #[cfg_attr(
+        feature = "pbr_transmission_textures",
+        sampler(20, sampler_asset = "diffuse_transmission_sampler")
+    )]
     #[cfg(feature = "pbr_transmission_textures")]
     pub diffuse_transmission_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::diffuse_transmission_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_transmission_textures")]
+    pub diffuse_transmission_sampler: Option<Handle<SamplerAsset>>,
+
     /// The amount of light transmitted _specularly_ through the material (i.e. via refraction).
     ///
     /// - When set to `0.0` (the default) no light is transmitted.
@@ -271,10 +299,19 @@ pub struct StandardMaterial {
     /// **Important:** The [`StandardMaterial::specular_transmission`] property must be set to a value higher than 0.0,
     /// or this texture won't have any effect.
     #[cfg_attr(feature = "pbr_transmission_textures", texture(15))]
-    #[cfg_attr(feature = "pbr_transmission_textures", sampler(16))]
+    #[cfg_attr(
+        feature = "pbr_transmission_textures",
+        sampler(16, sampler_asset = "specular_transmission_sampler")
+    )]
     #[cfg(feature = "pbr_transmission_textures")]
     pub specular_transmission_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::specular_transmission_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_transmission_textures")]
+    pub specular_transmission_sampler: Option<Handle<SamplerAsset>>,
+
     /// Thickness of the volume beneath the material surface.
     ///
     /// When set to `0.0` (the default) the material appears as an infinitely-thin film,
@@ -300,10 +337,19 @@ pub struct StandardMaterial {
     /// **Important:** The [`StandardMaterial::thickness`] property must be set to a value higher than 0.0,
     /// or this texture won't have any effect.
     #[cfg_attr(feature = "pbr_transmission_textures", texture(17))]
-    #[cfg_attr(feature = "pbr_transmission_textures", sampler(18))]
+    #[cfg_attr(
+        feature = "pbr_transmission_textures",
+        sampler(18, sampler_asset = "thickness_sampler")
+    )]
     #[cfg(feature = "pbr_transmission_textures")]
     pub thickness_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::thickness_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_transmission_textures")]
+    pub thickness_sampler: Option<Handle<SamplerAsset>>,
+
     /// The [index of refraction](https://en.wikipedia.org/wiki/Refractive_index) of the material.
     ///
     /// Defaults to 1.5.
@@ -386,10 +432,16 @@ pub struct StandardMaterial {
     /// [`Mesh::generate_tangents`]: bevy_render::mesh::Mesh::generate_tangents
     /// [`Mesh::with_generated_tangents`]: bevy_render::mesh::Mesh::with_generated_tangents
     #[texture(9)]
-    #[sampler(10)]
+    #[sampler(10, sampler_asset = "normal_map_sampler")]
     #[dependency]
     pub normal_map_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::normal_map_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[dependency]
+    pub normal_map_sampler: Option<Handle<SamplerAsset>>,
+
     /// Normal map textures authored for DirectX have their y-component flipped. Set this to flip
     /// it to right-handed conventions.
     pub flip_normal_map_y: bool,
@@ -410,10 +462,16 @@ pub struct StandardMaterial {
     /// The material will be less lit in places where this texture is dark.
     /// This is similar to ambient occlusion, but built into the model.
     #[texture(7)]
-    #[sampler(8)]
+    #[sampler(8, sampler_asset = "occlusion_sampler")]
     #[dependency]
     pub occlusion_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::occlusion_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[dependency]
+    pub occlusion_sampler: Option<Handle<SamplerAsset>>,
+
     /// The UV channel to use for the [`StandardMaterial::specular_texture`].
     ///
     /// Defaults to [`UvChannel::Uv0`].
@@ -437,10 +495,16 @@ pub struct StandardMaterial {
     /// may be desirable to pack the values together and supply the same
     /// texture to both fields.
     #[texture(27)]
-    #[sampler(28)]
+    #[sampler(28, sampler_asset = "specular_sampler")]
     #[cfg(feature = "pbr_specular_textures")]
     pub specular_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::specular_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_specular_textures")]
+    pub specular_sampler: Option<Handle<SamplerAsset>>,
+
     /// The UV channel to use for the
     /// [`StandardMaterial::specular_tint_texture`].
     ///
@@ -459,9 +523,15 @@ pub struct StandardMaterial {
     /// the deferred renderer.
     #[cfg(feature = "pbr_specular_textures")]
     #[texture(29)]
-    #[sampler(30)]
+    #[sampler(30, sampler_asset = "specular_tint_sampler")]
     pub specular_tint_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::specular_tint_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_specular_textures")]
+    pub specular_tint_sampler: Option<Handle<SamplerAsset>>,
+
     /// An extra thin translucent layer on top of the main PBR layer. This is
     /// typically used for painted surfaces.
     ///
@@ -483,10 +553,19 @@ pub struct StandardMaterial {
     ///
     /// As this is a non-color map, it must not be loaded as sRGB.
     #[cfg_attr(feature = "pbr_multi_layer_material_textures", texture(21))]
-    #[cfg_attr(feature = "pbr_multi_layer_material_textures", sampler(22))]
+    #[cfg_attr(
+        feature = "pbr_multi_layer_material_textures",
+        sampler(22, sampler_asset = "clearcoat_sampler")
+    )]
     #[cfg(feature = "pbr_multi_layer_material_textures")]
     pub clearcoat_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::clearcoat_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_multi_layer_material_textures")]
+    pub clearcoat_sampler: Option<Handle<SamplerAsset>>,
+
     /// The roughness of the clearcoat material. This is specified in exactly
     /// the same way as the [`StandardMaterial::perceptual_roughness`].
     ///
@@ -508,10 +587,19 @@ pub struct StandardMaterial {
     ///
     /// As this is a non-color map, it must not be loaded as sRGB.
     #[cfg_attr(feature = "pbr_multi_layer_material_textures", texture(23))]
-    #[cfg_attr(feature = "pbr_multi_layer_material_textures", sampler(24))]
+    #[cfg_attr(
+        feature = "pbr_multi_layer_material_textures",
+        sampler(24, sampler_asset = "clearcoat_roughness_sampler")
+    )]
     #[cfg(feature = "pbr_multi_layer_material_textures")]
     pub clearcoat_roughness_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::clearcoat_roughness_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_multi_layer_material_textures")]
+    pub clearcoat_roughness_sampler: Option<Handle<SamplerAsset>>,
+
     /// The UV channel to use for the [`StandardMaterial::clearcoat_normal_texture`].
     ///
     /// Defaults to [`UvChannel::Uv0`].
@@ -530,10 +618,19 @@ pub struct StandardMaterial {
     ///
     /// As this is a non-color map, it must not be loaded as sRGB.
     #[cfg_attr(feature = "pbr_multi_layer_material_textures", texture(25))]
-    #[cfg_attr(feature = "pbr_multi_layer_material_textures", sampler(26))]
+    #[cfg_attr(
+        feature = "pbr_multi_layer_material_textures",
+        sampler(26, sampler_asset = "clearcoat_normal_sampler")
+    )]
     #[cfg(feature = "pbr_multi_layer_material_textures")]
     pub clearcoat_normal_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::clearcoat_normal_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_multi_layer_material_textures")]
+    pub clearcoat_normal_sampler: Option<Handle<SamplerAsset>>,
+
     /// Increases the roughness along a specific direction, so that the specular
     /// highlight will be stretched instead of being a circular lobe.
     ///
@@ -600,10 +697,19 @@ pub struct StandardMaterial {
     /// [`KHR_materials_anisotropy` specification]:
     /// https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_anisotropy/README.md
     #[cfg_attr(feature = "pbr_anisotropy_texture", texture(13))]
-    #[cfg_attr(feature = "pbr_anisotropy_texture", sampler(14))]
+    #[cfg_attr(
+        feature = "pbr_anisotropy_texture",
+        sampler(14, sampler_asset = "anisotropy_sampler")
+    )]
     #[cfg(feature = "pbr_anisotropy_texture")]
     pub anisotropy_texture: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::anisotropy_texture`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[cfg(feature = "pbr_anisotropy_texture")]
+    pub anisotropy_sampler: Option<Handle<SamplerAsset>>,
+
     /// Support two-sided lighting by automatically flipping the normals for "back" faces
     /// within the PBR lighting shader.
     ///
@@ -704,10 +810,16 @@ pub struct StandardMaterial {
     /// [`parallax_mapping_method`]: StandardMaterial::parallax_mapping_method
     /// [`max_parallax_layer_count`]: StandardMaterial::max_parallax_layer_count
     #[texture(11)]
-    #[sampler(12)]
+    #[sampler(12, sampler_asset = "depth_map_sampler")]
     #[dependency]
     pub depth_map: Option<Handle<Image>>,
 
+    /// Overrides the sampler of the [`StandardMaterial::depth_map`].
+    ///
+    /// Defaults to [`None`], which uses the sampler of the image itself.
+    #[dependency]
+    pub depth_map_sampler: Option<Handle<SamplerAsset>>,
+
     /// How deep the offset introduced by the depth map should be.
     ///
     /// Default is `0.1`, anything over that value may look distorted.
@@ -826,16 +938,19 @@ impl Default for StandardMaterial {
             base_color: Color::WHITE,
             base_color_channel: UvChannel::Uv0,
             base_color_texture: None,
+            base_color_sampler: None,
             emissive: LinearRgba::BLACK,
             emissive_exposure_weight: 0.0,
             emissive_channel: UvChannel::Uv0,
             emissive_texture: None,
+            emissive_sampler: None,
             // Matches Blender's default roughness.
             perceptual_roughness: 0.5,
             // Metallic should generally be set to 0.0 or 1.0.
             metallic: 0.0,
             metallic_roughness_channel: UvChannel::Uv0,
             metallic_roughness_texture: None,
+            metallic_roughness_sampler: None,
             // Minimum real-world reflectance is 2%, most materials between 2-5%
             // Expressed in a linear scale and equivalent to 4% reflectance see
             // <https://google.github.io/filament/Material%20Properties.pdf>
@@ -845,32 +960,44 @@ impl Default for StandardMaterial {
             diffuse_transmission_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_transmission_textures")]
             diffuse_transmission_texture: None,
+            #[cfg(feature = "pbr_transmission_textures")]
+            diffuse_transmission_sampler: None,
             specular_transmission: 0.0,
             #[cfg(feature = "pbr_transmission_textures")]
             specular_transmission_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_transmission_textures")]
             specular_transmission_texture: None,
+            #[cfg(feature = "pbr_transmission_textures")]
+            specular_transmission_sampler: None,
             thickness: 0.0,
             #[cfg(feature = "pbr_transmission_textures")]
             thickness_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_transmission_textures")]
             thickness_texture: None,
+            #[cfg(feature = "pbr_transmission_textures")]
+            thickness_sampler: None,
             ior: 1.5,
             attenuation_color: Color::WHITE,
             attenuation_distance: f32::INFINITY,
             occlusion_channel: UvChannel::Uv0,
             occlusion_texture: None,
+            occlusion_sampler: None,
             normal_map_channel: UvChannel::Uv0,
             normal_map_texture: None,
+            normal_map_sampler: None,
             #[cfg(feature = "pbr_specular_textures")]
             specular_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_specular_textures")]
             specular_texture: None,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_sampler: None,
             specular_tint: Color::WHITE,
             #[cfg(feature = "pbr_specular_textures")]
             specular_tint_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_specular_textures")]
             specular_tint_texture: None,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_tint_sampler: None,
             clearcoat: 0.0,
             clearcoat_perceptual_roughness: 0.5,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
@@ -878,19 +1005,27 @@ impl Default for StandardMaterial {
             #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_texture: None,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_sampler: None,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_roughness_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_roughness_texture: None,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_roughness_sampler: None,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_normal_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_multi_layer_material_textures")]
             clearcoat_normal_texture: None,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_normal_sampler: None,
             anisotropy_strength: 0.0,
             anisotropy_rotation: 0.0,
             #[cfg(feature = "pbr_anisotropy_texture")]
             anisotropy_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_anisotropy_texture")]
             anisotropy_texture: None,
+            #[cfg(feature = "pbr_anisotropy_texture")]
+            anisotropy_sampler: None,
             flip_normal_map_y: false,
             double_sided: false,
             cull_mode: Some(Face::Back),
@@ -899,6 +1034,7 @@ impl Default for StandardMaterial {
             alpha_mode: AlphaMode::Opaque,
             depth_bias: 0.0,
             depth_map: None,
+            depth_map_sampler: None,
             parallax_depth_scale: 0.1,
             max_parallax_layer_count: 16.0,
             lightmap_exposure: 1.0,
@@ -35,6 +35,7 @@ use bevy_ecs::{
 };
 use bevy_math::{Affine3A, Vec4};
 use bevy_render::{
+    camera::ExtractedClipPlanes,
     globals::{GlobalsBuffer, GlobalsUniform},
     prelude::{Camera, Mesh},
     render_asset::RenderAssets,
@@ -416,6 +417,10 @@ where
             shader_defs.push("MAY_DISCARD".into());
         }
 
+        if key.mesh_key.contains(MeshPipelineKey::CLIP_PLANES) {
+            shader_defs.push("CLIP_PLANES".into());
+        }
+
         let blend_key = key
             .mesh_key
             .intersection(MeshPipelineKey::BLEND_RESERVED_BITS);
@@ -491,6 +496,10 @@ where
             shader_defs.push("DEFERRED_PREPASS".into());
         }
 
+        if key.mesh_key.contains(MeshPipelineKey::ENTITY_ID_PREPASS) {
+            shader_defs.push("ENTITY_ID_PREPASS".into());
+        }
+
         if key.mesh_key.contains(MeshPipelineKey::LIGHTMAPPED) {
             shader_defs.push("LIGHTMAP".into());
         }
@@ -540,7 +549,8 @@ where
         if key.mesh_key.intersects(
             MeshPipelineKey::NORMAL_PREPASS
                 | MeshPipelineKey::MOTION_VECTOR_PREPASS
-                | MeshPipelineKey::DEFERRED_PREPASS,
+                | MeshPipelineKey::DEFERRED_PREPASS
+                | MeshPipelineKey::ENTITY_ID_PREPASS,
         ) {
             shader_defs.push("PREPASS_FRAGMENT".into());
         }
@@ -558,12 +568,14 @@ where
 
         let vertex_buffer_layout = layout.0.get_layout(&vertex_attributes)?;
 
-        // Setup prepass fragment targets - normals in slot 0 (or None if not needed), motion vectors in slot 1
+        // Setup prepass fragment targets - normals in slot 0 (or None if not needed), motion vectors in slot 1,
+        // the deferred gbuffer in slots 2 and 3, entity IDs in slot 4
         let mut targets = prepass_target_descriptors(
             key.mesh_key.contains(MeshPipelineKey::NORMAL_PREPASS),
             key.mesh_key
                 .contains(MeshPipelineKey::MOTION_VECTOR_PREPASS),
             key.mesh_key.contains(MeshPipelineKey::DEFERRED_PREPASS),
+            key.mesh_key.contains(MeshPipelineKey::ENTITY_ID_PREPASS),
         );
 
         if targets.iter().all(Option::is_none) {
@@ -574,9 +586,11 @@ where
 
         // The fragment shader is only used when the normal prepass or motion vectors prepass
         // is enabled, the material uses alpha cutoff values and doesn't rely on the standard
-        // prepass shader, or we are emulating unclipped depth in the fragment shader.
+        // prepass shader, we are emulating unclipped depth in the fragment shader, or the view
+        // or the mesh has clip planes to discard fragments against.
         let fragment_required = !targets.is_empty()
             || emulate_unclipped_depth
+            || key.mesh_key.contains(MeshPipelineKey::CLIP_PLANES)
             || (key.mesh_key.contains(MeshPipelineKey::MAY_DISCARD)
                 && self.prepass_material_fragment_shader.is_some());
 
@@ -811,6 +825,8 @@ pub fn queue_prepass_material_meshes<M: Material>(
         Option<&NormalPrepass>,
         Option<&MotionVectorPrepass>,
         Option<&DeferredPrepass>,
+        Has<ExtractedClipPlanes>,
+        Has<EntityIdPrepass>,
     )>,
 ) where
     M::Data: PartialEq + Eq + Hash + Clone,
@@ -839,6 +855,8 @@ pub fn queue_prepass_material_meshes<M: Material>(
         normal_prepass,
         motion_vector_prepass,
         deferred_prepass,
+        has_clip_planes,
+        entity_id_prepass,
     ) in &views
     {
         let (
@@ -872,6 +890,12 @@ pub fn queue_prepass_material_meshes<M: Material>(
         if motion_vector_prepass.is_some() {
             view_key |= MeshPipelineKey::MOTION_VECTOR_PREPASS;
         }
+        if has_clip_planes {
+            view_key |= MeshPipelineKey::CLIP_PLANES;
+        }
+        if entity_id_prepass {
+            view_key |= MeshPipelineKey::ENTITY_ID_PREPASS;
+        }
 
         for (render_entity, visible_entity) in visible_entities.iter::<Mesh3d>() {
             let Some(material_asset_id) = render_material_instances.get(visible_entity) else {
@@ -941,6 +965,13 @@ pub fn queue_prepass_material_meshes<M: Material>(
                 mesh_key |= MeshPipelineKey::VISIBILITY_RANGE_DITHER;
             }
 
+            if mesh_instance
+                .flags
+                .contains(RenderMeshInstanceFlags::HAS_CLIP_PLANE)
+            {
+                mesh_key |= MeshPipelineKey::CLIP_PLANES;
+            }
+
             // If the previous frame has skins or morph targets, note that.
             if motion_vector_prepass.is_some() {
                 if mesh_instance
@@ -6,7 +6,7 @@
     skinning,
     morph,
     mesh_view_bindings::view,
-    view_transformations::position_world_to_clip,
+    view_transformations::{is_clipped_by_view_planes, position_world_to_clip},
 }
 
 #ifdef DEFERRED_PREPASS
@@ -179,6 +179,13 @@ fn vertex(vertex_no_morph: Vertex) -> VertexOutput {
 #ifdef PREPASS_FRAGMENT
 @fragment
 fn fragment(in: VertexOutput) -> FragmentOutput {
+#ifdef CLIP_PLANES
+    if is_clipped_by_view_planes(in.world_position.xyz)
+        || mesh_functions::is_clipped_by_mesh_plane(in.instance_index, in.world_position.xyz) {
+        discard;
+    }
+#endif // CLIP_PLANES
+
     var out: FragmentOutput;
 
 #ifdef NORMAL_PREPASS
@@ -212,6 +219,20 @@ fn fragment(in: VertexOutput) -> FragmentOutput {
     out.deferred_lighting_pass_id = 1u;
 #endif
 
+#ifdef ENTITY_ID_PREPASS
+    out.entity_id = mesh[in.instance_index].main_entity;
+#endif
+
     return out;
 }
+#else // PREPASS_FRAGMENT
+#ifdef CLIP_PLANES
+@fragment
+fn fragment(in: VertexOutput) {
+    if is_clipped_by_view_planes(in.world_position.xyz)
+        || mesh_functions::is_clipped_by_mesh_plane(in.instance_index, in.world_position.xyz) {
+        discard;
+    }
+}
+#endif // CLIP_PLANES
 #endif // PREPASS_FRAGMENT
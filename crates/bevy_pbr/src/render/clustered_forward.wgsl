@@ -39,6 +39,12 @@ fn view_z_to_z_slice(view_z: f32, is_orthographic: bool) -> u32 {
     if is_orthographic {
         // NOTE: view_z is correct in the orthographic case
         z_slice = u32(floor((view_z - bindings::lights.cluster_factors.z) * bindings::lights.cluster_factors.w));
+    } else if bindings::lights.cluster_factors.z < 0.0 {
+        // Linear depth slicing, with the first slice ending at the near plane
+        let depth = -view_z - bindings::lights.cluster_factors.w;
+        if depth >= 0.0 {
+            z_slice = u32(depth * -bindings::lights.cluster_factors.z) + 1u;
+        }
     } else {
         // NOTE: had to use -view_z to make it positive else log(negative) is nan
         z_slice = u32(log(-view_z) * bindings::lights.cluster_factors.z - bindings::lights.cluster_factors.w + 1.0);
@@ -0,0 +1,313 @@
+//! Statistics about the instances culled by GPU frustum and occlusion culling.
+//!
+//! See [`GpuCullingStatisticsPlugin`] for more info.
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+use bevy_ecs::{
+    component::Component,
+    entity::{hash_map::EntityHashMap, Entity},
+    query::With,
+    reflect::ReflectComponent,
+    resource::Resource,
+    schedule::IntoSystemConfigs as _,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_platform_support::{collections::HashMap, time::Instant};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    camera::Camera,
+    render_resource::{Buffer, BufferDescriptor, BufferUsages, CommandEncoderDescriptor, MapMode},
+    renderer::{render_system, RenderDevice, RenderQueue},
+    view::{ExtractedView, RetainedViewEntity},
+    Render, RenderApp, RenderSet,
+};
+use tracing::warn;
+
+use super::{GpuCullingStatisticsBuffers, GpuCullingStatisticsCounters};
+
+/// Enables collecting statistics about how many mesh instances the GPU culled
+/// in each view.
+///
+/// This is useful to verify that GPU frustum culling and
+/// [`OcclusionCulling`](bevy_render::experimental::occlusion_culling::OcclusionCulling)
+/// actually remove instances from your scene. The statistics are read back
+/// from the GPU every frame and are therefore a frame or two behind.
+///
+/// For each view, the statistics are:
+///  * stored in the [`GpuCullingStatistics`] component of the camera entity,
+///    if the view belongs to a camera,
+///  * and recorded in the [`DiagnosticsStore`] under the paths returned by
+///    [`GpuCullingStatisticsPlugin::diagnostic_path`], if the
+///    [`DiagnosticsStore`] is present.
+///
+/// Only views which are culled on the GPU report statistics; views that use
+/// CPU culling, such as those with
+/// [`NoIndirectDrawing`](bevy_render::view::NoIndirectDrawing), don't.
+/// Collecting the statistics adds a few atomic operations per instance to the
+/// mesh preprocessing shader, so this plugin shouldn't be left enabled in
+/// release builds.
+#[derive(Default)]
+pub struct GpuCullingStatisticsPlugin;
+
+impl GpuCullingStatisticsPlugin {
+    /// The names of the diagnostics recorded for each view.
+    pub const FIELDS: [&'static str; 4] =
+        ["submitted", "frustum_culled", "occlusion_culled", "visible"];
+
+    /// Returns the path of the diagnostic that records the given field of the
+    /// statistics of a view.
+    ///
+    /// `field` is one of [`GpuCullingStatisticsPlugin::FIELDS`]. Views that
+    /// belong to cameras are named after the camera entity; other views, such
+    /// as shadow cascades, also include the auxiliary entity and subview index.
+    pub fn diagnostic_path(view: &RetainedViewEntity, field: &str) -> DiagnosticPath {
+        let view_name =
+            if view.auxiliary_entity.id() == Entity::PLACEHOLDER && view.subview_index == 0 {
+                format!("{}", view.main_entity.id())
+            } else {
+                format!(
+                    "{}-{}-{}",
+                    view.main_entity.id(),
+                    view.auxiliary_entity.id(),
+                    view.subview_index
+                )
+            };
+        DiagnosticPath::from_components(["render", "gpu_culling", &view_name, field])
+    }
+}
+
+impl Plugin for GpuCullingStatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        let mutex = GpuCullingStatisticsMutex::default();
+        app.register_type::<GpuCullingStatistics>()
+            .insert_resource(mutex.clone())
+            .add_systems(PreUpdate, sync_gpu_culling_statistics);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .insert_resource(mutex)
+                .init_resource::<GpuCullingStatisticsReadback>()
+                .add_systems(
+                    Render,
+                    readback_gpu_culling_statistics
+                        .after(render_system)
+                        .in_set(RenderSet::Render),
+                );
+        }
+    }
+}
+
+/// The number of mesh instances that were culled on the GPU in a view during a
+/// recent frame.
+///
+/// This component is kept up to date on cameras by the
+/// [`GpuCullingStatisticsPlugin`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct GpuCullingStatistics {
+    /// The number of instances that entered GPU culling.
+    pub submitted: u32,
+    /// The number of instances that were culled because they were outside the
+    /// view frustum.
+    pub frustum_culled: u32,
+    /// The number of instances that were culled because they were occluded.
+    ///
+    /// This is always zero unless the view has
+    /// [`OcclusionCulling`](bevy_render::experimental::occlusion_culling::OcclusionCulling).
+    pub occlusion_culled: u32,
+}
+
+impl GpuCullingStatistics {
+    /// Returns the number of instances that survived culling and were drawn.
+    pub fn visible(&self) -> u32 {
+        self.submitted
+            .saturating_sub(self.frustum_culled)
+            .saturating_sub(self.occlusion_culled)
+    }
+}
+
+impl From<GpuCullingStatisticsCounters> for GpuCullingStatistics {
+    fn from(counters: GpuCullingStatisticsCounters) -> Self {
+        Self {
+            submitted: counters.submitted,
+            frustum_culled: counters.frustum_culled,
+            occlusion_culled: counters.occlusion_culled,
+        }
+    }
+}
+
+/// Stores the statistics read back from the GPU until they can be synced with
+/// the main app.
+///
+/// This mutex is locked in `PreUpdate`, during [`sync_gpu_culling_statistics`],
+/// and whenever the statistics of a view have been downloaded from the GPU.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct GpuCullingStatisticsMutex(
+    pub(crate) Arc<Mutex<HashMap<RetainedViewEntity, GpuCullingStatistics>>>,
+);
+
+/// Render world resource that marks culling statistics as enabled and holds
+/// the buffers used to read them back from the GPU.
+#[derive(Resource, Default)]
+pub struct GpuCullingStatisticsReadback {
+    views: EntityHashMap<ViewReadbackBuffer>,
+}
+
+/// A CPU-mappable buffer that the statistics of a single view are copied into.
+struct ViewReadbackBuffer {
+    buffer: Buffer,
+    /// Whether the buffer is currently being mapped, in which case it can't be
+    /// copied into.
+    mapping: Arc<AtomicBool>,
+}
+
+/// Copies the culling statistics of each view into CPU-visible buffers once
+/// the frame has been submitted, and maps them.
+fn readback_gpu_culling_statistics(
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    gpu_culling_statistics_buffers: Option<Res<GpuCullingStatisticsBuffers>>,
+    mut readback: ResMut<GpuCullingStatisticsReadback>,
+    views: Query<&ExtractedView>,
+    mutex: Res<GpuCullingStatisticsMutex>,
+) {
+    let Some(gpu_culling_statistics_buffers) = gpu_culling_statistics_buffers else {
+        return;
+    };
+
+    readback
+        .views
+        .retain(|view, _| gpu_culling_statistics_buffers.views.contains_key(view));
+
+    let mut command_encoder = render_device.create_command_encoder(&CommandEncoderDescriptor {
+        label: Some("GPU culling statistics readback"),
+    });
+    let mut mapped = vec![];
+
+    for (view_entity, counters_buffer) in &gpu_culling_statistics_buffers.views {
+        let Ok(view) = views.get(*view_entity) else {
+            continue;
+        };
+
+        let view_readback =
+            readback
+                .views
+                .entry(*view_entity)
+                .or_insert_with(|| ViewReadbackBuffer {
+                    buffer: render_device.create_buffer(&BufferDescriptor {
+                        label: Some("GPU culling statistics readback buffer"),
+                        size: counters_buffer.size(),
+                        usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+                        mapped_at_creation: false,
+                    }),
+                    mapping: Arc::default(),
+                });
+
+        // Skip this frame if last frame's statistics haven't arrived yet.
+        if view_readback.mapping.swap(true, Ordering::AcqRel) {
+            continue;
+        }
+
+        command_encoder.copy_buffer_to_buffer(
+            counters_buffer,
+            0,
+            &view_readback.buffer,
+            0,
+            counters_buffer.size(),
+        );
+        mapped.push((
+            view.retained_view_entity,
+            view_readback.buffer.clone(),
+            view_readback.mapping.clone(),
+        ));
+    }
+
+    if mapped.is_empty() {
+        return;
+    }
+
+    render_queue.submit([command_encoder.finish()]);
+
+    for (retained_view_entity, buffer, mapping) in mapped {
+        let slice = buffer.slice(..);
+        let buffer = buffer.clone();
+        let mutex = mutex.clone();
+        slice.map_async(MapMode::Read, move |result| match result {
+            Ok(()) => {
+                let counters = bytemuck::pod_read_unaligned::<GpuCullingStatisticsCounters>(
+                    &buffer.slice(..).get_mapped_range(),
+                );
+                buffer.unmap();
+                mapping.store(false, Ordering::Release);
+
+                if let Ok(mut statistics) = mutex.0.lock() {
+                    statistics.insert(retained_view_entity, counters.into());
+                }
+            }
+            Err(err) => {
+                warn!("Failed to map GPU culling statistics buffer: {err}");
+                mapping.store(false, Ordering::Release);
+            }
+        });
+    }
+}
+
+/// Copies the culling statistics read back from the GPU onto cameras and into
+/// the [`DiagnosticsStore`].
+pub fn sync_gpu_culling_statistics(
+    mut commands: Commands,
+    mutex: Res<GpuCullingStatisticsMutex>,
+    mut store: Option<ResMut<DiagnosticsStore>>,
+    cameras: Query<(), With<Camera>>,
+) {
+    let Some(statistics) = mutex
+        .0
+        .lock()
+        .ok()
+        .map(|mut statistics| core::mem::take(&mut *statistics))
+    else {
+        return;
+    };
+
+    let time = Instant::now();
+
+    for (view, view_statistics) in statistics {
+        let main_entity = view.main_entity.id();
+        if view.auxiliary_entity.id() == Entity::PLACEHOLDER
+            && view.subview_index == 0
+            && cameras.contains(main_entity)
+        {
+            commands.entity(main_entity).insert(view_statistics);
+        }
+
+        let Some(store) = store.as_deref_mut() else {
+            continue;
+        };
+
+        let values = [
+            view_statistics.submitted,
+            view_statistics.frustum_culled,
+            view_statistics.occlusion_culled,
+            view_statistics.visible(),
+        ];
+        for (field, value) in GpuCullingStatisticsPlugin::FIELDS.into_iter().zip(values) {
+            let path = GpuCullingStatisticsPlugin::diagnostic_path(&view, field);
+            if store.get(&path).is_none() {
+                store.add(Diagnostic::new(path.clone()));
+            }
+
+            store
+                .get_mut(&path)
+                .unwrap()
+                .add_measurement(DiagnosticMeasurement {
+                    time,
+                    value: value as f64,
+                });
+        }
+    }
+}
@@ -21,7 +21,7 @@ use bevy_core_pipeline::{
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     component::Component,
-    entity::Entity,
+    entity::{hash_map::EntityHashMap, Entity},
     prelude::resource_exists,
     query::{Has, QueryState, With, Without},
     resource::Resource,
@@ -41,10 +41,10 @@ use bevy_render::{
     render_resource::{
         binding_types::{storage_buffer, storage_buffer_read_only, texture_2d, uniform_buffer},
         BindGroup, BindGroupEntries, BindGroupLayout, BindingResource, Buffer, BufferBinding,
-        BufferVec, CachedComputePipelineId, ComputePassDescriptor, ComputePipelineDescriptor,
-        DynamicBindGroupLayoutEntries, PipelineCache, PushConstantRange, RawBufferVec, Shader,
-        ShaderStages, ShaderType, SpecializedComputePipeline, SpecializedComputePipelines,
-        TextureSampleType, UninitBufferVec,
+        BufferDescriptor, BufferUsages, BufferVec, CachedComputePipelineId, ComputePassDescriptor,
+        ComputePipelineDescriptor, DynamicBindGroupLayoutEntries, PipelineCache, PushConstantRange,
+        RawBufferVec, Shader, ShaderStages, ShaderType, SpecializedComputePipeline,
+        SpecializedComputePipelines, TextureSampleType, UninitBufferVec,
     },
     renderer::{RenderContext, RenderDevice, RenderQueue},
     settings::WgpuFeatures,
@@ -53,6 +53,7 @@ use bevy_render::{
 };
 use bevy_utils::TypeIdMap;
 use bitflags::bitflags;
+use bytemuck::{Pod, Zeroable};
 use smallvec::{smallvec, SmallVec};
 use tracing::warn;
 
@@ -60,7 +61,7 @@ use crate::{
     graph::NodePbr, MeshCullingData, MeshCullingDataBuffer, MeshInputUniform, MeshUniform,
 };
 
-use super::ViewLightEntities;
+use super::{GpuCullingStatisticsReadback, ViewLightEntities};
 
 /// The handle to the `mesh_preprocess.wgsl` compute shader.
 pub const MESH_PREPROCESS_SHADER_HANDLE: Handle<Shader> =
@@ -297,6 +298,12 @@ bitflags! {
         ///
         /// This `#define`'s `EARLY_PHASE` in the shader.
         const EARLY_PHASE = 4;
+        /// Whether the shader should accumulate [`GpuCullingStatistics`].
+        ///
+        /// This `#define`'s `GPU_CULLING_STATISTICS` in the shader.
+        ///
+        /// [`GpuCullingStatistics`]: super::GpuCullingStatistics
+        const GPU_CULLING_STATISTICS = 8;
     }
 
     /// Specifies variants of the indirect parameter building shader.
@@ -405,6 +412,37 @@ pub struct BuildIndirectParametersBindGroups {
     build_non_indexed_indirect: Option<BindGroup>,
 }
 
+/// The counters that the mesh preprocessing shader accumulates for each view
+/// when [`PreprocessPipelineKey::GPU_CULLING_STATISTICS`] is set.
+///
+/// This matches the `GpuCullingStatistics` structure in `mesh_preprocess.wgsl`.
+#[derive(Clone, Copy, Default, Pod, Zeroable, ShaderType)]
+#[repr(C)]
+pub struct GpuCullingStatisticsCounters {
+    /// The number of instances that entered mesh preprocessing.
+    pub submitted: u32,
+    /// The number of instances that were outside the view frustum.
+    pub frustum_culled: u32,
+    /// The number of instances that were occluded in both the early and the
+    /// late phase of occlusion culling.
+    pub occlusion_culled: u32,
+    /// Padding.
+    pub pad: u32,
+}
+
+/// The GPU buffers that store the [`GpuCullingStatisticsCounters`] of each
+/// view.
+///
+/// A buffer is allocated for every view that's preprocessed on the GPU, as the
+/// bind group layouts for GPU culling always contain the counters, but they're
+/// only written to if culling statistics are enabled.
+#[derive(Resource, Default)]
+pub struct GpuCullingStatisticsBuffers {
+    /// Maps each render-world view entity to the buffer containing its
+    /// counters.
+    pub views: EntityHashMap<Buffer>,
+}
+
 /// Stops the `GpuPreprocessNode` attempting to generate the buffer for this view
 /// useful to avoid duplicating effort if the bind group is shared between views
 #[derive(Component, Default)]
@@ -458,6 +496,7 @@ impl Plugin for GpuMeshPreprocessPlugin {
 
         render_app
             .init_resource::<PreprocessPipelines>()
+            .init_resource::<GpuCullingStatisticsBuffers>()
             .init_resource::<SpecializedComputePipelines<PreprocessPipeline>>()
             .init_resource::<SpecializedComputePipelines<ResetIndirectBatchSetsPipeline>>()
             .init_resource::<SpecializedComputePipelines<BuildIndirectParametersPipeline>>()
@@ -471,6 +510,11 @@ impl Plugin for GpuMeshPreprocessPlugin {
                         )
                         .in_set(RenderSet::PrepareBindGroups),
                     write_mesh_culling_data_buffer.in_set(RenderSet::PrepareResourcesFlush),
+                    prepare_gpu_culling_statistics_buffers
+                        .run_if(
+                            resource_exists::<BatchedInstanceBuffers<MeshUniform, MeshInputUniform>>,
+                        )
+                        .in_set(RenderSet::PrepareResourcesFlush),
                 ),
             )
             .add_render_graph_node::<EarlyGpuPreprocessNode>(Core3d, NodePbr::EarlyGpuPreprocess)
@@ -1150,6 +1194,9 @@ impl SpecializedComputePipeline for PreprocessPipeline {
             shader_defs.push("INDIRECT".into());
             shader_defs.push("FRUSTUM_CULLING".into());
         }
+        if key.contains(PreprocessPipelineKey::GPU_CULLING_STATISTICS) {
+            shader_defs.push("GPU_CULLING_STATISTICS".into());
+        }
         if key.contains(PreprocessPipelineKey::OCCLUSION_CULLING) {
             shader_defs.push("OCCLUSION_CULLING".into());
             if key.contains(PreprocessPipelineKey::EARLY_PHASE) {
@@ -1360,6 +1407,11 @@ fn gpu_culling_bind_group_layout_entries() -> DynamicBindGroupLayoutEntries {
             0,
             uniform_buffer::<ViewUniform>(/* has_dynamic_offset= */ true),
         ),
+        // `culling_statistics`
+        (
+            13,
+            storage_buffer::<GpuCullingStatisticsCounters>(/* has_dynamic_offset= */ false),
+        ),
     ))
 }
 
@@ -1394,9 +1446,16 @@ pub fn prepare_preprocess_pipelines(
         SpecializedComputePipelines<BuildIndirectParametersPipeline>,
     >,
     preprocess_pipelines: ResMut<PreprocessPipelines>,
+    gpu_culling_statistics: Option<Res<GpuCullingStatisticsReadback>>,
 ) {
     let preprocess_pipelines = preprocess_pipelines.into_inner();
 
+    // Only pay for the extra atomics if someone is going to read the counters.
+    let mut gpu_culling_key = PreprocessPipelineKey::FRUSTUM_CULLING;
+    if gpu_culling_statistics.is_some() {
+        gpu_culling_key.insert(PreprocessPipelineKey::GPU_CULLING_STATISTICS);
+    }
+
     preprocess_pipelines.direct_preprocess.prepare(
         &pipeline_cache,
         &mut specialized_preprocess_pipelines,
@@ -1405,14 +1464,14 @@ pub fn prepare_preprocess_pipelines(
     preprocess_pipelines.gpu_frustum_culling_preprocess.prepare(
         &pipeline_cache,
         &mut specialized_preprocess_pipelines,
-        PreprocessPipelineKey::FRUSTUM_CULLING,
+        gpu_culling_key,
     );
     preprocess_pipelines
         .early_gpu_occlusion_culling_preprocess
         .prepare(
             &pipeline_cache,
             &mut specialized_preprocess_pipelines,
-            PreprocessPipelineKey::FRUSTUM_CULLING
+            gpu_culling_key
                 | PreprocessPipelineKey::OCCLUSION_CULLING
                 | PreprocessPipelineKey::EARLY_PHASE,
         );
@@ -1421,7 +1480,7 @@ pub fn prepare_preprocess_pipelines(
         .prepare(
             &pipeline_cache,
             &mut specialized_preprocess_pipelines,
-            PreprocessPipelineKey::FRUSTUM_CULLING | PreprocessPipelineKey::OCCLUSION_CULLING,
+            gpu_culling_key | PreprocessPipelineKey::OCCLUSION_CULLING,
         );
 
     let mut build_indirect_parameters_pipeline_key = BuildIndirectParametersPipelineKey::empty();
@@ -1626,6 +1685,7 @@ pub fn prepare_preprocess_bind_groups(
     mesh_culling_data_buffer: Res<MeshCullingDataBuffer>,
     view_uniforms: Res<ViewUniforms>,
     previous_view_uniforms: Res<PreviousViewUniforms>,
+    gpu_culling_statistics_buffers: Res<GpuCullingStatisticsBuffers>,
     pipelines: Res<PreprocessPipelines>,
 ) {
     // Grab the `BatchedInstanceBuffers`.
@@ -1652,6 +1712,10 @@ pub fn prepare_preprocess_bind_groups(
 
     // Loop over each view.
     for (view, phase_work_item_buffers) in work_item_buffers {
+        let Some(culling_statistics_buffer) = gpu_culling_statistics_buffers.views.get(view) else {
+            continue;
+        };
+
         let mut bind_groups = TypeIdMap::default();
 
         // Loop over each phase.
@@ -1667,6 +1731,7 @@ pub fn prepare_preprocess_bind_groups(
                 view_uniforms: &view_uniforms,
                 previous_view_uniforms: &previous_view_uniforms,
                 pipelines: &pipelines,
+                culling_statistics_buffer,
                 current_input_buffer,
                 previous_input_buffer,
                 data_buffer,
@@ -1761,6 +1826,9 @@ struct PreprocessBindGroupBuilder<'a> {
     previous_view_uniforms: &'a PreviousViewUniforms,
     /// The pipelines for the mesh preprocessing shader.
     pipelines: &'a PreprocessPipelines,
+    /// The GPU buffer that the culling statistics for this view are
+    /// accumulated into.
+    culling_statistics_buffer: &'a Buffer,
     /// The GPU buffer containing the list of [`MeshInputUniform`]s for the
     /// current frame.
     current_input_buffer: &'a Buffer,
@@ -1914,6 +1982,7 @@ impl<'a> PreprocessBindGroupBuilder<'a> {
                             (7, indexed_metadata_buffer.as_entire_binding()),
                             (8, mesh_culling_data_buffer.as_entire_binding()),
                             (0, view_uniforms_binding.clone()),
+                            (13, self.culling_statistics_buffer.as_entire_binding()),
                             (10, &view_depth_pyramid.all_mips),
                             (
                                 2,
@@ -2006,6 +2075,7 @@ impl<'a> PreprocessBindGroupBuilder<'a> {
                             (7, non_indexed_metadata_buffer.as_entire_binding()),
                             (8, mesh_culling_data_buffer.as_entire_binding()),
                             (0, view_uniforms_binding.clone()),
+                            (13, self.culling_statistics_buffer.as_entire_binding()),
                             (10, &view_depth_pyramid.all_mips),
                             (
                                 2,
@@ -2094,6 +2164,7 @@ impl<'a> PreprocessBindGroupBuilder<'a> {
                             (7, indexed_metadata_buffer.as_entire_binding()),
                             (8, mesh_culling_data_buffer.as_entire_binding()),
                             (0, view_uniforms_binding.clone()),
+                            (13, self.culling_statistics_buffer.as_entire_binding()),
                             (10, &view_depth_pyramid.all_mips),
                             (
                                 2,
@@ -2175,6 +2246,7 @@ impl<'a> PreprocessBindGroupBuilder<'a> {
                             (7, non_indexed_metadata_buffer.as_entire_binding()),
                             (8, mesh_culling_data_buffer.as_entire_binding()),
                             (0, view_uniforms_binding.clone()),
+                            (13, self.culling_statistics_buffer.as_entire_binding()),
                             (10, &view_depth_pyramid.all_mips),
                             (
                                 2,
@@ -2263,6 +2335,7 @@ impl<'a> PreprocessBindGroupBuilder<'a> {
                             (7, indexed_metadata_buffer.as_entire_binding()),
                             (8, mesh_culling_data_buffer.as_entire_binding()),
                             (0, view_uniforms_binding.clone()),
+                            (13, self.culling_statistics_buffer.as_entire_binding()),
                         )),
                     ),
                 )
@@ -2317,6 +2390,7 @@ impl<'a> PreprocessBindGroupBuilder<'a> {
                             (7, non_indexed_metadata_buffer.as_entire_binding()),
                             (8, mesh_culling_data_buffer.as_entire_binding()),
                             (0, view_uniforms_binding.clone()),
+                            (13, self.culling_statistics_buffer.as_entire_binding()),
                         )),
                     ),
                 )
@@ -2459,3 +2533,39 @@ pub fn write_mesh_culling_data_buffer(
 ) {
     mesh_culling_data_buffer.write_buffer(&render_device, &render_queue);
 }
+
+/// Allocates a [`GpuCullingStatisticsCounters`] buffer for every view that's
+/// preprocessed on the GPU and resets the counters for this frame.
+pub fn prepare_gpu_culling_statistics_buffers(
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+    batched_instance_buffers: Res<BatchedInstanceBuffers<MeshUniform, MeshInputUniform>>,
+    mut gpu_culling_statistics_buffers: ResMut<GpuCullingStatisticsBuffers>,
+) {
+    let work_item_buffers = &batched_instance_buffers.work_item_buffers;
+
+    // Free the buffers of views that no longer exist.
+    gpu_culling_statistics_buffers
+        .views
+        .retain(|view, _| work_item_buffers.contains_key(view));
+
+    for view in work_item_buffers.keys() {
+        let buffer = gpu_culling_statistics_buffers
+            .views
+            .entry(*view)
+            .or_insert_with(|| {
+                render_device.create_buffer(&BufferDescriptor {
+                    label: Some("GPU culling statistics buffer"),
+                    size: size_of::<GpuCullingStatisticsCounters>() as u64,
+                    usage: BufferUsages::STORAGE | BufferUsages::COPY_SRC | BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                })
+            });
+
+        render_queue.write_buffer(
+            buffer,
+            0,
+            bytemuck::bytes_of(&GpuCullingStatisticsCounters::default()),
+        );
+    }
+}
@@ -10,7 +10,7 @@ use bevy_ecs::{
     prelude::*,
     system::lifetimeless::Read,
 };
-use bevy_math::{ops, Mat4, UVec4, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
+use bevy_math::{ops, Mat4, UVec2, UVec4, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_render::{
     batching::gpu_preprocessing::{GpuPreprocessingMode, GpuPreprocessingSupport},
@@ -37,7 +37,7 @@ use bevy_render::{
 };
 use bevy_transform::{components::GlobalTransform, prelude::Transform};
 use bevy_utils::default;
-use core::{hash::Hash, ops::Range};
+use core::{cmp::Reverse, hash::Hash, ops::Range};
 #[cfg(feature = "trace")]
 use tracing::info_span;
 use tracing::{error, warn};
@@ -59,6 +59,9 @@ pub struct ExtractedPointLight {
     pub soft_shadows_enabled: bool,
     /// whether this point light contributes diffuse light to lightmapped meshes
     pub affects_lightmapped_mesh_diffuse: bool,
+    pub contact_shadows: Option<ContactShadows>,
+    /// The [`ShadowMapPriority`] of this light. Always `1.0` for spot lights.
+    pub shadow_map_priority: f32,
 }
 
 #[derive(Component, Debug)]
@@ -78,6 +81,7 @@ pub struct ExtractedDirectionalLight {
     pub frusta: EntityHashMap<Vec<Frustum>>,
     pub render_layers: RenderLayers,
     pub soft_shadow_size: Option<f32>,
+    pub contact_shadows: Option<ContactShadows>,
 }
 
 // NOTE: These must match the bit flags in bevy_pbr/src/render/mesh_view_types.wgsl!
@@ -88,11 +92,16 @@ bitflags::bitflags! {
         const SPOT_LIGHT_Y_NEGATIVE             = 1 << 1;
         const VOLUMETRIC                        = 1 << 2;
         const AFFECTS_LIGHTMAPPED_MESH_DIFFUSE  = 1 << 3;
+        const CONTACT_SHADOWS                   = 1 << 4;
         const NONE                              = 0;
         const UNINITIALIZED                     = 0xFFFF;
     }
 }
 
+// The largest number of cubes in the point light shadow map texture, and of point light shadow
+// maps along each side of a cube face.
+const POINT_LIGHT_SHADOW_ATLAS_MAX: u32 = 256;
+
 #[derive(Copy, Clone, ShaderType, Default, Debug)]
 pub struct GpuDirectionalCascade {
     clip_from_world: Mat4,
@@ -113,6 +122,8 @@ pub struct GpuDirectionalLight {
     cascades_overlap_proportion: f32,
     depth_texture_base_index: u32,
     skip: u32,
+    contact_shadow_length: f32,
+    contact_shadow_thickness: f32,
 }
 
 // NOTE: These must match the bit flags in bevy_pbr/src/render/mesh_view_types.wgsl!
@@ -122,6 +133,7 @@ bitflags::bitflags! {
         const SHADOWS_ENABLED                   = 1 << 0;
         const VOLUMETRIC                        = 1 << 1;
         const AFFECTS_LIGHTMAPPED_MESH_DIFFUSE  = 1 << 2;
+        const CONTACT_SHADOWS                   = 1 << 3;
         const NONE                              = 0;
         const UNINITIALIZED                     = 0xFFFF;
     }
@@ -134,8 +146,7 @@ pub struct GpuLights {
     // xyz are x/y/z cluster dimensions and w is the number of clusters
     cluster_dimensions: UVec4,
     // xy are vec2<f32>(cluster_dimensions.xy) / vec2<f32>(view.width, view.height)
-    // z is cluster_dimensions.z / log(far / near)
-    // w is cluster_dimensions.z * log(near) / log(far / near)
+    // zw are the depth slicing factors from calculate_cluster_factors
     cluster_factors: Vec4,
     n_directional_lights: u32,
     // offset from spot light's light index to spot light's shadow map index
@@ -210,6 +221,7 @@ impl FromWorld for ShadowSamplers {
 pub fn extract_lights(
     mut commands: Commands,
     point_light_shadow_map: Extract<Res<PointLightShadowMap>>,
+    point_light_shadow_budget: Extract<Res<PointLightShadowBudget>>,
     directional_light_shadow_map: Extract<Res<DirectionalLightShadowMap>>,
     global_point_lights: Extract<Res<GlobalVisibleClusterableObjects>>,
     point_lights: Extract<
@@ -222,6 +234,8 @@ pub fn extract_lights(
             &ViewVisibility,
             &CubemapFrusta,
             Option<&VolumetricLight>,
+            Option<&ContactShadows>,
+            Option<&ShadowMapPriority>,
         )>,
     >,
     spot_lights: Extract<
@@ -234,6 +248,7 @@ pub fn extract_lights(
             &ViewVisibility,
             &Frustum,
             Option<&VolumetricLight>,
+            Option<&ContactShadows>,
         )>,
     >,
     directional_lights: Extract<
@@ -250,6 +265,7 @@ pub fn extract_lights(
                 &ViewVisibility,
                 Option<&RenderLayers>,
                 Option<&VolumetricLight>,
+                Option<&ContactShadows>,
             ),
             Without<SpotLight>,
         >,
@@ -263,6 +279,9 @@ pub fn extract_lights(
     if point_light_shadow_map.is_changed() {
         commands.insert_resource(point_light_shadow_map.clone());
     }
+    if point_light_shadow_budget.is_changed() {
+        commands.insert_resource(point_light_shadow_budget.clone());
+    }
     if directional_light_shadow_map.is_changed() {
         commands.insert_resource(directional_light_shadow_map.clone());
     }
@@ -286,6 +305,8 @@ pub fn extract_lights(
             view_visibility,
             frusta,
             volumetric_light,
+            contact_shadows,
+            shadow_map_priority,
         )) = point_lights.get(entity)
         else {
             continue;
@@ -325,6 +346,8 @@ pub fn extract_lights(
             soft_shadows_enabled: point_light.soft_shadows_enabled,
             #[cfg(not(feature = "experimental_pbr_pcss"))]
             soft_shadows_enabled: false,
+            contact_shadows: contact_shadows.copied(),
+            shadow_map_priority: shadow_map_priority.map_or(1.0, |priority| priority.0),
         };
         point_lights_values.push((
             render_entity,
@@ -350,6 +373,7 @@ pub fn extract_lights(
             view_visibility,
             frustum,
             volumetric_light,
+            contact_shadows,
         )) = spot_lights.get(entity)
         {
             if !view_visibility.get() {
@@ -391,6 +415,8 @@ pub fn extract_lights(
                         soft_shadows_enabled: spot_light.soft_shadows_enabled,
                         #[cfg(not(feature = "experimental_pbr_pcss"))]
                         soft_shadows_enabled: false,
+                        contact_shadows: contact_shadows.copied(),
+                        shadow_map_priority: 1.0,
                     },
                     render_visible_entities,
                     *frustum,
@@ -414,6 +440,7 @@ pub fn extract_lights(
         view_visibility,
         maybe_layers,
         volumetric_light,
+        contact_shadows,
     ) in &directional_lights
     {
         if !view_visibility.get() {
@@ -479,6 +506,7 @@ pub fn extract_lights(
                     cascades: extracted_cascades,
                     frusta: extracted_frusta,
                     render_layers: maybe_layers.unwrap_or_default().clone(),
+                    contact_shadows: contact_shadows.copied(),
                 },
                 RenderCascadesVisibleEntities {
                     entities: cascade_visible_entities,
@@ -601,6 +629,169 @@ fn face_index_to_name(face_index: usize) -> &'static str {
     }
 }
 
+/// Estimates the fraction of the screen covered by a point light's range when
+/// seen from the given view.
+fn point_light_screen_coverage(light: &ExtractedPointLight, view: &ExtractedView) -> f32 {
+    let distance = view
+        .world_from_view
+        .translation()
+        .distance(light.transform.translation());
+    // Once the view is inside the light's range, the light covers the whole
+    // screen.
+    let ratio = light.range / distance.max(light.range);
+    ratio * ratio
+}
+
+/// Distributes `texel_budget` between shadow-casting point lights in
+/// proportion to their weights, returning the shadow map face size for each
+/// light.
+///
+/// Face sizes are powers of two in `min_face_size..=max_face_size`, except
+/// when `max_face_size` itself isn't a power of two. They are never smaller
+/// than 1/256th of `max_face_size`, so that
+/// [`pack_point_light_shadow_atlas`] can place them.
+pub(crate) fn allocate_point_light_shadow_face_sizes(
+    weights: &[f32],
+    max_face_size: u32,
+    min_face_size: u32,
+    texel_budget: u64,
+) -> Vec<u32> {
+    let min_face_size = min_face_size
+        .max(max_face_size.div_ceil(POINT_LIGHT_SHADOW_ATLAS_MAX))
+        .next_power_of_two()
+        .min(max_face_size);
+    let total_weight: f32 = weights.iter().sum();
+    weights
+        .iter()
+        .map(|&weight| {
+            if total_weight <= 0.0 {
+                return min_face_size;
+            }
+            let face_texels = texel_budget as f32 * (weight / total_weight) / 6.0;
+            let ideal_face_size = ops::sqrt(face_texels) as u32;
+            if ideal_face_size == 0 {
+                return min_face_size;
+            }
+            // Round down to a power of two.
+            let face_size = 1 << (u32::BITS - 1 - ideal_face_size.leading_zeros());
+            face_size.clamp(min_face_size, max_face_size)
+        })
+        .collect()
+}
+
+/// Where a point light's shadow map is stored in the point light shadow map texture.
+///
+/// Each face of the light's shadow map occupies the same `face_size`×`face_size` square at
+/// `x`, `y` of the corresponding face of cube `layer`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct PointLightShadowAtlasSlot {
+    pub(crate) layer: u32,
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+    pub(crate) face_size: u32,
+}
+
+impl PointLightShadowAtlasSlot {
+    /// The fraction of each cube face that this slot occupies, in a texture whose cube faces are
+    /// `atlas_size`×`atlas_size` texels.
+    fn scale(&self, atlas_size: u32) -> f32 {
+        self.face_size as f32 / atlas_size as f32
+    }
+
+    /// The center of this slot's square on each cube face, in face coordinates from -1 to 1.
+    fn center(&self, atlas_size: u32) -> Vec2 {
+        (UVec2::new(self.x, self.y).as_vec2() + self.face_size as f32 / 2.0) / atlas_size as f32
+            * 2.0
+            - 1.0
+    }
+}
+
+/// Packs point light shadow maps with the given face sizes into cubes whose
+/// faces are `atlas_size`×`atlas_size` texels, returning the slot of each
+/// light and the number of cubes used.
+///
+/// Face sizes smaller than `atlas_size` must be powers of two, as returned by
+/// [`allocate_point_light_shadow_face_sizes`]. They are placed largest first
+/// along a Z-order curve, which keeps every square aligned to its own size and
+/// leaves no gaps. Lights that don't fit into `max_layers` cubes get no slot.
+pub(crate) fn pack_point_light_shadow_atlas(
+    face_sizes: &[u32],
+    atlas_size: u32,
+    max_layers: u32,
+) -> (Vec<Option<PointLightShadowAtlasSlot>>, u32) {
+    let max_layers = max_layers.min(POINT_LIGHT_SHADOW_ATLAS_MAX);
+    let mut slots = vec![None; face_sizes.len()];
+    if atlas_size == 0 {
+        return (slots, 0);
+    }
+
+    let mut order = (0..face_sizes.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| Reverse(face_sizes[index]));
+
+    // Squares smaller than the whole face are packed into the largest power-of-two square that
+    // fits in it, in units of the smallest square.
+    let grid_size = 1 << (u32::BITS - 1 - atlas_size.leading_zeros());
+    let cell_size = face_sizes
+        .iter()
+        .copied()
+        .filter(|&face_size| face_size < atlas_size)
+        .min()
+        .unwrap_or(grid_size);
+    let cells_per_layer = (grid_size / cell_size).pow(2);
+
+    let mut layer_count = 0;
+    // Start out with a full layer, so that the first square opens a new one.
+    let mut cursor = cells_per_layer;
+    for index in order {
+        let face_size = face_sizes[index];
+        if face_size >= atlas_size {
+            if layer_count == max_layers {
+                break;
+            }
+            slots[index] = Some(PointLightShadowAtlasSlot {
+                layer: layer_count,
+                x: 0,
+                y: 0,
+                face_size: atlas_size,
+            });
+            layer_count += 1;
+            continue;
+        }
+
+        let cells = (face_size / cell_size).pow(2);
+        // Squares are placed largest first, so the cursor is always aligned to the current square
+        // size and a layer with no room left is full.
+        if cursor + cells > cells_per_layer {
+            if layer_count == max_layers {
+                break;
+            }
+            layer_count += 1;
+            cursor = 0;
+        }
+
+        let (cell_x, cell_y) = morton_decode(cursor);
+        slots[index] = Some(PointLightShadowAtlasSlot {
+            layer: layer_count - 1,
+            x: cell_x * cell_size,
+            y: cell_y * cell_size,
+            face_size,
+        });
+        cursor += cells;
+    }
+
+    (slots, layer_count)
+}
+
+/// Splits a Z-order curve index into its x and y coordinates.
+fn morton_decode(index: u32) -> (u32, u32) {
+    let (mut x, mut y) = (0, 0);
+    for bit in 0..u32::BITS / 2 {
+        x |= ((index >> (2 * bit)) & 1) << bit;
+        y |= ((index >> (2 * bit + 1)) & 1) << bit;
+    }
+    (x, y)
+}
+
 #[derive(Component)]
 pub struct ShadowView {
     pub depth_attachment: DepthAttachment,
@@ -659,9 +850,13 @@ pub fn calculate_cluster_factors(
     far: f32,
     z_slices: f32,
     is_orthographic: bool,
+    z_slicing: ClusterZSlicing,
 ) -> Vec2 {
     if is_orthographic {
         Vec2::new(-near, z_slices / (-far - -near))
+    } else if z_slicing == ClusterZSlicing::Linear {
+        // NOTE: The negative first factor tells the shader to slice linearly
+        Vec2::new(-(z_slices - 1.0) / (far - near), near)
     } else {
         let z_slices_of_ln_zfar_over_znear = (z_slices - 1.0) / ops::ln(far / near);
         Vec2::new(
@@ -722,7 +917,10 @@ pub fn prepare_lights(
         With<Camera3d>,
     >,
     ambient_light: Res<AmbientLight>,
-    point_light_shadow_map: Res<PointLightShadowMap>,
+    (point_light_shadow_map, point_light_shadow_budget): (
+        Res<PointLightShadowMap>,
+        Res<PointLightShadowBudget>,
+    ),
     directional_light_shadow_map: Res<DirectionalLightShadowMap>,
     mut shadow_render_phases: ResMut<ViewBinnedRenderPhases<Shadow>>,
     (
@@ -806,17 +1004,10 @@ pub fn prepare_lights(
         .filter(|light| light.2.spot_light_angles.is_none())
         .count();
 
-    let point_light_volumetric_enabled_count = point_lights
-        .iter()
-        .filter(|(_, _, light, _)| light.volumetric && light.spot_light_angles.is_none())
-        .count()
-        .min(max_texture_cubes);
-
     let point_light_shadow_maps_count = point_lights
         .iter()
         .filter(|light| light.2.shadows_enabled && light.2.spot_light_angles.is_none())
-        .count()
-        .min(max_texture_cubes);
+        .count();
 
     let directional_volumetric_enabled_count = directional_lights
         .iter()
@@ -852,8 +1043,9 @@ pub fn prepare_lights(
 
     // Sort lights by
     // - point-light vs spot-light, so that we can iterate point lights and spot lights in contiguous blocks in the fragment shader,
-    // - then those with shadows enabled first, so that the index can be used to render at most `point_light_shadow_maps_count`
-    //   point light shadows and `spot_light_shadow_maps_count` spot light shadow maps,
+    // - then those with shadows enabled first, so that the index can be used to pack the
+    //   `point_light_shadow_maps_count` point light shadows and render at most
+    //   `spot_light_shadow_maps_count` spot light shadow maps,
     // - then by entity as a stable key to ensure that a consistent set of lights are chosen if the light count limit is exceeded.
     point_lights.sort_by_cached_key(|(entity, _, light, _)| {
         (
@@ -862,6 +1054,32 @@ pub fn prepare_lights(
         )
     });
 
+    // Distribute the point light shadow budget between the shadow-casting point lights, which
+    // are the first `point_light_shadow_maps_count` lights after sorting.
+    let point_light_shadow_weights = point_lights
+        .iter()
+        .take(point_light_shadow_maps_count)
+        .map(|(_, _, light, _)| {
+            let screen_coverage = views
+                .iter()
+                .map(|(_, _, view, ..)| point_light_screen_coverage(light, view))
+                .fold(0.0, f32::max);
+            screen_coverage * light.shadow_map_priority
+        })
+        .collect::<Vec<_>>();
+    let point_light_shadow_face_sizes = allocate_point_light_shadow_face_sizes(
+        &point_light_shadow_weights,
+        point_light_shadow_map.size as u32,
+        point_light_shadow_budget.min_face_size as u32,
+        point_light_shadow_budget.texel_budget as u64,
+    );
+    // Lights that don't fit into the shadow map texture don't cast shadows.
+    let (point_light_shadow_slots, point_light_shadow_layer_count) = pack_point_light_shadow_atlas(
+        &point_light_shadow_face_sizes,
+        point_light_shadow_map.size as u32,
+        max_texture_cubes as u32,
+    );
+
     // Sort lights by
     // - those with volumetric (and shadows) enabled first, so that the
     //   volumetric lighting pass can quickly find the volumetric lights;
@@ -887,8 +1105,13 @@ pub fn prepare_lights(
         let mut flags = PointLightFlags::NONE;
 
         // Lights are sorted, shadow enabled lights are first
+        let point_light_shadow_slot = point_light_shadow_slots
+            .get(index)
+            .copied()
+            .flatten()
+            .filter(|_| light.spot_light_angles.is_none());
         if light.shadows_enabled
-            && (index < point_light_shadow_maps_count
+            && (point_light_shadow_slot.is_some()
                 || (light.spot_light_angles.is_some()
                     && index - point_light_count < spot_light_shadow_maps_count))
         {
@@ -902,7 +1125,7 @@ pub fn prepare_lights(
         );
         if light.shadows_enabled
             && light.volumetric
-            && (index < point_light_volumetric_enabled_count
+            && (point_light_shadow_slot.is_some()
                 || (light.spot_light_angles.is_some()
                     && index - point_light_count < spot_light_volumetric_enabled_count))
         {
@@ -913,6 +1136,10 @@ pub fn prepare_lights(
             flags |= PointLightFlags::AFFECTS_LIGHTMAPPED_MESH_DIFFUSE;
         }
 
+        if light.contact_shadows.is_some() {
+            flags |= PointLightFlags::CONTACT_SHADOWS;
+        }
+
         let (light_custom_data, spot_light_tan_angle) = match light.spot_light_angles {
             Some((inner, outer)) => {
                 let light_direction = light.transform.forward();
@@ -959,13 +1186,23 @@ pub fn prepare_lights(
             shadow_normal_bias: light.shadow_normal_bias,
             shadow_map_near_z: light.shadow_map_near_z,
             spot_light_tan_angle,
-            pad_a: 0.0,
-            pad_b: 0.0,
+            contact_shadow_length: light
+                .contact_shadows
+                .map_or(0.0, |contact_shadows| contact_shadows.length),
+            contact_shadow_thickness: light
+                .contact_shadows
+                .map_or(0.0, |contact_shadows| contact_shadows.thickness),
             soft_shadow_size: if light.soft_shadows_enabled {
                 light.radius
             } else {
                 0.0
             },
+            shadow_atlas_layer: point_light_shadow_slot.map_or(0, |slot| slot.layer),
+            shadow_atlas_scale: point_light_shadow_slot
+                .map_or(1.0, |slot| slot.scale(point_light_shadow_map.size as u32)),
+            shadow_atlas_center: point_light_shadow_slot.map_or(Vec2::ZERO, |slot| {
+                slot.center(point_light_shadow_map.size as u32)
+            }),
         });
         global_light_meta.entity_to_index.insert(entity, index);
     }
@@ -995,6 +1232,10 @@ pub fn prepare_lights(
             flags |= DirectionalLightFlags::AFFECTS_LIGHTMAPPED_MESH_DIFFUSE;
         }
 
+        if light.contact_shadows.is_some() {
+            flags |= DirectionalLightFlags::CONTACT_SHADOWS;
+        }
+
         let num_cascades = light
             .cascade_shadow_config
             .bounds
@@ -1017,6 +1258,12 @@ pub fn prepare_lights(
             num_cascades: num_cascades as u32,
             cascades_overlap_proportion: light.cascade_shadow_config.overlap_proportion,
             depth_texture_base_index: num_directional_cascades_enabled as u32,
+            contact_shadow_length: light
+                .contact_shadows
+                .map_or(0.0, |contact_shadows| contact_shadows.length),
+            contact_shadow_thickness: light
+                .contact_shadows
+                .map_or(0.0, |contact_shadows| contact_shadows.thickness),
         };
         if index < directional_shadow_enabled_count {
             num_directional_cascades_enabled += num_cascades;
@@ -1033,6 +1280,9 @@ pub fn prepare_lights(
     live_shadow_mapping_lights.clear();
 
     let mut point_light_depth_attachments = HashMap::<u32, DepthAttachment>::default();
+    // Point lights share layers of the shadow map texture, so the first view of each light face
+    // is tracked separately from the attachments.
+    let mut point_light_faces = HashSet::<usize>::default();
     let mut directional_light_depth_attachments = HashMap::<u32, DepthAttachment>::default();
 
     let point_light_depth_texture = texture_cache.get(
@@ -1041,7 +1291,7 @@ pub fn prepare_lights(
             size: Extent3d {
                 width: point_light_shadow_map.size as u32,
                 height: point_light_shadow_map.size as u32,
-                depth_or_array_layers: point_light_shadow_maps_count.max(1) as u32 * 6,
+                depth_or_array_layers: point_light_shadow_layer_count.max(1) * 6,
             },
             mip_level_count: 1,
             sample_count: 1,
@@ -1156,6 +1406,7 @@ pub fn prepare_lights(
             clusters.far,
             clusters.dimensions.z as f32,
             is_orthographic,
+            clusters.z_slicing,
         );
 
         let n_clusters = clusters.dimensions.x * clusters.dimensions.y * clusters.dimensions.z;
@@ -1186,23 +1437,27 @@ pub fn prepare_lights(
         for &(light_entity, light_main_entity, light, (point_light_frusta, _)) in point_lights
             .iter()
             // Lights are sorted, shadow enabled lights are first
-            .take(point_light_count.min(max_texture_cubes))
+            .take(point_light_count)
         {
             let Ok(mut light_view_entities) = light_view_entities.get_mut(light_entity) else {
                 continue;
             };
 
-            if !light.shadows_enabled {
-                if let Some(entities) = light_view_entities.remove(&entity) {
-                    despawn_entities(&mut commands, entities);
-                }
-                continue;
-            }
-
             let light_index = *global_light_meta
                 .entity_to_index
                 .get(&light_entity)
                 .unwrap();
+            let Some(shadow_slot) = point_light_shadow_slots
+                .get(light_index)
+                .copied()
+                .flatten()
+                .filter(|_| light.shadows_enabled)
+            else {
+                if let Some(entities) = light_view_entities.remove(&entity) {
+                    despawn_entities(&mut commands, entities);
+                }
+                continue;
+            };
             // ignore scale because we don't want to effectively scale light radius and range
             // by applying those as a view transform to shadow map rendering of objects
             // and ignore rotation because we want the shadow map projections to align with the axes
@@ -1225,14 +1480,12 @@ pub fn prepare_lights(
                 .zip(light_view_entities.iter().copied())
                 .enumerate()
             {
-                let mut first = false;
-                let base_array_layer = (light_index * 6 + face_index) as u32;
+                let first = point_light_faces.insert(light_index * 6 + face_index);
+                let base_array_layer = shadow_slot.layer * 6 + face_index as u32;
 
                 let depth_attachment = point_light_depth_attachments
                     .entry(base_array_layer)
                     .or_insert_with(|| {
-                        first = true;
-
                         let depth_texture_view =
                             point_light_depth_texture
                                 .texture
@@ -1269,10 +1522,10 @@ pub fn prepare_lights(
                     ExtractedView {
                         retained_view_entity,
                         viewport: UVec4::new(
-                            0,
-                            0,
-                            point_light_shadow_map.size as u32,
-                            point_light_shadow_map.size as u32,
+                            shadow_slot.x,
+                            shadow_slot.y,
+                            shadow_slot.face_size,
+                            shadow_slot.face_size,
                         ),
                         world_from_view: view_translation * *view_rotation,
                         clip_from_world: None,
@@ -1692,6 +1945,14 @@ pub fn queue_shadows<M: Material>(
                     mesh_key |= MeshPipelineKey::LIGHTMAPPED;
                 }
 
+                // The clipped side of a mesh doesn't cast shadows either.
+                if mesh_instance
+                    .flags
+                    .contains(RenderMeshInstanceFlags::HAS_CLIP_PLANE)
+                {
+                    mesh_key |= MeshPipelineKey::CLIP_PLANES;
+                }
+
                 mesh_key |= match material.properties.alpha_mode {
                     AlphaMode::Mask(_)
                     | AlphaMode::Blend
@@ -1915,6 +2176,7 @@ impl Node for ShadowPassNode {
 
                 let depth_stencil_attachment =
                     Some(view_light.depth_attachment.get_attachment(StoreOp::Store));
+                let viewport = extracted_light_view.viewport.as_vec4();
 
                 let diagnostics = render_context.diagnostic_recorder();
                 render_context.add_command_buffer_generation_task(move |render_device| {
@@ -1934,6 +2196,9 @@ impl Node for ShadowPassNode {
                     });
 
                     let mut render_pass = TrackedRenderPass::new(&render_device, render_pass);
+                    // Point light shadow maps may only occupy part of the texture.
+                    render_pass
+                        .set_viewport(viewport.x, viewport.y, viewport.z, viewport.w, 0.0, 1.0);
                     let pass_span =
                         diagnostics.pass_span(&mut render_pass, view_light.pass_name.clone());
 
@@ -1955,3 +2220,93 @@ impl Node for ShadowPassNode {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        allocate_point_light_shadow_face_sizes, pack_point_light_shadow_atlas,
+        PointLightShadowAtlasSlot,
+    };
+    use bevy_math::Vec2;
+
+    #[test]
+    fn point_light_shadow_face_sizes_follow_weights() {
+        // Enough for one full resolution light.
+        let budget = 6 * 1024 * 1024;
+        assert_eq!(
+            allocate_point_light_shadow_face_sizes(&[1.0], 1024, 64, budget),
+            vec![1024]
+        );
+        // Splitting the budget four ways halves the face size.
+        assert_eq!(
+            allocate_point_light_shadow_face_sizes(&[1.0; 4], 1024, 64, budget),
+            vec![512; 4]
+        );
+        // Lights are clamped to the min and max face sizes.
+        assert_eq!(
+            allocate_point_light_shadow_face_sizes(&[1.0, 0.0], 1024, 64, budget * 4),
+            vec![1024, 64]
+        );
+        // Face sizes are kept small enough to be placed in the atlas.
+        assert_eq!(
+            allocate_point_light_shadow_face_sizes(&[1.0, 0.0], 1000, 3, budget),
+            vec![1000, 4]
+        );
+    }
+
+    #[test]
+    fn point_light_shadow_atlas_packs_lights_into_shared_cubes() {
+        let slot = |layer, x, y, face_size| {
+            Some(PointLightShadowAtlasSlot {
+                layer,
+                x,
+                y,
+                face_size,
+            })
+        };
+
+        // Full resolution lights get a cube each, and the smaller lights share the others.
+        let (slots, layer_count) =
+            pack_point_light_shadow_atlas(&[256, 1024, 512, 256, 512, 256, 256, 512], 1024, 4);
+        assert_eq!(layer_count, 2);
+        assert_eq!(
+            slots,
+            vec![
+                slot(1, 512, 512, 256),
+                slot(0, 0, 0, 1024),
+                slot(1, 0, 0, 512),
+                slot(1, 768, 512, 256),
+                slot(1, 512, 0, 512),
+                slot(1, 512, 768, 256),
+                slot(1, 768, 768, 256),
+                slot(1, 0, 512, 512),
+            ]
+        );
+
+        // Lights that don't fit get no slot.
+        let (slots, layer_count) = pack_point_light_shadow_atlas(&[1024, 512, 1024], 1024, 2);
+        assert_eq!(layer_count, 2);
+        assert_eq!(slots, vec![slot(0, 0, 0, 1024), None, slot(1, 0, 0, 1024)]);
+    }
+
+    #[test]
+    fn point_light_shadow_atlas_slot_locates_its_square() {
+        let slot = PointLightShadowAtlasSlot {
+            layer: 1,
+            x: 512,
+            y: 0,
+            face_size: 256,
+        };
+        assert_eq!(slot.scale(1024), 0.25);
+        assert_eq!(slot.center(1024), Vec2::new(0.25, -0.75));
+
+        let slot = PointLightShadowAtlasSlot {
+            layer: 0,
+            x: 0,
+            y: 0,
+            face_size: 1024,
+        };
+        assert_eq!(slot.scale(1024), 1.0);
+        assert_eq!(slot.center(1024), Vec2::ZERO);
+    }
+}
@@ -6,6 +6,7 @@ use bevy_asset::{load_internal_asset, AssetId, UntypedAssetId};
 use bevy_core_pipeline::{
     core_3d::{AlphaMask3d, Opaque3d, Transmissive3d, Transparent3d, CORE_3D_DEPTH_FORMAT},
     deferred::{AlphaMask3dDeferred, Opaque3dDeferred},
+    motion_blur::MotionBlurScale,
     oit::{prepare_oit_buffers, OrderIndependentTransparencySettingsOffset},
     prepass::MotionVectorPrepass,
 };
@@ -16,7 +17,7 @@ use bevy_ecs::{
     system::{lifetimeless::*, SystemParamItem, SystemState},
 };
 use bevy_image::{BevyDefault, ImageSampler, TextureFormatPixelInfo};
-use bevy_math::{Affine3, Rect, UVec2, Vec3, Vec4};
+use bevy_math::{Affine3, Affine3A, Rect, UVec2, Vec3, Vec4};
 use bevy_platform_support::collections::{hash_map::Entry, HashMap};
 use bevy_render::{
     batching::{
@@ -27,7 +28,7 @@ use bevy_render::{
         },
         no_gpu_preprocessing, GetBatchData, GetFullBatchData, NoAutomaticBatching,
     },
-    camera::Camera,
+    camera::{Camera, ComputedClipPlanes},
     mesh::*,
     primitives::Aabb,
     render_asset::RenderAssets,
@@ -288,6 +289,9 @@ pub struct MeshTransforms {
     pub world_from_local: Affine3,
     pub previous_world_from_local: Affine3,
     pub flags: u32,
+    /// The world-space half space kept by the mesh's clip plane, see
+    /// [`MeshUniform::clip_plane`].
+    pub clip_plane: Vec4,
 }
 
 #[derive(ShaderType, Clone)]
@@ -327,6 +331,16 @@ pub struct MeshUniform {
     /// Low 16 bits: index of the material inside the bind group data.
     /// High 16 bits: index of the lightmap in the binding array.
     pub material_and_lightmap_bind_group_slot: u32,
+    /// The bits of the main world entity of this mesh, low 32 bits first.
+    ///
+    /// This is written to the entity ID prepass, see
+    /// [`EntityIdPrepass`](bevy_core_pipeline::prepass::EntityIdPrepass).
+    pub main_entity: UVec2,
+    /// The world-space half space kept by the first of the mesh's
+    /// [`ClipPlanes`](bevy_render::camera::ClipPlanes), or `(0, 0, 0, 1)` if it has none, which keeps every point.
+    ///
+    /// See [`ClipPlane::half_space`](bevy_render::camera::ClipPlane::half_space).
+    pub clip_plane: Vec4,
 }
 
 /// Information that has to be transferred from CPU to GPU in order to produce
@@ -383,10 +397,20 @@ pub struct MeshInputUniform {
     /// Low 16 bits: index of the material inside the bind group data.
     /// High 16 bits: index of the lightmap in the binding array.
     pub material_and_lightmap_bind_group_slot: u32,
-    /// Padding.
-    pub pad_a: u32,
+    /// The factor by which the motion vectors of this mesh are scaled.
+    ///
+    /// The GPU moves the previous transform towards the current one by this
+    /// amount. See [`MotionBlurScale`].
+    pub motion_vector_scale: f32,
     /// Padding.
     pub pad_b: u32,
+    /// The bits of the main world entity of this mesh, low 32 bits first.
+    pub main_entity: UVec2,
+    /// Padding.
+    pub pad_c: UVec2,
+    /// The world-space half space kept by the mesh's clip plane, see
+    /// [`MeshUniform::clip_plane`].
+    pub clip_plane: Vec4,
 }
 
 /// Information about each mesh instance needed to cull it on GPU.
@@ -420,6 +444,7 @@ impl MeshUniform {
         maybe_lightmap: Option<(LightmapSlotIndex, Rect)>,
         current_skin_index: Option<u32>,
         previous_skin_index: Option<u32>,
+        main_entity: MainEntity,
     ) -> Self {
         let (local_from_world_transpose_a, local_from_world_transpose_b) =
             mesh_transforms.world_from_local.inverse_transpose_3x3();
@@ -440,10 +465,27 @@ impl MeshUniform {
             previous_skin_index: previous_skin_index.unwrap_or(u32::MAX),
             material_and_lightmap_bind_group_slot: u32::from(material_bind_group_slot)
                 | ((lightmap_bind_group_slot as u32) << 16),
+            main_entity: pack_entity_bits(main_entity),
+            clip_plane: mesh_transforms.clip_plane,
         }
     }
 }
 
+/// Returns the clip plane stored in [`MeshUniform::clip_plane`] for a mesh
+/// with the given [`ComputedClipPlanes`].
+fn mesh_clip_plane(clip_planes: Option<&ComputedClipPlanes>) -> Vec4 {
+    clip_planes
+        .and_then(|clip_planes| clip_planes.0.first().copied())
+        .unwrap_or(Vec4::W)
+}
+
+/// Splits the bits of a main world entity into the `UVec2` stored in [`MeshUniform`] and
+/// [`MeshInputUniform`], low 32 bits first.
+fn pack_entity_bits(main_entity: MainEntity) -> UVec2 {
+    let bits = main_entity.id().to_bits();
+    UVec2::new(bits as u32, (bits >> 32) as u32)
+}
+
 // NOTE: These must match the bit flags in bevy_pbr/src/render/mesh_types.wgsl!
 bitflags::bitflags! {
     /// Various flags and tightly-packed values on a mesh.
@@ -456,6 +498,16 @@ bitflags::bitflags! {
         ///
         /// This will be `u16::MAX` if this mesh has no LOD.
         const LOD_INDEX_MASK              = (1 << 16) - 1;
+        /// Bitmask for the 6-bit quantized depth bias scale from
+        /// [`crate::ShadowBias`].
+        ///
+        /// See [`MeshFlags::pack_shadow_bias_scale`] for the encoding.
+        const SHADOW_DEPTH_BIAS_SCALE_MASK  = 0b111111 << 16;
+        /// Bitmask for the 6-bit quantized normal bias scale from
+        /// [`crate::ShadowBias`].
+        ///
+        /// See [`MeshFlags::pack_shadow_bias_scale`] for the encoding.
+        const SHADOW_NORMAL_BIAS_SCALE_MASK = 0b111111 << 22;
         /// Disables frustum culling for this mesh.
         ///
         /// This corresponds to the
@@ -478,6 +530,7 @@ impl MeshFlags {
         no_frustum_culling: bool,
         not_shadow_receiver: bool,
         transmitted_receiver: bool,
+        shadow_bias: Option<&ShadowBias>,
     ) -> MeshFlags {
         let mut mesh_flags = if not_shadow_receiver {
             MeshFlags::empty()
@@ -501,11 +554,37 @@ impl MeshFlags {
         mesh_flags |=
             MeshFlags::from_bits_retain((lod_index_bits as u32) << MeshFlags::LOD_INDEX_SHIFT);
 
+        if let Some(shadow_bias) = shadow_bias {
+            mesh_flags |= MeshFlags::from_bits_retain(
+                (Self::pack_shadow_bias_scale(shadow_bias.depth_bias_scale)
+                    << MeshFlags::SHADOW_DEPTH_BIAS_SCALE_SHIFT)
+                    | (Self::pack_shadow_bias_scale(shadow_bias.normal_bias_scale)
+                        << MeshFlags::SHADOW_NORMAL_BIAS_SCALE_SHIFT),
+            );
+        }
+
         mesh_flags
     }
 
+    /// Quantizes a [`ShadowBias`] scale into 6 bits.
+    ///
+    /// The scale is stored in steps of `1/16`, offset so that all bits being
+    /// zero represents a scale of `1.0`. That way, meshes without a
+    /// [`ShadowBias`] component use the light's biases unchanged. This must be
+    /// kept in sync with `mesh_shadow_bias_scale` in `shadows.wgsl`.
+    fn pack_shadow_bias_scale(scale: f32) -> u32 {
+        let quantized = (scale * 16.0).round().clamp(0.0, 63.0) as u32;
+        quantized.wrapping_sub(16) & 0b111111
+    }
+
     /// The first bit of the LOD index.
     pub const LOD_INDEX_SHIFT: u32 = 0;
+
+    /// The first bit of the quantized shadow depth bias scale.
+    pub const SHADOW_DEPTH_BIAS_SCALE_SHIFT: u32 = 16;
+
+    /// The first bit of the quantized shadow normal bias scale.
+    pub const SHADOW_NORMAL_BIAS_SCALE_SHIFT: u32 = 22;
 }
 
 bitflags::bitflags! {
@@ -525,6 +604,9 @@ bitflags::bitflags! {
         /// The mesh had morph targets last frame and so they should be taken
         /// into account for motion vector computation.
         const HAS_PREVIOUS_MORPH      = 1 << 4;
+        /// The mesh has a clip plane, so the fragments on its clipped side
+        /// have to be discarded.
+        const HAS_CLIP_PLANE          = 1 << 5;
     }
 }
 
@@ -596,6 +678,11 @@ pub struct RenderMeshInstanceGpuBuilder {
     pub previous_input_index: Option<NonMaxU32>,
     /// Various flags.
     pub mesh_flags: MeshFlags,
+    /// The factor by which motion vectors are scaled; see [`MotionBlurScale`].
+    pub motion_vector_scale: f32,
+    /// The world-space half space kept by the mesh's clip plane, see
+    /// [`MeshUniform::clip_plane`].
+    pub clip_plane: Vec4,
 }
 
 /// The per-thread queues used during [`extract_meshes_for_gpu_building`].
@@ -649,6 +736,7 @@ impl RenderMeshInstanceShared {
         mesh: &Mesh3d,
         not_shadow_caster: bool,
         no_automatic_batching: bool,
+        clip_plane: Vec4,
     ) -> Self {
         let mut mesh_instance_flags = RenderMeshInstanceFlags::empty();
         mesh_instance_flags.set(RenderMeshInstanceFlags::SHADOW_CASTER, !not_shadow_caster);
@@ -660,6 +748,10 @@ impl RenderMeshInstanceShared {
             RenderMeshInstanceFlags::HAS_PREVIOUS_TRANSFORM,
             previous_transform.is_some(),
         );
+        mesh_instance_flags.set(
+            RenderMeshInstanceFlags::HAS_CLIP_PLANE,
+            clip_plane != Vec4::W,
+        );
 
         RenderMeshInstanceShared {
             mesh_asset_id: mesh.id(),
@@ -993,8 +1085,11 @@ impl RenderMeshInstanceGpuBuilder {
             material_and_lightmap_bind_group_slot: u32::from(
                 self.shared.material_bindings_index.slot,
             ) | ((lightmap_slot as u32) << 16),
-            pad_a: 0,
+            motion_vector_scale: self.motion_vector_scale,
             pad_b: 0,
+            main_entity: pack_entity_bits(entity),
+            pad_c: UVec2::ZERO,
+            clip_plane: self.clip_plane,
         };
 
         // Did the last frame contain this entity as well?
@@ -1135,6 +1230,9 @@ pub fn extract_meshes_for_cpu_building(
             Has<NotShadowCaster>,
             Has<NoAutomaticBatching>,
             Has<VisibilityRange>,
+            Option<&ShadowBias>,
+            Option<&MotionBlurScale>,
+            Option<&ComputedClipPlanes>,
         )>,
     >,
 ) {
@@ -1153,6 +1251,9 @@ pub fn extract_meshes_for_cpu_building(
             not_shadow_caster,
             no_automatic_batching,
             visibility_range,
+            shadow_bias,
+            motion_blur_scale,
+            clip_planes,
         )| {
             if !view_visibility.get() {
                 return;
@@ -1169,26 +1270,32 @@ pub fn extract_meshes_for_cpu_building(
                 no_frustum_culling,
                 not_shadow_receiver,
                 transmitted_receiver,
+                shadow_bias,
             );
 
+            let clip_plane = mesh_clip_plane(clip_planes);
             let shared = RenderMeshInstanceShared::from_components(
                 previous_transform,
                 mesh,
                 not_shadow_caster,
                 no_automatic_batching,
+                clip_plane,
             );
 
             let world_from_local = transform.affine();
+            let previous_world_from_local = scale_previous_world_from_local(
+                world_from_local,
+                previous_transform.map_or(world_from_local, |t| t.0),
+                motion_blur_scale.map_or(1.0, |scale| scale.0),
+            );
             queue.push((
                 entity,
                 RenderMeshInstanceCpu {
                     transforms: MeshTransforms {
                         world_from_local: (&world_from_local).into(),
-                        previous_world_from_local: (&previous_transform
-                            .map(|t| t.0)
-                            .unwrap_or(world_from_local))
-                            .into(),
+                        previous_world_from_local: (&previous_world_from_local).into(),
                         flags: mesh_flags.bits(),
+                        clip_plane,
                     },
                     shared,
                 },
@@ -1213,6 +1320,51 @@ pub fn extract_meshes_for_cpu_building(
     }
 }
 
+/// Moves the previous transform of a mesh towards its current transform so that
+/// the resulting motion vectors are multiplied by `motion_vector_scale`.
+///
+/// This mirrors the blend that `mesh_preprocess.wgsl` performs when
+/// [`MeshUniform`]s are built on GPU.
+fn scale_previous_world_from_local(
+    world_from_local: Affine3A,
+    previous_world_from_local: Affine3A,
+    motion_vector_scale: f32,
+) -> Affine3A {
+    if motion_vector_scale == 1.0 {
+        return previous_world_from_local;
+    }
+    Affine3A {
+        matrix3: world_from_local.matrix3
+            + (previous_world_from_local.matrix3 - world_from_local.matrix3) * motion_vector_scale,
+        translation: world_from_local
+            .translation
+            .lerp(previous_world_from_local.translation, motion_vector_scale),
+    }
+}
+
+/// The components that [`extract_meshes_for_gpu_building`] reads from each
+/// mesh in the main world.
+type GpuMeshExtractionQuery = (
+    Entity,
+    Read<ViewVisibility>,
+    Read<GlobalTransform>,
+    Option<Read<PreviousGlobalTransform>>,
+    Option<Read<Lightmap>>,
+    Option<Read<Aabb>>,
+    Read<Mesh3d>,
+    Has<NoFrustumCulling>,
+    Has<NotShadowReceiver>,
+    Has<TransmittedShadowReceiver>,
+    Has<NotShadowCaster>,
+    Has<NoAutomaticBatching>,
+    Has<VisibilityRange>,
+    Option<Read<ShadowBias>>,
+    (
+        Option<Read<MotionBlurScale>>,
+        Option<Read<ComputedClipPlanes>>,
+    ),
+);
+
 /// Extracts meshes from the main world into the render world and queues
 /// [`MeshInputUniform`]s to be uploaded to the GPU.
 ///
@@ -1227,21 +1379,7 @@ pub fn extract_meshes_for_gpu_building(
     mut render_mesh_instance_queues: ResMut<RenderMeshInstanceGpuQueues>,
     changed_meshes_query: Extract<
         Query<
-            (
-                Entity,
-                &ViewVisibility,
-                &GlobalTransform,
-                Option<&PreviousGlobalTransform>,
-                Option<&Lightmap>,
-                Option<&Aabb>,
-                &Mesh3d,
-                Has<NoFrustumCulling>,
-                Has<NotShadowReceiver>,
-                Has<TransmittedShadowReceiver>,
-                Has<NotShadowCaster>,
-                Has<NoAutomaticBatching>,
-                Has<VisibilityRange>,
-            ),
+            GpuMeshExtractionQuery,
             Or<(
                 Changed<ViewVisibility>,
                 Changed<GlobalTransform>,
@@ -1255,12 +1393,19 @@ pub fn extract_meshes_for_gpu_building(
                 Changed<NotShadowCaster>,
                 Changed<NoAutomaticBatching>,
                 Changed<VisibilityRange>,
+                Changed<ShadowBias>,
+                Changed<MotionBlurScale>,
+                Changed<ComputedClipPlanes>,
             )>,
         >,
     >,
+    all_meshes_query: Extract<Query<GpuMeshExtractionQuery>>,
     mut removed_visibilities_query: Extract<RemovedComponents<ViewVisibility>>,
     mut removed_global_transforms_query: Extract<RemovedComponents<GlobalTransform>>,
     mut removed_meshes_query: Extract<RemovedComponents<Mesh3d>>,
+    mut removed_shadow_biases_query: Extract<RemovedComponents<ShadowBias>>,
+    mut removed_motion_blur_scales_query: Extract<RemovedComponents<MotionBlurScale>>,
+    mut removed_clip_planes_query: Extract<RemovedComponents<ComputedClipPlanes>>,
     gpu_culling_query: Extract<Query<(), (With<Camera>, Without<NoIndirectDrawing>)>>,
 ) {
     let any_gpu_culling = !gpu_culling_query.is_empty();
@@ -1283,74 +1428,13 @@ pub fn extract_meshes_for_gpu_building(
     // construct the `MeshInputUniform` for them.
     changed_meshes_query.par_iter().for_each_init(
         || render_mesh_instance_queues.borrow_local_mut(),
-        |queue,
-         (
-            entity,
-            view_visibility,
-            transform,
-            previous_transform,
-            lightmap,
-            aabb,
-            mesh,
-            no_frustum_culling,
-            not_shadow_receiver,
-            transmitted_receiver,
-            not_shadow_caster,
-            no_automatic_batching,
-            visibility_range,
-        )| {
-            if !view_visibility.get() {
-                queue.remove(entity.into(), any_gpu_culling);
-                return;
-            }
-
-            let mut lod_index = None;
-            if visibility_range {
-                lod_index = render_visibility_ranges.lod_index_for_entity(entity.into());
-            }
-
-            let mesh_flags = MeshFlags::from_components(
-                transform,
-                lod_index,
-                no_frustum_culling,
-                not_shadow_receiver,
-                transmitted_receiver,
-            );
-
-            let shared = RenderMeshInstanceShared::from_components(
-                previous_transform,
-                mesh,
-                not_shadow_caster,
-                no_automatic_batching,
-            );
-
-            let lightmap_uv_rect = pack_lightmap_uv_rect(lightmap.map(|lightmap| lightmap.uv_rect));
-
-            let gpu_mesh_culling_data = any_gpu_culling.then(|| MeshCullingData::new(aabb));
-
-            let previous_input_index = if shared
-                .flags
-                .contains(RenderMeshInstanceFlags::HAS_PREVIOUS_TRANSFORM)
-            {
-                render_mesh_instances
-                    .get(&MainEntity::from(entity))
-                    .map(|render_mesh_instance| render_mesh_instance.current_uniform_index)
-            } else {
-                None
-            };
-
-            let gpu_mesh_instance_builder = RenderMeshInstanceGpuBuilder {
-                shared,
-                world_from_local: (&transform.affine()).into(),
-                lightmap_uv_rect,
-                mesh_flags,
-                previous_input_index,
-            };
-
-            queue.push(
-                entity.into(),
-                gpu_mesh_instance_builder,
-                gpu_mesh_culling_data,
+        |queue, query_row| {
+            extract_mesh_for_gpu_building(
+                query_row,
+                &render_visibility_ranges,
+                render_mesh_instances,
+                queue,
+                any_gpu_culling,
             );
         },
     );
@@ -1369,6 +1453,112 @@ pub fn extract_meshes_for_gpu_building(
             queue.remove(entity.into(), any_gpu_culling);
         }
     }
+
+    // Removing an optional component doesn't trip any of the change filters
+    // above, so re-extract those meshes explicitly.
+    for entity in removed_shadow_biases_query
+        .read()
+        .chain(removed_motion_blur_scales_query.read())
+        .chain(removed_clip_planes_query.read())
+    {
+        if changed_meshes_query.contains(entity) {
+            continue;
+        }
+        if let Ok(query_row) = all_meshes_query.get(entity) {
+            extract_mesh_for_gpu_building(
+                query_row,
+                &render_visibility_ranges,
+                render_mesh_instances,
+                &mut queue,
+                any_gpu_culling,
+            );
+        }
+    }
+}
+
+/// Queues the render-world data for a single mesh for
+/// [`extract_meshes_for_gpu_building`].
+fn extract_mesh_for_gpu_building(
+    (
+        entity,
+        view_visibility,
+        transform,
+        previous_transform,
+        lightmap,
+        aabb,
+        mesh,
+        no_frustum_culling,
+        not_shadow_receiver,
+        transmitted_receiver,
+        not_shadow_caster,
+        no_automatic_batching,
+        visibility_range,
+        shadow_bias,
+        (motion_blur_scale, clip_planes),
+    ): ROQueryItem<'_, GpuMeshExtractionQuery>,
+    render_visibility_ranges: &RenderVisibilityRanges,
+    render_mesh_instances: &RenderMeshInstancesGpu,
+    queue: &mut RenderMeshInstanceGpuQueue,
+    any_gpu_culling: bool,
+) {
+    if !view_visibility.get() {
+        queue.remove(entity.into(), any_gpu_culling);
+        return;
+    }
+
+    let mut lod_index = None;
+    if visibility_range {
+        lod_index = render_visibility_ranges.lod_index_for_entity(entity.into());
+    }
+
+    let mesh_flags = MeshFlags::from_components(
+        transform,
+        lod_index,
+        no_frustum_culling,
+        not_shadow_receiver,
+        transmitted_receiver,
+        shadow_bias,
+    );
+
+    let clip_plane = mesh_clip_plane(clip_planes);
+    let shared = RenderMeshInstanceShared::from_components(
+        previous_transform,
+        mesh,
+        not_shadow_caster,
+        no_automatic_batching,
+        clip_plane,
+    );
+
+    let lightmap_uv_rect = pack_lightmap_uv_rect(lightmap.map(|lightmap| lightmap.uv_rect));
+
+    let gpu_mesh_culling_data = any_gpu_culling.then(|| MeshCullingData::new(aabb));
+
+    let previous_input_index = if shared
+        .flags
+        .contains(RenderMeshInstanceFlags::HAS_PREVIOUS_TRANSFORM)
+    {
+        render_mesh_instances
+            .get(&MainEntity::from(entity))
+            .map(|render_mesh_instance| render_mesh_instance.current_uniform_index)
+    } else {
+        None
+    };
+
+    let gpu_mesh_instance_builder = RenderMeshInstanceGpuBuilder {
+        shared,
+        world_from_local: (&transform.affine()).into(),
+        lightmap_uv_rect,
+        mesh_flags,
+        previous_input_index,
+        motion_vector_scale: motion_blur_scale.map_or(1.0, |scale| scale.0),
+        clip_plane,
+    };
+
+    queue.push(
+        entity.into(),
+        gpu_mesh_instance_builder,
+        gpu_mesh_culling_data,
+    );
 }
 
 /// A system that sets the [`RenderMeshInstanceFlags`] for each mesh based on
@@ -1673,6 +1863,7 @@ impl GetBatchData for MeshPipeline {
                 maybe_lightmap.map(|lightmap| (lightmap.slot_index, lightmap.uv_rect)),
                 current_skin_index,
                 previous_skin_index,
+                main_entity,
             ),
             mesh_instance.should_batch().then_some((
                 material_bind_group_index.group,
@@ -1740,6 +1931,7 @@ impl GetFullBatchData for MeshPipeline {
             maybe_lightmap.map(|lightmap| (lightmap.slot_index, lightmap.uv_rect)),
             current_skin_index,
             previous_skin_index,
+            main_entity,
         ))
     }
 
@@ -1827,7 +2019,9 @@ bitflags::bitflags! {
         const HAS_PREVIOUS_MORPH                = 1 << 19;
         const OIT_ENABLED                       = 1 << 20;
         const DISTANCE_FOG                      = 1 << 21;
-        const LAST_FLAG                         = Self::DISTANCE_FOG.bits();
+        const CLIP_PLANES                       = 1 << 22; // The view or the mesh has `ClipPlane`s to discard fragments against
+        const ENTITY_ID_PREPASS                 = 1 << 23;
+        const LAST_FLAG                         = Self::ENTITY_ID_PREPASS.bits();
 
         // Bitfields
         const MSAA_RESERVED_BITS                = Self::MSAA_MASK_BITS << Self::MSAA_SHIFT_BITS;
@@ -2293,6 +2487,10 @@ impl SpecializedMeshPipeline for MeshPipeline {
             shader_defs.push("DISTANCE_FOG".into());
         }
 
+        if key.contains(MeshPipelineKey::CLIP_PLANES) {
+            shader_defs.push("CLIP_PLANES".into());
+        }
+
         if self.binding_arrays_are_usable {
             shader_defs.push("MULTIPLE_LIGHT_PROBES_IN_ARRAY".into());
             shader_defs.push("MULTIPLE_LIGHTMAPS_IN_ARRAY".into());
@@ -2899,11 +3097,105 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh {
 
 #[cfg(test)]
 mod tests {
-    use super::MeshPipelineKey;
+    use super::{
+        extract_meshes_for_gpu_building, MeshFlags, MeshPipelineKey, RenderMeshInstanceGpuQueue,
+        RenderMeshInstanceGpuQueues, RenderMeshInstances,
+    };
+    use crate::ShadowBias;
+    use bevy_ecs::{entity::Entity, system::IntoSystem, system::System, world::World};
+    use bevy_render::{
+        mesh::Mesh3d,
+        view::{RenderVisibilityRanges, ViewVisibility},
+        MainWorld,
+    };
+    use bevy_transform::components::GlobalTransform;
+
+    /// Returns the mesh flag bits of every mesh queued by
+    /// [`extract_meshes_for_gpu_building`].
+    fn queued_mesh_flags(render_world: &mut World) -> Vec<(Entity, u32)> {
+        let mut queued = vec![];
+        for queue in render_world
+            .resource_mut::<RenderMeshInstanceGpuQueues>()
+            .iter_mut()
+        {
+            if let RenderMeshInstanceGpuQueue::CpuCulling { changed, .. } = queue {
+                queued.extend(
+                    changed
+                        .iter()
+                        .map(|(entity, builder)| (entity.id(), builder.mesh_flags.bits())),
+                );
+            }
+        }
+        queued
+    }
+
     #[test]
     fn mesh_key_msaa_samples() {
         for i in [1, 2, 4, 8, 16, 32, 64, 128] {
             assert_eq!(MeshPipelineKey::from_msaa_samples(i).msaa_samples(), i);
         }
     }
+
+    #[test]
+    fn shadow_bias_scale_packing() {
+        // A scale of 1.0 must pack to zero so meshes without `ShadowBias` are unaffected.
+        assert_eq!(MeshFlags::pack_shadow_bias_scale(1.0), 0);
+        for scale in [0.0, 0.5, 1.0, 2.25, 3.9375] {
+            let packed = MeshFlags::pack_shadow_bias_scale(scale);
+            // Mirrors `mesh_shadow_bias_scale` in `shadows.wgsl`.
+            let unpacked = ((packed + 16) & 0b111111) as f32 / 16.0;
+            assert_eq!(unpacked, scale);
+        }
+    }
+
+    #[test]
+    fn removing_shadow_bias_re_extracts_mesh() {
+        let bias_mask = (MeshFlags::SHADOW_DEPTH_BIAS_SCALE_MASK
+            | MeshFlags::SHADOW_NORMAL_BIAS_SCALE_MASK)
+            .bits();
+
+        let mut render_world = World::new();
+        render_world.init_resource::<MainWorld>();
+        render_world.init_resource::<RenderVisibilityRanges>();
+        render_world.init_resource::<RenderMeshInstanceGpuQueues>();
+        render_world.insert_resource(RenderMeshInstances::GpuBuilding(Default::default()));
+
+        let mut view_visibility = ViewVisibility::HIDDEN;
+        view_visibility.set();
+        let entity = render_world
+            .resource_mut::<MainWorld>()
+            .spawn((
+                view_visibility,
+                GlobalTransform::default(),
+                Mesh3d::default(),
+                ShadowBias {
+                    depth_bias_scale: 2.0,
+                    normal_bias_scale: 0.5,
+                },
+            ))
+            .id();
+
+        let mut system = IntoSystem::into_system(extract_meshes_for_gpu_building);
+        system.initialize(&mut render_world);
+
+        system.run((), &mut render_world);
+        let queued = queued_mesh_flags(&mut render_world);
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].0, entity);
+        assert_ne!(queued[0].1 & bias_mask, 0);
+
+        // Nothing changed, so nothing is queued.
+        system.run((), &mut render_world);
+        assert!(queued_mesh_flags(&mut render_world).is_empty());
+
+        render_world
+            .resource_mut::<MainWorld>()
+            .entity_mut(entity)
+            .remove::<ShadowBias>();
+        system.run((), &mut render_world);
+        let queued = queued_mesh_flags(&mut render_world);
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].0, entity);
+        assert_eq!(queued[0].1 & bias_mask, 0);
+    }
 }
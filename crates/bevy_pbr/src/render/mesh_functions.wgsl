@@ -132,3 +132,9 @@ fn get_visibility_range_dither_level(instance_index: u32, world_position: vec4<f
     return offset + clamp(level, 0, 16);
 }
 #endif
+
+// Returns true if the world space position is on the clipped side of the mesh's clip plane.
+fn is_clipped_by_mesh_plane(instance_index: u32, world_position: vec3<f32>) -> bool {
+    let plane = mesh[instance_index].clip_plane;
+    return dot(plane.xyz, world_position) + plane.w < 0.0;
+}
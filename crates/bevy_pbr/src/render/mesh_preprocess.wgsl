@@ -71,6 +71,23 @@ struct LatePreprocessWorkItemIndirectParameters {
     pad: vec4<u32>,
 }
 
+#ifdef GPU_CULLING_STATISTICS
+// Counters that the `GpuCullingStatisticsPlugin` reads back to report how many
+// instances were culled in each view.
+struct GpuCullingStatistics {
+    // The number of instances that entered mesh preprocessing.
+    submitted: atomic<u32>,
+    // The number of instances that were culled because they were outside the
+    // view frustum.
+    frustum_culled: atomic<u32>,
+    // The number of instances that were culled because they were occluded in
+    // both the early and the late phase.
+    occlusion_culled: atomic<u32>,
+    // Padding.
+    pad: u32,
+}
+#endif  // GPU_CULLING_STATISTICS
+
 // These have to be in a structure because of Naga limitations on DX12.
 struct PushConstants {
     // The offset into the `late_preprocess_work_item_indirect_parameters`
@@ -116,6 +133,11 @@ struct PushConstants {
 var<push_constant> push_constants: PushConstants;
 #endif  // OCCLUSION_CULLING
 
+#ifdef GPU_CULLING_STATISTICS
+// The culling statistics for the current view.
+@group(0) @binding(13) var<storage, read_write> culling_statistics: GpuCullingStatistics;
+#endif  // GPU_CULLING_STATISTICS
+
 #ifdef FRUSTUM_CULLING
 // Returns true if the view frustum intersects an oriented bounding box (OBB).
 //
@@ -167,6 +189,11 @@ fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
     if (instance_index >= arrayLength(&work_items)) {
         return;
     }
+
+#ifdef GPU_CULLING_STATISTICS
+    // Work items in the late phase were already counted in the early phase.
+    atomicAdd(&culling_statistics.submitted, 1u);
+#endif  // GPU_CULLING_STATISTICS
 #endif
 
     // Unpack the work item.
@@ -187,6 +214,9 @@ fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
         // Do an OBB-based frustum cull.
         let model_center = world_from_local * vec4(aabb_center, 1.0);
         if (!view_frustum_intersects_obb(world_from_local, model_center, aabb_half_extents)) {
+#ifdef GPU_CULLING_STATISTICS
+            atomicAdd(&culling_statistics.frustum_culled, 1u);
+#endif  // GPU_CULLING_STATISTICS
             return;
         }
     }
@@ -292,6 +322,12 @@ fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
         late_preprocess_work_items[output_work_item_index].output_index = output_index;
         late_preprocess_work_items[output_work_item_index].indirect_parameters_index =
             indirect_parameters_index;
+#else   // EARLY_PHASE
+#ifdef GPU_CULLING_STATISTICS
+        // Only count meshes that are still occluded in the late phase, as those
+        // culled in the early phase get another chance here.
+        atomicAdd(&culling_statistics.occlusion_culled, 1u);
+#endif  // GPU_CULLING_STATISTICS
 #endif  // EARLY_PHASE
         // This mesh is culled. Skip it.
         return;
@@ -332,10 +368,17 @@ fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
     let mesh_output_index = output_index;
 #endif  // INDIRECT
 
+    // Scale the motion vectors by moving the previous transform towards the
+    // current one. Occlusion culling above still uses the unscaled transform.
+    let motion_vector_scale = current_input[input_index].motion_vector_scale;
+    let scaled_previous_world_from_local_affine_transpose = world_from_local_affine_transpose +
+        (previous_world_from_local_affine_transpose - world_from_local_affine_transpose) *
+        motion_vector_scale;
+
     // Write the output.
     output[mesh_output_index].world_from_local = world_from_local_affine_transpose;
     output[mesh_output_index].previous_world_from_local =
-        previous_world_from_local_affine_transpose;
+        scaled_previous_world_from_local_affine_transpose;
     output[mesh_output_index].local_from_world_transpose_a = local_from_world_transpose_a;
     output[mesh_output_index].local_from_world_transpose_b = local_from_world_transpose_b;
     output[mesh_output_index].flags = current_input[input_index].flags;
@@ -345,4 +388,6 @@ fn main(@builtin(global_invocation_id) global_invocation_id: vec3<u32>) {
     output[mesh_output_index].previous_skin_index = current_input[input_index].previous_skin_index;
     output[mesh_output_index].material_and_lightmap_bind_group_slot =
         current_input[input_index].material_and_lightmap_bind_group_slot;
+    output[mesh_output_index].main_entity = current_input[input_index].main_entity;
+    output[mesh_output_index].clip_plane = current_input[input_index].clip_plane;
 }
@@ -22,6 +22,11 @@ struct Mesh {
     // Low 16 bits: index of the material inside the bind group data.
     // High 16 bits: index of the lightmap in the binding array.
     material_and_lightmap_bind_group_slot: u32,
+    // The bits of the main world entity, low 32 bits first.
+    main_entity: vec2<u32>,
+    // The world-space half space kept by the mesh's clip plane, in the same
+    // form as the view's `clip_planes`. `(0, 0, 0, 1)` keeps every point.
+    clip_plane: vec4<f32>,
 };
 
 #ifdef SKINNED
@@ -38,6 +43,11 @@ struct MorphWeights {
 
 // [2^0, 2^16)
 const MESH_FLAGS_VISIBILITY_RANGE_INDEX_BITS: u32 = 65535u;
+// [2^16, 2^22)
+const MESH_FLAGS_SHADOW_DEPTH_BIAS_SCALE_SHIFT: u32 = 16u;
+// [2^22, 2^28)
+const MESH_FLAGS_SHADOW_NORMAL_BIAS_SCALE_SHIFT: u32 = 22u;
+const MESH_FLAGS_SHADOW_BIAS_SCALE_BITS: u32 = 63u;
 // 2^28
 const MESH_FLAGS_NO_FRUSTUM_CULLING_BIT: u32 = 268435456u;
 // 2^29
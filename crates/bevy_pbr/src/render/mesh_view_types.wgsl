@@ -10,17 +10,26 @@ struct ClusterableObject {
     flags: u32,
     shadow_depth_bias: f32,
     shadow_normal_bias: f32,
+    // For spot lights: the tangent of the outer angle
     spot_light_tan_angle: f32,
     soft_shadow_size: f32,
     shadow_map_near_z: f32,
-    texture_index: u32,
-    pad: f32,
+    contact_shadow_length: f32,
+    contact_shadow_thickness: f32,
+    // For point lights: the cube of the point light shadow map texture that holds the shadow map
+    shadow_atlas_layer: u32,
+    // For point lights: the fraction of each cube face that the shadow map occupies
+    shadow_atlas_scale: f32,
+    // For point lights: the center of the shadow map's square on each cube face, in face
+    // coordinates from -1 to 1
+    shadow_atlas_center: vec2<f32>,
 };
 
 const POINT_LIGHT_FLAGS_SHADOWS_ENABLED_BIT: u32                    = 1u;
 const POINT_LIGHT_FLAGS_SPOT_LIGHT_Y_NEGATIVE: u32                  = 2u;
 const POINT_LIGHT_FLAGS_VOLUMETRIC_BIT: u32                         = 4u;
 const POINT_LIGHT_FLAGS_AFFECTS_LIGHTMAPPED_MESH_DIFFUSE_BIT: u32   = 8u;
+const POINT_LIGHT_FLAGS_CONTACT_SHADOWS_BIT: u32                    = 16u;
 
 struct DirectionalCascade {
     clip_from_world: mat4x4<f32>,
@@ -41,11 +50,14 @@ struct DirectionalLight {
     cascades_overlap_proportion: f32,
     depth_texture_base_index: u32,
     skip: u32,
+    contact_shadow_length: f32,
+    contact_shadow_thickness: f32,
 };
 
 const DIRECTIONAL_LIGHT_FLAGS_SHADOWS_ENABLED_BIT: u32                  = 1u;
 const DIRECTIONAL_LIGHT_FLAGS_VOLUMETRIC_BIT: u32                       = 2u;
 const DIRECTIONAL_LIGHT_FLAGS_AFFECTS_LIGHTMAPPED_MESH_DIFFUSE_BIT: u32 = 4u;
+const DIRECTIONAL_LIGHT_FLAGS_CONTACT_SHADOWS_BIT: u32                  = 8u;
 
 struct Lights {
     // NOTE: this array size must be kept in sync with the constants defined in bevy_pbr/src/render/light.rs
@@ -55,9 +67,13 @@ struct Lights {
     cluster_dimensions: vec4<u32>,
     // xy are vec2<f32>(cluster_dimensions.xy) / vec2<f32>(view.width, view.height)
     //
-    // For perspective projections:
-    // z is cluster_dimensions.z / log(far / near)
-    // w is cluster_dimensions.z * log(near) / log(far / near)
+    // For perspective projections with exponential depth slicing:
+    // z is (cluster_dimensions.z - 1) / log(far / near)
+    // w is (cluster_dimensions.z - 1) * log(near) / log(far / near)
+    //
+    // For perspective projections with linear depth slicing:
+    // z is -(cluster_dimensions.z - 1) / (far - near)
+    // w is near
     //
     // For orthographic projections:
     // NOTE: near and far are +ve but -z is infront of the camera
@@ -107,7 +123,7 @@ struct ClusterOffsetsAndCounts {
 };
 #else
 struct ClusterableObjects {
-    data: array<ClusterableObject, 204u>,
+    data: array<ClusterableObject, 170u>,
 };
 struct ClusterLightIndexLists {
     // each u32 contains 4 u8 indices into the ClusterableObjects array
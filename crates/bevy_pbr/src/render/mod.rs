@@ -1,4 +1,5 @@
 mod fog;
+mod gpu_culling_statistics;
 mod gpu_preprocess;
 mod light;
 pub(crate) mod mesh;
@@ -8,6 +9,7 @@ mod morph;
 pub(crate) mod skin;
 
 pub use fog::*;
+pub use gpu_culling_statistics::*;
 pub use gpu_preprocess::*;
 pub use light::*;
 pub use mesh::*;
@@ -3,6 +3,8 @@
     pbr_functions::alpha_discard,
     pbr_fragment::pbr_input_from_standard_material,
     decal::clustered::apply_decal_base_color,
+    mesh_functions::is_clipped_by_mesh_plane,
+    view_transformations::is_clipped_by_view_planes,
 }
 
 #ifdef PREPASS_PIPELINE
@@ -53,6 +55,15 @@ fn fragment(
     pbr_functions::visibility_range_dither(in.position, in.visibility_range_dither);
 #endif
 
+    // Discard the fragment if it's on the clipped side of any of the view's or the mesh's clip
+    // planes.
+#ifdef CLIP_PLANES
+    if is_clipped_by_view_planes(in.world_position.xyz)
+        || is_clipped_by_mesh_plane(in.instance_index, in.world_position.xyz) {
+        discard;
+    }
+#endif
+
 #ifdef FORWARD_DECAL
     let forward_decal_info = get_forward_decal_info(in);
     in.world_position = forward_decal_info.world_position;
@@ -400,6 +400,8 @@ fn apply_pbr_lighting(
     var clusterable_object_index_ranges =
         clustering::unpack_clusterable_object_index_ranges(cluster_index);
 
+    let shadow_bias_scale = shadows::mesh_shadow_bias_scale(in.flags);
+
     // Point lights (direct)
     for (var i: u32 = clusterable_object_index_ranges.first_point_light_index_offset;
             i < clusterable_object_index_ranges.first_spot_light_index_offset;
@@ -419,7 +421,19 @@ fn apply_pbr_lighting(
         var shadow: f32 = 1.0;
         if ((in.flags & MESH_FLAGS_SHADOW_RECEIVER_BIT) != 0u
                 && (view_bindings::clusterable_objects.data[light_id].flags & mesh_view_types::POINT_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
-            shadow = shadows::fetch_point_shadow(light_id, in.world_position, in.world_normal);
+            shadow = shadows::fetch_point_shadow(
+                light_id, in.world_position, in.world_normal, shadow_bias_scale);
+        }
+        if ((in.flags & MESH_FLAGS_SHADOW_RECEIVER_BIT) != 0u
+                && (view_bindings::clusterable_objects.data[light_id].flags &
+                    mesh_view_types::POINT_LIGHT_FLAGS_CONTACT_SHADOWS_BIT) != 0u) {
+            let light = &view_bindings::clusterable_objects.data[light_id];
+            shadow *= shadows::fetch_contact_shadow(
+                in.world_position,
+                normalize((*light).position_radius.xyz - in.world_position.xyz),
+                (*light).contact_shadow_length,
+                (*light).contact_shadow_thickness,
+            );
         }
 
         let light_contrib = lighting::point_light(light_id, &lighting_input, enable_diffuse);
@@ -438,7 +452,12 @@ fn apply_pbr_lighting(
         var transmitted_shadow: f32 = 1.0;
         if ((in.flags & (MESH_FLAGS_SHADOW_RECEIVER_BIT | MESH_FLAGS_TRANSMITTED_SHADOW_RECEIVER_BIT)) == (MESH_FLAGS_SHADOW_RECEIVER_BIT | MESH_FLAGS_TRANSMITTED_SHADOW_RECEIVER_BIT)
                 && (view_bindings::clusterable_objects.data[light_id].flags & mesh_view_types::POINT_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
-            transmitted_shadow = shadows::fetch_point_shadow(light_id, diffuse_transmissive_lobe_world_position, -in.world_normal);
+            transmitted_shadow = shadows::fetch_point_shadow(
+                light_id,
+                diffuse_transmissive_lobe_world_position,
+                -in.world_normal,
+                shadow_bias_scale,
+            );
         }
 
         let transmitted_light_contrib =
@@ -472,6 +491,18 @@ fn apply_pbr_lighting(
                 in.world_position,
                 in.world_normal,
                 view_bindings::clusterable_objects.data[light_id].shadow_map_near_z,
+                shadow_bias_scale,
+            );
+        }
+        if ((in.flags & MESH_FLAGS_SHADOW_RECEIVER_BIT) != 0u
+                && (view_bindings::clusterable_objects.data[light_id].flags &
+                    mesh_view_types::POINT_LIGHT_FLAGS_CONTACT_SHADOWS_BIT) != 0u) {
+            let light = &view_bindings::clusterable_objects.data[light_id];
+            shadow *= shadows::fetch_contact_shadow(
+                in.world_position,
+                normalize((*light).position_radius.xyz - in.world_position.xyz),
+                (*light).contact_shadow_length,
+                (*light).contact_shadow_thickness,
             );
         }
 
@@ -496,6 +527,7 @@ fn apply_pbr_lighting(
                 diffuse_transmissive_lobe_world_position,
                 -in.world_normal,
                 view_bindings::clusterable_objects.data[light_id].shadow_map_near_z,
+                shadow_bias_scale,
             );
         }
 
@@ -529,7 +561,17 @@ fn apply_pbr_lighting(
         var shadow: f32 = 1.0;
         if ((in.flags & MESH_FLAGS_SHADOW_RECEIVER_BIT) != 0u
                 && (view_bindings::lights.directional_lights[i].flags & mesh_view_types::DIRECTIONAL_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
-            shadow = shadows::fetch_directional_shadow(i, in.world_position, in.world_normal, view_z);
+            shadow = shadows::fetch_directional_shadow(
+                i, in.world_position, in.world_normal, view_z, shadow_bias_scale);
+        }
+        if ((in.flags & MESH_FLAGS_SHADOW_RECEIVER_BIT) != 0u
+                && ((*light).flags & mesh_view_types::DIRECTIONAL_LIGHT_FLAGS_CONTACT_SHADOWS_BIT) != 0u) {
+            shadow *= shadows::fetch_contact_shadow(
+                in.world_position,
+                (*light).direction_to_light,
+                (*light).contact_shadow_length,
+                (*light).contact_shadow_thickness,
+            );
         }
 
         var light_contrib = lighting::directional_light(i, &lighting_input, enable_diffuse);
@@ -552,7 +594,13 @@ fn apply_pbr_lighting(
         var transmitted_shadow: f32 = 1.0;
         if ((in.flags & (MESH_FLAGS_SHADOW_RECEIVER_BIT | MESH_FLAGS_TRANSMITTED_SHADOW_RECEIVER_BIT)) == (MESH_FLAGS_SHADOW_RECEIVER_BIT | MESH_FLAGS_TRANSMITTED_SHADOW_RECEIVER_BIT)
                 && (view_bindings::lights.directional_lights[i].flags & mesh_view_types::DIRECTIONAL_LIGHT_FLAGS_SHADOWS_ENABLED_BIT) != 0u) {
-            transmitted_shadow = shadows::fetch_directional_shadow(i, diffuse_transmissive_lobe_world_position, -in.world_normal, view_z);
+            transmitted_shadow = shadows::fetch_directional_shadow(
+                i,
+                diffuse_transmissive_lobe_world_position,
+                -in.world_normal,
+                view_z,
+                shadow_bias_scale,
+            );
         }
 
         let transmitted_light_contrib =
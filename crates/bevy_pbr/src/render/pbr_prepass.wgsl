@@ -7,7 +7,9 @@
     pbr_functions::SampleBias,
     prepass_io,
     mesh_bindings::mesh,
+    mesh_functions::is_clipped_by_mesh_plane,
     mesh_view_bindings::view,
+    view_transformations::is_clipped_by_view_planes,
 }
 
 #ifdef MESHLET_MESH_MATERIAL_PASS
@@ -44,6 +46,13 @@ fn fragment(
     pbr_functions::visibility_range_dither(in.position, in.visibility_range_dither);
 #endif  // VISIBILITY_RANGE_DITHER
 
+#ifdef CLIP_PLANES
+    if is_clipped_by_view_planes(in.world_position.xyz)
+        || is_clipped_by_mesh_plane(in.instance_index, in.world_position.xyz) {
+        discard;
+    }
+#endif  // CLIP_PLANES
+
     pbr_prepass_functions::prepass_alpha_discard(in);
 #endif  // MESHLET_MESH_MATERIAL_PASS
 
@@ -135,11 +144,22 @@ fn fragment(
 #endif
 #endif
 
+#ifdef ENTITY_ID_PREPASS
+    out.entity_id = mesh[in.instance_index].main_entity;
+#endif
+
     return out;
 }
 #else
 @fragment
 fn fragment(in: prepass_io::VertexOutput) {
+#ifdef CLIP_PLANES
+    if is_clipped_by_view_planes(in.world_position.xyz)
+        || is_clipped_by_mesh_plane(in.instance_index, in.world_position.xyz) {
+        discard;
+    }
+#endif  // CLIP_PLANES
+
     pbr_prepass_functions::prepass_alpha_discard(in);
 }
 #endif // PREPASS_FRAGMENT
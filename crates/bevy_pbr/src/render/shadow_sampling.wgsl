@@ -309,25 +309,85 @@ fn sample_shadow_map_pcss(
 #endif  // SHADOW_FILTER_METHOD_TEMPORAL
 }
 
+// Point lights that were given less than the full shadow map resolution share
+// cubes of the shadow map texture, each rendering into the same square of all
+// six faces. This remaps a cubemap lookup direction so that it lands in the
+// light's square, by remapping the two minor axes (the face coordinates) while
+// keeping the major axis (the face) unchanged.
+//
+// The face coordinates are clamped to stay half a texel inside the square, so
+// that filtering never reads texels of a neighboring light's square. PCF and
+// PCSS remap each of their samples separately, so this also holds for them
+// regardless of the filter radius.
+fn point_shadow_lookup_direction(light_local: vec3<f32>, light_id: u32) -> vec3<f32> {
+    let light = &view_bindings::clusterable_objects.data[light_id];
+    let face_scale = (*light).shadow_atlas_scale;
+
+    // Shadow maps that fill whole cubes don't share them, and clamping would
+    // break filtering across the cube's edges.
+    if (face_scale >= 1.0) {
+        return light_local;
+    }
+
+    // Find the face, and the directions in which its texture coordinates
+    // increase, following the cubemap face selection rules.
+    let abs_light_local = abs(light_local);
+    let major_axis_magnitude = max(abs_light_local.x, max(abs_light_local.y, abs_light_local.z));
+    var major_axis: vec3<f32>;
+    var s_axis: vec3<f32>;
+    var t_axis: vec3<f32>;
+    if (abs_light_local.x >= major_axis_magnitude) {
+        major_axis = vec3(light_local.x, 0.0, 0.0);
+        s_axis = vec3(0.0, 0.0, -sign(light_local.x));
+        t_axis = vec3(0.0, -1.0, 0.0);
+    } else if (abs_light_local.y >= major_axis_magnitude) {
+        major_axis = vec3(0.0, light_local.y, 0.0);
+        s_axis = vec3(1.0, 0.0, 0.0);
+        t_axis = vec3(0.0, 0.0, sign(light_local.y));
+    } else {
+        major_axis = vec3(0.0, 0.0, light_local.z);
+        s_axis = vec3(sign(light_local.z), 0.0, 0.0);
+        t_axis = vec3(0.0, -1.0, 0.0);
+    }
+
+    // Clamp the face coordinates (in -1..1) to the inset square, scale them
+    // down to the size of the square, and move them to the square's center.
+    let square_texels = face_scale * f32(textureDimensions(view_bindings::point_shadow_textures).x);
+    let face_coords = clamp(
+        vec2(dot(light_local, s_axis), dot(light_local, t_axis)) / major_axis_magnitude,
+        vec2(-1.0 + 1.0 / square_texels),
+        vec2(1.0 - 1.0 / square_texels),
+    );
+    let atlas_coords = face_coords * face_scale + (*light).shadow_atlas_center;
+    return major_axis + (atlas_coords.x * s_axis + atlas_coords.y * t_axis) * major_axis_magnitude;
+}
+
+// Returns the cube of the point light shadow map texture that the light's
+// shadow map is stored in.
+fn point_shadow_atlas_layer(light_id: u32) -> i32 {
+    return i32(view_bindings::clusterable_objects.data[light_id].shadow_atlas_layer);
+}
+
 // NOTE: Due to the non-uniform control flow in `shadows::fetch_point_shadow`,
 // we must use the Level variant of textureSampleCompare to avoid undefined
 // behavior due to some of the fragments in a quad (2x2 fragments) being
 // processed not being sampled, and this messing with mip-mapping functionality.
 // The shadow maps have no mipmaps so Level just samples from LOD 0.
 fn sample_shadow_cubemap_hardware(light_local: vec3<f32>, depth: f32, light_id: u32) -> f32 {
+    let lookup_direction = point_shadow_lookup_direction(light_local, light_id);
 #ifdef NO_CUBE_ARRAY_TEXTURES_SUPPORT
     return textureSampleCompare(
         view_bindings::point_shadow_textures,
         view_bindings::point_shadow_textures_comparison_sampler,
-        light_local,
+        lookup_direction,
         depth
     );
 #else
     return textureSampleCompareLevel(
         view_bindings::point_shadow_textures,
         view_bindings::point_shadow_textures_comparison_sampler,
-        light_local,
-        i32(light_id),
+        lookup_direction,
+        point_shadow_atlas_layer(light_id),
         depth
     );
 #endif
@@ -348,18 +408,19 @@ fn search_for_blockers_in_shadow_cubemap_hardware(
 
 #ifdef PCSS_SAMPLERS_AVAILABLE
 
+    let lookup_direction = point_shadow_lookup_direction(light_local, light_id);
 #ifdef NO_CUBE_ARRAY_TEXTURES_SUPPORT
     let sampled_depth = textureSample(
         view_bindings::point_shadow_textures,
         view_bindings::point_shadow_textures_linear_sampler,
-        light_local,
+        lookup_direction,
     );
 #else
     let sampled_depth = textureSample(
         view_bindings::point_shadow_textures,
         view_bindings::point_shadow_textures_linear_sampler,
-        light_local,
-        i32(light_id),
+        lookup_direction,
+        point_shadow_atlas_layer(light_id),
     );
 #endif
 
@@ -1,14 +1,23 @@
 #define_import_path bevy_pbr::shadows
 
 #import bevy_pbr::{
+    mesh_types::{
+        MESH_FLAGS_SHADOW_BIAS_SCALE_BITS, MESH_FLAGS_SHADOW_DEPTH_BIAS_SCALE_SHIFT,
+        MESH_FLAGS_SHADOW_NORMAL_BIAS_SCALE_SHIFT,
+    },
     mesh_view_types::POINT_LIGHT_FLAGS_SPOT_LIGHT_Y_NEGATIVE,
     mesh_view_bindings as view_bindings,
     shadow_sampling::{
         SPOT_SHADOW_TEXEL_SIZE, sample_shadow_cubemap, sample_shadow_cubemap_pcss,
         sample_shadow_map, sample_shadow_map_pcss,
-    }
+    },
+    view_transformations::{depth_ndc_to_view_z, ndc_to_frag_coord, position_world_to_ndc},
 }
 
+#ifdef DEPTH_PREPASS
+#import bevy_pbr::prepass_utils
+#endif
+
 #import bevy_render::{
     color_operations::hsv_to_rgb,
     maths::PI_2
@@ -16,7 +25,28 @@
 
 const flip_z: vec3<f32> = vec3<f32>(1.0, 1.0, -1.0);
 
-fn fetch_point_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: vec3<f32>) -> f32 {
+// The number of depth samples taken along the ray when computing contact shadows.
+const CONTACT_SHADOW_STEPS: u32 = 8u;
+
+// Unpacks the scales that `ShadowBias` applies to the light's depth bias (x)
+// and normal bias (y) from the mesh flags.
+//
+// This must be kept in sync with `MeshFlags::pack_shadow_bias_scale`.
+fn mesh_shadow_bias_scale(mesh_flags: u32) -> vec2<f32> {
+    let packed = vec2<u32>(
+        (mesh_flags >> MESH_FLAGS_SHADOW_DEPTH_BIAS_SCALE_SHIFT) & MESH_FLAGS_SHADOW_BIAS_SCALE_BITS,
+        (mesh_flags >> MESH_FLAGS_SHADOW_NORMAL_BIAS_SCALE_SHIFT) & MESH_FLAGS_SHADOW_BIAS_SCALE_BITS,
+    );
+    // All bits being zero represents a scale of 1.0.
+    return vec2<f32>((packed + vec2(16u)) & vec2(MESH_FLAGS_SHADOW_BIAS_SCALE_BITS)) / 16.0;
+}
+
+fn fetch_point_shadow(
+    light_id: u32,
+    frag_position: vec4<f32>,
+    surface_normal: vec3<f32>,
+    bias_scale: vec2<f32>,
+) -> f32 {
     let light = &view_bindings::clusterable_objects.data[light_id];
 
     // because the shadow maps align with the axes and the frustum planes are at 45 degrees
@@ -28,8 +58,11 @@ fn fetch_point_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: v
     // The normal bias here is already scaled by the texel size at 1 world unit from the light.
     // The texel size increases proportionally with distance from the light so multiplying by
     // distance to light scales the normal bias to the texel size at the fragment distance.
-    let normal_offset = (*light).shadow_normal_bias * distance_to_light * surface_normal.xyz;
-    let depth_offset = (*light).shadow_depth_bias * normalize(surface_to_light.xyz);
+    // Lights that were given a lower shadow map resolution have proportionally larger texels.
+    let face_scale = (*light).shadow_atlas_scale;
+    let normal_offset = (*light).shadow_normal_bias * bias_scale.y * distance_to_light
+        * surface_normal.xyz / face_scale;
+    let depth_offset = (*light).shadow_depth_bias * bias_scale.x * normalize(surface_to_light.xyz);
     let offset_position = frag_position.xyz + normal_offset + depth_offset;
 
     // similar largest-absolute-axis trick as above, but now with the offset fragment position
@@ -67,6 +100,7 @@ fn fetch_spot_shadow(
     frag_position: vec4<f32>,
     surface_normal: vec3<f32>,
     near_z: f32,
+    bias_scale: vec2<f32>,
 ) -> f32 {
     let light = &view_bindings::clusterable_objects.data[light_id];
 
@@ -85,8 +119,8 @@ fn fetch_spot_shadow(
     let distance_to_light = dot(fwd, surface_to_light);
     let offset_position =
         -surface_to_light
-        + ((*light).shadow_depth_bias * normalize(surface_to_light))
-        + (surface_normal.xyz * (*light).shadow_normal_bias) * distance_to_light;
+        + ((*light).shadow_depth_bias * bias_scale.x * normalize(surface_to_light))
+        + (surface_normal.xyz * (*light).shadow_normal_bias * bias_scale.y) * distance_to_light;
 
     // the construction of the up and right vectors needs to precisely mirror the code
     // in render/light.rs:spot_light_view_matrix
@@ -173,13 +207,15 @@ fn sample_directional_cascade(
     cascade_index: u32,
     frag_position: vec4<f32>,
     surface_normal: vec3<f32>,
+    bias_scale: vec2<f32>,
 ) -> f32 {
     let light = &view_bindings::lights.directional_lights[light_id];
     let cascade = &(*light).cascades[cascade_index];
 
     // The normal bias is scaled to the texel size.
-    let normal_offset = (*light).shadow_normal_bias * (*cascade).texel_size * surface_normal.xyz;
-    let depth_offset = (*light).shadow_depth_bias * (*light).direction_to_light.xyz;
+    let normal_offset =
+        (*light).shadow_normal_bias * bias_scale.y * (*cascade).texel_size * surface_normal.xyz;
+    let depth_offset = (*light).shadow_depth_bias * bias_scale.x * (*light).direction_to_light.xyz;
     let offset_position = vec4<f32>(frag_position.xyz + normal_offset + depth_offset, frag_position.w);
 
     let light_local = world_to_directional_light_local(light_id, cascade_index, offset_position);
@@ -199,7 +235,13 @@ fn sample_directional_cascade(
     return sample_shadow_map(light_local.xy, light_local.z, array_index, texel_size);
 }
 
-fn fetch_directional_shadow(light_id: u32, frag_position: vec4<f32>, surface_normal: vec3<f32>, view_z: f32) -> f32 {
+fn fetch_directional_shadow(
+    light_id: u32,
+    frag_position: vec4<f32>,
+    surface_normal: vec3<f32>,
+    view_z: f32,
+    bias_scale: vec2<f32>,
+) -> f32 {
     let light = &view_bindings::lights.directional_lights[light_id];
     let cascade_index = get_cascade_index(light_id, view_z);
 
@@ -207,7 +249,8 @@ fn fetch_directional_shadow(light_id: u32, frag_position: vec4<f32>, surface_nor
         return 1.0;
     }
 
-    var shadow = sample_directional_cascade(light_id, cascade_index, frag_position, surface_normal);
+    var shadow = sample_directional_cascade(
+        light_id, cascade_index, frag_position, surface_normal, bias_scale);
 
     // Blend with the next cascade, if there is one.
     let next_cascade_index = cascade_index + 1u;
@@ -215,13 +258,47 @@ fn fetch_directional_shadow(light_id: u32, frag_position: vec4<f32>, surface_nor
         let this_far_bound = (*light).cascades[cascade_index].far_bound;
         let next_near_bound = (1.0 - (*light).cascades_overlap_proportion) * this_far_bound;
         if (-view_z >= next_near_bound) {
-            let next_shadow = sample_directional_cascade(light_id, next_cascade_index, frag_position, surface_normal);
+            let next_shadow = sample_directional_cascade(
+                light_id, next_cascade_index, frag_position, surface_normal, bias_scale);
             shadow = mix(shadow, next_shadow, (-view_z - next_near_bound) / (this_far_bound - next_near_bound));
         }
     }
     return shadow;
 }
 
+// Marches a short ray from the fragment towards the light through the depth
+// prepass, returning 0.0 if on-screen geometry occludes it and 1.0 otherwise.
+//
+// Without a depth prepass there's nothing to march against, so this always
+// returns 1.0.
+fn fetch_contact_shadow(
+    frag_position: vec4<f32>,
+    direction_to_light: vec3<f32>,
+    length: f32,
+    thickness: f32,
+) -> f32 {
+#ifdef DEPTH_PREPASS
+    let ray_step = direction_to_light * (length / f32(CONTACT_SHADOW_STEPS));
+    var ray_position = frag_position.xyz;
+    for (var i: u32 = 0u; i < CONTACT_SHADOW_STEPS; i = i + 1u) {
+        ray_position += ray_step;
+
+        let ray_ndc = position_world_to_ndc(ray_position);
+        if (any(abs(ray_ndc.xy) > vec2(1.0))) {
+            return 1.0;
+        }
+
+        let scene_depth = prepass_utils::prepass_depth(vec4(ndc_to_frag_coord(ray_ndc.xy), 0.0, 0.0), 0u);
+        // -z is forward, so the scene is in front of the ray when its view z is greater.
+        let depth_delta = depth_ndc_to_view_z(scene_depth) - depth_ndc_to_view_z(ray_ndc.z);
+        if (depth_delta > 0.0 && depth_delta < thickness) {
+            return 0.0;
+        }
+    }
+#endif  // DEPTH_PREPASS
+    return 1.0;
+}
+
 fn cascade_debug_visualization(
     output_color: vec3<f32>,
     light_id: u32,
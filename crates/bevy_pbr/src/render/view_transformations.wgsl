@@ -232,3 +232,18 @@ fn frag_coord_to_ndc(frag_coord: vec4<f32>) -> vec3<f32> {
 fn ndc_to_frag_coord(ndc: vec2<f32>) -> vec2<f32> {
     return ndc_to_uv(ndc) * view_bindings::view.viewport.zw;
 }
+
+// -----------------
+// CLIP PLANES -----
+// -----------------
+
+/// Returns true if the world space position is on the clipped side of any of the view's clip planes
+fn is_clipped_by_view_planes(world_position: vec3<f32>) -> bool {
+    for (var i = 0u; i < 4u; i += 1u) {
+        let plane = view_bindings::view.clip_planes[i];
+        if dot(plane.xyz, world_position) + plane.w < 0.0 {
+            return true;
+        }
+    }
+    return false;
+}
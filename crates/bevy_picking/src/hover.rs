@@ -14,7 +14,7 @@ use crate::{
 };
 
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, system::SystemParam};
 use bevy_math::FloatOrd;
 use bevy_platform_support::collections::HashMap;
 use bevy_reflect::prelude::*;
@@ -60,6 +60,105 @@ pub struct HoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
 #[derive(Debug, Deref, DerefMut, Default, Resource)]
 pub struct PreviousHoverMap(pub HashMap<PointerId, HashMap<Entity, HitData>>);
 
+/// A [`SystemParam`] for looking up the hover state of entities, along with the [`HitData`] of
+/// each pointer hovering over them.
+///
+/// This is a read-only view of the [`HoverMap`] and [`PreviousHoverMap`], which is more
+/// convenient to use than the maps themselves when you want to know about a particular entity,
+/// and saves tracking the hover state yourself with [`Pointer<Over>`] and [`Pointer<Out>`]
+/// observers.
+///
+/// The hover state is updated in [`PickSet::Hover`](crate::PickSet::Hover), so systems using
+/// this should run after that to see the state for the current frame.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_picking::hover::HoverState;
+/// # #[derive(Component)]
+/// # struct Terrain;
+/// fn print_terrain_hits(hover_state: HoverState, terrain: Query<Entity, With<Terrain>>) {
+///     for entity in &terrain {
+///         if let Some(position) = hover_state.hit(entity).and_then(|hit| hit.position) {
+///             println!("terrain hovered at {position}");
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(print_terrain_hits);
+/// ```
+///
+/// [`Pointer<Over>`]: crate::events::Pointer
+/// [`Pointer<Out>`]: crate::events::Pointer
+#[derive(SystemParam)]
+pub struct HoverState<'w> {
+    hover_map: Res<'w, HoverMap>,
+    previous_hover_map: Res<'w, PreviousHoverMap>,
+}
+
+impl HoverState<'_> {
+    /// Returns `true` if any pointer is hovering over `entity`.
+    pub fn is_hovered(&self, entity: Entity) -> bool {
+        self.hover_map
+            .values()
+            .any(|entities| entities.contains_key(&entity))
+    }
+
+    /// Returns `true` if `pointer` is hovering over `entity`.
+    pub fn is_hovered_by(&self, entity: Entity, pointer: PointerId) -> bool {
+        self.hit_by(entity, pointer).is_some()
+    }
+
+    /// Returns `true` if `entity` is hovered by any pointer now, but wasn't in the previous
+    /// update.
+    pub fn just_hovered(&self, entity: Entity) -> bool {
+        self.is_hovered(entity) && !was_hovered(&self.previous_hover_map, entity)
+    }
+
+    /// Returns `true` if `entity` was hovered by any pointer in the previous update, but isn't
+    /// anymore.
+    pub fn just_unhovered(&self, entity: Entity) -> bool {
+        !self.is_hovered(entity) && was_hovered(&self.previous_hover_map, entity)
+    }
+
+    /// Returns the closest hit on `entity` out of all the pointers hovering over it, if any.
+    pub fn hit(&self, entity: Entity) -> Option<&HitData> {
+        self.hits(entity)
+            .map(|(_, hit)| hit)
+            .min_by_key(|hit| FloatOrd(hit.depth))
+    }
+
+    /// Returns the hit of `pointer` on `entity`, if the pointer is hovering over it.
+    pub fn hit_by(&self, entity: Entity, pointer: PointerId) -> Option<&HitData> {
+        self.hover_map.get(&pointer)?.get(&entity)
+    }
+
+    /// Returns an iterator over each pointer hovering over `entity`, and its hit on the entity.
+    pub fn hits(&self, entity: Entity) -> impl Iterator<Item = (PointerId, &HitData)> {
+        self.hover_map
+            .iter()
+            .filter_map(move |(pointer, entities)| Some((*pointer, entities.get(&entity)?)))
+    }
+
+    /// Returns an iterator over each entity that `pointer` is hovering over, and the pointer's
+    /// hit on the entity.
+    ///
+    /// The entities are not in any particular order.
+    pub fn hovered_by(&self, pointer: PointerId) -> impl Iterator<Item = (Entity, &HitData)> {
+        self.hover_map
+            .get(&pointer)
+            .into_iter()
+            .flatten()
+            .map(|(entity, hit)| (*entity, hit))
+    }
+}
+
+fn was_hovered(previous_hover_map: &PreviousHoverMap, entity: Entity) -> bool {
+    previous_hover_map
+        .values()
+        .any(|entities| entities.contains_key(&entity))
+}
+
 /// Coalesces all data from inputs and backends to generate a map of the currently hovered entities.
 /// This is the final focusing step to determine which entity the pointer is hovering over.
 pub fn generate_hovermap(
@@ -365,6 +365,20 @@ impl<'a> ReflectDerive<'a> {
                 |(declaration_index, field)| -> Result<StructField, syn::Error> {
                     let attrs = FieldAttributes::parse_attributes(&field.attrs)?;
 
+                    if attrs.ignore.is_ignored() && !attrs.validators.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            "ignored fields cannot be validated",
+                        ));
+                    }
+
+                    if field.ident.is_none() && !attrs.aliases.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            "aliases can only be added to named fields",
+                        ));
+                    }
+
                     let reflection_index = if attrs.ignore.is_ignored() {
                         None
                     } else {
@@ -400,9 +414,17 @@ impl<'a> ReflectDerive<'a> {
                     Fields::Unnamed(..) => EnumVariantFields::Unnamed(fields),
                     Fields::Unit => EnumVariantFields::Unit,
                 };
+                let attrs = FieldAttributes::parse_attributes(&variant.attrs)?;
+                if !attrs.validators.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "validators can only be added to fields, not variants",
+                    ));
+                }
+
                 Ok(EnumVariant {
                     fields,
-                    attrs: FieldAttributes::parse_attributes(&variant.attrs)?,
+                    attrs,
                     data: variant,
                     #[cfg(feature = "documentation")]
                     doc: crate::documentation::Documentation::from_attributes(&variant.attrs),
@@ -481,6 +503,7 @@ impl<'a> ReflectMeta<'a> {
             where_clause_options,
             None,
             Option::<core::iter::Empty<&Type>>::None,
+            Vec::new(),
         )
     }
 
@@ -512,17 +535,17 @@ impl<'a> StructField<'a> {
         let ty = self.reflected_type();
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[cfg_attr(
-            not(feature = "documentation"),
-            expect(
-                unused_mut,
-                reason = "Needs to be mutable if `documentation` feature is enabled.",
-            )
-        )]
         let mut info = quote! {
             #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
         };
 
+        let aliases = &self.attrs.aliases;
+        if !aliases.is_empty() {
+            info.extend(quote! {
+                .with_aliases(&[#(#aliases),*])
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -546,6 +569,35 @@ impl<'a> StructField<'a> {
     pub fn attrs(&self) -> &FieldAttributes {
         &self.attrs
     }
+
+    /// Generates a `TokenStream` for the `FieldValidator` construction of each
+    /// `#[reflect(validate = ...)]` attribute on this field.
+    ///
+    /// If the field belongs to an enum variant, `variant` is the name of that variant.
+    pub fn to_validator_tokens<'b>(
+        &'b self,
+        bevy_reflect_path: &'b Path,
+        variant: Option<&'b str>,
+    ) -> impl Iterator<Item = proc_macro2::TokenStream> + 'b {
+        let ty = self.reflected_type();
+        let field = match &self.data.ident {
+            Some(ident) => {
+                let name = ident.to_string();
+                quote!(#bevy_reflect_path::validation::ValidatedField::Named(#name))
+            }
+            None => {
+                let index = self.reflection_index.unwrap_or_default();
+                quote!(#bevy_reflect_path::validation::ValidatedField::Index(#index))
+            }
+        };
+        let in_variant = variant.map(|variant| quote!(.in_variant(#variant)));
+
+        self.attrs.validators.iter().map(move |validator| {
+            quote! {
+                #bevy_reflect_path::validation::FieldValidator::new::<#ty, _>(#field, #validator)#in_variant
+            }
+        })
+    }
 }
 
 impl<'a> ReflectStruct<'a> {
@@ -571,6 +623,9 @@ impl<'a> ReflectStruct<'a> {
             where_clause_options,
             self.serialization_data(),
             Some(self.active_types().iter()),
+            self.active_fields()
+                .flat_map(|field| field.to_validator_tokens(self.meta().bevy_reflect_path(), None))
+                .collect(),
         )
     }
 
@@ -714,6 +769,18 @@ impl<'a> ReflectEnum<'a> {
             where_clause_options,
             None,
             Some(self.active_fields().map(StructField::reflected_type)),
+            self.variants
+                .iter()
+                .flat_map(|variant| {
+                    let name = variant.data.ident.to_string();
+                    variant
+                        .active_fields()
+                        .flat_map(|field| {
+                            field.to_validator_tokens(self.meta().bevy_reflect_path(), Some(&name))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
         )
     }
 
@@ -807,18 +874,18 @@ impl<'a> EnumVariant<'a> {
 
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[cfg_attr(
-            not(feature = "documentation"),
-            expect(
-                unused_mut,
-                reason = "Needs to be mutable if `documentation` feature is enabled.",
-            )
-        )]
         let mut info = quote! {
             #bevy_reflect_path::#info_struct::new(#args)
                 .with_custom_attributes(#custom_attributes)
         };
 
+        let aliases = &self.attrs.aliases;
+        if !aliases.is_empty() {
+            info.extend(quote! {
+                .with_aliases(&[#(#aliases),*])
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -9,13 +9,15 @@ use crate::{
     REFLECT_ATTRIBUTE_NAME,
 };
 use quote::ToTokens;
-use syn::{parse::ParseStream, Attribute, LitStr, Meta, Token, Type};
+use syn::{parse::ParseStream, Attribute, Expr, LitStr, Meta, Token, Type};
 
 mod kw {
     syn::custom_keyword!(ignore);
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
     syn::custom_keyword!(remote);
+    syn::custom_keyword!(validate);
+    syn::custom_keyword!(alias);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -80,6 +82,10 @@ pub(crate) struct FieldAttributes {
     pub custom_attributes: CustomAttributes,
     /// For defining the remote wrapper type that should be used in place of the field for reflection logic.
     pub remote: Option<Type>,
+    /// Expressions evaluating to the functions used to validate this field.
+    pub validators: Vec<Expr>,
+    /// Legacy names that this field or variant can be deserialized from.
+    pub aliases: Vec<LitStr>,
 }
 
 impl FieldAttributes {
@@ -125,6 +131,10 @@ impl FieldAttributes {
             self.parse_default(input)
         } else if lookahead.peek(kw::remote) {
             self.parse_remote(input)
+        } else if lookahead.peek(kw::validate) {
+            self.parse_validate(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
         } else {
             Err(lookahead.error())
         }
@@ -217,6 +227,33 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `validate` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(validate = path::to::func)]`
+    /// - `#[reflect(validate = in_range(0.0..=1.0))]`
+    fn parse_validate(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::validate>()?;
+        input.parse::<Token![=]>()?;
+
+        self.validators.push(input.parse()?);
+
+        Ok(())
+    }
+
+    /// Parse `alias` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(alias = "OldName")]`
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+
+        self.aliases.push(input.parse()?);
+
+        Ok(())
+    }
+
     /// Returns `Some(true)` if the field has a generic remote type.
     ///
     /// If the remote type is not generic, returns `Some(false)`.
@@ -339,6 +339,26 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(alias = "...")]`
+///
+/// This attribute adds a legacy name to a named field or an enum variant.
+/// When deserializing, the reflection deserializers accept the alias in place of the current name,
+/// while serializers always write the current name.
+/// This keeps data serialized before a field or variant was renamed, such as scenes, loadable.
+///
+/// A field or variant may have any number of aliases.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// struct Player {
+///   // This field used to be called `hp`:
+///   #[reflect(alias = "hp")]
+///   health: f32,
+/// }
+/// ```
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the field's `TypeInfo`.
@@ -473,8 +493,16 @@ pub fn derive_type_path(input: TokenStream) -> TokenStream {
 /// A macro that automatically generates type data for traits, which their implementors can then register.
 ///
 /// The output of this macro is a struct that takes reflected instances of the implementor's type
-/// and returns the value as a trait object.
-/// Because of this, **it can only be used on [object-safe] traits.**
+/// and returns the value as a trait object, or calls the trait's methods on it directly.
+///
+/// Downcasting to a trait object with `get`, `get_mut` and `get_boxed` is only available for
+/// [dyn-compatible] traits. For every trait, a `call_*` method is also generated for each trait
+/// method that takes `&self` or `&mut self` and has no type or const parameters,
+/// which downcasts the reflected value and calls the method on it, returning `None` if the
+/// value isn't of the implementor's type.
+/// This includes methods with lifetime parameters, and methods returning `impl Trait`,
+/// whose return value is boxed into a `Box<dyn Trait>`. Methods which mention `Self` in their
+/// arguments or return type can't be called this way.
 ///
 /// For a trait named `MyTrait`, this will generate the struct `ReflectMyTrait`.
 /// The generated struct can be created using `FromType` with any type that implements the trait.
@@ -515,9 +543,12 @@ pub fn derive_type_path(input: TokenStream) -> TokenStream {
 /// let reflected: Box<dyn Reflect> = Box::new(SomeStruct);
 /// let reflected_my_trait: &dyn MyTrait = my_trait.get(&*reflected).unwrap();
 /// assert_eq!("Hello, World!", reflected_my_trait.print());
+///
+/// // Or call the trait's methods on it directly
+/// assert_eq!(Some("Hello, World!"), my_trait.call_print(&*reflected));
 /// ```
 ///
-/// [object-safe]: https://doc.rust-lang.org/reference/items/traits.html#object-safety
+/// [dyn-compatible]: https://doc.rust-lang.org/reference/items/traits.html#dyn-compatibility
 #[proc_macro_attribute]
 pub fn reflect_trait(args: TokenStream, input: TokenStream) -> TokenStream {
     trait_reflection::reflect_trait(&args, input)
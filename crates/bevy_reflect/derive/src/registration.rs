@@ -13,6 +13,7 @@ pub(crate) fn impl_get_type_registration<'a>(
     where_clause_options: &WhereClauseOptions,
     serialization_data: Option<&SerializationDataDef>,
     type_dependencies: Option<impl Iterator<Item = &'a Type>>,
+    validators: Vec<proc_macro2::TokenStream>,
 ) -> proc_macro2::TokenStream {
     let type_path = meta.type_path();
     let bevy_reflect_path = meta.bevy_reflect_path();
@@ -45,14 +46,24 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    let validate_data = (!validators.is_empty()).then(|| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::validation::ReflectValidate>(
+                #bevy_reflect_path::validation::ReflectValidate::new([#(#validators),*])
+            );
+        }
+    });
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
             fn get_type_registration() -> #bevy_reflect_path::TypeRegistration {
                 let mut registration = #bevy_reflect_path::TypeRegistration::of::<Self>();
+                registration.set_crate_version(::core::option_env!("CARGO_PKG_VERSION"));
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #validate_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
@@ -3,8 +3,16 @@ use bevy_macro_utils::{
     BevyManifest,
 };
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Attribute, ItemTrait, Token};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::Parse,
+    parse_macro_input,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+    Attribute, FnArg, GenericParam, Ident, ItemTrait, Lifetime, ReturnType, Signature, Token,
+    TraitItem, TraitItemFn, Type, TypeParamBound, WherePredicate,
+};
 
 pub(crate) struct TraitInfo {
     item_trait: ItemTrait,
@@ -24,10 +32,244 @@ impl Parse for TraitInfo {
     }
 }
 
+/// A trait method which can be called on a reflected value through the generated type data.
+struct DispatchMethod {
+    /// The name of the method in the trait.
+    ident: Ident,
+    /// The name of the function pointer field, and of the method calling it.
+    call_ident: Ident,
+    /// The lifetime of the `self` reference.
+    self_lifetime: Lifetime,
+    /// The lifetime parameters of the function pointer, including `self_lifetime`.
+    lifetimes: Vec<Lifetime>,
+    mutable: bool,
+    arg_idents: Vec<Ident>,
+    arg_types: Vec<Type>,
+    /// The return type, after any `impl Trait` has been replaced by a boxed trait object.
+    return_type: Type,
+    /// Whether the method returns an `impl Trait` which must be boxed.
+    boxed: bool,
+}
+
+impl DispatchMethod {
+    /// Returns the dispatch information for `method`,
+    /// or `None` if it can't be called through a function pointer.
+    ///
+    /// Only methods taking `&self` or `&mut self`, with no type or const parameters, can be
+    /// dispatched. Lifetime parameters are supported as long as they have no bounds, and an
+    /// `impl Trait` return type is supported by boxing it.
+    fn new(method: &TraitItemFn, bevy_reflect_path: &syn::Path) -> Option<Self> {
+        let sig = &method.sig;
+        if sig.asyncness.is_some()
+            || sig.unsafety.is_some()
+            || sig.abi.is_some()
+            || sig.variadic.is_some()
+        {
+            return None;
+        }
+
+        let receiver = sig.receiver()?;
+        let (_, receiver_lifetime) = receiver.reference.as_ref()?;
+        if receiver.colon_token.is_some() {
+            return None;
+        }
+
+        let mut method_lifetimes = Vec::new();
+        for param in &sig.generics.params {
+            match param {
+                GenericParam::Lifetime(param) if param.bounds.is_empty() => {
+                    method_lifetimes.push(param.lifetime.clone());
+                }
+                _ => return None,
+            }
+        }
+        if let Some(where_clause) = &sig.generics.where_clause {
+            if !where_clause.predicates.iter().all(is_self_sized_bound) {
+                return None;
+            }
+        }
+
+        let mut arg_types = Vec::new();
+        for input in sig.inputs.iter().skip(1) {
+            let FnArg::Typed(arg) = input else {
+                return None;
+            };
+            if contains_self(arg.ty.to_token_stream()) || contains_impl_trait(&arg.ty) {
+                return None;
+            }
+            arg_types.push((*arg.ty).clone());
+        }
+
+        let (mut return_type, impl_bounds) = match &sig.output {
+            ReturnType::Default => (syn::parse_quote!(()), None),
+            ReturnType::Type(_, ty) => {
+                if contains_self(ty.to_token_stream()) {
+                    return None;
+                }
+                match &**ty {
+                    Type::ImplTrait(impl_trait) => {
+                        ((**ty).clone(), Some(impl_trait.bounds.clone()))
+                    }
+                    ty if contains_impl_trait(ty) => return None,
+                    ty => (ty.clone(), None),
+                }
+            }
+        };
+
+        let self_lifetime = receiver_lifetime
+            .clone()
+            .unwrap_or_else(|| Lifetime::new("'__self", Span::call_site()));
+        let mut lifetimes = method_lifetimes.clone();
+        if receiver_lifetime.is_none() {
+            lifetimes.insert(0, self_lifetime.clone());
+        }
+
+        let boxed = impl_bounds.is_some();
+        if let Some(mut bounds) = impl_bounds {
+            // The hidden type of an `impl Trait` may capture every lifetime in the signature,
+            // so they are all unified with the lifetime of `self`, which then bounds the box.
+            let mut replacer = LifetimeReplacer {
+                replacement: &self_lifetime,
+                named: &method_lifetimes,
+            };
+            for ty in &mut arg_types {
+                replacer.visit_type_mut(ty);
+            }
+            for bound in &mut bounds {
+                replacer.visit_type_param_bound_mut(bound);
+            }
+            lifetimes = vec![self_lifetime.clone()];
+
+            let bounds = bounds
+                .into_iter()
+                .filter(|bound| matches!(bound, TypeParamBound::Trait(_)))
+                .collect::<Punctuated<_, Token![+]>>();
+            if bounds.is_empty() {
+                return None;
+            }
+            return_type = syn::parse_quote! {
+                #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bounds + #self_lifetime>
+            };
+        } else {
+            // Elided lifetimes in the return type are tied to `self`.
+            LifetimeReplacer {
+                replacement: &self_lifetime,
+                named: &[],
+            }
+            .visit_type_mut(&mut return_type);
+        }
+
+        let ident = sig.ident.clone();
+        Some(Self {
+            call_ident: format_ident!("call_{}", ident),
+            ident,
+            self_lifetime,
+            lifetimes,
+            mutable: receiver.mutability.is_some(),
+            arg_idents: (0..arg_types.len())
+                .map(|index| format_ident!("__arg{}", index))
+                .collect(),
+            arg_types,
+            return_type,
+            boxed,
+        })
+    }
+}
+
+/// Replaces elided lifetimes, and the given named lifetimes, with a single lifetime.
+struct LifetimeReplacer<'a> {
+    replacement: &'a Lifetime,
+    named: &'a [Lifetime],
+}
+
+impl VisitMut for LifetimeReplacer<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" || self.named.contains(lifetime) {
+            *lifetime = self.replacement.clone();
+        }
+    }
+
+    fn visit_type_reference_mut(&mut self, reference: &mut syn::TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(self.replacement.clone());
+        }
+        visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    // Function pointers and `Fn` traits have their own elision scope.
+    fn visit_type_bare_fn_mut(&mut self, _: &mut syn::TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        _: &mut syn::ParenthesizedGenericArguments,
+    ) {
+    }
+}
+
+/// Returns `true` if the tokens mention `Self`, which can't be named outside of the trait.
+fn contains_self(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => contains_self(group.stream()),
+        _ => false,
+    })
+}
+
+/// Returns `true` if the type contains an `impl Trait` anywhere within it.
+fn contains_impl_trait(ty: &Type) -> bool {
+    struct Finder(bool);
+
+    impl VisitMut for Finder {
+        fn visit_type_impl_trait_mut(&mut self, _: &mut syn::TypeImplTrait) {
+            self.0 = true;
+        }
+    }
+
+    let mut finder = Finder(false);
+    finder.visit_type_mut(&mut ty.clone());
+    finder.0
+}
+
+/// Returns `true` if the predicate is `Self: Sized`, which excludes a method from trait objects.
+fn is_self_sized_bound(predicate: &WherePredicate) -> bool {
+    let WherePredicate::Type(predicate) = predicate else {
+        return false;
+    };
+    predicate.bounded_ty.to_token_stream().to_string() == "Self"
+        && predicate.bounds.iter().any(
+            |bound| matches!(bound, TypeParamBound::Trait(bound) if bound.path.is_ident("Sized")),
+        )
+}
+
+/// Returns `true` if the method would prevent the trait from being made into a trait object.
+fn is_dyn_incompatible(sig: &Signature) -> bool {
+    if let Some(where_clause) = &sig.generics.where_clause {
+        if where_clause.predicates.iter().any(is_self_sized_bound) {
+            return false;
+        }
+    }
+
+    let mentions_self = sig.inputs.iter().any(|input| match input {
+        FnArg::Receiver(_) => false,
+        FnArg::Typed(arg) => contains_self(arg.ty.to_token_stream()),
+    }) || contains_self(sig.output.to_token_stream());
+    let impl_trait = sig.inputs.iter().any(|input| match input {
+        FnArg::Receiver(_) => false,
+        FnArg::Typed(arg) => contains_impl_trait(&arg.ty),
+    }) || matches!(&sig.output, ReturnType::Type(_, ty) if contains_impl_trait(ty));
+    let generic = sig
+        .generics
+        .params
+        .iter()
+        .any(|param| !matches!(param, GenericParam::Lifetime(_)));
+
+    sig.receiver().is_none() || sig.asyncness.is_some() || mentions_self || impl_trait || generic
+}
+
 /// A trait attribute macro that allows a reflected type to be downcast to a trait object.
 ///
 /// This generates a struct that takes the form `ReflectMyTrait`. An instance of this struct can then be
-/// used to perform the conversion.
+/// used to perform the conversion, or to call the trait's methods directly on a reflected value.
 pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStream {
     let trait_info = parse_macro_input!(input as TraitInfo);
     let item_trait = &trait_info.item_trait;
@@ -36,31 +278,51 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
     let reflect_trait_ident = crate::ident::get_reflect_ident(&item_trait.ident.to_string());
     let bevy_reflect_path = BevyManifest::shared().get_path("bevy_reflect");
 
-    let struct_doc = format!(
-        " A type generated by the #[reflect_trait] macro for the `{trait_ident}` trait.\n\n This allows casting from `dyn Reflect` to `dyn {trait_ident}`.",
-    );
-    let get_doc = format!(
-        " Downcast a `&dyn Reflect` type to `&dyn {trait_ident}`.\n\n If the type cannot be downcast, `None` is returned.",
-    );
-    let get_mut_doc = format!(
-        " Downcast a `&mut dyn Reflect` type to `&mut dyn {trait_ident}`.\n\n If the type cannot be downcast, `None` is returned.",
-    );
-    let get_box_doc = format!(
-        " Downcast a `Box<dyn Reflect>` type to `Box<dyn {trait_ident}>`.\n\n If the type cannot be downcast, this will return `Err(Box<dyn Reflect>)`.",
-    );
+    let dyn_compatible = item_trait.items.iter().all(|item| match item {
+        TraitItem::Fn(method) => !is_dyn_incompatible(&method.sig),
+        TraitItem::Type(_) | TraitItem::Const(_) => false,
+        _ => true,
+    });
+    let methods = item_trait
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(method) => DispatchMethod::new(method, &bevy_reflect_path),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
 
-    TokenStream::from(quote! {
-        #item_trait
+    let struct_doc = if dyn_compatible {
+        format!(
+            " A type generated by the #[reflect_trait] macro for the `{trait_ident}` trait.\n\n This allows casting from `dyn Reflect` to `dyn {trait_ident}`, and calling the trait's methods on a `dyn Reflect`.",
+        )
+    } else {
+        format!(
+            " A type generated by the #[reflect_trait] macro for the `{trait_ident}` trait.\n\n This allows calling the trait's methods on a `dyn Reflect`.",
+        )
+    };
 
-        #[doc = #struct_doc]
-        #[derive(#FQClone)]
-        #trait_vis struct #reflect_trait_ident {
+    let mut fields = Vec::new();
+    let mut functions = Vec::new();
+    let mut constructors = Vec::new();
+
+    if dyn_compatible {
+        let get_doc = format!(
+            " Downcast a `&dyn Reflect` type to `&dyn {trait_ident}`.\n\n If the type cannot be downcast, `None` is returned.",
+        );
+        let get_mut_doc = format!(
+            " Downcast a `&mut dyn Reflect` type to `&mut dyn {trait_ident}`.\n\n If the type cannot be downcast, `None` is returned.",
+        );
+        let get_box_doc = format!(
+            " Downcast a `Box<dyn Reflect>` type to `Box<dyn {trait_ident}>`.\n\n If the type cannot be downcast, this will return `Err(Box<dyn Reflect>)`.",
+        );
+
+        fields.push(quote! {
             get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&dyn #trait_ident>,
             get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut dyn #trait_ident>,
             get_boxed_func: fn(#bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>, #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>>,
-        }
-
-        impl #reflect_trait_ident {
+        });
+        functions.push(quote! {
             #[doc = #get_doc]
             pub fn get<'a>(&self, reflect_value: &'a dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a dyn #trait_ident> {
                 (self.get_func)(reflect_value)
@@ -75,20 +337,94 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
             pub fn get_boxed(&self, reflect_value: #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>, #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>> {
                 (self.get_boxed_func)(reflect_value)
             }
+        });
+        constructors.push(quote! {
+            get_func: |reflect_value| {
+                <dyn #bevy_reflect_path::Reflect>::downcast_ref::<T>(reflect_value).map(|value| value as &dyn #trait_ident)
+            },
+            get_mut_func: |reflect_value| {
+                <dyn #bevy_reflect_path::Reflect>::downcast_mut::<T>(reflect_value).map(|value| value as &mut dyn #trait_ident)
+            },
+            get_boxed_func: |reflect_value| {
+                <dyn #bevy_reflect_path::Reflect>::downcast::<T>(reflect_value).map(|value| value as #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>)
+            },
+        });
+    }
+
+    for method in &methods {
+        let DispatchMethod {
+            ident,
+            call_ident,
+            self_lifetime,
+            lifetimes,
+            mutable,
+            arg_idents,
+            arg_types,
+            return_type,
+            boxed,
+        } = method;
+        let field_ident = format_ident!("{}_func", call_ident);
+        let (reflect_ref, downcast) = if *mutable {
+            (
+                quote!(&#self_lifetime mut dyn #bevy_reflect_path::Reflect),
+                quote!(downcast_mut),
+            )
+        } else {
+            (
+                quote!(&#self_lifetime dyn #bevy_reflect_path::Reflect),
+                quote!(downcast_ref),
+            )
+        };
+        let call = quote!(<T as #trait_ident>::#ident(value, #(#arg_idents),*));
+        let call = if *boxed {
+            // The lifetime of `self` isn't nameable in the constructor, so it's inferred instead.
+            let mut cast_type = return_type.clone();
+            LifetimeReplacer {
+                replacement: &Lifetime::new("'_", Span::call_site()),
+                named: core::slice::from_ref(self_lifetime),
+            }
+            .visit_type_mut(&mut cast_type);
+            quote!(#bevy_reflect_path::__macro_exports::alloc_utils::Box::new(#call) as #cast_type)
+        } else {
+            call
+        };
+        let call_doc = format!(
+            " Call `{trait_ident}::{ident}` on a reflected value.\n\n If the type cannot be downcast, `None` is returned.",
+        );
+
+        fields.push(quote! {
+            #field_ident: for<#(#lifetimes),*> fn(#reflect_ref, #(#arg_types),*) -> #FQOption<#return_type>,
+        });
+        functions.push(quote! {
+            #[doc = #call_doc]
+            pub fn #call_ident<#(#lifetimes),*>(&self, reflect_value: #reflect_ref, #(#arg_idents: #arg_types),*) -> #FQOption<#return_type> {
+                (self.#field_ident)(reflect_value, #(#arg_idents),*)
+            }
+        });
+        constructors.push(quote! {
+            #field_ident: |reflect_value, #(#arg_idents),*| {
+                <dyn #bevy_reflect_path::Reflect>::#downcast::<T>(reflect_value).map(|value| #call)
+            },
+        });
+    }
+
+    TokenStream::from(quote! {
+        #item_trait
+
+        #[doc = #struct_doc]
+        #[derive(#FQClone)]
+        #trait_vis struct #reflect_trait_ident {
+            #(#fields)*
+        }
+
+        impl #reflect_trait_ident {
+            #(#functions)*
         }
 
         impl<T: #trait_ident + #bevy_reflect_path::Reflect> #bevy_reflect_path::FromType<T> for #reflect_trait_ident {
             fn from_type() -> Self {
                 Self {
-                    get_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast_ref::<T>(reflect_value).map(|value| value as &dyn #trait_ident)
-                    },
-                    get_mut_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast_mut::<T>(reflect_value).map(|value| value as &mut dyn #trait_ident)
-                    },
-                    get_boxed_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast::<T>(reflect_value).map(|value| value as #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>)
-                    }
+                    #(#constructors)*
                 }
             }
         }
@@ -385,7 +385,7 @@ impl<'a> Iterator for ArrayIter<'a> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.array.len();
+        let size = self.array.len() - self.index;
         (size, Some(size))
     }
 }
@@ -533,4 +533,15 @@ mod tests {
         assert!(iter.next().is_none());
         assert!(iter.index == SIZE);
     }
+
+    #[test]
+    fn iter_size_hint_counts_remaining() {
+        let array = [0u8; 256];
+        let mut iter = crate::Array::iter(&array);
+        assert_eq!(iter.len(), 256);
+        iter.nth(99);
+        assert_eq!(iter.len(), 156);
+        assert_eq!(iter.by_ref().count(), 156);
+        assert_eq!(iter.len(), 0);
+    }
 }
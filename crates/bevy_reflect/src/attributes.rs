@@ -1,5 +1,5 @@
-use crate::Reflect;
-use alloc::boxed::Box;
+use crate::{self as bevy_reflect, std_traits::ReflectDefault, Reflect};
+use alloc::{borrow::Cow, boxed::Box};
 use bevy_utils::TypeIdMap;
 use core::{
     any::TypeId,
@@ -85,6 +85,26 @@ impl CustomAttributes {
     pub fn is_empty(&self) -> bool {
         self.attributes.is_empty()
     }
+
+    /// Returns the name given by a [`DisplayName`] attribute, if any.
+    pub fn display_name(&self) -> Option<&str> {
+        self.get::<DisplayName>().map(DisplayName::as_str)
+    }
+
+    /// Returns the text given by a [`Tooltip`] attribute, if any.
+    pub fn tooltip(&self) -> Option<&str> {
+        self.get::<Tooltip>().map(Tooltip::as_str)
+    }
+
+    /// Returns the [`Slider`] attribute, if any.
+    pub fn slider(&self) -> Option<&Slider> {
+        self.get::<Slider>()
+    }
+
+    /// Returns `true` if this collection contains a [`ColorPicker`] attribute.
+    pub fn has_color_picker(&self) -> bool {
+        self.contains::<ColorPicker>()
+    }
 }
 
 impl Debug for CustomAttributes {
@@ -175,6 +195,108 @@ macro_rules! impl_custom_attribute_methods {
 
 pub(crate) use impl_custom_attribute_methods;
 
+/// A custom attribute giving a human-readable name to show in inspectors and editors, in place of
+/// the type, field, or variant name.
+///
+/// This is one of the standard inspector attributes, along with [`Tooltip`], [`Slider`],
+/// and [`ColorPicker`]. Inspectors can read them through the typed accessors on
+/// [`CustomAttributes`], like [`CustomAttributes::display_name`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// use bevy_reflect::attributes::{ColorPicker, DisplayName, Slider, Tooltip};
+///
+/// #[derive(Reflect)]
+/// struct Light {
+///     #[reflect(@DisplayName::new("Light Color"), @ColorPicker)]
+///     color: [f32; 4],
+///     #[reflect(@Tooltip::new("Brightness of the light, in lumens"))]
+///     #[reflect(@Slider::new(0.0, 10_000.0, 100.0))]
+///     intensity: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = <Light as Typed>::type_info() else {
+///     panic!("expected struct info");
+/// };
+///
+/// let color = info.field("color").unwrap().custom_attributes();
+/// assert_eq!(Some("Light Color"), color.display_name());
+/// assert!(color.has_color_picker());
+///
+/// let intensity = info.field("intensity").unwrap().custom_attributes();
+/// assert_eq!(Some("Brightness of the light, in lumens"), intensity.tooltip());
+/// assert_eq!(Some(10_000.0), intensity.slider().map(|slider| slider.max));
+/// ```
+#[derive(Reflect, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct DisplayName(pub Cow<'static, str>);
+
+impl DisplayName {
+    /// Creates a display name from a static string.
+    pub const fn new(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    /// Returns the display name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A custom attribute giving a description to show in inspectors and editors, usually when
+/// hovering over the type, field, or variant.
+///
+/// See [`DisplayName`] for an example.
+#[derive(Reflect, Clone, Debug, PartialEq, Eq, Hash)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct Tooltip(pub Cow<'static, str>);
+
+impl Tooltip {
+    /// Creates a tooltip from a static string.
+    pub const fn new(text: &'static str) -> Self {
+        Self(Cow::Borrowed(text))
+    }
+
+    /// Returns the tooltip text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A custom attribute hinting that a numeric field should be edited with a slider, from `min` to
+/// `max` in increments of `step`.
+///
+/// This is only a hint for editors; values outside of the range are still allowed.
+/// To restrict values, see [`Bounds`](crate::validation::Bounds).
+///
+/// See [`DisplayName`] for an example.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Debug, PartialEq)]
+pub struct Slider {
+    /// The smallest value of the slider.
+    pub min: f64,
+    /// The largest value of the slider.
+    pub max: f64,
+    /// The increment between values of the slider.
+    pub step: f64,
+}
+
+impl Slider {
+    /// Creates a slider from `min` to `max`, in increments of `step`.
+    pub const fn new(min: f64, max: f64, step: f64) -> Self {
+        Self { min, max, step }
+    }
+}
+
+/// A custom attribute hinting that a field should be edited with a color picker.
+///
+/// See [`DisplayName`] for an example.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub struct ColorPicker;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,15 +305,6 @@ mod tests {
     use alloc::{format, string::String};
     use core::ops::RangeInclusive;
 
-    #[derive(Reflect, PartialEq, Debug)]
-    struct Tooltip(String);
-
-    impl Tooltip {
-        fn new(value: impl Into<String>) -> Self {
-            Self(value.into())
-        }
-    }
-
     #[test]
     fn should_get_custom_attribute() {
         let attributes = CustomAttributes::default().with_attribute(0.0..=1.0);
@@ -0,0 +1,522 @@
+use crate::{
+    reflect::impl_full_reflect,
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectDeserializer,
+        ReflectSerializeWithRegistry, ReflectSerializer, SerializeWithRegistry,
+    },
+    utility::{GenericTypeInfoCell, GenericTypePathCell},
+    ApplyError, FromReflect, FromType, GetTypeRegistration, OpaqueInfo, PartialReflect, Reflect,
+    ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectMut, ReflectOwned, ReflectRef,
+    TypeData, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
+};
+use alloc::{boxed::Box, format};
+use core::fmt;
+use serde::{de::Error as _, Deserializer, Serialize, Serializer};
+
+/// A trait object, such as `dyn MyTrait`, which can be reflected when it's boxed.
+///
+/// Implementing this trait for `dyn MyTrait` allows `Box<dyn MyTrait>` to be used as a field
+/// of a type which derives [`Reflect`], without having to mark it as `#[reflect(ignore)]`.
+///
+/// A boxed trait object is reflected as an [opaque] value, and registers the [`ReflectBoxed`]
+/// type data to access the concrete value inside of the box.
+/// It's serialized as a map from the [type path] of the concrete value to the value itself,
+/// just like the [`ReflectSerializer`] does, so that the concrete type can be looked up in the
+/// [`TypeRegistry`] when it's deserialized.
+///
+/// For this to work, the trait must have [`Reflect`] as a supertrait, and each concrete type
+/// must register the type data generated by [`#[reflect_trait]`](crate::reflect_trait) as well
+/// as [`ReflectFromReflect`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{
+/// #     reflect_trait, serde::{ReflectDeserializer, ReflectSerializer}, FromReflect, Reflect,
+/// #     ReflectTraitObject, TypePath, TypeRegistry,
+/// # };
+/// # use serde::de::DeserializeSeed;
+/// #[reflect_trait]
+/// trait Shape: Reflect {
+///     fn area(&self) -> f32;
+///
+///     fn clone_shape(&self) -> Box<dyn Shape>;
+/// }
+///
+/// impl TypePath for dyn Shape {
+///     fn type_path() -> &'static str {
+///         "dyn my_crate::Shape"
+///     }
+///
+///     fn short_type_path() -> &'static str {
+///         "dyn Shape"
+///     }
+/// }
+///
+/// impl ReflectTraitObject for dyn Shape {
+///     type TypeData = ReflectShape;
+///
+///     fn from_type_data(
+///         data: &ReflectShape,
+///         value: Box<dyn Reflect>,
+///     ) -> Result<Box<Self>, Box<dyn Reflect>> {
+///         data.get_boxed(value)
+///     }
+///
+///     fn clone_boxed(&self) -> Box<Self> {
+///         self.clone_shape()
+///     }
+/// }
+///
+/// #[derive(Reflect, Clone)]
+/// #[reflect(Shape)]
+/// struct Square(f32);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f32 {
+///         self.0 * self.0
+///     }
+///
+///     fn clone_shape(&self) -> Box<dyn Shape> {
+///         Box::new(self.clone())
+///     }
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Drawing {
+///     shape: Box<dyn Shape>,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Drawing>();
+/// registry.register::<Square>();
+///
+/// let drawing = Drawing {
+///     shape: Box::new(Square(2.0)),
+/// };
+/// let serialized = ron::to_string(&ReflectSerializer::new(&drawing, &registry)).unwrap();
+///
+/// let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+/// let deserialized = ReflectDeserializer::new(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// let drawing = Drawing::from_reflect(&*deserialized).unwrap();
+/// assert_eq!(drawing.shape.area(), 4.0);
+/// ```
+///
+/// [opaque]: ReflectKind::Opaque
+/// [type path]: TypePath
+pub trait ReflectTraitObject: Reflect + TypePath {
+    /// The type data used to convert a concrete value into this trait object.
+    ///
+    /// This is usually the type data generated by [`#[reflect_trait]`](crate::reflect_trait),
+    /// such as `ReflectMyTrait`.
+    type TypeData: TypeData;
+
+    /// Converts a concrete value into this trait object using its type data.
+    ///
+    /// If the value can't be converted, it's returned as an error.
+    fn from_type_data(
+        data: &Self::TypeData,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<Self>, Box<dyn Reflect>>;
+
+    /// Returns a boxed clone of this trait object.
+    fn clone_boxed(&self) -> Box<Self>;
+}
+
+/// Type data for a boxed [trait object], which allows accessing and creating the concrete value
+/// inside of the box.
+///
+/// This is registered automatically for every `Box<dyn MyTrait>` where
+/// `dyn MyTrait` implements [`ReflectTraitObject`].
+///
+/// [trait object]: ReflectTraitObject
+#[derive(Clone)]
+pub struct ReflectBoxed {
+    get: fn(&dyn Reflect) -> Option<&dyn Reflect>,
+    get_mut: fn(&mut dyn Reflect) -> Option<&mut dyn Reflect>,
+    from_value: fn(Box<dyn Reflect>, &TypeRegistry) -> Result<Box<dyn Reflect>, Box<dyn Reflect>>,
+}
+
+impl ReflectBoxed {
+    /// Returns the concrete value inside of the given box.
+    ///
+    /// Returns `None` if `boxed` isn't the type that this type data was created for.
+    pub fn get<'a>(&self, boxed: &'a dyn Reflect) -> Option<&'a dyn Reflect> {
+        (self.get)(boxed)
+    }
+
+    /// Returns the concrete value inside of the given box mutably.
+    ///
+    /// Returns `None` if `boxed` isn't the type that this type data was created for.
+    pub fn get_mut<'a>(&self, boxed: &'a mut dyn Reflect) -> Option<&'a mut dyn Reflect> {
+        (self.get_mut)(boxed)
+    }
+
+    /// Puts a concrete value into a box, using the type data registered for its type in
+    /// `registry`.
+    ///
+    /// Returns the value as an error if its type isn't registered, or doesn't register the
+    /// type data needed to convert it into the trait object.
+    pub fn from_value(
+        &self,
+        value: Box<dyn Reflect>,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, Box<dyn Reflect>> {
+        (self.from_value)(value, registry)
+    }
+}
+
+impl<T: ReflectTraitObject + ?Sized> FromType<Box<T>> for ReflectBoxed {
+    fn from_type() -> Self {
+        Self {
+            get: |boxed| {
+                boxed
+                    .downcast_ref::<Box<T>>()
+                    .map(|boxed| (**boxed).as_reflect())
+            },
+            get_mut: |boxed| {
+                boxed
+                    .downcast_mut::<Box<T>>()
+                    .map(|boxed| (**boxed).as_reflect_mut())
+            },
+            from_value: |value, registry| {
+                let type_id = value.reflect_type_info().type_id();
+                match registry.get_type_data::<T::TypeData>(type_id) {
+                    Some(data) => T::from_type_data(data, value)
+                        .map(|boxed| Box::new(boxed) as Box<dyn Reflect>),
+                    None => Err(value),
+                }
+            },
+        }
+    }
+}
+
+impl<T: TypePath + ?Sized> TypePath for Box<T> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("alloc::boxed::Box<{}>", T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("Box<{}>", T::short_type_path()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Box")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some("alloc")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some("alloc::boxed")
+    }
+}
+
+impl<T: ReflectTraitObject + ?Sized> PartialReflect for Box<T> {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    #[inline]
+    fn into_partial_reflect(self: Box<Self>) -> Box<dyn PartialReflect> {
+        self
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        self
+    }
+
+    fn as_partial_reflect_mut(&mut self) -> &mut dyn PartialReflect {
+        self
+    }
+
+    fn try_into_reflect(self: Box<Self>) -> Result<Box<dyn Reflect>, Box<dyn PartialReflect>> {
+        Ok(self)
+    }
+
+    fn try_as_reflect(&self) -> Option<&dyn Reflect> {
+        Some(self)
+    }
+
+    fn try_as_reflect_mut(&mut self) -> Option<&mut dyn Reflect> {
+        Some(self)
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Opaque
+    }
+
+    fn reflect_ref(&self) -> ReflectRef {
+        ReflectRef::Opaque(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut {
+        ReflectMut::Opaque(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Opaque(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn PartialReflect> {
+        Box::new(T::clone_boxed(self))
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        (**self).reflect_hash()
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn PartialReflect) -> Option<bool> {
+        match value.try_downcast_ref::<Self>() {
+            Some(value) => (**self).reflect_partial_eq((**value).as_partial_reflect()),
+            None => Some(false),
+        }
+    }
+
+    fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).debug(f)
+    }
+
+    fn try_apply(&mut self, value: &dyn PartialReflect) -> Result<(), ApplyError> {
+        // Another box replaces the value entirely, since its concrete type may differ,
+        // while any other value is applied to the concrete value inside of this box
+        match value.try_downcast_ref::<Self>() {
+            Some(value) => {
+                *self = T::clone_boxed(value);
+                Ok(())
+            }
+            None => (**self).try_apply(value),
+        }
+    }
+}
+
+impl_full_reflect!(<T> for Box<T> where T: ReflectTraitObject + ?Sized);
+
+impl<T: ReflectTraitObject + ?Sized> Typed for Box<T> {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Opaque(OpaqueInfo::new::<Self>()))
+    }
+}
+
+impl<T: ReflectTraitObject + ?Sized> GetTypeRegistration for Box<T> {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectBoxed>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+        registration.insert::<ReflectSerializeWithRegistry>(FromType::<Self>::from_type());
+        registration.insert::<ReflectDeserializeWithRegistry>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl<T: ReflectTraitObject + ?Sized> FromReflect for Box<T> {
+    fn from_reflect(reflect: &dyn PartialReflect) -> Option<Self> {
+        Some(T::clone_boxed(reflect.try_downcast_ref::<Self>()?))
+    }
+}
+
+impl<T: ReflectTraitObject + ?Sized> SerializeWithRegistry for Box<T> {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ReflectSerializer::new((**self).as_partial_reflect(), registry).serialize(serializer)
+    }
+}
+
+impl<'de, T: ReflectTraitObject + ?Sized> DeserializeWithRegistry<'de> for Box<T> {
+    fn deserialize<D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::DeserializeSeed;
+
+        let value = ReflectDeserializer::new(registry).deserialize(deserializer)?;
+        let type_path = value.reflect_type_path();
+
+        // The deserialized value may be dynamic, so it first has to be made concrete
+        let registration = value
+            .get_represented_type_info()
+            .and_then(|info| registry.get(info.type_id()))
+            .ok_or_else(|| D::Error::custom(format!("no registration found for `{type_path}`")))?;
+        let from_reflect = registration.data::<ReflectFromReflect>().ok_or_else(|| {
+            D::Error::custom(format!(
+                "`{type_path}` did not register the `ReflectFromReflect` type data"
+            ))
+        })?;
+        let value = from_reflect
+            .from_reflect(&*value)
+            .ok_or_else(|| D::Error::custom(format!("failed to convert value to `{type_path}`")))?;
+
+        let data = registry
+            .get_type_data::<T::TypeData>(registration.type_id())
+            .ok_or_else(|| {
+                D::Error::custom(format!(
+                    "`{type_path}` did not register the type data needed to convert it to `{}`",
+                    T::type_path()
+                ))
+            })?;
+        T::from_type_data(data, value).map_err(|value| {
+            D::Error::custom(format!(
+                "`{}` could not be converted to `{}`",
+                value.reflect_type_path(),
+                T::type_path()
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{
+        reflect_trait,
+        serde::{ReflectDeserializer, ReflectSerializer},
+        FromReflect, PartialReflect, Reflect, ReflectBoxed, ReflectTraitObject, TypePath,
+        TypeRegistry,
+    };
+    use alloc::{boxed::Box, string::String};
+    use serde::de::DeserializeSeed;
+
+    #[reflect_trait]
+    trait Enemy: Reflect {
+        fn hp(&self) -> u8;
+
+        fn clone_enemy(&self) -> Box<dyn Enemy>;
+    }
+
+    impl TypePath for dyn Enemy {
+        fn type_path() -> &'static str {
+            "dyn bevy_reflect::boxed::tests::Enemy"
+        }
+
+        fn short_type_path() -> &'static str {
+            "dyn Enemy"
+        }
+    }
+
+    impl ReflectTraitObject for dyn Enemy {
+        type TypeData = ReflectEnemy;
+
+        fn from_type_data(
+            data: &ReflectEnemy,
+            value: Box<dyn Reflect>,
+        ) -> Result<Box<Self>, Box<dyn Reflect>> {
+            data.get_boxed(value)
+        }
+
+        fn clone_boxed(&self) -> Box<Self> {
+            self.clone_enemy()
+        }
+    }
+
+    #[derive(Reflect, Clone)]
+    #[reflect(Enemy)]
+    struct Skeleton(u8);
+
+    impl Enemy for Skeleton {
+        fn hp(&self) -> u8 {
+            self.0
+        }
+
+        fn clone_enemy(&self) -> Box<dyn Enemy> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[derive(Reflect, Clone)]
+    #[reflect(Enemy)]
+    struct Zombie {
+        health: u8,
+        walk_speed: f32,
+    }
+
+    impl Enemy for Zombie {
+        fn hp(&self) -> u8 {
+            self.health
+        }
+
+        fn clone_enemy(&self) -> Box<dyn Enemy> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Level {
+        name: String,
+        boss: Box<dyn Enemy>,
+    }
+
+    fn create_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        registry.register::<Skeleton>();
+        registry.register::<Zombie>();
+        registry
+    }
+
+    #[test]
+    fn should_roundtrip_boxed_trait_object() {
+        let registry = create_registry();
+
+        let level = Level {
+            name: String::from("Level 1"),
+            boss: Box::new(Zombie {
+                health: 20,
+                walk_speed: 0.5,
+            }),
+        };
+
+        let serializer = ReflectSerializer::new(&level, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+
+        let expected = r#"{"bevy_reflect::boxed::tests::Level":(name:"Level 1",boss:{"bevy_reflect::boxed::tests::Zombie":(health:20,walk_speed:0.5)})}"#;
+        assert_eq!(expected, serialized);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let level = Level::from_reflect(&*deserialized).unwrap();
+
+        assert_eq!("Level 1", level.name);
+        assert_eq!(20, level.boss.hp());
+        assert!((*level.boss).as_reflect().is::<Zombie>());
+    }
+
+    #[test]
+    fn should_access_and_apply_boxed_value() {
+        let registry = create_registry();
+        let mut boss: Box<dyn Enemy> = Box::new(Skeleton(10));
+
+        let reflect_boxed = registry
+            .get_type_data::<ReflectBoxed>(core::any::TypeId::of::<Box<dyn Enemy>>())
+            .unwrap();
+        assert!(reflect_boxed.get(&boss).unwrap().is::<Skeleton>());
+
+        // Applying a value of the concrete type updates the value in the box
+        boss.apply(&Skeleton(15));
+        assert_eq!(15, boss.hp());
+
+        // Applying another box replaces the value entirely
+        let zombie: Box<dyn Enemy> = Box::new(Zombie {
+            health: 20,
+            walk_speed: 0.5,
+        });
+        boss.apply(&zombie);
+        assert_eq!(20, boss.hp());
+        assert!(boss.reflect_partial_eq(&zombie).unwrap());
+
+        let boxed = reflect_boxed
+            .from_value(Box::new(Skeleton(5)), &registry)
+            .unwrap();
+        assert_eq!(5, boxed.downcast_ref::<Box<dyn Enemy>>().unwrap().hp());
+    }
+}
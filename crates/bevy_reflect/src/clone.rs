@@ -0,0 +1,292 @@
+use crate::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Map, PartialReflect, Reflect, ReflectFromReflect,
+    ReflectRef, Set, TypeInfo, TypeRegistry, VariantField, VariantType,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::any::TypeId;
+use thiserror::Error;
+
+/// An error returned by [`PartialReflect::reflect_clone_deep`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ReflectCloneError {
+    /// The value is a dynamic type which doesn't represent any concrete type.
+    #[error("cannot clone dynamic value without represented type: `{type_path}`")]
+    NoRepresentedType { type_path: Box<str> },
+
+    /// The type isn't registered, or its registration has no [`ReflectFromReflect`].
+    #[error("type `{type_path}` has no registered `ReflectFromReflect`")]
+    MissingFromReflect { type_path: Box<str> },
+
+    /// [`ReflectFromReflect`] failed to convert the cloned data into the concrete type.
+    #[error("failed to convert cloned data into `{type_path}`")]
+    FromReflectFailed { type_path: Box<str> },
+
+    /// The value contains itself.
+    #[error("value of type `{type_path}` contains itself")]
+    Cycle { type_path: Box<str> },
+}
+
+/// Clones reflected values into concrete values, keeping track of the values
+/// currently being cloned to detect cycles.
+pub(crate) struct DeepCloner<'a> {
+    registry: &'a TypeRegistry,
+    ancestors: Vec<(*const (), TypeId)>,
+}
+
+impl<'a> DeepCloner<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            ancestors: Vec::new(),
+        }
+    }
+
+    pub fn clone_value(
+        &mut self,
+        value: &dyn PartialReflect,
+    ) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let type_info = value.get_represented_type_info().ok_or_else(|| {
+            ReflectCloneError::NoRepresentedType {
+                type_path: value.reflect_type_path().into(),
+            }
+        })?;
+
+        // Values are identified by their address and type, since a value's first field
+        // has the same address as the value itself
+        let key = (
+            value as *const dyn PartialReflect as *const (),
+            type_info.type_id(),
+        );
+        if self.ancestors.contains(&key) {
+            return Err(ReflectCloneError::Cycle {
+                type_path: type_info.type_path().into(),
+            });
+        }
+
+        self.ancestors.push(key);
+        let result = self.clone_with_info(value, type_info);
+        self.ancestors.pop();
+        result
+    }
+
+    fn clone_with_info(
+        &mut self,
+        value: &dyn PartialReflect,
+        type_info: &'static TypeInfo,
+    ) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        let data: Box<dyn PartialReflect> = match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                let mut dynamic = DynamicStruct::default();
+                dynamic.set_represented_type(Some(type_info));
+                for (index, field) in value.iter_fields().enumerate() {
+                    let name = value.name_at(index).unwrap();
+                    dynamic.insert_boxed(name, self.clone_value(field)?.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            ReflectRef::TupleStruct(value) => {
+                let mut dynamic = DynamicTupleStruct::default();
+                dynamic.set_represented_type(Some(type_info));
+                for field in value.iter_fields() {
+                    dynamic.insert_boxed(self.clone_value(field)?.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            ReflectRef::Tuple(value) => {
+                let mut dynamic = DynamicTuple::default();
+                dynamic.set_represented_type(Some(type_info));
+                for field in value.iter_fields() {
+                    dynamic.insert_boxed(self.clone_value(field)?.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            ReflectRef::List(value) => {
+                let mut dynamic = DynamicList::default();
+                dynamic.set_represented_type(Some(type_info));
+                for item in value.iter() {
+                    dynamic.push_box(self.clone_value(item)?.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            ReflectRef::Array(value) => {
+                let mut dynamic = value
+                    .iter()
+                    .map(|item| Ok(self.clone_value(item)?.into_partial_reflect()))
+                    .collect::<Result<DynamicArray, _>>()?;
+                dynamic.set_represented_type(Some(type_info));
+                Box::new(dynamic)
+            }
+            ReflectRef::Map(value) => {
+                let mut dynamic = DynamicMap::default();
+                dynamic.set_represented_type(Some(type_info));
+                for (key, value) in value.iter() {
+                    dynamic.insert_boxed(
+                        self.clone_value(key)?.into_partial_reflect(),
+                        self.clone_value(value)?.into_partial_reflect(),
+                    );
+                }
+                Box::new(dynamic)
+            }
+            ReflectRef::Set(value) => {
+                let mut dynamic = DynamicSet::default();
+                dynamic.set_represented_type(Some(type_info));
+                for item in value.iter() {
+                    dynamic.insert_boxed(self.clone_value(item)?.into_partial_reflect());
+                }
+                Box::new(dynamic)
+            }
+            ReflectRef::Enum(value) => {
+                let variant = match value.variant_type() {
+                    VariantType::Unit => DynamicVariant::Unit,
+                    VariantType::Tuple => {
+                        let mut tuple = DynamicTuple::default();
+                        for field in value.iter_fields() {
+                            tuple.insert_boxed(
+                                self.clone_value(field.value())?.into_partial_reflect(),
+                            );
+                        }
+                        DynamicVariant::Tuple(tuple)
+                    }
+                    VariantType::Struct => {
+                        let mut fields = DynamicStruct::default();
+                        for field in value.iter_fields() {
+                            if let VariantField::Struct(name, field) = field {
+                                fields.insert_boxed(
+                                    name,
+                                    self.clone_value(field)?.into_partial_reflect(),
+                                );
+                            }
+                        }
+                        DynamicVariant::Struct(fields)
+                    }
+                };
+                let mut dynamic = DynamicEnum::new_with_index(
+                    value.variant_index(),
+                    value.variant_name(),
+                    variant,
+                );
+                dynamic.set_represented_type(Some(type_info));
+                Box::new(dynamic)
+            }
+            #[cfg(feature = "functions")]
+            ReflectRef::Function(value) => value.clone_value(),
+            ReflectRef::Opaque(value) => {
+                // Opaque values are cloned as a whole, which usually gives a concrete value
+                let cloned = value.clone_value();
+                if cloned.is_dynamic() {
+                    cloned
+                } else {
+                    match cloned.try_into_reflect() {
+                        Ok(cloned) => return Ok(cloned),
+                        Err(cloned) => cloned,
+                    }
+                }
+            }
+        };
+
+        self.registry
+            .get_type_data::<ReflectFromReflect>(type_info.type_id())
+            .ok_or_else(|| ReflectCloneError::MissingFromReflect {
+                type_path: type_info.type_path().into(),
+            })?
+            .from_reflect(data.as_partial_reflect())
+            .ok_or_else(|| ReflectCloneError::FromReflectFailed {
+                type_path: type_info.type_path().into(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{GetTypeRegistration, TypePath};
+    use alloc::{string::String, sync::Arc, vec};
+    use bevy_platform_support::collections::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Inventory {
+        items: Vec<Item>,
+        counts: HashMap<String, u32>,
+        shared: Arc<String>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Item {
+        Sword { damage: f32 },
+        Potion(u8),
+        Empty,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Inventory>();
+        registry
+    }
+
+    fn inventory() -> Inventory {
+        Inventory {
+            items: vec![Item::Sword { damage: 2.5 }, Item::Potion(3), Item::Empty],
+            counts: [(String::from("gold"), 10)].into_iter().collect(),
+            shared: Arc::new(String::from("shared")),
+        }
+    }
+
+    #[test]
+    fn should_clone_concrete_value() {
+        let registry = registry();
+        let value = inventory();
+
+        let cloned = value.reflect_clone_deep(&registry).unwrap();
+        let cloned = cloned.downcast::<Inventory>().unwrap();
+        assert_eq!(*cloned, value);
+        // Opaque values are cloned with `Clone`, so `Arc`s are shared rather than duplicated
+        assert!(Arc::ptr_eq(&cloned.shared, &value.shared));
+    }
+
+    #[test]
+    fn should_clone_dynamic_value() {
+        let registry = registry();
+        let value = inventory();
+        let dynamic = value.clone_value();
+        assert!(dynamic.is_dynamic());
+
+        let cloned = dynamic.reflect_clone_deep(&registry).unwrap();
+        assert_eq!(cloned.downcast_ref::<Inventory>(), Some(&value));
+    }
+
+    #[test]
+    fn should_fail_without_represented_type() {
+        let registry = registry();
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("foo", 1u32);
+
+        assert_eq!(
+            dynamic.reflect_clone_deep(&registry).unwrap_err(),
+            ReflectCloneError::NoRepresentedType {
+                type_path: "bevy_reflect::DynamicStruct".into()
+            }
+        );
+    }
+
+    #[test]
+    fn should_fail_without_from_reflect() {
+        #[derive(Reflect)]
+        #[reflect(from_reflect = false)]
+        struct Foo {
+            value: u32,
+        }
+
+        let mut registry = TypeRegistry::empty();
+        registry.add_registration(Foo::get_type_registration());
+        registry.register::<u32>();
+
+        assert_eq!(
+            Foo { value: 1 }.reflect_clone_deep(&registry).unwrap_err(),
+            ReflectCloneError::MissingFromReflect {
+                type_path: Foo::type_path().into()
+            }
+        );
+    }
+}
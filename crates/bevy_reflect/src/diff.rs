@@ -0,0 +1,841 @@
+use crate::{
+    ApplyError, Enum, List, Map, PartialReflect, ReflectKind, ReflectMut, ReflectRef, Set, Struct,
+    VariantType,
+};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use thiserror::Error;
+
+/// A structural difference between two reflected values.
+///
+/// A diff describes how to turn an old value into a new one, in terms of only the parts that
+/// changed: the fields of a struct, the elements inserted into or removed from a list, or the
+/// entries of a map. It can be [applied] to the old value, or to any other value with the
+/// same structure, to make the same changes to it.
+/// This is useful for sending only the changes to a value over the network, for undo and redo,
+/// or for storing the changes made to a copy of a prefab.
+///
+/// Diffs are usually created using [`PartialReflect::diff`].
+///
+/// Values which are equal according to [`PartialReflect::reflect_partial_eq`] are
+/// [`Diff::Unchanged`].
+/// When two values have different types or kinds, or can't be compared any more finely
+/// (such as [opaque] values, or enums with different variants), the diff replaces the old
+/// value with a clone of the new one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Diff, PartialReflect, Reflect};
+/// #[derive(Reflect, Debug, PartialEq, Clone)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+///     items: Vec<String>,
+/// }
+///
+/// let old = Player {
+///     name: "Alice".to_string(),
+///     health: 100.0,
+///     items: vec!["sword".to_string()],
+/// };
+/// let new = Player {
+///     name: "Alice".to_string(),
+///     health: 75.0,
+///     items: vec!["sword".to_string(), "shield".to_string()],
+/// };
+///
+/// let diff = old.diff(&new);
+/// // Only the changed fields are stored
+/// let Diff::Struct(fields) = &diff else {
+///     panic!("expected a struct diff");
+/// };
+/// assert_eq!(fields.len(), 2);
+///
+/// let mut value = old.clone();
+/// diff.apply(&mut value).unwrap();
+/// assert_eq!(value, new);
+/// ```
+///
+/// [applied]: Diff::apply
+/// [opaque]: ReflectKind::Opaque
+#[derive(Debug)]
+pub enum Diff {
+    /// The values are equal.
+    Unchanged,
+    /// The old value is replaced by this new value.
+    Replaced(Box<dyn PartialReflect>),
+    /// Some fields of a [`Struct`] changed, identified by their names.
+    Struct(Vec<(String, Diff)>),
+    /// Some fields of a [`TupleStruct`](crate::TupleStruct) changed, identified by their indices.
+    TupleStruct(Vec<(usize, Diff)>),
+    /// Some fields of a [`Tuple`](crate::Tuple) changed, identified by their indices.
+    Tuple(Vec<(usize, Diff)>),
+    /// Some elements of an [`Array`](crate::Array) changed, identified by their indices.
+    Array(Vec<(usize, Diff)>),
+    /// Elements of a [`List`] were inserted, removed or changed.
+    List(Vec<ListDiffOp>),
+    /// Entries of a [`Map`] were inserted, removed or changed.
+    Map(MapDiff),
+    /// Values were inserted into or removed from a [`Set`].
+    Set(SetDiff),
+    /// Some fields of an [`Enum`]'s struct variant changed, identified by their names.
+    ///
+    /// The variant itself is the same for both values.
+    StructVariant(Vec<(String, Diff)>),
+    /// Some fields of an [`Enum`]'s tuple variant changed, identified by their indices.
+    ///
+    /// The variant itself is the same for both values.
+    TupleVariant(Vec<(usize, Diff)>),
+}
+
+/// A single change to a [`List`], as part of a [`Diff::List`].
+///
+/// Indices refer to the list as it is when the operation is applied, after all
+/// previous operations of the diff have been applied.
+#[derive(Debug)]
+pub enum ListDiffOp {
+    /// An element was inserted at this index.
+    Insert(usize, Box<dyn PartialReflect>),
+    /// The element at this index was removed.
+    Remove(usize),
+    /// The element at this index changed.
+    Modify(usize, Diff),
+}
+
+/// The changes to a [`Map`], as part of a [`Diff::Map`].
+#[derive(Debug, Default)]
+pub struct MapDiff {
+    /// The keys of the entries which were removed.
+    pub removed: Vec<Box<dyn PartialReflect>>,
+    /// The entries which were inserted.
+    pub inserted: Vec<(Box<dyn PartialReflect>, Box<dyn PartialReflect>)>,
+    /// The keys of the entries whose values changed, and how they changed.
+    pub modified: Vec<(Box<dyn PartialReflect>, Diff)>,
+}
+
+/// The changes to a [`Set`], as part of a [`Diff::Set`].
+#[derive(Debug, Default)]
+pub struct SetDiff {
+    /// The values which were removed.
+    pub removed: Vec<Box<dyn PartialReflect>>,
+    /// The values which were inserted.
+    pub inserted: Vec<Box<dyn PartialReflect>>,
+}
+
+/// An error returned when [applying](Diff::apply) a [`Diff`] to a value fails.
+#[derive(Error, Debug)]
+pub enum DiffApplyError {
+    /// The diff was created from values of a different [kind](ReflectKind) than the target.
+    #[error("attempted to apply a diff of a `{diff_kind}` to a `{target_kind}`")]
+    MismatchedKinds {
+        diff_kind: ReflectKind,
+        target_kind: ReflectKind,
+    },
+    /// The diff was created from a different kind of enum variant than the target's.
+    #[error(
+        "attempted to apply a diff of a {diff_variant:?} variant to a {target_variant:?} variant"
+    )]
+    MismatchedVariants {
+        diff_variant: VariantType,
+        target_variant: VariantType,
+    },
+    /// The target doesn't have a field with this name.
+    #[error("no field named `{0}` in the target")]
+    MissingField(String),
+    /// The target doesn't have a field or element at this index.
+    #[error("no field or element at index {0} in the target")]
+    MissingIndex(usize),
+    /// The target map doesn't have an entry with a changed key.
+    #[error("no entry for a modified key in the target map")]
+    MissingKey,
+    /// Applying a replaced value failed.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+}
+
+impl Diff {
+    /// Computes the diff which turns `old` into `new`.
+    ///
+    /// This is the same as calling [`PartialReflect::diff`] on `old`.
+    pub fn new(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Self {
+        if values_eq(old, new) {
+            return Diff::Unchanged;
+        }
+
+        let same_type = match (
+            old.get_represented_type_info(),
+            new.get_represented_type_info(),
+        ) {
+            (Some(old_info), Some(new_info)) => old_info.type_id() == new_info.type_id(),
+            _ => true,
+        };
+        if !same_type {
+            return Diff::Replaced(new.clone_value());
+        }
+
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => diff_struct(old, new),
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => {
+                diff_indexed(old.field_len(), new.field_len(), |index| {
+                    Some((old.field(index)?, new.field(index)?))
+                })
+                .map_or_else(|| Diff::Replaced(new.clone_value()), Diff::TupleStruct)
+            }
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => {
+                diff_indexed(old.field_len(), new.field_len(), |index| {
+                    Some((old.field(index)?, new.field(index)?))
+                })
+                .map_or_else(|| Diff::Replaced(new.clone_value()), Diff::Tuple)
+            }
+            (ReflectRef::Array(old), ReflectRef::Array(new)) => {
+                diff_indexed(old.len(), new.len(), |index| {
+                    Some((old.get(index)?, new.get(index)?))
+                })
+                .map_or_else(|| Diff::Replaced(new.clone_value()), Diff::Array)
+            }
+            (ReflectRef::List(old), ReflectRef::List(new)) => diff_list(old, new),
+            (ReflectRef::Map(old), ReflectRef::Map(new)) => diff_map(old, new),
+            (ReflectRef::Set(old), ReflectRef::Set(new)) => diff_set(old, new),
+            (ReflectRef::Enum(old), ReflectRef::Enum(new)) => diff_enum(old, new),
+            _ => Diff::Replaced(new.clone_value()),
+        }
+    }
+
+    /// Returns `true` if this diff doesn't change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Diff::Unchanged)
+    }
+
+    /// Applies the changes described by this diff to `target`.
+    ///
+    /// `target` doesn't need to be the value the diff was created from, as long as it has the
+    /// fields, elements and entries which the diff changes.
+    ///
+    /// # Handling Errors
+    ///
+    /// This function may leave `target` in a partially changed state if an error is returned.
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), DiffApplyError> {
+        match self {
+            Diff::Unchanged => return Ok(()),
+            Diff::Replaced(value) => return Ok(target.try_apply(value.as_partial_reflect())?),
+            _ => {}
+        }
+
+        match (self, target.reflect_mut()) {
+            (Diff::Struct(fields), ReflectMut::Struct(target)) => {
+                for (name, diff) in fields {
+                    let field = target
+                        .field_mut(name)
+                        .ok_or_else(|| DiffApplyError::MissingField(name.clone()))?;
+                    diff.apply(field)?;
+                }
+                Ok(())
+            }
+            (Diff::TupleStruct(fields), ReflectMut::TupleStruct(target)) => {
+                apply_indexed(fields, target, |target, index| target.field_mut(index))
+            }
+            (Diff::Tuple(fields), ReflectMut::Tuple(target)) => {
+                apply_indexed(fields, target, |target, index| target.field_mut(index))
+            }
+            (Diff::Array(elements), ReflectMut::Array(target)) => {
+                apply_indexed(elements, target, |target, index| target.get_mut(index))
+            }
+            (Diff::List(ops), ReflectMut::List(target)) => apply_list(ops, target),
+            (Diff::Map(diff), ReflectMut::Map(target)) => {
+                for key in &diff.removed {
+                    target.remove(key.as_partial_reflect());
+                }
+                for (key, diff) in &diff.modified {
+                    let value = target
+                        .get_mut(key.as_partial_reflect())
+                        .ok_or(DiffApplyError::MissingKey)?;
+                    diff.apply(value)?;
+                }
+                for (key, value) in &diff.inserted {
+                    target.insert_boxed(key.clone_value(), value.clone_value());
+                }
+                Ok(())
+            }
+            (Diff::Set(diff), ReflectMut::Set(target)) => {
+                for value in &diff.removed {
+                    target.remove(value.as_partial_reflect());
+                }
+                for value in &diff.inserted {
+                    target.insert_boxed(value.clone_value());
+                }
+                Ok(())
+            }
+            (Diff::StructVariant(fields), ReflectMut::Enum(target)) => {
+                check_variant(VariantType::Struct, target.variant_type())?;
+                for (name, diff) in fields {
+                    let field = target
+                        .field_mut(name)
+                        .ok_or_else(|| DiffApplyError::MissingField(name.clone()))?;
+                    diff.apply(field)?;
+                }
+                Ok(())
+            }
+            (Diff::TupleVariant(fields), ReflectMut::Enum(target)) => {
+                check_variant(VariantType::Tuple, target.variant_type())?;
+                apply_indexed(fields, target, |target, index| target.field_at_mut(index))
+            }
+            (diff, target) => Err(DiffApplyError::MismatchedKinds {
+                diff_kind: diff.kind(),
+                target_kind: target.kind(),
+            }),
+        }
+    }
+
+    fn kind(&self) -> ReflectKind {
+        match self {
+            Diff::Unchanged | Diff::Replaced(_) => ReflectKind::Opaque,
+            Diff::Struct(_) => ReflectKind::Struct,
+            Diff::TupleStruct(_) => ReflectKind::TupleStruct,
+            Diff::Tuple(_) => ReflectKind::Tuple,
+            Diff::Array(_) => ReflectKind::Array,
+            Diff::List(_) => ReflectKind::List,
+            Diff::Map(_) => ReflectKind::Map,
+            Diff::Set(_) => ReflectKind::Set,
+            Diff::StructVariant(_) | Diff::TupleVariant(_) => ReflectKind::Enum,
+        }
+    }
+}
+
+fn values_eq(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    a.reflect_partial_eq(b).unwrap_or(false)
+}
+
+fn diff_struct(old: &dyn Struct, new: &dyn Struct) -> Diff {
+    if old.field_len() != new.field_len() {
+        return Diff::Replaced(new.clone_value());
+    }
+
+    let mut fields = Vec::new();
+    for (index, new_field) in new.iter_fields().enumerate() {
+        let Some(name) = new.name_at(index) else {
+            return Diff::Replaced(new.clone_value());
+        };
+        let Some(old_field) = old.field(name) else {
+            return Diff::Replaced(new.clone_value());
+        };
+        let diff = Diff::new(old_field, new_field);
+        if !diff.is_unchanged() {
+            fields.push((String::from(name), diff));
+        }
+    }
+    changed_or_unchanged(fields, Diff::Struct)
+}
+
+/// Diffs the fields of two values with indexed fields, returning `None` if they have
+/// different numbers of fields.
+fn diff_indexed<'a>(
+    old_len: usize,
+    new_len: usize,
+    field: impl Fn(usize) -> Option<(&'a dyn PartialReflect, &'a dyn PartialReflect)>,
+) -> Option<Vec<(usize, Diff)>> {
+    if old_len != new_len {
+        return None;
+    }
+
+    let mut fields = Vec::new();
+    for index in 0..new_len {
+        let (old_field, new_field) = field(index)?;
+        let diff = Diff::new(old_field, new_field);
+        if !diff.is_unchanged() {
+            fields.push((index, diff));
+        }
+    }
+    Some(fields)
+}
+
+fn diff_enum(old: &dyn Enum, new: &dyn Enum) -> Diff {
+    if old.variant_name() != new.variant_name()
+        || old.variant_type() != new.variant_type()
+        || old.field_len() != new.field_len()
+    {
+        return Diff::Replaced(new.clone_value());
+    }
+
+    match new.variant_type() {
+        VariantType::Unit => Diff::Unchanged,
+        VariantType::Struct => {
+            let mut fields = Vec::new();
+            for index in 0..new.field_len() {
+                let Some((name, new_field)) = new.name_at(index).zip(new.field_at(index)) else {
+                    return Diff::Replaced(new.clone_value());
+                };
+                let Some(old_field) = old.field(name) else {
+                    return Diff::Replaced(new.clone_value());
+                };
+                let diff = Diff::new(old_field, new_field);
+                if !diff.is_unchanged() {
+                    fields.push((String::from(name), diff));
+                }
+            }
+            changed_or_unchanged(fields, Diff::StructVariant)
+        }
+        VariantType::Tuple => match diff_indexed(old.field_len(), new.field_len(), |index| {
+            Some((old.field_at(index)?, new.field_at(index)?))
+        }) {
+            Some(fields) => changed_or_unchanged(fields, Diff::TupleVariant),
+            None => Diff::Replaced(new.clone_value()),
+        },
+    }
+}
+
+/// The largest number of pairs of elements [`diff_list`] compares to find the longest common
+/// subsequence of two lists, since that takes time and memory proportional to their product.
+const MAX_LIST_DIFF_PAIRS: usize = 1 << 20;
+
+/// Diffs two lists by finding their longest common subsequence of equal elements.
+///
+/// Between the common elements, old and new elements are paired up as modifications, and any
+/// remaining ones become removals or insertions.
+///
+/// Finding the longest common subsequence takes `O(n * m)` time and memory for the `n` and `m`
+/// elements between the equal elements at the start and end of the lists. If there are more than
+/// [`MAX_LIST_DIFF_PAIRS`] pairs of those, they're paired up by index instead, which is linear
+/// but may modify more elements than necessary.
+fn diff_list(old: &dyn List, new: &dyn List) -> Diff {
+    let old = old.iter().collect::<Vec<_>>();
+    let new = new.iter().collect::<Vec<_>>();
+
+    // Equal elements at the start and end of the lists never need to be compared to the others
+    let prefix = old
+        .iter()
+        .zip(&new)
+        .take_while(|(old, new)| values_eq(**old, **new))
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| values_eq(**old, **new))
+        .count();
+    let old = &old[prefix..old.len() - suffix];
+    let new = &new[prefix..new.len() - suffix];
+
+    let mut ops = Vec::new();
+    // The index in the list being changed, as it is after the operations so far are applied
+    let mut cursor = prefix;
+    if old.len().saturating_mul(new.len()) > MAX_LIST_DIFF_PAIRS {
+        push_list_gap(&mut ops, &mut cursor, old, new);
+        return changed_or_unchanged(ops, Diff::List);
+    }
+
+    // `lengths[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if values_eq(old[i], new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && values_eq(old[i], new[j]) {
+            cursor += 1;
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        // Find the extent of the elements which differ, up to the next common element
+        let (gap_start_old, gap_start_new) = (i, j);
+        while i < old.len() || j < new.len() {
+            if i < old.len() && j < new.len() && values_eq(old[i], new[j]) {
+                break;
+            }
+            if j == new.len() || (i < old.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        push_list_gap(
+            &mut ops,
+            &mut cursor,
+            &old[gap_start_old..i],
+            &new[gap_start_new..j],
+        );
+    }
+
+    changed_or_unchanged(ops, Diff::List)
+}
+
+/// Pushes the operations which replace the `removed` elements at `cursor` with the `inserted`
+/// ones, pairing them up as modifications where possible.
+fn push_list_gap(
+    ops: &mut Vec<ListDiffOp>,
+    cursor: &mut usize,
+    removed: &[&dyn PartialReflect],
+    inserted: &[&dyn PartialReflect],
+) {
+    let modified = removed.len().min(inserted.len());
+    for (old_element, new_element) in removed.iter().zip(inserted) {
+        ops.push(ListDiffOp::Modify(
+            *cursor,
+            Diff::new(*old_element, *new_element),
+        ));
+        *cursor += 1;
+    }
+    for _ in &removed[modified..] {
+        ops.push(ListDiffOp::Remove(*cursor));
+    }
+    for new_element in &inserted[modified..] {
+        ops.push(ListDiffOp::Insert(*cursor, new_element.clone_value()));
+        *cursor += 1;
+    }
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> Diff {
+    let mut diff = MapDiff::default();
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => {
+                let value_diff = Diff::new(old_value, new_value);
+                if !value_diff.is_unchanged() {
+                    diff.modified.push((key.clone_value(), value_diff));
+                }
+            }
+            None => diff.removed.push(key.clone_value()),
+        }
+    }
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            diff.inserted
+                .push((key.clone_value(), new_value.clone_value()));
+        }
+    }
+
+    if diff.removed.is_empty() && diff.inserted.is_empty() && diff.modified.is_empty() {
+        Diff::Unchanged
+    } else {
+        Diff::Map(diff)
+    }
+}
+
+fn diff_set(old: &dyn Set, new: &dyn Set) -> Diff {
+    let removed = old
+        .iter()
+        .filter(|value| !new.contains(*value))
+        .map(|value| value.clone_value())
+        .collect::<Vec<_>>();
+    let inserted = new
+        .iter()
+        .filter(|value| !old.contains(*value))
+        .map(|value| value.clone_value())
+        .collect::<Vec<_>>();
+
+    if removed.is_empty() && inserted.is_empty() {
+        Diff::Unchanged
+    } else {
+        Diff::Set(SetDiff { removed, inserted })
+    }
+}
+
+fn changed_or_unchanged<T>(changes: Vec<T>, diff: impl FnOnce(Vec<T>) -> Diff) -> Diff {
+    if changes.is_empty() {
+        Diff::Unchanged
+    } else {
+        diff(changes)
+    }
+}
+
+fn apply_indexed<T: ?Sized>(
+    fields: &[(usize, Diff)],
+    target: &mut T,
+    field_mut: impl Fn(&mut T, usize) -> Option<&mut dyn PartialReflect>,
+) -> Result<(), DiffApplyError> {
+    for (index, diff) in fields {
+        let field = field_mut(target, *index).ok_or(DiffApplyError::MissingIndex(*index))?;
+        diff.apply(field)?;
+    }
+    Ok(())
+}
+
+fn apply_list(ops: &[ListDiffOp], target: &mut dyn List) -> Result<(), DiffApplyError> {
+    for op in ops {
+        match op {
+            ListDiffOp::Insert(index, value) => {
+                if *index > target.len() {
+                    return Err(DiffApplyError::MissingIndex(*index));
+                }
+                target.insert(*index, value.clone_value());
+            }
+            ListDiffOp::Remove(index) => {
+                if *index >= target.len() {
+                    return Err(DiffApplyError::MissingIndex(*index));
+                }
+                target.remove(*index);
+            }
+            ListDiffOp::Modify(index, diff) => {
+                let element = target
+                    .get_mut(*index)
+                    .ok_or(DiffApplyError::MissingIndex(*index))?;
+                diff.apply(element)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_variant(
+    diff_variant: VariantType,
+    target_variant: VariantType,
+) -> Result<(), DiffApplyError> {
+    if diff_variant == target_variant {
+        Ok(())
+    } else {
+        Err(DiffApplyError::MismatchedVariants {
+            diff_variant,
+            target_variant,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, DynamicList, Reflect};
+    use alloc::{string::ToString, vec};
+    use bevy_platform_support::collections::{HashMap, HashSet};
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Inner {
+        a: i32,
+        b: String,
+    }
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Outer {
+        inner: Inner,
+        list: Vec<Inner>,
+        map: HashMap<u32, String>,
+        set: HashSet<u32>,
+        shape: Shape,
+        tuple: (u8, u8),
+        array: [u8; 3],
+    }
+
+    fn outer() -> Outer {
+        Outer {
+            inner: Inner {
+                a: 1,
+                b: "one".to_string(),
+            },
+            list: vec![
+                Inner {
+                    a: 1,
+                    b: "one".to_string(),
+                },
+                Inner {
+                    a: 2,
+                    b: "two".to_string(),
+                },
+            ],
+            map: [(1, "one".to_string()), (2, "two".to_string())]
+                .into_iter()
+                .collect(),
+            set: [1, 2, 3].into_iter().collect(),
+            shape: Shape::Circle { radius: 1.0 },
+            tuple: (1, 2),
+            array: [1, 2, 3],
+        }
+    }
+
+    fn assert_roundtrip(old: &Outer, new: &Outer) -> Diff {
+        let diff = old.diff(new);
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(&value, new);
+        diff
+    }
+
+    #[test]
+    fn should_diff_equal_values_as_unchanged() {
+        assert!(outer().diff(&outer()).is_unchanged());
+    }
+
+    #[test]
+    fn should_diff_changed_struct_fields() {
+        let old = outer();
+        let mut new = outer();
+        new.inner.a = 5;
+
+        let diff = assert_roundtrip(&old, &new);
+        let Diff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].0, "inner");
+        let Diff::Struct(inner_fields) = &fields[0].1 else {
+            panic!("expected struct diff");
+        };
+        assert_eq!(inner_fields.len(), 1);
+        assert_eq!(inner_fields[0].0, "a");
+        assert!(matches!(inner_fields[0].1, Diff::Replaced(_)));
+    }
+
+    #[test]
+    fn should_diff_list_insertions_and_removals() {
+        let old = outer();
+        let mut new = outer();
+        new.list.remove(0);
+        new.list.push(Inner {
+            a: 3,
+            b: "three".to_string(),
+        });
+        new.list.push(Inner {
+            a: 4,
+            b: "four".to_string(),
+        });
+
+        assert_roundtrip(&old, &new);
+
+        let old_list = [1, 2, 3, 4].into_iter().collect::<Vec<i32>>();
+        let new_list = [0, 2, 4, 5].into_iter().collect::<Vec<i32>>();
+        let diff = old_list.diff(&new_list);
+        let Diff::List(ops) = &diff else {
+            panic!("expected list diff");
+        };
+        assert!(matches!(ops[0], ListDiffOp::Modify(0, _)));
+        assert!(matches!(ops[1], ListDiffOp::Remove(2)));
+        assert!(matches!(ops[2], ListDiffOp::Insert(3, _)));
+
+        let mut value = old_list.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, new_list);
+    }
+
+    #[test]
+    fn should_diff_maps_and_sets() {
+        let old = outer();
+        let mut new = outer();
+        new.map.remove(&1);
+        new.map.insert(2, "deux".to_string());
+        new.map.insert(3, "trois".to_string());
+        new.set.remove(&2);
+        new.set.insert(4);
+
+        let diff = assert_roundtrip(&old, &new);
+        let Diff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        let (_, Diff::Map(map_diff)) = &fields[0] else {
+            panic!("expected map diff");
+        };
+        assert_eq!(map_diff.removed.len(), 1);
+        assert_eq!(map_diff.inserted.len(), 1);
+        assert_eq!(map_diff.modified.len(), 1);
+        let (_, Diff::Set(set_diff)) = &fields[1] else {
+            panic!("expected set diff");
+        };
+        assert_eq!(set_diff.removed.len(), 1);
+        assert_eq!(set_diff.inserted.len(), 1);
+    }
+
+    #[test]
+    fn should_diff_enums() {
+        let old = outer();
+
+        let mut new = outer();
+        new.shape = Shape::Circle { radius: 2.0 };
+        let diff = assert_roundtrip(&old, &new);
+        let Diff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(fields[0].1, Diff::StructVariant(_)));
+
+        new.shape = Shape::Rect(1.0, 2.0);
+        let diff = assert_roundtrip(&old, &new);
+        let Diff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(fields[0].1, Diff::Replaced(_)));
+
+        let diff = Shape::Rect(1.0, 2.0).diff(&Shape::Rect(1.0, 3.0));
+        assert!(matches!(diff, Diff::TupleVariant(ref fields) if fields.len() == 1));
+        let mut value = Shape::Rect(5.0, 5.0);
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, Shape::Rect(5.0, 3.0));
+
+        let mut value = Shape::Point;
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(DiffApplyError::MismatchedVariants { .. })
+        ));
+    }
+
+    #[test]
+    fn should_diff_tuples_and_arrays() {
+        let old = outer();
+        let mut new = outer();
+        new.tuple.1 = 5;
+        new.array[2] = 5;
+
+        let diff = assert_roundtrip(&old, &new);
+        let Diff::Struct(fields) = diff else {
+            panic!("expected struct diff");
+        };
+        assert!(matches!(fields[0].1, Diff::Tuple(ref fields) if fields[0].0 == 1));
+        assert!(matches!(fields[1].1, Diff::Array(ref fields) if fields[0].0 == 2));
+    }
+
+    #[test]
+    fn should_apply_diff_to_other_value() {
+        let old = outer();
+        let mut new = outer();
+        new.inner.b = "uno".to_string();
+        let diff = old.diff(&new);
+
+        // Changes to other fields are kept
+        let mut other = outer();
+        other.inner.a = 10;
+        other.tuple = (9, 9);
+        diff.apply(&mut other).unwrap();
+        assert_eq!(other.inner.a, 10);
+        assert_eq!(other.inner.b, "uno");
+        assert_eq!(other.tuple, (9, 9));
+    }
+
+    #[test]
+    fn should_diff_dynamic_values() {
+        let mut old = DynamicList::default();
+        old.push(1_i32);
+        old.push(2_i32);
+        let mut new = DynamicList::default();
+        new.push(1_i32);
+        new.push(3_i32);
+
+        let diff = old.diff(&new);
+        let mut value = vec![1_i32, 2];
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, vec![1, 3]);
+    }
+
+    #[test]
+    fn should_not_apply_diff_to_mismatched_kind() {
+        let diff = (1_u8, 2_u8).diff(&(1_u8, 3_u8));
+        let mut value = vec![1_u8, 2];
+        assert!(matches!(
+            diff.apply(&mut value),
+            Err(DiffApplyError::MismatchedKinds {
+                diff_kind: ReflectKind::Tuple,
+                target_kind: ReflectKind::List,
+            })
+        ));
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     Struct, Tuple, TypeInfo, VariantFieldIter, VariantType,
 };
 
-use alloc::{boxed::Box, string::String};
+use alloc::{borrow::Cow, boxed::Box, string::String};
 use core::fmt::Formatter;
 use derive_more::derive::From;
 
@@ -186,6 +186,254 @@ impl DynamicEnum {
         dyn_enum.set_represented_type(type_info);
         dyn_enum
     }
+
+    /// Returns a [`DynamicEnumBuilder`] for fluently constructing a `DynamicEnum`
+    /// with the given variant.
+    ///
+    /// The variant is a unit variant unless fields are added to it.
+    /// See also the [`dyn_enum!`](crate::dyn_enum) macro.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{DynamicEnum, Enum, PartialReflect, Typed, VariantType};
+    /// let dyn_enum = DynamicEnum::builder("Some")
+    ///     .represented_type(Option::<usize>::type_info())
+    ///     .tuple_field(123usize)
+    ///     .build();
+    ///
+    /// assert_eq!(dyn_enum.variant_type(), VariantType::Tuple);
+    /// assert_eq!(dyn_enum.variant_index(), 1);
+    ///
+    /// let mut value: Option<usize> = None;
+    /// value.apply(dyn_enum.as_partial_reflect());
+    /// assert_eq!(value, Some(123));
+    /// ```
+    pub fn builder<I: Into<String>>(variant_name: I) -> DynamicEnumBuilder {
+        DynamicEnumBuilder {
+            represented_type: None,
+            variant_name: variant_name.into(),
+            variant_index: None,
+            variant: DynamicVariant::Unit,
+        }
+    }
+}
+
+/// A builder for [`DynamicEnum`].
+///
+/// Created with [`DynamicEnum::builder`].
+pub struct DynamicEnumBuilder {
+    represented_type: Option<&'static TypeInfo>,
+    variant_name: String,
+    variant_index: Option<usize>,
+    variant: DynamicVariant,
+}
+
+impl DynamicEnumBuilder {
+    /// Sets the [type] to be represented by the built `DynamicEnum`.
+    ///
+    /// Unless a variant index is given with [`variant_index`](Self::variant_index),
+    /// the index of the variant is looked up in the given [type].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given [type] is not a [`TypeInfo::Enum`].
+    ///
+    /// [type]: TypeInfo
+    pub fn represented_type(mut self, represented_type: &'static TypeInfo) -> Self {
+        assert!(
+            matches!(represented_type, TypeInfo::Enum(_)),
+            "expected TypeInfo::Enum but received: {:?}",
+            represented_type
+        );
+
+        self.represented_type = Some(represented_type);
+        self
+    }
+
+    /// Sets the index of the variant.
+    pub fn variant_index(mut self, variant_index: usize) -> Self {
+        self.variant_index = Some(variant_index);
+        self
+    }
+
+    /// Adds a field named `name` with the typed value `value`, making the
+    /// variant a struct variant.
+    ///
+    /// If the field already exists, it is overwritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if tuple fields have already been added.
+    pub fn field<'a, T: PartialReflect>(self, name: impl Into<Cow<'a, str>>, value: T) -> Self {
+        self.field_boxed(name, Box::new(value))
+    }
+
+    /// Adds a field named `name` with the boxed value `value`, making the
+    /// variant a struct variant.
+    ///
+    /// If the field already exists, it is overwritten.
+    ///
+    /// # Panics
+    ///
+    /// Panics if tuple fields have already been added.
+    pub fn field_boxed<'a>(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        value: Box<dyn PartialReflect>,
+    ) -> Self {
+        match &mut self.variant {
+            DynamicVariant::Unit => {
+                let mut data = DynamicStruct::default();
+                data.insert_boxed(name, value);
+                self.variant = DynamicVariant::Struct(data);
+            }
+            DynamicVariant::Struct(data) => data.insert_boxed(name, value),
+            DynamicVariant::Tuple(_) => panic!(
+                "cannot add a named field to tuple variant `{}`",
+                self.variant_name
+            ),
+        }
+        self
+    }
+
+    /// Appends a field with the typed value `value`, making the variant a
+    /// tuple variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if named fields have already been added.
+    pub fn tuple_field<T: PartialReflect>(self, value: T) -> Self {
+        self.tuple_field_boxed(Box::new(value))
+    }
+
+    /// Appends a field with the boxed value `value`, making the variant a
+    /// tuple variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if named fields have already been added.
+    pub fn tuple_field_boxed(mut self, value: Box<dyn PartialReflect>) -> Self {
+        match &mut self.variant {
+            DynamicVariant::Unit => {
+                let mut data = DynamicTuple::default();
+                data.insert_boxed(value);
+                self.variant = DynamicVariant::Tuple(data);
+            }
+            DynamicVariant::Tuple(data) => data.insert_boxed(value),
+            DynamicVariant::Struct(_) => panic!(
+                "cannot add a tuple field to struct variant `{}`",
+                self.variant_name
+            ),
+        }
+        self
+    }
+
+    /// Returns the built [`DynamicEnum`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the variant doesn't exist on the represented type.
+    /// See [`try_build`](Self::try_build) for a non-panicking version.
+    pub fn build(self) -> DynamicEnum {
+        self.try_build().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Returns the built [`DynamicEnum`].
+    ///
+    /// Returns [`ApplyError::UnknownVariant`] if no variant index was given
+    /// and the variant doesn't exist on the represented type.
+    pub fn try_build(self) -> Result<DynamicEnum, ApplyError> {
+        let variant_index = match (self.variant_index, self.represented_type) {
+            (Some(variant_index), _) => variant_index,
+            (None, Some(TypeInfo::Enum(info))) => {
+                info.index_of(&self.variant_name)
+                    .ok_or_else(|| ApplyError::UnknownVariant {
+                        enum_name: info.type_path().into(),
+                        variant_name: self.variant_name.as_str().into(),
+                    })?
+            }
+            (None, _) => 0,
+        };
+
+        let mut dyn_enum =
+            DynamicEnum::new_with_index(variant_index, self.variant_name, self.variant);
+        dyn_enum.set_represented_type(self.represented_type);
+        Ok(dyn_enum)
+    }
+}
+
+/// Creates a [`DynamicEnum`] from a variant written like an enum expression.
+///
+/// Unit, tuple and struct variants are supported.
+/// Field values may be any expression whose type implements [`PartialReflect`],
+/// including other dynamic values.
+/// Prefixing the variant with a type implementing [`Typed`](crate::Typed)
+/// also sets the represented type and variant index of the `DynamicEnum`.
+/// Types that aren't a single identifier must be wrapped in angle brackets,
+/// like `<Option<i32>>::Some(1)`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_enum, Enum, FromReflect, Reflect, VariantType};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// enum Shape {
+///     Point,
+///     Circle(f32),
+///     Rect { width: f32, height: f32 },
+/// }
+///
+/// let untyped = dyn_enum!(Circle(1.0f32));
+/// assert_eq!(untyped.variant_name(), "Circle");
+/// assert_eq!(untyped.variant_type(), VariantType::Tuple);
+///
+/// let typed = dyn_enum!(Shape::Rect {
+///     width: 1.0f32,
+///     height: 2.0f32,
+/// });
+/// assert_eq!(
+///     Shape::from_reflect(&typed),
+///     Some(Shape::Rect {
+///         width: 1.0,
+///         height: 2.0,
+///     })
+/// );
+///
+/// let generic = dyn_enum!(<Option<i32>>::Some(1));
+/// assert_eq!(Option::<i32>::from_reflect(&generic), Some(Some(1)));
+/// ```
+#[macro_export]
+macro_rules! dyn_enum {
+    (@fields $builder:expr;) => {
+        $builder.build()
+    };
+    (@fields $builder:expr; ($($value:expr),* $(,)?)) => {
+        $builder
+            $(.tuple_field($value))*
+            .build()
+    };
+    (@fields $builder:expr; { $($name:ident : $value:expr),* $(,)? }) => {
+        $builder
+            $(.field(::core::stringify!($name), $value))*
+            .build()
+    };
+    (<$ty:ty>::$variant:ident $($fields:tt)*) => {
+        $crate::dyn_enum!(@fields
+            $crate::DynamicEnum::builder(::core::stringify!($variant))
+                .represented_type(<$ty as $crate::Typed>::type_info());
+            $($fields)*
+        )
+    };
+    ($ty:ident :: $variant:ident $($fields:tt)*) => {
+        $crate::dyn_enum!(<$ty>::$variant $($fields)*)
+    };
+    ($variant:ident $($fields:tt)*) => {
+        $crate::dyn_enum!(@fields
+            $crate::DynamicEnum::builder(::core::stringify!($variant));
+            $($fields)*
+        )
+    };
 }
 
 impl Enum for DynamicEnum {
@@ -691,4 +691,55 @@ mod tests {
             "expected TestEnum::C{{value: 123}} != TestEnum::C2{{value: 1.23}}"
         );
     }
+
+    #[test]
+    fn should_build_dynamic_enum() {
+        let unit = dyn_enum!(MyEnum::A);
+        assert_eq!(unit.variant_type(), VariantType::Unit);
+        assert_eq!(MyEnum::from_reflect(&unit), Some(MyEnum::A));
+
+        let tuple = DynamicEnum::builder("B")
+            .represented_type(MyEnum::type_info())
+            .tuple_field(123usize)
+            .tuple_field(-321i32)
+            .build();
+        assert_eq!(tuple.variant_index(), 1);
+        assert_eq!(MyEnum::from_reflect(&tuple), Some(MyEnum::B(123, -321)));
+        assert!(tuple
+            .reflect_partial_eq(&dyn_enum!(MyEnum::B(123usize, -321i32)))
+            .unwrap());
+
+        let untyped = dyn_enum!(C {
+            foo: 1.23f32,
+            bar: true,
+        });
+        assert!(untyped.get_represented_type_info().is_none());
+        assert_eq!(untyped.variant_type(), VariantType::Struct);
+        assert_eq!(
+            MyEnum::from_reflect(&untyped),
+            Some(MyEnum::C {
+                foo: 1.23,
+                bar: true
+            })
+        );
+    }
+
+    #[test]
+    fn dynamic_enum_builder_should_reject_unknown_variant() {
+        let result = DynamicEnum::builder("D")
+            .represented_type(MyEnum::type_info())
+            .try_build();
+        assert!(matches!(
+            result,
+            Err(ApplyError::UnknownVariant { variant_name, .. }) if &*variant_name == "D"
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add a named field to tuple variant `B`")]
+    fn dynamic_enum_builder_should_not_mix_field_kinds() {
+        let _ = DynamicEnum::builder("B")
+            .tuple_field(123usize)
+            .field("foo", 1.23f32);
+    }
 }
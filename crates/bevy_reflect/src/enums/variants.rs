@@ -92,6 +92,15 @@ impl VariantInfo {
         }
     }
 
+    /// The legacy names that the underlying variant can be deserialized from.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Self::Struct(info) => info.aliases(),
+            Self::Tuple(info) => info.aliases(),
+            Self::Unit(info) => info.aliases(),
+        }
+    }
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -151,6 +160,7 @@ impl VariantInfo {
 #[derive(Clone, Debug)]
 pub struct StructVariantInfo {
     name: &'static str,
+    aliases: &'static [&'static str],
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
@@ -166,6 +176,7 @@ impl StructVariantInfo {
         let field_names = fields.iter().map(NamedField::name).collect();
         Self {
             name,
+            aliases: &[],
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
@@ -189,11 +200,23 @@ impl StructVariantInfo {
         }
     }
 
+    /// Sets the legacy names that this variant can be deserialized from.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The legacy names that this variant can be deserialized from, in addition to its [name].
+    ///
+    /// [name]: Self::name
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
@@ -247,6 +270,7 @@ impl StructVariantInfo {
 #[derive(Clone, Debug)]
 pub struct TupleVariantInfo {
     name: &'static str,
+    aliases: &'static [&'static str],
     fields: Box<[UnnamedField]>,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
@@ -258,6 +282,7 @@ impl TupleVariantInfo {
     pub fn new(name: &'static str, fields: &[UnnamedField]) -> Self {
         Self {
             name,
+            aliases: &[],
             fields: fields.to_vec().into_boxed_slice(),
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
@@ -279,11 +304,23 @@ impl TupleVariantInfo {
         }
     }
 
+    /// Sets the legacy names that this variant can be deserialized from.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The legacy names that this variant can be deserialized from, in addition to its [name].
+    ///
+    /// [name]: Self::name
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
@@ -312,6 +349,7 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    aliases: &'static [&'static str],
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -322,6 +360,7 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            aliases: &[],
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -342,11 +381,23 @@ impl UnitVariantInfo {
         }
     }
 
+    /// Sets the legacy names that this variant can be deserialized from.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The legacy names that this variant can be deserialized from, in addition to its [name].
+    ///
+    /// [name]: Self::name
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
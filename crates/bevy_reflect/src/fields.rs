@@ -9,6 +9,7 @@ use bevy_platform_support::sync::Arc;
 #[derive(Clone, Debug)]
 pub struct NamedField {
     name: &'static str,
+    aliases: &'static [&'static str],
     type_info: fn() -> Option<&'static TypeInfo>,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
@@ -21,6 +22,7 @@ impl NamedField {
     pub fn new<T: PartialReflect + MaybeTyped + TypePath>(name: &'static str) -> Self {
         Self {
             name,
+            aliases: &[],
             type_info: T::maybe_type_info,
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
@@ -43,11 +45,23 @@ impl NamedField {
         }
     }
 
+    /// Sets the legacy names that this field can be deserialized from.
+    pub fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self { aliases, ..self }
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The legacy names that this field can be deserialized from, in addition to its [name].
+    ///
+    /// [name]: Self::name
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
     /// The [`TypeInfo`] of the field.
     ///
     ///
@@ -0,0 +1,76 @@
+use crate::func::Capability;
+use core::any::{Any, TypeId};
+
+/// A piece of context provided to a [`DynamicFunction`] or [`DynamicFunctionMut`]
+/// alongside its arguments, in order to satisfy one of its [capabilities].
+///
+/// Unlike arguments, context doesn't need to be reflectable and is looked up by its type,
+/// rather than by its position in the [`ArgList`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+/// [`DynamicFunctionMut`]: crate::func::DynamicFunctionMut
+/// [capabilities]: Capability
+/// [`ArgList`]: crate::func::ArgList
+#[derive(Debug)]
+pub struct ArgContext<'a> {
+    capability: Capability,
+    value: ContextValue<'a>,
+}
+
+#[derive(Debug)]
+enum ContextValue<'a> {
+    Ref(&'a (dyn Any + Send + Sync)),
+    Mut(&'a mut (dyn Any + Send + Sync)),
+}
+
+impl<'a> ArgContext<'a> {
+    /// Create a context with immutable access to the given value.
+    pub fn from_ref<T: Any + Send + Sync>(context: &'a T) -> Self {
+        Self {
+            capability: Capability::read::<T>(),
+            value: ContextValue::Ref(context),
+        }
+    }
+
+    /// Create a context with mutable access to the given value.
+    pub fn from_mut<T: Any + Send + Sync>(context: &'a mut T) -> Self {
+        Self {
+            capability: Capability::write::<T>(),
+            value: ContextValue::Mut(context),
+        }
+    }
+
+    /// Returns the [`Capability`] that this context provides.
+    pub fn capability(&self) -> Capability {
+        self.capability
+    }
+
+    /// Returns the context as a `&T` if it's of type `T`.
+    ///
+    /// Otherwise, returns the context unchanged.
+    pub fn take_ref<T: Any>(self) -> Result<&'a T, Self> {
+        if self.capability.type_id() != TypeId::of::<T>() {
+            return Err(self);
+        }
+
+        let context: &'a (dyn Any + Send + Sync) = match self.value {
+            ContextValue::Ref(context) => context,
+            ContextValue::Mut(context) => context,
+        };
+        Ok(context.downcast_ref::<T>().unwrap())
+    }
+
+    /// Returns the context as a `&mut T` if it provides mutable access and is of type `T`.
+    ///
+    /// Otherwise, returns the context unchanged.
+    pub fn take_mut<T: Any>(self) -> Result<&'a mut T, Self> {
+        if self.capability != Capability::write::<T>() {
+            return Err(self);
+        }
+
+        match self.value {
+            ContextValue::Mut(context) => Ok(context.downcast_mut::<T>().unwrap()),
+            ContextValue::Ref(_) => unreachable!("capability should match the context value"),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use alloc::borrow::Cow;
 
 use thiserror::Error;
 
-use crate::func::args::Ownership;
+use crate::func::{args::Ownership, Capability};
 
 /// An error that occurs when converting an [argument].
 ///
@@ -28,6 +28,11 @@ pub enum ArgError {
     /// [`ArgList`]: crate::func::args::ArgList
     #[error("expected an argument but received none")]
     EmptyArgList,
+    /// Occurs when attempting to access context that wasn't provided to the [`ArgList`].
+    ///
+    /// [`ArgList`]: crate::func::args::ArgList
+    #[error("expected context `{0}` but received none")]
+    MissingContext(Capability),
 }
 
 /// The given argument count is out of bounds.
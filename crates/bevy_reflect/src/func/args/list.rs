@@ -1,14 +1,16 @@
 use crate::{
     func::{
-        args::{Arg, ArgValue, FromArg},
-        ArgError,
+        args::{Arg, ArgContext, ArgValue, FromArg},
+        ArgError, Capability,
     },
     PartialReflect, Reflect, TypePath,
 };
 use alloc::{
     boxed::Box,
     collections::vec_deque::{Iter, VecDeque},
+    vec::Vec,
 };
+use core::any::Any;
 
 /// A list of arguments that can be passed to a [`DynamicFunction`] or [`DynamicFunctionMut`].
 ///
@@ -32,7 +34,12 @@ use alloc::{
 ///   .with_arg(ArgValue::Ref(&3.14));
 /// ```
 ///
+/// Besides arguments, the list can also provide [context] to functions which declare
+/// that they need it through their [capabilities], such as access to an ECS world.
+///
 /// [arguments]: Arg
+/// [context]: ArgContext
+/// [capabilities]: Capability
 /// [`DynamicFunction`]: crate::func::DynamicFunction
 /// [`DynamicFunctionMut`]: crate::func::DynamicFunctionMut
 #[derive(Default, Debug)]
@@ -43,6 +50,8 @@ pub struct ArgList<'a> {
     /// This flag should be set when an argument is removed from the beginning of the list,
     /// so that any future push operations will re-index the arguments.
     needs_reindex: bool,
+    /// The context provided alongside the arguments.
+    contexts: Vec<ArgContext<'a>>,
 }
 
 impl<'a> ArgList<'a> {
@@ -51,6 +60,7 @@ impl<'a> ArgList<'a> {
         Self {
             list: VecDeque::new(),
             needs_reindex: false,
+            contexts: Vec::new(),
         }
     }
 
@@ -143,6 +153,86 @@ impl<'a> ArgList<'a> {
         self.with_arg(ArgValue::Owned(arg))
     }
 
+    /// Provide the given [`ArgContext`] alongside the arguments.
+    ///
+    /// Any previously provided context of the same type is replaced.
+    pub fn push_context(&mut self, context: ArgContext<'a>) {
+        let type_id = context.capability().type_id();
+        self.contexts
+            .retain(|existing| existing.capability().type_id() != type_id);
+        self.contexts.push(context);
+    }
+
+    /// Provide immutable access to the given context alongside the arguments.
+    ///
+    /// Any previously provided context of the same type is replaced.
+    pub fn with_context_ref<T: Any + Send + Sync>(mut self, context: &'a T) -> Self {
+        self.push_context(ArgContext::from_ref(context));
+        self
+    }
+
+    /// Provide mutable access to the given context alongside the arguments.
+    ///
+    /// Any previously provided context of the same type is replaced.
+    pub fn with_context_mut<T: Any + Send + Sync>(mut self, context: &'a mut T) -> Self {
+        self.push_context(ArgContext::from_mut(context));
+        self
+    }
+
+    /// Remove the context of type `T` and return it as `Ok(&T)`.
+    ///
+    /// If no context of this type was provided, returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::ArgList;
+    /// let value = 123u32;
+    /// let mut args = ArgList::new().with_context_ref(&value);
+    /// let value = args.take_context_ref::<u32>().unwrap();
+    /// assert_eq!(*value, 123);
+    /// ```
+    pub fn take_context_ref<T: Any>(&mut self) -> Result<&'a T, ArgError> {
+        self.take_context(Capability::read::<T>())?
+            .take_ref()
+            .map_err(|_| ArgError::MissingContext(Capability::read::<T>()))
+    }
+
+    /// Remove the context of type `T` and return it as `Ok(&mut T)`.
+    ///
+    /// If no context of this type was provided, or it was provided immutably, returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::func::ArgList;
+    /// let mut value = 123u32;
+    /// let mut args = ArgList::new().with_context_mut(&mut value);
+    /// let value = args.take_context_mut::<u32>().unwrap();
+    /// assert_eq!(*value, 123);
+    /// ```
+    pub fn take_context_mut<T: Any>(&mut self) -> Result<&'a mut T, ArgError> {
+        self.take_context(Capability::write::<T>())?
+            .take_mut()
+            .map_err(|_| ArgError::MissingContext(Capability::write::<T>()))
+    }
+
+    fn take_context(&mut self, capability: Capability) -> Result<ArgContext<'a>, ArgError> {
+        let index = self
+            .contexts
+            .iter()
+            .position(|context| capability.is_satisfied_by(&context.capability()))
+            .ok_or(ArgError::MissingContext(capability))?;
+        Ok(self.contexts.swap_remove(index))
+    }
+
+    /// Returns an iterator over the [capabilities] provided by the context in this list.
+    ///
+    /// [capabilities]: Capability
+    pub fn capabilities(&self) -> impl Iterator<Item = Capability> + '_ {
+        self.contexts.iter().map(ArgContext::capability)
+    }
+
     /// Remove the first argument in the list and return it.
     ///
     /// It's generally preferred to use [`Self::take`] instead of this method
@@ -4,6 +4,7 @@
 //! [`DynamicFunctionMut`]: crate::func::DynamicFunctionMut
 
 pub use arg::*;
+pub use context::*;
 pub use count::*;
 pub use error::*;
 pub use from_arg::*;
@@ -12,6 +13,7 @@ pub use list::*;
 pub use ownership::*;
 
 mod arg;
+mod context;
 mod count;
 mod error;
 mod from_arg;
@@ -0,0 +1,121 @@
+use core::{
+    any::{type_name, Any, TypeId},
+    fmt::{Display, Formatter},
+};
+
+/// A piece of context, other than its arguments, that a [`DynamicFunction`] or
+/// [`DynamicFunctionMut`] needs access to in order to be called.
+///
+/// This allows functions to declare that they need access to something like an ECS world,
+/// which is then provided alongside the arguments with [`ArgList::with_context_ref`]
+/// or [`ArgList::with_context_mut`].
+///
+/// The capabilities of a function are listed in its [`FunctionInfo`],
+/// and are validated before the function is called.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{
+/// #     ArgList, Capability, DynamicFunction, FunctionError, IntoReturn, SignatureInfo,
+/// # };
+/// struct Score(i32);
+///
+/// let add_score = DynamicFunction::new(
+///     |mut args| {
+///         let score = args.take_context_mut::<Score>()?;
+///         let amount = args.take::<i32>()?;
+///         score.0 += amount;
+///         Ok(().into_return())
+///     },
+///     SignatureInfo::named("add_score").with_arg::<i32>("amount"),
+/// )
+/// .with_capability(Capability::write::<Score>());
+///
+/// let mut score = Score(0);
+/// let args = ArgList::new().with_owned(5_i32).with_context_mut(&mut score);
+/// add_score.call(args).unwrap();
+/// assert_eq!(score.0, 5);
+///
+/// // Calling the function without the context it needs fails
+/// let args = ArgList::new().with_owned(5_i32);
+/// assert_eq!(
+///     add_score.call(args).unwrap_err(),
+///     FunctionError::MissingCapability(Capability::write::<Score>()),
+/// );
+/// ```
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+/// [`DynamicFunctionMut`]: crate::func::DynamicFunctionMut
+/// [`ArgList::with_context_ref`]: crate::func::ArgList::with_context_ref
+/// [`ArgList::with_context_mut`]: crate::func::ArgList::with_context_mut
+/// [`FunctionInfo`]: crate::func::FunctionInfo
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Capability {
+    type_id: TypeId,
+    type_name: &'static str,
+    access: CapabilityAccess,
+}
+
+impl Capability {
+    /// Create a capability for immutable access to a `T`.
+    pub fn read<T: Any>() -> Self {
+        Self::new::<T>(CapabilityAccess::Read)
+    }
+
+    /// Create a capability for mutable access to a `T`.
+    pub fn write<T: Any>() -> Self {
+        Self::new::<T>(CapabilityAccess::Write)
+    }
+
+    /// Create a capability for the given access to a `T`.
+    pub fn new<T: Any>(access: CapabilityAccess) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            access,
+        }
+    }
+
+    /// The [`TypeId`] of the context this capability accesses.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The name of the type of the context this capability accesses.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The kind of access this capability requires.
+    pub fn access(&self) -> CapabilityAccess {
+        self.access
+    }
+
+    /// Returns `true` if this capability is satisfied by `provided`.
+    ///
+    /// This is the case when both access the same type, and `provided` has at least
+    /// the access required by this capability.
+    pub fn is_satisfied_by(&self, provided: &Capability) -> bool {
+        self.type_id == provided.type_id
+            && (self.access == CapabilityAccess::Read || provided.access == CapabilityAccess::Write)
+    }
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.access {
+            CapabilityAccess::Read => write!(f, "&{}", self.type_name),
+            CapabilityAccess::Write => write!(f, "&mut {}", self.type_name),
+        }
+    }
+}
+
+/// The kind of access a [`Capability`] requires.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CapabilityAccess {
+    /// The context is accessed immutably (i.e. `&T`).
+    Read,
+    /// The context is accessed mutably (i.e. `&mut T`).
+    Write,
+}
@@ -5,8 +5,8 @@ use crate::{
         args::{ArgCount, ArgList},
         dynamic_function_internal::DynamicFunctionInternal,
         info::FunctionInfo,
-        DynamicFunctionMut, Function, FunctionOverloadError, FunctionResult, IntoFunction,
-        IntoFunctionMut,
+        Capability, DynamicFunctionMut, Function, FunctionOverloadError, FunctionResult,
+        IntoFunction, IntoFunctionMut,
     },
     ApplyError, MaybeTyped, PartialReflect, Reflect, ReflectKind, ReflectMut, ReflectOwned,
     ReflectRef, TypeInfo, TypePath,
@@ -124,6 +124,15 @@ impl<'env> DynamicFunction<'env> {
         self
     }
 
+    /// Add a [`Capability`] that the function requires in order to be called.
+    ///
+    /// Calling the function without providing the required context in its [`ArgList`]
+    /// will return [`FunctionError::MissingCapability`](crate::func::FunctionError::MissingCapability).
+    pub fn with_capability(mut self, capability: Capability) -> Self {
+        self.internal = self.internal.with_capability(capability);
+        self
+    }
+
     /// Add an overload to this function.
     ///
     /// Overloads allow a single [`DynamicFunction`] to represent multiple functions of different signatures.
@@ -1,6 +1,6 @@
 use crate::func::args::ArgCount;
 use crate::func::signature::{ArgListSignature, ArgumentSignature};
-use crate::func::{ArgList, FunctionError, FunctionInfo, FunctionOverloadError};
+use crate::func::{ArgList, Capability, FunctionError, FunctionInfo, FunctionOverloadError};
 use alloc::{borrow::Cow, vec, vec::Vec};
 use bevy_platform_support::collections::HashMap;
 use core::fmt::{Debug, Formatter};
@@ -107,22 +107,38 @@ impl<F> DynamicFunctionInternal<F> {
         self.info.arg_count()
     }
 
+    /// Add a capability that the function requires.
+    pub fn with_capability(mut self, capability: Capability) -> Self {
+        self.info = self.info.with_capability(capability);
+        self
+    }
+
     /// Helper method for validating that a given set of arguments are _potentially_ valid for this function.
     ///
     /// Currently, this validates:
     /// - The number of arguments is within the expected range
+    /// - The context required by the function's capabilities is provided
     pub fn validate_args(&self, args: &ArgList) -> Result<(), FunctionError> {
         let expected_arg_count = self.arg_count();
         let received_arg_count = args.len();
 
         if !expected_arg_count.contains(received_arg_count) {
-            Err(FunctionError::ArgCountMismatch {
+            return Err(FunctionError::ArgCountMismatch {
                 expected: expected_arg_count,
                 received: received_arg_count,
-            })
-        } else {
-            Ok(())
+            });
         }
+
+        for capability in self.info.capabilities() {
+            if !args
+                .capabilities()
+                .any(|provided| capability.is_satisfied_by(&provided))
+            {
+                return Err(FunctionError::MissingCapability(*capability));
+            }
+        }
+
+        Ok(())
     }
 
     /// Merge another [`DynamicFunctionInternal`] into this one.
@@ -5,7 +5,8 @@ use core::fmt::{Debug, Formatter};
 use crate::func::{
     args::{ArgCount, ArgList},
     dynamic_function_internal::DynamicFunctionInternal,
-    DynamicFunction, FunctionInfo, FunctionOverloadError, FunctionResult, IntoFunctionMut,
+    Capability, DynamicFunction, FunctionInfo, FunctionOverloadError, FunctionResult,
+    IntoFunctionMut,
 };
 
 /// A [`Box`] containing a callback to a reflected function.
@@ -113,6 +114,15 @@ impl<'env> DynamicFunctionMut<'env> {
         self
     }
 
+    /// Add a [`Capability`] that the function requires in order to be called.
+    ///
+    /// Calling the function without providing the required context in its [`ArgList`]
+    /// will return [`FunctionError::MissingCapability`](crate::func::FunctionError::MissingCapability).
+    pub fn with_capability(mut self, capability: Capability) -> Self {
+        self.internal = self.internal.with_capability(capability);
+        self
+    }
+
     /// Add an overload to this function.
     ///
     /// Overloads allow a single [`DynamicFunctionMut`] to represent multiple functions of different signatures.
@@ -1,7 +1,7 @@
 use crate::func::signature::ArgumentSignature;
 use crate::func::{
     args::{ArgCount, ArgError},
-    Return,
+    Capability, Return,
 };
 use alloc::borrow::Cow;
 use bevy_platform_support::collections::HashSet;
@@ -25,6 +25,9 @@ pub enum FunctionError {
         expected: HashSet<ArgumentSignature>,
         received: ArgumentSignature,
     },
+    /// The function requires a [`Capability`] which isn't provided by the arguments.
+    #[error("function requires context `{0}` but it was not provided")]
+    MissingCapability(Capability),
 }
 
 /// The result of calling a [`DynamicFunction`] or [`DynamicFunctionMut`].
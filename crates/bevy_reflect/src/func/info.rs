@@ -4,7 +4,7 @@ use core::fmt::{Debug, Formatter};
 use crate::{
     func::args::{ArgCount, ArgCountOutOfBoundsError, ArgInfo, GetOwnership, Ownership},
     func::signature::ArgumentSignature,
-    func::FunctionOverloadError,
+    func::{Capability, FunctionOverloadError},
     type_info::impl_type_methods,
     Type, TypePath,
 };
@@ -26,6 +26,7 @@ pub struct FunctionInfo {
     name: Option<Cow<'static, str>>,
     arg_count: ArgCount,
     signatures: Box<[SignatureInfo]>,
+    capabilities: Vec<Capability>,
 }
 
 impl FunctionInfo {
@@ -40,6 +41,7 @@ impl FunctionInfo {
             name: signature.name.clone(),
             arg_count: ArgCount::new(signature.arg_count()).unwrap(),
             signatures: vec![signature].into(),
+            capabilities: Vec::new(),
         }
     }
 
@@ -159,6 +161,37 @@ impl FunctionInfo {
         &self.signatures
     }
 
+    /// Add a [`Capability`] that the function requires in order to be called.
+    ///
+    /// If the function already requires access to the same type,
+    /// the access required is widened to satisfy both capabilities.
+    pub fn with_capability(mut self, capability: Capability) -> Self {
+        self.add_capability(capability);
+        self
+    }
+
+    /// The [capabilities] the function requires in order to be called.
+    ///
+    /// [capabilities]: Capability
+    pub fn capabilities(&self) -> &[Capability] {
+        &self.capabilities
+    }
+
+    fn add_capability(&mut self, capability: Capability) {
+        match self
+            .capabilities
+            .iter_mut()
+            .find(|existing| existing.type_id() == capability.type_id())
+        {
+            Some(existing) => {
+                if !capability.is_satisfied_by(existing) {
+                    *existing = capability;
+                }
+            }
+            None => self.capabilities.push(capability),
+        }
+    }
+
     /// Returns a wrapper around this info that implements [`Debug`] for pretty-printing the function.
     ///
     /// This can be useful for more readable debugging and logging.
@@ -192,6 +225,10 @@ impl FunctionInfo {
             self.name = other.name;
         }
 
+        for capability in other.capabilities {
+            self.add_capability(capability);
+        }
+
         let signatures = core::mem::take(&mut self.signatures);
         self.signatures = IntoIterator::into_iter(signatures)
             .chain(IntoIterator::into_iter(other.signatures))
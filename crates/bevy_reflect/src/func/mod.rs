@@ -120,6 +120,20 @@
 //!
 //! To learn more, see the docs on [`DynamicFunction::with_overload`].
 //!
+//! # Capabilities
+//!
+//! Some functions need access to more than just their arguments,
+//! such as a function that spawns an entity needing access to the ECS world.
+//! Since such context isn't usually reflectable, it can't be passed as a regular argument.
+//!
+//! Instead, a function can declare the context it needs as a list of [capabilities],
+//! which are stored in its [`FunctionInfo`] and validated before the function is called.
+//! The context itself is provided alongside the arguments using [`ArgList::with_context_ref`]
+//! or [`ArgList::with_context_mut`], and retrieved by the function using
+//! [`ArgList::take_context_ref`] or [`ArgList::take_context_mut`].
+//!
+//! To learn more, see the docs on [`Capability`].
+//!
 //! # Function Registration
 //!
 //! This module also provides a [`FunctionRegistry`] that can be used to register functions and closures
@@ -155,10 +169,12 @@
 //! [coherence issues]: https://doc.rust-lang.org/rustc/lints/listing/warn-by-default.html#coherence-leak-check
 //! [monomorphized]: https://en.wikipedia.org/wiki/Monomorphization
 //! [overloading]: #overloading-functions
+//! [capabilities]: Capability
 //! [function overloading]: https://en.wikipedia.org/wiki/Function_overloading
 //! [variadic functions]: https://en.wikipedia.org/wiki/Variadic_function
 
 pub use args::{ArgError, ArgList, ArgValue};
+pub use capability::*;
 pub use dynamic_function::*;
 pub use dynamic_function_mut::*;
 pub use error::*;
@@ -172,6 +188,7 @@ pub use registry::*;
 pub use return_type::*;
 
 pub mod args;
+mod capability;
 mod dynamic_function;
 mod dynamic_function_internal;
 mod dynamic_function_mut;
@@ -263,4 +280,39 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn should_validate_capabilities() {
+        struct Counter(i32);
+
+        let func = DynamicFunction::new(
+            |mut args| {
+                let counter = args.take_context_mut::<Counter>()?;
+                counter.0 += args.take::<i32>()?;
+                Ok(counter.0.into_return())
+            },
+            SignatureInfo::anonymous().with_arg::<i32>("amount"),
+        )
+        .with_capability(Capability::read::<Counter>())
+        .with_capability(Capability::write::<Counter>());
+        assert_eq!(
+            func.info().capabilities(),
+            &[Capability::write::<Counter>()]
+        );
+
+        let mut counter = Counter(1);
+
+        let args = ArgList::new().with_owned(2_i32).with_context_ref(&counter);
+        assert_eq!(
+            func.call(args).unwrap_err(),
+            FunctionError::MissingCapability(Capability::write::<Counter>())
+        );
+
+        let args = ArgList::new()
+            .with_owned(2_i32)
+            .with_context_mut(&mut counter);
+        let result = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(result.try_take::<i32>().unwrap(), 3);
+        assert_eq!(counter.0, 3);
+    }
 }
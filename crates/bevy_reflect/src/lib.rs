@@ -381,6 +381,11 @@
 //! See the [dynamic types example](https://github.com/bevyengine/bevy/blob/latest/examples/reflection/dynamic_types.rs)
 //! for more information and usage details.
 //!
+//! Trait objects themselves can also be reflected when boxed.
+//! Implementing [`ReflectTraitObject`] for `dyn MyTrait` allows `Box<dyn MyTrait>` fields to be
+//! reflected, serialized and deserialized, using the generated type data to recreate the
+//! concrete value from the registry.
+//!
 //! # Serialization
 //!
 //! By using reflection, we are also able to get serialization capabilities for free.
@@ -565,12 +570,16 @@ extern crate std;
 extern crate alloc;
 
 mod array;
+mod boxed;
+mod clone;
+mod diff;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
 pub mod func;
 mod kind;
 mod list;
+mod manifest;
 mod map;
 mod path;
 mod reflect;
@@ -610,6 +619,7 @@ pub mod std_traits;
 #[cfg(feature = "debug_stack")]
 mod type_info_stack;
 pub mod utility;
+pub mod validation;
 
 /// The reflect prelude.
 ///
@@ -629,12 +639,16 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use boxed::*;
+pub use clone::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
 pub use generics::*;
 pub use kind::*;
 pub use list::*;
+pub use manifest::*;
 pub use map::*;
 pub use path::*;
 pub use reflect::*;
@@ -1500,6 +1514,66 @@ mod tests {
         assert_eq!(foo, *foo2.downcast::<Foo>().unwrap());
     }
 
+    #[test]
+    fn should_call_reflected_trait_methods() {
+        // Not dyn-compatible, since `items` returns an `impl Trait`
+        #[reflect_trait]
+        trait Inventory {
+            fn capacity(&self) -> usize;
+            fn find<'a>(&'a self, prefix: &str) -> Option<&'a str>;
+            fn add(&mut self, item: &str);
+            fn items(&self) -> impl Iterator<Item = &str>;
+            fn new() -> Self
+            where
+                Self: Sized;
+        }
+
+        #[derive(Reflect, Default)]
+        #[reflect(Inventory)]
+        struct Backpack {
+            items: Vec<String>,
+        }
+
+        impl Inventory for Backpack {
+            fn capacity(&self) -> usize {
+                4
+            }
+
+            fn find<'a>(&'a self, prefix: &str) -> Option<&'a str> {
+                self.items().find(|item| item.starts_with(prefix))
+            }
+
+            fn add(&mut self, item: &str) {
+                self.items.push(item.to_string());
+            }
+
+            fn items(&self) -> impl Iterator<Item = &str> {
+                self.items.as_slice().iter().map(String::as_str)
+            }
+
+            fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Backpack>();
+        let inventory = registry
+            .get_type_data::<ReflectInventory>(TypeId::of::<Backpack>())
+            .unwrap();
+
+        let mut value: Box<dyn Reflect> = Box::new(Backpack::new());
+        inventory.call_add(&mut *value, "sword").unwrap();
+        inventory.call_add(&mut *value, "shield").unwrap();
+
+        assert_eq!(Some(4), inventory.call_capacity(&*value));
+        assert_eq!(Some(Some("shield")), inventory.call_find(&*value, "sh"));
+        let items = inventory.call_items(&*value).unwrap().collect::<Vec<_>>();
+        assert_eq!(vec!["sword", "shield"], items);
+
+        assert_eq!(None, inventory.call_capacity(&123_i32));
+    }
+
     #[test]
     fn should_drain_fields() {
         let array_value: Box<dyn Array> = Box::new([123_i32, 321_i32]);
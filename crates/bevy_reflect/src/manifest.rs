@@ -0,0 +1,217 @@
+use crate::{TypeInfo, TypeRegistry, VariantInfo};
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A description of the layout of every type in a [`TypeRegistry`].
+///
+/// The layout of a type is made up of its kind and the names and types of its fields and
+/// variants. Two registries with equal manifests can read each other's reflected data, so
+/// manifests can be used to check whether two builds of an app are compatible, for example
+/// when a client connects to a server.
+///
+/// Manifests are created with [`TypeRegistry::manifest`], and can be serialized to send them to
+/// another process. To compare them cheaply, use [`RegistryManifest::fingerprint`], and to find out
+/// which types differ, use [`RegistryManifest::diff`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Health(f32);
+///
+/// let mut server = TypeRegistry::new();
+/// server.register::<Health>();
+///
+/// let mut client = TypeRegistry::new();
+/// client.register::<Health>();
+///
+/// assert_eq!(server.fingerprint(), client.fingerprint());
+/// assert!(client.diff(&server.manifest()).is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryManifest {
+    layouts: BTreeMap<String, String>,
+}
+
+impl RegistryManifest {
+    /// Creates a manifest of the types registered in `registry`.
+    pub fn new(registry: &TypeRegistry) -> Self {
+        let layouts = registry
+            .iter()
+            .map(|registration| {
+                let type_info = registration.type_info();
+                (String::from(type_info.type_path()), layout_of(type_info))
+            })
+            .collect();
+        Self { layouts }
+    }
+
+    /// Returns the layout of the type with the given [type path], if it's in this manifest.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub fn layout(&self, type_path: &str) -> Option<&str> {
+        self.layouts.get(type_path).map(String::as_str)
+    }
+
+    /// Returns an iterator over the type paths and layouts of the types in this manifest,
+    /// ordered by type path.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &str)> {
+        self.layouts
+            .iter()
+            .map(|(type_path, layout)| (type_path.as_str(), layout.as_str()))
+    }
+
+    /// Returns the number of types in this manifest.
+    pub fn len(&self) -> usize {
+        self.layouts.len()
+    }
+
+    /// Returns `true` if this manifest contains no types.
+    pub fn is_empty(&self) -> bool {
+        self.layouts.is_empty()
+    }
+
+    /// Returns a hash of this manifest.
+    ///
+    /// Unlike [`Hash`], the fingerprint doesn't depend on the platform or the build of the app,
+    /// so it can be compared with fingerprints made by other processes. Equal manifests always
+    /// have equal fingerprints.
+    pub fn fingerprint(&self) -> u64 {
+        // 64-bit FNV-1a
+        const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const PRIME: u64 = 0x100000001b3;
+
+        let mut hash = OFFSET_BASIS;
+        for (type_path, layout) in self.iter() {
+            // Each string is terminated by a zero byte, which can't appear in type paths or
+            // layouts, so that different manifests can't produce the same sequence of bytes.
+            let parts: [&[u8]; 4] = [type_path.as_bytes(), &[0], layout.as_bytes(), &[0]];
+            for bytes in parts {
+                for &byte in bytes {
+                    hash ^= u64::from(byte);
+                    hash = hash.wrapping_mul(PRIME);
+                }
+            }
+        }
+        hash
+    }
+
+    /// Returns the differences between this manifest and `other`.
+    pub fn diff(&self, other: &RegistryManifest) -> RegistryManifestDiff {
+        let mut diff = RegistryManifestDiff::default();
+        for (type_path, layout) in &self.layouts {
+            match other.layouts.get(type_path) {
+                Some(other_layout) if other_layout != layout => {
+                    diff.changed.push(type_path.clone());
+                }
+                Some(_) => {}
+                None => diff.removed.push(type_path.clone()),
+            }
+        }
+        for type_path in other.layouts.keys() {
+            if !self.layouts.contains_key(type_path) {
+                diff.added.push(type_path.clone());
+            }
+        }
+        diff
+    }
+}
+
+impl Serialize for RegistryManifest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.layouts.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RegistryManifest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        BTreeMap::deserialize(deserializer).map(|layouts| Self { layouts })
+    }
+}
+
+/// The differences between two [`RegistryManifest`]s, as returned by [`RegistryManifest::diff`].
+///
+/// Each list contains type paths, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryManifestDiff {
+    /// Types which are only in the other manifest.
+    pub added: Vec<String>,
+    /// Types which are only in this manifest.
+    pub removed: Vec<String>,
+    /// Types which are in both manifests, but with different layouts.
+    pub changed: Vec<String>,
+}
+
+impl RegistryManifestDiff {
+    /// Returns `true` if the two manifests were equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Describes the layout of a type as a string, e.g. `struct { x: f32, y: f32 }`.
+fn layout_of(type_info: &TypeInfo) -> String {
+    match type_info {
+        TypeInfo::Struct(info) => {
+            let fields = info
+                .iter()
+                .map(|field| format!("{}: {}", field.name(), field.type_path()));
+            format!("struct {{ {} }}", join(fields))
+        }
+        TypeInfo::TupleStruct(info) => {
+            let fields = info.iter().map(|field| String::from(field.type_path()));
+            format!("tuple struct ({})", join(fields))
+        }
+        TypeInfo::Tuple(info) => {
+            let fields = info.iter().map(|field| String::from(field.type_path()));
+            format!("tuple ({})", join(fields))
+        }
+        TypeInfo::List(info) => format!("list [{}]", info.item_ty().path()),
+        TypeInfo::Array(info) => {
+            format!("array [{}; {}]", info.item_ty().path(), info.capacity())
+        }
+        TypeInfo::Map(info) => {
+            format!(
+                "map {{ {}: {} }}",
+                info.key_ty().path(),
+                info.value_ty().path()
+            )
+        }
+        TypeInfo::Set(info) => format!("set {{ {} }}", info.value_ty().path()),
+        TypeInfo::Enum(info) => {
+            let variants = info.iter().map(|variant| match variant {
+                VariantInfo::Struct(variant) => {
+                    let fields = variant
+                        .iter()
+                        .map(|field| format!("{}: {}", field.name(), field.type_path()));
+                    format!("{} {{ {} }}", variant.name(), join(fields))
+                }
+                VariantInfo::Tuple(variant) => {
+                    let fields = variant.iter().map(|field| String::from(field.type_path()));
+                    format!("{}({})", variant.name(), join(fields))
+                }
+                VariantInfo::Unit(variant) => String::from(variant.name()),
+            });
+            format!("enum {{ {} }}", join(variants))
+        }
+        TypeInfo::Opaque(_) => String::from("opaque"),
+    }
+}
+
+fn join(items: impl Iterator<Item = String>) -> String {
+    let mut joined = String::new();
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            joined.push_str(", ");
+        }
+        joined.push_str(&item);
+    }
+    joined
+}
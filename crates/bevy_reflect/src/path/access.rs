@@ -4,7 +4,13 @@ use alloc::borrow::Cow;
 use core::fmt;
 
 use super::error::AccessErrorKind;
-use crate::{AccessError, PartialReflect, ReflectKind, ReflectMut, ReflectRef, VariantType};
+use crate::{
+    std_traits::ReflectDefault, AccessError, DynamicArray, DynamicEnum, DynamicList, DynamicMap,
+    DynamicSet, DynamicStruct, DynamicTuple, DynamicTupleStruct, DynamicVariant, PartialReflect,
+    ReflectFromReflect, ReflectKind, ReflectMut, ReflectRef, Type, TypeInfo, TypeRegistry,
+    VariantInfo, VariantType,
+};
+use alloc::boxed::Box;
 
 type InnerResult<T> = Result<T, AccessErrorKind>;
 
@@ -166,6 +172,99 @@ impl<'a> Access<'a> {
         }
     }
 
+    pub(super) fn element_mut_or_insert<'r>(
+        &self,
+        base: &'r mut dyn PartialReflect,
+        offset: Option<usize>,
+        registry: &TypeRegistry,
+    ) -> Result<&'r mut dyn PartialReflect, AccessError<'a>> {
+        self.insert_missing(base, registry)
+            .map_err(|err| err.with_access(self.clone(), offset))?;
+        self.element_mut(base, offset)
+    }
+
+    /// Creates the element targeted by this access if it's missing from `base`.
+    ///
+    /// Lists are extended with default values up to the accessed index, and enums in a unit
+    /// variant (such as [`None`]) are changed to the first variant with the accessed field.
+    /// Any other error is left for [`element_mut`](Self::element_mut) to report.
+    fn insert_missing(
+        &self,
+        base: &mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> InnerResult<()> {
+        match self.element_inner(base) {
+            Ok(None)
+            | Err(AccessErrorKind::IncompatibleEnumVariantTypes {
+                actual: VariantType::Unit,
+                ..
+            }) => {}
+            _ => return Ok(()),
+        }
+
+        match (self, base.reflect_mut()) {
+            (&Self::ListIndex(index), ReflectMut::List(list)) => {
+                let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
+                    return Ok(());
+                };
+                while list.len() <= index {
+                    list.push(create_default(&info.item_ty(), registry)?);
+                }
+            }
+            (_, ReflectMut::Enum(enum_mut)) => {
+                let Some(TypeInfo::Enum(info)) = enum_mut.get_represented_type_info() else {
+                    return Ok(());
+                };
+                let Some((variant_index, variant)) = info
+                    .iter()
+                    .enumerate()
+                    .find(|(_, variant)| self.fits_variant(variant))
+                else {
+                    return Ok(());
+                };
+
+                let dynamic_variant = match variant {
+                    VariantInfo::Struct(variant) => {
+                        let mut dynamic = DynamicStruct::default();
+                        for field in variant.iter() {
+                            dynamic
+                                .insert_boxed(field.name(), create_default(field.ty(), registry)?);
+                        }
+                        DynamicVariant::Struct(dynamic)
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        let mut dynamic = DynamicTuple::default();
+                        for field in variant.iter() {
+                            dynamic.insert_boxed(create_default(field.ty(), registry)?);
+                        }
+                        DynamicVariant::Tuple(dynamic)
+                    }
+                    VariantInfo::Unit(_) => return Ok(()),
+                };
+                let dynamic =
+                    DynamicEnum::new_with_index(variant_index, variant.name(), dynamic_variant);
+                enum_mut
+                    .try_apply(&dynamic)
+                    .map_err(|_| AccessErrorKind::CannotInsert {
+                        type_path: info.type_path(),
+                    })?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn fits_variant(&self, variant: &VariantInfo) -> bool {
+        match (self, variant) {
+            (Self::Field(field), VariantInfo::Struct(variant)) => {
+                variant.field(field.as_ref()).is_some()
+            }
+            (&Self::FieldIndex(index), VariantInfo::Struct(variant)) => index < variant.field_len(),
+            (&Self::TupleIndex(index), VariantInfo::Tuple(variant)) => index < variant.field_len(),
+            _ => false,
+        }
+    }
+
     /// Returns a reference to this [`Access`]'s inner value as a [`&dyn Display`](fmt::Display).
     pub fn display_value(&self) -> &dyn fmt::Display {
         match self {
@@ -182,3 +281,76 @@ impl<'a> Access<'a> {
         }
     }
 }
+
+/// Creates a default value of the given type, for filling in missing elements.
+///
+/// Uses the type's [`ReflectDefault`] if it's registered. Otherwise, structs, tuples and arrays
+/// are created from the defaults of their fields, collections are created empty, and the
+/// result is converted to the concrete type using [`ReflectFromReflect`] if it's registered.
+fn create_default(ty: &Type, registry: &TypeRegistry) -> InnerResult<Box<dyn PartialReflect>> {
+    if let Some(default) = registry.get_type_data::<ReflectDefault>(ty.id()) {
+        return Ok(default.default().into_partial_reflect());
+    }
+
+    let error = || AccessErrorKind::CannotInsert {
+        type_path: ty.path(),
+    };
+    let type_info = registry.get_type_info(ty.id()).ok_or_else(error)?;
+    let dynamic: Box<dyn PartialReflect> = match type_info {
+        TypeInfo::Struct(info) => {
+            let mut dynamic = DynamicStruct::default();
+            for field in info.iter() {
+                dynamic.insert_boxed(field.name(), create_default(field.ty(), registry)?);
+            }
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        TypeInfo::TupleStruct(info) => {
+            let mut dynamic = DynamicTupleStruct::default();
+            for field in info.iter() {
+                dynamic.insert_boxed(create_default(field.ty(), registry)?);
+            }
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        TypeInfo::Tuple(info) => {
+            let mut dynamic = DynamicTuple::default();
+            for field in info.iter() {
+                dynamic.insert_boxed(create_default(field.ty(), registry)?);
+            }
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        TypeInfo::Array(info) => {
+            let mut dynamic = (0..info.capacity())
+                .map(|_| create_default(&info.item_ty(), registry))
+                .collect::<InnerResult<DynamicArray>>()?;
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        TypeInfo::List(_) => {
+            let mut dynamic = DynamicList::default();
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        TypeInfo::Map(_) => {
+            let mut dynamic = DynamicMap::default();
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        TypeInfo::Set(_) => {
+            let mut dynamic = DynamicSet::default();
+            dynamic.set_represented_type(Some(type_info));
+            Box::new(dynamic)
+        }
+        _ => return Err(error()),
+    };
+
+    match registry.get_type_data::<ReflectFromReflect>(ty.id()) {
+        Some(from_reflect) => from_reflect
+            .from_reflect(dynamic.as_ref())
+            .map(|value| value.into_partial_reflect())
+            .ok_or_else(error),
+        None => Ok(dynamic),
+    }
+}
@@ -27,6 +27,15 @@ pub enum AccessErrorKind {
         /// The actual [`VariantType`] that was found.
         actual: VariantType,
     },
+
+    /// An error that occurs when a missing element can't be inserted,
+    /// because no default value could be created for its type.
+    CannotInsert {
+        /// The [type path] of the value which couldn't be created.
+        ///
+        /// [type path]: crate::TypePath::type_path
+        type_path: &'static str,
+    },
 }
 
 impl AccessErrorKind {
@@ -123,6 +132,10 @@ impl fmt::Display for AccessError<'_> {
                 "Expected variant {} access to access a {expected:?} variant, found a {actual:?} variant instead.",
                 access.kind()
             ),
+            AccessErrorKind::CannotInsert { type_path } => write!(
+                f,
+                "Cannot insert the missing element, because no default value could be created for `{type_path}`."
+            ),
         }
     }
 }
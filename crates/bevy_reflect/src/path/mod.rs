@@ -8,7 +8,7 @@ mod parse;
 pub use parse::ParseError;
 use parse::PathParser;
 
-use crate::{PartialReflect, Reflect};
+use crate::{PartialReflect, Reflect, TypeRegistry};
 use alloc::vec::Vec;
 use core::fmt;
 use derive_more::derive::From;
@@ -62,6 +62,16 @@ pub trait ReflectPath<'a>: Sized {
         root: &mut dyn PartialReflect,
     ) -> PathResult<'a, &mut dyn PartialReflect>;
 
+    /// Gets a mutable reference to the specified element on the given [`Reflect`] object,
+    /// inserting any missing elements along the way.
+    ///
+    /// See [`GetPath::reflect_path_mut_or_insert`] for more details.
+    fn reflect_element_mut_or_insert<'r>(
+        self,
+        root: &'r mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> PathResult<'a, &'r mut dyn PartialReflect>;
+
     /// Gets a `&T` to the specified element on the given [`Reflect`] object.
     ///
     /// See [`GetPath::path`] for more details.
@@ -81,6 +91,22 @@ pub trait ReflectPath<'a>: Sized {
                 .ok_or(ReflectPathError::InvalidDowncast)
         })
     }
+
+    /// Gets a `&mut T` to the specified element on the given [`Reflect`] object,
+    /// inserting any missing elements along the way.
+    ///
+    /// See [`GetPath::path_mut_or_insert`] for more details.
+    fn element_mut_or_insert<'r, T: Reflect>(
+        self,
+        root: &'r mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> PathResult<'a, &'r mut T> {
+        self.reflect_element_mut_or_insert(root, registry)
+            .and_then(|p| {
+                p.try_downcast_mut::<T>()
+                    .ok_or(ReflectPathError::InvalidDowncast)
+            })
+    }
 }
 impl<'a> ReflectPath<'a> for &'a str {
     fn reflect_element(self, mut root: &dyn PartialReflect) -> PathResult<'a, &dyn PartialReflect> {
@@ -99,6 +125,16 @@ impl<'a> ReflectPath<'a> for &'a str {
         }
         Ok(root)
     }
+    fn reflect_element_mut_or_insert<'r>(
+        self,
+        mut root: &'r mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> PathResult<'a, &'r mut dyn PartialReflect> {
+        for (access, offset) in PathParser::new(self) {
+            root = access?.element_mut_or_insert(root, Some(offset), registry)?;
+        }
+        Ok(root)
+    }
 }
 /// A trait which allows nested [`Reflect`] values to be retrieved with path strings.
 ///
@@ -288,6 +324,79 @@ pub trait GetPath: PartialReflect {
     fn path_mut<'p, T: Reflect>(&mut self, path: impl ReflectPath<'p>) -> PathResult<'p, &mut T> {
         path.element_mut(self.as_partial_reflect_mut())
     }
+
+    /// Returns a mutable reference to the value specified by `path`,
+    /// inserting any missing elements along the way.
+    ///
+    /// This is useful for setting deeply nested values, such as in editor tooling,
+    /// without needing to create each intermediate value by hand.
+    /// Missing elements are created as follows:
+    /// - A [`List`] that is too short for an index access is extended with default values.
+    /// - An [`Enum`] in a unit variant, such as [`None`], is changed to the first variant
+    ///   which has the accessed field, and that variant's fields are set to default values.
+    ///
+    /// Default values are created with the type's [`ReflectDefault`] type data from `registry`.
+    /// If the type doesn't have it, structs, tuples and arrays are created from the default
+    /// values of their fields instead, and collections such as [`List`] are created empty.
+    ///
+    /// Any other missing element, such as a struct field that doesn't exist or an index past
+    /// the end of an [`Array`], results in the same error as [`reflect_path_mut`].
+    ///
+    /// To retrieve a statically typed mutable reference, use
+    /// [`path_mut_or_insert`][GetPath::path_mut_or_insert].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{GetPath, Reflect, TypeRegistry};
+    /// #[derive(Reflect, Default)]
+    /// struct Foo {
+    ///   bar: Option<Bar>,
+    /// }
+    ///
+    /// #[derive(Reflect, Default)]
+    /// struct Bar {
+    ///   baz: Vec<u32>,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Foo>();
+    ///
+    /// let mut foo = Foo::default();
+    /// *foo.path_mut_or_insert::<u32>("bar.0.baz[2]", &registry).unwrap() = 123;
+    /// assert_eq!(foo.bar.unwrap().baz, vec![0, 0, 123]);
+    /// ```
+    ///
+    /// [`List`]: crate::List
+    /// [`Enum`]: crate::Enum
+    /// [`Array`]: crate::Array
+    /// [`ReflectDefault`]: crate::std_traits::ReflectDefault
+    /// [`reflect_path_mut`]: GetPath::reflect_path_mut
+    fn reflect_path_mut_or_insert<'p>(
+        &mut self,
+        path: impl ReflectPath<'p>,
+        registry: &TypeRegistry,
+    ) -> PathResult<'p, &mut dyn PartialReflect> {
+        path.reflect_element_mut_or_insert(self.as_partial_reflect_mut(), registry)
+    }
+
+    /// Returns a statically typed mutable reference to the value specified by `path`,
+    /// inserting any missing elements along the way.
+    ///
+    /// See [`reflect_path_mut_or_insert`][GetPath::reflect_path_mut_or_insert] for how
+    /// missing elements are inserted.
+    ///
+    /// This will automatically handle downcasting to type `T`.
+    /// The downcast will fail if this value is not of type `T`
+    /// (which may be the case when using dynamic types like [`DynamicStruct`]).
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    fn path_mut_or_insert<'p, T: Reflect>(
+        &mut self,
+        path: impl ReflectPath<'p>,
+        registry: &TypeRegistry,
+    ) -> PathResult<'p, &mut T> {
+        path.element_mut_or_insert(self.as_partial_reflect_mut(), registry)
+    }
 }
 
 // Implement `GetPath` for `dyn Reflect`
@@ -453,6 +562,16 @@ impl<'a> ReflectPath<'a> for &'a ParsedPath {
         }
         Ok(root)
     }
+    fn reflect_element_mut_or_insert<'r>(
+        self,
+        mut root: &'r mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> PathResult<'a, &'r mut dyn PartialReflect> {
+        for OffsetAccess { access, offset } in &self.0 {
+            root = access.element_mut_or_insert(root, *offset, registry)?;
+        }
+        Ok(root)
+    }
 }
 impl<const N: usize> From<[OffsetAccess; N]> for ParsedPath {
     fn from(value: [OffsetAccess; N]) -> Self {
@@ -801,6 +920,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reflect_path_mut_or_insert() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<A>();
+        registry.register::<Option<C>>();
+        let mut a = a_sample();
+
+        *a.path_mut_or_insert::<f32>("y[3].mосква", &registry)
+            .unwrap() = 4.0;
+        assert_eq!(
+            a.y,
+            vec![
+                C { mосква: 1.0 },
+                C { mосква: 2.0 },
+                C { mосква: 0.0 },
+                C { mосква: 4.0 },
+            ]
+        );
+
+        *a.path_mut_or_insert::<u32>("unit_variant.1", &registry)
+            .unwrap() = 5;
+        assert_eq!(a.unit_variant, F::Tuple(0, 5));
+
+        // Existing variants are not replaced
+        assert!(a
+            .path_mut_or_insert::<char>("tuple_variant.東京", &registry)
+            .is_err());
+        assert_eq!(a.tuple_variant, F::Tuple(123, 321));
+
+        let mut option: Option<C> = None;
+        *option
+            .path_mut_or_insert::<f32>("0.mосква", &registry)
+            .unwrap() = 1.0;
+        assert_eq!(option, Some(C { mосква: 1.0 }));
+
+        assert_eq!(
+            a.reflect_path_mut_or_insert("array[3]", &registry)
+                .err()
+                .unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::MissingField(ReflectKind::Array),
+                access: Access::ListIndex(3),
+                offset: Some(6),
+            })
+        );
+
+        let empty_registry = TypeRegistry::empty();
+        assert_eq!(
+            a.reflect_path_mut_or_insert("y[5]", &empty_registry)
+                .err()
+                .unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::CannotInsert {
+                    type_path: C::type_path(),
+                },
+                access: Access::ListIndex(5),
+                offset: Some(2),
+            })
+        );
+    }
+
     #[test]
     fn accept_leading_tokens() {
         assert_eq!(
@@ -1,7 +1,8 @@
 use crate::{
-    array_debug, enum_debug, list_debug, map_debug, set_debug, struct_debug, tuple_debug,
-    tuple_struct_debug, DynamicTypePath, DynamicTyped, OpaqueInfo, ReflectKind,
-    ReflectKindMismatchError, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, Typed,
+    array_debug, clone::DeepCloner, enum_debug, list_debug, map_debug, set_debug, struct_debug,
+    tuple_debug, tuple_struct_debug, validation::ValidationError, Diff, DynamicTypePath,
+    DynamicTyped, OpaqueInfo, ReflectCloneError, ReflectKind, ReflectKindMismatchError, ReflectMut,
+    ReflectOwned, ReflectRef, TypeInfo, TypePath, TypeRegistry, Typed,
 };
 use alloc::boxed::Box;
 use core::{
@@ -231,6 +232,52 @@ where
     /// [`Map`]: crate::Map
     fn clone_value(&self) -> Box<dyn PartialReflect>;
 
+    /// Clones the value into a concrete value of the type it represents.
+    ///
+    /// Unlike [`clone_value`], which returns a dynamic value for most types, this converts
+    /// the clone into the concrete type using the [`ReflectFromReflect`] type data in the
+    /// `registry`, so the result can be [downcast] to the concrete type directly.
+    /// This works for dynamic values too, such as those loaded from a scene,
+    /// as long as they [represent] a registered type.
+    ///
+    /// Every field, element and entry is converted in the same way, so a dynamic value
+    /// nested inside a field of an opaque or user-defined type is converted as well.
+    /// Opaque values are cloned as a whole, so an [`Arc`] is cloned by reference and the
+    /// clone shares its data with the original. Since shared values are never traversed,
+    /// reference cycles through `Arc`s aren't detected, and are kept as-is in the clone.
+    ///
+    /// Returns [`ReflectCloneError::Cycle`] instead of recursing forever if a value is
+    /// reached again while it's being cloned, such as through an unsound `Reflect` impl
+    /// which hands out its own parent as a field.
+    ///
+    /// [`clone_value`]: PartialReflect::clone_value
+    /// [`ReflectFromReflect`]: crate::ReflectFromReflect
+    /// [downcast]: Reflect::downcast
+    /// [represent]: PartialReflect::get_represented_type_info
+    /// [`Arc`]: alloc::sync::Arc
+    fn reflect_clone_deep(
+        &self,
+        registry: &TypeRegistry,
+    ) -> Result<Box<dyn Reflect>, ReflectCloneError> {
+        DeepCloner::new(registry).clone_value(self.as_partial_reflect())
+    }
+
+    /// Validates this value, and every value nested inside it, using the validators
+    /// registered with `#[reflect(validate = ...)]`.
+    ///
+    /// The validators are found in the [`ReflectValidate`] type data in the `registry`.
+    /// Dynamic values are validated as the type they [represent].
+    ///
+    /// Returns the first [`ValidationError`] encountered, whose [path] is relative to
+    /// this value.
+    ///
+    /// [`ReflectValidate`]: crate::validation::ReflectValidate
+    /// [represent]: PartialReflect::get_represented_type_info
+    /// [path]: ValidationError::path
+    fn validate(&self, registry: &TypeRegistry) -> Result<(), ValidationError> {
+        crate::validation::validate(self.as_partial_reflect(), registry)
+    }
+
     /// Returns a hash of the value (which includes the type).
     ///
     /// If the underlying type does not support hashing, returns `None`.
@@ -245,6 +292,14 @@ where
         None
     }
 
+    /// Computes the structural [`Diff`] which turns this value into `other`.
+    ///
+    /// The diff only contains the parts of the value which changed, and can be
+    /// [applied](Diff::apply) to this value or another value with the same structure.
+    fn diff(&self, other: &dyn PartialReflect) -> Diff {
+        Diff::new(self.as_partial_reflect(), other)
+    }
+
     /// Debug formatter for the value.
     ///
     /// Any value that is not an implementor of other `Reflect` subtraits
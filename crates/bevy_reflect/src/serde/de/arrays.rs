@@ -1,6 +1,6 @@
 use crate::{
     serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
-    ArrayInfo, DynamicArray, TypeRegistry,
+    Access, ArrayInfo, DynamicArray, TypeRegistry,
 };
 use alloc::{string::ToString, vec::Vec};
 use core::{fmt, fmt::Formatter};
@@ -28,13 +28,16 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for ArrayVisitor<'_, P>
     where
         V: SeqAccess<'de>,
     {
-        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        let mut vec = Vec::with_capacity(self.array_info.capacity());
         let registration = try_get_registration(self.array_info.item_ty(), self.registry)?;
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer::new_internal(
-            registration,
-            self.registry,
-            self.processor.as_deref_mut(),
-        ))? {
+        while let Some(value) = seq.next_element_seed(
+            TypedReflectDeserializer::new_internal(
+                registration,
+                self.registry,
+                self.processor.as_deref_mut(),
+            )
+            .with_access(Access::ListIndex(vec.len())),
+        )? {
             vec.push(value);
         }
 
@@ -0,0 +1,53 @@
+use crate::serde::de::error_utils::make_custom_error;
+use crate::{FromType, PartialReflect, Reflect};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{fmt::Display, str::FromStr};
+use serde::{Deserialize, Deserializer};
+
+/// Type data used to deserialize a [`Reflect`] type from a string using its [`FromStr`] implementation.
+///
+/// This allows opaque types without a [`Deserialize`] implementation, such as IDs from
+/// third-party crates, to be deserialized by the reflection deserializers like
+/// [`TypedReflectDeserializer`] and [`ReflectDeserializer`].
+/// This is the deserialization equivalent of [`ReflectSerializeAsString`].
+///
+/// This can be registered [via the registry] or by adding `#[reflect(DeserializeFromString)]` to
+/// the type definition.
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+/// [`ReflectSerializeAsString`]: crate::serde::ReflectSerializeAsString
+/// [via the registry]: crate::TypeRegistry::register_type_data
+#[derive(Clone)]
+pub struct ReflectDeserializeFromString {
+    from_str: fn(value: &str) -> Result<Box<dyn PartialReflect>, String>,
+}
+
+impl ReflectDeserializeFromString {
+    /// Deserialize a [`Reflect`] type from a string with its [`FromStr`] implementation.
+    pub fn deserialize<'de, D>(&self, deserializer: D) -> Result<Box<dyn PartialReflect>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        (self.from_str)(&value).map_err(make_custom_error)
+    }
+}
+
+impl<T: Reflect + FromStr> FromType<T> for ReflectDeserializeFromString
+where
+    T::Err: Display,
+{
+    fn from_type() -> Self {
+        Self {
+            from_str: |value| {
+                T::from_str(value)
+                    .map(|value| Box::new(value) as Box<dyn PartialReflect>)
+                    .map_err(|err| err.to_string())
+            },
+        }
+    }
+}
@@ -1,18 +1,24 @@
 #[cfg(feature = "debug_stack")]
 use crate::serde::de::error_utils::TYPE_INFO_STACK;
-use crate::serde::{ReflectDeserializeWithRegistry, SerializationData};
+use crate::serde::{
+    MapKeyEncoding, ReflectDeserializeFromString, ReflectDeserializeWithRegistry, SerializationData,
+};
 use crate::{
     serde::{
         de::{
             arrays::ArrayVisitor, enums::EnumVisitor, error_utils::make_custom_error,
             lists::ListVisitor, maps::MapVisitor, options::OptionVisitor, sets::SetVisitor,
             structs::StructVisitor, tuple_structs::TupleStructVisitor, tuples::TupleVisitor,
+            versioned::VersionedStructVisitor,
         },
-        TypeRegistrationDeserializer,
+        migrate::VERSIONED_FIELDS,
+        LenientTypeRegistrationDeserializer, ReflectMigrate, TypeManifest,
+        TypeRegistrationDeserializer, UnknownTypeError,
     },
-    PartialReflect, ReflectDeserialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+    validation::{apply_field_attributes, ReflectValidate},
+    Access, PartialReflect, ReflectDeserialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::{fmt, fmt::Formatter};
 use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
 
@@ -22,7 +28,8 @@ use super::ReflectDeserializerProcessor;
 ///
 /// This is the deserializer counterpart to [`ReflectSerializer`].
 ///
-/// See [`TypedReflectDeserializer`] for a deserializer that expects a known type.
+/// See [`TypedReflectDeserializer`] for a deserializer that expects a known type,
+/// and [`LenientReflectDeserializer`] for one that skips values of unregistered types.
 ///
 /// # Input
 ///
@@ -30,6 +37,9 @@ use super::ReflectDeserializerProcessor;
 /// where the key is the _full_ [type path] of the reflected type
 /// and the value is the serialized data.
 ///
+/// If a [`TypeManifest`] is set using [`with_type_manifest`], the key is instead expected
+/// to be the numeric ID of the type in the manifest.
+///
 /// # Output
 ///
 /// This deserializer will return a [`Box<dyn Reflect>`] containing the deserialized data.
@@ -102,9 +112,11 @@ use super::ReflectDeserializerProcessor;
 /// [`FromReflect`]: crate::FromReflect
 /// [`ReflectFromReflect`]: crate::ReflectFromReflect
 /// [`with_processor`]: Self::with_processor
+/// [`with_type_manifest`]: Self::with_type_manifest
 pub struct ReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    type_manifest: Option<&'a TypeManifest>,
 }
 
 impl<'a> ReflectDeserializer<'a, ()> {
@@ -118,6 +130,7 @@ impl<'a> ReflectDeserializer<'a, ()> {
         Self {
             registry,
             processor: None,
+            type_manifest: None,
         }
     }
 }
@@ -133,8 +146,21 @@ impl<'a, P: ReflectDeserializerProcessor> ReflectDeserializer<'a, P> {
         Self {
             registry,
             processor: Some(processor),
+            type_manifest: None,
         }
     }
+
+    /// Expects the type of the value to be identified by its ID in the given [`TypeManifest`],
+    /// instead of by its type path.
+    ///
+    /// This must be the same manifest that was passed to [`ReflectSerializer::with_type_manifest`]
+    /// when the value was serialized.
+    ///
+    /// [`ReflectSerializer::with_type_manifest`]: crate::serde::ReflectSerializer::with_type_manifest
+    pub fn with_type_manifest(mut self, type_manifest: &'a TypeManifest) -> Self {
+        self.type_manifest = Some(type_manifest);
+        self
+    }
 }
 
 impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeserializer<'_, P> {
@@ -147,6 +173,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
         struct UntypedReflectDeserializerVisitor<'a, P> {
             registry: &'a TypeRegistry,
             processor: Option<&'a mut P>,
+            type_manifest: Option<&'a TypeManifest>,
         }
 
         impl<'de, P: ReflectDeserializerProcessor> Visitor<'de>
@@ -163,8 +190,15 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
             where
                 A: MapAccess<'de>,
             {
+                let type_registration_deserializer = match self.type_manifest {
+                    Some(type_manifest) => TypeRegistrationDeserializer::with_type_manifest(
+                        self.registry,
+                        type_manifest,
+                    ),
+                    None => TypeRegistrationDeserializer::new(self.registry),
+                };
                 let registration = map
-                    .next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+                    .next_key_seed(type_registration_deserializer)?
                     .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
 
                 let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
@@ -184,6 +218,187 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for ReflectDeser
         deserializer.deserialize_map(UntypedReflectDeserializerVisitor {
             registry: self.registry,
             processor: self.processor,
+            type_manifest: self.type_manifest,
+        })
+    }
+}
+
+/// A deserializer for reflected types which skips values of unregistered types,
+/// instead of failing.
+///
+/// This expects the same input as [`ReflectDeserializer`]. Data written by a newer build of an
+/// app, such as a saved scene, may contain types which aren't registered in an older build.
+/// Instead of returning an error for these, this deserializer skips the value, adds an
+/// [`UnknownTypeError`] to the given list, and returns `None`.
+/// Errors in the data of registered types are still returned as usual.
+///
+/// Skipping a value requires a self-describing format, such as RON or JSON.
+/// Formats which aren't self-describing, such as [`bincode`], can't skip a value without knowing
+/// its type, so they still fail on unregistered types.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{LenientReflectDeserializer, UnknownTypeError}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// #[type_path = "my_crate"]
+/// struct MyStruct {
+///   value: i32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+///
+/// let mut unknown_types = Vec::new();
+///
+/// let input = r#"{ "my_crate::MyStruct": (value: 123) }"#;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let output = LenientReflectDeserializer::new(&registry, &mut unknown_types)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert!(output.unwrap().represents::<MyStruct>());
+///
+/// let input = r#"{ "my_crate::NewerStruct": (value: 123) }"#;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let output = LenientReflectDeserializer::new(&registry, &mut unknown_types)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert!(output.is_none());
+///
+/// assert_eq!(
+///     unknown_types,
+///     vec![UnknownTypeError { type_path: "my_crate::NewerStruct".to_string() }],
+/// );
+/// ```
+///
+/// [`bincode`]: https://docs.rs/bincode
+pub struct LenientReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    type_manifest: Option<&'a TypeManifest>,
+    unknown_types: &'a mut Vec<UnknownTypeError>,
+}
+
+impl<'a> LenientReflectDeserializer<'a, ()> {
+    /// Creates a lenient deserializer with no processor, which adds the types it skips
+    /// to `unknown_types`.
+    ///
+    /// If you want to add custom logic for deserializing certain types, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(registry: &'a TypeRegistry, unknown_types: &'a mut Vec<UnknownTypeError>) -> Self {
+        Self {
+            registry,
+            processor: None,
+            type_manifest: None,
+            unknown_types,
+        }
+    }
+}
+
+impl<'a, P: ReflectDeserializerProcessor> LenientReflectDeserializer<'a, P> {
+    /// Creates a lenient deserializer with a processor, which adds the types it skips
+    /// to `unknown_types`.
+    ///
+    /// If you do not need any custom logic for handling certain types, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        registry: &'a TypeRegistry,
+        processor: &'a mut P,
+        unknown_types: &'a mut Vec<UnknownTypeError>,
+    ) -> Self {
+        Self {
+            registry,
+            processor: Some(processor),
+            type_manifest: None,
+            unknown_types,
+        }
+    }
+
+    /// Expects the type of the value to be identified by its ID in the given [`TypeManifest`],
+    /// instead of by its type path.
+    ///
+    /// See [`ReflectDeserializer::with_type_manifest`].
+    pub fn with_type_manifest(mut self, type_manifest: &'a TypeManifest) -> Self {
+        self.type_manifest = Some(type_manifest);
+        self
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for LenientReflectDeserializer<'_, P>
+{
+    type Value = Option<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct LenientReflectDeserializerVisitor<'a, P> {
+            registry: &'a TypeRegistry,
+            processor: Option<&'a mut P>,
+            type_manifest: Option<&'a TypeManifest>,
+            unknown_types: &'a mut Vec<UnknownTypeError>,
+        }
+
+        impl<'de, P: ReflectDeserializerProcessor> Visitor<'de>
+            for LenientReflectDeserializerVisitor<'_, P>
+        {
+            type Value = Option<Box<dyn PartialReflect>>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter
+                    .write_str("map containing `type` and `value` entries for the reflected value")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let type_registration_deserializer = match self.type_manifest {
+                    Some(type_manifest) => LenientTypeRegistrationDeserializer::with_type_manifest(
+                        self.registry,
+                        type_manifest,
+                    ),
+                    None => LenientTypeRegistrationDeserializer::new(self.registry),
+                };
+                let registration = map
+                    .next_key_seed(type_registration_deserializer)?
+                    .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
+
+                let value = match registration {
+                    Ok(registration) => {
+                        Some(map.next_value_seed(TypedReflectDeserializer::new_internal(
+                            registration,
+                            self.registry,
+                            self.processor,
+                        ))?)
+                    }
+                    Err(unknown_type) => {
+                        map.next_value::<IgnoredAny>()?;
+                        self.unknown_types.push(unknown_type);
+                        None
+                    }
+                };
+
+                if map.next_key::<IgnoredAny>()?.is_some() {
+                    return Err(Error::invalid_length(2, &"a single entry"));
+                }
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_map(LenientReflectDeserializerVisitor {
+            registry: self.registry,
+            processor: self.processor,
+            type_manifest: self.type_manifest,
+            unknown_types: self.unknown_types,
         })
     }
 }
@@ -273,6 +488,8 @@ pub struct TypedReflectDeserializer<'a, P: ReflectDeserializerProcessor = ()> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
     processor: Option<&'a mut P>,
+    access: Option<Access<'a>>,
+    fallthrough: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a, ()> {
@@ -290,6 +507,8 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            access: None,
+            fallthrough: false,
         }
     }
 
@@ -308,6 +527,8 @@ impl<'a> TypedReflectDeserializer<'a, ()> {
             registration,
             registry,
             processor: None,
+            access: None,
+            fallthrough: false,
         }
     }
 }
@@ -331,6 +552,37 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor: Some(processor),
+            access: None,
+            fallthrough: false,
+        }
+    }
+
+    /// Creates a typed deserializer which runs the default deserialization
+    /// for this value, but still passes any values nested inside it to the
+    /// processor.
+    ///
+    /// This is meant to be called from inside
+    /// [`ReflectDeserializerProcessor::try_deserialize`], after the processor
+    /// has inspected the value and decided not to override it. Unlike
+    /// [`with_processor`], the processor isn't asked about this value again
+    /// and the type info stack isn't reset.
+    ///
+    /// The processor isn't told about the path of this value either, since
+    /// the outer deserializer already passed it to
+    /// [`ReflectDeserializerProcessor::enter`] before calling the processor.
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn fallthrough(
+        registration: &'a TypeRegistration,
+        registry: &'a TypeRegistry,
+        processor: &'a mut P,
+    ) -> Self {
+        Self {
+            registration,
+            registry,
+            processor: Some(processor),
+            access: None,
+            fallthrough: true,
         }
     }
 
@@ -344,8 +596,19 @@ impl<'a, P: ReflectDeserializerProcessor> TypedReflectDeserializer<'a, P> {
             registration,
             registry,
             processor,
+            access: None,
+            fallthrough: false,
         }
     }
+
+    /// Sets the [`Access`] that leads from the parent value to this one.
+    ///
+    /// The processor is told about it with [`ReflectDeserializerProcessor::enter`]
+    /// before this value is deserialized.
+    pub(super) fn with_access(mut self, access: Access<'a>) -> Self {
+        self.access = Some(access);
+        self
+    }
 }
 
 impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
@@ -357,10 +620,18 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
     where
         D: serde::Deserializer<'de>,
     {
+        let registration = self.registration;
+
+        let access = self.access.take();
+        if let (Some(processor), Some(access)) = (self.processor.as_deref_mut(), &access) {
+            processor.enter(access);
+        }
+
         let deserialize_internal = || -> Result<Self::Value, D::Error> {
             // First, check if our processor wants to deserialize this type
             // This takes priority over any other deserialization operations
-            let deserializer = if let Some(processor) = self.processor.as_deref_mut() {
+            let processor = self.processor.as_deref_mut().filter(|_| !self.fallthrough);
+            let deserializer = if let Some(processor) = processor {
                 match processor.try_deserialize(self.registration, self.registry, deserializer) {
                     Ok(Ok(value)) => {
                         return Ok(value);
@@ -389,18 +660,38 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                 return Ok(value);
             }
 
+            if let Some(deserialize_reflect) =
+                self.registration.data::<ReflectDeserializeFromString>()
+            {
+                let value = deserialize_reflect.deserialize(deserializer)?;
+                return Ok(value);
+            }
+
             match self.registration.type_info() {
                 TypeInfo::Struct(struct_info) => {
-                    let mut dynamic_struct = deserializer.deserialize_struct(
-                        struct_info.type_path_table().ident().unwrap(),
-                        struct_info.field_names(),
-                        StructVisitor {
-                            struct_info,
-                            registration: self.registration,
-                            registry: self.registry,
-                            processor: self.processor,
-                        },
-                    )?;
+                    let mut dynamic_struct = match self.registration.data::<ReflectMigrate>() {
+                        Some(migrate) => deserializer.deserialize_struct(
+                            struct_info.type_path_table().ident().unwrap(),
+                            VERSIONED_FIELDS,
+                            VersionedStructVisitor {
+                                struct_info,
+                                registration: self.registration,
+                                registry: self.registry,
+                                processor: self.processor.as_deref_mut(),
+                                migrate,
+                            },
+                        )?,
+                        None => deserializer.deserialize_struct(
+                            struct_info.type_path_table().ident().unwrap(),
+                            struct_info.field_names(),
+                            StructVisitor {
+                                struct_info,
+                                registration: self.registration,
+                                registry: self.registry,
+                                processor: self.processor.as_deref_mut(),
+                            },
+                        )?,
+                    };
                     dynamic_struct.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_struct))
                 }
@@ -414,7 +705,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                                 tuple_struct_info,
                                 registration: self.registration,
                                 registry: self.registry,
-                                processor: self.processor,
+                                processor: self.processor.as_deref_mut(),
                             },
                         )?
                     } else {
@@ -425,7 +716,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                                 tuple_struct_info,
                                 registration: self.registration,
                                 registry: self.registry,
-                                processor: self.processor,
+                                processor: self.processor.as_deref_mut(),
                             },
                         )?
                     };
@@ -436,7 +727,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                     let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
                         list_info,
                         registry: self.registry,
-                        processor: self.processor,
+                        processor: self.processor.as_deref_mut(),
                     })?;
                     dynamic_list.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_list))
@@ -447,18 +738,27 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                         ArrayVisitor {
                             array_info,
                             registry: self.registry,
-                            processor: self.processor,
+                            processor: self.processor.as_deref_mut(),
                         },
                     )?;
                     dynamic_array.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_array))
                 }
                 TypeInfo::Map(map_info) => {
-                    let mut dynamic_map = deserializer.deserialize_map(MapVisitor {
+                    let encoding = self
+                        .registry
+                        .map_key_encoding_of(self.registration.type_id());
+                    let visitor = MapVisitor {
                         map_info,
                         registry: self.registry,
-                        processor: self.processor,
-                    })?;
+                        processor: self.processor.as_deref_mut(),
+                        encoding,
+                    };
+                    let mut dynamic_map = if encoding == MapKeyEncoding::Pairs {
+                        deserializer.deserialize_seq(visitor)?
+                    } else {
+                        deserializer.deserialize_map(visitor)?
+                    };
                     dynamic_map.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_map))
                 }
@@ -466,7 +766,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                     let mut dynamic_set = deserializer.deserialize_seq(SetVisitor {
                         set_info,
                         registry: self.registry,
-                        processor: self.processor,
+                        processor: self.processor.as_deref_mut(),
                     })?;
                     dynamic_set.set_represented_type(Some(self.registration.type_info()));
                     Ok(Box::new(dynamic_set))
@@ -478,7 +778,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                             tuple_info,
                             registration: self.registration,
                             registry: self.registry,
-                            processor: self.processor,
+                            processor: self.processor.as_deref_mut(),
                         },
                     )?;
                     dynamic_tuple.set_represented_type(Some(self.registration.type_info()));
@@ -492,7 +792,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                         deserializer.deserialize_option(OptionVisitor {
                             enum_info,
                             registry: self.registry,
-                            processor: self.processor,
+                            processor: self.processor.as_deref_mut(),
                         })?
                     } else {
                         deserializer.deserialize_enum(
@@ -502,7 +802,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                                 enum_info,
                                 registration: self.registration,
                                 registry: self.registry,
-                                processor: self.processor,
+                                processor: self.processor.as_deref_mut(),
                             },
                         )?
                     };
@@ -512,7 +812,7 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                 TypeInfo::Opaque(_) => {
                     // This case should already be handled
                     Err(make_custom_error(format_args!(
-                        "type `{type_path}` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`",
+                        "type `{type_path}` did not register the `ReflectDeserialize`, `ReflectDeserializeWithRegistry` or `ReflectDeserializeFromString` type data. For certain types, this may need to be registered manually using `register_type_data`",
                     )))
                 }
             }
@@ -521,11 +821,40 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
         #[cfg(feature = "debug_stack")]
         TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
 
-        let output = deserialize_internal();
+        // Nested values have already been validated by their own deserializers
+        let output = deserialize_internal().and_then(|mut value| {
+            apply_field_attributes(
+                value.as_partial_reflect_mut(),
+                registration.type_info(),
+                self.registry,
+            )
+            .map_err(|err| {
+                make_custom_error(format_args!(
+                    "invalid value for `{}`: {err}",
+                    registration.type_info().type_path()
+                ))
+            })?;
+
+            if let Some(validate) = registration.data::<ReflectValidate>() {
+                validate
+                    .validate(value.as_partial_reflect())
+                    .map_err(|err| {
+                        make_custom_error(format_args!(
+                            "invalid value for `{}`: {err}",
+                            registration.type_info().type_path()
+                        ))
+                    })?;
+            }
+            Ok(value)
+        });
 
         #[cfg(feature = "debug_stack")]
         TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
 
+        if let (Some(processor), Some(_)) = (self.processor, access) {
+            processor.exit();
+        }
+
         output
     }
 }
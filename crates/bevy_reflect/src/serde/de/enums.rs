@@ -9,7 +9,7 @@ use crate::{
         },
         TypedReflectDeserializer,
     },
-    DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, EnumInfo, StructVariantInfo,
+    Access, DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, EnumInfo, StructVariantInfo,
     TupleVariantInfo, TypeRegistration, TypeRegistry, VariantInfo,
 };
 use core::{fmt, fmt::Formatter};
@@ -61,12 +61,14 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for EnumVisitor<'_, P> {
                     *TupleLikeInfo::field_at(tuple_info, 0)?.ty(),
                     self.registry,
                 )?;
-                let value =
-                    variant.newtype_variant_seed(TypedReflectDeserializer::new_internal(
+                let value = variant.newtype_variant_seed(
+                    TypedReflectDeserializer::new_internal(
                         registration,
                         self.registry,
                         self.processor,
-                    ))?;
+                    )
+                    .with_access(Access::TupleIndex(0)),
+                )?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
                 dynamic_tuple.into()
@@ -130,14 +132,21 @@ impl<'de> DeserializeSeed<'de> for VariantDeserializer {
             where
                 E: Error,
             {
-                self.0.variant(variant_name).ok_or_else(|| {
-                    let names = self.0.iter().map(VariantInfo::name);
-                    make_custom_error(format_args!(
-                        "unknown variant `{}`, expected one of {:?}",
-                        variant_name,
-                        ExpectedValues::from_iter(names)
-                    ))
-                })
+                self.0
+                    .variant(variant_name)
+                    .or_else(|| {
+                        self.0
+                            .iter()
+                            .find(|variant| variant.aliases().contains(&variant_name))
+                    })
+                    .ok_or_else(|| {
+                        let names = self.0.iter().map(VariantInfo::name);
+                        make_custom_error(format_args!(
+                            "unknown variant `{}`, expected one of {:?}",
+                            variant_name,
+                            ExpectedValues::from_iter(names)
+                        ))
+                    })
             }
         }
 
@@ -1,6 +1,6 @@
 use crate::{
     serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
-    DynamicList, ListInfo, TypeRegistry,
+    Access, DynamicList, List, ListInfo, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
 use serde::de::{SeqAccess, Visitor};
@@ -29,11 +29,14 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for ListVisitor<'_, P> {
     {
         let mut list = DynamicList::default();
         let registration = try_get_registration(self.list_info.item_ty(), self.registry)?;
-        while let Some(value) = seq.next_element_seed(TypedReflectDeserializer::new_internal(
-            registration,
-            self.registry,
-            self.processor.as_deref_mut(),
-        ))? {
+        while let Some(value) = seq.next_element_seed(
+            TypedReflectDeserializer::new_internal(
+                registration,
+                self.registry,
+                self.processor.as_deref_mut(),
+            )
+            .with_access(Access::ListIndex(list.len())),
+        )? {
             list.push_box(value);
         }
         Ok(list)
@@ -1,19 +1,27 @@
 use crate::{
-    serde::{de::registration_utils::try_get_registration, TypedReflectDeserializer},
-    DynamicMap, Map, MapInfo, TypeRegistry,
+    serde::{
+        de::{error_utils::make_custom_error, registration_utils::try_get_registration},
+        MapKeyEncoding, ReflectDeserializeFromString, TypedReflectDeserializer,
+    },
+    DynamicMap, Map, MapInfo, PartialReflect, TypeRegistration, TypeRegistry,
 };
+use alloc::boxed::Box;
 use core::{fmt, fmt::Formatter};
-use serde::de::{MapAccess, Visitor};
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
 
 use super::ReflectDeserializerProcessor;
 
 /// A [`Visitor`] for deserializing [`Map`] values.
 ///
+/// Maps using [`MapKeyEncoding::Pairs`] are deserialized from sequences,
+/// and all other maps are deserialized from maps.
+///
 /// [`Map`]: crate::Map
 pub(super) struct MapVisitor<'a, P> {
     pub map_info: &'static MapInfo,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a mut P>,
+    pub encoding: MapKeyEncoding,
 }
 
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
@@ -30,11 +38,23 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
         let mut dynamic_map = DynamicMap::default();
         let key_registration = try_get_registration(self.map_info.key_ty(), self.registry)?;
         let value_registration = try_get_registration(self.map_info.value_ty(), self.registry)?;
-        while let Some(key) = map.next_key_seed(TypedReflectDeserializer::new_internal(
-            key_registration,
-            self.registry,
-            self.processor.as_deref_mut(),
-        ))? {
+        let string_keys = self.encoding == MapKeyEncoding::String
+            && !MapKeyEncoding::is_passthrough_key(key_registration.type_id());
+        loop {
+            let key = if string_keys {
+                map.next_key_seed(StringKeyDeserializer {
+                    registration: key_registration,
+                })?
+            } else {
+                map.next_key_seed(TypedReflectDeserializer::new_internal(
+                    key_registration,
+                    self.registry,
+                    self.processor.as_deref_mut(),
+                ))?
+            };
+            let Some(key) = key else {
+                break;
+            };
             let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
                 value_registration,
                 self.registry,
@@ -45,4 +65,97 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapVisitor<'_, P> {
 
         Ok(dynamic_map)
     }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut dynamic_map = DynamicMap::default();
+        let key_registration = try_get_registration(self.map_info.key_ty(), self.registry)?;
+        let value_registration = try_get_registration(self.map_info.value_ty(), self.registry)?;
+        while let Some((key, value)) = seq.next_element_seed(MapEntryDeserializer {
+            key_registration,
+            value_registration,
+            registry: self.registry,
+            processor: self.processor.as_deref_mut(),
+        })? {
+            dynamic_map.insert_boxed(key, value);
+        }
+
+        Ok(dynamic_map)
+    }
+}
+
+/// A deserializer for map keys using [`MapKeyEncoding::String`].
+struct StringKeyDeserializer<'a> {
+    registration: &'a TypeRegistration,
+}
+
+impl<'de> DeserializeSeed<'de> for StringKeyDeserializer<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let reflect_deserialize_from_string = self
+            .registration
+            .data::<ReflectDeserializeFromString>()
+            .ok_or_else(|| {
+                make_custom_error::<D::Error>(format_args!(
+                    "map key of type `{}` did not register the `ReflectDeserializeFromString` type data, which is required to deserialize it from a string",
+                    self.registration.type_info().type_path()
+                ))
+            })?;
+
+        reflect_deserialize_from_string.deserialize(deserializer)
+    }
+}
+
+/// A deserializer for map entries using [`MapKeyEncoding::Pairs`].
+struct MapEntryDeserializer<'a, P> {
+    key_registration: &'a TypeRegistration,
+    value_registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for MapEntryDeserializer<'_, P> {
+    type Value = (Box<dyn PartialReflect>, Box<dyn PartialReflect>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for MapEntryDeserializer<'_, P> {
+    type Value = (Box<dyn PartialReflect>, Box<dyn PartialReflect>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected map entry as a key-value pair")
+    }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let key = seq
+            .next_element_seed(TypedReflectDeserializer::new_internal(
+                self.key_registration,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ))?
+            .ok_or_else(|| Error::invalid_length(0, &"a key-value pair"))?;
+        let value = seq
+            .next_element_seed(TypedReflectDeserializer::new_internal(
+                self.value_registration,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ))?
+            .ok_or_else(|| Error::invalid_length(1, &"a key-value pair"))?;
+        Ok((key, value))
+    }
 }
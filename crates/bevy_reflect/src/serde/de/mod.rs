@@ -1,9 +1,13 @@
+pub use deserialize_from_string::*;
 pub use deserialize_with_registry::*;
 pub use deserializer::*;
 pub use processor::*;
 pub use registrations::*;
+pub use self_describing::*;
+pub use streaming::*;
 
 mod arrays;
+mod deserialize_from_string;
 mod deserialize_with_registry;
 mod deserializer;
 mod enums;
@@ -15,12 +19,15 @@ mod options;
 mod processor;
 mod registration_utils;
 mod registrations;
+mod self_describing;
 mod sets;
+mod streaming;
 mod struct_utils;
 mod structs;
 mod tuple_structs;
 mod tuple_utils;
 mod tuples;
+mod versioned;
 
 #[cfg(test)]
 mod tests {
@@ -33,18 +40,22 @@ mod tests {
     use bincode::Options;
     use core::{any::TypeId, f32::consts::PI, ops::RangeInclusive};
     use serde::{de::DeserializeSeed, Deserialize};
-    use serde::{de::IgnoredAny, Deserializer};
+    use serde::{
+        de::{value::StringDeserializer, IgnoredAny, IntoDeserializer},
+        Deserializer,
+    };
 
     use bevy_platform_support::collections::{HashMap, HashSet};
 
     use crate::{
         self as bevy_reflect,
         serde::{
-            ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializer,
-            TypedReflectDeserializer,
+            LenientReflectDeserializer, ReflectDeserializer, ReflectDeserializerProcessor,
+            ReflectSerializer, StreamedElement, StreamingReflectDeserializer,
+            TypedReflectDeserializer, TypedReflectSerializer, UnknownTypeError,
         },
-        DynamicEnum, FromReflect, PartialReflect, Reflect, ReflectDeserialize, TypeRegistration,
-        TypeRegistry,
+        Access, DynamicEnum, FromReflect, OffsetAccess, ParsedPath, PartialReflect, Reflect,
+        ReflectDeserialize, TypeRegistration, TypeRegistry,
     };
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -429,6 +440,60 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn should_deserialize_aliases() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Player {
+            #[reflect(alias = "hp", alias = "hit_points")]
+            health: u32,
+            team: Team,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Team {
+            #[reflect(alias = "Crimson")]
+            Red,
+            #[reflect(alias = "Azure")]
+            Blue {
+                #[reflect(alias = "id")]
+                index: u32,
+            },
+        }
+
+        let mut registry = get_registry();
+        registry.register::<Player>();
+        registry.register::<Team>();
+        let registration = registry.get(TypeId::of::<Player>()).unwrap();
+
+        let deserialize = |input: &str| {
+            let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+            let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let output = reflect_deserializer
+                .deserialize(&mut ron_deserializer)
+                .unwrap();
+            <Player as FromReflect>::from_reflect(output.as_partial_reflect()).unwrap()
+        };
+
+        let expected = Player {
+            health: 10,
+            team: Team::Red,
+        };
+        assert_eq!(expected, deserialize("(health: 10, team: Red)"));
+        assert_eq!(expected, deserialize("(hp: 10, team: Crimson)"));
+        assert_eq!(expected, deserialize("(hit_points: 10, team: Red)"));
+
+        let expected = Player {
+            health: 5,
+            team: Team::Blue { index: 2 },
+        };
+        assert_eq!(expected, deserialize("(hp: 5, team: Azure(id: 2))"));
+
+        // Serialization always uses the current names.
+        let serializer = TypedReflectSerializer::new(&expected, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("(health:5,team:Blue(index:2))", output);
+    }
+
     // Regression test for https://github.com/bevyengine/bevy/issues/12462
     #[test]
     fn should_reserialize() {
@@ -523,9 +588,9 @@ mod tests {
             .deserialize(&mut deserializer)
             .unwrap_err();
         #[cfg(feature = "debug_stack")]
-        assert_eq!(error, ron::Error::Message("type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data` (stack: `core::ops::RangeInclusive<f32>`)".to_string()));
+        assert_eq!(error, ron::Error::Message("type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize`, `ReflectDeserializeWithRegistry` or `ReflectDeserializeFromString` type data. For certain types, this may need to be registered manually using `register_type_data` (stack: `core::ops::RangeInclusive<f32>`)".to_string()));
         #[cfg(not(feature = "debug_stack"))]
-        assert_eq!(error, ron::Error::Message("type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()));
+        assert_eq!(error, ron::Error::Message("type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize`, `ReflectDeserializeWithRegistry` or `ReflectDeserializeFromString` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()));
     }
 
     #[test]
@@ -765,6 +830,289 @@ mod tests {
         assert!(<Foo as FromReflect>::from_reflect(dynamic_output.as_partial_reflect()).is_none());
     }
 
+    #[test]
+    fn should_fall_through_from_processor() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo {
+            path: String,
+            paths: Vec<String>,
+        }
+
+        struct EmbeddedPathProcessor;
+
+        impl ReflectDeserializerProcessor for EmbeddedPathProcessor {
+            fn try_deserialize<'de, D>(
+                &mut self,
+                registration: &TypeRegistration,
+                registry: &TypeRegistry,
+                deserializer: D,
+            ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                if registration.type_id() == TypeId::of::<Vec<String>>() {
+                    // Falling through without inspecting the value still
+                    // processes the elements
+                    return TypedReflectDeserializer::fallthrough(registration, registry, self)
+                        .deserialize(deserializer)
+                        .map(Ok);
+                }
+                if registration.type_id() != TypeId::of::<String>() {
+                    return Ok(Err(deserializer));
+                }
+
+                let path = String::deserialize(deserializer)?;
+                if let Some(path) = path.strip_prefix("embedded://") {
+                    return Ok(Ok(Box::new(alloc::format!("embedded/{path}"))));
+                }
+
+                let deserializer: StringDeserializer<D::Error> = path.into_deserializer();
+                TypedReflectDeserializer::fallthrough(registration, registry, self)
+                    .deserialize(deserializer)
+                    .map(Ok)
+            }
+        }
+
+        let expected = Foo {
+            path: "embedded/a.png".to_string(),
+            paths: vec!["b.png".to_string(), "embedded/c.png".to_string()],
+        };
+
+        let input = r#"(
+            path: "embedded://a.png",
+            paths: ["b.png", "embedded://c.png"],
+        )"#;
+
+        let mut registry = get_registry();
+        registry.register::<Foo>();
+        registry.register::<Vec<String>>();
+        let registration = registry.get(TypeId::of::<Foo>()).unwrap();
+        let mut processor = EmbeddedPathProcessor;
+        let reflect_deserializer =
+            TypedReflectDeserializer::with_processor(registration, &registry, &mut processor);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output =
+            <Foo as FromReflect>::from_reflect(dynamic_output.as_partial_reflect()).unwrap();
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn should_tell_processor_the_current_path() {
+        #[derive(Reflect)]
+        struct Mesh(u32);
+
+        #[derive(Reflect)]
+        enum Material {
+            Color { id: u32 },
+            Texture(u8, u32),
+        }
+
+        #[derive(Reflect)]
+        struct MyAsset {
+            mesh: Mesh,
+            lods: Vec<u32>,
+            fallback: Option<Mesh>,
+            materials: [Material; 2],
+        }
+
+        struct PathProcessor {
+            path: ParsedPath,
+            found: Vec<String>,
+        }
+
+        impl ReflectDeserializerProcessor for PathProcessor {
+            fn try_deserialize<'de, D>(
+                &mut self,
+                registration: &TypeRegistration,
+                _: &TypeRegistry,
+                deserializer: D,
+            ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                if registration.type_id() == TypeId::of::<u32>() {
+                    self.found.push(self.path.to_string());
+                }
+                Ok(Err(deserializer))
+            }
+
+            fn enter(&mut self, access: &Access) {
+                self.path
+                    .0
+                    .push(OffsetAccess::from(access.clone().into_owned()));
+            }
+
+            fn exit(&mut self) {
+                self.path.0.pop();
+            }
+        }
+
+        let input = r#"(
+            mesh: (1),
+            lods: [2, 3],
+            fallback: Some((4)),
+            materials: (Color(id: 5), Texture(6, 7)),
+        )"#;
+
+        let mut registry = get_registry();
+        registry.register::<MyAsset>();
+        let registration = registry.get(TypeId::of::<MyAsset>()).unwrap();
+        let mut processor = PathProcessor {
+            path: ParsedPath(Vec::new()),
+            found: Vec::new(),
+        };
+        let reflect_deserializer =
+            TypedReflectDeserializer::with_processor(registration, &registry, &mut processor);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ".mesh.0",
+                ".lods[0]",
+                ".lods[1]",
+                ".fallback.0.0",
+                ".materials[0].id",
+                ".materials[1].1",
+            ],
+            processor.found
+        );
+        assert!(processor.path.0.is_empty());
+    }
+
+    #[test]
+    fn should_stream_list_elements() {
+        let registry = get_registry();
+
+        let input = "[1, 2, 3]";
+        let mut elements = Vec::new();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let streaming_deserializer =
+            StreamingReflectDeserializer::of::<Vec<i32>>(&registry, |element| {
+                let StreamedElement::ListElement(value) = element else {
+                    panic!("expected list element");
+                };
+                elements
+                    .push(<i32 as FromReflect>::from_reflect(value.as_partial_reflect()).unwrap());
+            });
+        let count = streaming_deserializer
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(elements, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn should_stream_map_entries() {
+        let registry = get_registry();
+
+        let input = "{1: 10, 2: 20}";
+        let mut entries = HashMap::<u8, usize>::default();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let streaming_deserializer =
+            StreamingReflectDeserializer::of::<HashMap<u8, usize>>(&registry, |element| {
+                let StreamedElement::MapEntry { key, value } = element else {
+                    panic!("expected map entry");
+                };
+                entries.insert(
+                    <u8 as FromReflect>::from_reflect(key.as_partial_reflect()).unwrap(),
+                    <usize as FromReflect>::from_reflect(value.as_partial_reflect()).unwrap(),
+                );
+            });
+        let count = streaming_deserializer
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[&1], 10);
+        assert_eq!(entries[&2], 20);
+    }
+
+    #[test]
+    fn should_not_stream_non_collection() {
+        let registry = get_registry();
+
+        let input = "(foo: 123)";
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let streaming_deserializer =
+            StreamingReflectDeserializer::of::<SomeStruct>(&registry, |_| {});
+        let error = streaming_deserializer
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+
+        #[cfg(feature = "debug_stack")]
+        assert_eq!(error, ron::Error::Message("type `bevy_reflect::serde::de::tests::SomeStruct` cannot be streamed, as it is not a list or map (stack: `bevy_reflect::serde::de::tests::SomeStruct`)".to_string()));
+        #[cfg(not(feature = "debug_stack"))]
+        assert_eq!(error, ron::Error::Message("type `bevy_reflect::serde::de::tests::SomeStruct` cannot be streamed, as it is not a list or map".to_string()));
+    }
+
+    #[test]
+    fn should_skip_unknown_types_when_lenient() {
+        let registry = get_registry();
+        let mut unknown_types = Vec::new();
+
+        let inputs = [
+            r#"{"bevy_reflect::serde::de::tests::SomeStruct": (foo: 123)}"#,
+            r#"{"my_crate::Unknown": (value: [1, 2, 3], nested: (a: "b"))}"#,
+            r#"{"i32": 5}"#,
+            r#"{"my_crate::AlsoUnknown": ()}"#,
+        ];
+        let outputs = inputs
+            .iter()
+            .map(|input| {
+                let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+                LenientReflectDeserializer::new(&registry, &mut unknown_types)
+                    .deserialize(&mut deserializer)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(outputs.len(), 4);
+        assert_eq!(
+            <SomeStruct as FromReflect>::from_reflect(outputs[0].as_deref().unwrap()),
+            Some(SomeStruct { foo: 123 })
+        );
+        assert!(outputs[1].is_none());
+        assert_eq!(
+            <i32 as FromReflect>::from_reflect(outputs[2].as_deref().unwrap()),
+            Some(5)
+        );
+        assert!(outputs[3].is_none());
+        assert_eq!(
+            unknown_types,
+            vec![
+                UnknownTypeError {
+                    type_path: "my_crate::Unknown".to_string()
+                },
+                UnknownTypeError {
+                    type_path: "my_crate::AlsoUnknown".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_return_data_errors_when_lenient() {
+        let registry = get_registry();
+        let mut unknown_types = Vec::new();
+
+        let input = r#"{"bevy_reflect::serde::de::tests::SomeStruct": (foo: "not a number")}"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let result = LenientReflectDeserializer::new(&registry, &mut unknown_types)
+            .deserialize(&mut deserializer);
+
+        assert!(result.is_err());
+        assert!(unknown_types.is_empty());
+    }
+
     #[cfg(feature = "functions")]
     mod functions {
         use super::*;
@@ -850,7 +1198,7 @@ mod tests {
             assert_eq!(
                 error,
                 ron::Error::Message(
-                    "type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize` type data. For certain types, this may need to be registered manually using `register_type_data` (stack: `bevy_reflect::serde::de::tests::debug_stack::Foo` -> `bevy_reflect::serde::de::tests::debug_stack::Bar` -> `bevy_reflect::serde::de::tests::debug_stack::Baz` -> `alloc::vec::Vec<core::ops::RangeInclusive<f32>>` -> `core::ops::RangeInclusive<f32>`)".to_string()
+                    "type `core::ops::RangeInclusive<f32>` did not register the `ReflectDeserialize`, `ReflectDeserializeWithRegistry` or `ReflectDeserializeFromString` type data. For certain types, this may need to be registered manually using `register_type_data` (stack: `bevy_reflect::serde::de::tests::debug_stack::Foo` -> `bevy_reflect::serde::de::tests::debug_stack::Bar` -> `bevy_reflect::serde::de::tests::debug_stack::Baz` -> `alloc::vec::Vec<core::ops::RangeInclusive<f32>>` -> `core::ops::RangeInclusive<f32>`)".to_string()
                 )
             );
         }
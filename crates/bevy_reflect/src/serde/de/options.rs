@@ -3,7 +3,7 @@ use crate::{
         de::{error_utils::make_custom_error, registration_utils::try_get_registration},
         TypedReflectDeserializer,
     },
-    DynamicEnum, DynamicTuple, EnumInfo, TypeRegistry, VariantInfo,
+    Access, DynamicEnum, DynamicTuple, EnumInfo, TypeRegistry, VariantInfo,
 };
 use core::{fmt, fmt::Formatter};
 use serde::de::{DeserializeSeed, Error, Visitor};
@@ -47,7 +47,8 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for OptionVisitor<'_, P>
                     registration,
                     self.registry,
                     self.processor,
-                );
+                )
+                .with_access(Access::TupleIndex(0));
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
                 let mut option = DynamicEnum::default();
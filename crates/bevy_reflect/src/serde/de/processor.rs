@@ -1,4 +1,4 @@
-use crate::{PartialReflect, TypeRegistration, TypeRegistry};
+use crate::{Access, PartialReflect, TypeRegistration, TypeRegistry};
 use alloc::boxed::Box;
 
 /// Allows overriding the default deserialization behavior of
@@ -190,6 +190,50 @@ pub trait ReflectDeserializerProcessor {
     /// }
     /// ```
     ///
+    /// Returning the deserializer is only possible before any data has been
+    /// read from it. To decide based on the value itself, for example to only
+    /// intercept asset paths starting with `embedded://`, read the value into
+    /// an owned intermediate first. If you then don't want to override it,
+    /// fall through to the default deserialization with
+    /// [`TypedReflectDeserializer::fallthrough`] over a deserializer for that
+    /// intermediate. Any values nested inside it are still passed to the
+    /// processor:
+    ///
+    /// ```
+    /// # use bevy_reflect::{TypeRegistration, PartialReflect, TypeRegistry};
+    /// # use bevy_reflect::serde::{ReflectDeserializerProcessor, TypedReflectDeserializer};
+    /// # use core::any::TypeId;
+    /// use serde::de::{value::StringDeserializer, DeserializeSeed, IntoDeserializer};
+    ///
+    /// struct EmbeddedPathProcessor;
+    ///
+    /// impl ReflectDeserializerProcessor for EmbeddedPathProcessor {
+    ///     fn try_deserialize<'de, D>(
+    ///         &mut self,
+    ///         registration: &TypeRegistration,
+    ///         registry: &TypeRegistry,
+    ///         deserializer: D,
+    ///     ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    ///     where
+    ///         D: serde::Deserializer<'de>
+    ///     {
+    ///         if registration.type_id() != TypeId::of::<String>() {
+    ///             return Ok(Err(deserializer));
+    ///         }
+    ///
+    ///         let path = <String as serde::Deserialize>::deserialize(deserializer)?;
+    ///         if let Some(path) = path.strip_prefix("embedded://") {
+    ///             return Ok(Ok(Box::new(format!("embedded/{path}"))));
+    ///         }
+    ///
+    ///         let deserializer: StringDeserializer<D::Error> = path.into_deserializer();
+    ///         TypedReflectDeserializer::fallthrough(registration, registry, self)
+    ///             .deserialize(deserializer)
+    ///             .map(Ok)
+    ///     }
+    /// }
+    /// ```
+    ///
     /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
     /// [`FromReflect`]: crate::FromReflect
     fn try_deserialize<'de, D>(
@@ -200,6 +244,71 @@ pub trait ReflectDeserializerProcessor {
     ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
     where
         D: serde::Deserializer<'de>;
+
+    /// Called when the deserializer moves into a field or element of the
+    /// value it's currently deserializing, before [`try_deserialize`] is
+    /// called for that field or element.
+    ///
+    /// Together with [`exit`], this lets a processor keep track of *where* in
+    /// the value it is, for example to treat an asset handle in a `mesh`
+    /// field differently from one in a `material` field. Struct fields are
+    /// entered with [`Access::Field`], tuple, tuple struct and variant fields
+    /// (including the value inside a `Some`) with [`Access::TupleIndex`],
+    /// and list and array elements with [`Access::ListIndex`]. The keys and
+    /// values of maps and the values of sets have no path, so they're not
+    /// entered.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Examples
+    ///
+    /// Tracking the path of the value being deserialized:
+    ///
+    /// ```
+    /// # use bevy_reflect::{Access, TypeRegistration, PartialReflect, TypeRegistry};
+    /// # use bevy_reflect::serde::ReflectDeserializerProcessor;
+    /// use bevy_reflect::{OffsetAccess, ParsedPath};
+    ///
+    /// struct PathTrackingProcessor {
+    ///     path: ParsedPath,
+    /// }
+    ///
+    /// impl ReflectDeserializerProcessor for PathTrackingProcessor {
+    ///     fn try_deserialize<'de, D>(
+    ///         &mut self,
+    ///         registration: &TypeRegistration,
+    ///         _registry: &TypeRegistry,
+    ///         deserializer: D,
+    ///     ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    ///     where
+    ///         D: serde::Deserializer<'de>
+    ///     {
+    ///         // `self.path` is e.g. `.mesh` or `.materials[1]` here
+    ///         Ok(Err(deserializer))
+    ///     }
+    ///
+    ///     fn enter(&mut self, access: &Access) {
+    ///         self.path.0.push(OffsetAccess::from(access.clone().into_owned()));
+    ///     }
+    ///
+    ///     fn exit(&mut self) {
+    ///         self.path.0.pop();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`try_deserialize`]: Self::try_deserialize
+    /// [`exit`]: Self::exit
+    fn enter(&mut self, _access: &Access) {}
+
+    /// Called when the deserializer is done with the field or element that
+    /// was most recently passed to [`enter`], whether or not it was
+    /// deserialized successfully.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// [`enter`]: Self::enter
+    fn exit(&mut self) {}
 }
 
 impl ReflectDeserializerProcessor for () {
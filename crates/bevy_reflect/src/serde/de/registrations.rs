@@ -1,22 +1,39 @@
-use crate::{serde::de::error_utils::make_custom_error, TypeRegistration, TypeRegistry};
+use crate::{
+    serde::{de::error_utils::make_custom_error, TypeManifest},
+    TypeRegistration, TypeRegistry,
+};
+use alloc::string::String;
 use core::{fmt, fmt::Formatter};
 use serde::de::{DeserializeSeed, Error, Visitor};
+use thiserror::Error;
 
 /// A deserializer for type registrations.
 ///
 /// This will return a [`&TypeRegistration`] corresponding to the given type.
 /// This deserializer expects a string containing the _full_ [type path] of the
-/// type to find the `TypeRegistration` of.
+/// type to find the `TypeRegistration` of, or the type's ID if a [`TypeManifest`] is given.
 ///
 /// [`&TypeRegistration`]: TypeRegistration
 /// [type path]: crate::TypePath::type_path
 pub struct TypeRegistrationDeserializer<'a> {
     registry: &'a TypeRegistry,
+    type_manifest: Option<&'a TypeManifest>,
 }
 
 impl<'a> TypeRegistrationDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            type_manifest: None,
+        }
+    }
+
+    /// Creates a deserializer which expects the ID of the type in the given [`TypeManifest`].
+    pub fn with_type_manifest(registry: &'a TypeRegistry, type_manifest: &'a TypeManifest) -> Self {
+        Self {
+            registry,
+            type_manifest: Some(type_manifest),
+        }
     }
 }
 
@@ -27,25 +44,113 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
-        struct TypeRegistrationVisitor<'a>(&'a TypeRegistry);
+        deserialize_registration(self.registry, self.type_manifest, deserializer)?
+            .map_err(make_custom_error)
+    }
+}
+
+/// An error returned by [`LenientTypeRegistrationDeserializer`] for a type which
+/// isn't registered in the [`TypeRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("no registration found for `{type_path}`")]
+pub struct UnknownTypeError {
+    /// The type path which no registration was found for.
+    pub type_path: String,
+}
 
-        impl<'de, 'a> Visitor<'de> for TypeRegistrationVisitor<'a> {
-            type Value = &'a TypeRegistration;
+/// A deserializer for type registrations which doesn't fail on unregistered types.
+///
+/// This expects the same input as [`TypeRegistrationDeserializer`], but returns an
+/// [`UnknownTypeError`] instead of failing when the type isn't registered,
+/// so the caller can decide to skip the value of that type.
+pub struct LenientTypeRegistrationDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    type_manifest: Option<&'a TypeManifest>,
+}
 
-            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-                formatter.write_str("string containing `type` entry for the reflected value")
-            }
+impl<'a> LenientTypeRegistrationDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            type_manifest: None,
+        }
+    }
 
-            fn visit_str<E>(self, type_path: &str) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                self.0.get_with_type_path(type_path).ok_or_else(|| {
-                    make_custom_error(format_args!("no registration found for `{type_path}`"))
-                })
-            }
+    /// Creates a deserializer which expects the ID of the type in the given [`TypeManifest`].
+    pub fn with_type_manifest(registry: &'a TypeRegistry, type_manifest: &'a TypeManifest) -> Self {
+        Self {
+            registry,
+            type_manifest: Some(type_manifest),
         }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for LenientTypeRegistrationDeserializer<'a> {
+    type Value = Result<&'a TypeRegistration, UnknownTypeError>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_registration(self.registry, self.type_manifest, deserializer)
+    }
+}
+
+fn deserialize_registration<'a, 'de, D>(
+    registry: &'a TypeRegistry,
+    type_manifest: Option<&'a TypeManifest>,
+    deserializer: D,
+) -> Result<Result<&'a TypeRegistration, UnknownTypeError>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct TypeRegistrationVisitor<'a>(&'a TypeRegistry);
+
+    impl<'de, 'a> Visitor<'de> for TypeRegistrationVisitor<'a> {
+        type Value = Result<&'a TypeRegistration, UnknownTypeError>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            formatter.write_str("string containing `type` entry for the reflected value")
+        }
+
+        fn visit_str<E>(self, type_path: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            Ok(self
+                .0
+                .get_with_versioned_type_path(type_path)
+                .ok_or_else(|| UnknownTypeError {
+                    type_path: type_path.into(),
+                }))
+        }
+    }
+
+    struct TypeIdVisitor<'a>(&'a TypeRegistry, &'a TypeManifest);
+
+    impl<'de, 'a> Visitor<'de> for TypeIdVisitor<'a> {
+        type Value = Result<&'a TypeRegistration, UnknownTypeError>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            formatter.write_str("type manifest ID for the reflected value")
+        }
+
+        fn visit_u64<E>(self, id: u64) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            let type_path = u32::try_from(id)
+                .ok()
+                .and_then(|id| self.1.type_path(id))
+                .ok_or_else(|| {
+                    make_custom_error(format_args!("no type found in manifest for ID `{id}`"))
+                })?;
+            TypeRegistrationVisitor(self.0).visit_str(type_path)
+        }
+    }
 
-        deserializer.deserialize_str(TypeRegistrationVisitor(self.registry))
+    match type_manifest {
+        Some(type_manifest) => deserializer.deserialize_u32(TypeIdVisitor(registry, type_manifest)),
+        None => deserializer.deserialize_str(TypeRegistrationVisitor(registry)),
     }
 }
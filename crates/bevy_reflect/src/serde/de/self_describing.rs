@@ -0,0 +1,354 @@
+use crate::{
+    serde::{
+        de::error_utils::make_custom_error,
+        ser::self_describing::{
+            ARRAY, KINDS, LIST, MAP, SET, STRUCT, STRUCT_VARIANT, TUPLE, TUPLE_STRUCT,
+            TUPLE_VARIANT, TYPED, UNIT_VARIANT,
+        },
+        ReflectDeserializer,
+    },
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Map, PartialReflect, Set, TypeRegistry,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+/// A deserializer for reflected values written by a [`SelfDescribingReflectSerializer`].
+///
+/// # Input
+///
+/// This deserializer expects the output of a [`SelfDescribingReflectSerializer`]: a map with a
+/// single entry, where the key is either `Typed`, or the [kind] of the value.
+///
+/// # Output
+///
+/// Values tagged `Typed` are deserialized exactly as a [`ReflectDeserializer`] would, so they
+/// have a represented type.
+///
+/// All other values are returned as the dynamic type for their kind, such as a
+/// [`DynamicStruct`] for a `Struct`, without a represented type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{GetField, PartialReflect, ReflectRef, TypeRegistry};
+/// # use bevy_reflect::serde::SelfDescribingReflectDeserializer;
+/// # use serde::de::DeserializeSeed;
+/// let registry = TypeRegistry::new();
+///
+/// let input = r#"{"Struct":{"health":{"Typed":{"u32":100}}}}"#;
+///
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let reflect_deserializer = SelfDescribingReflectDeserializer::new(&registry);
+///
+/// let output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+/// assert!(output.get_represented_type_info().is_none());
+///
+/// let ReflectRef::Struct(output) = output.reflect_ref() else {
+///     panic!("expected a struct");
+/// };
+/// assert_eq!(output.get_field::<u32>("health"), Some(&100));
+/// ```
+///
+/// [`SelfDescribingReflectSerializer`]: crate::serde::SelfDescribingReflectSerializer
+/// [kind]: crate::ReflectKind
+pub struct SelfDescribingReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> SelfDescribingReflectDeserializer<'a> {
+    /// Creates a self-describing deserializer.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for SelfDescribingReflectDeserializer<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SelfDescribingVisitor<'a> {
+            registry: &'a TypeRegistry,
+        }
+
+        impl<'de> Visitor<'de> for SelfDescribingVisitor<'_> {
+            type Value = Box<dyn PartialReflect>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("map containing a kind tag and its value")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let kind = map
+                    .next_key::<String>()?
+                    .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
+
+                let registry = self.registry;
+                let value: Box<dyn PartialReflect> = match kind.as_str() {
+                    TYPED => map.next_value_seed(ReflectDeserializer::new(registry))?,
+                    STRUCT => Box::new(map.next_value_seed(StructFieldsSeed { registry })?),
+                    TUPLE_STRUCT => {
+                        let mut tuple_struct = DynamicTupleStruct::default();
+                        for value in map.next_value_seed(ElementsSeed { registry })? {
+                            tuple_struct.insert_boxed(value);
+                        }
+                        Box::new(tuple_struct)
+                    }
+                    TUPLE => Box::new(tuple_from(map.next_value_seed(ElementsSeed { registry })?)),
+                    LIST => {
+                        let mut list = DynamicList::default();
+                        for value in map.next_value_seed(ElementsSeed { registry })? {
+                            list.push_box(value);
+                        }
+                        Box::new(list)
+                    }
+                    ARRAY => {
+                        let values = map.next_value_seed(ElementsSeed { registry })?;
+                        Box::new(DynamicArray::new(values.into_boxed_slice()))
+                    }
+                    MAP => {
+                        let mut dynamic_map = DynamicMap::default();
+                        for (key, value) in map.next_value_seed(EntriesSeed { registry })? {
+                            dynamic_map.insert_boxed(key, value);
+                        }
+                        Box::new(dynamic_map)
+                    }
+                    SET => {
+                        let mut set = DynamicSet::default();
+                        for value in map.next_value_seed(ElementsSeed { registry })? {
+                            set.insert_boxed(value);
+                        }
+                        Box::new(set)
+                    }
+                    UNIT_VARIANT => {
+                        let name = map.next_value::<String>()?;
+                        Box::new(DynamicEnum::new(name, DynamicVariant::Unit))
+                    }
+                    TUPLE_VARIANT => {
+                        let (name, values) = map.next_value_seed(VariantSeed {
+                            fields: ElementsSeed { registry },
+                        })?;
+                        Box::new(DynamicEnum::new(name, tuple_from(values)))
+                    }
+                    STRUCT_VARIANT => {
+                        let (name, fields) = map.next_value_seed(VariantSeed {
+                            fields: StructFieldsSeed { registry },
+                        })?;
+                        Box::new(DynamicEnum::new(name, fields))
+                    }
+                    _ => return Err(Error::unknown_variant(&kind, KINDS)),
+                };
+
+                if map.next_key::<IgnoredAny>()?.is_some() {
+                    return Err(Error::invalid_length(2, &"a single entry"));
+                }
+
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_map(SelfDescribingVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+fn tuple_from(values: Vec<Box<dyn PartialReflect>>) -> DynamicTuple {
+    let mut tuple = DynamicTuple::default();
+    for value in values {
+        tuple.insert_boxed(value);
+    }
+    tuple
+}
+
+/// Deserializes a sequence of self-describing values.
+struct ElementsSeed<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ElementsSeed<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ElementsSeed<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of self-describing values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) =
+            seq.next_element_seed(SelfDescribingReflectDeserializer::new(self.registry))?
+        {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a map from field names to self-describing values.
+struct StructFieldsSeed<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for StructFieldsSeed<'_> {
+    type Value = DynamicStruct;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StructFieldsSeed<'_> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map of field names to self-describing values")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dynamic_struct = DynamicStruct::default();
+        while let Some(name) = map.next_key::<String>()? {
+            let value =
+                map.next_value_seed(SelfDescribingReflectDeserializer::new(self.registry))?;
+            dynamic_struct.insert_boxed(name, value);
+        }
+        Ok(dynamic_struct)
+    }
+}
+
+/// Deserializes a sequence of self-describing key-value pairs.
+struct EntriesSeed<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for EntriesSeed<'_> {
+    type Value = Vec<(Box<dyn PartialReflect>, Box<dyn PartialReflect>)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for EntriesSeed<'_> {
+    type Value = Vec<(Box<dyn PartialReflect>, Box<dyn PartialReflect>)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of self-describing key-value pairs")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(entry) = seq.next_element_seed(EntrySeed {
+            registry: self.registry,
+        })? {
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+/// Deserializes a single self-describing key-value pair.
+struct EntrySeed<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for EntrySeed<'_> {
+    type Value = (Box<dyn PartialReflect>, Box<dyn PartialReflect>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for EntrySeed<'_> {
+    type Value = (Box<dyn PartialReflect>, Box<dyn PartialReflect>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("self-describing key-value pair")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let key = seq
+            .next_element_seed(SelfDescribingReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element_seed(SelfDescribingReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok((key, value))
+    }
+}
+
+/// Deserializes the name of an enum variant followed by its fields.
+struct VariantSeed<S> {
+    fields: S,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for VariantSeed<S> {
+    type Value = (String, S::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> Visitor<'de> for VariantSeed<S> {
+    type Value = (String, S::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("variant name and fields")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let name = seq
+            .next_element::<String>()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let fields = seq
+            .next_element_seed(self.fields)?
+            .ok_or_else(|| make_custom_error(format_args!("missing fields of variant `{name}`")))?;
+        Ok((name, fields))
+    }
+}
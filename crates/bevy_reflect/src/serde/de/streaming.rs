@@ -0,0 +1,281 @@
+#[cfg(feature = "debug_stack")]
+use crate::serde::de::error_utils::TYPE_INFO_STACK;
+use crate::{
+    serde::{
+        de::{error_utils::make_custom_error, registration_utils::try_get_registration},
+        TypedReflectDeserializer,
+    },
+    Access, ListInfo, MapInfo, PartialReflect, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+};
+use alloc::boxed::Box;
+use core::{fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, MapAccess, SeqAccess, Visitor};
+
+use super::ReflectDeserializerProcessor;
+
+/// A single element yielded by a [`StreamingReflectDeserializer`].
+#[derive(Debug)]
+pub enum StreamedElement {
+    /// An element of a [`List`](crate::List).
+    ListElement(Box<dyn PartialReflect>),
+    /// An entry of a [`Map`](crate::Map).
+    MapEntry {
+        /// The key of the entry.
+        key: Box<dyn PartialReflect>,
+        /// The value of the entry.
+        value: Box<dyn PartialReflect>,
+    },
+}
+
+/// A deserializer for reflected [`List`] and [`Map`] types which passes each element
+/// to a callback as soon as it is deserialized.
+///
+/// [`TypedReflectDeserializer`] collects the elements of a list or map into a
+/// [`DynamicList`] or [`DynamicMap`] before returning it, so the whole collection has to be
+/// held in memory as dynamic values at once.
+/// For very large collections, such as the entities of a big scene file, this deserializer
+/// can be used instead to process (or convert with [`FromReflect`]) each element and then
+/// drop it, before the next element is deserialized.
+///
+/// Only the elements themselves are deserialized using [`TypedReflectDeserializer`],
+/// so a [`ReflectDeserializerProcessor`] passed to [`with_processor`] is used for the
+/// elements, but not for the collection type itself.
+///
+/// # Input
+///
+/// This deserializer expects the same input as [`TypedReflectDeserializer`] would for the
+/// given list or map type.
+///
+/// # Output
+///
+/// The callback receives a [`StreamedElement::ListElement`] for each element of a list,
+/// or a [`StreamedElement::MapEntry`] for each entry of a map, in the order they appear in the input.
+/// The deserializer itself returns the number of elements passed to the callback.
+///
+/// Deserializing any type other than a list or map returns an error.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{StreamedElement, StreamingReflectDeserializer}};
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Vec<i32>>();
+///
+/// let input = "[1, 2, 3]";
+///
+/// let mut sum = 0;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let streaming_deserializer =
+///     StreamingReflectDeserializer::of::<Vec<i32>>(&registry, |element| {
+///         let StreamedElement::ListElement(value) = element else {
+///             unreachable!();
+///         };
+///         sum += value.try_downcast_ref::<i32>().unwrap();
+///     });
+///
+/// let count = streaming_deserializer.deserialize(&mut deserializer).unwrap();
+/// assert_eq!(count, 3);
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// [`List`]: crate::List
+/// [`Map`]: crate::Map
+/// [`DynamicList`]: crate::DynamicList
+/// [`DynamicMap`]: crate::DynamicMap
+/// [`FromReflect`]: crate::FromReflect
+/// [`with_processor`]: Self::with_processor
+pub struct StreamingReflectDeserializer<'a, F, P: ReflectDeserializerProcessor = ()> {
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    callback: F,
+}
+
+impl<'a, F: FnMut(StreamedElement)> StreamingReflectDeserializer<'a, F, ()> {
+    /// Creates a streaming deserializer with no processor.
+    ///
+    /// If you want to add custom logic for deserializing certain element types, use
+    /// [`with_processor`].
+    ///
+    /// [`with_processor`]: Self::with_processor
+    pub fn new(
+        registration: &'a TypeRegistration,
+        registry: &'a TypeRegistry,
+        callback: F,
+    ) -> Self {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.set(crate::type_info_stack::TypeInfoStack::new());
+
+        Self {
+            registration,
+            registry,
+            processor: None,
+            callback,
+        }
+    }
+
+    /// Creates a new [`StreamingReflectDeserializer`] for the given type `T`
+    /// without a processor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not registered in the given [`TypeRegistry`].
+    pub fn of<T: TypePath>(registry: &'a TypeRegistry, callback: F) -> Self {
+        let registration = registry
+            .get(core::any::TypeId::of::<T>())
+            .unwrap_or_else(|| panic!("no registration found for type `{}`", T::type_path()));
+
+        Self::new(registration, registry, callback)
+    }
+}
+
+impl<'a, F: FnMut(StreamedElement), P: ReflectDeserializerProcessor>
+    StreamingReflectDeserializer<'a, F, P>
+{
+    /// Creates a streaming deserializer with a processor, which is used when
+    /// deserializing the elements.
+    ///
+    /// If you do not need any custom logic for handling certain types, use
+    /// [`new`].
+    ///
+    /// [`new`]: Self::new
+    pub fn with_processor(
+        registration: &'a TypeRegistration,
+        registry: &'a TypeRegistry,
+        processor: &'a mut P,
+        callback: F,
+    ) -> Self {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.set(crate::type_info_stack::TypeInfoStack::new());
+
+        Self {
+            registration,
+            registry,
+            processor: Some(processor),
+            callback,
+        }
+    }
+}
+
+impl<'de, F: FnMut(StreamedElement), P: ReflectDeserializerProcessor> DeserializeSeed<'de>
+    for StreamingReflectDeserializer<'_, F, P>
+{
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(|stack| stack.push(self.registration.type_info()));
+
+        let output = match self.registration.type_info() {
+            TypeInfo::List(list_info) => deserializer.deserialize_seq(StreamingListVisitor {
+                list_info,
+                registry: self.registry,
+                processor: self.processor,
+                callback: self.callback,
+            }),
+            TypeInfo::Map(map_info) => deserializer.deserialize_map(StreamingMapVisitor {
+                map_info,
+                registry: self.registry,
+                processor: self.processor,
+                callback: self.callback,
+            }),
+            type_info => Err(make_custom_error(format_args!(
+                "type `{}` cannot be streamed, as it is not a list or map",
+                type_info.type_path(),
+            ))),
+        };
+
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+        output
+    }
+}
+
+/// A [`Visitor`] for streaming the elements of [`List`] values.
+///
+/// [`List`]: crate::List
+struct StreamingListVisitor<'a, F, P> {
+    list_info: &'static ListInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    callback: F,
+}
+
+impl<'de, F: FnMut(StreamedElement), P: ReflectDeserializerProcessor> Visitor<'de>
+    for StreamingListVisitor<'_, F, P>
+{
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected list value")
+    }
+
+    fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut count = 0;
+        let registration = try_get_registration(self.list_info.item_ty(), self.registry)?;
+        while let Some(value) = seq.next_element_seed(
+            TypedReflectDeserializer::new_internal(
+                registration,
+                self.registry,
+                self.processor.as_deref_mut(),
+            )
+            .with_access(Access::ListIndex(count)),
+        )? {
+            (self.callback)(StreamedElement::ListElement(value));
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// A [`Visitor`] for streaming the entries of [`Map`] values.
+///
+/// [`Map`]: crate::Map
+struct StreamingMapVisitor<'a, F, P> {
+    map_info: &'static MapInfo,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    callback: F,
+}
+
+impl<'de, F: FnMut(StreamedElement), P: ReflectDeserializerProcessor> Visitor<'de>
+    for StreamingMapVisitor<'_, F, P>
+{
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected map value")
+    }
+
+    fn visit_map<V>(mut self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut count = 0;
+        let key_registration = try_get_registration(self.map_info.key_ty(), self.registry)?;
+        let value_registration = try_get_registration(self.map_info.value_ty(), self.registry)?;
+        while let Some(key) = map.next_key_seed(TypedReflectDeserializer::new_internal(
+            key_registration,
+            self.registry,
+            self.processor.as_deref_mut(),
+        ))? {
+            let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
+                value_registration,
+                self.registry,
+                self.processor.as_deref_mut(),
+            ))?;
+            (self.callback)(StreamedElement::MapEntry { key, value });
+            count += 1;
+        }
+        Ok(count)
+    }
+}
@@ -7,7 +7,8 @@ use crate::{
         },
         SerializationData, TypedReflectDeserializer,
     },
-    DynamicStruct, NamedField, StructInfo, StructVariantInfo, TypeRegistration, TypeRegistry,
+    Access, DynamicStruct, NamedField, StructInfo, StructVariantInfo, TypeRegistration,
+    TypeRegistry,
 };
 use alloc::string::ToString;
 use core::slice::Iter;
@@ -100,21 +101,31 @@ where
 {
     let mut dynamic_struct = DynamicStruct::default();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.field::<V::Error>(&key).map_err(|_| {
-            let fields = info.iter_fields().map(NamedField::name);
-            make_custom_error(format_args!(
-                "unknown field `{}`, expected one of {:?}",
-                key,
-                ExpectedValues::from_iter(fields)
-            ))
-        })?;
+        let field = info
+            .field::<V::Error>(&key)
+            .ok()
+            .or_else(|| {
+                info.iter_fields()
+                    .find(|field| field.aliases().contains(&key.as_str()))
+            })
+            .ok_or_else(|| {
+                let fields = info.iter_fields().map(NamedField::name);
+                make_custom_error(format_args!(
+                    "unknown field `{}`, expected one of {:?}",
+                    key,
+                    ExpectedValues::from_iter(fields)
+                ))
+            })?;
         let registration = try_get_registration(*field.ty(), registry)?;
-        let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
-            registration,
-            registry,
-            processor.as_deref_mut(),
-        ))?;
-        dynamic_struct.insert_boxed(&key, value);
+        let value = map.next_value_seed(
+            TypedReflectDeserializer::new_internal(
+                registration,
+                registry,
+                processor.as_deref_mut(),
+            )
+            .with_access(Access::Field(field.name().into())),
+        )?;
+        dynamic_struct.insert_boxed(field.name(), value);
     }
 
     if let Some(serialization_data) = registration.data::<SerializationData>() {
@@ -172,11 +183,14 @@ where
         }
 
         let value = seq
-            .next_element_seed(TypedReflectDeserializer::new_internal(
-                try_get_registration(*info.field_at(index)?.ty(), registry)?,
-                registry,
-                processor.as_deref_mut(),
-            ))?
+            .next_element_seed(
+                TypedReflectDeserializer::new_internal(
+                    try_get_registration(*info.field_at(index)?.ty(), registry)?,
+                    registry,
+                    processor.as_deref_mut(),
+                )
+                .with_access(Access::Field(name.into())),
+            )?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         dynamic_struct.insert_boxed(name, value);
     }
@@ -1,6 +1,6 @@
 use crate::{
     serde::{de::tuple_utils::visit_tuple, SerializationData},
-    DynamicTupleStruct, TupleStructInfo, TypeRegistration, TypeRegistry,
+    Access, DynamicTupleStruct, TupleStructInfo, TypeRegistration, TypeRegistry,
 };
 use core::{fmt, fmt::Formatter};
 use serde::de::{DeserializeSeed, SeqAccess, Visitor};
@@ -61,7 +61,8 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for TupleStructVisitor<'
             self.registry,
         )?;
         let reflect_deserializer =
-            TypedReflectDeserializer::new_internal(registration, self.registry, self.processor);
+            TypedReflectDeserializer::new_internal(registration, self.registry, self.processor)
+                .with_access(Access::TupleIndex(0));
         let value = reflect_deserializer.deserialize(deserializer)?;
 
         tuple.insert_boxed(value.into_partial_reflect());
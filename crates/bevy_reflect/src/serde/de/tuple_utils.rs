@@ -3,8 +3,8 @@ use crate::{
         de::{error_utils::make_custom_error, registration_utils::try_get_registration},
         SerializationData, TypedReflectDeserializer,
     },
-    DynamicTuple, TupleInfo, TupleStructInfo, TupleVariantInfo, TypeRegistration, TypeRegistry,
-    UnnamedField,
+    Access, DynamicTuple, TupleInfo, TupleStructInfo, TupleVariantInfo, TypeRegistration,
+    TypeRegistry, UnnamedField,
 };
 use alloc::string::ToString;
 use serde::de::{Error, SeqAccess};
@@ -97,11 +97,14 @@ where
         }
 
         let value = seq
-            .next_element_seed(TypedReflectDeserializer::new_internal(
-                try_get_registration(*info.field_at(index)?.ty(), registry)?,
-                registry,
-                processor.as_deref_mut(),
-            ))?
+            .next_element_seed(
+                TypedReflectDeserializer::new_internal(
+                    try_get_registration(*info.field_at(index)?.ty(), registry)?,
+                    registry,
+                    processor.as_deref_mut(),
+                )
+                .with_access(Access::TupleIndex(index)),
+            )?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         tuple.insert_boxed(value);
     }
@@ -0,0 +1,467 @@
+use crate::{
+    serde::{
+        de::{
+            error_utils::make_custom_error, helpers::Ident, struct_utils::visit_struct_seq,
+            structs::StructVisitor,
+        },
+        migrate::{VALUE_FIELD, VERSIONED_FIELDS, VERSION_FIELD},
+        ReflectMigrate, SerializationData,
+    },
+    DynamicArray, DynamicList, DynamicStruct, PartialReflect, ReflectRef, Struct, StructInfo,
+    TypeInfo, TypeRegistration, TypeRegistry,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{any::TypeId, fmt, fmt::Formatter};
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+use super::ReflectDeserializerProcessor;
+
+/// A [`Visitor`] for deserializing [`Struct`] values with [`ReflectMigrate`] type data,
+/// running any migrations needed to bring the data to the current version.
+///
+/// [`Struct`]: crate::Struct
+pub(super) struct VersionedStructVisitor<'a, P> {
+    pub struct_info: &'static StructInfo,
+    pub registration: &'a TypeRegistration,
+    pub registry: &'a TypeRegistry,
+    pub processor: Option<&'a mut P>,
+    pub migrate: &'a ReflectMigrate,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for VersionedStructVisitor<'_, P> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("versioned reflected struct value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| Error::invalid_length(0, &"2"))?;
+        let value = seq
+            .next_element_seed(VersionedValueSeed {
+                struct_info: self.struct_info,
+                registration: self.registration,
+                registry: self.registry,
+                processor: self.processor,
+                is_current: version == self.migrate.version(),
+            })?
+            .ok_or_else(|| Error::invalid_length(1, &"2"))?;
+
+        migrate(
+            value,
+            version,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.migrate,
+        )
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let first_key = map.next_key::<Ident>()?.map(|Ident(key)| key);
+        if first_key.as_deref() != Some(VERSION_FIELD) {
+            // This data was serialized before the type was versioned,
+            // so its fields are at the top level
+            let value = visit_old_struct(&mut map, first_key)?;
+            return migrate(
+                value,
+                0,
+                self.struct_info,
+                self.registration,
+                self.registry,
+                self.migrate,
+            );
+        }
+
+        let version = map.next_value::<u32>()?;
+        match map.next_key::<Ident>()? {
+            Some(Ident(key)) if key == VALUE_FIELD => {}
+            Some(Ident(key)) => return Err(Error::unknown_field(&key, VERSIONED_FIELDS)),
+            None => return Err(Error::missing_field(VALUE_FIELD)),
+        }
+        let value = map.next_value_seed(VersionedValueSeed {
+            struct_info: self.struct_info,
+            registration: self.registration,
+            registry: self.registry,
+            processor: self.processor,
+            is_current: version == self.migrate.version(),
+        })?;
+
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(Error::invalid_length(3, &"2"));
+        }
+
+        migrate(
+            value,
+            version,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.migrate,
+        )
+    }
+}
+
+/// Migrates deserialized data from `version` to the current version,
+/// converts its fields to their current types,
+/// then adds the defaults of any skipped fields that are missing.
+fn migrate<E: Error>(
+    value: DynamicStruct,
+    version: u32,
+    struct_info: &StructInfo,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+    migrate: &ReflectMigrate,
+) -> Result<DynamicStruct, E> {
+    let mut value = migrate.migrate(value, version).ok_or_else(|| {
+        make_custom_error(format_args!(
+            "data for `{}` has version {}, which is newer than the current version {}",
+            struct_info.type_path(),
+            version,
+            migrate.version(),
+        ))
+    })?;
+
+    if version != migrate.version() {
+        for field in struct_info.iter() {
+            let Some(old_value) = value.field(field.name()) else {
+                continue;
+            };
+            if is_type(old_value, field.type_id()) {
+                continue;
+            }
+            let new_value = registry
+                .get_type_info(field.type_id())
+                .and_then(|type_info| update_type(old_value, type_info, registry))
+                .ok_or_else(|| {
+                    make_custom_error(format_args!(
+                        "migrated field `{}` of `{}` cannot be converted to `{}`",
+                        field.name(),
+                        struct_info.type_path(),
+                        field.type_path(),
+                    ))
+                })?;
+            value.insert_boxed(field.name(), new_value);
+        }
+    }
+
+    if let Some(serialization_data) = registration.data::<SerializationData>() {
+        for (skipped_index, skipped_field) in serialization_data.iter_skipped() {
+            let Some(field) = struct_info.field_at(*skipped_index) else {
+                continue;
+            };
+            if value.field(field.name()).is_none() {
+                value.insert_boxed(
+                    field.name(),
+                    skipped_field.generate_default().into_partial_reflect(),
+                );
+            }
+        }
+    }
+
+    Ok(value)
+}
+
+/// Returns whether `value` is, or represents, the type with the given [`TypeId`].
+fn is_type(value: &dyn PartialReflect, type_id: TypeId) -> bool {
+    value
+        .get_represented_type_info()
+        .is_some_and(|info| info.type_id() == type_id)
+}
+
+/// Converts an old field value to the type described by `type_info`.
+///
+/// Numbers are cast to the current numeric type, and structs, lists and arrays
+/// are converted field by field or element by element.
+/// Returns `None` if the value can't be converted.
+fn update_type(
+    value: &dyn PartialReflect,
+    type_info: &'static TypeInfo,
+    registry: &TypeRegistry,
+) -> Option<Box<dyn PartialReflect>> {
+    if is_type(value, type_info.type_id()) {
+        return Some(value.clone_value());
+    }
+    if let Some(number) = cast_number(value, type_info.type_id()) {
+        return Some(number);
+    }
+
+    match (type_info, value.reflect_ref()) {
+        (TypeInfo::Struct(info), ReflectRef::Struct(old)) => {
+            let mut new = DynamicStruct::default();
+            new.set_represented_type(Some(type_info));
+            for field in info.iter() {
+                let Some(old_field) = old.field(field.name()) else {
+                    continue;
+                };
+                let field_info = registry.get_type_info(field.type_id())?;
+                new.insert_boxed(field.name(), update_type(old_field, field_info, registry)?);
+            }
+            Some(Box::new(new))
+        }
+        (TypeInfo::List(info), ReflectRef::List(old)) => {
+            let item_info = registry.get_type_info(info.item_ty().id())?;
+            let mut new = old
+                .iter()
+                .map(|item| update_type(item, item_info, registry))
+                .collect::<Option<DynamicList>>()?;
+            new.set_represented_type(Some(type_info));
+            Some(Box::new(new))
+        }
+        (TypeInfo::Array(info), ReflectRef::List(old)) if old.len() == info.capacity() => {
+            let item_info = registry.get_type_info(info.item_ty().id())?;
+            let mut new = old
+                .iter()
+                .map(|item| update_type(item, item_info, registry))
+                .collect::<Option<DynamicArray>>()?;
+            new.set_represented_type(Some(type_info));
+            Some(Box::new(new))
+        }
+        _ => None,
+    }
+}
+
+/// Casts an untyped number to the numeric type with the given [`TypeId`],
+/// returning `None` if either isn't a number or the value doesn't fit.
+///
+/// Integers can be cast to floats, but floats are never truncated to integers.
+fn cast_number(value: &dyn PartialReflect, type_id: TypeId) -> Option<Box<dyn PartialReflect>> {
+    macro_rules! cast_int {
+        ($int:expr) => {
+            cast_int!($int, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize)
+        };
+        ($int:expr, $($ty:ty),*) => {
+            $(if type_id == TypeId::of::<$ty>() {
+                return <$ty>::try_from($int)
+                    .ok()
+                    .map(|int| Box::new(int) as Box<dyn PartialReflect>);
+            })*
+        };
+    }
+
+    let float = if let Some(&int) = value.try_downcast_ref::<i64>() {
+        cast_int!(int);
+        int as f64
+    } else if let Some(&int) = value.try_downcast_ref::<u64>() {
+        cast_int!(int);
+        int as f64
+    } else {
+        *value.try_downcast_ref::<f64>()?
+    };
+
+    if type_id == TypeId::of::<f32>() {
+        Some(Box::new(float as f32))
+    } else if type_id == TypeId::of::<f64>() {
+        Some(Box::new(float))
+    } else {
+        None
+    }
+}
+
+/// A [`DeserializeSeed`] for the fields of a versioned struct.
+struct VersionedValueSeed<'a, P> {
+    struct_info: &'static StructInfo,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+    is_current: bool,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de> for VersionedValueSeed<'_, P> {
+    type Value = DynamicStruct;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = self.struct_info.type_path_table().ident().unwrap();
+        let fields = self.struct_info.field_names();
+        if self.is_current {
+            deserializer.deserialize_struct(
+                name,
+                fields,
+                StructVisitor {
+                    struct_info: self.struct_info,
+                    registration: self.registration,
+                    registry: self.registry,
+                    processor: self.processor,
+                },
+            )
+        } else {
+            deserializer.deserialize_struct(
+                name,
+                fields,
+                OldStructVisitor {
+                    struct_info: self.struct_info,
+                    registration: self.registration,
+                    registry: self.registry,
+                    processor: self.processor,
+                },
+            )
+        }
+    }
+}
+
+/// A [`Visitor`] for the fields of a struct which were serialized at an older version.
+struct OldStructVisitor<'a, P> {
+    struct_info: &'static StructInfo,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a mut P>,
+}
+
+impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for OldStructVisitor<'_, P> {
+    type Value = DynamicStruct;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflected struct value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Without field names, the best we can do is assume the layout hasn't changed
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.processor,
+        )
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let first_key = map.next_key::<Ident>()?.map(|Ident(key)| key);
+        visit_old_struct(&mut map, first_key)
+    }
+}
+
+/// Deserializes the fields of a struct which were serialized at an older version,
+/// starting with the value of `first_key`, which has already been read.
+///
+/// Fields are deserialized without type information, since their types may have changed
+/// since the data was serialized. They are converted to their current types after migrating.
+fn visit_old_struct<'de, V>(
+    map: &mut V,
+    first_key: Option<String>,
+) -> Result<DynamicStruct, V::Error>
+where
+    V: MapAccess<'de>,
+{
+    let mut dynamic_struct = DynamicStruct::default();
+    let mut next_key = first_key;
+    while let Some(key) = next_key {
+        let value = map.next_value_seed(UntypedValueSeed)?;
+        dynamic_struct.insert_boxed(key, value);
+        next_key = map.next_key::<Ident>()?.map(|Ident(key)| key);
+    }
+    Ok(dynamic_struct)
+}
+
+/// A [`DeserializeSeed`] for values whose type isn't known,
+/// which requires a self-describing format.
+struct UntypedValueSeed;
+
+impl<'de> DeserializeSeed<'de> for UntypedValueSeed {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(UntypedValueVisitor)
+    }
+}
+
+struct UntypedValueVisitor;
+
+impl<'de> Visitor<'de> for UntypedValueVisitor {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Box::new(v.to_string()))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(()))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(()))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(UntypedValueSeed)? {
+            list.push_box(value);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut dynamic_struct = DynamicStruct::default();
+        while let Some(Ident(key)) = map.next_key::<Ident>()? {
+            dynamic_struct.insert_boxed(key, map.next_value_seed(UntypedValueSeed)?);
+        }
+        Ok(Box::new(dynamic_struct))
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{DynamicStruct, FromType, Struct};
+use alloc::vec::Vec;
+
+/// The name of the field containing the version of a [versioned] struct in serialized output.
+///
+/// [versioned]: ReflectMigrate
+pub(super) const VERSION_FIELD: &str = "version";
+/// The name of the field containing the data of a [versioned] struct in serialized output.
+///
+/// [versioned]: ReflectMigrate
+pub(super) const VALUE_FIELD: &str = "value";
+/// The fields of a [versioned] struct in serialized output.
+///
+/// [versioned]: ReflectMigrate
+pub(super) const VERSIONED_FIELDS: &[&str] = &[VERSION_FIELD, VALUE_FIELD];
+
+/// A function which migrates the data of a struct from one version to the next.
+///
+/// See [`ReflectMigrate`] for details.
+pub type MigrationFn = fn(DynamicStruct) -> DynamicStruct;
+
+/// A trait for struct types which are serialized with a version number,
+/// and can migrate data from older versions.
+///
+/// This is used to create [`ReflectMigrate`] type data with `#[reflect(Migrate)]`.
+pub trait Migrate: Struct {
+    /// The current version of the type.
+    const VERSION: u32;
+
+    /// Returns the migration steps of the type, as pairs of the version each step migrates
+    /// from, and the function which migrates data from that version to the next.
+    fn migrations() -> Vec<(u32, MigrationFn)> {
+        Vec::new()
+    }
+}
+
+/// Type data for struct types which are serialized with a version number,
+/// and can migrate data from older versions.
+///
+/// When a struct has this type data, [`TypedReflectSerializer`] wraps its serialized fields
+/// with the current version, like `(version: 2, value: (health: 10.0))` in RON.
+///
+/// When [`TypedReflectDeserializer`] reads data with an older version, it runs the migration
+/// step registered for each version in turn, until the data is at the current version.
+/// Versions without a migration step are skipped, which is useful if a version only added
+/// fields that are [skipped] during serialization.
+/// Data with a newer version than the current one fails to deserialize.
+///
+/// Data which was serialized before the type had this type data is treated as version `0`.
+/// Since this data has no version to identify it, this only works with self-describing
+/// formats, such as RON or JSON, and if the struct doesn't start with a field named `version`.
+///
+/// # Old data
+///
+/// Fields in old data are deserialized without type information, since their types may have
+/// changed since the data was serialized. This means they can only be loaded from
+/// self-describing formats, and are represented as:
+/// - `bool`, `i64`, `u64`, `f64`, or `String` for primitives
+/// - `()` for unit and `None` values
+/// - [`DynamicList`] for sequences
+/// - [`DynamicStruct`] for maps and structs
+///
+/// Migration steps are responsible for renaming and restructuring these fields.
+/// Once all steps have run, each field is converted to its current type: numbers are cast to
+/// the field's numeric type, and structs, lists and arrays are converted field by field or
+/// element by element. Fields which can't be converted, such as a number which doesn't fit in
+/// the field's type, fail to deserialize, so migration steps should convert any other changes
+/// themselves.
+///
+/// # Example
+///
+/// ```
+/// # use serde::de::DeserializeSeed;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{DynamicStruct, TypeRegistry};
+/// # use bevy_reflect::serde::{
+/// #     Migrate, MigrationFn, ReflectMigrate, TypedReflectDeserializer, TypedReflectSerializer,
+/// # };
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(Migrate)]
+/// struct Player {
+///     health: f32,
+///     name: String,
+/// }
+///
+/// impl Migrate for Player {
+///     const VERSION: u32 = 1;
+///
+///     fn migrations() -> Vec<(u32, MigrationFn)> {
+///         vec![(0, rename_hp as MigrationFn)]
+///     }
+/// }
+///
+/// // In version 0, `health` was called `hp`
+/// fn rename_hp(old: DynamicStruct) -> DynamicStruct {
+///     let mut new = DynamicStruct::default();
+///     // `hp` is deserialized as an `f64`, and converted to an `f32` after migrating
+///     new.insert_boxed("health", old.field("hp").unwrap().clone_value());
+///     new.insert_boxed("name", old.field("name").unwrap().clone_value());
+///     new
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let player = Player { health: 10.0, name: "Alice".to_string() };
+/// let output = ron::to_string(&TypedReflectSerializer::new(&player, &registry)).unwrap();
+/// assert_eq!(output, r#"(version:1,value:(health:10.0,name:"Alice"))"#);
+///
+/// // Data saved before versioning was added still loads
+/// let input = r#"(hp: 10.0, name: "Alice")"#;
+/// let mut deserializer = ron::Deserializer::from_str(input).unwrap();
+/// let output = TypedReflectDeserializer::of::<Player>(&registry)
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(Player::from_reflect(output.as_partial_reflect()), Some(player));
+/// ```
+///
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [skipped]: crate::serde::SerializationData
+/// [`DynamicList`]: crate::DynamicList
+#[derive(Debug, Clone)]
+pub struct ReflectMigrate {
+    version: u32,
+    migrations: Vec<(u32, MigrationFn)>,
+}
+
+impl ReflectMigrate {
+    /// Creates type data for a struct at the given version, with no migration steps.
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Adds a step which migrates data from `from_version` to the next version.
+    ///
+    /// Steps for the same version are run in the order they were added.
+    pub fn with_migration(mut self, from_version: u32, migration: MigrationFn) -> Self {
+        let index = self
+            .migrations
+            .partition_point(|(version, _)| *version <= from_version);
+        self.migrations.insert(index, (from_version, migration));
+        self
+    }
+
+    /// Returns the current version of the type.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Migrates data from `from_version` to the current version.
+    ///
+    /// Returns `None` if `from_version` is newer than the current version.
+    pub fn migrate(&self, mut value: DynamicStruct, from_version: u32) -> Option<DynamicStruct> {
+        if from_version > self.version {
+            return None;
+        }
+
+        for (version, migration) in &self.migrations {
+            if (from_version..self.version).contains(version) {
+                value = migration(value);
+            }
+        }
+        Some(value)
+    }
+}
+
+impl<T: Migrate> FromType<T> for ReflectMigrate {
+    fn from_type() -> Self {
+        T::migrations().into_iter().fold(
+            ReflectMigrate::new(T::VERSION),
+            |migrate, (from_version, migration)| migrate.with_migration(from_version, migration),
+        )
+    }
+}
@@ -1,17 +1,24 @@
 mod de;
+mod migrate;
+mod schema;
 mod ser;
 mod type_data;
+mod type_manifest;
 
 pub use de::*;
+pub use migrate::*;
+pub use schema::*;
 pub use ser::*;
 pub use type_data::*;
+pub use type_manifest::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        self as bevy_reflect, type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct,
-        FromReflect, PartialReflect, Reflect, Struct,
+        self as bevy_reflect, type_registry::TypeRegistry, DynamicEnum, DynamicList, DynamicMap,
+        DynamicStruct, DynamicTuple, DynamicTupleStruct, FromReflect, PartialReflect, Reflect,
+        ReflectRef, Struct, TypePath,
     };
     use serde::de::DeserializeSeed;
 
@@ -183,13 +190,451 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn should_roundtrip_unproxied_dynamic_as_self_describing() {
+        use alloc::string::String;
+
+        #[derive(Reflect)]
+        struct TestStruct {
+            a: i32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+
+        let mut position = DynamicTuple::default();
+        position.insert(1.5_f32);
+        position.insert(-2.0_f32);
+
+        let mut tags = DynamicList::default();
+        tags.push(1_u8);
+        tags.push(2_u8);
+
+        let mut names = DynamicMap::default();
+        names.insert(7_u32, String::from("seven"));
+
+        let mut value = DynamicStruct::default();
+        value.insert("position", position);
+        value.insert("tags", tags);
+        value.insert("names", names);
+        value.insert("state", DynamicEnum::new("Idle", ()));
+        value.insert("typed", TestStruct { a: 123 });
+
+        let serializer = SelfDescribingReflectSerializer::new(&value, &registry);
+        let result = ron::ser::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&result).unwrap();
+        let reflect_deserializer = SelfDescribingReflectDeserializer::new(&registry);
+        let output = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert!(output.get_represented_type_info().is_none());
+        assert!(value
+            .reflect_partial_eq(output.as_partial_reflect())
+            .unwrap());
+
+        let ReflectRef::Struct(output) = output.reflect_ref() else {
+            panic!("expected a struct");
+        };
+        let typed = output.field("typed").unwrap();
+        assert_eq!(
+            typed.get_represented_type_info().unwrap().type_path(),
+            TestStruct::type_path()
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_map_with_complex_keys() {
+        use crate::{ReflectDeserialize, ReflectSerialize};
+        use alloc::string::String;
+        use bevy_platform_support::collections::HashMap;
+        use core::any::TypeId;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Reflect, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[reflect(
+            Hash,
+            PartialEq,
+            Serialize,
+            Deserialize,
+            SerializeAsString,
+            DeserializeFromString
+        )]
+        struct GridPos {
+            x: i32,
+            y: i32,
+        }
+
+        impl core::fmt::Display for GridPos {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{},{}", self.x, self.y)
+            }
+        }
+
+        impl core::str::FromStr for GridPos {
+            type Err = &'static str;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (x, y) = s.split_once(',').ok_or("missing `,`")?;
+                Ok(GridPos {
+                    x: x.parse().map_err(|_| "invalid x")?,
+                    y: y.parse().map_err(|_| "invalid y")?,
+                })
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Tile(u8);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Grid {
+            tiles: HashMap<GridPos, Tile>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Grid>();
+
+        let grid = Grid {
+            tiles: [(GridPos { x: 1, y: 2 }, Tile(3))].into_iter().collect(),
+        };
+
+        let roundtrip = |registry: &TypeRegistry| -> String {
+            let serializer = TypedReflectSerializer::new(&grid, registry);
+            let json = serde_json::to_string(&serializer).unwrap();
+
+            let registration = registry.get(TypeId::of::<Grid>()).unwrap();
+            let mut deserializer = serde_json::Deserializer::from_str(&json);
+            let value = TypedReflectDeserializer::new(registration, registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(Some(&grid), Grid::from_reflect(&*value).as_ref());
+
+            json
+        };
+
+        // JSON only supports string keys
+        assert!(serde_json::to_string(&TypedReflectSerializer::new(&grid, &registry)).is_err());
+
+        registry.set_map_key_encoding(MapKeyEncoding::Pairs);
+        assert_eq!(r#"{"tiles":[[{"x":1,"y":2},3]]}"#, roundtrip(&registry));
+
+        registry.set_map_key_encoding(MapKeyEncoding::String);
+        assert_eq!(r#"{"tiles":{"1,2":3}}"#, roundtrip(&registry));
+
+        registry.set_map_key_encoding(MapKeyEncoding::Native);
+        registry
+            .get_mut(TypeId::of::<HashMap<GridPos, Tile>>())
+            .unwrap()
+            .insert(MapKeyEncoding::Pairs);
+        assert_eq!(r#"{"tiles":[[{"x":1,"y":2},3]]}"#, roundtrip(&registry));
+    }
+
+    #[test]
+    fn should_pass_through_primitive_map_keys_as_strings() {
+        use alloc::string::String;
+        use bevy_platform_support::collections::HashMap;
+        use core::any::TypeId;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Inventory {
+            names: HashMap<String, u32>,
+            slots: HashMap<u32, String>,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inventory>();
+        registry.set_map_key_encoding(MapKeyEncoding::String);
+
+        let inventory = Inventory {
+            names: [(String::from("sword"), 1)].into_iter().collect(),
+            slots: [(2, String::from("shield"))].into_iter().collect(),
+        };
+
+        let serializer = TypedReflectSerializer::new(&inventory, &registry);
+        let json = serde_json::to_string(&serializer).unwrap();
+        assert_eq!(r#"{"names":{"sword":1},"slots":{"2":"shield"}}"#, json);
+
+        let registration = registry.get(TypeId::of::<Inventory>()).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(inventory), Inventory::from_reflect(&*value));
+    }
+
+    #[test]
+    fn should_roundtrip_with_type_manifest() {
+        use bincode::Options;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct TestStruct {
+            a: i32,
+            b: i32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+        let manifest = TypeManifest::from_registry(&registry);
+        let id = manifest
+            .id("bevy_reflect::serde::tests::TestStruct")
+            .unwrap();
+
+        let value = TestStruct { a: 123, b: 456 };
+
+        let serializer = ReflectSerializer::new(&value, &registry).with_type_manifest(&manifest);
+        let expected = alloc::format!("{{{id}:(a:123,b:456)}}");
+        let result = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(expected, result);
+
+        // The manifest should survive being sent to another process
+        let manifest_bytes = bincode::serialize(&manifest).unwrap();
+        let manifest: TypeManifest = bincode::deserialize(&manifest_bytes).unwrap();
+        assert_eq!(manifest, TypeManifest::from_registry(&registry));
+
+        let serializer = ReflectSerializer::new(&value, &registry).with_type_manifest(&manifest);
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let type_path_bytes =
+            bincode::serialize(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert!(bytes.len() < type_path_bytes.len());
+
+        let reflect_deserializer =
+            ReflectDeserializer::new(&registry).with_type_manifest(&manifest);
+        let result = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+
+        assert_eq!(
+            TestStruct::from_reflect(result.as_partial_reflect()),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn should_roundtrip_large_arrays() {
+        use bincode::Options;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct LookupTable {
+            bytes: [u8; 256],
+            curve: [[f32; 2]; 64],
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<LookupTable>();
+
+        let value = LookupTable {
+            bytes: core::array::from_fn(|i| i as u8),
+            curve: core::array::from_fn(|i| [i as f32, (i * i) as f32]),
+        };
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let result = TypedReflectDeserializer::of::<LookupTable>(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            LookupTable::from_reflect(result.as_partial_reflect()),
+            Some(value)
+        );
+
+        // Non-self-describing formats rely on the length of the array being known
+        let value = LookupTable::from_reflect(result.as_partial_reflect()).unwrap();
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .serialize(&serializer)
+            .unwrap();
+        let result = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                TypedReflectDeserializer::of::<LookupTable>(&registry),
+                &output,
+            )
+            .unwrap();
+        assert_eq!(
+            LookupTable::from_reflect(result.as_partial_reflect()),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn should_migrate_versioned_struct() {
+        use alloc::{string::String, vec, vec::Vec};
+        use bincode::Options;
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Migrate)]
+        struct Player {
+            name: String,
+            health: f32,
+            #[reflect(skip_serializing)]
+            id: u32,
+        }
+
+        impl Migrate for Player {
+            const VERSION: u32 = 2;
+
+            fn migrations() -> Vec<(u32, MigrationFn)> {
+                vec![(1, rename_hp as MigrationFn), (0, add_name as MigrationFn)]
+            }
+        }
+
+        // Version 0 didn't have a name
+        fn add_name(mut old: DynamicStruct) -> DynamicStruct {
+            old.insert("name", String::from("Player"));
+            old
+        }
+
+        // Version 1 called `health` `hp`
+        fn rename_hp(old: DynamicStruct) -> DynamicStruct {
+            let hp = old.field("hp").unwrap().try_downcast_ref::<f64>().unwrap();
+            let mut new = DynamicStruct::default();
+            new.insert_boxed("name", old.field("name").unwrap().clone_value());
+            new.insert("health", *hp as f32);
+            new
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+
+        let deserialize = |input: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            TypedReflectDeserializer::of::<Player>(&registry)
+                .deserialize(&mut deserializer)
+                .map(|value| Player::from_reflect(value.as_partial_reflect()).unwrap())
+        };
+
+        let player = Player {
+            name: String::from("Alice"),
+            health: 10.0,
+            id: 0,
+        };
+        let output = ron::ser::to_string(&TypedReflectSerializer::new(&player, &registry)).unwrap();
+        assert_eq!(output, r#"(version:2,value:(name:"Alice",health:10.0))"#);
+        assert_eq!(deserialize(&output).unwrap(), player);
+
+        let unnamed_player = Player {
+            name: String::from("Player"),
+            health: 10.0,
+            id: 0,
+        };
+        assert_eq!(
+            deserialize(r#"(version: 1, value: (name: "Alice", hp: 10.0))"#).unwrap(),
+            player
+        );
+        assert_eq!(
+            deserialize("(version: 0, value: (hp: 10.0))").unwrap(),
+            unnamed_player
+        );
+        // Data without a version is treated as version 0
+        assert_eq!(deserialize("(hp: 10.0)").unwrap(), unnamed_player);
+        assert!(deserialize("(version: 3, value: (hp: 10.0))").is_err());
+
+        let bytes = bincode::serialize(&TypedReflectSerializer::new(&player, &registry)).unwrap();
+        let result = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(TypedReflectDeserializer::of::<Player>(&registry), &bytes)
+            .unwrap();
+        assert_eq!(
+            Player::from_reflect(result.as_partial_reflect()),
+            Some(player)
+        );
+    }
+
+    #[test]
+    fn should_convert_field_types_after_migration() {
+        use alloc::{string::String, vec, vec::Vec};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Position {
+            x: f32,
+            y: f32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Migrate)]
+        struct Stats {
+            position: Position,
+            scores: Vec<u8>,
+            level: u16,
+        }
+
+        impl Migrate for Stats {
+            const VERSION: u32 = 1;
+
+            fn migrations() -> Vec<(u32, MigrationFn)> {
+                vec![(0, parse_level as MigrationFn)]
+            }
+        }
+
+        // Version 0 stored `level` as a string
+        fn parse_level(mut old: DynamicStruct) -> DynamicStruct {
+            let level = old.field("level").unwrap().try_downcast_ref::<String>();
+            let level = level.unwrap().parse::<u64>().unwrap();
+            old.insert("level", level);
+            old
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Stats>();
+
+        let deserialize = |input: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            TypedReflectDeserializer::of::<Stats>(&registry)
+                .deserialize(&mut deserializer)
+                .map(|value| Stats::from_reflect(value.as_partial_reflect()).unwrap())
+        };
+
+        assert_eq!(
+            deserialize(r#"(position: (x: 1, y: 2), scores: [3, 4], level: "5")"#).unwrap(),
+            Stats {
+                position: Position { x: 1.0, y: 2.0 },
+                scores: vec![3, 4],
+                level: 5,
+            }
+        );
+        // Values which don't fit in the current type fail to deserialize
+        assert!(deserialize(r#"(position: (x: 1, y: 2), scores: [300], level: "5")"#).is_err());
+        assert!(deserialize(r#"(position: (x: 1, y: 2), scores: [], level: "70000")"#).is_err());
+        assert!(deserialize(r#"(position: (x: 1.5, y: 2), scores: [1.5], level: "5")"#).is_err());
+    }
+
+    #[test]
+    fn should_not_serialize_type_missing_from_manifest() {
+        #[derive(Reflect)]
+        struct TestStruct {
+            a: i32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+        let manifest = TypeManifest::from_type_paths(["i32"]);
+
+        let value = TestStruct { a: 123 };
+        let serializer = ReflectSerializer::new(&value, &registry).with_type_manifest(&manifest);
+        let error = ron::ser::to_string(&serializer).unwrap_err();
+
+        let ron::Error::Message(message) = error else {
+            panic!("expected custom error message, got {error:?}");
+        };
+        assert!(message.starts_with(
+            "type `bevy_reflect::serde::tests::TestStruct` is not in the type manifest"
+        ));
+    }
+
     mod type_data {
         use super::*;
         use crate::from_reflect::FromReflect;
         use crate::serde::{DeserializeWithRegistry, ReflectDeserializeWithRegistry};
         use crate::serde::{ReflectSerializeWithRegistry, SerializeWithRegistry};
         use crate::{ReflectFromReflect, TypePath};
-        use alloc::{format, string::String, vec, vec::Vec};
+        use alloc::{
+            format,
+            string::{String, ToString},
+            vec,
+            vec::Vec,
+        };
         use bevy_platform_support::sync::Arc;
         use bevy_reflect_derive::reflect_trait;
         use core::any::TypeId;
@@ -246,8 +691,8 @@ mod tests {
 
         #[derive(Reflect, Debug)]
         #[reflect(SerializeWithRegistry, DeserializeWithRegistry)]
-        // Note that we have to use `Arc` instead of `Box` here due to the
-        // former being the only one between the two to implement `Reflect`.
+        // Note that we use `Arc` instead of `Box` here since the latter only
+        // implements `Reflect` when `dyn Enemy` implements `ReflectTraitObject`.
         struct EnemyList(Vec<Arc<dyn Enemy>>);
 
         impl SerializeWithRegistry for EnemyList {
@@ -417,6 +862,61 @@ mod tests {
             assert_ne!(format!("{:?}", unexpected), format!("{:?}", output));
         }
 
+        #[test]
+        fn should_roundtrip_opaque_type_as_string() {
+            #[derive(Reflect, Clone, Debug, PartialEq)]
+            #[reflect(opaque, Debug, PartialEq, SerializeAsString, DeserializeFromString)]
+            struct ThirdPartyId(u64);
+
+            impl core::fmt::Display for ThirdPartyId {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    write!(f, "id-{}", self.0)
+                }
+            }
+
+            impl core::str::FromStr for ThirdPartyId {
+                type Err = String;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.strip_prefix("id-")
+                        .and_then(|id| id.parse().ok())
+                        .map(ThirdPartyId)
+                        .ok_or_else(|| format!("invalid id `{s}`"))
+                }
+            }
+
+            #[derive(Reflect, Debug, PartialEq)]
+            struct Player {
+                id: ThirdPartyId,
+            }
+
+            let mut registry = TypeRegistry::default();
+            registry.register::<ThirdPartyId>();
+            registry.register::<Player>();
+
+            let player = Player {
+                id: ThirdPartyId(42),
+            };
+
+            let serializer = ReflectSerializer::new(&player, &registry);
+            let serialized = ron::ser::to_string(&serializer).unwrap();
+            let expected = r#"{"bevy_reflect::serde::tests::type_data::Player":(id:"id-42")}"#;
+            assert_eq!(expected, serialized);
+
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            let value = ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(player, Player::from_reflect(&*value).unwrap());
+
+            let input = r#"{"bevy_reflect::serde::tests::type_data::Player":(id:"42")}"#;
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let error = ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap_err();
+            assert!(error.to_string().contains("invalid id `42`"));
+        }
+
         #[test]
         fn should_serialize_single_tuple_struct_as_newtype() {
             #[derive(Reflect, Serialize, PartialEq, Debug)]
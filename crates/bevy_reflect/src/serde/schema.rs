@@ -0,0 +1,754 @@
+use crate::{
+    serde::{
+        MapKeyEncoding, ReflectSerializeAsString, ReflectSerializeWithRegistry, SerializationData,
+        TypedReflectSerializer,
+    },
+    std_traits::ReflectDefault,
+    NamedField, Reflect, ReflectSerialize, Type, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
+};
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeMap, format, string::String, vec::Vec};
+use core::any::TypeId;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+/// The [JSON Schema] dialect used by [`ReflectSchema`].
+///
+/// [JSON Schema]: https://json-schema.org
+pub const REFLECT_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports a [JSON Schema] describing the serialized form of the type with the given [`TypeId`].
+///
+/// The schema describes the output of [`TypedReflectSerializer`] (and the input of
+/// [`TypedReflectDeserializer`]) when used with a self-describing format such as JSON.
+/// Every registered type reachable from the given type gets its own entry in the schema's
+/// `$defs`, keyed by its [type path], so recursive types are supported.
+///
+/// The schema includes:
+/// - the fields of structs, tuples and tuple structs,
+///   except for fields which are skipped using [`SerializationData`],
+///   as they are never serialized and are filled in with their default value instead,
+/// - every variant of enums, in serde's externally tagged representation,
+/// - the serialized default value of types which register [`ReflectDefault`],
+/// - doc comments of types, fields and variants, when the `documentation` feature is enabled.
+///
+/// Types which use a custom serialization, through [`ReflectSerialize`] or
+/// [`ReflectSerializeWithRegistry`], cannot be described beyond their documentation,
+/// except for primitives and strings. Neither can types which are not registered.
+/// Types which register [`ReflectSerializeAsString`] are described as strings.
+///
+/// Returns `None` if the type is not registered in the given [`TypeRegistry`].
+///
+/// # Example
+///
+/// ```
+/// # use core::any::TypeId;
+/// # use bevy_reflect::{Reflect, TypePath, TypeRegistry, serde::export_schema};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let schema = export_schema(&registry, TypeId::of::<Player>()).unwrap();
+/// let json = serde_json::to_value(&schema).unwrap();
+///
+/// assert_eq!(json["$ref"], format!("#/$defs/{}", Player::type_path()));
+/// assert_eq!(
+///     json["$defs"][Player::type_path()]["properties"]["health"],
+///     serde_json::json!({ "type": "integer", "minimum": 0 })
+/// );
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+/// [type path]: crate::TypePath
+pub fn export_schema(registry: &TypeRegistry, type_id: TypeId) -> Option<ReflectSchema<'_>> {
+    let registration = registry.get(type_id)?;
+    let mut builder = SchemaBuilder {
+        registry,
+        definitions: BTreeMap::new(),
+    };
+    builder.define(registration);
+
+    Some(ReflectSchema {
+        registry,
+        root: registration.type_info().type_path(),
+        definitions: builder.definitions,
+    })
+}
+
+/// A [JSON Schema] describing the serialized form of a reflected type.
+///
+/// This is created using [`export_schema`], and is turned into a schema document by
+/// serializing it, for example with `serde_json`.
+///
+/// [JSON Schema]: https://json-schema.org
+pub struct ReflectSchema<'a> {
+    registry: &'a TypeRegistry,
+    root: &'static str,
+    definitions: BTreeMap<&'static str, Schema>,
+}
+
+impl ReflectSchema<'_> {
+    /// The [type path] of the type described by this schema.
+    ///
+    /// [type path]: crate::TypePath
+    pub fn root_type_path(&self) -> &'static str {
+        self.root
+    }
+
+    /// Returns an iterator over the [type paths] of all types defined in this schema,
+    /// including the root type.
+    ///
+    /// [type paths]: crate::TypePath
+    pub fn type_paths(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.definitions.keys().copied()
+    }
+}
+
+impl Serialize for ReflectSchema<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry("$schema", REFLECT_SCHEMA_DIALECT)?;
+        state.serialize_entry("$ref", &definition_ref(self.root))?;
+        state.serialize_entry(
+            "$defs",
+            &DefinitionsSerializer {
+                definitions: &self.definitions,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+fn definition_ref(type_path: &str) -> String {
+    format!("#/$defs/{type_path}")
+}
+
+/// A single schema, describing a value or a reference to a definition.
+#[derive(Default)]
+struct Schema {
+    kind: SchemaKind,
+    description: Option<Cow<'static, str>>,
+    default: Option<Box<dyn Reflect>>,
+}
+
+impl From<SchemaKind> for Schema {
+    fn from(kind: SchemaKind) -> Self {
+        Self {
+            kind,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Default)]
+enum SchemaKind {
+    /// Any value, used when the serialized form is unknown.
+    #[default]
+    Any,
+    Null,
+    Boolean,
+    Integer {
+        unsigned: bool,
+    },
+    Number,
+    String {
+        len: Option<usize>,
+    },
+    Ref(&'static str),
+    Array {
+        items: Box<Schema>,
+        len: Option<usize>,
+        unique: bool,
+    },
+    Tuple(Vec<Schema>),
+    /// An object with the given required properties, and either no other properties or,
+    /// if `additional_properties` is set, any others matching it.
+    Object {
+        properties: Vec<(&'static str, Schema)>,
+        additional_properties: Option<Box<Schema>>,
+    },
+    OneOf(Vec<Schema>),
+    Const(&'static str),
+}
+
+struct SchemaBuilder<'a> {
+    registry: &'a TypeRegistry,
+    definitions: BTreeMap<&'static str, Schema>,
+}
+
+impl SchemaBuilder<'_> {
+    /// Returns the schema of a value of the given type, defining the type if needed.
+    fn reference(&mut self, ty: &Type) -> Schema {
+        if let Some(kind) = primitive_schema(ty.id()) {
+            return kind.into();
+        }
+
+        match self.registry.get(ty.id()) {
+            Some(registration) => {
+                self.define(registration);
+                SchemaKind::Ref(ty.path()).into()
+            }
+            None => Schema {
+                description: Some(format!("unregistered type `{}`", ty.path()).into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Adds the definition of the given type, if it has not already been added.
+    fn define(&mut self, registration: &TypeRegistration) {
+        let type_info = registration.type_info();
+        let type_path = type_info.type_path();
+        if self.definitions.contains_key(type_path) {
+            return;
+        }
+        // Insert a placeholder first, in case the type refers to itself
+        self.definitions.insert(type_path, Schema::default());
+
+        let mut schema = self.describe(registration);
+        schema.default = registration
+            .data::<ReflectDefault>()
+            .map(ReflectDefault::default);
+        #[cfg(feature = "documentation")]
+        let schema = with_docs(schema, type_info.docs());
+        self.definitions.insert(type_path, schema);
+    }
+
+    fn describe(&mut self, registration: &TypeRegistration) -> Schema {
+        if let Some(kind) = primitive_schema(registration.type_id()) {
+            return kind.into();
+        }
+
+        if registration.data::<ReflectSerialize>().is_some()
+            || registration
+                .data::<ReflectSerializeWithRegistry>()
+                .is_some()
+        {
+            return Schema {
+                description: Some("custom serialization".into()),
+                ..Default::default()
+            };
+        }
+
+        if registration.data::<ReflectSerializeAsString>().is_some() {
+            return SchemaKind::String { len: None }.into();
+        }
+
+        let serialization_data = registration.data::<SerializationData>();
+        let is_serialized =
+            |index: usize| !serialization_data.is_some_and(|data| data.is_field_skipped(index));
+
+        match registration.type_info() {
+            TypeInfo::Struct(info) => {
+                let fields = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| is_serialized(*index))
+                    .map(|(_, field)| field)
+                    .collect::<Vec<_>>();
+                self.named_fields(fields)
+            }
+            TypeInfo::TupleStruct(info) => {
+                if info.field_len() == 1 && serialization_data.is_none() {
+                    return self.unnamed_field(info.field_at(0).unwrap());
+                }
+                let fields = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| is_serialized(*index))
+                    .map(|(_, field)| field)
+                    .collect::<Vec<_>>();
+                self.unnamed_fields(fields)
+            }
+            TypeInfo::Tuple(info) => self.unnamed_fields(info.iter().collect()),
+            TypeInfo::List(info) => SchemaKind::Array {
+                items: Box::new(self.reference(&info.item_ty())),
+                len: None,
+                unique: false,
+            }
+            .into(),
+            TypeInfo::Array(info) => SchemaKind::Array {
+                items: Box::new(self.reference(&info.item_ty())),
+                len: Some(info.capacity()),
+                unique: false,
+            }
+            .into(),
+            TypeInfo::Set(info) => SchemaKind::Array {
+                items: Box::new(self.reference(&info.value_ty())),
+                len: None,
+                unique: true,
+            }
+            .into(),
+            TypeInfo::Map(info) => {
+                if self.registry.map_key_encoding_of(registration.type_id())
+                    == MapKeyEncoding::Pairs
+                {
+                    let entry = SchemaKind::Tuple(alloc::vec![
+                        self.reference(&info.key_ty()),
+                        self.reference(&info.value_ty()),
+                    ]);
+                    SchemaKind::Array {
+                        items: Box::new(entry.into()),
+                        len: None,
+                        unique: false,
+                    }
+                    .into()
+                } else {
+                    SchemaKind::Object {
+                        properties: Vec::new(),
+                        additional_properties: Some(Box::new(self.reference(&info.value_ty()))),
+                    }
+                    .into()
+                }
+            }
+            TypeInfo::Enum(info) => {
+                if info.type_path_table().module_path() == Some("core::option")
+                    && info.type_path_table().ident() == Some("Option")
+                {
+                    let some = match info.variant("Some") {
+                        Some(VariantInfo::Tuple(variant)) => {
+                            self.unnamed_field(variant.field_at(0).unwrap())
+                        }
+                        _ => Schema::default(),
+                    };
+                    return SchemaKind::OneOf(alloc::vec![SchemaKind::Null.into(), some]).into();
+                }
+
+                let variants = info.iter().map(|variant| self.variant(variant)).collect();
+                SchemaKind::OneOf(variants).into()
+            }
+            TypeInfo::Opaque(_) => Schema {
+                description: Some("opaque type without serialization".into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn variant(&mut self, variant: &VariantInfo) -> Schema {
+        let schema = match variant {
+            VariantInfo::Unit(variant) => SchemaKind::Const(variant.name()).into(),
+            VariantInfo::Tuple(variant) if variant.field_len() == 1 => {
+                let value = self.unnamed_field(variant.field_at(0).unwrap());
+                tagged_variant(variant.name(), value)
+            }
+            VariantInfo::Tuple(variant) => {
+                let value = self.unnamed_fields(variant.iter().collect());
+                tagged_variant(variant.name(), value)
+            }
+            VariantInfo::Struct(variant) => {
+                let value = self.named_fields(variant.iter().collect());
+                tagged_variant(variant.name(), value)
+            }
+        };
+
+        #[cfg(feature = "documentation")]
+        return with_docs(schema, variant.docs());
+        #[cfg(not(feature = "documentation"))]
+        schema
+    }
+
+    fn named_fields(&mut self, fields: Vec<&NamedField>) -> Schema {
+        let properties = fields
+            .into_iter()
+            .map(|field| {
+                let schema = self.reference(field.ty());
+                #[cfg(feature = "documentation")]
+                let schema = with_docs(schema, field.docs());
+                (field.name(), schema)
+            })
+            .collect();
+
+        SchemaKind::Object {
+            properties,
+            additional_properties: None,
+        }
+        .into()
+    }
+
+    fn unnamed_fields(&mut self, fields: Vec<&UnnamedField>) -> Schema {
+        let items = fields
+            .into_iter()
+            .map(|field| self.unnamed_field(field))
+            .collect();
+        SchemaKind::Tuple(items).into()
+    }
+
+    fn unnamed_field(&mut self, field: &UnnamedField) -> Schema {
+        let schema = self.reference(field.ty());
+        #[cfg(feature = "documentation")]
+        return with_docs(schema, field.docs());
+        #[cfg(not(feature = "documentation"))]
+        schema
+    }
+}
+
+/// Returns the schema of an enum variant with fields, which is serialized as an object with a
+/// single property named after the variant.
+fn tagged_variant(name: &'static str, value: Schema) -> Schema {
+    SchemaKind::Object {
+        properties: alloc::vec![(name, value)],
+        additional_properties: None,
+    }
+    .into()
+}
+
+/// Sets the description of a schema to the given doc comment, if any.
+#[cfg(feature = "documentation")]
+fn with_docs(mut schema: Schema, docs: Option<&str>) -> Schema {
+    if let Some(docs) = docs {
+        schema.description = Some(String::from(docs.trim()).into());
+    }
+    schema
+}
+
+/// Returns the schema of primitive types, whose serialized form is known even though
+/// they use [`ReflectSerialize`].
+fn primitive_schema(type_id: TypeId) -> Option<SchemaKind> {
+    macro_rules! match_types {
+        ($($ty:ty),* => $kind:expr) => {
+            if $(type_id == TypeId::of::<$ty>())||* {
+                return Some($kind);
+            }
+        };
+    }
+
+    match_types!(bool => SchemaKind::Boolean);
+    match_types!(i8, i16, i32, i64, i128, isize => SchemaKind::Integer { unsigned: false });
+    match_types!(u8, u16, u32, u64, u128, usize => SchemaKind::Integer { unsigned: true });
+    match_types!(f32, f64 => SchemaKind::Number);
+    match_types!(String, &'static str, Cow<'static, str> => SchemaKind::String { len: None });
+    match_types!(char => SchemaKind::String { len: Some(1) });
+    None
+}
+
+struct SchemaSerializer<'a> {
+    schema: &'a Schema,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> SchemaSerializer<'a> {
+    fn new(schema: &'a Schema, registry: &'a TypeRegistry) -> Self {
+        Self { schema, registry }
+    }
+}
+
+impl Serialize for SchemaSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        let mut state = serializer.serialize_map(None)?;
+        match &self.schema.kind {
+            SchemaKind::Any => {}
+            SchemaKind::Null => state.serialize_entry("type", "null")?,
+            SchemaKind::Boolean => state.serialize_entry("type", "boolean")?,
+            SchemaKind::Integer { unsigned } => {
+                state.serialize_entry("type", "integer")?;
+                if *unsigned {
+                    state.serialize_entry("minimum", &0)?;
+                }
+            }
+            SchemaKind::Number => state.serialize_entry("type", "number")?,
+            SchemaKind::String { len } => {
+                state.serialize_entry("type", "string")?;
+                if let Some(len) = len {
+                    state.serialize_entry("minLength", len)?;
+                    state.serialize_entry("maxLength", len)?;
+                }
+            }
+            SchemaKind::Ref(type_path) => {
+                state.serialize_entry("$ref", &definition_ref(type_path))?;
+            }
+            SchemaKind::Array { items, len, unique } => {
+                state.serialize_entry("type", "array")?;
+                state.serialize_entry("items", &SchemaSerializer::new(items, registry))?;
+                if let Some(len) = len {
+                    state.serialize_entry("minItems", len)?;
+                    state.serialize_entry("maxItems", len)?;
+                }
+                if *unique {
+                    state.serialize_entry("uniqueItems", &true)?;
+                }
+            }
+            SchemaKind::Tuple(items) => {
+                state.serialize_entry("type", "array")?;
+                state.serialize_entry(
+                    "prefixItems",
+                    &SchemaListSerializer {
+                        schemas: items,
+                        registry,
+                    },
+                )?;
+                state.serialize_entry("minItems", &items.len())?;
+                state.serialize_entry("maxItems", &items.len())?;
+            }
+            SchemaKind::Object {
+                properties,
+                additional_properties,
+            } => {
+                state.serialize_entry("type", "object")?;
+                if !properties.is_empty() {
+                    state.serialize_entry(
+                        "properties",
+                        &PropertiesSerializer {
+                            properties,
+                            registry,
+                        },
+                    )?;
+                    let required = properties.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+                    state.serialize_entry("required", &required)?;
+                }
+                match additional_properties {
+                    Some(schema) => state.serialize_entry(
+                        "additionalProperties",
+                        &SchemaSerializer::new(schema, registry),
+                    )?,
+                    None => state.serialize_entry("additionalProperties", &false)?,
+                }
+            }
+            SchemaKind::OneOf(schemas) => {
+                state.serialize_entry("oneOf", &SchemaListSerializer { schemas, registry })?;
+            }
+            SchemaKind::Const(value) => state.serialize_entry("const", value)?,
+        }
+
+        if let Some(description) = &self.schema.description {
+            state.serialize_entry("description", description)?;
+        }
+        if let Some(default) = &self.schema.default {
+            state.serialize_entry(
+                "default",
+                &TypedReflectSerializer::new(default.as_partial_reflect(), registry),
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct SchemaListSerializer<'a> {
+    schemas: &'a [Schema],
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for SchemaListSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(
+            self.schemas
+                .iter()
+                .map(|schema| SchemaSerializer::new(schema, self.registry)),
+        )
+    }
+}
+
+struct PropertiesSerializer<'a> {
+    properties: &'a [(&'static str, Schema)],
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for PropertiesSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(
+            self.properties
+                .iter()
+                .map(|(name, schema)| (name, SchemaSerializer::new(schema, self.registry))),
+        )
+    }
+}
+
+struct DefinitionsSerializer<'a> {
+    definitions: &'a BTreeMap<&'static str, Schema>,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for DefinitionsSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(
+            self.definitions.iter().map(|(type_path, schema)| {
+                (type_path, SchemaSerializer::new(schema, self.registry))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_reflect, prelude::ReflectDefault, TypePath};
+    use alloc::{string::String, vec::Vec};
+    use serde_json::json;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Settings {
+        name: String,
+        volume: f32,
+        tags: Vec<String>,
+        mode: Option<Mode>,
+        #[reflect(skip_serializing)]
+        cache: u32,
+    }
+
+    #[derive(Reflect, Default)]
+    enum Mode {
+        #[default]
+        Off,
+        Level(u8),
+        Range(u8, u8),
+        Custom {
+            gain: f32,
+        },
+    }
+
+    #[derive(Reflect)]
+    #[reflect(no_field_bounds)]
+    struct Node {
+        children: Vec<Node>,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        registry
+    }
+
+    #[test]
+    fn should_export_struct_schema() {
+        let registry = get_registry();
+        let schema = export_schema(&registry, TypeId::of::<Settings>()).unwrap();
+        let json = serde_json::to_value(&schema).unwrap();
+
+        assert_eq!(json["$schema"], REFLECT_SCHEMA_DIALECT);
+        assert_eq!(
+            json["$ref"],
+            format!("#/$defs/{}", Settings::type_path()).as_str()
+        );
+        assert_eq!(
+            json["$defs"][Settings::type_path()],
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "volume": { "type": "number" },
+                    "tags": { "$ref": format!("#/$defs/{}", Vec::<String>::type_path()) },
+                    "mode": { "$ref": format!("#/$defs/{}", Option::<Mode>::type_path()) },
+                },
+                "required": ["name", "volume", "tags", "mode"],
+                "additionalProperties": false,
+                "default": {
+                    "name": "",
+                    "volume": 0.0,
+                    "tags": [],
+                    "mode": null,
+                },
+            })
+        );
+        assert_eq!(
+            json["$defs"][Vec::<String>::type_path()],
+            json!({ "type": "array", "items": { "type": "string" } })
+        );
+    }
+
+    #[test]
+    fn should_export_enum_schema() {
+        let registry = get_registry();
+        let schema = export_schema(&registry, TypeId::of::<Settings>()).unwrap();
+        let json = serde_json::to_value(&schema).unwrap();
+
+        assert_eq!(
+            json["$defs"][Option::<Mode>::type_path()],
+            json!({
+                "oneOf": [
+                    { "type": "null" },
+                    { "$ref": format!("#/$defs/{}", Mode::type_path()) },
+                ],
+            })
+        );
+        assert_eq!(
+            json["$defs"][Mode::type_path()],
+            json!({
+                "oneOf": [
+                    { "const": "Off" },
+                    {
+                        "type": "object",
+                        "properties": { "Level": { "type": "integer", "minimum": 0 } },
+                        "required": ["Level"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Range": {
+                                "type": "array",
+                                "prefixItems": [
+                                    { "type": "integer", "minimum": 0 },
+                                    { "type": "integer", "minimum": 0 },
+                                ],
+                                "minItems": 2,
+                                "maxItems": 2,
+                            },
+                        },
+                        "required": ["Range"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Custom": {
+                                "type": "object",
+                                "properties": { "gain": { "type": "number" } },
+                                "required": ["gain"],
+                                "additionalProperties": false,
+                            },
+                        },
+                        "required": ["Custom"],
+                        "additionalProperties": false,
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn should_export_recursive_schema() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Node>();
+
+        let schema = export_schema(&registry, TypeId::of::<Node>()).unwrap();
+        let mut type_paths = schema.type_paths().collect::<Vec<_>>();
+        type_paths.sort();
+        let mut expected = alloc::vec![Node::type_path(), Vec::<Node>::type_path()];
+        expected.sort();
+        assert_eq!(type_paths, expected);
+
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            json["$defs"][Vec::<Node>::type_path()]["items"],
+            json!({ "$ref": format!("#/$defs/{}", Node::type_path()) })
+        );
+    }
+
+    #[test]
+    fn should_not_export_unregistered_type() {
+        let registry = TypeRegistry::default();
+        assert!(export_schema(&registry, TypeId::of::<Node>()).is_none());
+    }
+}
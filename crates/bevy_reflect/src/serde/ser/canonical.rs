@@ -0,0 +1,145 @@
+//! Helpers for producing canonical output, see [`TypedReflectSerializer::with_canonical`].
+//!
+//! [`TypedReflectSerializer::with_canonical`]: crate::serde::TypedReflectSerializer::with_canonical
+
+use crate::{PartialReflect, ReflectRef};
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+use core::cmp::Ordering;
+
+/// Returns `value` with negative zero and NaN normalized.
+pub(super) fn canonical_f32(value: f32) -> f32 {
+    if value.is_nan() {
+        f32::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+/// Returns `value` with negative zero and NaN normalized.
+pub(super) fn canonical_f64(value: f64) -> f64 {
+    if value.is_nan() {
+        f64::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+/// Sorts `values` into a stable order, which only depends on the values themselves.
+pub(super) fn sort_canonically<T>(values: &mut [T], key: impl Fn(&T) -> &dyn PartialReflect) {
+    values.sort_by(|a, b| canonical_cmp(key(a), key(b)));
+}
+
+/// Compares two reflected values, to give the entries of maps and sets a stable order.
+///
+/// Primitives are compared by value, and other values are compared field by field.
+/// This is a total order, but isn't necessarily consistent with [`PartialOrd`].
+fn canonical_cmp(a: &dyn PartialReflect, b: &dyn PartialReflect) -> Ordering {
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => cmp_all(a.iter_fields(), b.iter_fields()),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            cmp_all(a.iter_fields(), b.iter_fields())
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => cmp_all(a.iter_fields(), b.iter_fields()),
+        (ReflectRef::List(a), ReflectRef::List(b)) => cmp_all(a.iter(), b.iter()),
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => cmp_all(a.iter(), b.iter()),
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => {
+            let mut a = a.iter().collect::<Vec<_>>();
+            let mut b = b.iter().collect::<Vec<_>>();
+            sort_canonically(&mut a, |(key, _)| *key);
+            sort_canonically(&mut b, |(key, _)| *key);
+            cmp_all(
+                a.into_iter().flat_map(|(key, value)| [key, value]),
+                b.into_iter().flat_map(|(key, value)| [key, value]),
+            )
+        }
+        (ReflectRef::Set(a), ReflectRef::Set(b)) => {
+            let mut a = a.iter().collect::<Vec<_>>();
+            let mut b = b.iter().collect::<Vec<_>>();
+            sort_canonically(&mut a, |value| *value);
+            sort_canonically(&mut b, |value| *value);
+            cmp_all(a.into_iter(), b.into_iter())
+        }
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => {
+            a.variant_index().cmp(&b.variant_index()).then_with(|| {
+                cmp_all(
+                    a.iter_fields().map(|field| field.value()),
+                    b.iter_fields().map(|field| field.value()),
+                )
+            })
+        }
+        (ReflectRef::Opaque(_), ReflectRef::Opaque(_)) => cmp_opaque(a, b),
+        // Values of different kinds can only be compared by their types.
+        _ => a.reflect_type_path().cmp(b.reflect_type_path()),
+    }
+}
+
+/// Compares two sequences of values lexicographically.
+fn cmp_all<'a>(
+    a: impl Iterator<Item = &'a dyn PartialReflect>,
+    b: impl Iterator<Item = &'a dyn PartialReflect>,
+) -> Ordering {
+    let mut b = b.fuse();
+    for a in a {
+        let Some(b) = b.next() else {
+            return Ordering::Greater;
+        };
+        match canonical_cmp(a, b) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    if b.next().is_some() {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Compares two opaque values, by value if they're primitives of the same type,
+/// and by their type and [`Debug`] output otherwise.
+fn cmp_opaque(a: &dyn PartialReflect, b: &dyn PartialReflect) -> Ordering {
+    macro_rules! cmp_as {
+        ($($ty:ty),*) => {
+            $(
+                if let (Some(a), Some(b)) = (a.try_downcast_ref::<$ty>(), b.try_downcast_ref::<$ty>()) {
+                    return a.cmp(b);
+                }
+            )*
+        };
+    }
+
+    cmp_as!(
+        bool,
+        char,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize,
+        String,
+        &'static str,
+        Cow<'static, str>
+    );
+
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<f32>(), b.try_downcast_ref::<f32>()) {
+        return canonical_f32(*a).total_cmp(&canonical_f32(*b));
+    }
+    if let (Some(a), Some(b)) = (a.try_downcast_ref::<f64>(), b.try_downcast_ref::<f64>()) {
+        return canonical_f64(*a).total_cmp(&canonical_f64(*b));
+    }
+
+    a.reflect_type_path()
+        .cmp(b.reflect_type_path())
+        .then_with(|| format!("{a:?}").cmp(&format!("{b:?}")))
+}
@@ -1,13 +1,13 @@
 use crate::serde::ser::error_utils::make_custom_error;
 #[cfg(feature = "debug_stack")]
 use crate::serde::ser::error_utils::TYPE_INFO_STACK;
-use crate::serde::ReflectSerializeWithRegistry;
+use crate::serde::{ReflectSerializeAsString, ReflectSerializeWithRegistry};
 use crate::{PartialReflect, ReflectSerialize, TypeRegistry};
 use core::borrow::Borrow;
 use serde::{Serialize, Serializer};
 
-/// Attempts to serialize a [`PartialReflect`] value with custom [`ReflectSerialize`]
-/// or [`ReflectSerializeWithRegistry`] type data.
+/// Attempts to serialize a [`PartialReflect`] value with custom [`ReflectSerialize`],
+/// [`ReflectSerializeWithRegistry`] or [`ReflectSerializeAsString`] type data.
 ///
 /// On success, returns the result of the serialization.
 /// On failure, returns the original serializer and the error that occurred.
@@ -53,9 +53,16 @@ pub(super) fn try_custom_serialize<S: Serializer>(
         TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
 
         Ok(reflect_serialize_with_registry.serialize(value, serializer, type_registry))
+    } else if let Some(reflect_serialize_as_string) =
+        registration.data::<ReflectSerializeAsString>()
+    {
+        #[cfg(feature = "debug_stack")]
+        TYPE_INFO_STACK.with_borrow_mut(crate::type_info_stack::TypeInfoStack::pop);
+
+        Ok(reflect_serialize_as_string.serialize(value, serializer))
     } else {
         Err((serializer, make_custom_error(format_args!(
-            "type `{}` did not register the `ReflectSerialize` or `ReflectSerializeWithRegistry` type data. For certain types, this may need to be registered manually using `register_type_data`",
+            "type `{}` did not register the `ReflectSerialize`, `ReflectSerializeWithRegistry` or `ReflectSerializeAsString` type data. For certain types, this may need to be registered manually using `register_type_data`",
             info.type_path(),
         ))))
     }
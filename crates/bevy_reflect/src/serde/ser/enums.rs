@@ -14,6 +14,7 @@ pub(super) struct EnumSerializer<'a, P> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub canonical: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
@@ -77,14 +78,29 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                     field_len,
                 )?;
                 for (index, field) in self.enum_value.iter_fields().enumerate() {
-                    let field_info = struct_info.field_at(index).unwrap();
+                    let (field_info, value) = if self.canonical {
+                        // Follow the declaration order, even if a dynamic variant's fields were
+                        // inserted in a different order.
+                        let field_info = struct_info.field_at(index).unwrap();
+                        let value = self.enum_value.field(field_info.name()).ok_or_else(|| {
+                            make_custom_error::<S::Error>(format_args!(
+                                "missing field `{}` of variant `{variant_name}`",
+                                field_info.name()
+                            ))
+                        })?;
+                        (field_info, value)
+                    } else {
+                        (struct_info.field_at(index).unwrap(), field.value())
+                    };
                     state.serialize_field(
                         field_info.name(),
                         &TypedReflectSerializer::new_internal(
-                            field.value(),
+                            value,
                             self.registry,
                             self.processor,
-                        ),
+                            self.canonical,
+                        )
+                        .with_field(type_info, field_info),
                     )?;
                 }
                 state.end()
@@ -99,13 +115,19 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         field,
                         self.registry,
                         self.processor,
+                        self.canonical,
                     ))
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new_internal(field, self.registry, self.processor),
+                        &TypedReflectSerializer::new_internal(
+                            field,
+                            self.registry,
+                            self.processor,
+                            self.canonical,
+                        ),
                     )
                 }
             }
@@ -121,6 +143,7 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         field.value(),
                         self.registry,
                         self.processor,
+                        self.canonical,
                     ))?;
                 }
                 state.end()
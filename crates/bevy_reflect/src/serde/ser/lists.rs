@@ -8,6 +8,7 @@ pub(super) struct ListSerializer<'a, P> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub canonical: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for ListSerializer<'_, P> {
@@ -21,6 +22,7 @@ impl<P: ReflectSerializerProcessor> Serialize for ListSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.canonical,
             ))?;
         }
         state.end()
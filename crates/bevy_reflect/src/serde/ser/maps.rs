@@ -1,5 +1,15 @@
-use crate::{serde::TypedReflectSerializer, Map, TypeRegistry};
-use serde::{ser::SerializeMap, Serialize};
+use crate::{
+    serde::{
+        ser::{canonical::sort_canonically, error_utils::make_custom_error},
+        MapKeyEncoding, ReflectSerializeAsString, TypedReflectSerializer,
+    },
+    Map, PartialReflect, TypeRegistry,
+};
+use alloc::vec::Vec;
+use serde::{
+    ser::{SerializeMap, SerializeSeq, SerializeTuple},
+    Serialize,
+};
 
 use super::ReflectSerializerProcessor;
 
@@ -8,6 +18,7 @@ pub(super) struct MapSerializer<'a, P> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub canonical: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for MapSerializer<'_, P> {
@@ -15,13 +26,147 @@ impl<P: ReflectSerializerProcessor> Serialize for MapSerializer<'_, P> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.map.len()))?;
-        for (key, value) in self.map.iter() {
-            state.serialize_entry(
-                &TypedReflectSerializer::new_internal(key, self.registry, self.processor),
-                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
-            )?;
+        let encoding = match self.map.get_represented_type_info() {
+            Some(info) => self.registry.map_key_encoding_of(info.type_id()),
+            None => self.registry.map_key_encoding(),
+        };
+
+        let mut entries = self.map.iter().collect::<Vec<_>>();
+        if self.canonical {
+            sort_canonically(&mut entries, |(key, _)| *key);
+        }
+
+        match encoding {
+            MapKeyEncoding::Native => {
+                let mut state = serializer.serialize_map(Some(self.map.len()))?;
+                for (key, value) in entries {
+                    state.serialize_entry(
+                        &TypedReflectSerializer::new_internal(
+                            key,
+                            self.registry,
+                            self.processor,
+                            self.canonical,
+                        ),
+                        &TypedReflectSerializer::new_internal(
+                            value,
+                            self.registry,
+                            self.processor,
+                            self.canonical,
+                        ),
+                    )?;
+                }
+                state.end()
+            }
+            MapKeyEncoding::String => {
+                let mut state = serializer.serialize_map(Some(self.map.len()))?;
+                for (key, value) in entries {
+                    let value = TypedReflectSerializer::new_internal(
+                        value,
+                        self.registry,
+                        self.processor,
+                        self.canonical,
+                    );
+                    let is_passthrough = key
+                        .get_represented_type_info()
+                        .is_some_and(|info| MapKeyEncoding::is_passthrough_key(info.type_id()));
+                    if is_passthrough {
+                        state.serialize_entry(
+                            &TypedReflectSerializer::new_internal(
+                                key,
+                                self.registry,
+                                self.processor,
+                                self.canonical,
+                            ),
+                            &value,
+                        )?;
+                    } else {
+                        state.serialize_entry(
+                            &StringKeySerializer {
+                                key,
+                                registry: self.registry,
+                            },
+                            &value,
+                        )?;
+                    }
+                }
+                state.end()
+            }
+            MapKeyEncoding::Pairs => {
+                let mut state = serializer.serialize_seq(Some(self.map.len()))?;
+                for (key, value) in entries {
+                    state.serialize_element(&MapEntrySerializer {
+                        key,
+                        value,
+                        registry: self.registry,
+                        processor: self.processor,
+                        canonical: self.canonical,
+                    })?;
+                }
+                state.end()
+            }
         }
+    }
+}
+
+/// A serializer for map keys using [`MapKeyEncoding::String`].
+struct StringKeySerializer<'a> {
+    key: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for StringKeySerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let key = self.key.try_as_reflect().ok_or_else(|| {
+            make_custom_error::<S::Error>(format_args!(
+                "map key of type `{}` does not implement `Reflect`",
+                self.key.reflect_type_path()
+            ))
+        })?;
+
+        let reflect_serialize_as_string = self
+            .registry
+            .get_type_data::<ReflectSerializeAsString>(key.reflect_type_info().type_id())
+            .ok_or_else(|| {
+                make_custom_error::<S::Error>(format_args!(
+                    "map key of type `{}` did not register the `ReflectSerializeAsString` type data, which is required to serialize it as a string",
+                    key.reflect_type_path()
+                ))
+            })?;
+
+        reflect_serialize_as_string.serialize(key, serializer)
+    }
+}
+
+/// A serializer for map entries using [`MapKeyEncoding::Pairs`].
+struct MapEntrySerializer<'a, P> {
+    key: &'a dyn PartialReflect,
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+    canonical: bool,
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for MapEntrySerializer<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&TypedReflectSerializer::new_internal(
+            self.key,
+            self.registry,
+            self.processor,
+            self.canonical,
+        ))?;
+        state.serialize_element(&TypedReflectSerializer::new_internal(
+            self.value,
+            self.registry,
+            self.processor,
+            self.canonical,
+        ))?;
         state.end()
     }
 }
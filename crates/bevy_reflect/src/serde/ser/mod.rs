@@ -1,16 +1,21 @@
 pub use processor::*;
+pub use self_describing::*;
 pub use serializable::*;
+pub use serialize_as_string::*;
 pub use serialize_with_registry::*;
 pub use serializer::*;
 
 mod arrays;
+mod canonical;
 mod custom_serialization;
 mod enums;
 mod error_utils;
 mod lists;
 mod maps;
 mod processor;
+pub(super) mod self_describing;
 mod serializable;
+mod serialize_as_string;
 mod serialize_with_registry;
 mod serializer;
 mod sets;
@@ -23,10 +28,12 @@ mod tests {
     use crate::{
         self as bevy_reflect,
         serde::{ReflectSerializer, ReflectSerializerProcessor},
-        PartialReflect, Reflect, ReflectSerialize, Struct, TypeRegistry,
+        DynamicStruct, NamedField, PartialReflect, Reflect, ReflectSerialize, Struct, TypeInfo,
+        TypeRegistry, Typed,
     };
     use alloc::{
         boxed::Box,
+        format,
         string::{String, ToString},
         vec,
         vec::Vec,
@@ -434,6 +441,58 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_serialize_canonically() {
+        #[derive(Reflect)]
+        struct Inventory {
+            counts: HashMap<String, u32>,
+            tags: HashSet<u8>,
+            weight: f32,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Inventory>();
+
+        let mut counts = HashMap::default();
+        let mut tags = HashSet::default();
+        for index in (0..16).rev() {
+            counts.insert(format!("item{index:02}"), index);
+            tags.insert(index as u8);
+        }
+        let value = Inventory {
+            counts,
+            tags,
+            weight: -0.0,
+        };
+
+        // The same value, with its fields inserted in a different order
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_represented_type(Some(<Inventory as Typed>::type_info()));
+        dynamic.insert("weight", value.weight);
+        dynamic.insert("tags", value.tags.clone());
+        dynamic.insert("counts", value.counts.clone());
+
+        let serialize = |value: &dyn PartialReflect| {
+            let serializer = ReflectSerializer::new(value, &registry).with_canonical(true);
+            ron::ser::to_string(&serializer).unwrap()
+        };
+
+        let counts = (0..16)
+            .map(|index| format!(r#""item{index:02}":{index}"#))
+            .collect::<Vec<_>>()
+            .join(",");
+        let tags = (0..16)
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let expected = format!(
+            r#"{{"bevy_reflect::serde::ser::tests::Inventory":(counts:{{{counts}}},tags:[{tags}],weight:0.0)}}"#
+        );
+
+        assert_eq!(expected, serialize(&value));
+        assert_eq!(expected, serialize(&dynamic));
+    }
+
     #[test]
     fn should_return_error_if_missing_registration() {
         let value = RangeInclusive::<f32>::new(0.0, 1.0);
@@ -471,14 +530,14 @@ mod tests {
         assert_eq!(
             error,
             ron::Error::Message(
-                "type `core::ops::RangeInclusive<f32>` did not register the `ReflectSerialize` or `ReflectSerializeWithRegistry` type data. For certain types, this may need to be registered manually using `register_type_data` (stack: `core::ops::RangeInclusive<f32>`)".to_string()
+                "type `core::ops::RangeInclusive<f32>` did not register the `ReflectSerialize`, `ReflectSerializeWithRegistry` or `ReflectSerializeAsString` type data. For certain types, this may need to be registered manually using `register_type_data` (stack: `core::ops::RangeInclusive<f32>`)".to_string()
             )
         );
         #[cfg(not(feature = "debug_stack"))]
         assert_eq!(
             error,
             ron::Error::Message(
-                "type `core::ops::RangeInclusive<f32>` did not register the `ReflectSerialize` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()
+                "type `core::ops::RangeInclusive<f32>` did not register the `ReflectSerialize`, `ReflectSerializeWithRegistry` or `ReflectSerializeAsString` type data. For certain types, this may need to be registered manually using `register_type_data`".to_string()
             )
         );
     }
@@ -602,6 +661,84 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_use_processor_for_named_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Login {
+            user: String,
+            password: String,
+            other: OtherLogin,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum OtherLogin {
+            Token { password: String },
+        }
+
+        struct RedactProcessor;
+
+        impl ReflectSerializerProcessor for RedactProcessor {
+            fn try_serialize<S>(
+                &self,
+                _: &dyn PartialReflect,
+                _: &TypeRegistry,
+                serializer: S,
+            ) -> Result<Result<S::Ok, S>, S::Error>
+            where
+                S: Serializer,
+            {
+                Ok(Err(serializer))
+            }
+
+            fn try_serialize_field<S>(
+                &self,
+                container: &TypeInfo,
+                field: &NamedField,
+                _: &dyn PartialReflect,
+                _: &TypeRegistry,
+                serializer: S,
+            ) -> Result<Result<S::Ok, S>, S::Error>
+            where
+                S: Serializer,
+            {
+                if container.type_id() == TypeId::of::<Login>() && field.name() == "password" {
+                    Ok(Ok(serializer.serialize_str("<redacted>")?))
+                } else {
+                    Ok(Err(serializer))
+                }
+            }
+        }
+
+        let value = Login {
+            user: String::from("user"),
+            password: String::from("hunter2"),
+            other: OtherLogin::Token {
+                password: String::from("token"),
+            },
+        };
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Login>();
+
+        let processor = RedactProcessor;
+        let serializer = ReflectSerializer::with_processor(&value, &registry, &processor);
+
+        let config = PrettyConfig::default().new_line(String::from("\n"));
+        let output = ron::ser::to_string_pretty(&serializer, config).unwrap();
+
+        let expected = r#"{
+    "bevy_reflect::serde::ser::tests::Login": (
+        user: "user",
+        password: "<redacted>",
+        other: Token(
+            password: "token",
+        ),
+    ),
+}"#;
+
+        assert_eq!(expected, output);
+    }
+
     #[test]
     fn should_propagate_processor_serialize_error() {
         struct ErroringProcessor;
@@ -1,6 +1,6 @@
 use serde::Serializer;
 
-use crate::{PartialReflect, TypeRegistry};
+use crate::{NamedField, PartialReflect, TypeInfo, TypeRegistry};
 
 /// Allows overriding the default serialization behavior of
 /// [`ReflectSerializer`] and [`TypedReflectSerializer`] for specific values.
@@ -127,6 +127,7 @@ use crate::{PartialReflect, TypeRegistry};
 /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
 /// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
 /// [`try_serialize`]: Self::try_serialize
+/// [`try_serialize_field`]: Self::try_serialize_field
 /// [`SerializeWithRegistry`]: crate::serde::SerializeWithRegistry
 /// [`ReflectDeserializerProcessor`]: crate::serde::ReflectDeserializerProcessor
 pub trait ReflectSerializerProcessor {
@@ -179,6 +180,85 @@ pub trait ReflectSerializerProcessor {
     ) -> Result<Result<S::Ok, S>, S::Error>
     where
         S: Serializer;
+
+    /// Attempts to serialize the value of a named `field` of the `container`
+    /// type, which is either a struct or an enum with a struct variant.
+    ///
+    /// This is called before [`try_serialize`] for every named field, and
+    /// follows the same rules: return `Ok(Ok(value))` to override the
+    /// serialization, or `Ok(Err(serializer))` to fall through to
+    /// [`try_serialize`] and then the default logic. By default, no fields
+    /// are overridden.
+    ///
+    /// The field is always written, so this can replace a field's value but
+    /// not remove it. To never serialize a field, use
+    /// `#[reflect(skip_serializing)]` instead.
+    ///
+    /// Fields can be matched by name, or by a [custom attribute] on the field:
+    ///
+    /// ```
+    /// # use bevy_reflect::{NamedField, PartialReflect, Reflect, TypeInfo, TypeRegistry};
+    /// # use bevy_reflect::serde::ReflectSerializerProcessor;
+    /// #[derive(Reflect)]
+    /// struct Redacted;
+    ///
+    /// #[derive(Reflect)]
+    /// struct Login {
+    ///     user: String,
+    ///     #[reflect(@Redacted)]
+    ///     password: String,
+    /// }
+    ///
+    /// struct RedactProcessor;
+    ///
+    /// impl ReflectSerializerProcessor for RedactProcessor {
+    ///     fn try_serialize<S>(
+    ///         &self,
+    ///         _value: &dyn PartialReflect,
+    ///         _registry: &TypeRegistry,
+    ///         serializer: S,
+    ///     ) -> Result<Result<S::Ok, S>, S::Error>
+    ///     where
+    ///         S: serde::Serializer,
+    ///     {
+    ///         Ok(Err(serializer))
+    ///     }
+    ///
+    ///     fn try_serialize_field<S>(
+    ///         &self,
+    ///         _container: &TypeInfo,
+    ///         field: &NamedField,
+    ///         _value: &dyn PartialReflect,
+    ///         _registry: &TypeRegistry,
+    ///         serializer: S,
+    ///     ) -> Result<Result<S::Ok, S>, S::Error>
+    ///     where
+    ///         S: serde::Serializer,
+    ///     {
+    ///         if field.has_attribute::<Redacted>() {
+    ///             Ok(Ok(serializer.serialize_str("<redacted>")?))
+    ///         } else {
+    ///             Ok(Err(serializer))
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`try_serialize`]: Self::try_serialize
+    /// [custom attribute]: crate::attributes::CustomAttributes
+    fn try_serialize_field<S>(
+        &self,
+        _container: &TypeInfo,
+        _field: &NamedField,
+        _value: &dyn PartialReflect,
+        _registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        Ok(Err(serializer))
+    }
 }
 
 impl ReflectSerializerProcessor for () {
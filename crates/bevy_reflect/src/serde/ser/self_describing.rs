@@ -0,0 +1,265 @@
+use crate::{
+    serde::{ser::error_utils::make_custom_error, ReflectSerializer},
+    Enum, PartialReflect, ReflectRef, Struct, TypeRegistry, VariantType,
+};
+use alloc::vec::Vec;
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Serialize, Serializer,
+};
+
+pub(in crate::serde) const TYPED: &str = "Typed";
+pub(in crate::serde) const STRUCT: &str = "Struct";
+pub(in crate::serde) const TUPLE_STRUCT: &str = "TupleStruct";
+pub(in crate::serde) const TUPLE: &str = "Tuple";
+pub(in crate::serde) const LIST: &str = "List";
+pub(in crate::serde) const ARRAY: &str = "Array";
+pub(in crate::serde) const MAP: &str = "Map";
+pub(in crate::serde) const SET: &str = "Set";
+pub(in crate::serde) const UNIT_VARIANT: &str = "UnitVariant";
+pub(in crate::serde) const TUPLE_VARIANT: &str = "TupleVariant";
+pub(in crate::serde) const STRUCT_VARIANT: &str = "StructVariant";
+
+/// All the tags which a [`SelfDescribingReflectSerializer`] can output.
+pub(in crate::serde) const KINDS: &[&str] = &[
+    TYPED,
+    STRUCT,
+    TUPLE_STRUCT,
+    TUPLE,
+    LIST,
+    ARRAY,
+    MAP,
+    SET,
+    UNIT_VARIANT,
+    TUPLE_VARIANT,
+    STRUCT_VARIANT,
+];
+
+/// A serializer for reflected values which may not have a represented type,
+/// such as dynamic values built by a scripting layer.
+///
+/// This is the serializer counterpart to [`SelfDescribingReflectDeserializer`].
+///
+/// # Output
+///
+/// This serializer will output a map with a single entry, where the key is a tag
+/// describing how the value is stored:
+///
+/// - If the value has a [represented type] which is in the registry, the tag is `Typed`,
+///   and the value is serialized exactly as a [`ReflectSerializer`] would.
+/// - Otherwise, the tag is the [kind] of the value, i.e. one of `Struct`, `TupleStruct`,
+///   `Tuple`, `List`, `Array`, `Map` or `Set`, or `UnitVariant`, `TupleVariant` or
+///   `StructVariant` for enums. The fields or elements of the value are serialized
+///   recursively in the same way, and the entries of maps are serialized as a list of
+///   key-value pairs.
+///
+/// Since opaque values can only be serialized through their type, they must always have
+/// a registered represented type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{DynamicStruct, TypeRegistry, serde::SelfDescribingReflectSerializer};
+/// let registry = TypeRegistry::new();
+///
+/// let mut value = DynamicStruct::default();
+/// value.insert("health", 100_u32);
+///
+/// let serializer = SelfDescribingReflectSerializer::new(&value, &registry);
+/// let output = ron::to_string(&serializer).unwrap();
+///
+/// assert_eq!(output, r#"{"Struct":{"health":{"Typed":{"u32":100}}}}"#);
+/// ```
+///
+/// [`SelfDescribingReflectDeserializer`]: crate::serde::SelfDescribingReflectDeserializer
+/// [represented type]: PartialReflect::get_represented_type_info
+/// [kind]: crate::ReflectKind
+pub struct SelfDescribingReflectSerializer<'a> {
+    value: &'a dyn PartialReflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> SelfDescribingReflectSerializer<'a> {
+    /// Creates a self-describing serializer for the given value.
+    pub fn new(value: &'a dyn PartialReflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+}
+
+impl Serialize for SelfDescribingReflectSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+
+        if let Some(type_info) = self.value.get_represented_type_info() {
+            if self.registry.contains(type_info.type_id()) {
+                state.serialize_entry(TYPED, &ReflectSerializer::new(self.value, self.registry))?;
+                return state.end();
+            }
+        }
+
+        let registry = self.registry;
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(struct_value) => {
+                state.serialize_entry(STRUCT, &StructFields::new(struct_value, registry))?;
+            }
+            ReflectRef::TupleStruct(tuple_struct) => {
+                let values = Elements::new(tuple_struct.iter_fields(), registry);
+                state.serialize_entry(TUPLE_STRUCT, &values)?;
+            }
+            ReflectRef::Tuple(tuple) => {
+                state.serialize_entry(TUPLE, &Elements::new(tuple.iter_fields(), registry))?;
+            }
+            ReflectRef::List(list) => {
+                state.serialize_entry(LIST, &Elements::new(list.iter(), registry))?;
+            }
+            ReflectRef::Array(array) => {
+                state.serialize_entry(ARRAY, &Elements::new(array.iter(), registry))?;
+            }
+            ReflectRef::Map(map) => {
+                state.serialize_entry(MAP, &Entries::new(map.iter(), registry))?;
+            }
+            ReflectRef::Set(set) => {
+                state.serialize_entry(SET, &Elements::new(set.iter(), registry))?;
+            }
+            ReflectRef::Enum(enum_value) => {
+                let name = enum_value.variant_name();
+                match enum_value.variant_type() {
+                    VariantType::Unit => state.serialize_entry(UNIT_VARIANT, name)?,
+                    VariantType::Tuple => {
+                        let values = enum_value.iter_fields().map(|field| field.value());
+                        state.serialize_entry(
+                            TUPLE_VARIANT,
+                            &(name, Elements::new(values, registry)),
+                        )?;
+                    }
+                    VariantType::Struct => state.serialize_entry(
+                        STRUCT_VARIANT,
+                        &(name, StructFields::from_variant(enum_value, registry)),
+                    )?,
+                }
+            }
+            #[cfg(feature = "functions")]
+            ReflectRef::Function(_) => {
+                return Err(make_custom_error("functions cannot be serialized"));
+            }
+            ReflectRef::Opaque(_) => {
+                return Err(make_custom_error(format_args!(
+                    "cannot serialize opaque value `{}` without a registered represented type",
+                    self.value.reflect_type_path()
+                )));
+            }
+        }
+
+        state.end()
+    }
+}
+
+/// Serializes values as a sequence of self-describing values.
+struct Elements<'a> {
+    values: Vec<&'a dyn PartialReflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Elements<'a> {
+    fn new(
+        values: impl Iterator<Item = &'a dyn PartialReflect>,
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        Self {
+            values: values.collect(),
+            registry,
+        }
+    }
+}
+
+impl Serialize for Elements<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for value in &self.values {
+            state
+                .serialize_element(&SelfDescribingReflectSerializer::new(*value, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes named fields as a map from names to self-describing values.
+struct StructFields<'a> {
+    fields: Vec<(&'a str, &'a dyn PartialReflect)>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> StructFields<'a> {
+    fn new(struct_value: &'a dyn Struct, registry: &'a TypeRegistry) -> Self {
+        let fields = struct_value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, value)| (struct_value.name_at(index).unwrap(), value))
+            .collect();
+        Self { fields, registry }
+    }
+
+    fn from_variant(enum_value: &'a dyn Enum, registry: &'a TypeRegistry) -> Self {
+        let fields = enum_value
+            .iter_fields()
+            .map(|field| (field.name().unwrap(), field.value()))
+            .collect();
+        Self { fields, registry }
+    }
+}
+
+impl Serialize for StructFields<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.fields.len()))?;
+        for (name, value) in &self.fields {
+            state.serialize_entry(
+                name,
+                &SelfDescribingReflectSerializer::new(*value, self.registry),
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes map entries as a sequence of self-describing key-value pairs.
+struct Entries<'a> {
+    entries: Vec<(&'a dyn PartialReflect, &'a dyn PartialReflect)>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Entries<'a> {
+    fn new(
+        entries: impl Iterator<Item = (&'a dyn PartialReflect, &'a dyn PartialReflect)>,
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        Self {
+            entries: entries.collect(),
+            registry,
+        }
+    }
+}
+
+impl Serialize for Entries<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.entries.len()))?;
+        for (key, value) in &self.entries {
+            state.serialize_element(&(
+                SelfDescribingReflectSerializer::new(*key, self.registry),
+                SelfDescribingReflectSerializer::new(*value, self.registry),
+            ))?;
+        }
+        state.end()
+    }
+}
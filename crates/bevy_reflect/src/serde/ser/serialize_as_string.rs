@@ -0,0 +1,58 @@
+use crate::{FromType, Reflect};
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use serde::Serializer;
+
+/// Type data used to serialize a [`Reflect`] type as a string using its [`Display`] implementation.
+///
+/// This allows opaque types without a [`Serialize`] implementation, such as IDs from
+/// third-party crates, to be serialized by the reflection serializers like
+/// [`TypedReflectSerializer`] and [`ReflectSerializer`].
+/// Types that register this should usually also register [`ReflectDeserializeFromString`]
+/// so that they can be deserialized again.
+///
+/// This can be registered [via the registry] or by adding `#[reflect(SerializeAsString)]` to
+/// the type definition.
+///
+/// [`Serialize`]: ::serde::Serialize
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`ReflectDeserializeFromString`]: crate::serde::ReflectDeserializeFromString
+/// [via the registry]: crate::TypeRegistry::register_type_data
+#[derive(Clone)]
+pub struct ReflectSerializeAsString {
+    to_string: fn(value: &dyn Reflect) -> String,
+}
+
+impl ReflectSerializeAsString {
+    /// Serialize a [`Reflect`] type as the string produced by its [`Display`] implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type of the value doesn't match the type this type data was created for.
+    pub fn serialize<S>(&self, value: &dyn Reflect, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&(self.to_string)(value))
+    }
+}
+
+impl<T: Reflect + Display> FromType<T> for ReflectSerializeAsString {
+    fn from_type() -> Self {
+        Self {
+            to_string: |value| {
+                value
+                    .downcast_ref::<T>()
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Expected value to be of type {} but received {}",
+                            core::any::type_name::<T>(),
+                            value.reflect_type_path()
+                        )
+                    })
+                    .to_string()
+            },
+        }
+    }
+}
@@ -2,12 +2,20 @@
 use crate::serde::ser::error_utils::TYPE_INFO_STACK;
 use crate::{
     serde::ser::{
-        arrays::ArraySerializer, custom_serialization::try_custom_serialize, enums::EnumSerializer,
-        error_utils::make_custom_error, lists::ListSerializer, maps::MapSerializer,
-        sets::SetSerializer, structs::StructSerializer, tuple_structs::TupleStructSerializer,
+        arrays::ArraySerializer,
+        canonical::{canonical_f32, canonical_f64},
+        custom_serialization::try_custom_serialize,
+        enums::EnumSerializer,
+        error_utils::make_custom_error,
+        lists::ListSerializer,
+        maps::MapSerializer,
+        sets::SetSerializer,
+        structs::StructSerializer,
+        tuple_structs::TupleStructSerializer,
         tuples::TupleSerializer,
     },
-    PartialReflect, ReflectRef, TypeRegistry,
+    serde::TypeManifest,
+    NamedField, PartialReflect, ReflectRef, TypeInfo, TypeRegistry,
 };
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
@@ -25,6 +33,9 @@ use super::ReflectSerializerProcessor;
 /// where the key is the _full_ [type path] of the reflected type
 /// and the value is the serialized data.
 ///
+/// If a [`TypeManifest`] is set using [`with_type_manifest`], the key is instead the
+/// numeric ID of the type in the manifest, which is much more compact in binary formats.
+///
 /// If you want to override serialization for specific values, you can pass in
 /// a reference to a [`ReflectSerializerProcessor`] which will take priority
 /// over all other serialization methods - see [`with_processor`].
@@ -54,10 +65,13 @@ use super::ReflectSerializerProcessor;
 /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
 /// [type path]: crate::TypePath::type_path
 /// [`with_processor`]: Self::with_processor
+/// [`with_type_manifest`]: Self::with_type_manifest
 pub struct ReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    canonical: bool,
+    type_manifest: Option<&'a TypeManifest>,
 }
 
 impl<'a> ReflectSerializer<'a, ()> {
@@ -72,6 +86,8 @@ impl<'a> ReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            canonical: false,
+            type_manifest: None,
         }
     }
 }
@@ -92,8 +108,29 @@ impl<'a, P: ReflectSerializerProcessor> ReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            canonical: false,
+            type_manifest: None,
         }
     }
+
+    /// Identifies the type of the value by its ID in the given [`TypeManifest`],
+    /// instead of by its type path.
+    ///
+    /// The value must then be deserialized using a [`ReflectDeserializer`] with the same manifest.
+    ///
+    /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+    pub fn with_type_manifest(mut self, type_manifest: &'a TypeManifest) -> Self {
+        self.type_manifest = Some(type_manifest);
+        self
+    }
+
+    /// Sets whether to produce canonical output.
+    ///
+    /// See [`TypedReflectSerializer::with_canonical`] for details.
+    pub fn with_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
@@ -101,26 +138,41 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
     where
         S: Serializer,
     {
+        let type_info = self.value.get_represented_type_info().ok_or_else(|| {
+            if self.value.is_dynamic() {
+                make_custom_error(format_args!(
+                    "cannot serialize dynamic value without represented type: `{}`",
+                    self.value.reflect_type_path()
+                ))
+            } else {
+                make_custom_error(format_args!(
+                    "cannot get type info for `{}`",
+                    self.value.reflect_type_path()
+                ))
+            }
+        })?;
+        let type_path = type_info.type_path();
+        let value = TypedReflectSerializer::new_internal(
+            self.value,
+            self.registry,
+            self.processor,
+            self.canonical,
+        );
+
         let mut state = serializer.serialize_map(Some(1))?;
-        state.serialize_entry(
-            self.value
-                .get_represented_type_info()
-                .ok_or_else(|| {
-                    if self.value.is_dynamic() {
-                        make_custom_error(format_args!(
-                            "cannot serialize dynamic value without represented type: `{}`",
-                            self.value.reflect_type_path()
-                        ))
-                    } else {
-                        make_custom_error(format_args!(
-                            "cannot get type info for `{}`",
-                            self.value.reflect_type_path()
-                        ))
-                    }
-                })?
-                .type_path(),
-            &TypedReflectSerializer::new_internal(self.value, self.registry, self.processor),
-        )?;
+        match self.type_manifest {
+            Some(type_manifest) => {
+                let id = type_manifest.id(type_path).ok_or_else(|| {
+                    make_custom_error(format_args!(
+                        "type `{type_path}` is not in the type manifest"
+                    ))
+                })?;
+                state.serialize_entry(&id, &value)?;
+            }
+            None => {
+                state.serialize_entry(&self.registry.serialized_type_path(type_info), &value)?
+            }
+        }
         state.end()
     }
 }
@@ -172,6 +224,9 @@ pub struct TypedReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    canonical: bool,
+    /// The containing type and field info, if this value is a named field.
+    field: Option<(&'a TypeInfo, &'a NamedField)>,
 }
 
 impl<'a> TypedReflectSerializer<'a, ()> {
@@ -189,6 +244,8 @@ impl<'a> TypedReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            canonical: false,
+            field: None,
         }
     }
 }
@@ -212,6 +269,8 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            canonical: false,
+            field: None,
         }
     }
 
@@ -220,13 +279,41 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
         value: &'a dyn PartialReflect,
         registry: &'a TypeRegistry,
         processor: Option<&'a P>,
+        canonical: bool,
     ) -> Self {
         Self {
             value,
             registry,
             processor,
+            canonical,
+            field: None,
         }
     }
+
+    /// Sets whether to produce canonical output.
+    ///
+    /// Canonical output only depends on the value being serialized, so serializing equal values
+    /// always produces the same output, which keeps files like scenes stable under version control.
+    /// In canonical output:
+    /// - The entries of maps and the elements of sets are sorted, rather than following the
+    ///   iteration order of the collection, which e.g. varies between runs for a `HashMap`.
+    /// - The fields of structs are emitted in the order they're declared in,
+    ///   even for dynamic structs whose fields were inserted in a different order.
+    /// - Negative zero floats are written as positive zero, and all NaN floats are written as
+    ///   the same NaN.
+    ///
+    /// Canonical output is slower to produce, and can be deserialized as usual.
+    pub fn with_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Marks the value being serialized as the named `field` of the `container` type,
+    /// so that the processor can override serialization for that field.
+    pub(super) fn with_field(mut self, container: &'a TypeInfo, field: &'a NamedField) -> Self {
+        self.field = Some((container, field));
+        self
+    }
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P> {
@@ -241,9 +328,28 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
             }
         }
 
-        // First, check if our processor wants to serialize this type
+        // First, check if our processor wants to serialize this field, then this type
         // This takes priority over any other serialization operations
         let serializer = if let Some(processor) = self.processor {
+            let serializer = match self.field {
+                Some((container, field)) => match processor.try_serialize_field(
+                    container,
+                    field,
+                    self.value,
+                    self.registry,
+                    serializer,
+                ) {
+                    Ok(Ok(value)) => {
+                        return Ok(value);
+                    }
+                    Err(err) => {
+                        return Err(make_custom_error(err));
+                    }
+                    Ok(Err(serializer)) => serializer,
+                },
+                None => serializer,
+            };
+
             match processor.try_serialize(self.value, self.registry, serializer) {
                 Ok(Ok(value)) => {
                     return Ok(value);
@@ -257,6 +363,15 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
             serializer
         };
 
+        if self.canonical {
+            if let Some(value) = self.value.try_downcast_ref::<f32>() {
+                return canonical_f32(*value).serialize(serializer);
+            }
+            if let Some(value) = self.value.try_downcast_ref::<f64>() {
+                return canonical_f64(*value).serialize(serializer);
+            }
+        }
+
         // Handle both Value case and types that have a custom `Serialize`
         let (serializer, error) = match try_custom_serialize(self.value, self.registry, serializer)
         {
@@ -269,48 +384,56 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
                 struct_value,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(tuple_struct) => TupleStructSerializer {
                 tuple_struct,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::Tuple(tuple) => TupleSerializer {
                 tuple,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::List(list) => ListSerializer {
                 list,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::Array(array) => ArraySerializer {
                 array,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::Map(map) => MapSerializer {
                 map,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::Set(set) => SetSerializer {
                 set,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             ReflectRef::Enum(enum_value) => EnumSerializer {
                 enum_value,
                 registry: self.registry,
                 processor: self.processor,
+                canonical: self.canonical,
             }
             .serialize(serializer),
             #[cfg(feature = "functions")]
@@ -1,4 +1,8 @@
-use crate::{serde::TypedReflectSerializer, Set, TypeRegistry};
+use crate::{
+    serde::{ser::canonical::sort_canonically, TypedReflectSerializer},
+    Set, TypeRegistry,
+};
+use alloc::vec::Vec;
 use serde::{ser::SerializeSeq, Serialize};
 
 use super::ReflectSerializerProcessor;
@@ -8,6 +12,7 @@ pub(super) struct SetSerializer<'a, P> {
     pub set: &'a dyn Set,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub canonical: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for SetSerializer<'_, P> {
@@ -15,12 +20,18 @@ impl<P: ReflectSerializerProcessor> Serialize for SetSerializer<'_, P> {
     where
         S: serde::Serializer,
     {
+        let mut values = self.set.iter().collect::<Vec<_>>();
+        if self.canonical {
+            sort_canonically(&mut values, |value| *value);
+        }
+
         let mut state = serializer.serialize_seq(Some(self.set.len()))?;
-        for value in self.set.iter() {
+        for value in values {
             state.serialize_element(&TypedReflectSerializer::new_internal(
                 value,
                 self.registry,
                 self.processor,
+                self.canonical,
             ))?;
         }
         state.end()
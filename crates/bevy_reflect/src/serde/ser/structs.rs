@@ -1,7 +1,12 @@
 use crate::{
-    serde::{ser::error_utils::make_custom_error, SerializationData, TypedReflectSerializer},
-    Struct, TypeInfo, TypeRegistry,
+    serde::{
+        migrate::{VALUE_FIELD, VERSIONED_FIELDS, VERSION_FIELD},
+        ser::error_utils::make_custom_error,
+        ReflectMigrate, SerializationData, TypedReflectSerializer,
+    },
+    Struct, StructInfo, TypeInfo, TypeRegistry,
 };
+use alloc::vec::Vec;
 use serde::{ser::SerializeStruct, Serialize};
 
 use super::ReflectSerializerProcessor;
@@ -11,6 +16,7 @@ pub(super) struct StructSerializer<'a, P> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub canonical: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
@@ -37,24 +43,98 @@ impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
             }
         };
 
-        let serialization_data = self
-            .registry
-            .get(type_info.type_id())
-            .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
+        let registration = self.registry.get(type_info.type_id());
+        let fields = StructFieldsSerializer {
+            struct_value: self.struct_value,
+            type_info,
+            struct_info,
+            serialization_data: registration.and_then(|registration| registration.data()),
+            registry: self.registry,
+            processor: self.processor,
+            canonical: self.canonical,
+        };
+
+        match registration.and_then(|registration| registration.data::<ReflectMigrate>()) {
+            Some(migrate) => {
+                let mut state = serializer.serialize_struct(
+                    struct_info.type_path_table().ident().unwrap(),
+                    VERSIONED_FIELDS.len(),
+                )?;
+                state.serialize_field(VERSION_FIELD, &migrate.version())?;
+                state.serialize_field(VALUE_FIELD, &fields)?;
+                state.end()
+            }
+            None => fields.serialize(serializer),
+        }
+    }
+}
+
+/// A serializer for the fields of a [`Struct`] value, without its version.
+struct StructFieldsSerializer<'a, P> {
+    struct_value: &'a dyn Struct,
+    type_info: &'a TypeInfo,
+    struct_info: &'a StructInfo,
+    serialization_data: Option<&'a SerializationData>,
+    registry: &'a TypeRegistry,
+    processor: Option<&'a P>,
+    canonical: bool,
+}
+
+impl<P: ReflectSerializerProcessor> Serialize for StructFieldsSerializer<'_, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let ignored_len = self
+            .serialization_data
+            .map(SerializationData::len)
+            .unwrap_or(0);
         let mut state = serializer.serialize_struct(
-            struct_info.type_path_table().ident().unwrap(),
+            self.struct_info.type_path_table().ident().unwrap(),
             self.struct_value.field_len() - ignored_len,
         )?;
 
-        for (index, value) in self.struct_value.iter_fields().enumerate() {
-            if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+        let fields = if self.canonical {
+            // Follow the declaration order, even if a dynamic struct's fields were inserted
+            // in a different order.
+            self.struct_info
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    let value = self.struct_value.field(field.name()).ok_or_else(|| {
+                        make_custom_error::<S::Error>(format_args!(
+                            "missing field `{}` of `{}`",
+                            field.name(),
+                            self.struct_info.type_path()
+                        ))
+                    })?;
+                    Ok((index, field, value))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            self.struct_value
+                .iter_fields()
+                .enumerate()
+                .map(|(index, value)| (index, self.struct_info.field_at(index).unwrap(), value))
+                .collect()
+        };
+
+        for (index, field, value) in fields {
+            if self
+                .serialization_data
+                .is_some_and(|data| data.is_field_skipped(index))
+            {
                 continue;
             }
-            let key = struct_info.field_at(index).unwrap().name();
             state.serialize_field(
-                key,
-                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
+                field.name(),
+                &TypedReflectSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.canonical,
+                )
+                .with_field(self.type_info, field),
             )?;
         }
         state.end()
@@ -11,6 +11,7 @@ pub(super) struct TupleStructSerializer<'a, P> {
     pub tuple_struct: &'a dyn TupleStruct,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub canonical: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
@@ -47,7 +48,12 @@ impl<P: ReflectSerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
             let field = self.tuple_struct.field(0).unwrap();
             return serializer.serialize_newtype_struct(
                 tuple_struct_info.type_path_table().ident().unwrap(),
-                &TypedReflectSerializer::new_internal(field, self.registry, self.processor),
+                &TypedReflectSerializer::new_internal(
+                    field,
+                    self.registry,
+                    self.processor,
+                    self.canonical,
+                ),
             );
         }
 
@@ -64,6 +70,7 @@ impl<P: ReflectSerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.canonical,
             ))?;
         }
         state.end()
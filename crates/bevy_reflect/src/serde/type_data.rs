@@ -1,6 +1,7 @@
 use crate::Reflect;
-use alloc::boxed::Box;
+use alloc::{borrow::Cow, boxed::Box, string::String};
 use bevy_platform_support::collections::{hash_map::Iter, HashMap};
+use core::any::TypeId;
 
 /// Contains data relevant to the automatic reflect powered (de)serialization of a type.
 #[derive(Debug, Clone)]
@@ -134,3 +135,69 @@ impl SkippedField {
         (self.default_fn)()
     }
 }
+
+/// Controls how the keys of [`Map`] values are encoded by the reflection serializers and
+/// deserializers.
+///
+/// Some self-describing formats, such as JSON, only support strings as map keys, and others
+/// like RON can't express every kind of key in every configuration. Maps whose keys are
+/// structs or enums, such as `HashMap<GridPos, Tile>`, can be round-tripped through those
+/// formats by picking a different encoding.
+///
+/// The encoding of every map can be set with [`TypeRegistry::set_map_key_encoding`].
+/// It can also be overridden for a specific map type by inserting this as type data
+/// into the [registration] of that map type.
+///
+/// [`Map`]: crate::Map
+/// [`TypeRegistry::set_map_key_encoding`]: crate::TypeRegistry::set_map_key_encoding
+/// [registration]: crate::TypeRegistration::insert
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MapKeyEncoding {
+    /// Maps are encoded as maps of the format, with keys encoded like any other value.
+    #[default]
+    Native,
+    /// Maps are encoded as maps of the format, with keys encoded as strings.
+    ///
+    /// Key types must register both [`ReflectSerializeAsString`] and
+    /// [`ReflectDeserializeFromString`], which convert keys to and from strings using their
+    /// `Display` and `FromStr` implementations.
+    ///
+    /// Keys which are already strings or primitives, such as `String`, `char`, `bool`
+    /// and the integer and float types, are passed through unchanged, since formats which
+    /// only support string keys can already encode them.
+    ///
+    /// [`ReflectSerializeAsString`]: crate::serde::ReflectSerializeAsString
+    /// [`ReflectDeserializeFromString`]: crate::serde::ReflectDeserializeFromString
+    String,
+    /// Maps are encoded as sequences of key-value pairs, which are each encoded as a tuple.
+    Pairs,
+}
+
+impl MapKeyEncoding {
+    /// Returns `true` if keys of the given type are passed through unchanged when using
+    /// [`MapKeyEncoding::String`].
+    pub(crate) fn is_passthrough_key(type_id: TypeId) -> bool {
+        [
+            TypeId::of::<String>(),
+            TypeId::of::<&'static str>(),
+            TypeId::of::<Cow<'static, str>>(),
+            TypeId::of::<char>(),
+            TypeId::of::<bool>(),
+            TypeId::of::<u8>(),
+            TypeId::of::<u16>(),
+            TypeId::of::<u32>(),
+            TypeId::of::<u64>(),
+            TypeId::of::<u128>(),
+            TypeId::of::<usize>(),
+            TypeId::of::<i8>(),
+            TypeId::of::<i16>(),
+            TypeId::of::<i32>(),
+            TypeId::of::<i64>(),
+            TypeId::of::<i128>(),
+            TypeId::of::<isize>(),
+            TypeId::of::<f32>(),
+            TypeId::of::<f64>(),
+        ]
+        .contains(&type_id)
+    }
+}
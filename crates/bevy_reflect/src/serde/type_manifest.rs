@@ -0,0 +1,157 @@
+use crate::TypeRegistry;
+use alloc::{string::String, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A mapping between the [type paths] of reflected types and compact numeric IDs.
+///
+/// By default, [`ReflectSerializer`] and [`ReflectDeserializer`] identify the type of a value
+/// by its full type path, which takes up a lot of space in binary formats such as
+/// [`bincode`] or [`postcard`].
+/// Passing a manifest to [`ReflectSerializer::with_type_manifest`] and
+/// [`ReflectDeserializer::with_type_manifest`] replaces the type path with the type's ID instead.
+///
+/// IDs are assigned in the order of the manifest's type paths. A manifest created with
+/// [`from_registry`] sorts them, so processes with the same registered types create the same
+/// manifest. When this can't be guaranteed, such as between different builds of an app,
+/// the manifest used for serialization can itself be serialized and sent or stored alongside
+/// the data, and then deserialized and used for deserializing the data.
+///
+/// # Example
+///
+/// ```
+/// # use bincode::Options;
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{ReflectDeserializer, ReflectSerializer, TypeManifest}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct MyStruct {
+///   value: i32
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<MyStruct>();
+/// let manifest = TypeManifest::from_registry(&registry);
+///
+/// let input = MyStruct { value: 123 };
+/// let serializer = ReflectSerializer::new(&input, &registry).with_type_manifest(&manifest);
+/// let bytes = bincode::serialize(&serializer).unwrap();
+///
+/// // The manifest is needed to deserialize the data, so send it along with it
+/// let manifest_bytes = bincode::serialize(&manifest).unwrap();
+///
+/// let manifest: TypeManifest = bincode::deserialize(&manifest_bytes).unwrap();
+/// let deserializer = ReflectDeserializer::new(&registry).with_type_manifest(&manifest);
+/// let output = bincode::DefaultOptions::new()
+///     .with_fixint_encoding()
+///     .deserialize_seed(deserializer, &bytes)
+///     .unwrap();
+///
+/// assert_eq!(MyStruct::from_reflect(output.as_partial_reflect()), Some(input));
+/// ```
+///
+/// [type paths]: crate::TypePath::type_path
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+/// [`ReflectSerializer::with_type_manifest`]: crate::serde::ReflectSerializer::with_type_manifest
+/// [`ReflectDeserializer::with_type_manifest`]: crate::serde::ReflectDeserializer::with_type_manifest
+/// [`bincode`]: https://docs.rs/bincode
+/// [`postcard`]: https://docs.rs/postcard
+/// [`from_registry`]: Self::from_registry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeManifest {
+    type_paths: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl TypeManifest {
+    /// Creates a manifest containing every type in the given [`TypeRegistry`],
+    /// with IDs assigned in order of their sorted type paths.
+    pub fn from_registry(registry: &TypeRegistry) -> Self {
+        let mut type_paths = registry
+            .iter()
+            .map(|registration| registration.type_info().type_path())
+            .collect::<Vec<_>>();
+        type_paths.sort_unstable();
+        Self::from_type_paths(type_paths)
+    }
+
+    /// Creates a manifest containing the given type paths, with IDs assigned in iteration order.
+    ///
+    /// Type paths which appear more than once are only assigned an ID the first time.
+    pub fn from_type_paths<T: Into<String>>(type_paths: impl IntoIterator<Item = T>) -> Self {
+        let mut manifest = Self::default();
+        for type_path in type_paths {
+            manifest.push(type_path.into());
+        }
+        manifest
+    }
+
+    /// Adds a type path to the end of the manifest, returning its new ID.
+    ///
+    /// If the type path is already in the manifest, returns its existing ID instead.
+    pub fn push(&mut self, type_path: impl Into<String>) -> u32 {
+        let type_path = type_path.into();
+        if let Some(&id) = self.ids.get(&type_path) {
+            return id;
+        }
+        let id = u32::try_from(self.type_paths.len()).expect("too many types in manifest");
+        self.ids.insert(type_path.clone(), id);
+        self.type_paths.push(type_path);
+        id
+    }
+
+    /// Returns the ID of the given type path, if it is in the manifest.
+    pub fn id(&self, type_path: &str) -> Option<u32> {
+        self.ids.get(type_path).copied()
+    }
+
+    /// Returns the type path with the given ID, if it is in the manifest.
+    pub fn type_path(&self, id: u32) -> Option<&str> {
+        self.type_paths.get(id as usize).map(String::as_str)
+    }
+
+    /// Returns an iterator over the IDs and type paths in the manifest, in order of their IDs.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.type_paths
+            .iter()
+            .enumerate()
+            .map(|(id, type_path)| (id as u32, type_path.as_str()))
+    }
+
+    /// Returns the number of types in the manifest.
+    pub fn len(&self) -> usize {
+        self.type_paths.len()
+    }
+
+    /// Returns `true` if the manifest contains no types.
+    pub fn is_empty(&self) -> bool {
+        self.type_paths.is_empty()
+    }
+}
+
+/// Serializes the manifest as a sequence of type paths, in order of their IDs.
+impl Serialize for TypeManifest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(&self.type_paths)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeManifest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let type_paths = Vec::<String>::deserialize(deserializer)?;
+        let len = type_paths.len();
+        let manifest = Self::from_type_paths(type_paths);
+        if manifest.len() != len {
+            return Err(serde::de::Error::custom(
+                "type manifest contains duplicate type paths",
+            ));
+        }
+        Ok(manifest)
+    }
+}
@@ -325,6 +325,123 @@ impl DynamicStruct {
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
     }
+
+    /// Returns a [`DynamicStructBuilder`] for fluently constructing a `DynamicStruct`.
+    ///
+    /// See also the [`dyn_struct!`](crate::dyn_struct) macro.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{DynamicStruct, Struct};
+    /// let dyn_struct = DynamicStruct::builder()
+    ///     .field("x", 1.0f32)
+    ///     .field("y", 2.0f32)
+    ///     .build();
+    ///
+    /// assert_eq!(dyn_struct.field_len(), 2);
+    /// assert_eq!(dyn_struct.get_field::<f32>("y"), Some(&2.0));
+    /// ```
+    pub fn builder() -> DynamicStructBuilder {
+        DynamicStructBuilder::default()
+    }
+}
+
+/// A builder for [`DynamicStruct`].
+///
+/// Created with [`DynamicStruct::builder`].
+#[derive(Default)]
+pub struct DynamicStructBuilder {
+    dynamic: DynamicStruct,
+}
+
+impl DynamicStructBuilder {
+    /// Sets the [type] to be represented by the built `DynamicStruct`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given [type] is not a [`TypeInfo::Struct`].
+    ///
+    /// [type]: TypeInfo
+    pub fn represented_type(mut self, represented_type: &'static TypeInfo) -> Self {
+        self.dynamic.set_represented_type(Some(represented_type));
+        self
+    }
+
+    /// Adds a field named `name` with the typed value `value`.
+    ///
+    /// If the field already exists, it is overwritten.
+    pub fn field<'a, T: PartialReflect>(mut self, name: impl Into<Cow<'a, str>>, value: T) -> Self {
+        self.dynamic.insert(name, value);
+        self
+    }
+
+    /// Adds a field named `name` with the boxed value `value`.
+    ///
+    /// If the field already exists, it is overwritten.
+    pub fn field_boxed<'a>(
+        mut self,
+        name: impl Into<Cow<'a, str>>,
+        value: Box<dyn PartialReflect>,
+    ) -> Self {
+        self.dynamic.insert_boxed(name, value);
+        self
+    }
+
+    /// Returns the built [`DynamicStruct`].
+    pub fn build(self) -> DynamicStruct {
+        self.dynamic
+    }
+}
+
+/// Creates a [`DynamicStruct`] from a list of named fields.
+///
+/// Field values may be any expression whose type implements [`PartialReflect`],
+/// including other dynamic values.
+/// Prefixing the fields with a type implementing [`Typed`](crate::Typed)
+/// also sets the represented type of the `DynamicStruct`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_struct, FromReflect, Reflect, Struct};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     position: (f32, f32),
+/// }
+///
+/// let untyped = dyn_struct! {
+///     name: String::from("Ferris"),
+///     position: (1.0f32, 2.0f32),
+/// };
+/// assert!(untyped.get_represented_type_info().is_none());
+///
+/// let typed = dyn_struct!(Player {
+///     name: String::from("Ferris"),
+///     position: (1.0f32, 2.0f32),
+/// });
+/// assert_eq!(
+///     Player::from_reflect(&typed),
+///     Some(Player {
+///         name: String::from("Ferris"),
+///         position: (1.0, 2.0),
+///     })
+/// );
+/// ```
+#[macro_export]
+macro_rules! dyn_struct {
+    ($($name:ident : $value:expr),* $(,)?) => {
+        $crate::DynamicStruct::builder()
+            $(.field(::core::stringify!($name), $value))*
+            .build()
+    };
+    ($ty:path { $($name:ident : $value:expr),* $(,)? }) => {
+        $crate::DynamicStruct::builder()
+            .represented_type(<$ty as $crate::Typed>::type_info())
+            $(.field(::core::stringify!($name), $value))*
+            .build()
+    };
 }
 
 impl Struct for DynamicStruct {
@@ -587,6 +704,8 @@ pub fn struct_debug(dyn_struct: &dyn Struct, f: &mut Formatter<'_>) -> core::fmt
 mod tests {
     use crate as bevy_reflect;
     use crate::*;
+    use alloc::string::String;
+
     #[derive(Reflect, Default)]
     struct MyStruct {
         a: (),
@@ -609,4 +728,48 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(prev_index, iter.index);
     }
+
+    #[test]
+    fn should_build_dynamic_struct() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo {
+            a: i32,
+            b: Bar,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Bar {
+            c: String,
+        }
+
+        let built = DynamicStruct::builder()
+            .represented_type(Foo::type_info())
+            .field("a", 123)
+            .field(
+                "b",
+                DynamicStruct::builder()
+                    .field("c", String::from("hello"))
+                    .build(),
+            )
+            .build();
+        let from_macro = dyn_struct!(Foo {
+            a: 123,
+            b: dyn_struct! { c: String::from("hello") },
+        });
+
+        let expected = Foo {
+            a: 123,
+            b: Bar {
+                c: String::from("hello"),
+            },
+        };
+        assert_eq!(Foo::from_reflect(&built), Some(expected));
+        assert!(built.reflect_partial_eq(&from_macro).unwrap());
+        assert_eq!(
+            from_macro
+                .get_represented_type_info()
+                .map(TypeInfo::type_path),
+            Some(Foo::type_path())
+        );
+    }
 }
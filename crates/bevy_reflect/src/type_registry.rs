@@ -1,5 +1,8 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
-use alloc::{boxed::Box, string::String};
+use crate::{
+    serde::{MapKeyEncoding, Serializable},
+    FromReflect, Reflect, RegistryManifest, RegistryManifestDiff, TypeInfo, TypePath, Typed,
+};
+use alloc::{borrow::Cow, boxed::Box, format, string::String, vec, vec::Vec};
 use bevy_platform_support::{
     collections::{HashMap, HashSet},
     sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
@@ -31,6 +34,9 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    type_path_collisions: HashMap<&'static str, Vec<TypeId>>,
+    namespace_by_crate_version: bool,
+    map_key_encoding: MapKeyEncoding,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -93,6 +99,9 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            type_path_collisions: Default::default(),
+            namespace_by_crate_version: false,
+            map_key_encoding: MapKeyEncoding::Native,
         }
     }
 
@@ -196,6 +205,7 @@ impl TypeRegistry {
             &mut self.short_path_to_id,
             &mut self.type_path_to_id,
             &mut self.ambiguous_names,
+            &mut self.type_path_collisions,
         );
         self.registrations
             .insert(registration.type_id(), registration);
@@ -224,6 +234,7 @@ impl TypeRegistry {
                     &mut self.short_path_to_id,
                     &mut self.type_path_to_id,
                     &mut self.ambiguous_names,
+                    &mut self.type_path_collisions,
                 );
                 entry.insert(registration);
                 true
@@ -237,6 +248,7 @@ impl TypeRegistry {
         short_path_to_id: &mut HashMap<&'static str, TypeId>,
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
+        type_path_collisions: &mut HashMap<&'static str, Vec<TypeId>>,
     ) {
         let short_name = registration.type_info().type_path_table().short_path();
         if short_path_to_id.contains_key(short_name) || ambiguous_names.contains(short_name) {
@@ -246,7 +258,19 @@ impl TypeRegistry {
         } else {
             short_path_to_id.insert(short_name, registration.type_id());
         }
-        type_path_to_id.insert(registration.type_info().type_path(), registration.type_id());
+        let type_path = registration.type_info().type_path();
+        let type_id = registration.type_id();
+        if let Some(existing) = type_path_to_id.insert(type_path, type_id) {
+            if existing != type_id {
+                // usually caused by multiple versions of the same crate
+                let type_ids = type_path_collisions
+                    .entry(type_path)
+                    .or_insert_with(|| vec![existing]);
+                if !type_ids.contains(&type_id) {
+                    type_ids.push(type_id);
+                }
+            }
+        }
     }
 
     /// Registers the type data `D` for type `T`.
@@ -377,6 +401,162 @@ impl TypeRegistry {
         self.ambiguous_names.contains(short_type_path)
     }
 
+    /// Returns an iterator over the [type paths] which are shared by multiple registered types.
+    ///
+    /// This usually happens when multiple versions of the same crate are in the dependency tree,
+    /// since each version's types have the same type path but a different [`TypeId`].
+    /// Looking up a registration with [`get_with_type_path`] returns the type which was
+    /// registered last, so data referring to one of these types by type path may be
+    /// deserialized as the wrong type.
+    ///
+    /// See [`set_namespace_by_crate_version`] for a way to disambiguate these types.
+    ///
+    /// [type paths]: TypePath::type_path
+    /// [`get_with_type_path`]: Self::get_with_type_path
+    /// [`set_namespace_by_crate_version`]: Self::set_namespace_by_crate_version
+    pub fn ambiguities(&self) -> impl Iterator<Item = TypePathAmbiguity<'_>> {
+        self.type_path_collisions
+            .iter()
+            .map(|(type_path, type_ids)| TypePathAmbiguity {
+                type_path,
+                type_ids,
+                registry: self,
+            })
+    }
+
+    /// Returns `true` if the given [type path] is shared by multiple registered types.
+    ///
+    /// See [`ambiguities`](Self::ambiguities) for more information.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn is_type_path_ambiguous(&self, type_path: &str) -> bool {
+        self.type_path_collisions.contains_key(type_path)
+    }
+
+    /// Returns `true` if types with [ambiguous] type paths are namespaced by the version of
+    /// their crate when serialized.
+    ///
+    /// See [`set_namespace_by_crate_version`](Self::set_namespace_by_crate_version).
+    ///
+    /// [ambiguous]: Self::ambiguities
+    pub fn namespace_by_crate_version(&self) -> bool {
+        self.namespace_by_crate_version
+    }
+
+    /// Sets whether types with [ambiguous] type paths are namespaced by the version of
+    /// their crate when serialized.
+    ///
+    /// When enabled, [`serialized_type_path`] appends `@` followed by the
+    /// [crate version] to the type paths of these types, such as `my_crate::Foo@0.2.0`,
+    /// which [`get_with_versioned_type_path`] can then use to find the right type.
+    /// Types which aren't ambiguous are unaffected.
+    ///
+    /// Disabled by default.
+    ///
+    /// [ambiguous]: Self::ambiguities
+    /// [`serialized_type_path`]: Self::serialized_type_path
+    /// [crate version]: TypeRegistration::crate_version
+    /// [`get_with_versioned_type_path`]: Self::get_with_versioned_type_path
+    pub fn set_namespace_by_crate_version(&mut self, enabled: bool) {
+        self.namespace_by_crate_version = enabled;
+    }
+
+    /// Returns the default [`MapKeyEncoding`] used by the reflection serializers for maps.
+    ///
+    /// See [`set_map_key_encoding`](Self::set_map_key_encoding).
+    pub fn map_key_encoding(&self) -> MapKeyEncoding {
+        self.map_key_encoding
+    }
+
+    /// Sets the default [`MapKeyEncoding`] used by the reflection serializers for maps.
+    ///
+    /// Map types which register [`MapKeyEncoding`] as type data use that encoding instead.
+    ///
+    /// Defaults to [`MapKeyEncoding::Native`].
+    pub fn set_map_key_encoding(&mut self, encoding: MapKeyEncoding) {
+        self.map_key_encoding = encoding;
+    }
+
+    /// Returns the [`MapKeyEncoding`] used to (de)serialize the map type with the given [`TypeId`].
+    ///
+    /// This is the encoding registered as type data of the map type, if any, or the
+    /// [default encoding](Self::map_key_encoding) otherwise.
+    pub fn map_key_encoding_of(&self, type_id: TypeId) -> MapKeyEncoding {
+        self.get_type_data::<MapKeyEncoding>(type_id)
+            .copied()
+            .unwrap_or(self.map_key_encoding)
+    }
+
+    /// Returns a [`RegistryManifest`] describing the layout of every registered type.
+    pub fn manifest(&self) -> RegistryManifest {
+        RegistryManifest::new(self)
+    }
+
+    /// Returns a stable hash of the layout of every registered type.
+    ///
+    /// Registries with the same fingerprint have the same registered types, with the same
+    /// fields and variants. See [`RegistryManifest::fingerprint`].
+    pub fn fingerprint(&self) -> u64 {
+        self.manifest().fingerprint()
+    }
+
+    /// Returns the differences between the types registered in this registry and the types
+    /// described by `other`, such as the manifest of a registry in another process.
+    ///
+    /// See [`RegistryManifest::diff`].
+    pub fn diff(&self, other: &RegistryManifest) -> RegistryManifestDiff {
+        self.manifest().diff(other)
+    }
+
+    /// Returns the type path which should be used to identify the given type in serialized data.
+    ///
+    /// This is the type's [type path], followed by `@` and its [crate version] if
+    /// [namespacing by crate version] is enabled and the type path is [ambiguous].
+    ///
+    /// [type path]: TypePath::type_path
+    /// [crate version]: TypeRegistration::crate_version
+    /// [namespacing by crate version]: Self::set_namespace_by_crate_version
+    /// [ambiguous]: Self::ambiguities
+    pub fn serialized_type_path(&self, type_info: &'static TypeInfo) -> Cow<'static, str> {
+        let type_path = type_info.type_path();
+        if self.namespace_by_crate_version && self.is_type_path_ambiguous(type_path) {
+            if let Some(version) = self
+                .get(type_info.type_id())
+                .and_then(TypeRegistration::crate_version)
+            {
+                return Cow::Owned(format!("{type_path}@{version}"));
+            }
+        }
+        Cow::Borrowed(type_path)
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the given
+    /// [serialized type path], which may be namespaced by crate version.
+    ///
+    /// If the path is `my_crate::Foo@0.2.0` and multiple types have the type path
+    /// `my_crate::Foo`, this returns the one whose [crate version] is `0.2.0`.
+    /// If only one type has that type path, it's returned regardless of its version,
+    /// so data from other versions of a crate can still be loaded.
+    ///
+    /// Paths without a version are looked up with [`get_with_type_path`].
+    ///
+    /// [serialized type path]: Self::serialized_type_path
+    /// [crate version]: TypeRegistration::crate_version
+    /// [`get_with_type_path`]: Self::get_with_type_path
+    pub fn get_with_versioned_type_path(&self, path: &str) -> Option<&TypeRegistration> {
+        let Some((type_path, version)) = path.rsplit_once('@') else {
+            return self.get_with_type_path(path);
+        };
+
+        match self.type_path_collisions.get(type_path) {
+            Some(type_ids) => type_ids
+                .iter()
+                .filter_map(|type_id| self.get(*type_id))
+                .find(|registration| registration.crate_version() == Some(version)),
+            None => self.get_with_type_path(type_path),
+        }
+    }
+
     /// Returns a reference to the [`TypeData`] of type `T` associated with the given [`TypeId`].
     ///
     /// The returned value may be used to downcast [`Reflect`] trait objects to
@@ -419,6 +599,52 @@ impl TypeRegistry {
         self.registrations.values_mut()
     }
 
+    /// Returns an iterator over the [`TypeRegistration`]s of the registered types whose
+    /// [type path] matches the given glob pattern.
+    ///
+    /// In the pattern:
+    /// - `*` matches any sequence of characters within a single path segment, i.e. not `:`
+    /// - `**` matches any sequence of characters, including across path segments
+    /// - `?` matches any single character other than `:`
+    ///
+    /// All other characters match themselves. Note that the generic arguments of a type are
+    /// part of its type path, so `my_crate::*` matches `my_crate::Foo<u32>`
+    /// but not `my_crate::Foo<alloc::string::String>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// mod components {
+    /// #   use bevy_reflect::Reflect;
+    ///     #[derive(Reflect)]
+    ///     pub struct Health(pub f32);
+    ///
+    ///     pub mod player {
+    /// #       use bevy_reflect::Reflect;
+    ///         #[derive(Reflect)]
+    ///         pub struct Player;
+    ///     }
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::empty();
+    /// registry.register::<components::Health>();
+    /// registry.register::<components::player::Player>();
+    ///
+    /// let shallow: Vec<_> = registry.find("*::components::*").collect();
+    /// assert_eq!(shallow.len(), 1);
+    ///
+    /// let deep: Vec<_> = registry.find("*::components::**").collect();
+    /// assert_eq!(deep.len(), 2);
+    /// ```
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn find<'a>(&'a self, glob: &'a str) -> impl Iterator<Item = &'a TypeRegistration> + 'a {
+        self.registrations
+            .values()
+            .filter(move |registration| matches_glob(glob, registration.type_info().type_path()))
+    }
+
     /// Checks to see if the [`TypeData`] of type `T` is associated with each registered type,
     /// returning a ([`TypeRegistration`], [`TypeData`]) iterator for all entries where data of that type was found.
     pub fn iter_with_data<T: TypeData>(&self) -> impl Iterator<Item = (&TypeRegistration, &T)> {
@@ -429,6 +655,111 @@ impl TypeRegistry {
     }
 }
 
+/// Returns `true` if `path` matches the glob `pattern`, as described in [`TypeRegistry::find`].
+fn matches_glob(pattern: &str, path: &str) -> bool {
+    let next_char_len = |index: usize| path[index..].chars().next().map_or(1, char::len_utf8);
+    let (pattern, bytes) = (pattern.as_bytes(), path.as_bytes());
+    let (mut p, mut t) = (0, 0);
+    // The positions in `pattern` and `path` to resume from after a mismatch,
+    // for the last `**` and for the last `*` which follows it.
+    let mut any_star: Option<(usize, usize)> = None;
+    let mut segment_star: Option<(usize, usize)> = None;
+
+    while t < bytes.len() {
+        if pattern[p..].starts_with(b"**") {
+            p += 2;
+            any_star = Some((p, t));
+            segment_star = None;
+        } else if pattern.get(p) == Some(&b'*') {
+            p += 1;
+            segment_star = Some((p, t));
+        } else if pattern.get(p) == Some(&b'?') && bytes[t] != b':' {
+            p += 1;
+            t += next_char_len(t);
+        } else if pattern.get(p) == Some(&bytes[t]) {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) =
+            segment_star.filter(|&(_, star_t)| bytes[star_t] != b':')
+        {
+            // A `*` can't extend past the end of a path segment.
+            t = star_t + next_char_len(star_t);
+            p = star_p;
+            segment_star = Some((p, t));
+        } else if let Some((star_p, star_t)) = any_star {
+            t = star_t + next_char_len(star_t);
+            p = star_p;
+            any_star = Some((p, t));
+            segment_star = None;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// A [type path] which is shared by multiple registered types,
+/// as returned by [`TypeRegistry::ambiguities`].
+///
+/// [type path]: TypePath::type_path
+#[derive(Clone, Copy)]
+pub struct TypePathAmbiguity<'a> {
+    type_path: &'static str,
+    type_ids: &'a [TypeId],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> TypePathAmbiguity<'a> {
+    /// Returns the type path shared by the types.
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// Returns the [`TypeId`]s of the types, in the order they were registered.
+    pub fn type_ids(&self) -> &'a [TypeId] {
+        self.type_ids
+    }
+
+    /// Returns an iterator over the [`TypeRegistration`]s of the types,
+    /// in the order they were registered.
+    pub fn registrations(&self) -> impl Iterator<Item = &'a TypeRegistration> + 'a {
+        let registry = self.registry;
+        self.type_ids
+            .iter()
+            .filter_map(move |type_id| registry.get(*type_id))
+    }
+
+    /// Returns `true` if every type has a different [crate version],
+    /// so the types can be disambiguated when [namespacing by crate version].
+    ///
+    /// [crate version]: TypeRegistration::crate_version
+    /// [namespacing by crate version]: TypeRegistry::set_namespace_by_crate_version
+    pub fn is_resolvable_by_crate_version(&self) -> bool {
+        let mut versions = HashSet::<&str>::default();
+        self.registrations().all(|registration| {
+            registration
+                .crate_version()
+                .is_some_and(|version| versions.insert(version))
+        })
+    }
+}
+
+impl Debug for TypePathAmbiguity<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypePathAmbiguity")
+            .field("type_path", &self.type_path)
+            .field(
+                "crate_versions",
+                &self
+                    .registrations()
+                    .map(TypeRegistration::crate_version)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
 impl TypeRegistryArc {
     /// Takes a read lock on the underlying [`TypeRegistry`].
     pub fn read(&self) -> RwLockReadGuard<'_, TypeRegistry> {
@@ -471,6 +802,7 @@ impl TypeRegistryArc {
 pub struct TypeRegistration {
     data: TypeIdMap<Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    crate_version: Option<&'static str>,
 }
 
 impl Debug for TypeRegistration {
@@ -487,6 +819,7 @@ impl TypeRegistration {
         Self {
             data: Default::default(),
             type_info: T::type_info(),
+            crate_version: None,
         }
     }
 
@@ -501,6 +834,28 @@ impl TypeRegistration {
         self.type_info
     }
 
+    /// Returns the version of the crate which defines the type, if known.
+    ///
+    /// This is set automatically by `#[derive(Reflect)]`, and is used to disambiguate types
+    /// which share a [type path] when [namespacing by crate version].
+    ///
+    /// The derive reads the version from the `CARGO_PKG_VERSION` environment variable at
+    /// compile time, so this is `None` for types in crates which aren't built by Cargo,
+    /// unless it's set manually with [`set_crate_version`](Self::set_crate_version).
+    ///
+    /// [type path]: TypePath::type_path
+    /// [namespacing by crate version]: TypeRegistry::set_namespace_by_crate_version
+    pub fn crate_version(&self) -> Option<&'static str> {
+        self.crate_version
+    }
+
+    /// Sets the version of the crate which defines the type.
+    ///
+    /// See [`crate_version`](Self::crate_version).
+    pub fn set_crate_version(&mut self, crate_version: Option<&'static str>) {
+        self.crate_version = crate_version;
+    }
+
     /// Inserts an instance of `T` into this registration's [type data].
     ///
     /// If another instance of `T` was previously inserted, it is replaced.
@@ -631,6 +986,7 @@ impl Clone for TypeRegistration {
         TypeRegistration {
             data,
             type_info: self.type_info,
+            crate_version: self.crate_version,
         }
     }
 }
@@ -949,4 +1305,203 @@ mod test {
         let data = registration.data::<DataA>().unwrap();
         assert_eq!(data.0, 456);
     }
+
+    #[test]
+    fn find_by_glob() {
+        use alloc::vec::Vec;
+
+        #[derive(Reflect)]
+        struct Foo;
+
+        #[derive(Reflect)]
+        struct Bar<T>(T);
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+        registry.register::<Bar<u32>>();
+
+        let find = |glob| {
+            let mut paths: Vec<_> = registry
+                .find(glob)
+                .map(|registration| registration.type_info().type_path())
+                .collect();
+            paths.sort();
+            paths
+        };
+
+        assert_eq!(
+            find("bevy_reflect::type_registry::test::*"),
+            [
+                "bevy_reflect::type_registry::test::Bar<u32>",
+                "bevy_reflect::type_registry::test::Foo"
+            ]
+        );
+        assert_eq!(find("**::F?o"), ["bevy_reflect::type_registry::test::Foo"]);
+        assert_eq!(
+            find("**<u32>"),
+            ["bevy_reflect::type_registry::test::Bar<u32>"]
+        );
+        assert!(find("bevy_reflect::*").is_empty());
+        assert_eq!(find("bevy_reflect::**").len(), 2);
+        assert_eq!(find("bevy_reflect::type_registry::test::Foo").len(), 1);
+    }
+
+    #[test]
+    fn glob_matching() {
+        use super::matches_glob;
+
+        assert!(matches_glob("a::*::c", "a::b::c"));
+        assert!(!matches_glob("a::*::c", "a::b::d::c"));
+        assert!(matches_glob("a::**::c", "a::b::d::c"));
+        assert!(matches_glob("**::*c", "a::b::abc"));
+        assert!(!matches_glob("*b", "a::b"));
+        assert!(matches_glob("a*b*c", "aXbYbZc"));
+        assert!(!matches_glob("a?c", "a:c"));
+        assert!(matches_glob("é?::*", "éü::x"));
+        assert!(matches_glob("**", ""));
+        assert!(!matches_glob("?", ""));
+
+        // Would take exponential time with naive backtracking.
+        let path = "a".repeat(64);
+        assert!(!matches_glob(&("*a".repeat(32) + "b"), &path));
+        assert!(!matches_glob(&("**a".repeat(32) + "b"), &path));
+    }
+
+    #[test]
+    fn type_path_ambiguities() {
+        mod v1 {
+            use crate as bevy_reflect;
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_crate"]
+            pub struct Foo;
+        }
+
+        mod v2 {
+            use crate as bevy_reflect;
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_crate"]
+            pub struct Foo;
+        }
+
+        let mut registry = TypeRegistry::empty();
+        for (mut registration, version) in [
+            (v1::Foo::get_type_registration(), "0.1.0"),
+            (v2::Foo::get_type_registration(), "0.2.0"),
+        ] {
+            registration.set_crate_version(Some(version));
+            registry.add_registration(registration);
+        }
+        registry.register::<u32>();
+
+        let ambiguities = registry.ambiguities().collect::<Vec<_>>();
+        assert_eq!(ambiguities.len(), 1);
+        assert_eq!(ambiguities[0].type_path(), "my_crate::Foo");
+        assert_eq!(
+            ambiguities[0].type_ids(),
+            [TypeId::of::<v1::Foo>(), TypeId::of::<v2::Foo>()]
+        );
+        assert!(ambiguities[0].is_resolvable_by_crate_version());
+        assert!(registry.is_type_path_ambiguous("my_crate::Foo"));
+        assert!(!registry.is_type_path_ambiguous("u32"));
+
+        let v1_info = v1::Foo::type_info();
+        assert_eq!(registry.serialized_type_path(v1_info), "my_crate::Foo");
+        registry.set_namespace_by_crate_version(true);
+        assert_eq!(
+            registry.serialized_type_path(v1_info),
+            "my_crate::Foo@0.1.0"
+        );
+        assert_eq!(registry.serialized_type_path(u32::type_info()), "u32");
+
+        let get = |path| {
+            registry
+                .get_with_versioned_type_path(path)
+                .map(TypeRegistration::type_id)
+        };
+        assert_eq!(get("my_crate::Foo@0.1.0"), Some(TypeId::of::<v1::Foo>()));
+        assert_eq!(get("my_crate::Foo@0.2.0"), Some(TypeId::of::<v2::Foo>()));
+        assert_eq!(get("my_crate::Foo@0.3.0"), None);
+        assert_eq!(get("u32@1.0.0"), Some(TypeId::of::<u32>()));
+        assert_eq!(get("u32"), Some(TypeId::of::<u32>()));
+    }
+
+    #[test]
+    fn manifest_should_detect_layout_changes() {
+        mod server {
+            use crate as bevy_reflect;
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_game"]
+            pub struct Health(pub f32);
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_game"]
+            pub enum Team {
+                Red,
+                Blue,
+            }
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_game"]
+            pub struct Score(pub u32);
+        }
+
+        mod client {
+            use crate as bevy_reflect;
+            use alloc::string::String;
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_game"]
+            pub struct Health(pub f32);
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_game"]
+            pub enum Team {
+                Red,
+                Blue,
+                Green,
+            }
+
+            #[derive(bevy_reflect::Reflect)]
+            #[type_path = "my_game"]
+            pub struct Name(pub String);
+        }
+
+        let mut server = TypeRegistry::empty();
+        server.register::<server::Health>();
+        server.register::<server::Team>();
+        server.register::<server::Score>();
+
+        let mut client = TypeRegistry::empty();
+        client.register::<client::Health>();
+        client.register::<client::Team>();
+        client.register::<client::Name>();
+
+        let server_manifest = server.manifest();
+        assert_eq!(
+            server_manifest.layout("my_game::Health"),
+            Some("tuple struct (f32)")
+        );
+        assert_eq!(
+            server_manifest.layout("my_game::Team"),
+            Some("enum { Red, Blue }")
+        );
+        assert_eq!(server.fingerprint(), server_manifest.fingerprint());
+        assert_ne!(server.fingerprint(), client.fingerprint());
+
+        let diff = client.diff(&server_manifest);
+        // Field types are registered too
+        assert_eq!(diff.added, ["my_game::Score", "u32"]);
+        assert_eq!(diff.removed, ["alloc::string::String", "my_game::Name"]);
+        assert_eq!(diff.changed, ["my_game::Team"]);
+
+        let mut server_copy = TypeRegistry::empty();
+        server_copy.register::<server::Team>();
+        server_copy.register::<server::Score>();
+        server_copy.register::<server::Health>();
+        assert_eq!(server_copy.fingerprint(), server.fingerprint());
+        assert!(server_copy.diff(&server_manifest).is_empty());
+    }
 }
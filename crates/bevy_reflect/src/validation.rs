@@ -0,0 +1,822 @@
+//! Validation of reflected values.
+//!
+//! Fields of types deriving [`Reflect`] can be given validation functions using the
+//! `#[reflect(validate = ...)]` attribute. The expression must evaluate to a function
+//! or closure taking a reference to the field's type and returning a
+//! `Result<(), ValidationError>`:
+//!
+//! ```
+//! # use bevy_reflect::{Reflect, PartialReflect, TypeRegistry};
+//! # use bevy_reflect::validation::{in_range, non_empty, ValidationError};
+//! #[derive(Reflect)]
+//! struct Player {
+//!     #[reflect(validate = non_empty)]
+//!     name: String,
+//!     #[reflect(validate = in_range(0.0..=100.0))]
+//!     health: f32,
+//!     #[reflect(validate = is_even)]
+//!     score: u32,
+//! }
+//!
+//! fn is_even(value: &u32) -> Result<(), ValidationError> {
+//!     if value % 2 == 0 {
+//!         Ok(())
+//!     } else {
+//!         Err(ValidationError::new("expected an even number"))
+//!     }
+//! }
+//!
+//! let mut registry = TypeRegistry::new();
+//! registry.register::<Player>();
+//!
+//! let player = Player { name: "Bevy".into(), health: 150.0, score: 2 };
+//! let error = player.validate(&registry).unwrap_err();
+//! assert_eq!(error.path(), ".health");
+//! ```
+//!
+//! The validators are stored in the [`ReflectValidate`] type data, which is run by
+//! [`PartialReflect::validate`] and the [`TypedReflectDeserializer`].
+//!
+//! # Field attributes
+//!
+//! Custom field attributes, added with `#[reflect(@...)]`, can also check deserialized values
+//! by implementing [`FieldAttribute`] and registering its [`ReflectFieldAttribute`] type data.
+//! Unlike validators, they can repair invalid values instead of rejecting them,
+//! such as [`Bounds`] clamping numbers into its range:
+//!
+//! ```
+//! # use bevy_reflect::{Reflect, PartialReflect, FromReflect, TypeRegistry};
+//! # use bevy_reflect::serde::TypedReflectDeserializer;
+//! # use bevy_reflect::validation::Bounds;
+//! # use serde::de::DeserializeSeed;
+//! #[derive(Reflect, PartialEq, Debug)]
+//! struct Light {
+//!     #[reflect(@Bounds::new(0.0, 1.0).clamped())]
+//!     intensity: f32,
+//! }
+//!
+//! let mut registry = TypeRegistry::new();
+//! registry.register::<Light>();
+//! registry.register::<Bounds>();
+//!
+//! let mut deserializer = ron::de::Deserializer::from_str("(intensity: 2.5)").unwrap();
+//! let value = TypedReflectDeserializer::of::<Light>(&registry)
+//!     .deserialize(&mut deserializer)
+//!     .unwrap();
+//! assert_eq!(Light::from_reflect(&*value), Some(Light { intensity: 1.0 }));
+//! ```
+//!
+//! [`Reflect`]: crate::Reflect
+//! [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+
+use crate::{
+    self as bevy_reflect, attributes::CustomAttributes, enums::VariantField, FromReflect, FromType,
+    PartialReflect, Reflect, ReflectMut, ReflectRef, TypeInfo, TypePath, TypeRegistry, VariantInfo,
+};
+use alloc::{borrow::Cow, format, string::String, sync::Arc, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    ops::RangeBounds,
+};
+
+/// An error returned when a reflected value fails validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    path: String,
+    message: Cow<'static, str>,
+}
+
+impl ValidationError {
+    /// Creates a new validation error with the given message.
+    pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            path: String::new(),
+            message: message.into(),
+        }
+    }
+
+    /// The [path] of the invalid value, relative to the value that was validated.
+    ///
+    /// This is empty if the validated value itself is invalid.
+    ///
+    /// [path]: crate::ReflectPath
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The message describing why the value is invalid.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Prepends the given path segment, such as `.field` or `[0]`, to the path of this error.
+    pub fn with_parent(mut self, segment: &str) -> Self {
+        self.path.insert_str(0, segment);
+        self
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "`{}`: {}", self.path, self.message)
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// The field of a struct, tuple struct or enum variant that a [`FieldValidator`] checks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidatedField {
+    /// A named field of a struct or struct variant.
+    Named(&'static str),
+    /// A field of a tuple struct or tuple variant, by its index.
+    Index(usize),
+}
+
+impl ValidatedField {
+    fn path_segment(&self) -> String {
+        match self {
+            Self::Named(name) => format!(".{name}"),
+            Self::Index(index) => format!(".{index}"),
+        }
+    }
+}
+
+/// A validation function attached to a single field of a type.
+///
+/// These are usually created with the `#[reflect(validate = ...)]` field attribute.
+#[derive(Clone)]
+pub struct FieldValidator {
+    variant: Option<&'static str>,
+    field: ValidatedField,
+    validate: Arc<dyn Fn(&dyn PartialReflect) -> Result<(), ValidationError> + Send + Sync>,
+}
+
+impl FieldValidator {
+    /// Creates a validator that runs `validate` on the given field of type `T`.
+    ///
+    /// If the field isn't an instance of `T`, such as when it was deserialized into a
+    /// dynamic value, it is converted with [`FromReflect`] first.
+    pub fn new<T, F>(field: ValidatedField, validate: F) -> Self
+    where
+        T: FromReflect + TypePath,
+        F: Fn(&T) -> Result<(), ValidationError> + Send + Sync + 'static,
+    {
+        Self {
+            variant: None,
+            field,
+            validate: Arc::new(move |value| {
+                if let Some(value) = value.try_downcast_ref::<T>() {
+                    return validate(value);
+                }
+
+                match T::from_reflect(value) {
+                    Some(value) => validate(&value),
+                    None => Err(ValidationError::new(format!(
+                        "expected a value of type `{}`",
+                        T::type_path()
+                    ))),
+                }
+            }),
+        }
+    }
+
+    /// Restricts this validator to the field of the given enum variant.
+    pub fn in_variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// The name of the enum variant containing the validated field, if any.
+    pub fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+
+    /// The validated field.
+    pub fn field(&self) -> ValidatedField {
+        self.field
+    }
+
+    /// Validates the field of the given struct, tuple struct or enum.
+    ///
+    /// Succeeds if `value` doesn't contain the field, such as when it's an enum
+    /// set to a different variant.
+    pub fn validate(&self, value: &dyn PartialReflect) -> Result<(), ValidationError> {
+        let field = match (value.reflect_ref(), self.field, self.variant) {
+            (ReflectRef::Struct(value), ValidatedField::Named(name), None) => value.field(name),
+            (ReflectRef::TupleStruct(value), ValidatedField::Index(index), None) => {
+                value.field(index)
+            }
+            (ReflectRef::Enum(value), ValidatedField::Named(name), Some(variant))
+                if value.variant_name() == variant =>
+            {
+                value.field(name)
+            }
+            (ReflectRef::Enum(value), ValidatedField::Index(index), Some(variant))
+                if value.variant_name() == variant =>
+            {
+                value.field_at(index)
+            }
+            _ => None,
+        };
+
+        match field {
+            Some(field) => {
+                (self.validate)(field).map_err(|err| err.with_parent(&self.field.path_segment()))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Debug for FieldValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldValidator")
+            .field("variant", &self.variant)
+            .field("field", &self.field)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Type data containing the [`FieldValidator`]s of a type.
+///
+/// This is registered by the `#[reflect(validate = ...)]` field attribute.
+/// More validators can be added at runtime by getting this type data mutably from the
+/// [`TypeRegistry`].
+#[derive(Clone, Debug, Default)]
+pub struct ReflectValidate {
+    validators: Vec<FieldValidator>,
+}
+
+impl ReflectValidate {
+    /// Creates type data from the given validators.
+    pub fn new(validators: impl IntoIterator<Item = FieldValidator>) -> Self {
+        Self {
+            validators: validators.into_iter().collect(),
+        }
+    }
+
+    /// Adds a validator.
+    pub fn push(&mut self, validator: FieldValidator) {
+        self.validators.push(validator);
+    }
+
+    /// The validators of the type.
+    pub fn validators(&self) -> &[FieldValidator] {
+        &self.validators
+    }
+
+    /// Runs all validators on the fields of the given value, returning the first error.
+    ///
+    /// This doesn't validate values nested inside the fields; use
+    /// [`PartialReflect::validate`] for that.
+    pub fn validate(&self, value: &dyn PartialReflect) -> Result<(), ValidationError> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator.validate(value))
+    }
+}
+
+/// Validates a value and everything nested inside it using the [`ReflectValidate`]
+/// type data in the registry.
+pub(crate) fn validate(
+    value: &dyn PartialReflect,
+    registry: &TypeRegistry,
+) -> Result<(), ValidationError> {
+    if let Some(type_info) = value.get_represented_type_info() {
+        if let Some(validate) = registry.get_type_data::<ReflectValidate>(type_info.type_id()) {
+            validate.validate(value)?;
+        }
+    }
+
+    let validate_child = |child: &dyn PartialReflect, segment: &dyn Fn() -> String| {
+        validate(child, registry).map_err(|err| err.with_parent(&segment()))
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                validate_child(field, &|| format!(".{}", value.name_at(index).unwrap()))?;
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                validate_child(field, &|| format!(".{index}"))?;
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                validate_child(field, &|| format!(".{index}"))?;
+            }
+        }
+        ReflectRef::List(value) => {
+            for (index, item) in value.iter().enumerate() {
+                validate_child(item, &|| format!("[{index}]"))?;
+            }
+        }
+        ReflectRef::Array(value) => {
+            for (index, item) in value.iter().enumerate() {
+                validate_child(item, &|| format!("[{index}]"))?;
+            }
+        }
+        ReflectRef::Map(value) => {
+            for (key, item) in value.iter() {
+                validate_child(key, &|| format!("[{key:?}]"))?;
+                validate_child(item, &|| format!("[{key:?}]"))?;
+            }
+        }
+        ReflectRef::Set(value) => {
+            for item in value.iter() {
+                validate_child(item, &|| format!("[{item:?}]"))?;
+            }
+        }
+        ReflectRef::Enum(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                match field {
+                    VariantField::Struct(name, field) => {
+                        validate_child(field, &|| format!(".{name}"))?;
+                    }
+                    VariantField::Tuple(field) => {
+                        validate_child(field, &|| format!(".{index}"))?;
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "functions")]
+        ReflectRef::Function(_) => {}
+        ReflectRef::Opaque(_) => {}
+    }
+
+    Ok(())
+}
+
+/// A custom field attribute that checks, and possibly repairs, the values of the fields
+/// it's attached to when they're deserialized.
+///
+/// The attribute type must register [`ReflectFieldAttribute`] in the [`TypeRegistry`], usually
+/// with `#[reflect(FieldAttribute)]`, for the [`TypedReflectDeserializer`] to find it.
+/// See [the module docs](self#field-attributes) for more info.
+///
+/// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
+pub trait FieldAttribute: Reflect {
+    /// Checks the deserialized `value` of a field with this attribute.
+    ///
+    /// The value may be modified in place to repair it, or an error returned to reject it.
+    fn apply(&self, value: &mut dyn PartialReflect) -> Result<(), ValidationError>;
+}
+
+/// Type data for [`FieldAttribute`]s, used to apply a field's custom attributes while
+/// deserializing it.
+#[derive(Clone)]
+pub struct ReflectFieldAttribute {
+    apply: fn(&dyn Reflect, &mut dyn PartialReflect) -> Result<(), ValidationError>,
+}
+
+impl ReflectFieldAttribute {
+    /// Applies the `attribute`, which must be of the type this was created for, to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attribute` is of a different type.
+    pub fn apply(
+        &self,
+        attribute: &dyn Reflect,
+        value: &mut dyn PartialReflect,
+    ) -> Result<(), ValidationError> {
+        (self.apply)(attribute, value)
+    }
+}
+
+impl<T: FieldAttribute> FromType<T> for ReflectFieldAttribute {
+    fn from_type() -> Self {
+        Self {
+            apply: |attribute, value| {
+                attribute
+                    .downcast_ref::<T>()
+                    .expect("attribute should be of the type this type data was created for")
+                    .apply(value)
+            },
+        }
+    }
+}
+
+impl Debug for ReflectFieldAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectFieldAttribute")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Applies the [`FieldAttribute`]s of every field of a struct, tuple struct or enum value.
+///
+/// This doesn't apply attributes of fields nested inside the fields, since the deserializer
+/// already applies those as it deserializes each value.
+pub(crate) fn apply_field_attributes(
+    value: &mut dyn PartialReflect,
+    type_info: &TypeInfo,
+    registry: &TypeRegistry,
+) -> Result<(), ValidationError> {
+    let apply = |attributes: &CustomAttributes,
+                 value: Option<&mut dyn PartialReflect>,
+                 segment: &dyn Fn() -> String| {
+        let Some(value) = value else {
+            return Ok(());
+        };
+        attributes
+            .iter()
+            .filter_map(|(type_id, attribute)| {
+                let field_attribute = registry.get_type_data::<ReflectFieldAttribute>(*type_id)?;
+                Some((field_attribute, attribute))
+            })
+            .try_for_each(|(field_attribute, attribute)| field_attribute.apply(attribute, value))
+            .map_err(|err| err.with_parent(&segment()))
+    };
+
+    match (type_info, value.reflect_mut()) {
+        (TypeInfo::Struct(info), ReflectMut::Struct(value)) => {
+            for field in info.iter() {
+                apply(
+                    field.custom_attributes(),
+                    value.field_mut(field.name()),
+                    &|| format!(".{}", field.name()),
+                )?;
+            }
+        }
+        (TypeInfo::TupleStruct(info), ReflectMut::TupleStruct(value)) => {
+            for field in info.iter() {
+                apply(
+                    field.custom_attributes(),
+                    value.field_mut(field.index()),
+                    &|| format!(".{}", field.index()),
+                )?;
+            }
+        }
+        (TypeInfo::Enum(info), ReflectMut::Enum(value)) => {
+            match info.variant(value.variant_name()) {
+                Some(VariantInfo::Struct(variant)) => {
+                    for field in variant.iter() {
+                        apply(
+                            field.custom_attributes(),
+                            value.field_mut(field.name()),
+                            &|| format!(".{}", field.name()),
+                        )?;
+                    }
+                }
+                Some(VariantInfo::Tuple(variant)) => {
+                    for field in variant.iter() {
+                        apply(
+                            field.custom_attributes(),
+                            value.field_at_mut(field.index()),
+                            &|| format!(".{}", field.index()),
+                        )?;
+                    }
+                }
+                Some(VariantInfo::Unit(_)) | None => {}
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// What a [`Bounds`] attribute does with values outside of its bounds.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Debug, PartialEq)]
+pub enum BoundsPolicy {
+    /// Fails deserialization with a [`ValidationError`].
+    #[default]
+    Reject,
+    /// Clamps the value to the nearest bound.
+    Clamp,
+}
+
+/// A [`FieldAttribute`] for numeric fields, which checks that deserialized values lie between
+/// `min` and `max`, inclusive.
+///
+/// What happens to values outside of the bounds is decided by its [`BoundsPolicy`].
+/// This attribute must be registered in the [`TypeRegistry`] to take effect.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::validation::Bounds;
+/// #[derive(Reflect)]
+/// struct Audio {
+///     // Deserializing a volume of 1.5 fails.
+///     #[reflect(@Bounds::new(0.0, 1.0))]
+///     volume: f32,
+///     // Deserializing a balance of -2.0 sets it to -1.0.
+///     #[reflect(@Bounds::new(-1.0, 1.0).clamped())]
+///     balance: f32,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(FieldAttribute, Debug, PartialEq)]
+pub struct Bounds {
+    /// The smallest allowed value.
+    pub min: f64,
+    /// The largest allowed value.
+    pub max: f64,
+    /// What to do with values outside of the bounds.
+    pub policy: BoundsPolicy,
+}
+
+impl Bounds {
+    /// Creates bounds that reject values outside of `min..=max`.
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            policy: BoundsPolicy::Reject,
+        }
+    }
+
+    /// Clamps values outside of the bounds, instead of rejecting them.
+    pub const fn clamped(self) -> Self {
+        Self {
+            policy: BoundsPolicy::Clamp,
+            ..self
+        }
+    }
+}
+
+impl Bounds {
+    /// Returns the value that `number` should be replaced with, if it's outside of the bounds.
+    fn check(&self, number: f64) -> Result<Option<f64>, ValidationError> {
+        if number >= self.min && number <= self.max {
+            return Ok(None);
+        }
+        match self.policy {
+            BoundsPolicy::Reject => Err(ValidationError::new(format!(
+                "{number} is outside of the bounds {}..={}",
+                self.min, self.max
+            ))),
+            BoundsPolicy::Clamp => Ok(Some(number.clamp(self.min, self.max))),
+        }
+    }
+}
+
+impl FieldAttribute for Bounds {
+    fn apply(&self, value: &mut dyn PartialReflect) -> Result<(), ValidationError> {
+        if let Some(value) = value.try_downcast_mut::<f64>() {
+            if let Some(clamped) = self.check(*value)? {
+                *value = clamped;
+            }
+            return Ok(());
+        }
+
+        macro_rules! apply_bounds {
+            ($($ty:ty),*) => {
+                $(
+                    if let Some(value) = value.try_downcast_mut::<$ty>() {
+                        if let Some(clamped) = self.check(*value as f64)? {
+                            *value = clamped as $ty;
+                        }
+                        return Ok(());
+                    }
+                )*
+            };
+        }
+
+        apply_bounds!(f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+        Err(ValidationError::new(format!(
+            "bounds can't be applied to a value of type `{}`",
+            value.reflect_type_path()
+        )))
+    }
+}
+
+/// Returns a validator which checks that a value lies within the given range.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::validation::in_range;
+/// #[derive(Reflect)]
+/// struct Volume(#[reflect(validate = in_range(0.0..=1.0))] f32);
+/// ```
+pub fn in_range<T, R>(range: R) -> impl Fn(&T) -> Result<(), ValidationError> + Send + Sync
+where
+    T: PartialOrd + Debug,
+    R: RangeBounds<T> + Debug + Send + Sync,
+{
+    move |value| {
+        if range.contains(value) {
+            Ok(())
+        } else {
+            Err(ValidationError::new(format!(
+                "{value:?} is outside of the range {range:?}"
+            )))
+        }
+    }
+}
+
+/// A validator which checks that a string isn't empty.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::validation::non_empty;
+/// #[derive(Reflect)]
+/// struct Name(#[reflect(validate = non_empty)] String);
+/// ```
+pub fn non_empty<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), ValidationError> {
+    if value.as_ref().is_empty() {
+        Err(ValidationError::new("expected a non-empty string"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{serde::TypedReflectDeserializer, Reflect};
+    use alloc::{string::ToString, vec};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        #[reflect(validate = non_empty)]
+        name: String,
+        #[reflect(validate = in_range(0.0..=100.0))]
+        health: f32,
+        inventory: Vec<Item>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Item {
+        Potion(#[reflect(validate = in_range(1..=5))] u8),
+        Sword {
+            #[reflect(validate = non_empty)]
+            name: String,
+        },
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Volume(
+        #[reflect(ignore)] bool,
+        #[reflect(validate = in_range(0.0..=1.0))] f32,
+    );
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<Volume>();
+        registry
+    }
+
+    fn player() -> Player {
+        Player {
+            name: "Bevy".to_string(),
+            health: 50.0,
+            inventory: vec![
+                Item::Potion(2),
+                Item::Sword {
+                    name: "Excalibur".to_string(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn should_validate_fields() {
+        let registry = registry();
+        assert_eq!(player().validate(&registry), Ok(()));
+
+        let invalid = Player {
+            name: String::new(),
+            ..player()
+        };
+        let error = invalid.validate(&registry).unwrap_err();
+        assert_eq!(error.path(), ".name");
+        assert_eq!(error.message(), "expected a non-empty string");
+
+        let invalid = Player {
+            health: 150.0,
+            ..player()
+        };
+        let error = invalid.validate(&registry).unwrap_err();
+        assert_eq!(error.path(), ".health");
+        assert_eq!(
+            error.to_string(),
+            "`.health`: 150.0 is outside of the range 0.0..=100.0"
+        );
+
+        let error = Volume(true, 2.0).validate(&registry).unwrap_err();
+        assert_eq!(error.path(), ".0");
+    }
+
+    #[test]
+    fn should_validate_nested_values() {
+        let registry = registry();
+
+        let mut invalid = player();
+        invalid.inventory.push(Item::Potion(9));
+        assert_eq!(
+            invalid.validate(&registry).unwrap_err().path(),
+            ".inventory[2].0"
+        );
+
+        let mut invalid = player();
+        invalid.inventory[1] = Item::Sword {
+            name: String::new(),
+        };
+        assert_eq!(
+            invalid.validate(&registry).unwrap_err().path(),
+            ".inventory[1].name"
+        );
+    }
+
+    #[test]
+    fn should_validate_dynamic_values() {
+        let registry = registry();
+
+        let mut invalid = player();
+        invalid.inventory.push(Item::Potion(0));
+        let dynamic = invalid.clone_value();
+        assert!(dynamic.is_dynamic());
+        assert_eq!(
+            dynamic.validate(&registry).unwrap_err().path(),
+            ".inventory[2].0"
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_values_when_deserializing() {
+        let registry = registry();
+        let deserialize = |input: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            TypedReflectDeserializer::of::<Player>(&registry).deserialize(&mut deserializer)
+        };
+
+        let value = deserialize(r#"(name: "Bevy", health: 50.0, inventory: [Potion(2)])"#).unwrap();
+        assert!(value.represents::<Player>());
+
+        let error = deserialize(r#"(name: "Bevy", health: -1.0, inventory: [])"#).unwrap_err();
+        assert!(error.to_string().contains(
+            "invalid value for `bevy_reflect::validation::tests::Player`: `.health`: -1.0 is outside of the range 0.0..=100.0"
+        ));
+
+        let error =
+            deserialize(r#"(name: "Bevy", health: 50.0, inventory: [Potion(7)])"#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid value for `bevy_reflect::validation::tests::Item`: `.0`"));
+    }
+
+    #[test]
+    fn should_apply_field_attributes_when_deserializing() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Light {
+            #[reflect(@Bounds::new(0.0, 1.0).clamped())]
+            intensity: f32,
+            #[reflect(@Bounds::new(1.0, 8.0))]
+            samples: u8,
+            kind: LightKind,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum LightKind {
+            Point,
+            Spot(#[reflect(@Bounds::new(0.0, 90.0).clamped())] f32),
+        }
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Light>();
+        registry.register::<Bounds>();
+        let deserialize = |input: &str| {
+            let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            TypedReflectDeserializer::of::<Light>(&registry)
+                .deserialize(&mut deserializer)
+                .map(|value| Light::from_reflect(value.as_partial_reflect()).unwrap())
+        };
+
+        let light = deserialize("(intensity: 0.5, samples: 4, kind: Point)").unwrap();
+        assert_eq!(light.intensity, 0.5);
+
+        let light = deserialize("(intensity: 2.5, samples: 4, kind: Spot(120.0))").unwrap();
+        assert_eq!(
+            light,
+            Light {
+                intensity: 1.0,
+                samples: 4,
+                kind: LightKind::Spot(90.0),
+            }
+        );
+
+        let error = deserialize("(intensity: 0.5, samples: 16, kind: Point)").unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("`.samples`: 16 is outside of the bounds 1..=8"));
+    }
+}
@@ -3,6 +3,7 @@
 use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
+use bevy_diagnostic::DiagnosticsStore;
 use bevy_ecs::{
     component::ComponentId,
     entity::Entity,
@@ -62,6 +63,9 @@ pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/diagnostics` request.
+pub const BRP_DIAGNOSTICS_METHOD: &str = "bevy/diagnostics";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -200,6 +204,18 @@ pub struct BrpListParams {
     pub entity: Entity,
 }
 
+/// `bevy/diagnostics`: Returns the latest measurements of the diagnostics in the
+/// [`DiagnosticsStore`], optionally only those under a path prefix.
+///
+/// The server responds with a [`BrpDiagnosticsResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpDiagnosticsParams {
+    /// If provided, only diagnostics whose path starts with this prefix are returned,
+    /// such as `memory/`.
+    #[serde(default)]
+    pub prefix: Option<String>,
+}
+
 /// `bevy/mutate_component`:
 ///
 /// The server responds with a null.
@@ -351,6 +367,23 @@ pub enum BrpGetWatchingResponse {
 /// The response to a `bevy/list` request.
 pub type BrpListResponse = Vec<String>;
 
+/// The response to a `bevy/diagnostics` request, associating each diagnostic path to its
+/// measurements.
+pub type BrpDiagnosticsResponse = HashMap<String, BrpDiagnostic>;
+
+/// The measurements of a single diagnostic, as returned by a `bevy/diagnostics` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpDiagnostic {
+    /// The latest value, if any was measured.
+    pub value: Option<f64>,
+    /// The average of the recorded values.
+    pub average: Option<f64>,
+    /// The exponential moving average of the recorded values.
+    pub smoothed: Option<f64>,
+    /// The unit suffix of the values, such as `B` or `ms`.
+    pub suffix: String,
+}
+
 /// A single response from a `bevy/list+watch` request.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpListWatchingResponse {
@@ -927,6 +960,41 @@ pub fn process_remote_list_watching_request(
     }
 }
 
+/// Handles a `bevy/diagnostics` request coming from a client.
+pub fn process_remote_diagnostics_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpDiagnosticsParams { prefix } = params.map(parse).transpose()?.unwrap_or_default();
+
+    let mut response = BrpDiagnosticsResponse::default();
+
+    // Without a `DiagnosticsStore`, there are no diagnostics to report.
+    if let Some(store) = world.get_resource::<DiagnosticsStore>() {
+        for diagnostic in store.iter() {
+            let path = diagnostic.path().as_str();
+            if prefix
+                .as_deref()
+                .is_some_and(|prefix| !path.starts_with(prefix))
+            {
+                continue;
+            }
+
+            response.insert(
+                path.to_owned(),
+                BrpDiagnostic {
+                    value: diagnostic.value(),
+                    average: diagnostic.average(),
+                    smoothed: diagnostic.smoothed(),
+                    suffix: diagnostic.suffix.to_string(),
+                },
+            );
+        }
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
 /// Handles a `bevy/registry/schema` request (list all registry types in form of schema) coming from a client.
 pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> BrpResult {
     let filter: BrpJsonSchemaQueryFilter = match params {
@@ -230,6 +230,21 @@
 //!
 //! `result`: An array of fully-qualified type names of components.
 //!
+//! ### bevy/diagnostics
+//!
+//! Retrieve the latest measurements of the diagnostics in the `DiagnosticsStore`, such as the
+//! frame time or the memory usage.
+//!
+//! `params` (optional):
+//! - `prefix`: Only diagnostics whose path starts with this prefix are returned, such as
+//!   `memory/`.
+//!
+//! `result`: A map associating each diagnostic path to an object with:
+//! - `value`: The latest measured value, or null.
+//! - `average`: The average of the recorded values, or null.
+//! - `smoothed`: The exponential moving average of the recorded values, or null.
+//! - `suffix`: The unit suffix of the values.
+//!
 //! ### bevy/get+watch
 //!
 //! Watch the values of one or more components from an entity.
@@ -432,6 +447,10 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
                 builtin_methods::process_remote_mutate_component_request,
             )
+            .with_method(
+                builtin_methods::BRP_DIAGNOSTICS_METHOD,
+                builtin_methods::process_remote_diagnostics_request,
+            )
             .with_watching_method(
                 builtin_methods::BRP_GET_AND_WATCH_METHOD,
                 builtin_methods::process_remote_get_watching_request,
@@ -6,7 +6,7 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Comma,
-    Data, DataStruct, Error, Fields, Lit, LitInt, LitStr, Meta, MetaList, Result,
+    Data, DataStruct, Error, Fields, Lit, LitInt, LitStr, Member, Meta, MetaList, Result, Token,
 };
 
 const UNIFORM_ATTRIBUTE_NAME: Symbol = Symbol("uniform");
@@ -365,7 +365,7 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                     let SamplerAttrs {
                         sampler_binding_type,
                         visibility,
-                        ..
+                        sampler_asset,
                     } = get_sampler_attrs(nested_meta_items)?;
                     let TextureAttrs { dimension, .. } = tex_attrs
                         .as_ref()
@@ -390,13 +390,38 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                         }
                     };
 
+                    // If a sampler asset is given, it overrides the sampler of the image
+                    let sampler = match sampler_asset {
+                        Some(sampler_asset) => {
+                            let sampler_asset = sampler_asset.iter();
+                            quote! {
+                                let sampler_asset: Option<&#asset_path::Handle<#image_path::SamplerAsset>> = (&self.#(#sampler_asset).*).into();
+                                if let Some(sampler_asset) = sampler_asset {
+                                    let sampler = samplers.get(sampler_asset).ok_or_else(|| #render_path::render_resource::AsBindGroupError::RetryNextUpdate)?;
+                                    let binding_type = #render_path::render_resource::#sampler_binding_type;
+                                    if !sampler.is_compatible_with(binding_type) {
+                                        return Err(#render_path::render_resource::AsBindGroupError::InvalidSamplerType(
+                                            #binding_index,
+                                            format!("{:?}", sampler.binding_type),
+                                            format!("{:?}", binding_type),
+                                        ));
+                                    }
+                                    sampler.sampler.clone()
+                                } else {
+                                    image_sampler
+                                }
+                            }
+                        }
+                        None => quote! { image_sampler },
+                    };
+
                     // insert fallible texture-based entries at 0 so that if we fail here, we exit before allocating any buffers
                     binding_impls.insert(0, quote! {
                         (
                             #binding_index,
                             #render_path::render_resource::OwnedBindingResource::Sampler({
                                 let handle: Option<&#asset_path::Handle<#image_path::Image>> = (&self.#field_name).into();
-                                if let Some(handle) = handle {
+                                let image_sampler = if let Some(handle) = handle {
                                     let image = images.get(handle).ok_or_else(|| #render_path::render_resource::AsBindGroupError::RetryNextUpdate)?;
 
                                     let Some(sample_type) = image.texture_format.sample_type(None, Some(render_device.features())) else {
@@ -419,7 +444,9 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                                     image.sampler.clone()
                                 } else {
                                     #fallback_image.sampler.clone()
-                                }
+                                };
+
+                                #sampler
                             })
                         )
                     });
@@ -622,6 +649,7 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                 #ecs_path::system::lifetimeless::SRes<#render_path::render_asset::RenderAssets<#render_path::texture::GpuImage>>,
                 #ecs_path::system::lifetimeless::SRes<#render_path::texture::FallbackImage>,
                 #ecs_path::system::lifetimeless::SRes<#render_path::render_asset::RenderAssets<#render_path::storage::GpuShaderStorageBuffer>>,
+                #ecs_path::system::lifetimeless::SRes<#render_path::render_asset::RenderAssets<#render_path::texture::GpuSampler>>,
             );
 
             #bindless_slot_count
@@ -634,7 +662,7 @@ pub fn derive_as_bind_group(ast: syn::DeriveInput) -> Result<TokenStream> {
                 &self,
                 layout: &#render_path::render_resource::BindGroupLayout,
                 render_device: &#render_path::renderer::RenderDevice,
-                (images, fallback_image, storage_buffers): &mut #ecs_path::system::SystemParamItem<'_, '_, Self::Param>,
+                (images, fallback_image, storage_buffers, samplers): &mut #ecs_path::system::SystemParamItem<'_, '_, Self::Param>,
                 force_no_bindless: bool,
             ) -> Result<#render_path::render_resource::UnpreparedBindGroup<Self::Data>, #render_path::render_resource::AsBindGroupError> {
                 #uniform_binding_type_declarations
@@ -1135,6 +1163,7 @@ fn get_texture_sample_type_value(lit_str: &LitStr) -> Result<BindingTextureSampl
 struct SamplerAttrs {
     sampler_binding_type: SamplerBindingType,
     visibility: ShaderStageVisibility,
+    sampler_asset: Option<Punctuated<Member, Token![.]>>,
 }
 
 #[derive(Default)]
@@ -1156,6 +1185,7 @@ impl ToTokens for SamplerBindingType {
 }
 
 const SAMPLER_TYPE: Symbol = Symbol("sampler_type");
+const SAMPLER_ASSET: Symbol = Symbol("sampler_asset");
 
 const FILTERING: &str = "filtering";
 const NON_FILTERING: &str = "non_filtering";
@@ -1164,6 +1194,7 @@ const COMPARISON: &str = "comparison";
 fn get_sampler_attrs(metas: Vec<Meta>) -> Result<SamplerAttrs> {
     let mut sampler_binding_type = Default::default();
     let mut visibility = ShaderStageVisibility::vertex_fragment();
+    let mut sampler_asset = None;
 
     for meta in metas {
         use syn::Meta::{List, NameValue};
@@ -1173,6 +1204,11 @@ fn get_sampler_attrs(metas: Vec<Meta>) -> Result<SamplerAttrs> {
                 let value = get_lit_str(DIMENSION, &m.value)?;
                 sampler_binding_type = get_sampler_binding_type_value(value)?;
             }
+            // Parse #[sampler(0, sampler_asset = "..."))].
+            NameValue(m) if m.path == SAMPLER_ASSET => {
+                let value = get_lit_str(SAMPLER_ASSET, &m.value)?;
+                sampler_asset = Some(value.parse_with(Punctuated::parse_separated_nonempty)?);
+            }
             // Parse #[sampler(0, visibility(...))].
             List(m) if m.path == VISIBILITY => {
                 visibility = get_visibility_flag_value(&m)?;
@@ -1180,7 +1216,7 @@ fn get_sampler_attrs(metas: Vec<Meta>) -> Result<SamplerAttrs> {
             NameValue(m) => {
                 return Err(Error::new_spanned(
                     m.path,
-                    "Not a valid name. Available attributes: `sampler_type`.",
+                    "Not a valid name. Available attributes: `sampler_type`, `sampler_asset`.",
                 ));
             }
             _ => {
@@ -1195,6 +1231,7 @@ fn get_sampler_attrs(metas: Vec<Meta>) -> Result<SamplerAttrs> {
     Ok(SamplerAttrs {
         sampler_binding_type,
         visibility,
+        sampler_asset,
     })
 }
 
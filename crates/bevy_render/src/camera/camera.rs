@@ -21,7 +21,7 @@ use crate::{
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
-    change_detection::DetectChanges,
+    change_detection::{DetectChanges, DetectChangesMut},
     component::{Component, HookContext},
     entity::{Entity, EntityBorrow},
     event::EventReader,
@@ -33,7 +33,9 @@ use bevy_ecs::{
     world::DeferredWorld,
 };
 use bevy_image::Image;
-use bevy_math::{ops, vec2, Dir3, FloatOrd, Mat4, Ray3d, Rect, URect, UVec2, UVec4, Vec2, Vec3};
+use bevy_math::{
+    ops, vec2, Dir3, FloatOrd, Mat4, Ray3d, Rect, URect, UVec2, UVec4, Vec2, Vec3, Vec4,
+};
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_reflect::prelude::*;
 use bevy_render_macros::ExtractComponent;
@@ -44,7 +46,7 @@ use bevy_window::{
 };
 use core::ops::Range;
 use derive_more::derive::From;
-use tracing::warn;
+use tracing::{error, warn};
 use wgpu::{BlendState, TextureFormat, TextureUsages};
 
 /// Render viewport configuration for the [`Camera`] component.
@@ -316,6 +318,9 @@ pub struct Camera {
     pub target: RenderTarget,
     /// If this is set to `true`, the camera will use an intermediate "high dynamic range" render texture.
     /// This allows rendering with a wider range of lighting values.
+    ///
+    /// This can be overridden by the [`ImageRenderTarget`] the camera renders to.
+    /// See [`Camera::is_hdr`].
     pub hdr: bool,
     // todo: reflect this when #6042 lands
     /// The [`CameraOutputMode`] for this camera.
@@ -356,6 +361,18 @@ impl Default for Camera {
 }
 
 impl Camera {
+    /// Returns `true` if this camera uses an intermediate "high dynamic range" render texture.
+    ///
+    /// This is [`Camera::hdr`], unless the camera renders to an image whose
+    /// [`ImageRenderTarget::hdr`] overrides it.
+    #[inline]
+    pub fn is_hdr(&self) -> bool {
+        match &self.target {
+            RenderTarget::Image(image_target) => image_target.hdr.unwrap_or(self.hdr),
+            _ => self.hdr,
+        }
+    }
+
     /// Converts a physical size in this `Camera` to a logical size.
     #[inline]
     pub fn to_logical(&self, physical_size: UVec2) -> Option<Vec2> {
@@ -738,6 +755,29 @@ pub struct ImageRenderTarget {
     /// The scale factor of the render target image, corresponding to the scale
     /// factor for a window target. This should almost always be 1.0.
     pub scale_factor: FloatOrd,
+    /// Overrides the [`Msaa`] of cameras rendering to this image, if set.
+    ///
+    /// Offscreen targets, such as minimaps or UI rendered to a texture, often don't need
+    /// as many samples as the main view.
+    pub msaa: Option<Msaa>,
+    /// Overrides [`Camera::hdr`] for cameras rendering to this image, if set.
+    ///
+    /// See [`Camera::is_hdr`].
+    pub hdr: Option<bool>,
+}
+
+impl ImageRenderTarget {
+    /// Overrides the [`Msaa`] of cameras rendering to this image.
+    pub fn with_msaa(mut self, msaa: Msaa) -> Self {
+        self.msaa = Some(msaa);
+        self
+    }
+
+    /// Overrides [`Camera::hdr`] for cameras rendering to this image.
+    pub fn with_hdr(mut self, hdr: bool) -> Self {
+        self.hdr = Some(hdr);
+        self
+    }
 }
 
 impl From<Handle<Image>> for RenderTarget {
@@ -751,6 +791,8 @@ impl From<Handle<Image>> for ImageRenderTarget {
         Self {
             handle,
             scale_factor: FloatOrd(1.0),
+            msaa: None,
+            hdr: None,
         }
     }
 }
@@ -886,6 +928,28 @@ impl NormalizedRenderTarget {
     }
 }
 
+/// Logs an error if `image` can't be rendered to by a camera.
+fn validate_image_render_target(camera: Entity, image_target: &ImageRenderTarget, image: &Image) {
+    let descriptor = &image.texture_descriptor;
+    if !descriptor.usage.contains(TextureUsages::RENDER_ATTACHMENT) {
+        error!(
+            "Camera {camera} renders to image {:?}, but the image's texture usages don't include \
+            `RENDER_ATTACHMENT`, so nothing will be rendered to it. To fix this, add \
+            `TextureUsages::RENDER_ATTACHMENT` to `image.texture_descriptor.usage`.",
+            image_target.handle.id(),
+        );
+    }
+    if descriptor.sample_count > 1 {
+        error!(
+            "Camera {camera} renders to image {:?}, which has a sample count of {}, but render \
+            target images must have a sample count of 1. To render with MSAA, set \
+            `ImageRenderTarget::msaa` or the camera's `Msaa` component instead.",
+            image_target.handle.id(),
+            descriptor.sample_count,
+        );
+    }
+}
+
 /// System in charge of updating a [`Camera`] when its window or projection changes.
 ///
 /// The system detects window creation, resize, and scale factor change events to update the camera
@@ -907,7 +971,7 @@ pub fn camera_system(
     windows: Query<(Entity, &Window)>,
     images: Res<Assets<Image>>,
     manual_texture_views: Res<ManualTextureViews>,
-    mut cameras: Query<(&mut Camera, &mut Projection)>,
+    mut cameras: Query<(Entity, &mut Camera, &mut Projection)>,
 ) {
     let primary_window = primary_window.iter().next();
 
@@ -928,7 +992,7 @@ pub fn camera_system(
         })
         .collect();
 
-    for (mut camera, mut camera_projection) in &mut cameras {
+    for (entity, mut camera, mut camera_projection) in &mut cameras {
         let mut viewport_size = camera
             .viewport
             .as_ref()
@@ -987,6 +1051,11 @@ pub fn camera_system(
                     }
                 }
                 camera.computed.target_info = new_computed_target_info;
+                if let NormalizedRenderTarget::Image(image_target) = &normalized_target {
+                    if let Some(image) = images.get(&image_target.handle) {
+                        validate_image_render_target(entity, image_target, image);
+                    }
+                }
                 if let Some(size) = camera.logical_viewport_size() {
                     if size.x != 0.0 && size.y != 0.0 {
                         camera_projection.update(size.x, size.y);
@@ -1059,6 +1128,7 @@ pub fn extract_cameras(
             Option<&RenderLayers>,
             Option<&Projection>,
             Has<NoIndirectDrawing>,
+            Option<&ComputedClipPlanes>,
         )>,
     >,
     primary_window: Extract<Query<Entity, With<PrimaryWindow>>>,
@@ -1080,6 +1150,7 @@ pub fn extract_cameras(
         render_layers,
         projection,
         no_indirect_drawing,
+        clip_planes,
     ) in query.iter()
     {
         if !camera.is_active {
@@ -1092,6 +1163,7 @@ pub fn extract_cameras(
                 Projection,
                 NoIndirectDrawing,
                 ViewUniformOffset,
+                ExtractedClipPlanes,
             )>();
             continue;
         }
@@ -1151,14 +1223,14 @@ pub fn extract_cameras(
                     exposure: exposure
                         .map(Exposure::exposure)
                         .unwrap_or_else(|| Exposure::default().exposure()),
-                    hdr: camera.hdr,
+                    hdr: camera.is_hdr(),
                 },
                 ExtractedView {
                     retained_view_entity: RetainedViewEntity::new(main_entity.into(), None, 0),
                     clip_from_view: camera.clip_from_view(),
                     world_from_view: *transform,
                     clip_from_world: None,
-                    hdr: camera.hdr,
+                    hdr: camera.is_hdr(),
                     viewport: UVec4::new(
                         viewport_origin.x,
                         viewport_origin.y,
@@ -1191,6 +1263,22 @@ pub fn extract_cameras(
             {
                 commands.insert(NoIndirectDrawing);
             }
+
+            let mut clip_planes = clip_planes
+                .map(|clip_planes| clip_planes.0.clone())
+                .unwrap_or_default();
+            if clip_planes.len() > MAX_VIEW_CLIP_PLANES {
+                warn!(
+                    "Camera {main_entity} references {} clip planes, but at most {MAX_VIEW_CLIP_PLANES} are supported. The rest are ignored.",
+                    clip_planes.len(),
+                );
+                clip_planes.truncate(MAX_VIEW_CLIP_PLANES);
+            }
+            if clip_planes.is_empty() {
+                commands.remove::<ExtractedClipPlanes>();
+            } else {
+                commands.insert(ExtractedClipPlanes(clip_planes));
+            }
         };
     }
 }
@@ -1287,6 +1375,80 @@ impl TemporalJitter {
     }
 }
 
+/// The maximum number of [`ClipPlane`]s that a single view can use.
+pub const MAX_VIEW_CLIP_PLANES: usize = 4;
+
+/// A plane that clips away the mesh geometry on one side of it, for every camera or mesh that
+/// references it in its [`ClipPlanes`].
+///
+/// The plane passes through the translation of the entity and faces along its local up (`+Y`)
+/// axis. Geometry above the plane is kept and geometry below it is discarded, so to render only
+/// what's above a water surface, place the plane on the surface with its up axis pointing out of
+/// the water.
+///
+/// Clipping happens in the fragment shader of the standard mesh pipelines, so it applies to every
+/// material using them, including in the prepass and shadow passes. Custom material shaders
+/// have to perform it themselves.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(Transform)]
+pub struct ClipPlane;
+
+impl ClipPlane {
+    /// Returns the world-space half space kept by a clip plane with the given transform, as a
+    /// normal and a distance.
+    ///
+    /// A point `p` is kept if `normal.dot(p) + distance >= 0`.
+    pub fn half_space(transform: &GlobalTransform) -> Vec4 {
+        let normal = *transform.up();
+        normal.extend(-normal.dot(transform.translation()))
+    }
+}
+
+/// Lists the [`ClipPlane`] entities that clip a camera or a mesh.
+///
+/// On a camera, the planes clip every mesh the camera renders, and at most
+/// [`MAX_VIEW_CLIP_PLANES`] planes are used. On a mesh, they clip only that mesh in every view,
+/// and the standard mesh pipelines only use the first plane. Entities without a [`ClipPlane`] are
+/// ignored.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(ComputedClipPlanes)]
+pub struct ClipPlanes(pub Vec<Entity>);
+
+/// The world-space half spaces of the [`ClipPlane`]s referenced by an entity's [`ClipPlanes`].
+/// See [`ClipPlane::half_space`].
+///
+/// This is updated by [`update_clip_planes`] in [`PostUpdate`](bevy_app::PostUpdate), after
+/// transforms are propagated, and only changes when the planes do.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct ComputedClipPlanes(pub Vec<Vec4>);
+
+/// Updates the [`ComputedClipPlanes`] of every entity with [`ClipPlanes`].
+pub fn update_clip_planes(
+    mut clip_planes_query: Query<(&ClipPlanes, &mut ComputedClipPlanes)>,
+    clip_plane_query: Query<&GlobalTransform, With<ClipPlane>>,
+) {
+    for (clip_planes, mut computed_clip_planes) in &mut clip_planes_query {
+        let half_spaces = clip_planes
+            .iter()
+            .filter_map(|entity| clip_plane_query.get(*entity).ok())
+            .map(ClipPlane::half_space)
+            .collect();
+        computed_clip_planes.set_if_neq(ComputedClipPlanes(half_spaces));
+    }
+}
+
+/// The world-space half spaces of the [`ClipPlane`]s that clip a view in the render world. See
+/// [`ClipPlane::half_space`].
+///
+/// This is extracted from the [`ClipPlanes`] of cameras, but can be inserted on any view, for
+/// example one rendering a reflection, to clip the meshes it renders. At most
+/// [`MAX_VIEW_CLIP_PLANES`] planes are used.
+#[derive(Component, Clone, Debug, Default)]
+pub struct ExtractedClipPlanes(pub Vec<Vec4>);
+
 /// Camera component specifying a mip bias to apply when sampling from material textures.
 ///
 /// Often used in conjunction with antialiasing post-process effects to reduce textures blurriness.
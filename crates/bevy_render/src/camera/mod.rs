@@ -14,8 +14,9 @@ use crate::{
     extract_component::ExtractComponentPlugin, extract_resource::ExtractResourcePlugin,
     render_graph::RenderGraph, ExtractSchedule, Render, RenderApp, RenderSet,
 };
-use bevy_app::{App, Plugin};
+use bevy_app::{App, Plugin, PostUpdate};
 use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_transform::TransformSystem;
 
 #[derive(Default)]
 pub struct CameraPlugin;
@@ -29,6 +30,9 @@ impl Plugin for CameraPlugin {
             .register_type::<Exposure>()
             .register_type::<TemporalJitter>()
             .register_type::<MipBias>()
+            .register_type::<ClipPlane>()
+            .register_type::<ClipPlanes>()
+            .register_type::<ComputedClipPlanes>()
             .init_resource::<ManualTextureViews>()
             .init_resource::<ClearColor>()
             .add_plugins((
@@ -36,7 +40,11 @@ impl Plugin for CameraPlugin {
                 ExtractResourcePlugin::<ManualTextureViews>::default(),
                 ExtractResourcePlugin::<ClearColor>::default(),
                 ExtractComponentPlugin::<CameraMainTextureUsages>::default(),
-            ));
+            ))
+            .add_systems(
+                PostUpdate,
+                update_clip_planes.after(TransformSystem::TransformPropagate),
+            );
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
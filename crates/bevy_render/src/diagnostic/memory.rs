@@ -0,0 +1,69 @@
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::prelude::*;
+
+use crate::{renderer::RenderDevice, Render, RenderApp, RenderSet};
+
+/// Adds "memory usage" diagnostics for the GPU memory allocated by the renderer to an App.
+///
+/// This measures the number of bytes allocated for buffers and textures under
+/// [`RenderMemoryDiagnosticsPlugin::ALLOCATED`], and the number of bytes reserved from the
+/// driver, which includes memory that isn't used yet, under
+/// [`RenderMemoryDiagnosticsPlugin::RESERVED`].
+///
+/// # Supported platforms
+/// Allocator reports are currently supported only on Vulkan and DX12.
+/// On other platforms no measurements will be recorded.
+#[derive(Default)]
+pub struct RenderMemoryDiagnosticsPlugin;
+
+impl Plugin for RenderMemoryDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        let mutex = RenderMemoryMutex::default();
+        app.register_diagnostic(Diagnostic::new(Self::ALLOCATED).with_suffix("B"))
+            .register_diagnostic(Diagnostic::new(Self::RESERVED).with_suffix("B"))
+            .insert_resource(mutex.clone())
+            .add_systems(PreUpdate, sync_memory_diagnostics);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .insert_resource(mutex)
+                .add_systems(Render, report_memory.in_set(RenderSet::Cleanup));
+        }
+    }
+}
+
+impl RenderMemoryDiagnosticsPlugin {
+    /// Bytes of GPU memory allocated for buffers and textures.
+    pub const ALLOCATED: DiagnosticPath = DiagnosticPath::const_new("memory/render/allocated");
+    /// Bytes of GPU memory reserved from the driver.
+    pub const RESERVED: DiagnosticPath = DiagnosticPath::const_new("memory/render/reserved");
+}
+
+/// The last allocator report, as `(allocated, reserved)` bytes, shared between the main world
+/// and the render world.
+#[derive(Debug, Default, Clone, Resource)]
+struct RenderMemoryMutex(Arc<Mutex<Option<(u64, u64)>>>);
+
+fn report_memory(mutex: Res<RenderMemoryMutex>, render_device: Res<RenderDevice>) {
+    let Some(report) = render_device.wgpu_device().generate_allocator_report() else {
+        return;
+    };
+    if let Ok(mut memory) = mutex.0.lock() {
+        *memory = Some((report.total_allocated_bytes, report.total_reserved_bytes));
+    }
+}
+
+fn sync_memory_diagnostics(mutex: Res<RenderMemoryMutex>, mut diagnostics: Diagnostics) {
+    let Some((allocated, reserved)) = mutex.0.lock().ok().and_then(|mut v| v.take()) else {
+        return;
+    };
+
+    diagnostics.add_measurement(&RenderMemoryDiagnosticsPlugin::ALLOCATED, || {
+        allocated as f64
+    });
+    diagnostics.add_measurement(&RenderMemoryDiagnosticsPlugin::RESERVED, || reserved as f64);
+}
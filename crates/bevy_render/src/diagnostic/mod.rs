@@ -3,6 +3,7 @@
 //! For more info, see [`RenderDiagnosticsPlugin`].
 
 pub(crate) mod internal;
+mod memory;
 
 use alloc::{borrow::Cow, sync::Arc};
 use core::marker::PhantomData;
@@ -17,6 +18,8 @@ use self::internal::{
 
 use super::{RenderDevice, RenderQueue};
 
+pub use memory::RenderMemoryDiagnosticsPlugin;
+
 /// Enables collecting render diagnostics, such as CPU/GPU elapsed time per render pass,
 /// as well as pipeline statistics (number of primitives, number of shader invocations, etc).
 ///
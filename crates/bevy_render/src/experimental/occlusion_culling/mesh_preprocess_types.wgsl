@@ -19,8 +19,15 @@ struct MeshInput {
     // Low 16 bits: index of the material inside the bind group data.
     // High 16 bits: index of the lightmap in the binding array.
     material_and_lightmap_bind_group_slot: u32,
-    pad_a: u32,
+    // The factor by which motion vectors are scaled. The previous transform is
+    // moved towards the current one by this amount.
+    motion_vector_scale: f32,
     pad_b: u32,
+    // The bits of the main world entity, low 32 bits first.
+    main_entity: vec2<u32>,
+    pad_c: vec2<u32>,
+    // The world-space half space kept by the mesh's clip plane.
+    clip_plane: vec4<f32>,
 }
 
 // The `wgpu` indirect parameters structure. This is a union of two structures.
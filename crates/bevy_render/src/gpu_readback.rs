@@ -1,7 +1,9 @@
 use crate::{
     extract_component::ExtractComponentPlugin,
     render_asset::RenderAssets,
-    render_resource::{Buffer, BufferUsages, Extent3d, ImageDataLayout, Texture, TextureFormat},
+    render_resource::{
+        Buffer, BufferUsages, Extent3d, ImageDataLayout, Texture, TextureDimension, TextureFormat,
+    },
     renderer::{render_system, RenderDevice},
     storage::{GpuShaderStorageBuffer, ShaderStorageBuffer},
     sync_world::MainEntity,
@@ -10,20 +12,21 @@ use crate::{
 };
 use async_channel::{Receiver, Sender};
 use bevy_app::{App, Plugin};
-use bevy_asset::Handle;
+use bevy_asset::{Handle, RenderAssetUsages};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::schedule::IntoSystemConfigs;
 use bevy_ecs::{
     change_detection::ResMut,
-    entity::Entity,
+    entity::{hash_set::EntityHashSet, Entity},
     event::Event,
     prelude::{Component, Resource, World},
-    system::{Query, Res},
+    system::{Commands, Query, Res},
 };
 use bevy_image::{Image, TextureFormatPixelInfo};
 use bevy_platform_support::collections::HashMap;
 use bevy_reflect::Reflect;
 use bevy_render_macros::ExtractComponent;
+use core::fmt;
 use encase::internal::ReadFrom;
 use encase::private::Reader;
 use encase::ShaderType;
@@ -47,7 +50,10 @@ impl Default for GpuReadbackPlugin {
 
 impl Plugin for GpuReadbackPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ExtractComponentPlugin::<Readback>::default());
+        app.add_plugins((
+            ExtractComponentPlugin::<Readback>::default(),
+            ExtractComponentPlugin::<ReadbackFrameLatency>::default(),
+        ));
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
@@ -59,6 +65,7 @@ impl Plugin for GpuReadbackPlugin {
                     Render,
                     (
                         prepare_buffers.in_set(RenderSet::PrepareResources),
+                        prepare_texture_readbacks.in_set(RenderSet::PrepareBindGroups),
                         map_buffers.after(render_system).in_set(RenderSet::Render),
                     ),
                 );
@@ -70,10 +77,21 @@ impl Plugin for GpuReadbackPlugin {
 ///
 /// Data is read asynchronously and will be triggered on the entity via the [`ReadbackComplete`] event
 /// when complete. If this component is not removed, the readback will be attempted every frame
+///
+/// The typed variants deliver the data as a [`TypedReadbackComplete`] or [`ImageReadbackComplete`]
+/// event instead. Results for an entity are always triggered in the order they were requested, and
+/// the number of readbacks in flight at once can be limited with [`ReadbackFrameLatency`].
 #[derive(Component, ExtractComponent, Clone, Debug)]
 pub enum Readback {
     Texture(Handle<Image>),
     Buffer(Handle<ShaderStorageBuffer>),
+    /// Reads back a texture and delivers it as an [`Image`] via [`ImageReadbackComplete`].
+    Image(Handle<Image>),
+    /// Reads back a buffer and delivers it decoded via [`TypedReadbackComplete`].
+    TypedBuffer {
+        buffer: Handle<ShaderStorageBuffer>,
+        decoder: ReadbackDecoder,
+    },
 }
 
 impl Readback {
@@ -86,6 +104,68 @@ impl Readback {
     pub fn buffer(buffer: Handle<ShaderStorageBuffer>) -> Self {
         Self::Buffer(buffer)
     }
+
+    /// Create a readback component for a texture using the given handle, which delivers the data
+    /// as an [`Image`] with the row padding required by the copy removed.
+    pub fn image(image: Handle<Image>) -> Self {
+        Self::Image(image)
+    }
+
+    /// Create a readback component for a buffer using the given handle, which delivers the data
+    /// decoded as `T` via the [`TypedReadbackComplete<T>`] event.
+    pub fn buffer_typed<T: ShaderType + ReadFrom + Default + Send + Sync + 'static>(
+        buffer: Handle<ShaderStorageBuffer>,
+    ) -> Self {
+        Self::TypedBuffer {
+            buffer,
+            decoder: ReadbackDecoder(trigger_typed::<T>),
+        }
+    }
+}
+
+/// Decodes the raw bytes of a [`Readback::TypedBuffer`] and triggers the typed event.
+///
+/// Created by [`Readback::buffer_typed`].
+#[derive(Clone, Copy)]
+pub struct ReadbackDecoder(fn(&mut World, Entity, &[u8]));
+
+impl fmt::Debug for ReadbackDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ReadbackDecoder").finish_non_exhaustive()
+    }
+}
+
+fn trigger_typed<T: ShaderType + ReadFrom + Default + Send + Sync + 'static>(
+    world: &mut World,
+    entity: Entity,
+    data: &[u8],
+) {
+    world.trigger_targets(TypedReadbackComplete(read_shader_type::<T>(data)), entity);
+}
+
+/// A render world component that requests a single readback of a texture that isn't an [`Image`]
+/// asset, such as one of the intermediate textures of a view.
+///
+/// Insert it on a render world entity during [`RenderSet::PrepareResources`] on every frame the
+/// texture should be read. It is removed once the copy has been queued, and the data is delivered
+/// to the corresponding main world entity as an [`ImageReadbackComplete`] event. The entity's
+/// [`ReadbackFrameLatency`] is respected like for [`Readback`].
+#[derive(Component, Clone, Debug)]
+pub struct TextureReadback(pub Texture);
+
+/// Limits how many readbacks of an entity can be in flight at once.
+///
+/// Each frame a [`Readback`] requests a copy into a staging buffer, which is mapped and delivered
+/// once the GPU is done with it, usually a few frames later. With this component, no new copy is
+/// requested while this many are still pending, so the staging buffers of the entity form a ring
+/// of this size. Without it, a new copy is requested every frame.
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReadbackFrameLatency(pub u32);
+
+impl Default for ReadbackFrameLatency {
+    fn default() -> Self {
+        Self(3)
+    }
 }
 
 /// An event that is triggered when a gpu readback is complete.
@@ -99,13 +179,27 @@ pub struct ReadbackComplete(pub Vec<u8>);
 impl ReadbackComplete {
     /// Convert the raw bytes of the event to a shader type.
     pub fn to_shader_type<T: ShaderType + ReadFrom + Default>(&self) -> T {
-        let mut val = T::default();
-        let mut reader = Reader::new::<T>(&self.0, 0).expect("Failed to create Reader");
-        T::read_from(&mut val, &mut reader);
-        val
+        read_shader_type(&self.0)
     }
 }
 
+/// An event that is triggered when a [`Readback::TypedBuffer`] readback is complete.
+///
+/// The event contains the data decoded as the shader type the readback was created with.
+#[derive(Event, Deref, DerefMut, Debug)]
+pub struct TypedReadbackComplete<T>(pub T);
+
+/// An event that is triggered when a [`Readback::Image`] readback is complete.
+#[derive(Event, Deref, DerefMut, Debug)]
+pub struct ImageReadbackComplete(pub Image);
+
+fn read_shader_type<T: ShaderType + ReadFrom + Default>(data: &[u8]) -> T {
+    let mut val = T::default();
+    let mut reader = Reader::new::<T>(data, 0).expect("Failed to create Reader");
+    T::read_from(&mut val, &mut reader);
+    val
+}
+
 #[derive(Resource)]
 struct GpuReadbackMaxUnusedFrames(usize);
 
@@ -195,15 +289,39 @@ enum ReadbackSource {
     },
 }
 
+/// How the data of a finished readback is delivered to the main world.
+enum ReadbackDelivery {
+    Bytes,
+    Typed(ReadbackDecoder),
+    Image {
+        size: Extent3d,
+        dimension: TextureDimension,
+        format: TextureFormat,
+        padded_bytes_per_row: u32,
+    },
+}
+
 #[derive(Resource, Default)]
 struct GpuReadbacks {
     requested: Vec<GpuReadback>,
     mapped: Vec<GpuReadback>,
 }
 
+impl GpuReadbacks {
+    /// Returns the number of readbacks of the given entity that haven't been delivered yet.
+    fn in_flight(&self, entity: Entity) -> usize {
+        self.requested
+            .iter()
+            .chain(&self.mapped)
+            .filter(|readback| readback.entity == entity)
+            .count()
+    }
+}
+
 struct GpuReadback {
     pub entity: Entity,
     pub src: ReadbackSource,
+    pub delivery: ReadbackDelivery,
     pub buffer: Buffer,
     pub rx: Receiver<(Entity, Buffer, Vec<u8>)>,
     pub tx: Sender<(Entity, Buffer, Vec<u8>)>,
@@ -215,30 +333,75 @@ fn sync_readbacks(
     mut readbacks: ResMut<GpuReadbacks>,
     max_unused_frames: Res<GpuReadbackMaxUnusedFrames>,
 ) {
+    // Entities with an older readback that is still pending. Newer readbacks of these entities are
+    // held back so that results are always delivered in order.
+    let mut pending = EntityHashSet::default();
     readbacks.mapped.retain(|readback| {
-        if let Ok((entity, buffer, result)) = readback.rx.try_recv() {
-            main_world.trigger_targets(ReadbackComplete(result), entity);
-            buffer_pool.return_buffer(&buffer);
-            false
-        } else {
-            true
+        if pending.contains(&readback.entity) {
+            return true;
         }
+        let Ok((entity, buffer, result)) = readback.rx.try_recv() else {
+            pending.insert(readback.entity);
+            return true;
+        };
+        deliver_readback(&mut main_world, entity, &readback.delivery, result);
+        buffer_pool.return_buffer(&buffer);
+        false
     });
 
     buffer_pool.update(max_unused_frames.0);
 }
 
+fn deliver_readback(world: &mut World, entity: Entity, delivery: &ReadbackDelivery, data: Vec<u8>) {
+    match delivery {
+        ReadbackDelivery::Bytes => world.trigger_targets(ReadbackComplete(data), entity),
+        ReadbackDelivery::Typed(decoder) => (decoder.0)(world, entity, &data),
+        ReadbackDelivery::Image {
+            size,
+            dimension,
+            format,
+            padded_bytes_per_row,
+        } => {
+            let bytes_per_row = size.width as usize * format.pixel_size();
+            let rows = (size.height * size.depth_or_array_layers) as usize;
+            let data = if *padded_bytes_per_row as usize == bytes_per_row {
+                data
+            } else {
+                data.chunks(*padded_bytes_per_row as usize)
+                    .take(rows)
+                    .flat_map(|row| &row[..bytes_per_row])
+                    .copied()
+                    .collect()
+            };
+            let image = Image::new(
+                *size,
+                *dimension,
+                data,
+                *format,
+                RenderAssetUsages::MAIN_WORLD,
+            );
+            world.trigger_targets(ImageReadbackComplete(image), entity);
+        }
+    }
+}
+
 fn prepare_buffers(
     render_device: Res<RenderDevice>,
     mut readbacks: ResMut<GpuReadbacks>,
     mut buffer_pool: ResMut<GpuReadbackBufferPool>,
     gpu_images: Res<RenderAssets<GpuImage>>,
     ssbos: Res<RenderAssets<GpuShaderStorageBuffer>>,
-    handles: Query<(&MainEntity, &Readback)>,
+    handles: Query<(&MainEntity, &Readback, Option<&ReadbackFrameLatency>)>,
 ) {
-    for (entity, readback) in handles.iter() {
+    for (entity, readback, latency) in handles.iter() {
+        if let Some(latency) = latency {
+            if readbacks.in_flight(entity.id()) >= latency.0.max(1) as usize {
+                continue;
+            }
+        }
+
         match readback {
-            Readback::Texture(image) => {
+            Readback::Texture(image) | Readback::Image(image) => {
                 if let Some(gpu_image) = gpu_images.get(image) {
                     let layout = layout_data(gpu_image.size, gpu_image.texture_format);
                     let buffer = buffer_pool.get(
@@ -248,6 +411,17 @@ fn prepare_buffers(
                             gpu_image.texture_format.pixel_size() as u32,
                         ) as u64,
                     );
+                    let delivery = match readback {
+                        Readback::Image(_) => ReadbackDelivery::Image {
+                            size: gpu_image.size,
+                            dimension: gpu_image.texture.dimension(),
+                            format: gpu_image.texture_format,
+                            padded_bytes_per_row: align_byte_size(
+                                gpu_image.size.width * gpu_image.texture_format.pixel_size() as u32,
+                            ),
+                        },
+                        _ => ReadbackDelivery::Bytes,
+                    };
                     let (tx, rx) = async_channel::bounded(1);
                     readbacks.requested.push(GpuReadback {
                         entity: entity.id(),
@@ -256,16 +430,21 @@ fn prepare_buffers(
                             layout,
                             size: gpu_image.size,
                         },
+                        delivery,
                         buffer,
                         rx,
                         tx,
                     });
                 }
             }
-            Readback::Buffer(buffer) => {
+            Readback::Buffer(buffer) | Readback::TypedBuffer { buffer, .. } => {
                 if let Some(ssbo) = ssbos.get(buffer) {
                     let size = ssbo.buffer.size();
                     let buffer = buffer_pool.get(&render_device, size);
+                    let delivery = match readback {
+                        Readback::TypedBuffer { decoder, .. } => ReadbackDelivery::Typed(*decoder),
+                        _ => ReadbackDelivery::Bytes,
+                    };
                     let (tx, rx) = async_channel::bounded(1);
                     readbacks.requested.push(GpuReadback {
                         entity: entity.id(),
@@ -274,6 +453,7 @@ fn prepare_buffers(
                             dst_start: 0,
                             buffer: ssbo.buffer.clone(),
                         },
+                        delivery,
                         buffer,
                         rx,
                         tx,
@@ -284,6 +464,54 @@ fn prepare_buffers(
     }
 }
 
+fn prepare_texture_readbacks(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    mut readbacks: ResMut<GpuReadbacks>,
+    mut buffer_pool: ResMut<GpuReadbackBufferPool>,
+    textures: Query<(
+        Entity,
+        &MainEntity,
+        &TextureReadback,
+        Option<&ReadbackFrameLatency>,
+    )>,
+) {
+    for (entity, main_entity, TextureReadback(texture), latency) in &textures {
+        commands.entity(entity).remove::<TextureReadback>();
+
+        if let Some(latency) = latency {
+            if readbacks.in_flight(main_entity.id()) >= latency.0.max(1) as usize {
+                continue;
+            }
+        }
+
+        let size = texture.size();
+        let format = texture.format();
+        let buffer = buffer_pool.get(
+            &render_device,
+            get_aligned_size(size, format.pixel_size() as u32) as u64,
+        );
+        let (tx, rx) = async_channel::bounded(1);
+        readbacks.requested.push(GpuReadback {
+            entity: main_entity.id(),
+            src: ReadbackSource::Texture {
+                texture: texture.clone(),
+                layout: layout_data(size, format),
+                size,
+            },
+            delivery: ReadbackDelivery::Image {
+                size,
+                dimension: texture.dimension(),
+                format,
+                padded_bytes_per_row: align_byte_size(size.width * format.pixel_size() as u32),
+            },
+            buffer,
+            rx,
+            tx,
+        });
+    }
+}
+
 pub(crate) fn submit_readback_commands(world: &World, command_encoder: &mut CommandEncoder) {
     let readbacks = world.resource::<GpuReadbacks>();
     for readback in &readbacks.requested {
@@ -379,3 +607,80 @@ pub(crate) fn layout_data(extent: Extent3d, format: TextureFormat) -> ImageDataL
         offset: 0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::observer::Trigger;
+
+    #[derive(Resource, Default)]
+    struct Delivered(Vec<Vec<u8>>);
+
+    #[test]
+    fn image_readback_removes_row_padding() {
+        let mut world = World::new();
+        world.init_resource::<Delivered>();
+        let entity = world
+            .spawn_empty()
+            .observe(
+                |trigger: Trigger<ImageReadbackComplete>, mut delivered: ResMut<Delivered>| {
+                    delivered.0.push(trigger.event().data.clone());
+                },
+            )
+            .id();
+
+        let size = Extent3d {
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 1,
+        };
+        let format = TextureFormat::Rgba8Unorm;
+        let padded_bytes_per_row = align_byte_size(size.width * format.pixel_size() as u32);
+        let mut data = vec![0; get_aligned_size(size, format.pixel_size() as u32) as usize];
+        for row in 0..2 {
+            let start = row * padded_bytes_per_row as usize;
+            data[start..start + 8].fill(row as u8 + 1);
+        }
+
+        let delivery = ReadbackDelivery::Image {
+            size,
+            dimension: TextureDimension::D2,
+            format,
+            padded_bytes_per_row,
+        };
+        deliver_readback(&mut world, entity, &delivery, data);
+        world.flush();
+
+        let delivered = &world.resource::<Delivered>().0;
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0], [[1; 8], [2; 8]].concat());
+    }
+
+    #[test]
+    fn typed_readback_decodes_data() {
+        let mut world = World::new();
+        world.init_resource::<Delivered>();
+        let entity = world
+            .spawn_empty()
+            .observe(
+                |trigger: Trigger<TypedReadbackComplete<u32>>, mut delivered: ResMut<Delivered>| {
+                    delivered.0.push(trigger.event().0.to_le_bytes().to_vec());
+                },
+            )
+            .id();
+
+        let Readback::TypedBuffer { decoder, .. } =
+            Readback::buffer_typed::<u32>(Handle::default())
+        else {
+            panic!("expected a typed buffer readback");
+        };
+        let data = 42u32.to_le_bytes().to_vec();
+        deliver_readback(&mut world, entity, &ReadbackDelivery::Typed(decoder), data);
+        world.flush();
+
+        assert_eq!(
+            world.resource::<Delivered>().0,
+            [42u32.to_le_bytes().to_vec()]
+        );
+    }
+}
@@ -5,11 +5,11 @@ use crate::{
         BindGroup, BindGroupId, Buffer, BufferId, BufferSlice, RenderPipeline, RenderPipelineId,
         ShaderStages,
     },
-    renderer::RenderDevice,
+    renderer::{PassMergeSlot, RenderDevice},
 };
 use bevy_color::LinearRgba;
 use bevy_utils::default;
-use core::ops::Range;
+use core::{mem::ManuallyDrop, ops::Range};
 use wgpu::{IndexFormat, QuerySet, RenderPass};
 
 #[cfg(feature = "detailed_trace")]
@@ -134,8 +134,10 @@ impl DrawState {
 /// It is used to set the current [`RenderPipeline`], [`BindGroup`]s and [`Buffer`]s.
 /// After all requirements are specified, draw calls can be issued.
 pub struct TrackedRenderPass<'a> {
-    pass: RenderPass<'a>,
+    pass: ManuallyDrop<RenderPass<'a>>,
     state: DrawState,
+    /// Where to keep the pass open when this is dropped, if it can be merged with the next pass.
+    merge_slot: Option<PassMergeSlot<'a>>,
 }
 
 impl<'a> TrackedRenderPass<'a> {
@@ -150,16 +152,26 @@ impl<'a> TrackedRenderPass<'a> {
                 vertex_buffers: vec![None; max_vertex_buffers],
                 ..default()
             },
-            pass,
+            pass: ManuallyDrop::new(pass),
+            merge_slot: None,
         }
     }
 
+    /// Keeps the pass open in `merge_slot` when this is dropped, so that the next compatible pass
+    /// can be merged into it.
+    pub(crate) fn with_merge_slot(mut self, merge_slot: PassMergeSlot<'a>) -> Self {
+        self.merge_slot = Some(merge_slot);
+        self
+    }
+
     /// Returns the wgpu [`RenderPass`].
     ///
     /// Function invalidates internal tracking state,
     /// some redundant pipeline operations may not be skipped.
+    /// This also prevents the next render pass from being merged into this one.
     pub fn wgpu_pass(&mut self) -> &mut RenderPass<'a> {
         self.state.reset_tracking();
+        self.merge_slot = None;
         &mut self.pass
     }
 
@@ -532,6 +544,7 @@ impl<'a> TrackedRenderPass<'a> {
     pub fn set_stencil_reference(&mut self, reference: u32) {
         #[cfg(feature = "detailed_trace")]
         trace!("set stencil reference: {}", reference);
+        self.merge_slot = None;
         self.pass.set_stencil_reference(reference);
     }
 
@@ -541,6 +554,7 @@ impl<'a> TrackedRenderPass<'a> {
     pub fn set_scissor_rect(&mut self, x: u32, y: u32, width: u32, height: u32) {
         #[cfg(feature = "detailed_trace")]
         trace!("set_scissor_rect: {} {} {} {}", x, y, width, height);
+        self.merge_slot = None;
         self.pass.set_scissor_rect(x, y, width, height);
     }
 
@@ -580,6 +594,7 @@ impl<'a> TrackedRenderPass<'a> {
             min_depth,
             max_depth
         );
+        self.merge_slot = None;
         self.pass
             .set_viewport(x, y, width, height, min_depth, max_depth);
     }
@@ -657,10 +672,21 @@ impl<'a> TrackedRenderPass<'a> {
     pub fn set_blend_constant(&mut self, color: LinearRgba) {
         #[cfg(feature = "detailed_trace")]
         trace!("set blend constant: {:?}", color);
+        self.merge_slot = None;
         self.pass.set_blend_constant(wgpu::Color::from(color));
     }
 }
 
+impl Drop for TrackedRenderPass<'_> {
+    fn drop(&mut self) {
+        // SAFETY: `self.pass` is not used again after this.
+        let pass = unsafe { ManuallyDrop::take(&mut self.pass) };
+        if let Some(merge_slot) = self.merge_slot.take() {
+            merge_slot.reopen(pass);
+        }
+    }
+}
+
 impl WriteTimestamp for TrackedRenderPass<'_> {
     fn write_timestamp(&mut self, query_set: &QuerySet, index: u32) {
         self.pass.write_timestamp(query_set, index);
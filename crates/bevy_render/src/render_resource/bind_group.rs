@@ -181,11 +181,16 @@ impl Deref for BindGroup {
 ///         most fields should be a [`Handle<Image>`](bevy_asset::Handle) or [`Option<Handle<Image>>`]. If the value of an [`Option<Handle<Image>>`] is
 ///         [`None`], the [`crate::texture::FallbackImage`] resource will be used instead. This attribute can be used in conjunction with a `texture` binding attribute
 ///         (with a different binding index) if a binding of the texture for the [`Image`](bevy_image::Image) is also required.
-///
-/// | Arguments              | Values                                                                  | Default                |
-/// |------------------------|-------------------------------------------------------------------------|------------------------|
-/// | `sampler_type` = "..." | `"filtering"`, `"non_filtering"`, `"comparison"`.                       |  `"filtering"`         |
-/// | `visibility(...)`      | `all`, `none`, or a list-combination of `vertex`, `fragment`, `compute` |   `vertex`, `fragment` |
+///     * If the `sampler_asset` argument names another field, that field's [`Handle<SamplerAsset>`](bevy_image::SamplerAsset)
+///         overrides the sampler of the [`Image`](bevy_image::Image), so that the same image can be sampled differently by
+///         different materials. The field will be assumed to implement `Into<Option<Handle<SamplerAsset>>>`, and nested
+///         fields can be named with dots, like `"samplers.base_color"`. If the value is [`None`], the sampler of the image is used.
+///
+/// | Arguments               | Values                                                                  | Default                |
+/// |-------------------------|-------------------------------------------------------------------------|------------------------|
+/// | `sampler_type` = "..."  | `"filtering"`, `"non_filtering"`, `"comparison"`.                       |  `"filtering"`         |
+/// | `sampler_asset` = "..." | the name of a field holding a sampler asset handle                      |  none                  |
+/// | `visibility(...)`       | `all`, `none`, or a list-combination of `vertex`, `fragment`, `compute` |   `vertex`, `fragment` |
 /// * `storage(BINDING_INDEX, arguments)`
 ///     * The field's [`Handle<Storage>`](bevy_asset::Handle) will be used to look up the matching [`Buffer`] GPU resource, which
 ///       will be bound as a storage buffer in shaders. If the `storage` attribute is used, the field is expected a raw
@@ -8,6 +8,7 @@ mod buffer_vec;
 mod gpu_array_buffer;
 mod pipeline;
 mod pipeline_cache;
+mod pipeline_diagnostics;
 mod pipeline_specializer;
 pub mod resource_macros;
 mod shader;
@@ -24,6 +25,7 @@ pub use buffer_vec::*;
 pub use gpu_array_buffer::*;
 pub use pipeline::*;
 pub use pipeline_cache::*;
+pub use pipeline_diagnostics::*;
 pub use pipeline_specializer::*;
 pub use shader::*;
 pub use storage_buffer::*;
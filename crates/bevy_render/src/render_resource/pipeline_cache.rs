@@ -11,10 +11,13 @@ use bevy_ecs::{
     resource::Resource,
     system::{Res, ResMut},
 };
-use bevy_platform_support::collections::{hash_map::EntryRef, HashMap, HashSet};
+use bevy_platform_support::{
+    collections::{hash_map::EntryRef, HashMap, HashSet},
+    time::Instant,
+};
 use bevy_tasks::Task;
 use bevy_utils::default;
-use core::{future::Future, hash::Hash, mem, ops::Deref};
+use core::{future::Future, hash::Hash, mem, ops::Deref, time::Duration};
 use naga::valid::Capabilities;
 use std::sync::{Mutex, PoisonError};
 use thiserror::Error;
@@ -44,7 +47,7 @@ pub enum Pipeline {
     ComputePipeline(ComputePipeline),
 }
 
-type CachedPipelineId = usize;
+pub(crate) type CachedPipelineId = usize;
 
 /// Index of a cached render pipeline in a [`PipelineCache`].
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
@@ -74,9 +77,38 @@ impl CachedComputePipelineId {
     }
 }
 
+impl PipelineDescriptor {
+    fn label(&self) -> Option<&Cow<'static, str>> {
+        match self {
+            PipelineDescriptor::RenderPipelineDescriptor(descriptor) => descriptor.label.as_ref(),
+            PipelineDescriptor::ComputePipelineDescriptor(descriptor) => descriptor.label.as_ref(),
+        }
+    }
+
+    /// Returns the shaders of the pipeline, along with the shader defs they're processed with.
+    fn shaders(&self) -> Vec<(AssetId<Shader>, &[ShaderDefVal])> {
+        match self {
+            PipelineDescriptor::RenderPipelineDescriptor(descriptor) => {
+                let mut shaders = vec![(
+                    descriptor.vertex.shader.id(),
+                    descriptor.vertex.shader_defs.as_slice(),
+                )];
+                if let Some(fragment) = &descriptor.fragment {
+                    shaders.push((fragment.shader.id(), fragment.shader_defs.as_slice()));
+                }
+                shaders
+            }
+            PipelineDescriptor::ComputePipelineDescriptor(descriptor) => {
+                vec![(descriptor.shader.id(), descriptor.shader_defs.as_slice())]
+            }
+        }
+    }
+}
+
 pub struct CachedPipeline {
     pub descriptor: PipelineDescriptor,
     pub state: CachedPipelineState,
+    pub statistics: CachedPipelineStatistics,
 }
 
 /// State of a cached pipeline inserted into a [`PipelineCache`].
@@ -456,6 +488,9 @@ pub struct PipelineCache {
     /// If `true`, disables asynchronous pipeline compilation.
     /// This has no effect on macOS, wasm, or without the `multi_threaded` feature.
     synchronous_pipeline_compilation: bool,
+    /// Compilations which finished since the queue was last processed, and how long they took.
+    compile_times: Arc<Mutex<Vec<(CachedPipelineId, Duration)>>>,
+    shader_errors: HashMap<CachedPipelineId, ShaderCompileError>,
 }
 
 impl PipelineCache {
@@ -469,6 +504,52 @@ impl PipelineCache {
         self.waiting_pipelines.iter().copied()
     }
 
+    /// Returns the shader compilation errors of the pipelines which failed to compile.
+    ///
+    /// Errors are removed once the shaders of their pipeline change, and the pipeline is
+    /// compiled again.
+    pub fn shader_errors(&self) -> impl Iterator<Item = &ShaderCompileError> {
+        self.shader_errors.values()
+    }
+
+    /// Returns the compilation statistics of the cached pipelines, grouped by their label.
+    pub fn statistics(&self) -> Vec<PipelineStatistics> {
+        let mut by_label = HashMap::<_, (PipelineStatistics, HashSet<_>)>::default();
+        for cached_pipeline in &self.pipelines {
+            let label = cached_pipeline.descriptor.label();
+            let (statistics, shader_variants) = by_label.entry(label).or_insert_with(|| {
+                let statistics = PipelineStatistics {
+                    label: label.cloned(),
+                    ..default()
+                };
+                (statistics, HashSet::default())
+            });
+
+            statistics.specializations += 1;
+            shader_variants.extend(cached_pipeline.descriptor.shaders());
+            match cached_pipeline.state {
+                CachedPipelineState::Ok(_) => statistics.compiled += 1,
+                CachedPipelineState::Err(
+                    PipelineCacheError::ProcessShaderError(_)
+                    | PipelineCacheError::CreateShaderModule(_),
+                ) => statistics.failed += 1,
+                _ => {}
+            }
+            statistics.compilation.compilations += cached_pipeline.statistics.compilations;
+            statistics.compilation.compile_time += cached_pipeline.statistics.compile_time;
+        }
+
+        let mut statistics = by_label
+            .into_values()
+            .map(|(mut statistics, shader_variants)| {
+                statistics.shader_variants = shader_variants.len();
+                statistics
+            })
+            .collect::<Vec<_>>();
+        statistics.sort_by(|a, b| a.label.cmp(&b.label));
+        statistics
+    }
+
     /// Create a new pipeline cache associated with the given render device.
     pub fn new(
         device: RenderDevice,
@@ -483,6 +564,8 @@ impl PipelineCache {
             new_pipelines: default(),
             pipelines: default(),
             synchronous_pipeline_compilation,
+            compile_times: default(),
+            shader_errors: default(),
         }
     }
 
@@ -607,6 +690,7 @@ impl PipelineCache {
         new_pipelines.push(CachedPipeline {
             descriptor: PipelineDescriptor::RenderPipelineDescriptor(Box::new(descriptor)),
             state: CachedPipelineState::Queued,
+            statistics: default(),
         });
         id
     }
@@ -636,6 +720,7 @@ impl PipelineCache {
         new_pipelines.push(CachedPipeline {
             descriptor: PipelineDescriptor::ComputePipelineDescriptor(Box::new(descriptor)),
             state: CachedPipelineState::Queued,
+            statistics: default(),
         });
         id
     }
@@ -646,6 +731,7 @@ impl PipelineCache {
         for cached_pipeline in pipelines_to_queue {
             self.pipelines[cached_pipeline].state = CachedPipelineState::Queued;
             self.waiting_pipelines.insert(cached_pipeline);
+            self.shader_errors.remove(&cached_pipeline);
         }
     }
 
@@ -655,6 +741,7 @@ impl PipelineCache {
         for cached_pipeline in pipelines_to_queue {
             self.pipelines[cached_pipeline].state = CachedPipelineState::Queued;
             self.waiting_pipelines.insert(cached_pipeline);
+            self.shader_errors.remove(&cached_pipeline);
         }
     }
 
@@ -668,7 +755,7 @@ impl PipelineCache {
         let layout_cache = self.layout_cache.clone();
 
         create_pipeline_task(
-            async move {
+            self.time_compilation(id, async move {
                 let mut shader_cache = shader_cache.lock().unwrap();
                 let mut layout_cache = layout_cache.lock().unwrap();
 
@@ -764,7 +851,7 @@ impl PipelineCache {
                 Ok(Pipeline::RenderPipeline(
                     device.create_render_pipeline(&descriptor),
                 ))
-            },
+            }),
             self.synchronous_pipeline_compilation,
         )
     }
@@ -779,7 +866,7 @@ impl PipelineCache {
         let layout_cache = self.layout_cache.clone();
 
         create_pipeline_task(
-            async move {
+            self.time_compilation(id, async move {
                 let mut shader_cache = shader_cache.lock().unwrap();
                 let mut layout_cache = layout_cache.lock().unwrap();
 
@@ -823,11 +910,36 @@ impl PipelineCache {
                 Ok(Pipeline::ComputePipeline(
                     device.create_compute_pipeline(&descriptor),
                 ))
-            },
+            }),
             self.synchronous_pipeline_compilation,
         )
     }
 
+    /// Wraps the creation of a pipeline to record how long it took in its statistics.
+    fn time_compilation(
+        &self,
+        id: CachedPipelineId,
+        task: impl Future<Output = Result<Pipeline, PipelineCacheError>> + Send + 'static,
+    ) -> impl Future<Output = Result<Pipeline, PipelineCacheError>> + Send + 'static {
+        let compile_times = self.compile_times.clone();
+        async move {
+            let started = Instant::now();
+            let result = task.await;
+            // Pipelines waiting on shaders didn't compile, and will be retried.
+            if !matches!(
+                result,
+                Err(PipelineCacheError::ShaderNotLoaded(_)
+                    | PipelineCacheError::ShaderImportNotYetAvailable)
+            ) {
+                compile_times
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push((id, started.elapsed()));
+            }
+            result
+        }
+    }
+
     /// Process the pipeline queue and create all pending pipelines if possible.
     ///
     /// This is generally called automatically during the [`RenderSet::Render`] step, but can
@@ -854,6 +966,17 @@ impl PipelineCache {
             self.process_pipeline(&mut pipelines[id], id);
         }
 
+        for (id, compile_time) in self
+            .compile_times
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .drain(..)
+        {
+            let statistics = &mut pipelines[id].statistics;
+            statistics.compilations += 1;
+            statistics.compile_time += compile_time;
+        }
+
         self.pipelines = pipelines;
     }
 
@@ -890,13 +1013,28 @@ impl PipelineCache {
 
                 // Shader could not be processed ... retrying won't help
                 PipelineCacheError::ProcessShaderError(err) => {
-                    let error_detail =
-                        err.emit_to_string(&self.shader_cache.lock().unwrap().composer);
-                    error!("failed to process shader:\n{}", error_detail);
+                    let error = ShaderCompileError::from_composer_error(
+                        id,
+                        cached_pipeline.descriptor.label().cloned(),
+                        err,
+                        &self.shader_cache.lock().unwrap().composer,
+                    );
+                    error!("failed to process shader:\n{}", error.message);
+                    self.shader_errors.insert(id, error);
                     return;
                 }
                 PipelineCacheError::CreateShaderModule(description) => {
                     error!("failed to create shader module: {}", description);
+                    self.shader_errors.insert(
+                        id,
+                        ShaderCompileError {
+                            pipeline: id,
+                            pipeline_label: cached_pipeline.descriptor.label().cloned(),
+                            path: None,
+                            location: None,
+                            message: description.clone(),
+                        },
+                    );
                     return;
                 }
             },
@@ -0,0 +1,119 @@
+use alloc::borrow::Cow;
+use core::time::Duration;
+use naga_oil::compose::{ComposerError, ComposerErrorInner};
+
+use super::pipeline_cache::CachedPipelineId;
+
+/// Statistics about the compilation of a single [`CachedPipeline`](super::CachedPipeline).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CachedPipelineStatistics {
+    /// The number of times the pipeline was compiled, including recompilations after one of its
+    /// shaders changed.
+    pub compilations: u32,
+    /// The total time spent compiling the pipeline, including processing its shaders.
+    pub compile_time: Duration,
+}
+
+/// Compilation statistics of the pipelines in a [`PipelineCache`](super::PipelineCache) which
+/// share a label, as returned by [`PipelineCache::statistics`](super::PipelineCache::statistics).
+///
+/// Every specialization of a pipeline is cached as a separate pipeline with the same label, so
+/// this summarizes the cost of a pipeline across all of its specializations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PipelineStatistics {
+    /// The label of the pipelines.
+    pub label: Option<Cow<'static, str>>,
+    /// The number of specializations of the pipeline.
+    pub specializations: usize,
+    /// The number of distinct combinations of shader and shader defs used by the
+    /// specializations, each of which is processed into its own shader module.
+    pub shader_variants: usize,
+    /// The number of specializations which compiled successfully.
+    pub compiled: usize,
+    /// The number of specializations which failed to compile.
+    pub failed: usize,
+    /// Statistics about the compilation of the specializations, summed up.
+    pub compilation: CachedPipelineStatistics,
+}
+
+/// An error which occurred while compiling the shaders of a pipeline in a
+/// [`PipelineCache`](super::PipelineCache), as returned by
+/// [`PipelineCache::shader_errors`](super::PipelineCache::shader_errors).
+///
+/// Errors from processing shaders are mapped back to the file they occurred in, so an error in an
+/// imported shader points to the imported file rather than to the shader importing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShaderCompileError {
+    /// The ID of the pipeline whose shaders failed to compile.
+    pub pipeline: CachedPipelineId,
+    /// The label of the pipeline whose shaders failed to compile.
+    pub pipeline_label: Option<Cow<'static, str>>,
+    /// The path of the shader file the error occurred in, if known.
+    pub path: Option<String>,
+    /// The location of the error in that file, if known.
+    pub location: Option<ShaderSourceLocation>,
+    /// The full error report.
+    pub message: String,
+}
+
+/// A location in the source of a shader, as reported by [`ShaderCompileError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShaderSourceLocation {
+    /// The line of the location, starting from 1.
+    pub line: usize,
+    /// The column of the location in characters, starting from 1.
+    pub column: usize,
+}
+
+impl ShaderSourceLocation {
+    /// Returns the location at byte `offset` into `source`, or `None` if it's out of bounds.
+    pub fn from_offset(source: &str, offset: usize) -> Option<Self> {
+        let before = source.get(..offset)?;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Some(Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        })
+    }
+}
+
+// `naga_oil` stores the index of the module a span belongs to above these bits of its offsets.
+const SPAN_OFFSET_MASK: usize = (1 << 20) - 1;
+
+impl ShaderCompileError {
+    pub(crate) fn from_composer_error(
+        pipeline: CachedPipelineId,
+        pipeline_label: Option<Cow<'static, str>>,
+        error: &ComposerError,
+        composer: &naga_oil::compose::Composer,
+    ) -> Self {
+        // Spans are relative to the whole composed module, while import positions are already
+        // relative to the source of the module.
+        let module_offset =
+            |offset: usize| (offset & SPAN_OFFSET_MASK).saturating_sub(error.source.offset());
+        let offset = match &error.inner {
+            ComposerErrorInner::ImportNotFound(_, offset)
+            | ComposerErrorInner::ImportParseError(_, offset) => Some(*offset),
+            ComposerErrorInner::WgslParseError(error) => error
+                .labels()
+                .find_map(|(span, _)| span.to_range())
+                .map(|range| module_offset(range.start)),
+            ComposerErrorInner::ShaderValidationError(error) => error
+                .spans()
+                .find_map(|(span, _)| span.to_range())
+                .map(|range| module_offset(range.start)),
+            _ => None,
+        };
+        let location = offset.and_then(|offset| {
+            ShaderSourceLocation::from_offset(&error.source.source(composer), offset)
+        });
+
+        Self {
+            pipeline,
+            pipeline_label,
+            path: Some(error.source.path(composer).clone()),
+            location,
+            message: error.emit_to_string(composer),
+        }
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
         Edge, InternedRenderLabel, InternedRenderSubGraph, NodeRunError, NodeState, RenderGraph,
         RenderGraphContext, SlotLabel, SlotType, SlotValue,
     },
-    renderer::{RenderContext, RenderDevice},
+    renderer::{RenderContext, RenderDevice, RenderPassMerging},
 };
 
 /// The [`RenderGraphRunner`] is responsible for executing a [`RenderGraph`].
@@ -83,8 +83,15 @@ impl RenderGraphRunner {
             adapter.get_info(),
             diagnostics_recorder,
         );
+        let render_pass_merging = world.get_resource::<RenderPassMerging>();
+        render_context
+            .set_render_pass_merging(render_pass_merging.is_some_and(|merging| merging.enabled));
         Self::run_graph(graph, None, &mut render_context, world, &[], None)?;
         finalizer(render_context.command_encoder());
+        if let Some(render_pass_merging) = render_pass_merging {
+            render_pass_merging
+                .set_merged_passes(core::mem::take(&mut render_context.merged_render_passes));
+        }
 
         let (render_device, mut diagnostics_recorder) = {
             #[cfg(feature = "trace")]
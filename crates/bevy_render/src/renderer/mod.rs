@@ -1,10 +1,12 @@
 mod graph_runner;
+mod pass_merging;
 mod render_device;
 
 use bevy_derive::{Deref, DerefMut};
 #[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
 use bevy_tasks::ComputeTaskPool;
 pub use graph_runner::*;
+pub use pass_merging::*;
 pub use render_device::*;
 use tracing::{error, info, info_span, warn};
 
@@ -385,6 +387,9 @@ pub struct RenderContext<'w> {
     #[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
     force_serial: bool,
     diagnostics_recorder: Option<Arc<DiagnosticsRecorder>>,
+    render_pass_merging: bool,
+    open_render_pass: Option<OpenRenderPass>,
+    merged_render_passes: Vec<MergedRenderPass>,
 }
 
 impl<'w> RenderContext<'w> {
@@ -415,6 +420,9 @@ impl<'w> RenderContext<'w> {
             #[cfg(not(all(target_arch = "wasm32", target_feature = "atomics")))]
             force_serial,
             diagnostics_recorder: diagnostics_recorder.map(Arc::new),
+            render_pass_merging: false,
+            open_render_pass: None,
+            merged_render_passes: Vec::new(),
         }
     }
 
@@ -429,8 +437,24 @@ impl<'w> RenderContext<'w> {
         self.diagnostics_recorder.clone()
     }
 
+    /// Sets whether compatible sequential render passes begun with
+    /// [`begin_tracked_render_pass`](Self::begin_tracked_render_pass) are merged.
+    ///
+    /// See [`RenderPassMerging`] for details.
+    pub fn set_render_pass_merging(&mut self, enabled: bool) {
+        self.render_pass_merging = enabled;
+    }
+
+    /// Returns the render passes that were merged into a previous pass so far.
+    pub fn merged_render_passes(&self) -> &[MergedRenderPass] {
+        &self.merged_render_passes
+    }
+
     /// Gets the current [`CommandEncoder`].
+    ///
+    /// This ends the render pass kept open for merging, if there is one.
     pub fn command_encoder(&mut self) -> &mut CommandEncoder {
+        self.open_render_pass = None;
         self.command_encoder.get_or_insert_with(|| {
             self.render_device
                 .create_command_encoder(&wgpu::CommandEncoderDescriptor::default())
@@ -439,18 +463,59 @@ impl<'w> RenderContext<'w> {
 
     /// Creates a new [`TrackedRenderPass`] for the context,
     /// configured using the provided `descriptor`.
+    ///
+    /// If [render pass merging](Self::set_render_pass_merging) is enabled, and the previous
+    /// render pass is compatible with `descriptor`, this continues the previous pass instead.
     pub fn begin_tracked_render_pass<'a>(
         &'a mut self,
         descriptor: RenderPassDescriptor<'_>,
     ) -> TrackedRenderPass<'a> {
-        // Cannot use command_encoder() as we need to split the borrow on self
-        let command_encoder = self.command_encoder.get_or_insert_with(|| {
-            self.render_device
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default())
-        });
+        let key = if self.render_pass_merging {
+            RenderPassKey::new(&descriptor)
+        } else {
+            None
+        };
+        let open_render_pass = self.open_render_pass.take();
+
+        let Some(key) = key else {
+            // End the open pass before beginning a new one
+            drop(open_render_pass);
+            // Cannot use command_encoder() as we need to split the borrow on self
+            let command_encoder = self.command_encoder.get_or_insert_with(|| {
+                self.render_device
+                    .create_command_encoder(&wgpu::CommandEncoderDescriptor::default())
+            });
 
-        let render_pass = command_encoder.begin_render_pass(&descriptor);
-        TrackedRenderPass::new(&self.render_device, render_pass)
+            let render_pass = command_encoder.begin_render_pass(&descriptor);
+            return TrackedRenderPass::new(&self.render_device, render_pass);
+        };
+
+        let label = descriptor.label.map(ToString::to_string);
+        let (render_pass, label) = match open_render_pass {
+            Some(open) if open.key == key && RenderPassKey::continues(&descriptor) => {
+                self.merged_render_passes.push(MergedRenderPass {
+                    into: open.label.clone(),
+                    label,
+                });
+                (open.pass, open.label)
+            }
+            previous => {
+                // End the previous pass before beginning a new one
+                drop(previous);
+                let command_encoder = self.command_encoder.get_or_insert_with(|| {
+                    self.render_device
+                        .create_command_encoder(&wgpu::CommandEncoderDescriptor::default())
+                });
+                let render_pass = command_encoder.begin_render_pass(&descriptor);
+                (render_pass.forget_lifetime(), label)
+            }
+        };
+
+        TrackedRenderPass::new(&self.render_device, render_pass).with_merge_slot(PassMergeSlot {
+            slot: &mut self.open_render_pass,
+            key,
+            label,
+        })
     }
 
     /// Append a [`CommandBuffer`] to the command buffer queue.
@@ -565,6 +630,7 @@ impl<'w> RenderContext<'w> {
     }
 
     fn flush_encoder(&mut self) {
+        self.open_render_pass = None;
         if let Some(encoder) = self.command_encoder.take() {
             self.command_buffer_queue
                 .push(QueuedCommandBuffer::Ready(encoder.finish()));
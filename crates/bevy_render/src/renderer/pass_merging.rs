@@ -0,0 +1,159 @@
+use bevy_ecs::resource::Resource;
+use std::sync::Mutex;
+use wgpu::{LoadOp, RenderPass, StoreOp, TextureView};
+
+use crate::render_resource::RenderPassDescriptor;
+
+/// Configures the merging of compatible sequential render passes, and lists the passes that were
+/// merged in the last frame.
+///
+/// When enabled, a render pass begun with
+/// [`RenderContext::begin_tracked_render_pass`](super::RenderContext::begin_tracked_render_pass)
+/// is kept open after its [`TrackedRenderPass`](crate::render_phase::TrackedRenderPass) is
+/// dropped. If the next tracked render pass renders to the same attachments with the same store
+/// operations, and loads all of them instead of clearing them, it continues the open pass instead
+/// of beginning a new one.
+///
+/// This saves tile-based GPUs, which are common on mobile, from storing the attachments to memory
+/// at the end of one pass just to load them again at the start of the next. `wgpu` doesn't expose
+/// subpasses, so merged passes are recorded as a single render pass with a single subpass.
+///
+/// A pass can't be merged with the next one if it set the viewport, scissor rect, stencil
+/// reference or blend constant, or accessed the underlying `wgpu` pass, since the next pass would
+/// inherit that state. Passes with timestamp writes or occlusion queries are never merged, and any
+/// other use of the [`CommandEncoder`](wgpu::CommandEncoder) ends the open pass.
+///
+/// This is enabled by default on Android and iOS.
+#[derive(Resource, Debug)]
+pub struct RenderPassMerging {
+    /// Whether compatible sequential render passes are merged.
+    pub enabled: bool,
+    merged: Mutex<Vec<MergedRenderPass>>,
+}
+
+impl Default for RenderPassMerging {
+    fn default() -> Self {
+        Self {
+            enabled: cfg!(any(target_os = "android", target_os = "ios")),
+            merged: Mutex::default(),
+        }
+    }
+}
+
+impl RenderPassMerging {
+    /// Returns the render passes that were merged into a previous pass in the last frame.
+    pub fn merged_passes(&self) -> Vec<MergedRenderPass> {
+        self.merged.lock().expect("lock poisoned").clone()
+    }
+
+    pub(crate) fn set_merged_passes(&self, merged: Vec<MergedRenderPass>) {
+        *self.merged.lock().expect("lock poisoned") = merged;
+    }
+}
+
+/// A render pass which was merged into a previous render pass, as listed by
+/// [`RenderPassMerging::merged_passes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergedRenderPass {
+    /// The label of the first pass of the merged passes.
+    pub into: Option<String>,
+    /// The label of the pass that was merged into it.
+    pub label: Option<String>,
+}
+
+/// The attachments of a render pass and their store operations, which must match for passes to be
+/// merged.
+///
+/// Attachments are compared by the address of their [`TextureView`], which is stable for the
+/// duration of the render graph, since views are reference counted.
+#[derive(PartialEq, Eq)]
+pub(crate) struct RenderPassKey {
+    color_attachments: Vec<Option<(usize, Option<usize>, StoreOp)>>,
+    depth_stencil_attachment: Option<(usize, Option<StoreOp>, Option<StoreOp>)>,
+}
+
+impl RenderPassKey {
+    /// Returns the key of a pass using `descriptor`, or `None` if it can't be merged.
+    pub(crate) fn new(descriptor: &RenderPassDescriptor<'_>) -> Option<Self> {
+        if descriptor.timestamp_writes.is_some() || descriptor.occlusion_query_set.is_some() {
+            return None;
+        }
+
+        fn address(view: &TextureView) -> usize {
+            core::ptr::from_ref(view) as usize
+        }
+
+        Some(Self {
+            color_attachments: descriptor
+                .color_attachments
+                .iter()
+                .map(|attachment| {
+                    attachment.as_ref().map(|attachment| {
+                        (
+                            address(attachment.view),
+                            attachment.resolve_target.map(address),
+                            attachment.ops.store,
+                        )
+                    })
+                })
+                .collect(),
+            depth_stencil_attachment: descriptor.depth_stencil_attachment.as_ref().map(
+                |attachment| {
+                    (
+                        address(attachment.view),
+                        attachment.depth_ops.map(|ops| ops.store),
+                        attachment.stencil_ops.map(|ops| ops.store),
+                    )
+                },
+            ),
+        })
+    }
+
+    /// Returns `true` if a pass using `descriptor` loads all of its attachments, so that it can
+    /// continue a previous pass.
+    pub(crate) fn continues(descriptor: &RenderPassDescriptor<'_>) -> bool {
+        let colors_load = descriptor
+            .color_attachments
+            .iter()
+            .flatten()
+            .all(|attachment| matches!(attachment.ops.load, LoadOp::Load));
+        let depth_stencil_loads =
+            descriptor
+                .depth_stencil_attachment
+                .as_ref()
+                .is_none_or(|attachment| {
+                    attachment
+                        .depth_ops
+                        .is_none_or(|ops| matches!(ops.load, LoadOp::Load))
+                        && attachment
+                            .stencil_ops
+                            .is_none_or(|ops| matches!(ops.load, LoadOp::Load))
+                });
+        colors_load && depth_stencil_loads
+    }
+}
+
+/// A render pass which is kept open, so that the next compatible pass can be merged into it.
+pub(crate) struct OpenRenderPass {
+    pub(crate) pass: RenderPass<'static>,
+    pub(crate) key: RenderPassKey,
+    pub(crate) label: Option<String>,
+}
+
+/// Where a [`TrackedRenderPass`](crate::render_phase::TrackedRenderPass) puts its pass when it's
+/// dropped, to keep it open for merging.
+pub(crate) struct PassMergeSlot<'a> {
+    pub(crate) slot: &'a mut Option<OpenRenderPass>,
+    pub(crate) key: RenderPassKey,
+    pub(crate) label: Option<String>,
+}
+
+impl PassMergeSlot<'_> {
+    pub(crate) fn reopen(self, pass: RenderPass<'_>) {
+        *self.slot = Some(OpenRenderPass {
+            pass: pass.forget_lifetime(),
+            key: self.key,
+            label: self.label,
+        });
+    }
+}
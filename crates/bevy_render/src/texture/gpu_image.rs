@@ -1,13 +1,20 @@
+use super::{can_generate_mips, mip_chain_len, MipGenerationQueue};
 use crate::{
     render_asset::{PrepareAssetError, RenderAsset, RenderAssetUsages},
     render_resource::{DefaultImageSampler, Sampler, Texture, TextureView},
     renderer::{RenderDevice, RenderQueue},
 };
 use bevy_asset::AssetId;
-use bevy_ecs::system::{lifetimeless::SRes, SystemParamItem};
-use bevy_image::{Image, ImageSampler};
+use bevy_ecs::system::{
+    lifetimeless::{SRes, SResMut},
+    SystemParamItem,
+};
+use bevy_image::{Image, ImageSampler, TextureFormatPixelInfo};
 use bevy_math::{AspectRatio, UVec2};
-use wgpu::{Extent3d, TextureFormat, TextureViewDescriptor};
+use wgpu::{
+    Extent3d, ImageCopyTexture, ImageDataLayout, Origin3d, TextureAspect, TextureDescriptor,
+    TextureFormat, TextureUsages, TextureViewDescriptor,
+};
 
 /// The GPU-representation of an [`Image`].
 /// Consists of the [`Texture`], its [`TextureView`] and the corresponding [`Sampler`], and the texture's size.
@@ -27,6 +34,7 @@ impl RenderAsset for GpuImage {
         SRes<RenderDevice>,
         SRes<RenderQueue>,
         SRes<DefaultImageSampler>,
+        SResMut<MipGenerationQueue>,
     );
 
     #[inline]
@@ -43,15 +51,54 @@ impl RenderAsset for GpuImage {
     fn prepare_asset(
         image: Self::SourceAsset,
         _: AssetId<Self::SourceAsset>,
-        (render_device, render_queue, default_sampler): &mut SystemParamItem<Self::Param>,
+        (render_device, render_queue, default_sampler, mip_generation_queue): &mut SystemParamItem<
+            Self::Param,
+        >,
     ) -> Result<Self, PrepareAssetError<Self::SourceAsset>> {
-        let texture = render_device.create_texture_with_data(
-            render_queue,
-            &image.texture_descriptor,
-            // TODO: Is this correct? Do we need to use `MipMajor` if it's a ktx2 file?
-            wgpu::util::TextureDataOrder::default(),
-            &image.data,
-        );
+        let generate_mips = image.generate_mips
+            && !image.data.is_empty()
+            && can_generate_mips(&image.texture_descriptor, render_device.features());
+
+        let (texture, mip_level_count) = if generate_mips {
+            let descriptor = TextureDescriptor {
+                mip_level_count: mip_chain_len(image.texture_descriptor.size),
+                usage: image.texture_descriptor.usage
+                    | TextureUsages::RENDER_ATTACHMENT
+                    | TextureUsages::TEXTURE_BINDING,
+                ..image.texture_descriptor.clone()
+            };
+            let texture = render_device.create_texture(&descriptor);
+
+            // Only the first mip level is uploaded, the others are generated from it.
+            let size = descriptor.size;
+            render_queue.write_texture(
+                ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: 0,
+                    origin: Origin3d::ZERO,
+                    aspect: TextureAspect::All,
+                },
+                &image.data,
+                ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(size.width * descriptor.format.pixel_size() as u32),
+                    rows_per_image: Some(size.height),
+                },
+                size,
+            );
+            mip_generation_queue.push(texture.clone(), &descriptor);
+
+            (texture, descriptor.mip_level_count)
+        } else {
+            let texture = render_device.create_texture_with_data(
+                render_queue,
+                &image.texture_descriptor,
+                // TODO: Is this correct? Do we need to use `MipMajor` if it's a ktx2 file?
+                wgpu::util::TextureDataOrder::default(),
+                &image.data,
+            );
+            (texture, image.texture_descriptor.mip_level_count)
+        };
 
         let texture_view = texture.create_view(
             image
@@ -73,7 +120,7 @@ impl RenderAsset for GpuImage {
             texture_format: image.texture_descriptor.format,
             sampler,
             size: image.texture_descriptor.size,
-            mip_level_count: image.texture_descriptor.mip_level_count,
+            mip_level_count,
         })
     }
 }
@@ -0,0 +1,62 @@
+use crate::{
+    render_asset::{PrepareAssetError, RenderAsset},
+    render_resource::{Sampler, SamplerBindingType},
+    renderer::RenderDevice,
+};
+use bevy_asset::AssetId;
+use bevy_ecs::system::{lifetimeless::SRes, SystemParamItem};
+use bevy_image::{ImageFilterMode, SamplerAsset};
+
+/// The GPU-representation of a [`SamplerAsset`].
+#[derive(Debug, Clone)]
+pub struct GpuSampler {
+    pub sampler: Sampler,
+    /// The most restrictive type of sampler binding that this sampler can be bound to.
+    ///
+    /// Samplers with a [`SamplerBindingType::NonFiltering`] binding type can also be bound to
+    /// [`SamplerBindingType::Filtering`] bindings.
+    pub binding_type: SamplerBindingType,
+}
+
+impl RenderAsset for GpuSampler {
+    type SourceAsset = SamplerAsset;
+    type Param = SRes<RenderDevice>;
+
+    /// Converts the extracted sampler into a [`GpuSampler`].
+    fn prepare_asset(
+        sampler: Self::SourceAsset,
+        _: AssetId<Self::SourceAsset>,
+        render_device: &mut SystemParamItem<Self::Param>,
+    ) -> Result<Self, PrepareAssetError<Self::SourceAsset>> {
+        let descriptor = &sampler.descriptor;
+        let binding_type = if descriptor.compare.is_some() {
+            SamplerBindingType::Comparison
+        } else if [
+            descriptor.mag_filter,
+            descriptor.min_filter,
+            descriptor.mipmap_filter,
+        ]
+        .into_iter()
+        .any(|filter| matches!(filter, ImageFilterMode::Linear))
+        {
+            SamplerBindingType::Filtering
+        } else {
+            SamplerBindingType::NonFiltering
+        };
+
+        Ok(GpuSampler {
+            sampler: render_device.create_sampler(&descriptor.as_wgpu()),
+            binding_type,
+        })
+    }
+}
+
+impl GpuSampler {
+    /// Returns whether this sampler can be bound to a sampler binding of the given type.
+    #[inline]
+    pub fn is_compatible_with(&self, binding_type: SamplerBindingType) -> bool {
+        self.binding_type == binding_type
+            || (self.binding_type == SamplerBindingType::NonFiltering
+                && binding_type == SamplerBindingType::Filtering)
+    }
+}
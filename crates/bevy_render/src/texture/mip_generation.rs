@@ -0,0 +1,361 @@
+//! Generation of image mip chains on the GPU.
+//!
+//! See [`MipGenerationPlugin`] for more info.
+
+use crate::{
+    prelude::Shader,
+    render_graph::{Node, NodeRunError, RenderGraph, RenderGraphContext, RenderLabel},
+    render_resource::{
+        binding_types::{sampler, texture_2d},
+        BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedPipelineState,
+        CachedRenderPipelineId, FragmentState, PipelineCache, RenderPipelineDescriptor, Sampler,
+        SamplerBindingType, ShaderStages, SpecializedRenderPipeline, SpecializedRenderPipelines,
+        Texture, TextureView, VertexState,
+    },
+    renderer::{RenderContext, RenderDevice},
+    Render, RenderApp, RenderSet,
+};
+use alloc::borrow::Cow;
+use bevy_app::{App, Plugin};
+use bevy_asset::{load_internal_asset, Handle};
+use bevy_ecs::prelude::*;
+use bevy_utils::default;
+use tracing::warn;
+use wgpu::{
+    Extent3d, FilterMode, TextureDescriptor, TextureDimension, TextureFormat,
+    TextureFormatFeatureFlags, TextureSampleType, TextureUsages, TextureViewDescriptor,
+    TextureViewDimension,
+};
+
+const MIP_GENERATION_SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(94270518367105984161284734058722618263);
+
+/// Generates the mip chains of images flagged with
+/// [`Image::generate_mips`](bevy_image::Image::generate_mips) on the GPU.
+///
+/// When such an image is prepared, its texture is created with a full mip
+/// chain and only the first mip level is uploaded. Every other mip level is
+/// then rendered by downsampling the previous one in a render graph node that
+/// runs before any camera is rendered.
+///
+/// Since the downsampling pipelines are compiled asynchronously, the
+/// generated mip levels of an image may only be filled in a few frames after
+/// the image is first uploaded.
+///
+/// This plugin is added by the [`ImagePlugin`](super::ImagePlugin).
+#[derive(Default)]
+pub struct MipGenerationPlugin;
+
+/// The label of the render graph node which generates the mip chains of
+/// uploaded images.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct MipGenerationLabel;
+
+impl Plugin for MipGenerationPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            MIP_GENERATION_SHADER_HANDLE,
+            "mip_generation.wgsl",
+            Shader::from_wgsl
+        );
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .init_resource::<MipGenerationQueue>()
+            .init_resource::<PreparedMipGenerations>()
+            .init_resource::<SpecializedRenderPipelines<MipGenerationPipeline>>()
+            .add_systems(
+                Render,
+                prepare_mip_generation.in_set(RenderSet::PrepareBindGroups),
+            );
+
+        let mut render_graph = render_app.world_mut().resource_mut::<RenderGraph>();
+        render_graph.add_node(MipGenerationLabel, MipGenerationNode);
+        if render_graph
+            .get_node_state(crate::graph::CameraDriverLabel)
+            .is_ok()
+        {
+            render_graph.add_node_edge(MipGenerationLabel, crate::graph::CameraDriverLabel);
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.init_resource::<MipGenerationPipeline>();
+        }
+    }
+}
+
+/// Returns the number of mip levels in a full mip chain of a 2D texture with
+/// the given size.
+pub fn mip_chain_len(size: Extent3d) -> u32 {
+    32 - size.width.max(size.height).max(1).leading_zeros()
+}
+
+/// Returns whether the [`MipGenerationPlugin`] can generate the mip chain of a
+/// texture with the given descriptor.
+///
+/// Mip chains can only be generated for 2D textures with a single mip level,
+/// whose format is uncompressed and supports being both rendered to and
+/// filtered.
+pub fn can_generate_mips(descriptor: &TextureDescriptor, features: wgpu::Features) -> bool {
+    let format = descriptor.format;
+    let format_features = format.guaranteed_format_features(features);
+    descriptor.dimension == TextureDimension::D2
+        && descriptor.mip_level_count == 1
+        && descriptor.sample_count == 1
+        && mip_chain_len(descriptor.size) > 1
+        && !format.is_compressed()
+        && matches!(
+            format.sample_type(None, Some(features)),
+            Some(TextureSampleType::Float { filterable: true })
+        )
+        && format_features
+            .allowed_usages
+            .contains(TextureUsages::RENDER_ATTACHMENT)
+        && format_features
+            .flags
+            .contains(TextureFormatFeatureFlags::FILTERABLE)
+}
+
+/// Textures whose mip chains are waiting to be generated.
+///
+/// [`GpuImage`](super::GpuImage)s add their textures to this queue when they
+/// are prepared with [`Image::generate_mips`](bevy_image::Image::generate_mips)
+/// set.
+#[derive(Resource, Default)]
+pub struct MipGenerationQueue {
+    pending: Vec<PendingMipGeneration>,
+}
+
+struct PendingMipGeneration {
+    texture: Texture,
+    format: TextureFormat,
+    mip_level_count: u32,
+    layer_count: u32,
+}
+
+impl MipGenerationQueue {
+    /// Queues generating every mip level but the first of `texture`, which must
+    /// have been created with [`TextureUsages::RENDER_ATTACHMENT`] and
+    /// [`TextureUsages::TEXTURE_BINDING`].
+    pub fn push(&mut self, texture: Texture, descriptor: &TextureDescriptor) {
+        self.pending.push(PendingMipGeneration {
+            texture,
+            format: descriptor.format,
+            mip_level_count: descriptor.mip_level_count,
+            layer_count: descriptor.size.depth_or_array_layers,
+        });
+    }
+}
+
+#[derive(Resource, Default)]
+struct PreparedMipGenerations(Vec<PreparedMipGeneration>);
+
+struct PreparedMipGeneration {
+    pipeline_id: CachedRenderPipelineId,
+    passes: Vec<MipGenerationPass>,
+}
+
+/// Renders a single mip level of a single texture layer.
+struct MipGenerationPass {
+    bind_group: BindGroup,
+    target: TextureView,
+}
+
+#[derive(Resource)]
+struct MipGenerationPipeline {
+    bind_group_layout: BindGroupLayout,
+    sampler: Sampler,
+}
+
+impl FromWorld for MipGenerationPipeline {
+    fn from_world(render_world: &mut World) -> Self {
+        let render_device = render_world.resource::<RenderDevice>();
+
+        let bind_group_layout = render_device.create_bind_group_layout(
+            "mip_generation_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                ),
+            ),
+        );
+        let sampler = render_device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("mip_generation_sampler"),
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..default()
+        });
+
+        Self {
+            bind_group_layout,
+            sampler,
+        }
+    }
+}
+
+impl SpecializedRenderPipeline for MipGenerationPipeline {
+    type Key = TextureFormat;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        RenderPipelineDescriptor {
+            label: Some(Cow::Borrowed("mip_generation_pipeline")),
+            layout: vec![self.bind_group_layout.clone()],
+            vertex: VertexState {
+                buffers: vec![],
+                shader_defs: vec![],
+                entry_point: Cow::Borrowed("vs_main"),
+                shader: MIP_GENERATION_SHADER_HANDLE,
+            },
+            primitive: default(),
+            depth_stencil: None,
+            multisample: default(),
+            fragment: Some(FragmentState {
+                shader: MIP_GENERATION_SHADER_HANDLE,
+                entry_point: Cow::Borrowed("fs_main"),
+                shader_defs: vec![],
+                targets: vec![Some(wgpu::ColorTargetState {
+                    format: key,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            push_constant_ranges: Vec::new(),
+            zero_initialize_workgroup_memory: false,
+        }
+    }
+}
+
+/// Prepares the bind groups of queued textures whose downsampling pipeline is
+/// ready, so that the [`MipGenerationNode`] can render their mip levels.
+fn prepare_mip_generation(
+    mut queue: ResMut<MipGenerationQueue>,
+    mut prepared: ResMut<PreparedMipGenerations>,
+    pipeline: Res<MipGenerationPipeline>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<MipGenerationPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    render_device: Res<RenderDevice>,
+) {
+    // The mip generations prepared in the previous frame have been rendered by now.
+    prepared.0.clear();
+
+    queue.pending.retain(|pending| {
+        let pipeline_id = pipelines.specialize(&pipeline_cache, &pipeline, pending.format);
+        match pipeline_cache.get_render_pipeline_state(pipeline_id) {
+            CachedPipelineState::Ok(_) => {}
+            CachedPipelineState::Err(err) => {
+                warn!(
+                    "Failed to generate mip levels for a texture with format {:?}: {err}",
+                    pending.format
+                );
+                return false;
+            }
+            CachedPipelineState::Queued | CachedPipelineState::Creating(_) => return true,
+        }
+
+        let mip_view = |mip_level: u32, layer: u32| {
+            pending.texture.create_view(&TextureViewDescriptor {
+                label: Some("mip_generation_view"),
+                dimension: Some(TextureViewDimension::D2),
+                base_mip_level: mip_level,
+                mip_level_count: Some(1),
+                base_array_layer: layer,
+                array_layer_count: Some(1),
+                ..default()
+            })
+        };
+
+        let mut passes = Vec::new();
+        for layer in 0..pending.layer_count {
+            for mip_level in 1..pending.mip_level_count {
+                let source = mip_view(mip_level - 1, layer);
+                let bind_group = render_device.create_bind_group(
+                    "mip_generation_bind_group",
+                    &pipeline.bind_group_layout,
+                    &BindGroupEntries::sequential((&source, &pipeline.sampler)),
+                );
+                passes.push(MipGenerationPass {
+                    bind_group,
+                    target: mip_view(mip_level, layer),
+                });
+            }
+        }
+        prepared.0.push(PreparedMipGeneration {
+            pipeline_id,
+            passes,
+        });
+        false
+    });
+}
+
+/// Renders the mip levels prepared by [`prepare_mip_generation`].
+struct MipGenerationNode;
+
+impl Node for MipGenerationNode {
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let prepared = world.resource::<PreparedMipGenerations>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        for generation in &prepared.0 {
+            let Some(pipeline) = pipeline_cache.get_render_pipeline(generation.pipeline_id) else {
+                continue;
+            };
+
+            // Each pass reads the mip level written by the previous one.
+            for pass in &generation.passes {
+                let mut render_pass = render_context.command_encoder().begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        label: Some("mip_generation_pass"),
+                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                            view: &pass.target,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                                store: wgpu::StoreOp::Store,
+                            },
+                        })],
+                        depth_stencil_attachment: None,
+                        timestamp_writes: None,
+                        occlusion_query_set: None,
+                    },
+                );
+                render_pass.set_pipeline(pipeline);
+                render_pass.set_bind_group(0, &pass.bind_group, &[]);
+                render_pass.draw(0..3, 0..1);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mip_chain_len_should_cover_largest_dimension() {
+        let size = |width, height| Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        assert_eq!(mip_chain_len(size(1, 1)), 1);
+        assert_eq!(mip_chain_len(size(2, 1)), 2);
+        assert_eq!(mip_chain_len(size(256, 256)), 9);
+        assert_eq!(mip_chain_len(size(300, 17)), 9);
+        assert_eq!(mip_chain_len(size(1, 1024)), 11);
+    }
+}
@@ -0,0 +1,663 @@
+//! Streaming of the mip levels of KTX2 images from disk.
+//!
+//! See [`MipStreamingPlugin`] for more info.
+
+use crate::{
+    camera::{Camera, CameraUpdateSystem},
+    renderer::RenderDevice,
+};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{
+    io::{AssetReaderError, AsyncSeekForwardExt, MissingAssetSourceError},
+    AssetId, AssetServer, Assets, Handle,
+};
+use bevy_ecs::prelude::*;
+use bevy_image::{
+    ktx2_level_index_end, ktx2_level_ranges, ktx2_mip_level_to_image, CompressedImageFormats,
+    Image, TextureError, KTX2_HEADER_LENGTH,
+};
+use bevy_platform_support::collections::HashMap;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_tasks::{IoTaskPool, Task};
+use bevy_transform::{components::GlobalTransform, TransformSystem};
+use futures_lite::AsyncReadExt;
+use thiserror::Error;
+use tracing::warn;
+use wgpu::{Extent3d, TextureDimension, TextureFormat};
+
+/// Streams the mip levels of KTX2 images from disk based on how large they
+/// appear on screen, within a VRAM budget.
+///
+/// Images are streamed when they are referenced by a [`StreamedMips`]
+/// component. To avoid loading the full image up front, load it with
+/// [`ImageLoaderSettings::max_mip_levels`](bevy_image::ImageLoaderSettings::max_mip_levels)
+/// set to a small number of mip levels: the smallest mip levels are then loaded
+/// first, and larger ones are read from the image file as they are needed.
+/// Each streamed mip level is read and decoded on its own, using the level
+/// index of the KTX2 file to read only its bytes. Mip levels that aren't needed
+/// anymore are dropped again, down to
+/// [`MipStreamingSettings::min_resident_mip_levels`].
+///
+/// While the larger mip levels of an image are not resident, the image is
+/// sampled from its largest resident mip level instead, so it simply appears
+/// blurrier. wgpu doesn't support sparse textures, so rather than committing
+/// memory for individual mip levels of a texture, the resident mip levels make
+/// up the whole image. The images are modified in place, so their GPU textures
+/// are recreated whenever a mip level is streamed in or dropped, and materials
+/// using them must pick up
+/// [`AssetEvent::Modified`](bevy_asset::AssetEvent::Modified) events to show
+/// the streamed mip levels.
+#[derive(Default)]
+pub struct MipStreamingPlugin;
+
+impl Plugin for MipStreamingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<StreamedMips>()
+            .register_type::<MipStreamingSettings>()
+            .init_resource::<MipStreamingSettings>()
+            .init_resource::<StreamedImages>()
+            .add_systems(
+                PostUpdate,
+                stream_image_mips
+                    .after(TransformSystem::TransformPropagate)
+                    .after(CameraUpdateSystem),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        let supported_compressed_formats = match app.world().get_resource::<RenderDevice>() {
+            Some(render_device) => CompressedImageFormats::from_features(render_device.features()),
+            None => CompressedImageFormats::NONE,
+        };
+        app.world_mut()
+            .resource_mut::<StreamedImages>()
+            .supported_compressed_formats = supported_compressed_formats;
+    }
+}
+
+/// Configures the [`MipStreamingPlugin`].
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct MipStreamingSettings {
+    /// The maximum number of bytes that all streamed images may take up
+    /// together.
+    ///
+    /// When streaming in a mip level would exceed the budget, mip levels of
+    /// images with a lower [`StreamedMips::priority`] are dropped to make room.
+    /// If that isn't enough, the mip level isn't streamed in.
+    pub vram_budget: u64,
+    /// The minimum number of mip levels to keep resident for each image.
+    pub min_resident_mip_levels: u32,
+    /// The maximum number of mip levels that may be read from disk at the same
+    /// time.
+    pub max_pending_loads: usize,
+}
+
+impl Default for MipStreamingSettings {
+    fn default() -> Self {
+        Self {
+            vram_budget: 512 * 1024 * 1024,
+            min_resident_mip_levels: 4,
+            max_pending_loads: 4,
+        }
+    }
+}
+
+/// Marks an entity as displaying an image whose mip levels should be streamed
+/// by the [`MipStreamingPlugin`].
+///
+/// The size that the image appears at on screen is estimated from
+/// [`world_size`](Self::world_size), the [`GlobalTransform`] of the entity and
+/// each active camera. Larger mip levels are streamed in until the image has
+/// at least as many texels as it covers pixels.
+///
+/// Several entities may stream the same image, in which case the largest
+/// screen size and highest priority among them are used.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(GlobalTransform)]
+pub struct StreamedMips {
+    /// The image to stream.
+    ///
+    /// This must be a KTX2 image loaded from an asset path.
+    pub image: Handle<Image>,
+    /// The approximate world space size covered by the image on this entity,
+    /// before scaling.
+    pub world_size: f32,
+    /// The priority of this image when the [`MipStreamingSettings::vram_budget`]
+    /// is exceeded.
+    ///
+    /// Images with a higher priority are streamed in first and have their mip
+    /// levels dropped last.
+    pub priority: f32,
+}
+
+/// The images streamed by the [`MipStreamingPlugin`].
+#[derive(Resource, Default)]
+pub struct StreamedImages {
+    images: HashMap<AssetId<Image>, StreamedImage>,
+    supported_compressed_formats: CompressedImageFormats,
+}
+
+impl StreamedImages {
+    /// Returns the number of mip levels of the given image that are resident,
+    /// or `None` if the image isn't being streamed.
+    pub fn resident_mip_levels(&self, image: impl Into<AssetId<Image>>) -> Option<u32> {
+        self.images
+            .get(&image.into())
+            .map(|streamed_image| streamed_image.resident_mip_levels)
+    }
+
+    /// Returns the number of bytes taken up by all streamed images.
+    pub fn resident_bytes(&self) -> u64 {
+        self.images
+            .values()
+            .map(|streamed_image| streamed_image.resident_bytes)
+            .sum()
+    }
+}
+
+/// The streaming state of a single image.
+struct StreamedImage {
+    resident_mip_levels: u32,
+    resident_bytes: u64,
+    /// Whether every mip level of the image file is resident.
+    complete: bool,
+    /// Whether streaming the image failed, in which case its mip levels aren't
+    /// streamed in or dropped anymore.
+    failed: bool,
+    pending: Option<PendingMipLoad>,
+}
+
+struct PendingMipLoad {
+    /// Resolves to the next larger mip level of the image, or `None` if every
+    /// mip level of the image file is already resident.
+    task: Task<Result<Option<Image>, MipStreamingError>>,
+}
+
+/// An error that occurs when streaming the mip levels of an image.
+#[derive(Error, Debug)]
+pub enum MipStreamingError {
+    #[error("the image was not loaded from an asset path")]
+    MissingPath,
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    #[error(transparent)]
+    AssetReader(#[from] AssetReaderError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Texture(#[from] TextureError),
+    #[error(
+        "the streamed mip levels have format {actual:?}, but the image has format {expected:?}"
+    )]
+    FormatMismatch {
+        expected: TextureFormat,
+        actual: TextureFormat,
+    },
+    #[error("the streamed mip level doesn't match the resident mip levels of the image")]
+    SizeMismatch,
+}
+
+/// Estimates the screen size of streamed images, then streams their mip levels
+/// in or drops them accordingly.
+pub fn stream_image_mips(
+    mut streamed_images: ResMut<StreamedImages>,
+    mut images: ResMut<Assets<Image>>,
+    settings: Res<MipStreamingSettings>,
+    asset_server: Res<AssetServer>,
+    streamed_mips: Query<(&StreamedMips, &GlobalTransform)>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    let StreamedImages {
+        images: streamed_images,
+        supported_compressed_formats,
+    } = &mut *streamed_images;
+
+    // Apply the mip levels that finished streaming in
+    streamed_images.retain(|id, streamed_image| {
+        if !images.contains(*id) {
+            return false;
+        }
+
+        let Some(pending) = streamed_image.pending.as_mut() else {
+            return true;
+        };
+        let Some(result) = bevy_tasks::futures::check_ready(&mut pending.task) else {
+            return true;
+        };
+        streamed_image.pending = None;
+
+        let result = result.and_then(|level| {
+            let Some(level) = level else {
+                streamed_image.complete = true;
+                return Ok(());
+            };
+            let image = images.get_mut(*id).unwrap();
+            let (expected, actual) = (
+                image.texture_descriptor.format,
+                level.texture_descriptor.format,
+            );
+            if expected != actual {
+                return Err(MipStreamingError::FormatMismatch { expected, actual });
+            }
+            if !prepend_mip_level(image, level) {
+                return Err(MipStreamingError::SizeMismatch);
+            }
+            streamed_image.resident_mip_levels = image.texture_descriptor.mip_level_count;
+            streamed_image.resident_bytes = image.data.len() as u64;
+            Ok(())
+        });
+        match result {
+            Ok(()) => {}
+            Err(err) => {
+                warn!(
+                    "Failed to stream mip levels of {:?}: {err}",
+                    asset_server.get_path(*id)
+                );
+                streamed_image.failed = true;
+            }
+        }
+        true
+    });
+
+    // Find the largest size that each image appears at on screen
+    let mut requests: HashMap<AssetId<Image>, MipRequest> = HashMap::default();
+    for (streamed_mips, transform) in &streamed_mips {
+        let request = requests.entry(streamed_mips.image.id()).or_default();
+        request.priority = request.priority.max(streamed_mips.priority);
+
+        let world_size = streamed_mips.world_size * transform.scale().max_element();
+        for (camera, camera_transform) in &cameras {
+            if !camera.is_active {
+                continue;
+            }
+            let Some(viewport_size) = camera.physical_viewport_size() else {
+                continue;
+            };
+
+            let clip_from_view = camera.clip_from_view();
+            let view_position = camera_transform
+                .compute_matrix()
+                .inverse()
+                .transform_point3(transform.translation());
+            let clip_w = (clip_from_view * view_position.extend(1.0)).w;
+            if clip_w <= 0.0 {
+                continue;
+            }
+
+            let ndc_size = world_size * clip_from_view.y_axis.y.abs() / clip_w;
+            let screen_size = ndc_size * 0.5 * viewport_size.y as f32;
+            request.screen_size = request.screen_size.max(screen_size);
+        }
+    }
+
+    let mut pending_loads = streamed_images
+        .values()
+        .filter(|streamed_image| streamed_image.pending.is_some())
+        .count();
+
+    // Drop mip levels that are much larger than needed
+    for (id, request) in &requests {
+        let Some(image) = images.get(*id) else {
+            continue;
+        };
+        let streamed_image = streamed_images.entry(*id).or_insert_with(|| StreamedImage {
+            resident_mip_levels: image.texture_descriptor.mip_level_count,
+            resident_bytes: image.data.len() as u64,
+            complete: false,
+            failed: false,
+            pending: None,
+        });
+
+        if streamed_image.pending.is_none()
+            && streamed_image.resident_mip_levels > settings.min_resident_mip_levels
+            && request.screen_size * 4.0 < image.width().max(image.height()) as f32
+        {
+            let image = images.get_mut(*id).unwrap();
+            if !drop_largest_mip_level(image) {
+                streamed_image.failed = true;
+                continue;
+            }
+            streamed_image.resident_mip_levels = image.texture_descriptor.mip_level_count;
+            streamed_image.resident_bytes = image.data.len() as u64;
+            streamed_image.complete = false;
+        }
+    }
+
+    // Stream in the next mip level of images that are too small, most
+    // important first
+    let mut upgrades: Vec<_> = requests
+        .iter()
+        .filter_map(|(id, request)| {
+            let image = images.get(*id)?;
+            let streamed_image = streamed_images.get(id)?;
+            let resident_size = image.width().max(image.height()) as f32;
+            (!streamed_image.complete
+                && !streamed_image.failed
+                && streamed_image.pending.is_none()
+                && request.screen_size > resident_size)
+                .then_some((*id, request.priority, request.screen_size / resident_size))
+        })
+        .collect();
+    upgrades.sort_by(|(_, a_priority, a_need), (_, b_priority, b_need)| {
+        b_priority
+            .total_cmp(a_priority)
+            .then(b_need.total_cmp(a_need))
+    });
+
+    let mut resident_bytes: u64 = streamed_images
+        .values()
+        .map(|streamed_image| streamed_image.resident_bytes)
+        .sum();
+    for (id, priority, _) in upgrades {
+        if pending_loads >= settings.max_pending_loads {
+            break;
+        }
+
+        // Adding a mip level roughly quadruples the size of the largest one,
+        // which is about three quarters of a mip chain
+        let cost = streamed_images[&id].resident_bytes * 3;
+        while resident_bytes + cost > settings.vram_budget {
+            let Some(evicted) = streamed_images
+                .iter()
+                .filter(|(evicted, streamed_image)| {
+                    **evicted != id
+                        && !streamed_image.failed
+                        && streamed_image.pending.is_none()
+                        && streamed_image.resident_mip_levels > settings.min_resident_mip_levels
+                        && requests
+                            .get(*evicted)
+                            .is_none_or(|request| request.priority < priority)
+                })
+                .min_by(|(a, _), (b, _)| {
+                    let a_priority = requests
+                        .get(*a)
+                        .map_or(f32::MIN, |request| request.priority);
+                    let b_priority = requests
+                        .get(*b)
+                        .map_or(f32::MIN, |request| request.priority);
+                    a_priority.total_cmp(&b_priority)
+                })
+                .map(|(evicted, _)| *evicted)
+            else {
+                break;
+            };
+
+            let streamed_image = streamed_images.get_mut(&evicted).unwrap();
+            let Some(image) = images.get_mut(evicted) else {
+                streamed_image.failed = true;
+                continue;
+            };
+            if !drop_largest_mip_level(image) {
+                streamed_image.failed = true;
+                continue;
+            }
+            resident_bytes -= streamed_image.resident_bytes - image.data.len() as u64;
+            streamed_image.resident_mip_levels = image.texture_descriptor.mip_level_count;
+            streamed_image.resident_bytes = image.data.len() as u64;
+            streamed_image.complete = false;
+        }
+        if resident_bytes + cost > settings.vram_budget {
+            continue;
+        }
+
+        let image = images.get(id).unwrap();
+        let streamed_image = streamed_images.get_mut(&id).unwrap();
+        streamed_image.pending = Some(PendingMipLoad {
+            task: spawn_mip_load(
+                &asset_server,
+                id,
+                *supported_compressed_formats,
+                image.texture_descriptor.format.is_srgb(),
+                streamed_image.resident_mip_levels,
+            ),
+        });
+        resident_bytes += cost;
+        pending_loads += 1;
+    }
+}
+
+/// The screen size and priority that an image is requested with.
+#[derive(Default)]
+struct MipRequest {
+    screen_size: f32,
+    priority: f32,
+}
+
+/// Reads the mip level of an image which is one larger than its
+/// `resident_mip_levels` smallest mip levels from its file.
+///
+/// Only the start of the file and the mip level itself are read: KTX2 files
+/// store their mip levels from smallest to largest after the header, so the
+/// reader only ever has to seek forward.
+fn spawn_mip_load(
+    asset_server: &AssetServer,
+    id: AssetId<Image>,
+    supported_compressed_formats: CompressedImageFormats,
+    is_srgb: bool,
+    resident_mip_levels: u32,
+) -> Task<Result<Option<Image>, MipStreamingError>> {
+    let path = asset_server.get_path(id).map(|path| path.into_owned());
+    let asset_server = asset_server.clone();
+    IoTaskPool::get().spawn(async move {
+        let path = path.ok_or(MipStreamingError::MissingPath)?;
+        let source = asset_server.get_source(path.source())?;
+        let mut reader = source.reader().read(path.path()).await?;
+
+        // Read the header and level index to locate the mip levels
+        let mut metadata = vec![0; KTX2_HEADER_LENGTH];
+        reader.read_exact(&mut metadata).await?;
+        let level_index_end = ktx2_level_index_end(&metadata)?;
+        metadata.resize(level_index_end, 0);
+        reader
+            .read_exact(&mut metadata[KTX2_HEADER_LENGTH..])
+            .await?;
+        let levels = ktx2_level_ranges(&metadata)?;
+        let Some(level) = (levels.len() as u32).checked_sub(resident_mip_levels + 1) else {
+            return Ok(None);
+        };
+
+        // Read the rest of the metadata, which is followed by the mip levels
+        let metadata_end = levels
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .unwrap_or_default()
+            .max(level_index_end as u64);
+        metadata.resize(metadata_end as usize, 0);
+        reader.read_exact(&mut metadata[level_index_end..]).await?;
+
+        let range = &levels[level as usize];
+        reader.seek_forward(range.start - metadata_end).await?;
+        let mut level_data = vec![0; (range.end - range.start) as usize];
+        reader.read_exact(&mut level_data).await?;
+
+        Ok(Some(ktx2_mip_level_to_image(
+            &metadata,
+            level,
+            &level_data,
+            supported_compressed_formats,
+            is_srgb,
+        )?))
+    })
+}
+
+/// Adds `level` as the new largest mip level of an image.
+///
+/// `level` must be an image with a single mip level, twice the size of the
+/// largest mip level of `image`. The data of both is expected to be laid out
+/// like in [`drop_largest_mip_level`]. Returns `false` if it isn't.
+fn prepend_mip_level(image: &mut Image, level: Image) -> bool {
+    let descriptor = &image.texture_descriptor;
+    let (format, dimension, size) = (descriptor.format, descriptor.dimension, descriptor.size);
+    let level_size = level.texture_descriptor.size;
+    if level.texture_descriptor.mip_level_count != 1
+        || level_size
+            .mip_level_size(1, dimension)
+            .physical_size(format)
+            != size
+    {
+        return false;
+    }
+
+    let layer_count = match dimension {
+        TextureDimension::D3 => 1,
+        _ => size.depth_or_array_layers,
+    } as usize;
+    let level_bytes = mip_level_bytes(level_size, dimension, format, 0);
+    let layer_bytes: usize = (0..descriptor.mip_level_count)
+        .map(|level| mip_level_bytes(size, dimension, format, level))
+        .sum();
+    if level_bytes == 0
+        || level.data.len() != level_bytes * layer_count
+        || image.data.len() != layer_bytes * layer_count
+    {
+        return false;
+    }
+
+    image.data = level
+        .data
+        .chunks_exact(level_bytes)
+        .zip(image.data.chunks_exact(layer_bytes))
+        .flat_map(|(level_layer, layer)| level_layer.iter().chain(layer))
+        .copied()
+        .collect();
+    image.texture_descriptor.size = level_size;
+    image.texture_descriptor.mip_level_count += 1;
+    true
+}
+
+/// Drops the largest mip level of an image, making the next one its base.
+///
+/// The image data is expected to be laid out layer by layer, with the mip
+/// levels of each layer stored from largest to smallest. Returns `false` if
+/// the image has a single mip level or its data isn't laid out as expected.
+fn drop_largest_mip_level(image: &mut Image) -> bool {
+    let descriptor = &image.texture_descriptor;
+    if descriptor.mip_level_count <= 1 {
+        return false;
+    }
+
+    let (format, dimension, size) = (descriptor.format, descriptor.dimension, descriptor.size);
+    let layer_count = match dimension {
+        TextureDimension::D3 => 1,
+        _ => size.depth_or_array_layers,
+    } as usize;
+    let level_bytes = |level| mip_level_bytes(size, dimension, format, level);
+    let largest_level_bytes = level_bytes(0);
+    let layer_bytes: usize = (0..descriptor.mip_level_count).map(level_bytes).sum();
+    if layer_bytes == 0 || image.data.len() != layer_bytes * layer_count {
+        warn!("Can't drop mip level of an image with unexpected data size");
+        return false;
+    }
+
+    image.data = image
+        .data
+        .chunks_exact(layer_bytes)
+        .flat_map(|layer| &layer[largest_level_bytes..])
+        .copied()
+        .collect();
+    image.texture_descriptor.size = size.mip_level_size(1, dimension).physical_size(format);
+    image.texture_descriptor.mip_level_count -= 1;
+    true
+}
+
+/// Returns the number of bytes of a single layer of the given mip level.
+fn mip_level_bytes(
+    size: Extent3d,
+    dimension: TextureDimension,
+    format: TextureFormat,
+    level: u32,
+) -> usize {
+    let level_size = size.mip_level_size(level, dimension).physical_size(format);
+    let (block_width, block_height) = format.block_dimensions();
+    // Streamed images are never depth or stencil textures
+    let block_bytes = format.block_copy_size(None).unwrap_or_default();
+    let depth = match dimension {
+        TextureDimension::D3 => level_size.depth_or_array_layers,
+        _ => 1,
+    };
+    (level_size.width / block_width * (level_size.height / block_height) * depth * block_bytes)
+        as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{drop_largest_mip_level, prepend_mip_level};
+    use bevy_image::Image;
+    use wgpu::{Extent3d, TextureFormat};
+
+    #[test]
+    fn drop_largest_mip_level_of_array() {
+        let mut image = Image::default();
+        image.texture_descriptor.format = TextureFormat::R8Unorm;
+        image.texture_descriptor.size = Extent3d {
+            width: 4,
+            height: 4,
+            depth_or_array_layers: 2,
+        };
+        image.texture_descriptor.mip_level_count = 3;
+        // 4x4, 2x2 and 1x1 mip levels for each layer
+        image.data = [[0; 16].as_slice(), &[1; 4], &[2; 1]]
+            .repeat(2)
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect();
+
+        assert!(drop_largest_mip_level(&mut image));
+
+        assert_eq!(image.texture_descriptor.mip_level_count, 2);
+        assert_eq!(
+            image.texture_descriptor.size,
+            Extent3d {
+                width: 2,
+                height: 2,
+                depth_or_array_layers: 2,
+            }
+        );
+        assert_eq!(image.data, [1, 1, 1, 1, 2, 1, 1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn prepend_mip_level_to_array() {
+        let mut image = Image::default();
+        image.texture_descriptor.format = TextureFormat::R8Unorm;
+        image.texture_descriptor.size = Extent3d {
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 2,
+        };
+        image.texture_descriptor.mip_level_count = 2;
+        // 2x2 and 1x1 mip levels for each layer
+        image.data = [[1; 4].as_slice(), &[2; 1]]
+            .repeat(2)
+            .into_iter()
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut level = Image::default();
+        level.texture_descriptor.format = TextureFormat::R8Unorm;
+        level.texture_descriptor.size = Extent3d {
+            width: 4,
+            height: 4,
+            depth_or_array_layers: 2,
+        };
+        level.data = [[0; 16], [3; 16]].concat();
+
+        assert!(prepend_mip_level(&mut image, level));
+
+        assert_eq!(image.texture_descriptor.mip_level_count, 3);
+        assert_eq!(image.texture_descriptor.size.width, 4);
+        let expected: Vec<u8> =
+            [[0; 16].as_slice(), &[1; 4], &[2], &[3; 16], &[1; 4], &[2]].concat();
+        assert_eq!(image.data, expected);
+
+        // Levels of the wrong size are rejected
+        let mut level = Image::default();
+        level.texture_descriptor.format = TextureFormat::R8Unorm;
+        assert!(!prepend_mip_level(&mut image, level));
+    }
+}
@@ -1,5 +1,9 @@
 mod fallback_image;
 mod gpu_image;
+mod gpu_sampler;
+mod mip_generation;
+#[cfg(feature = "ktx2")]
+mod mip_streaming;
 mod texture_attachment;
 mod texture_cache;
 
@@ -8,9 +12,15 @@ pub use crate::render_resource::DefaultImageSampler;
 use bevy_image::CompressedImageSaver;
 #[cfg(feature = "hdr")]
 use bevy_image::HdrTextureLoader;
-use bevy_image::{CompressedImageFormats, Image, ImageLoader, ImageSamplerDescriptor};
+use bevy_image::{
+    CompressedImageFormats, Image, ImageLoader, ImageSamplerDescriptor, SamplerAsset,
+};
 pub use fallback_image::*;
 pub use gpu_image::*;
+pub use gpu_sampler::*;
+pub use mip_generation::*;
+#[cfg(feature = "ktx2")]
+pub use mip_streaming::*;
 pub use texture_attachment::*;
 pub use texture_cache::*;
 
@@ -19,6 +29,7 @@ use crate::{
 };
 use bevy_app::{App, Plugin};
 use bevy_asset::{AssetApp, Assets, Handle};
+use bevy_color::WorkingColorSpace;
 use bevy_ecs::prelude::*;
 
 /// A handle to a 1 x 1 transparent white image.
@@ -34,8 +45,22 @@ pub const TRANSPARENT_IMAGE_HANDLE: Handle<Image> =
 pub struct ImagePlugin {
     /// The default image sampler to use when [`bevy_image::ImageSampler`] is set to `Default`.
     pub default_sampler: ImageSamplerDescriptor,
+    /// The linear color space in which the renderer does its lighting and blending.
+    ///
+    /// Loaded images are converted into it, according to their
+    /// [`ImageLoaderSettings::color_space`](bevy_image::ImageLoaderSettings::color_space), and
+    /// it is converted back to Rec. 709 when tonemapping. Material and light colors aren't
+    /// converted, and should be given in this color space using
+    /// [`WorkingColorSpace::from_linear_rec709`].
+    pub working_color_space: WorkingColorSpace,
 }
 
+/// The [`WorkingColorSpace`] configured in the [`ImagePlugin`].
+///
+/// This resource is available in both the main world and the render world.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RenderWorkingColorSpace(pub WorkingColorSpace);
+
 impl Default for ImagePlugin {
     fn default() -> Self {
         ImagePlugin::default_linear()
@@ -47,6 +72,7 @@ impl ImagePlugin {
     pub fn default_linear() -> ImagePlugin {
         ImagePlugin {
             default_sampler: ImageSamplerDescriptor::linear(),
+            working_color_space: WorkingColorSpace::default(),
         }
     }
 
@@ -54,6 +80,7 @@ impl ImagePlugin {
     pub fn default_nearest() -> ImagePlugin {
         ImagePlugin {
             default_sampler: ImageSamplerDescriptor::nearest(),
+            working_color_space: WorkingColorSpace::default(),
         }
     }
 }
@@ -70,10 +97,18 @@ impl Plugin for ImagePlugin {
             app.init_asset_loader::<HdrTextureLoader>();
         }
 
-        app.add_plugins(RenderAssetPlugin::<GpuImage>::default())
-            .register_type::<Image>()
-            .init_asset::<Image>()
-            .register_asset_reflect::<Image>();
+        app.add_plugins((
+            RenderAssetPlugin::<GpuImage>::default(),
+            RenderAssetPlugin::<GpuSampler>::default(),
+            MipGenerationPlugin,
+        ))
+        .register_type::<Image>()
+        .init_asset::<Image>()
+        .register_asset_reflect::<Image>()
+        .register_type::<SamplerAsset>()
+        .init_asset::<SamplerAsset>()
+        .register_asset_reflect::<SamplerAsset>()
+        .insert_resource(RenderWorkingColorSpace(self.working_color_space));
 
         let mut image_assets = app.world_mut().resource_mut::<Assets<Image>>();
 
@@ -117,7 +152,10 @@ impl Plugin for ImagePlugin {
                 }
                 None => CompressedImageFormats::NONE,
             };
-            app.register_asset_loader(ImageLoader::new(supported_compressed_formats));
+            app.register_asset_loader(
+                ImageLoader::new(supported_compressed_formats)
+                    .with_working_color_space(self.working_color_space),
+            );
         }
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
@@ -127,6 +165,7 @@ impl Plugin for ImagePlugin {
             };
             render_app
                 .insert_resource(DefaultImageSampler(default_sampler))
+                .insert_resource(RenderWorkingColorSpace(self.working_color_space))
                 .init_resource::<FallbackImage>()
                 .init_resource::<FallbackImageZero>()
                 .init_resource::<FallbackImageCubemap>()
@@ -7,11 +7,12 @@ pub use window::*;
 
 use crate::{
     camera::{
-        CameraMainTextureUsages, ClearColor, ClearColorConfig, Exposure, ExtractedCamera,
-        ManualTextureViews, MipBias, NormalizedRenderTarget, TemporalJitter,
+        Camera, CameraMainTextureUsages, ClearColor, ClearColorConfig, Exposure, ExtractedCamera,
+        ExtractedClipPlanes, ManualTextureViews, MipBias, NormalizedRenderTarget, RenderTarget,
+        TemporalJitter, MAX_VIEW_CLIP_PLANES,
     },
     experimental::occlusion_culling::OcclusionCulling,
-    extract_component::ExtractComponentPlugin,
+    extract_component::{ExtractComponent, ExtractComponentPlugin},
     prelude::Shader,
     primitives::Frustum,
     render_asset::RenderAssets,
@@ -29,12 +30,11 @@ use alloc::sync::Arc;
 use bevy_app::{App, Plugin};
 use bevy_color::LinearRgba;
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, query::QueryItem};
 use bevy_image::BevyDefault as _;
 use bevy_math::{mat3, vec2, vec3, Mat3, Mat4, UVec4, Vec2, Vec3, Vec4, Vec4Swizzles};
 use bevy_platform_support::collections::{hash_map::Entry, HashMap};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
-use bevy_render_macros::ExtractComponent;
 use bevy_transform::components::GlobalTransform;
 use core::{
     ops::Range,
@@ -159,19 +159,11 @@ impl Plugin for ViewPlugin {
 /// Some advanced rendering features may require that MSAA is disabled.
 ///
 /// Note that the web currently only supports 1 or 4 samples.
-#[derive(
-    Component,
-    Default,
-    Clone,
-    Copy,
-    ExtractComponent,
-    Reflect,
-    PartialEq,
-    PartialOrd,
-    Eq,
-    Hash,
-    Debug,
-)]
+///
+/// Cameras which render to an image use the image target's [`msaa`] instead, if it's set.
+///
+/// [`msaa`]: crate::camera::ImageRenderTarget::msaa
+#[derive(Component, Default, Clone, Copy, Reflect, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
 #[reflect(Component, Default, PartialEq, Hash, Debug)]
 pub enum Msaa {
     Off = 1,
@@ -188,6 +180,20 @@ impl Msaa {
     }
 }
 
+impl ExtractComponent for Msaa {
+    type QueryData = (&'static Self, Option<&'static Camera>);
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component((msaa, camera): QueryItem<'_, Self::QueryData>) -> Option<Self::Out> {
+        let target_msaa = camera.and_then(|camera| match &camera.target {
+            RenderTarget::Image(image_target) => image_target.msaa,
+            _ => None,
+        });
+        Some(target_msaa.unwrap_or(*msaa))
+    }
+}
+
 /// An identifier for a view that is stable across frames.
 ///
 /// We can't use [`Entity`] for this because render world entities aren't
@@ -558,6 +564,9 @@ pub struct ViewUniform {
     pub frustum: [Vec4; 6],
     pub color_grading: ColorGradingUniform,
     pub mip_bias: f32,
+    /// World-space half spaces of the [`ClipPlane`](crate::camera::ClipPlane)s of this view, in the
+    /// same form as `frustum`. Unused planes are `(0, 0, 0, 1)`, which contains every point.
+    pub clip_planes: [Vec4; MAX_VIEW_CLIP_PLANES],
 }
 
 #[derive(Resource)]
@@ -878,6 +887,7 @@ pub fn prepare_view_uniforms(
         Option<&Frustum>,
         Option<&TemporalJitter>,
         Option<&MipBias>,
+        Option<&ExtractedClipPlanes>,
     )>,
 ) {
     let view_iter = views.iter();
@@ -889,7 +899,16 @@ pub fn prepare_view_uniforms(
     else {
         return;
     };
-    for (entity, extracted_camera, extracted_view, frustum, temporal_jitter, mip_bias) in &views {
+    for (
+        entity,
+        extracted_camera,
+        extracted_view,
+        frustum,
+        temporal_jitter,
+        mip_bias,
+        clip_planes,
+    ) in &views
+    {
         let viewport = extracted_view.viewport.as_vec4();
         let unjittered_projection = extracted_view.clip_from_view;
         let mut clip_from_view = unjittered_projection;
@@ -915,6 +934,14 @@ pub fn prepare_view_uniforms(
             .map(|frustum| frustum.half_spaces.map(|h| h.normal_d()))
             .unwrap_or([Vec4::ZERO; 6]);
 
+        // Unused clip planes keep every point.
+        let mut view_clip_planes = [Vec4::W; MAX_VIEW_CLIP_PLANES];
+        if let Some(clip_planes) = clip_planes {
+            for (view_clip_plane, clip_plane) in view_clip_planes.iter_mut().zip(&clip_planes.0) {
+                *view_clip_plane = *clip_plane;
+            }
+        }
+
         let view_uniforms = ViewUniformOffset {
             offset: writer.write(&ViewUniform {
                 clip_from_world,
@@ -932,6 +959,7 @@ pub fn prepare_view_uniforms(
                 frustum,
                 color_grading: extracted_view.color_grading.clone().into(),
                 mip_bias: mip_bias.unwrap_or(&MipBias(0.0)).0,
+                clip_planes: view_clip_planes,
             }),
         };
 
@@ -60,4 +60,7 @@ struct View {
     frustum: array<vec4<f32>, 6>,
     color_grading: ColorGrading,
     mip_bias: f32,
+    // World-space half spaces of the clip planes, in the same form as `frustum`.
+    // Unused planes are `(0, 0, 0, 1)`, which contains every point.
+    clip_planes: array<vec4<f32>, 4>,
 };
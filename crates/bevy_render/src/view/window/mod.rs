@@ -8,7 +8,8 @@ use bevy_ecs::{entity::hash_map::EntityHashMap, prelude::*};
 use bevy_platform_support::collections::HashSet;
 use bevy_utils::default;
 use bevy_window::{
-    CompositeAlphaMode, PresentMode, PrimaryWindow, RawHandleWrapper, Window, WindowClosing,
+    CompositeAlphaMode, HdrOutput, PresentMode, PrimaryWindow, RawHandleWrapper, Window,
+    WindowClosing,
 };
 use core::{
     num::NonZero,
@@ -68,6 +69,10 @@ pub struct ExtractedWindow {
     pub size_changed: bool,
     pub present_mode_changed: bool,
     pub alpha_mode: CompositeAlphaMode,
+    /// The HDR output requested by the [`Window`], see [`Window::hdr_output`].
+    pub hdr_output: Option<HdrOutput>,
+    /// Whether HDR output was turned on or off this frame, requiring the surface to be reconfigured.
+    pub hdr_output_changed: bool,
 }
 
 impl ExtractedWindow {
@@ -81,8 +86,18 @@ impl ExtractedWindow {
         ));
         self.swap_chain_texture = Some(SurfaceTexture::from(frame));
     }
+
+    /// Returns the HDR output settings of this window if HDR output is active, meaning that it
+    /// was requested and that the surface supports it.
+    pub fn active_hdr_output(&self) -> Option<HdrOutput> {
+        self.hdr_output
+            .filter(|_| self.swap_chain_texture_format == Some(HDR_SURFACE_FORMAT))
+    }
 }
 
+/// The surface format used for HDR output: linear, extended-range scRGB.
+const HDR_SURFACE_FORMAT: TextureFormat = TextureFormat::Rgba16Float;
+
 #[derive(Default, Resource)]
 pub struct ExtractedWindows {
     pub primary: Option<Entity>,
@@ -133,6 +148,8 @@ fn extract_windows(
             swap_chain_texture_format: None,
             present_mode_changed: false,
             alpha_mode: window.composite_alpha_mode,
+            hdr_output: window.hdr_output,
+            hdr_output_changed: false,
         });
 
         // NOTE: Drop the swap chain frame here
@@ -141,6 +158,9 @@ fn extract_windows(
             || new_height != extracted_window.physical_height;
         extracted_window.present_mode_changed =
             window.present_mode != extracted_window.present_mode;
+        extracted_window.hdr_output_changed =
+            window.hdr_output.is_some() != extracted_window.hdr_output.is_some();
+        extracted_window.hdr_output = window.hdr_output;
 
         if extracted_window.size_changed {
             debug!(
@@ -287,6 +307,7 @@ pub fn need_surface_configuration(
         if !window_surfaces.configured_windows.contains(&window.entity)
             || window.size_changed
             || window.present_mode_changed
+            || window.hdr_output_changed
         {
             return true;
         }
@@ -300,6 +321,41 @@ pub fn need_surface_configuration(
 // has to wait for the cpu to finish to start on the next frame.
 const DEFAULT_DESIRED_MAXIMUM_FRAME_LATENCY: u32 = 2;
 
+/// Chooses the format of a window's surface among the `formats` it supports.
+fn select_surface_format(formats: &[TextureFormat], window: &ExtractedWindow) -> TextureFormat {
+    if window.hdr_output.is_some() {
+        if formats.contains(&HDR_SURFACE_FORMAT) {
+            return HDR_SURFACE_FORMAT;
+        }
+        warn!(
+            "HDR output was requested for window {}, but its surface doesn't support it. \
+            Falling back to SDR output.",
+            window.entity
+        );
+    }
+
+    // Prefer sRGB formats for surfaces, but fall back to first available format if no sRGB formats are available.
+    let mut format = *formats.first().expect("No supported formats for surface");
+    for &available_format in formats {
+        // Rgba8UnormSrgb and Bgra8UnormSrgb and the only sRGB formats wgpu exposes that we can use for surfaces.
+        if available_format == TextureFormat::Rgba8UnormSrgb
+            || available_format == TextureFormat::Bgra8UnormSrgb
+        {
+            format = available_format;
+            break;
+        }
+    }
+    format
+}
+
+fn surface_view_formats(format: TextureFormat) -> Vec<TextureFormat> {
+    if !format.is_srgb() && format.add_srgb_suffix() != format {
+        vec![format.add_srgb_suffix()]
+    } else {
+        vec![]
+    }
+}
+
 /// Creates window surfaces.
 pub fn create_surfaces(
     // By accessing a NonSend resource, we tell the scheduler to put this system on the main thread,
@@ -331,20 +387,7 @@ pub fn create_surfaces(
                         .expect("Failed to create wgpu surface")
                 };
                 let caps = surface.get_capabilities(&render_adapter);
-                let formats = caps.formats;
-                // For future HDR output support, we'll need to request a format that supports HDR,
-                // but as of wgpu 0.15 that is not yet supported.
-                // Prefer sRGB formats for surfaces, but fall back to first available format if no sRGB formats are available.
-                let mut format = *formats.first().expect("No supported formats for surface");
-                for available_format in formats {
-                    // Rgba8UnormSrgb and Bgra8UnormSrgb and the only sRGB formats wgpu exposes that we can use for surfaces.
-                    if available_format == TextureFormat::Rgba8UnormSrgb
-                        || available_format == TextureFormat::Bgra8UnormSrgb
-                    {
-                        format = available_format;
-                        break;
-                    }
-                }
+                let format = select_surface_format(&caps.formats, window);
 
                 let configuration = SurfaceConfiguration {
                     format,
@@ -374,11 +417,7 @@ pub fn create_surfaces(
                         }
                         CompositeAlphaMode::Inherit => wgpu::CompositeAlphaMode::Inherit,
                     },
-                    view_formats: if !format.is_srgb() {
-                        vec![format.add_srgb_suffix()]
-                    } else {
-                        vec![]
-                    },
+                    view_formats: surface_view_formats(format),
                 };
 
                 render_device.configure_surface(&surface, &configuration);
@@ -389,7 +428,12 @@ pub fn create_surfaces(
                 }
             });
 
-        if window.size_changed || window.present_mode_changed {
+        if window.size_changed || window.present_mode_changed || window.hdr_output_changed {
+            if window.hdr_output_changed {
+                let caps = data.surface.get_capabilities(&render_adapter);
+                data.configuration.format = select_surface_format(&caps.formats, window);
+                data.configuration.view_formats = surface_view_formats(data.configuration.format);
+            }
             data.configuration.width = window.physical_width;
             data.configuration.height = window.physical_height;
             data.configuration.present_mode = match window.present_mode {
@@ -1,3 +1,5 @@
+use core::ops::Range;
+
 use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::reflect::ReflectResource;
@@ -65,9 +67,19 @@ impl DynamicScene {
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+        self.allocate_entities(world, entity_map);
+        self.write_entities_to_world(world, entity_map, &type_registry, 0..self.entities.len())?;
+        // Insert resources after all entities have been added to the world.
+        // This ensures the entities are available for the resources to reference during mapping.
+        self.write_resources_to_world(world, entity_map, &type_registry)
+    }
 
-        // First ensure that every entity in the scene has a corresponding world
-        // entity in the entity map.
+    /// Ensures that every entity in the scene has a corresponding world entity in the entity map.
+    pub(crate) fn allocate_entities(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) {
         for scene_entity in &self.entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
@@ -76,8 +88,19 @@ impl DynamicScene {
                 .entry(scene_entity.entity)
                 .or_insert_with(|| world.spawn_empty().id());
         }
+    }
 
-        for scene_entity in &self.entities {
+    /// Writes the components of the entities at `range` in [`Self::entities`] to the given world.
+    ///
+    /// [`Self::allocate_entities`] must have been called with the same `entity_map` first.
+    pub(crate) fn write_entities_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        range: Range<usize>,
+    ) -> Result<(), SceneSpawnError> {
+        for scene_entity in &self.entities[range] {
             // Fetch the entity with the given entity id from the `entity_map`.
             let entity = *entity_map
                 .get(&scene_entity.entity)
@@ -114,13 +137,21 @@ impl DynamicScene {
                 reflect_component.apply_or_insert(
                     &mut world.entity_mut(entity),
                     component.as_partial_reflect(),
-                    &type_registry,
+                    type_registry,
                 );
             }
         }
 
-        // Insert resources after all entities have been added to the world.
-        // This ensures the entities are available for the resources to reference during mapping.
+        Ok(())
+    }
+
+    /// Inserts the resources of the scene into the given world.
+    pub(crate) fn write_resources_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         for resource in &self.resources {
             let mut resource = resource.clone_value();
             let type_info = resource.get_represented_type_info().ok_or_else(|| {
@@ -149,7 +180,7 @@ impl DynamicScene {
 
             // If the world already contains an instance of the given resource
             // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), &type_registry);
+            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), type_registry);
         }
 
         Ok(())
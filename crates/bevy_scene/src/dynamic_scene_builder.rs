@@ -7,8 +7,9 @@ use bevy_ecs::{
     resource::Resource,
     world::World,
 };
-use bevy_reflect::{PartialReflect, ReflectFromReflect};
-use bevy_utils::default;
+use bevy_reflect::{FromReflect, PartialReflect, Reflect, ReflectFromReflect};
+use bevy_utils::{default, TypeIdMap};
+use core::any::TypeId;
 
 /// A [`DynamicScene`] builder, used to build a scene from a [`World`] by extracting some entities and resources.
 ///
@@ -30,6 +31,10 @@ use bevy_utils::default;
 ///
 /// Extraction happens immediately and uses the filter as it exists during the time of extraction.
 ///
+/// Extracted resources can also be modified before they are stored in the scene by
+/// [adding a hook](DynamicSceneBuilder::with_resource_hook), for example to strip data that
+/// should not be saved.
+///
 /// # Entity Order
 ///
 /// Extracted entities will always be stored in ascending order based on their [index](Entity::index).
@@ -59,9 +64,12 @@ pub struct DynamicSceneBuilder<'w> {
     extracted_scene: BTreeMap<Entity, DynamicEntity>,
     component_filter: SceneFilter,
     resource_filter: SceneFilter,
+    resource_hooks: TypeIdMap<ResourceHook<'w>>,
     original_world: &'w World,
 }
 
+type ResourceHook<'w> = Box<dyn Fn(&mut Box<dyn PartialReflect>) + 'w>;
+
 impl<'w> DynamicSceneBuilder<'w> {
     /// Prepare a builder that will extract entities and their component from the given [`World`].
     pub fn from_world(world: &'w World) -> Self {
@@ -70,6 +78,7 @@ impl<'w> DynamicSceneBuilder<'w> {
             extracted_scene: default(),
             component_filter: SceneFilter::default(),
             resource_filter: SceneFilter::default(),
+            resource_hooks: default(),
             original_world: world,
         }
     }
@@ -88,6 +97,57 @@ impl<'w> DynamicSceneBuilder<'w> {
         self
     }
 
+    /// Adds a hook which is called on each extracted value of the resource type `R`, before it is
+    /// stored in the scene.
+    ///
+    /// This can be used to change how a resource is saved without changing the resource in the
+    /// world, such as converting absolute file paths to relative ones.
+    /// Only one hook can be added per resource type; adding another one replaces it.
+    ///
+    /// Like filters, hooks are applied at the time of extraction, so they must be added before
+    /// calling [`extract_resources`](Self::extract_resources).
+    ///
+    /// ```
+    /// # use bevy_scene::DynamicSceneBuilder;
+    /// # use bevy_ecs::reflect::AppTypeRegistry;
+    /// # use bevy_ecs::prelude::{ReflectResource, Resource, World};
+    /// # use bevy_reflect::Reflect;
+    /// #[derive(Resource, Default, Reflect)]
+    /// #[reflect(Resource)]
+    /// struct SaveDirectory(String);
+    ///
+    /// # let mut world = World::default();
+    /// # world.init_resource::<AppTypeRegistry>();
+    /// # world.resource::<AppTypeRegistry>().write().register::<SaveDirectory>();
+    /// world.insert_resource(SaveDirectory("/home/user/game/saves".to_string()));
+    ///
+    /// let scene = DynamicSceneBuilder::from_world(&world)
+    ///     .with_resource_hook(|directory: &mut SaveDirectory| {
+    ///         directory.0 = directory.0.trim_start_matches("/home/user/game/").to_string();
+    ///     })
+    ///     .extract_resources()
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn with_resource_hook<R: Resource + Reflect + FromReflect>(
+        mut self,
+        hook: impl Fn(&mut R) + 'w,
+    ) -> Self {
+        self.resource_hooks.insert(
+            TypeId::of::<R>(),
+            Box::new(move |resource: &mut Box<dyn PartialReflect>| {
+                if let Some(resource) = resource.try_downcast_mut::<R>() {
+                    hook(resource);
+                } else if let Some(mut concrete) = R::from_reflect(resource.as_partial_reflect()) {
+                    // The resource was extracted as a dynamic value, so replace it with the hooked concrete value
+                    hook(&mut concrete);
+                    *resource = Box::new(concrete);
+                }
+            }),
+        );
+        self
+    }
+
     /// Updates the filter to allow all component and resource types.
     ///
     /// This is useful for resetting the filter so that types may be selectively denied
@@ -325,7 +385,8 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// Re-extracting a resource that was already extracted will have no effect.
     ///
     /// To control which resources are extracted, use the [`allow_resource`] or
-    /// [`deny_resource`] helper methods.
+    /// [`deny_resource`] helper methods. To modify extracted resources, use
+    /// [`with_resource_hook`].
     ///
     /// ```
     /// # use bevy_scene::DynamicSceneBuilder;
@@ -346,6 +407,7 @@ impl<'w> DynamicSceneBuilder<'w> {
     ///
     /// [`allow_resource`]: Self::allow_resource
     /// [`deny_resource`]: Self::deny_resource
+    /// [`with_resource_hook`]: Self::with_resource_hook
     #[must_use]
     pub fn extract_resources(mut self) -> Self {
         let type_registry = self.original_world.resource::<AppTypeRegistry>().read();
@@ -371,12 +433,16 @@ impl<'w> DynamicSceneBuilder<'w> {
                     .data::<ReflectResource>()?
                     .reflect(self.original_world)?;
 
-                let resource = type_registration
+                let mut resource = type_registration
                     .data::<ReflectFromReflect>()
                     .and_then(|fr| fr.from_reflect(resource.as_partial_reflect()))
                     .map(PartialReflect::into_partial_reflect)
                     .unwrap_or_else(|| resource.clone_value());
 
+                if let Some(hook) = self.resource_hooks.get(&type_id) {
+                    hook(&mut resource);
+                }
+
                 self.extracted_resources.insert(component_id, resource);
                 Some(())
             };
@@ -730,4 +796,38 @@ mod tests {
             .expect("resource should be concrete due to `FromReflect`")
             .is::<SomeType>());
     }
+
+    #[test]
+    fn should_apply_resource_hooks() {
+        #[derive(Resource, Reflect, Default, Eq, PartialEq, Debug)]
+        #[reflect(Resource)]
+        struct ResourceC(u32);
+
+        let mut world = World::default();
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<ResourceA>();
+            register.register::<ResourceC>();
+        }
+        world.insert_resource(atr);
+
+        world.insert_resource(ResourceA);
+        world.insert_resource(ResourceC(1));
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .with_resource_hook(|resource: &mut ResourceC| resource.0 += 1)
+            .extract_resources()
+            .build();
+
+        assert_eq!(scene.resources.len(), 2);
+        let resource = scene
+            .resources
+            .iter()
+            .find_map(|resource| resource.try_downcast_ref::<ResourceC>())
+            .expect("resource should be extracted");
+        assert_eq!(resource, &ResourceC(2));
+        assert_eq!(world.resource::<ResourceC>(), &ResourceC(1));
+    }
 }
@@ -20,6 +20,7 @@ mod scene;
 mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
+mod scene_validation;
 
 #[cfg(feature = "serialize")]
 pub mod serde;
@@ -35,6 +36,7 @@ pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
+pub use scene_validation::*;
 
 /// The scene prelude.
 ///
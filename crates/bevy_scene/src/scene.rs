@@ -1,11 +1,15 @@
+use core::{any::TypeId, ops::Range};
+
 use crate::{DynamicScene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::{
+    archetype::Archetype,
     entity::{hash_map::EntityHashMap, Entity, SceneEntityMapper},
+    entity_disabling::DefaultQueryFilters,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath};
+use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
 
 /// A composition of [`World`] objects.
 ///
@@ -58,8 +62,22 @@ impl Scene {
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+        self.write_resources_to_world(world, &type_registry)?;
+        self.allocate_entities(world, entity_map);
+        self.write_entities_to_world(world, entity_map, &type_registry, 0..self.entity_count())
+    }
 
-        // Resources archetype
+    /// Returns the number of entities in the scene.
+    pub fn entity_count(&self) -> usize {
+        self.world.archetypes().iter().map(Archetype::len).sum()
+    }
+
+    /// Copies the resources of the scene into the given world.
+    pub(crate) fn write_resources_to_world(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
         for (component_id, resource_data) in self.world.storages().resources.iter() {
             if !resource_data.is_present() {
                 continue;
@@ -75,6 +93,12 @@ impl Scene {
                 .type_id()
                 .expect("reflected resources must have a type_id");
 
+            // The default query filters store `ComponentId`s that are only valid in the world
+            // they were created in, and every world already inserts its own on creation.
+            if type_id == TypeId::of::<DefaultQueryFilters>() {
+                continue;
+            }
+
             let registration =
                 type_registry
                     .get(type_id)
@@ -86,11 +110,19 @@ impl Scene {
                     type_path: registration.type_info().type_path().to_string(),
                 }
             })?;
-            reflect_resource.copy(&self.world, world, &type_registry);
+            reflect_resource.copy(&self.world, world, type_registry);
         }
 
-        // Ensure that all scene entities have been allocated in the destination
-        // world before handling components that may contain references that need mapping.
+        Ok(())
+    }
+
+    /// Ensures that all scene entities have been allocated in the destination world, so that
+    /// components which reference other scene entities can be mapped.
+    pub(crate) fn allocate_entities(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) {
         for archetype in self.world.archetypes().iter() {
             for scene_entity in archetype.entities() {
                 entity_map
@@ -98,14 +130,34 @@ impl Scene {
                     .or_insert_with(|| world.spawn_empty().id());
             }
         }
+    }
 
+    /// Writes the components of the scene entities in `range` to the given world.
+    ///
+    /// Entities are ordered by archetype, so that the reflection data of each archetype's
+    /// components only has to be looked up once rather than for every entity.
+    /// [`Self::allocate_entities`] must have been called with the same `entity_map` first.
+    pub(crate) fn write_entities_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        range: Range<usize>,
+    ) -> Result<(), SceneSpawnError> {
+        let mut archetype_start = 0;
         for archetype in self.world.archetypes().iter() {
-            for scene_entity in archetype.entities() {
-                let entity = *entity_map
-                    .get(&scene_entity.id())
-                    .expect("should have previously spawned an entity");
+            let archetype_range = archetype_start..archetype_start + archetype.len();
+            archetype_start = archetype_range.end;
 
-                for component_id in archetype.components() {
+            let start = range.start.max(archetype_range.start);
+            let end = range.end.min(archetype_range.end);
+            if start >= end {
+                continue;
+            }
+
+            let components = archetype
+                .components()
+                .map(|component_id| {
                     let component_info = self
                         .world
                         .components()
@@ -123,7 +175,18 @@ impl Scene {
                                 type_path: registration.type_info().type_path().to_string(),
                             }
                         })?;
+                    Ok((reflect_component, registration.data::<ReflectMapEntities>()))
+                })
+                .collect::<Result<Vec<_>, SceneSpawnError>>()?;
+
+            let entities =
+                &archetype.entities()[start - archetype_range.start..end - archetype_range.start];
+            for scene_entity in entities {
+                let entity = *entity_map
+                    .get(&scene_entity.id())
+                    .expect("should have previously spawned an entity");
 
+                for (reflect_component, map_entities) in &components {
                     let Some(mut component) = reflect_component
                         .reflect(self.world.entity(scene_entity.id()))
                         .map(PartialReflect::clone_value)
@@ -133,7 +196,7 @@ impl Scene {
 
                     // If this component references entities in the scene,
                     // update them to the entities in the world.
-                    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                    if let Some(map_entities) = map_entities {
                         SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
                             map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
                         });
@@ -141,7 +204,7 @@ impl Scene {
                     reflect_component.apply_or_insert(
                         &mut world.entity_mut(entity),
                         component.as_partial_reflect(),
-                        &type_registry,
+                        type_registry,
                     );
                 }
             }
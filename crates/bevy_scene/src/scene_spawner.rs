@@ -69,6 +69,10 @@ impl InstanceId {
 /// - [`spawn_as_child`](Self::spawn_as_child)
 /// - [`despawn`](Self::despawn)
 /// - [`despawn_instance`](Self::despawn_instance)
+///
+/// By default, queued scenes are spawned in full the next time the [`scene_spawner_system`] runs,
+/// which can stall a frame for scenes with many entities. Use [`set_spawn_budget`](Self::set_spawn_budget)
+/// to spread the work across several frames instead.
 #[derive(Default, Resource)]
 pub struct SceneSpawner {
     pub(crate) spawned_dynamic_scenes: HashMap<AssetId<DynamicScene>, HashSet<InstanceId>>,
@@ -79,6 +83,99 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    spawn_budget: Option<usize>,
+    partial_spawns: Vec<PartialSpawn>,
+}
+
+/// A scene queued for spawning.
+enum QueuedScene {
+    Dynamic(Handle<DynamicScene>),
+    Scene(Handle<Scene>),
+}
+
+/// A queued scene instance whose entities are written to the world over several frames,
+/// when the [`SceneSpawner`] has a spawn budget.
+struct PartialSpawn {
+    scene: QueuedScene,
+    instance_id: InstanceId,
+    parent: Option<Entity>,
+    entity_map: EntityHashMap<Entity>,
+    /// The number of scene entities whose components have already been written.
+    written: usize,
+}
+
+impl PartialSpawn {
+    fn new(scene: QueuedScene, instance_id: InstanceId, parent: Option<Entity>) -> Self {
+        Self {
+            scene,
+            instance_id,
+            parent,
+            entity_map: EntityHashMap::default(),
+            written: 0,
+        }
+    }
+
+    /// Writes up to `budget` more entities of the scene to the world, subtracting the number
+    /// of entities written from the budget.
+    ///
+    /// Returns `true` once the whole scene has been written.
+    fn write(&mut self, world: &mut World, budget: &mut usize) -> Result<bool, SceneSpawnError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        match &self.scene {
+            QueuedScene::Dynamic(handle) => {
+                let id = handle.id();
+                world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                    let scene = scenes
+                        .get(id)
+                        .ok_or(SceneSpawnError::NonExistentScene { id })?;
+
+                    if self.written == 0 {
+                        scene.allocate_entities(world, &mut self.entity_map);
+                    }
+                    let end = scene.entities.len().min(self.written + *budget);
+                    scene.write_entities_to_world(
+                        world,
+                        &mut self.entity_map,
+                        &registry,
+                        self.written..end,
+                    )?;
+                    *budget -= end - self.written;
+                    self.written = end;
+                    if end < scene.entities.len() {
+                        return Ok(false);
+                    }
+
+                    scene.write_resources_to_world(world, &mut self.entity_map, &registry)?;
+                    Ok(true)
+                })
+            }
+            QueuedScene::Scene(handle) => {
+                let id = handle.id();
+                world.resource_scope(|world, scenes: Mut<Assets<Scene>>| {
+                    let scene = scenes
+                        .get(id)
+                        .ok_or(SceneSpawnError::NonExistentRealScene { id })?;
+
+                    if self.written == 0 {
+                        scene.write_resources_to_world(world, &registry)?;
+                        scene.allocate_entities(world, &mut self.entity_map);
+                    }
+                    let entity_count = scene.entity_count();
+                    let end = entity_count.min(self.written + *budget);
+                    scene.write_entities_to_world(
+                        world,
+                        &mut self.entity_map,
+                        &registry,
+                        self.written..end,
+                    )?;
+                    *budget -= end - self.written;
+                    self.written = end;
+                    Ok(end == entity_count)
+                })
+            }
+        }
+    }
 }
 
 /// Errors that can occur when spawning a scene.
@@ -198,16 +295,47 @@ impl SceneSpawner {
     }
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
+    ///
+    /// If the instance is still being spawned over several frames, its spawn is cancelled.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
-        if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for &entity in instance.entity_map.values() {
-                if let Ok(entity_mut) = world.get_entity_mut(entity) {
-                    entity_mut.despawn();
-                };
-            }
+        let entity_map = if let Some(instance) = self.spawned_instances.remove(instance_id) {
+            instance.entity_map
+        } else if let Some(index) = self
+            .partial_spawns
+            .iter()
+            .position(|partial_spawn| partial_spawn.instance_id == *instance_id)
+        {
+            self.partial_spawns.remove(index).entity_map
+        } else {
+            return;
+        };
+
+        for &entity in entity_map.values() {
+            if let Ok(entity_mut) = world.get_entity_mut(entity) {
+                entity_mut.despawn();
+            };
         }
     }
 
+    /// Limits the number of scene entities written to the world each time the queued scenes
+    /// are spawned, which spreads the spawning of large scenes across several frames.
+    ///
+    /// Scene instances are spawned in the order they were queued, and only become ready (see
+    /// [`instance_is_ready`](Self::instance_is_ready) and [`SceneInstanceReady`]) once all of
+    /// their entities have been written. Until then, the entities of a partially spawned
+    /// instance exist in the world, but may be missing some of their components.
+    ///
+    /// `None`, the default, spawns every queued scene in full.
+    pub fn set_spawn_budget(&mut self, entities_per_frame: Option<usize>) {
+        self.spawn_budget = entities_per_frame.map(|budget| budget.max(1));
+    }
+
+    /// Returns the maximum number of scene entities written to the world each time the queued
+    /// scenes are spawned. See [`set_spawn_budget`](Self::set_spawn_budget).
+    pub fn spawn_budget(&self) -> Option<usize> {
+        self.spawn_budget
+    }
+
     /// Immediately spawns a new instance of the provided dynamic scene.
     pub fn spawn_dynamic_sync(
         &mut self,
@@ -312,7 +440,14 @@ impl SceneSpawner {
     }
 
     /// Immediately spawns all scenes scheduled for spawn.
+    ///
+    /// If a [spawn budget](Self::set_spawn_budget) is set, only spawns up to that many entities,
+    /// and continues with the remaining entities the next time this is called.
     pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        if let Some(budget) = self.spawn_budget {
+            return self.spawn_queued_scenes_with_budget(world, budget);
+        }
+
         let scenes_to_spawn = core::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (handle, instance_id, parent) in scenes_to_spawn {
@@ -371,6 +506,74 @@ impl SceneSpawner {
         Ok(())
     }
 
+    fn spawn_queued_scenes_with_budget(
+        &mut self,
+        world: &mut World,
+        mut budget: usize,
+    ) -> Result<(), SceneSpawnError> {
+        // Newly queued scenes go after the ones which are already partially spawned,
+        // so that instances finish spawning in the order they were queued.
+        for (handle, instance_id, parent) in core::mem::take(&mut self.dynamic_scenes_to_spawn) {
+            self.partial_spawns.push(PartialSpawn::new(
+                QueuedScene::Dynamic(handle),
+                instance_id,
+                parent,
+            ));
+        }
+        for (handle, instance_id, parent) in core::mem::take(&mut self.scenes_to_spawn) {
+            self.partial_spawns.push(PartialSpawn::new(
+                QueuedScene::Scene(handle),
+                instance_id,
+                parent,
+            ));
+        }
+
+        let partial_spawns = core::mem::take(&mut self.partial_spawns);
+        for mut partial_spawn in partial_spawns {
+            if budget == 0 {
+                self.partial_spawns.push(partial_spawn);
+                continue;
+            }
+
+            match partial_spawn.write(world, &mut budget) {
+                Ok(true) => {
+                    let PartialSpawn {
+                        scene,
+                        instance_id,
+                        parent,
+                        entity_map,
+                        ..
+                    } = partial_spawn;
+                    self.spawned_instances
+                        .insert(instance_id, InstanceInfo { entity_map });
+                    if let QueuedScene::Dynamic(handle) = scene {
+                        self.spawned_dynamic_scenes
+                            .entry(handle.id())
+                            .or_default()
+                            .insert(instance_id);
+                    }
+
+                    // Scenes with parents need more setup before they are ready.
+                    // See `set_scene_instance_parent_sync()`.
+                    if parent.is_none() {
+                        // Defer via commands otherwise SceneSpawner is not available in the observer.
+                        world.commands().trigger(SceneInstanceReady { instance_id });
+                    }
+                }
+                Ok(false)
+                | Err(
+                    SceneSpawnError::NonExistentScene { .. }
+                    | SceneSpawnError::NonExistentRealScene { .. },
+                ) => {
+                    self.partial_spawns.push(partial_spawn);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
         let scenes_with_parent = core::mem::take(&mut self.scenes_with_parent);
 
@@ -446,6 +649,16 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .scenes_to_spawn
             .retain(|(_, instance, _)| !dead_instances.contains(instance));
+        // Partially spawned instances already have entities in the world, which must be cleaned up
+        let partial_dead_instances = scene_spawner
+            .partial_spawns
+            .iter()
+            .map(|partial_spawn| partial_spawn.instance_id)
+            .filter(|instance| dead_instances.contains(instance))
+            .collect::<Vec<_>>();
+        for instance in &partial_dead_instances {
+            scene_spawner.despawn_instance_sync(world, instance);
+        }
 
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
@@ -816,6 +1029,46 @@ mod tests {
         observe_trigger(&mut app, scene_id, scene_entity);
     }
 
+    #[test]
+    fn spawn_scene_with_budget() {
+        let mut app = setup();
+        for _ in 0..3 {
+            app.world_mut().spawn(ComponentF);
+        }
+
+        // Build scene.
+        let scene = build_dynamic_scene(&mut app);
+
+        // Spawn scene, writing at most 2 entities per frame.
+        let scene_id = app
+            .world_mut()
+            .run_system_once(move |mut scene_spawner: ResMut<'_, SceneSpawner>| {
+                scene_spawner.set_spawn_budget(Some(2));
+                scene_spawner.spawn_dynamic(scene.clone())
+            })
+            .unwrap();
+
+        // The scene has 5 entities, so it takes 3 frames to spawn.
+        for _ in 0..2 {
+            app.update();
+            assert!(!app
+                .world()
+                .resource::<SceneSpawner>()
+                .instance_is_ready(scene_id));
+        }
+        app.update();
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(scene_id));
+        assert_eq!(scene_spawner.iter_instance_entities(scene_id).count(), 5);
+
+        let count = app
+            .world_mut()
+            .query::<&ComponentF>()
+            .iter(app.world())
+            .count();
+        assert_eq!(count, 10);
+    }
+
     #[test]
     fn despawn_scene() {
         let mut app = App::new();
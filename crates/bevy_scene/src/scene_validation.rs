@@ -0,0 +1,344 @@
+use core::fmt;
+
+use crate::DynamicScene;
+use bevy_ecs::{
+    entity::{hash_set::EntityHashSet, Entity, EntityMapper},
+    reflect::{ReflectComponent, ReflectMapEntities, ReflectResource},
+};
+use bevy_reflect::{PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry};
+use thiserror::Error;
+
+/// A problem with a value in a [`DynamicScene`], found by [`DynamicScene::validate`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SceneValidationError {
+    /// The value is a dynamic type without a represented type.
+    #[error("dynamic type `{type_path}` has no represented type. consider changing this using `set_represented_type`")]
+    NoRepresentedType {
+        /// The dynamic type of the value.
+        type_path: String,
+    },
+    /// The type of the value isn't in the type registry.
+    #[error("type `{type_path}` was not found in the type registry. consider registering the type using `app.register_type::<T>()`")]
+    UnregisteredType {
+        /// The unregistered type.
+        type_path: String,
+    },
+    /// The value is used as a component, but its type doesn't reflect `Component`.
+    #[error("type `{type_path}` is used as a component, but doesn't have `ReflectComponent` type data. consider adding `#[reflect(Component)]` to your type")]
+    MissingReflectComponent {
+        /// The type of the component.
+        type_path: String,
+    },
+    /// The value is used as a resource, but its type doesn't reflect `Resource`.
+    #[error("type `{type_path}` is used as a resource, but doesn't have `ReflectResource` type data. consider adding `#[reflect(Resource)]` to your type")]
+    MissingReflectResource {
+        /// The type of the resource.
+        type_path: String,
+    },
+    /// The value can't be converted to its concrete type, usually because of missing or
+    /// mistyped fields.
+    #[error("value of type `{type_path}` could not be converted with `FromReflect`. check that it has all of the type's fields, with the right types")]
+    FromReflectFailed {
+        /// The type of the value.
+        type_path: String,
+    },
+    /// The value references an entity that isn't part of the scene.
+    #[error("value of type `{type_path}` references the entity {entity}, which is not part of the scene")]
+    UnresolvedEntity {
+        /// The type of the value.
+        type_path: String,
+        /// The referenced entity.
+        entity: Entity,
+    },
+}
+
+/// Where a [`SceneValidationError`] was found in a [`DynamicScene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneValidationSource {
+    /// A component of the scene entity with this identifier.
+    Entity(Entity),
+    /// A resource of the scene.
+    Resource,
+}
+
+/// A [`SceneValidationError`], along with where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SceneValidationIssue {
+    /// Where the problem was found.
+    pub source: SceneValidationSource,
+    /// The problem.
+    pub error: SceneValidationError,
+}
+
+impl fmt::Display for SceneValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.source {
+            SceneValidationSource::Entity(entity) => write!(f, "entity {entity}: {}", self.error),
+            SceneValidationSource::Resource => write!(f, "resource: {}", self.error),
+        }
+    }
+}
+
+/// The result of [`DynamicScene::validate`], listing every problem found in the scene.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SceneValidationReport {
+    /// The problems found in the scene, in the order of the scene's entities and resources.
+    pub issues: Vec<SceneValidationIssue>,
+}
+
+impl SceneValidationReport {
+    /// Returns `true` if no problems were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Iterates over the problems found in the scene.
+    pub fn iter(&self) -> impl Iterator<Item = &SceneValidationIssue> {
+        self.issues.iter()
+    }
+}
+
+impl fmt::Display for SceneValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "scene is valid");
+        }
+        write!(f, "scene has {} problem(s):", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n- {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl DynamicScene {
+    /// Checks that the scene can be written to a world with the given type registry, without
+    /// modifying any world.
+    ///
+    /// This finds every problem at once, rather than stopping at the first error like
+    /// [`DynamicScene::write_to_world`]. It checks for:
+    /// - values whose types aren't registered, or don't reflect `Component` or `Resource`,
+    /// - values that can't be converted with `FromReflect`,
+    /// - entity references to entities that aren't part of the scene.
+    pub fn validate(&self, type_registry: &TypeRegistry) -> SceneValidationReport {
+        let scene_entities = self
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .collect::<EntityHashSet>();
+        let mut report = SceneValidationReport::default();
+
+        for scene_entity in &self.entities {
+            let source = SceneValidationSource::Entity(scene_entity.entity);
+            for component in &scene_entity.components {
+                validate_value(
+                    component.as_ref(),
+                    type_registry,
+                    &scene_entities,
+                    |registration, type_path| {
+                        registration
+                            .data::<ReflectComponent>()
+                            .is_none()
+                            .then(|| SceneValidationError::MissingReflectComponent { type_path })
+                    },
+                    |error| report.issues.push(SceneValidationIssue { source, error }),
+                );
+            }
+        }
+
+        for resource in &self.resources {
+            validate_value(
+                resource.as_ref(),
+                type_registry,
+                &scene_entities,
+                |registration, type_path| {
+                    registration
+                        .data::<ReflectResource>()
+                        .is_none()
+                        .then(|| SceneValidationError::MissingReflectResource { type_path })
+                },
+                |error| {
+                    report.issues.push(SceneValidationIssue {
+                        source: SceneValidationSource::Resource,
+                        error,
+                    });
+                },
+            );
+        }
+
+        report
+    }
+}
+
+/// Reports every problem with a single component or resource of a scene.
+///
+/// `check_kind` returns an error if the registration lacks the type data needed to insert the
+/// value as a component or resource.
+fn validate_value(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+    scene_entities: &EntityHashSet,
+    check_kind: impl FnOnce(&TypeRegistration, String) -> Option<SceneValidationError>,
+    mut report: impl FnMut(SceneValidationError),
+) {
+    let Some(type_info) = value.get_represented_type_info() else {
+        report(SceneValidationError::NoRepresentedType {
+            type_path: value.reflect_type_path().to_string(),
+        });
+        return;
+    };
+    let type_path = type_info.type_path();
+    let Some(registration) = type_registry.get(type_info.type_id()) else {
+        report(SceneValidationError::UnregisteredType {
+            type_path: type_path.to_string(),
+        });
+        return;
+    };
+    if let Some(error) = check_kind(registration, type_path.to_string()) {
+        report(error);
+    }
+
+    // Mapping entities converts the value with `FromReflect`, so only do so once it's known
+    // to succeed.
+    let Some(from_reflect) = registration.data::<ReflectFromReflect>() else {
+        return;
+    };
+    if from_reflect.from_reflect(value).is_none() {
+        report(SceneValidationError::FromReflectFailed {
+            type_path: type_path.to_string(),
+        });
+        return;
+    }
+
+    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+        let mut collector = EntityReferenceCollector {
+            scene_entities,
+            unresolved: Vec::new(),
+        };
+        let mut value = value.clone_value();
+        map_entities.map_entities(value.as_mut(), &mut collector);
+        for entity in collector.unresolved {
+            report(SceneValidationError::UnresolvedEntity {
+                type_path: type_path.to_string(),
+                entity,
+            });
+        }
+    }
+}
+
+/// An [`EntityMapper`] that leaves entities unchanged, collecting the ones outside of the scene.
+struct EntityReferenceCollector<'a> {
+    scene_entities: &'a EntityHashSet,
+    unresolved: Vec<Entity>,
+}
+
+impl EntityMapper for EntityReferenceCollector<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        if entity != Entity::PLACEHOLDER
+            && !self.scene_entities.contains(&entity)
+            && !self.unresolved.contains(&entity)
+        {
+            self.unresolved.push(entity);
+        }
+        entity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, VisitEntities, VisitEntitiesMut},
+        reflect::{ReflectComponent, ReflectMapEntities},
+    };
+    use bevy_reflect::{DynamicTupleStruct, PartialReflect, Reflect, TypeRegistry, Typed};
+
+    use crate::{
+        DynamicEntity, DynamicScene, SceneValidationError, SceneValidationIssue,
+        SceneValidationSource,
+    };
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, VisitEntities, VisitEntitiesMut)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    #[derive(Reflect)]
+    struct NotAComponent;
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct Unregistered;
+
+    #[test]
+    fn validate_scene() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        registry.register::<Target>();
+        registry.register::<NotAComponent>();
+
+        let a = Entity::from_raw(0);
+        let b = Entity::from_raw(1);
+        let outside = Entity::from_raw(7);
+
+        let mut invalid_health = DynamicTupleStruct::default();
+        invalid_health.set_represented_type(Some(Health::type_info()));
+
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![
+                DynamicEntity {
+                    entity: a,
+                    components: vec![Health(10).clone_value(), Target(b).clone_value()],
+                },
+                DynamicEntity {
+                    entity: b,
+                    components: vec![
+                        Target(outside).clone_value(),
+                        NotAComponent.clone_value(),
+                        Unregistered.clone_value(),
+                        Box::new(invalid_health),
+                    ],
+                },
+            ],
+        };
+
+        let report = scene.validate(&registry);
+        assert!(!report.is_valid());
+        let issue = |error| SceneValidationIssue {
+            source: SceneValidationSource::Entity(b),
+            error,
+        };
+        assert_eq!(
+            report.issues,
+            vec![
+                issue(SceneValidationError::UnresolvedEntity {
+                    type_path: "bevy_scene::scene_validation::tests::Target".to_string(),
+                    entity: outside,
+                }),
+                issue(SceneValidationError::MissingReflectComponent {
+                    type_path: "bevy_scene::scene_validation::tests::NotAComponent".to_string(),
+                }),
+                issue(SceneValidationError::UnregisteredType {
+                    type_path: "bevy_scene::scene_validation::tests::Unregistered".to_string(),
+                }),
+                issue(SceneValidationError::FromReflectFailed {
+                    type_path: "bevy_scene::scene_validation::tests::Health".to_string(),
+                }),
+            ]
+        );
+
+        registry.register::<Unregistered>();
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: a,
+                components: vec![Health(10).clone_value(), Unregistered.clone_value()],
+            }],
+        };
+        assert!(scene.validate(&registry).is_valid());
+    }
+}
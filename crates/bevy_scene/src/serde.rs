@@ -5,14 +5,15 @@ use bevy_ecs::entity::Entity;
 use bevy_platform_support::collections::HashSet;
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        LenientReflectDeserializer, LenientTypeRegistrationDeserializer, ReflectDeserializer,
+        TypeRegistrationDeserializer, TypedReflectDeserializer, TypedReflectSerializer,
+        UnknownTypeError,
     },
     PartialReflect, ReflectFromReflect, TypeRegistry,
 };
 use core::fmt::Formatter;
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
     ser::{SerializeMap, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
@@ -175,18 +176,19 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
                 .iter()
                 .map(|entry| {
                     (
-                        entry.get_represented_type_info().unwrap().type_path(),
+                        self.registry
+                            .serialized_type_path(entry.get_represented_type_info().unwrap()),
                         entry.as_partial_reflect(),
                     )
                 })
                 .collect::<Vec<_>>();
-            entries.sort_by_key(|(type_path, _)| *type_path);
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
             entries
         };
 
         for (type_path, partial_reflect) in sorted_entries {
             state.serialize_entry(
-                type_path,
+                &type_path,
                 &TypedReflectSerializer::new(partial_reflect, self.registry),
             )?;
         }
@@ -211,6 +213,11 @@ enum EntityField {
 pub struct SceneDeserializer<'a> {
     /// Type registry in which the components and resources types used in the scene to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// If set, components and resources of types which aren't registered are skipped and
+    /// added to this list, instead of failing deserialization.
+    ///
+    /// See [`LenientReflectDeserializer`].
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
@@ -225,6 +232,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
             &[SCENE_RESOURCES, SCENE_ENTITIES],
             SceneVisitor {
                 type_registry: self.type_registry,
+                unknown_types: self.unknown_types,
             },
         )
     }
@@ -232,6 +240,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
 
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
@@ -241,19 +250,21 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         formatter.write_str("scene struct")
     }
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
         let resources = seq
             .next_element_seed(SceneMapDeserializer {
                 registry: self.type_registry,
+                unknown_types: self.unknown_types.as_deref_mut(),
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
             .next_element_seed(SceneEntitiesDeserializer {
                 type_registry: self.type_registry,
+                unknown_types: self.unknown_types,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
@@ -263,7 +274,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         })
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -277,6 +288,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                     }
                     resources = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.type_registry,
+                        unknown_types: self.unknown_types.as_deref_mut(),
                     })?);
                 }
                 SceneField::Entities => {
@@ -285,6 +297,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                     }
                     entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
                         type_registry: self.type_registry,
+                        unknown_types: self.unknown_types.as_deref_mut(),
                     })?);
                 }
             }
@@ -304,6 +317,8 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
 pub struct SceneEntitiesDeserializer<'a> {
     /// Type registry in which the component types used by the entities to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// If set, components of types which aren't registered are skipped and added to this list.
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
@@ -315,12 +330,14 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     {
         deserializer.deserialize_map(SceneEntitiesVisitor {
             type_registry: self.type_registry,
+            unknown_types: self.unknown_types,
         })
     }
 }
 
 struct SceneEntitiesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
@@ -330,7 +347,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
         formatter.write_str("map of entities")
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -339,6 +356,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
             let entity = map.next_value_seed(SceneEntityDeserializer {
                 entity,
                 type_registry: self.type_registry,
+                unknown_types: self.unknown_types.as_deref_mut(),
             })?;
             entities.push(entity);
         }
@@ -353,6 +371,8 @@ pub struct SceneEntityDeserializer<'a> {
     pub entity: Entity,
     /// Type registry in which the component types used by the entity to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// If set, components of types which aren't registered are skipped and added to this list.
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
@@ -368,6 +388,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
             SceneEntityVisitor {
                 entity: self.entity,
                 registry: self.type_registry,
+                unknown_types: self.unknown_types,
             },
         )
     }
@@ -376,6 +397,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
 struct SceneEntityVisitor<'a> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
@@ -392,6 +414,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         let components = seq
             .next_element_seed(SceneMapDeserializer {
                 registry: self.registry,
+                unknown_types: self.unknown_types,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
@@ -401,7 +424,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         })
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -415,6 +438,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 
                     components = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.registry,
+                        unknown_types: self.unknown_types.as_deref_mut(),
                     })?);
                 }
             }
@@ -434,6 +458,8 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 pub struct SceneMapDeserializer<'a> {
     /// Type registry in which the types of the values to deserialize are registered.
     pub registry: &'a TypeRegistry,
+    /// If set, values of types which aren't registered are skipped and added to this list.
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
@@ -445,12 +471,14 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
     {
         deserializer.deserialize_map(SceneMapVisitor {
             registry: self.registry,
+            unknown_types: self.unknown_types,
         })
     }
 }
 
 struct SceneMapVisitor<'a> {
     pub registry: &'a TypeRegistry,
+    pub unknown_types: Option<&'a mut Vec<UnknownTypeError>>,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
@@ -465,22 +493,56 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let mut dynamic_properties = Vec::new();
-        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
-            dynamic_properties.push(entity);
+        match self.unknown_types {
+            Some(unknown_types) => {
+                while let Some(entity) = seq.next_element_seed(LenientReflectDeserializer::new(
+                    self.registry,
+                    unknown_types,
+                ))? {
+                    dynamic_properties.extend(entity);
+                }
+            }
+            None => {
+                while let Some(entity) =
+                    seq.next_element_seed(ReflectDeserializer::new(self.registry))?
+                {
+                    dynamic_properties.push(entity);
+                }
+            }
         }
 
         Ok(dynamic_properties)
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
         let mut added = <HashSet<_>>::default();
         let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
+        loop {
+            let registration = match self.unknown_types.as_deref_mut() {
+                Some(unknown_types) => {
+                    match map
+                        .next_key_seed(LenientTypeRegistrationDeserializer::new(self.registry))?
+                    {
+                        Some(Ok(registration)) => registration,
+                        Some(Err(unknown_type)) => {
+                            map.next_value::<IgnoredAny>()?;
+                            unknown_types.push(unknown_type);
+                            continue;
+                        }
+                        None => break,
+                    }
+                }
+                None => {
+                    match map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))? {
+                        Some(registration) => registration,
+                        None => break,
+                    }
+                }
+            };
+
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
                     "duplicate reflect type: `{}`",
@@ -699,6 +761,7 @@ mod tests {
         let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
         let scene_deserializer = SceneDeserializer {
             type_registry: &world.resource::<AppTypeRegistry>().read(),
+            unknown_types: None,
         };
         let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
 
@@ -727,6 +790,105 @@ mod tests {
         assert_eq!(1, dst_world.query::<&Baz>().iter(&dst_world).count());
     }
 
+    #[test]
+    fn should_reject_invalid_component_values() {
+        use bevy_reflect::validation::in_range;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Health {
+            #[reflect(validate = in_range(0.0..=100.0))]
+            value: f32,
+        }
+
+        let world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Health>();
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Health": (
+          value: 150.0,
+        ),
+      },
+    ),
+  },
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &world.resource::<AppTypeRegistry>().read(),
+            unknown_types: None,
+        };
+        let error = scene_deserializer
+            .deserialize(&mut deserializer)
+            .err()
+            .unwrap();
+
+        assert!(error
+            .to_string()
+            .contains("`.value`: 150.0 is outside of the range 0.0..=100.0"));
+    }
+
+    #[test]
+    fn should_skip_unknown_types_when_lenient() {
+        use bevy_reflect::serde::UnknownTypeError;
+
+        let world = create_world();
+
+        let input = r#"(
+  resources: {
+    "bevy_scene::serde::tests::MyResource": (
+      foo: 123,
+    ),
+    "my_crate::NewerResource": (),
+  },
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Foo": (123),
+        "my_crate::NewerComponent": (value: 1.0),
+      },
+    ),
+  },
+)"#;
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+            unknown_types: None,
+        };
+        assert!(scene_deserializer.deserialize(&mut deserializer).is_err());
+
+        let mut unknown_types = Vec::new();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+            unknown_types: Some(&mut unknown_types),
+        };
+        let scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(1, scene.resources.len());
+        assert_eq!(1, scene.entities.len());
+        assert_eq!(1, scene.entities[0].components.len());
+        assert_eq!(
+            unknown_types,
+            [
+                UnknownTypeError {
+                    type_path: "my_crate::NewerResource".into(),
+                },
+                UnknownTypeError {
+                    type_path: "my_crate::NewerComponent".into(),
+                },
+            ]
+        );
+    }
+
     fn roundtrip_ron(world: &World) -> (DynamicScene, DynamicScene) {
         let scene = DynamicScene::from_world(world);
         let registry = world.resource::<AppTypeRegistry>().read();
@@ -734,6 +896,7 @@ mod tests {
         let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
         let scene_deserializer = SceneDeserializer {
             type_registry: &registry,
+            unknown_types: None,
         };
         let deserialized_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
         (scene, deserialized_scene)
@@ -796,6 +959,49 @@ mod tests {
         assert_eq!(&qux, world.query::<&Qux>().single(&world));
     }
 
+    #[test]
+    fn should_roundtrip_entity_references_with_processors() {
+        use bevy_ecs::{
+            entity::SceneEntityMapper,
+            reflect::{EntityDeserializerProcessor, EntitySerializerProcessor},
+        };
+        use bevy_reflect::{
+            serde::{ReflectDeserializer, ReflectSerializer},
+            PartialReflect,
+        };
+
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let target = Entity::from_raw(42);
+        let stable_id = Entity::from_raw(0);
+        let mut stable_ids = EntityHashMap::default();
+        stable_ids.insert(target, stable_id);
+
+        let processor = EntitySerializerProcessor::with_entity_map(&stable_ids);
+        let value = MyEntityRef(target);
+        let serializer = ReflectSerializer::with_processor(&value, &registry, &processor);
+        let serialized = ron::to_string(&serializer).unwrap();
+        assert!(serialized.contains(&stable_id.to_bits().to_string()));
+
+        let mut dst_world = create_world();
+        let loaded = dst_world.spawn_empty().id();
+        let mut map = EntityHashMap::default();
+        map.insert(stable_id, loaded);
+
+        let value = SceneEntityMapper::world_scope(&mut map, &mut dst_world, |_, mapper| {
+            let mut processor = EntityDeserializerProcessor::new(mapper);
+            let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+            ReflectDeserializer::with_processor(&registry, &mut processor)
+                .deserialize(&mut deserializer)
+                .unwrap()
+        });
+
+        let mut entity_ref = MyEntityRef(Entity::PLACEHOLDER);
+        entity_ref.apply(value.as_ref());
+        assert_eq!(loaded, entity_ref.0);
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -826,6 +1032,7 @@ mod tests {
 
         let scene_deserializer = SceneDeserializer {
             type_registry: registry,
+            unknown_types: None,
         };
         let deserialized_scene = scene_deserializer
             .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
@@ -868,6 +1075,7 @@ mod tests {
 
         let scene_deserializer = SceneDeserializer {
             type_registry: registry,
+            unknown_types: None,
         };
         let mut reader = BufReader::new(buf.as_slice());
 
@@ -911,6 +1119,7 @@ mod tests {
 
         let scene_deserializer = SceneDeserializer {
             type_registry: registry,
+            unknown_types: None,
         };
 
         let deserialized_scene = bincode::DefaultOptions::new()
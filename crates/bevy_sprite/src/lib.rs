@@ -14,6 +14,7 @@ mod mesh2d;
 #[cfg(feature = "bevy_sprite_picking_backend")]
 mod picking_backend;
 mod render;
+mod sorting;
 mod sprite;
 mod texture_slice;
 
@@ -23,9 +24,10 @@ mod texture_slice;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        sorting::{OrderInLayer, SortingLayer, YSort},
         sprite::{Sprite, SpriteImageMode},
         texture_slice::{BorderRect, SliceScaleMode, TextureSlice, TextureSlicer},
-        ColorMaterial, MeshMaterial2d, ScalingMode,
+        ColorMaterial, Mesh2dInstances, MeshMaterial2d, ScalingMode, SpriteMaterialHandle,
     };
 }
 
@@ -33,6 +35,7 @@ pub use mesh2d::*;
 #[cfg(feature = "bevy_sprite_picking_backend")]
 pub use picking_backend::*;
 pub use render::*;
+pub use sorting::*;
 pub use sprite::*;
 pub use texture_slice::*;
 
@@ -77,6 +80,10 @@ impl Default for SpritePlugin {
 pub const SPRITE_SHADER_HANDLE: Handle<Shader> = Handle::weak_from_u128(2763343953151597127);
 pub const SPRITE_VIEW_BINDINGS_SHADER_HANDLE: Handle<Shader> =
     Handle::weak_from_u128(8846920112458963210);
+pub const SPRITE_VERTEX_OUTPUT_SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(1460287193615734621);
+pub const SPRITE_FUNCTIONS_SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(5137629014582316409);
 
 /// System set for sprite rendering.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -99,6 +106,18 @@ impl Plugin for SpritePlugin {
             "render/sprite_view_bindings.wgsl",
             Shader::from_wgsl
         );
+        load_internal_asset!(
+            app,
+            SPRITE_VERTEX_OUTPUT_SHADER_HANDLE,
+            "render/sprite_vertex_output.wgsl",
+            Shader::from_wgsl
+        );
+        load_internal_asset!(
+            app,
+            SPRITE_FUNCTIONS_SHADER_HANDLE,
+            "render/sprite_functions.wgsl",
+            Shader::from_wgsl
+        );
 
         if !app.is_plugin_added::<TextureAtlasPlugin>() {
             app.add_plugins(TextureAtlasPlugin);
@@ -108,7 +127,11 @@ impl Plugin for SpritePlugin {
             .register_type::<SpriteImageMode>()
             .register_type::<TextureSlicer>()
             .register_type::<Anchor>()
+            .register_type::<SortingLayer>()
+            .register_type::<OrderInLayer>()
+            .register_type::<YSort>()
             .register_type::<Mesh2d>()
+            .register_type::<Mesh2dInstances>()
             .add_plugins((Mesh2dRenderPlugin, ColorMaterialPlugin))
             .add_systems(
                 PostUpdate,
@@ -32,6 +32,8 @@ fn fragment(
 
 #ifdef VERTEX_COLORS
     output_color = output_color * mesh.color;
+#else ifdef MESH2D_INSTANCED
+    output_color = output_color * mesh.color;
 #endif
 
     if ((material.flags & COLOR_MATERIAL_FLAGS_TEXTURE_BIT) != 0u) {
@@ -0,0 +1,183 @@
+use bevy_color::{Color, ColorToComponents, LinearRgba};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+use bevy_math::{Affine3A, Vec4};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    render_resource::{Buffer, BufferInitDescriptor, BufferUsages},
+    renderer::{RenderDevice, RenderQueue},
+    sync_world::{MainEntity, MainEntityHashMap},
+    view::NoFrustumCulling,
+    Extract,
+};
+use bevy_transform::components::{GlobalTransform, Transform};
+use bytemuck::{Pod, Zeroable};
+
+/// A single copy of a mesh drawn by [`Mesh2dInstances`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct Mesh2dInstance {
+    /// The transform of the instance, relative to the entity's [`GlobalTransform`].
+    pub transform: Transform,
+    /// The color of the instance, multiplied with the color output by the material.
+    pub color: Color,
+}
+
+impl Mesh2dInstance {
+    /// Creates a new white instance with the given transform.
+    pub const fn from_transform(transform: Transform) -> Self {
+        Self {
+            transform,
+            color: Color::WHITE,
+        }
+    }
+
+    /// Returns this instance with the given color.
+    pub const fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+impl Default for Mesh2dInstance {
+    fn default() -> Self {
+        Self::from_transform(Transform::IDENTITY)
+    }
+}
+
+/// Draws many copies of an entity's [`Mesh2d`](bevy_render::mesh::Mesh2d) in a single draw call.
+///
+/// Each [`Mesh2dInstance`] supplies its own transform and color, so large numbers of
+/// identical quads (bullets, particles, tiles) can be drawn from one entity instead of
+/// extracting and batching one entity per copy. The instances are uploaded to the GPU
+/// only when this component or the entity's [`GlobalTransform`] changes.
+///
+/// The entity's [`MeshMaterial2d`](crate::MeshMaterial2d) is shared by all instances.
+/// Materials which use the default 2D mesh vertex shader, such as
+/// [`ColorMaterial`](crate::ColorMaterial), support instancing automatically; materials
+/// with a custom vertex shader must handle the `MESH2D_INSTANCED` shader def themselves.
+///
+/// All instances are sorted together using the entity's sort key, and are drawn in the
+/// order they appear in this list. Since the instances may be spread far from the entity,
+/// frustum culling is disabled for entities with this component.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(NoFrustumCulling)]
+pub struct Mesh2dInstances(pub Vec<Mesh2dInstance>);
+
+impl FromIterator<Mesh2dInstance> for Mesh2dInstances {
+    fn from_iter<T: IntoIterator<Item = Mesh2dInstance>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct Mesh2dInstanceData {
+    // Affine 4x3 transposed to 3x4
+    i_world_from_local_transpose: [Vec4; 3],
+    i_color: [f32; 4],
+}
+
+impl Mesh2dInstanceData {
+    #[inline]
+    fn new(world_from_local: &Affine3A, color: Color) -> Self {
+        let transpose_3x3 = world_from_local.matrix3.transpose();
+        Self {
+            i_world_from_local_transpose: [
+                transpose_3x3.x_axis.extend(world_from_local.translation.x),
+                transpose_3x3.y_axis.extend(world_from_local.translation.y),
+                transpose_3x3.z_axis.extend(world_from_local.translation.z),
+            ],
+            i_color: LinearRgba::from(color).to_f32_array(),
+        }
+    }
+}
+
+/// The instance-rate vertex buffer of an entity with [`Mesh2dInstances`].
+#[derive(Default)]
+pub struct RenderMesh2dInstanceBuffer {
+    data: Vec<Mesh2dInstanceData>,
+    buffer: Option<Buffer>,
+    instance_count: u32,
+    dirty: bool,
+}
+
+impl RenderMesh2dInstanceBuffer {
+    /// The GPU buffer holding the instances, if any have been uploaded.
+    pub fn buffer(&self) -> Option<&Buffer> {
+        self.buffer.as_ref()
+    }
+
+    /// The number of instances in [`Self::buffer`].
+    pub fn instance_count(&self) -> u32 {
+        self.instance_count
+    }
+}
+
+/// The instance buffers of all entities with [`Mesh2dInstances`], in the render world.
+#[derive(Default, Resource, Deref, DerefMut)]
+pub struct RenderMesh2dInstanceBuffers(MainEntityHashMap<RenderMesh2dInstanceBuffer>);
+
+pub fn extract_mesh2d_instances(
+    mut instance_buffers: ResMut<RenderMesh2dInstanceBuffers>,
+    query: Extract<Query<(Entity, Ref<GlobalTransform>, Ref<Mesh2dInstances>)>>,
+) {
+    for (entity, transform, instances) in &query {
+        let main_entity = MainEntity::from(entity);
+        let is_new = !instance_buffers.contains_key(&main_entity);
+        if !is_new && !transform.is_changed() && !instances.is_changed() {
+            continue;
+        }
+
+        let world_from_entity = transform.affine();
+        let instance_buffer = instance_buffers.entry(main_entity).or_default();
+        instance_buffer.data.clear();
+        instance_buffer
+            .data
+            .extend(instances.iter().map(|instance| {
+                Mesh2dInstanceData::new(
+                    &(world_from_entity * instance.transform.compute_affine()),
+                    instance.color,
+                )
+            }));
+        instance_buffer.dirty = true;
+    }
+
+    instance_buffers.retain(|main_entity, _| query.contains(main_entity.id()));
+}
+
+pub fn prepare_mesh2d_instance_buffers(
+    mut instance_buffers: ResMut<RenderMesh2dInstanceBuffers>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+) {
+    for instance_buffer in instance_buffers.values_mut() {
+        if !instance_buffer.dirty {
+            continue;
+        }
+        instance_buffer.dirty = false;
+        instance_buffer.instance_count = instance_buffer.data.len() as u32;
+        if instance_buffer.data.is_empty() {
+            continue;
+        }
+
+        let contents: &[u8] = bytemuck::cast_slice(&instance_buffer.data);
+        match &instance_buffer.buffer {
+            // Reuse the existing buffer if the instances still fit
+            Some(buffer) if buffer.size() >= contents.len() as u64 => {
+                render_queue.write_buffer(buffer, 0, contents);
+            }
+            _ => {
+                instance_buffer.buffer = Some(render_device.create_buffer_with_data(
+                    &BufferInitDescriptor {
+                        label: Some("mesh2d_instance_buffer"),
+                        contents,
+                        usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+                    },
+                ));
+            }
+        }
+        instance_buffer.data.clear();
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
-    DrawMesh2d, Mesh2d, Mesh2dPipeline, Mesh2dPipelineKey, RenderMesh2dInstances,
-    SetMesh2dBindGroup, SetMesh2dViewBindGroup,
+    DrawMesh2d, Mesh2d, Mesh2dPipeline, Mesh2dPipelineKey, RenderMesh2dInstanceBuffers,
+    RenderMesh2dInstances, SetMesh2dBindGroup, SetMesh2dViewBindGroup,
 };
 use bevy_app::{App, Plugin};
 use bevy_asset::{Asset, AssetApp, AssetId, AssetServer, Handle};
 use bevy_core_pipeline::{
     core_2d::{
         AlphaMask2d, AlphaMask2dBinKey, BatchSetKey2d, Opaque2d, Opaque2dBinKey, Transparent2d,
+        Transparent2dSortKey,
     },
     tonemapping::{DebandDither, Tonemapping},
 };
@@ -524,6 +525,7 @@ pub fn queue_material2d_meshes<M: Material2d>(
     ),
     mut render_mesh_instances: ResMut<RenderMesh2dInstances>,
     render_material_instances: Res<RenderMaterial2dInstances<M>>,
+    instance_buffers: Res<RenderMesh2dInstanceBuffers>,
     mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
     mut opaque_render_phases: ResMut<ViewBinnedRenderPhases<Opaque2d>>,
     mut alpha_mask_render_phases: ResMut<ViewBinnedRenderPhases<AlphaMask2d>>,
@@ -583,9 +585,12 @@ pub fn queue_material2d_meshes<M: Material2d>(
             let Some(mesh) = render_meshes.get(mesh_instance.mesh_asset_id) else {
                 continue;
             };
-            let mesh_key = view_key
+            let mut mesh_key = view_key
                 | Mesh2dPipelineKey::from_primitive_topology(mesh.primitive_topology())
                 | material_2d.properties.mesh_pipeline_key_bits;
+            if instance_buffers.contains_key(visible_entity) {
+                mesh_key |= Mesh2dPipelineKey::INSTANCED;
+            }
 
             let pipeline_id = pipelines.specialize(
                 &pipeline_cache,
@@ -606,7 +611,6 @@ pub fn queue_material2d_meshes<M: Material2d>(
             };
 
             mesh_instance.material_bind_group_id = material_2d.get_bind_group_id();
-            let mesh_z = mesh_instance.transforms.world_from_local.translation.z;
 
             // We don't support multidraw yet for 2D meshes, so we use this
             // custom logic to generate the `BinnedRenderPhaseType` instead of
@@ -660,8 +664,13 @@ pub fn queue_material2d_meshes<M: Material2d>(
                         // NOTE: Back-to-front ordering for transparent with ascending sort means far should have the
                         // lowest sort key and getting closer should increase. As we have
                         // -z in front of the camera, the largest distance is -far with values increasing toward the
-                        // camera. As such we can just use mesh_z as the distance
-                        sort_key: FloatOrd(mesh_z + material_2d.properties.depth_bias),
+                        // camera. As such we can just use mesh_z as the distance, unless the mesh is y-sorted
+                        sort_key: Transparent2dSortKey {
+                            depth: FloatOrd(
+                                mesh_instance.sort_key.depth.0 + material_2d.properties.depth_bias,
+                            ),
+                            ..mesh_instance.sort_key
+                        },
                         // Batching is done in batch_and_prepare_render_phase
                         batch_range: 0..1,
                         extra_index: PhaseItemExtraIndex::None,
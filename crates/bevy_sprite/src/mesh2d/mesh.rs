@@ -1,9 +1,14 @@
 use bevy_app::Plugin;
 use bevy_asset::{load_internal_asset, AssetId, Handle};
 
-use crate::Material2dBindGroupId;
+use crate::{
+    extract_mesh2d_instances, prepare_mesh2d_instance_buffers, Material2dBindGroupId,
+    Mesh2dInstances, RenderMesh2dInstanceBuffers, Sorting2d,
+};
 use bevy_core_pipeline::{
-    core_2d::{AlphaMask2d, Camera2d, Opaque2d, Transparent2d, CORE_2D_DEPTH_FORMAT},
+    core_2d::{
+        AlphaMask2d, Camera2d, Opaque2d, Transparent2d, Transparent2dSortKey, CORE_2D_DEPTH_FORMAT,
+    },
     tonemapping::{
         get_lut_bind_group_layout_entries, get_lut_bindings, Tonemapping, TonemappingLuts,
     },
@@ -95,11 +100,13 @@ impl Plugin for Mesh2dRenderPlugin {
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
                 .init_resource::<RenderMesh2dInstances>()
+                .init_resource::<RenderMesh2dInstanceBuffers>()
                 .init_resource::<SpecializedMeshPipelines<Mesh2dPipeline>>()
-                .add_systems(ExtractSchedule, extract_mesh2d)
+                .add_systems(ExtractSchedule, (extract_mesh2d, extract_mesh2d_instances))
                 .add_systems(
                     Render,
                     (
+                        prepare_mesh2d_instance_buffers.in_set(RenderSet::PrepareResources),
                         batch_and_prepare_binned_render_phase::<Opaque2d, Mesh2dPipeline>
                             .in_set(RenderSet::PrepareResources),
                         batch_and_prepare_binned_render_phase::<AlphaMask2d, Mesh2dPipeline>
@@ -198,6 +205,9 @@ pub struct RenderMesh2dInstance {
     pub mesh_asset_id: AssetId<Mesh>,
     pub material_bind_group_id: Material2dBindGroupId,
     pub automatic_batching: bool,
+    /// The key used to sort the mesh if it's drawn in the [`Transparent2d`] phase,
+    /// without any material depth bias applied.
+    pub sort_key: Transparent2dSortKey,
 }
 
 #[derive(Default, Resource, Deref, DerefMut)]
@@ -215,12 +225,16 @@ pub fn extract_mesh2d(
             &GlobalTransform,
             &Mesh2d,
             Has<NoAutomaticBatching>,
+            Has<Mesh2dInstances>,
+            Sorting2d,
         )>,
     >,
 ) {
     render_mesh_instances.clear();
 
-    for (entity, view_visibility, transform, handle, no_automatic_batching) in &query {
+    for (entity, view_visibility, transform, handle, no_automatic_batching, instanced, sorting) in
+        &query
+    {
         if !view_visibility.get() {
             continue;
         }
@@ -233,7 +247,9 @@ pub fn extract_mesh2d(
                 },
                 mesh_asset_id: handle.0.id(),
                 material_bind_group_id: Material2dBindGroupId::default(),
-                automatic_batching: !no_automatic_batching,
+                // Instanced meshes draw their own instance buffer, so they can't be batched
+                automatic_batching: !no_automatic_batching && !instanced,
+                sort_key: sorting.sort_key(transform, 0.0),
             },
         );
     }
@@ -447,6 +463,7 @@ bitflags::bitflags! {
         const DEBAND_DITHER                     = 1 << 2;
         const BLEND_ALPHA                       = 1 << 3;
         const MAY_DISCARD                       = 1 << 4;
+        const INSTANCED                         = 1 << 5;
         const MSAA_RESERVED_BITS                = Self::MSAA_MASK_BITS << Self::MSAA_SHIFT_BITS;
         const PRIMITIVE_TOPOLOGY_RESERVED_BITS  = Self::PRIMITIVE_TOPOLOGY_MASK_BITS << Self::PRIMITIVE_TOPOLOGY_SHIFT_BITS;
         const TONEMAP_METHOD_RESERVED_BITS      = Self::TONEMAP_METHOD_MASK_BITS << Self::TONEMAP_METHOD_SHIFT_BITS;
@@ -595,7 +612,27 @@ impl SpecializedMeshPipeline for Mesh2dPipeline {
             shader_defs.push("MAY_DISCARD".into());
         }
 
-        let vertex_buffer_layout = layout.0.get_layout(&vertex_attributes)?;
+        let mut vertex_buffer_layouts = vec![layout.0.get_layout(&vertex_attributes)?];
+
+        if key.contains(Mesh2dPipelineKey::INSTANCED) {
+            shader_defs.push("MESH2D_INSTANCED".into());
+            vertex_buffer_layouts.push(
+                VertexBufferLayout::from_vertex_formats(
+                    VertexStepMode::Instance,
+                    [
+                        // @location(8) i_world_from_local_transpose_col0: vec4<f32>,
+                        VertexFormat::Float32x4,
+                        // @location(9) i_world_from_local_transpose_col1: vec4<f32>,
+                        VertexFormat::Float32x4,
+                        // @location(10) i_world_from_local_transpose_col2: vec4<f32>,
+                        VertexFormat::Float32x4,
+                        // @location(11) i_color: vec4<f32>,
+                        VertexFormat::Float32x4,
+                    ],
+                )
+                .offset_locations_by(8),
+            );
+        }
 
         let format = match key.contains(Mesh2dPipelineKey::HDR) {
             true => ViewTarget::TEXTURE_FORMAT_HDR,
@@ -618,7 +655,7 @@ impl SpecializedMeshPipeline for Mesh2dPipeline {
                 shader: MESH2D_SHADER_HANDLE,
                 entry_point: "vertex".into(),
                 shader_defs: shader_defs.clone(),
-                buffers: vec![vertex_buffer_layout],
+                buffers: vertex_buffer_layouts,
             },
             fragment: Some(FragmentState {
                 shader: MESH2D_SHADER_HANDLE,
@@ -788,6 +825,7 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh2d {
         SRes<RenderAssets<RenderMesh>>,
         SRes<RenderMesh2dInstances>,
         SRes<MeshAllocator>,
+        SRes<RenderMesh2dInstanceBuffers>,
     );
     type ViewQuery = ();
     type ItemQuery = ();
@@ -797,12 +835,17 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh2d {
         item: &P,
         _view: (),
         _item_query: Option<()>,
-        (meshes, render_mesh2d_instances, mesh_allocator): SystemParamItem<'w, '_, Self::Param>,
+        (meshes, render_mesh2d_instances, mesh_allocator, instance_buffers): SystemParamItem<
+            'w,
+            '_,
+            Self::Param,
+        >,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         let meshes = meshes.into_inner();
         let render_mesh2d_instances = render_mesh2d_instances.into_inner();
         let mesh_allocator = mesh_allocator.into_inner();
+        let instance_buffers = instance_buffers.into_inner();
 
         let Some(RenderMesh2dInstance { mesh_asset_id, .. }) =
             render_mesh2d_instances.get(&item.main_entity())
@@ -818,7 +861,17 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh2d {
 
         pass.set_vertex_buffer(0, vertex_buffer_slice.buffer.slice(..));
 
-        let batch_range = item.batch_range();
+        let mut batch_range = item.batch_range().clone();
+        if let Some(instance_buffer) = instance_buffers.get(&item.main_entity()) {
+            let Some(buffer) = instance_buffer.buffer() else {
+                return RenderCommandResult::Skip;
+            };
+            if instance_buffer.instance_count() == 0 {
+                return RenderCommandResult::Skip;
+            }
+            pass.set_vertex_buffer(1, buffer.slice(..));
+            batch_range = 0..instance_buffer.instance_count();
+        }
         match &gpu_mesh.buffer_info {
             RenderMeshBufferInfo::Indexed {
                 index_format,
@@ -834,11 +887,11 @@ impl<P: PhaseItem> RenderCommand<P> for DrawMesh2d {
                 pass.draw_indexed(
                     index_buffer_slice.range.start..(index_buffer_slice.range.start + count),
                     vertex_buffer_slice.range.start as i32,
-                    batch_range.clone(),
+                    batch_range,
                 );
             }
             RenderMeshBufferInfo::NonIndexed => {
-                pass.draw(vertex_buffer_slice.range, batch_range.clone());
+                pass.draw(vertex_buffer_slice.range, batch_range);
             }
         }
         RenderCommandResult::Success
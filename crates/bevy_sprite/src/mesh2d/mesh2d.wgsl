@@ -8,6 +8,10 @@
 #import bevy_core_pipeline::tonemapping
 #endif
 
+#ifdef MESH2D_INSTANCED
+#import bevy_render::maths::affine3_to_square
+#endif
+
 struct Vertex {
     @builtin(instance_index) instance_index: u32,
 #ifdef VERTEX_POSITIONS
@@ -25,6 +29,15 @@ struct Vertex {
 #ifdef VERTEX_COLORS
     @location(4) color: vec4<f32>,
 #endif
+#ifdef MESH2D_INSTANCED
+    // NOTE: Instance-rate vertex buffer members prefixed with i_
+    // NOTE: i_world_from_local_transpose_colN are the 3 columns of a 3x4 matrix that is the
+    // transpose of the affine 4x3 world from local matrix of the instance.
+    @location(8) i_world_from_local_transpose_col0: vec4<f32>,
+    @location(9) i_world_from_local_transpose_col1: vec4<f32>,
+    @location(10) i_world_from_local_transpose_col2: vec4<f32>,
+    @location(11) i_color: vec4<f32>,
+#endif
 };
 
 @vertex
@@ -35,7 +48,15 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 #endif
 
 #ifdef VERTEX_POSITIONS
+#ifdef MESH2D_INSTANCED
+    var world_from_local = affine3_to_square(mat3x4<f32>(
+        vertex.i_world_from_local_transpose_col0,
+        vertex.i_world_from_local_transpose_col1,
+        vertex.i_world_from_local_transpose_col2,
+    ));
+#else
     var world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+#endif
     out.world_position = mesh_functions::mesh2d_position_local_to_world(
         world_from_local,
         vec4<f32>(vertex.position, 1.0)
@@ -44,8 +65,17 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 #endif
 
 #ifdef VERTEX_NORMALS
+#ifdef MESH2D_INSTANCED
+    // Instances have no precomputed inverse transpose, so this assumes uniform scale
+    out.world_normal = normalize(mat3x3<f32>(
+        world_from_local[0].xyz,
+        world_from_local[1].xyz,
+        world_from_local[2].xyz
+    ) * vertex.normal);
+#else
     out.world_normal = mesh_functions::mesh2d_normal_local_to_world(vertex.normal, vertex.instance_index);
 #endif
+#endif
 
 #ifdef VERTEX_TANGENTS
     out.world_tangent = mesh_functions::mesh2d_tangent_local_to_world(
@@ -54,7 +84,12 @@ fn vertex(vertex: Vertex) -> VertexOutput {
     );
 #endif
 
+#ifdef MESH2D_INSTANCED
+    out.color = vertex.i_color;
 #ifdef VERTEX_COLORS
+    out.color = out.color * vertex.color;
+#endif
+#else ifdef VERTEX_COLORS
     out.color = vertex.color;
 #endif
     return out;
@@ -70,6 +105,12 @@ fn fragment(
     color = tonemapping::tone_mapping(color, view.color_grading);
 #endif
     return color;
+#else ifdef MESH2D_INSTANCED
+    var color = in.color;
+#ifdef TONEMAP_IN_SHADER
+    color = tonemapping::tone_mapping(color, view.color_grading);
+#endif
+    return color;
 #else
     return vec4<f32>(1.0, 0.0, 1.0, 1.0);
 #endif
@@ -12,5 +12,7 @@ struct VertexOutput {
     #endif
     #ifdef VERTEX_COLORS
     @location(4) color: vec4<f32>,
+    #else ifdef MESH2D_INSTANCED
+    @location(4) color: vec4<f32>,
     #endif
 }
@@ -1,9 +1,11 @@
 mod color_material;
+mod instancing;
 mod material;
 mod mesh;
 mod wireframe2d;
 
 pub use color_material::*;
+pub use instancing::*;
 pub use material::*;
 pub use mesh::*;
 pub use wireframe2d::*;
@@ -1,10 +1,14 @@
+mod sprite_material;
+
+pub use sprite_material::*;
+
 use core::ops::Range;
 
-use crate::{ComputedTextureSlices, ScalingMode, Sprite, SPRITE_SHADER_HANDLE};
-use bevy_asset::{AssetEvent, AssetId, Assets};
+use crate::{ComputedTextureSlices, ScalingMode, Sorting2d, Sprite, SPRITE_SHADER_HANDLE};
+use bevy_asset::{AssetEvent, AssetId, Assets, UntypedAssetId};
 use bevy_color::{ColorToComponents, LinearRgba};
 use bevy_core_pipeline::{
-    core_2d::{Transparent2d, CORE_2D_DEPTH_FORMAT},
+    core_2d::{Transparent2d, Transparent2dSortKey, CORE_2D_DEPTH_FORMAT},
     tonemapping::{
         get_lut_bind_group_layout_entries, get_lut_bindings, DebandDither, Tonemapping,
         TonemappingLuts,
@@ -16,7 +20,7 @@ use bevy_ecs::{
     system::{lifetimeless::*, SystemParamItem, SystemState},
 };
 use bevy_image::{BevyDefault, Image, ImageSampler, TextureAtlasLayout, TextureFormatPixelInfo};
-use bevy_math::{Affine3A, FloatOrd, Quat, Rect, Vec2, Vec4};
+use bevy_math::{Affine3A, Quat, Rect, Vec2, Vec4};
 use bevy_platform_support::collections::HashMap;
 use bevy_render::sync_world::MainEntity;
 use bevy_render::view::RenderVisibleEntities;
@@ -43,7 +47,7 @@ use bevy_transform::components::GlobalTransform;
 use bytemuck::{Pod, Zeroable};
 use fixedbitset::FixedBitSet;
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct SpritePipeline {
     view_layout: BindGroupLayout,
     material_layout: BindGroupLayout,
@@ -178,6 +182,42 @@ impl SpritePipelineKey {
             SpritePipelineKey::NONE
         }
     }
+
+    /// Computes the key of the sprite pipeline used to render sprites into the given view.
+    pub fn from_view(
+        view: &ExtractedView,
+        msaa: &Msaa,
+        tonemapping: Option<&Tonemapping>,
+        dither: Option<&DebandDither>,
+    ) -> Self {
+        let msaa_key = SpritePipelineKey::from_msaa_samples(msaa.samples());
+        let mut view_key = SpritePipelineKey::from_hdr(view.hdr) | msaa_key;
+
+        if !view.hdr {
+            if let Some(tonemapping) = tonemapping {
+                view_key |= SpritePipelineKey::TONEMAP_IN_SHADER;
+                view_key |= match tonemapping {
+                    Tonemapping::None => SpritePipelineKey::TONEMAP_METHOD_NONE,
+                    Tonemapping::Reinhard => SpritePipelineKey::TONEMAP_METHOD_REINHARD,
+                    Tonemapping::ReinhardLuminance => {
+                        SpritePipelineKey::TONEMAP_METHOD_REINHARD_LUMINANCE
+                    }
+                    Tonemapping::AcesFitted => SpritePipelineKey::TONEMAP_METHOD_ACES_FITTED,
+                    Tonemapping::AgX => SpritePipelineKey::TONEMAP_METHOD_AGX,
+                    Tonemapping::SomewhatBoringDisplayTransform => {
+                        SpritePipelineKey::TONEMAP_METHOD_SOMEWHAT_BORING_DISPLAY_TRANSFORM
+                    }
+                    Tonemapping::TonyMcMapface => SpritePipelineKey::TONEMAP_METHOD_TONY_MC_MAPFACE,
+                    Tonemapping::BlenderFilmic => SpritePipelineKey::TONEMAP_METHOD_BLENDER_FILMIC,
+                };
+            }
+            if let Some(DebandDither::Enabled) = dither {
+                view_key |= SpritePipelineKey::DEBAND_DITHER;
+            }
+        }
+
+        view_key
+    }
 }
 
 impl SpecializedRenderPipeline for SpritePipeline {
@@ -340,6 +380,12 @@ pub struct ExtractedSprite {
     /// entity that caused that creation for use in determining visibility.
     pub original_entity: Option<Entity>,
     pub scaling_mode: Option<ScalingMode>,
+    /// The key this sprite is sorted by in the [`Transparent2d`] phase, usually
+    /// computed from [`Sorting2d`](crate::Sorting2d).
+    pub sort_key: Transparent2dSortKey,
+    /// The [`SpriteMaterial`] this sprite is drawn with, if any. Sprites with a material are
+    /// queued by [`queue_sprite_materials`] instead of [`queue_sprites`].
+    pub material: Option<UntypedAssetId>,
 }
 
 #[derive(Resource, Default)]
@@ -376,20 +422,24 @@ pub fn extract_sprites(
             &Sprite,
             &GlobalTransform,
             Option<&ComputedTextureSlices>,
+            Sorting2d,
         )>,
     >,
 ) {
     extracted_sprites.sprites.clear();
-    for (original_entity, entity, view_visibility, sprite, transform, slices) in sprite_query.iter()
+    for (original_entity, entity, view_visibility, sprite, transform, slices, sorting) in
+        sprite_query.iter()
     {
         if !view_visibility.get() {
             continue;
         }
 
+        let sort_key = sorting.sort_key(transform, 0.0);
+
         if let Some(slices) = slices {
             extracted_sprites.sprites.extend(
                 slices
-                    .extract_sprites(transform, original_entity, sprite)
+                    .extract_sprites(transform, original_entity, sprite, sort_key)
                     .map(|e| {
                         (
                             (
@@ -432,6 +482,8 @@ pub fn extract_sprites(
                     anchor: sprite.anchor.as_vec(),
                     original_entity: Some(original_entity),
                     scaling_mode: sprite.image_mode.scale(),
+                    sort_key,
+                    material: None,
                 },
             );
         }
@@ -486,6 +538,7 @@ pub struct SpriteViewBindGroup {
 #[derive(Component, PartialEq, Eq, Clone)]
 pub struct SpriteBatch {
     image_handle_id: AssetId<Image>,
+    material: Option<UntypedAssetId>,
     range: Range<u32>,
 }
 
@@ -518,32 +571,7 @@ pub fn queue_sprites(
             continue;
         };
 
-        let msaa_key = SpritePipelineKey::from_msaa_samples(msaa.samples());
-        let mut view_key = SpritePipelineKey::from_hdr(view.hdr) | msaa_key;
-
-        if !view.hdr {
-            if let Some(tonemapping) = tonemapping {
-                view_key |= SpritePipelineKey::TONEMAP_IN_SHADER;
-                view_key |= match tonemapping {
-                    Tonemapping::None => SpritePipelineKey::TONEMAP_METHOD_NONE,
-                    Tonemapping::Reinhard => SpritePipelineKey::TONEMAP_METHOD_REINHARD,
-                    Tonemapping::ReinhardLuminance => {
-                        SpritePipelineKey::TONEMAP_METHOD_REINHARD_LUMINANCE
-                    }
-                    Tonemapping::AcesFitted => SpritePipelineKey::TONEMAP_METHOD_ACES_FITTED,
-                    Tonemapping::AgX => SpritePipelineKey::TONEMAP_METHOD_AGX,
-                    Tonemapping::SomewhatBoringDisplayTransform => {
-                        SpritePipelineKey::TONEMAP_METHOD_SOMEWHAT_BORING_DISPLAY_TRANSFORM
-                    }
-                    Tonemapping::TonyMcMapface => SpritePipelineKey::TONEMAP_METHOD_TONY_MC_MAPFACE,
-                    Tonemapping::BlenderFilmic => SpritePipelineKey::TONEMAP_METHOD_BLENDER_FILMIC,
-                };
-            }
-            if let Some(DebandDither::Enabled) = dither {
-                view_key |= SpritePipelineKey::DEBAND_DITHER;
-            }
-        }
-
+        let view_key = SpritePipelineKey::from_view(view, msaa, tonemapping, dither);
         let pipeline = pipelines.specialize(&pipeline_cache, &sprite_pipeline, view_key);
 
         view_entities.clear();
@@ -560,19 +588,16 @@ pub fn queue_sprites(
         for ((entity, main_entity), extracted_sprite) in extracted_sprites.sprites.iter() {
             let index = extracted_sprite.original_entity.unwrap_or(*entity).index();
 
-            if !view_entities.contains(index as usize) {
+            if !view_entities.contains(index as usize) || extracted_sprite.material.is_some() {
                 continue;
             }
 
-            // These items will be sorted by depth with other phase items
-            let sort_key = FloatOrd(extracted_sprite.transform.translation().z);
-
-            // Add the item to the render phase
+            // Add the item to the render phase, to be sorted with other phase items
             transparent_phase.add(Transparent2d {
                 draw_function: draw_sprite_function,
                 pipeline,
                 entity: (*entity, *main_entity),
-                sort_key,
+                sort_key: extracted_sprite.sort_key,
                 // batch_range and dynamic_offset will be calculated in prepare_sprites
                 batch_range: 0..0,
                 extra_index: PhaseItemExtraIndex::None,
@@ -654,6 +679,7 @@ pub fn prepare_sprite_image_bind_groups(
         let mut batch_item_index = 0;
         let mut batch_image_size = Vec2::ZERO;
         let mut batch_image_handle = AssetId::invalid();
+        let mut batch_material = None;
 
         // Iterate through the phase items and detect when successive sprites that can be batched.
         // Spawn an entity with a `SpriteBatch` component for each possible batch.
@@ -668,13 +694,16 @@ pub fn prepare_sprite_image_bind_groups(
                 continue;
             };
 
-            if batch_image_handle != extracted_sprite.image_handle_id {
+            if batch_image_handle != extracted_sprite.image_handle_id
+                || batch_material != extracted_sprite.material
+            {
                 let Some(gpu_image) = gpu_images.get(extracted_sprite.image_handle_id) else {
                     continue;
                 };
 
                 batch_image_size = gpu_image.size_2d().as_vec2();
                 batch_image_handle = extracted_sprite.image_handle_id;
+                batch_material = extracted_sprite.material;
                 image_bind_groups
                     .values
                     .entry(batch_image_handle)
@@ -694,6 +723,7 @@ pub fn prepare_sprite_image_bind_groups(
                     item.entity(),
                     SpriteBatch {
                         image_handle_id: batch_image_handle,
+                        material: batch_material,
                         range: index..index,
                     },
                 ));
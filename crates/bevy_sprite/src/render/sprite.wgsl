@@ -1,14 +1,11 @@
-#ifdef TONEMAP_IN_SHADER
-#import bevy_core_pipeline::tonemapping
-#endif
+#import bevy_render::maths::affine3_to_square
 
-#import bevy_render::{
-    maths::affine3_to_square,
-    view::View,
+#import bevy_sprite::{
+    sprite_functions::{sample_sprite, tonemap_sprite},
+    sprite_vertex_output::VertexOutput,
+    sprite_view_bindings::view,
 }
 
-#import bevy_sprite::sprite_view_bindings::view
-
 struct VertexInput {
     @builtin(vertex_index) index: u32,
     // NOTE: Instance-rate vertex buffer members prefixed with i_
@@ -21,12 +18,6 @@ struct VertexInput {
     @location(4) i_uv_offset_scale: vec4<f32>,
 }
 
-struct VertexOutput {
-    @builtin(position) clip_position: vec4<f32>,
-    @location(0) uv: vec2<f32>,
-    @location(1) @interpolate(flat) color: vec4<f32>,
-};
-
 @vertex
 fn vertex(in: VertexInput) -> VertexOutput {
     var out: VertexOutput;
@@ -48,16 +39,7 @@ fn vertex(in: VertexInput) -> VertexOutput {
     return out;
 }
 
-@group(1) @binding(0) var sprite_texture: texture_2d<f32>;
-@group(1) @binding(1) var sprite_sampler: sampler;
-
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
-    var color = in.color * textureSample(sprite_texture, sprite_sampler, in.uv);
-
-#ifdef TONEMAP_IN_SHADER
-    color = tonemapping::tone_mapping(color, view.color_grading);
-#endif
-
-    return color;
+    return tonemap_sprite(sample_sprite(in));
 }
@@ -0,0 +1,27 @@
+#define_import_path bevy_sprite::sprite_functions
+
+#ifdef TONEMAP_IN_SHADER
+#import bevy_core_pipeline::tonemapping
+#endif
+
+#import bevy_sprite::{
+    sprite_vertex_output::VertexOutput,
+    sprite_view_bindings::view,
+}
+
+@group(1) @binding(0) var sprite_texture: texture_2d<f32>;
+@group(1) @binding(1) var sprite_sampler: sampler;
+
+// Samples the sprite's image at the interpolated UV and tints it with the sprite's color.
+fn sample_sprite(in: VertexOutput) -> vec4<f32> {
+    return in.color * textureSample(sprite_texture, sprite_sampler, in.uv);
+}
+
+// Applies the view's tonemapping if the sprite pipeline was specialized to tonemap in shader.
+fn tonemap_sprite(color: vec4<f32>) -> vec4<f32> {
+#ifdef TONEMAP_IN_SHADER
+    return tonemapping::tone_mapping(color, view.color_grading);
+#else
+    return color;
+#endif
+}
@@ -0,0 +1,484 @@
+use core::{hash::Hash, marker::PhantomData};
+
+use bevy_app::{App, Plugin};
+use bevy_asset::{Asset, AssetApp, AssetId, AssetServer, Handle};
+use bevy_core_pipeline::{
+    core_2d::Transparent2d,
+    tonemapping::{DebandDither, Tonemapping},
+};
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::{
+    prelude::*,
+    system::{
+        lifetimeless::{Read, SRes},
+        SystemParamItem,
+    },
+};
+use bevy_reflect::{prelude::ReflectDefault, Reflect};
+use bevy_render::{
+    render_asset::{
+        prepare_assets, PrepareAssetError, RenderAsset, RenderAssetPlugin, RenderAssets,
+    },
+    render_phase::{
+        AddRenderCommand, DrawFunctions, PhaseItem, PhaseItemExtraIndex, RenderCommand,
+        RenderCommandResult, SetItemPipeline, TrackedRenderPass, ViewSortedRenderPhases,
+    },
+    render_resource::{
+        AsBindGroup, AsBindGroupError, BindGroup, BindGroupLayout, BindingResources, PipelineCache,
+        RenderPipelineDescriptor, Shader, ShaderRef, SpecializedRenderPipeline,
+        SpecializedRenderPipelines,
+    },
+    renderer::RenderDevice,
+    view::{ExtractedView, Msaa, RenderVisibleEntities},
+    Extract, ExtractSchedule, Render, RenderApp, RenderSet,
+};
+use derive_more::derive::From;
+use fixedbitset::FixedBitSet;
+
+use super::{
+    queue_sprites, DrawSpriteBatch, ExtractedSprites, SetSpriteTextureBindGroup,
+    SetSpriteViewBindGroup, SpriteBatch, SpritePipeline, SpritePipelineKey,
+};
+use crate::{Sprite, SpriteSystem};
+
+/// Sprite materials extend the built-in sprite shader with custom shader logic and extra
+/// bindings, for effects such as outlines, flashing on hit or palette swaps.
+///
+/// Unlike a [`Material2d`](crate::Material2d), a sprite material does not require replacing the
+/// [`Sprite`] with a [`Mesh2d`](bevy_render::mesh::Mesh2d): texture atlases, slicing, tiling,
+/// flipping and batching keep working as usual. The material is added next to the [`Sprite`]
+/// with a [`SpriteMaterialHandle`] and rendered once its [`SpriteMaterialPlugin`] is added.
+///
+/// The material's bind group is bound at group 2, after the sprite view (group 0) and the sprite
+/// texture (group 1). Shaders can import `bevy_sprite::sprite_functions` to sample the sprite's
+/// image and apply the view's tonemapping the same way the default sprite shader does, and
+/// `bevy_sprite::sprite_vertex_output::VertexOutput` as the fragment input.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_sprite::{Sprite, SpriteMaterial, SpriteMaterialHandle};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::TypePath;
+/// # use bevy_render::render_resource::{AsBindGroup, ShaderRef};
+/// # use bevy_color::LinearRgba;
+/// # use bevy_color::palettes::basic::WHITE;
+/// # use bevy_asset::{Asset, AssetServer, Assets};
+/// #
+/// #[derive(AsBindGroup, Debug, Clone, Asset, TypePath)]
+/// pub struct FlashMaterial {
+///     #[uniform(0)]
+///     flash_color: LinearRgba,
+///     #[uniform(1)]
+///     flash_amount: f32,
+/// }
+///
+/// impl SpriteMaterial for FlashMaterial {
+///     fn fragment_shader() -> ShaderRef {
+///         "shaders/sprite_flash.wgsl".into()
+///     }
+/// }
+///
+/// fn setup(
+///     mut commands: Commands,
+///     mut materials: ResMut<Assets<FlashMaterial>>,
+///     asset_server: Res<AssetServer>,
+/// ) {
+///     commands.spawn((
+///         Sprite::from_image(asset_server.load("player.png")),
+///         SpriteMaterialHandle(materials.add(FlashMaterial {
+///             flash_color: WHITE.into(),
+///             flash_amount: 0.0,
+///         })),
+///     ));
+/// }
+/// ```
+///
+/// The matching fragment shader would look like this:
+///
+/// ```wgsl
+/// #import bevy_sprite::{
+///     sprite_functions::{sample_sprite, tonemap_sprite},
+///     sprite_vertex_output::VertexOutput,
+/// }
+///
+/// @group(2) @binding(0) var<uniform> flash_color: vec4<f32>;
+/// @group(2) @binding(1) var<uniform> flash_amount: f32;
+///
+/// @fragment
+/// fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+///     let color = sample_sprite(in);
+///     let flashed = mix(color.rgb, flash_color.rgb, flash_amount);
+///     return tonemap_sprite(vec4(flashed, color.a));
+/// }
+/// ```
+pub trait SpriteMaterial: AsBindGroup + Asset + Clone + Sized {
+    /// Returns this material's vertex shader. If [`ShaderRef::Default`] is returned, the default sprite vertex shader
+    /// will be used.
+    fn vertex_shader() -> ShaderRef {
+        ShaderRef::Default
+    }
+
+    /// Returns this material's fragment shader. If [`ShaderRef::Default`] is returned, the default sprite fragment shader
+    /// will be used.
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Default
+    }
+
+    /// Customizes the default [`RenderPipelineDescriptor`].
+    #[expect(
+        unused_variables,
+        reason = "The parameters here are intentionally unused by the default implementation; however, putting underscores here will result in the underscores being copied by rust-analyzer's tab completion."
+    )]
+    #[inline]
+    fn specialize(descriptor: &mut RenderPipelineDescriptor, key: SpriteMaterialKey<Self>) {}
+}
+
+/// A [material](SpriteMaterial) used for rendering a [`Sprite`].
+///
+/// See [`SpriteMaterial`] for general information about sprite materials and how to implement
+/// your own.
+#[derive(Component, Clone, Debug, Deref, DerefMut, Reflect, PartialEq, Eq, From)]
+#[reflect(Component, Default)]
+pub struct SpriteMaterialHandle<M: SpriteMaterial>(pub Handle<M>);
+
+impl<M: SpriteMaterial> Default for SpriteMaterialHandle<M> {
+    fn default() -> Self {
+        Self(Handle::default())
+    }
+}
+
+impl<M: SpriteMaterial> From<SpriteMaterialHandle<M>> for AssetId<M> {
+    fn from(material: SpriteMaterialHandle<M>) -> Self {
+        material.id()
+    }
+}
+
+impl<M: SpriteMaterial> From<&SpriteMaterialHandle<M>> for AssetId<M> {
+    fn from(material: &SpriteMaterialHandle<M>) -> Self {
+        material.id()
+    }
+}
+
+/// Adds the necessary ECS resources and render logic to enable rendering sprites using the given
+/// [`SpriteMaterial`] asset type.
+pub struct SpriteMaterialPlugin<M: SpriteMaterial>(PhantomData<M>);
+
+impl<M: SpriteMaterial> Default for SpriteMaterialPlugin<M> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<M: SpriteMaterial> Plugin for SpriteMaterialPlugin<M>
+where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.init_asset::<M>()
+            .register_type::<SpriteMaterialHandle<M>>()
+            .add_plugins(RenderAssetPlugin::<PreparedSpriteMaterial<M>>::default());
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .add_render_command::<Transparent2d, DrawSpriteMaterial<M>>()
+                .init_resource::<SpecializedRenderPipelines<SpriteMaterialPipeline<M>>>()
+                .add_systems(
+                    ExtractSchedule,
+                    extract_sprite_materials::<M>.after(SpriteSystem::ExtractSprites),
+                )
+                .add_systems(
+                    Render,
+                    queue_sprite_materials::<M>
+                        .in_set(RenderSet::Queue)
+                        .after(prepare_assets::<PreparedSpriteMaterial<M>>)
+                        .ambiguous_with(queue_sprites),
+                );
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.init_resource::<SpriteMaterialPipeline<M>>();
+        }
+    }
+}
+
+/// Tags the [`ExtractedSprites`] of every sprite with a [`SpriteMaterialHandle<M>`] with its
+/// material, including the sprites extracted for each slice of a sliced or tiled sprite.
+pub fn extract_sprite_materials<M: SpriteMaterial>(
+    mut extracted_sprites: ResMut<ExtractedSprites>,
+    materials: Extract<Query<&SpriteMaterialHandle<M>, With<Sprite>>>,
+) {
+    if materials.is_empty() {
+        return;
+    }
+
+    for ((_, main_entity), extracted_sprite) in extracted_sprites.sprites.iter_mut() {
+        if let Ok(material) = materials.get(main_entity.id()) {
+            extracted_sprite.material = Some(material.id().untyped());
+        }
+    }
+}
+
+/// Render pipeline data for a given [`SpriteMaterial`].
+#[derive(Resource)]
+pub struct SpriteMaterialPipeline<M: SpriteMaterial> {
+    pub sprite_pipeline: SpritePipeline,
+    pub sprite_material_layout: BindGroupLayout,
+    pub vertex_shader: Option<Handle<Shader>>,
+    pub fragment_shader: Option<Handle<Shader>>,
+    marker: PhantomData<M>,
+}
+
+pub struct SpriteMaterialKey<M: SpriteMaterial> {
+    pub sprite_key: SpritePipelineKey,
+    pub bind_group_data: M::Data,
+}
+
+impl<M: SpriteMaterial> Eq for SpriteMaterialKey<M> where M::Data: PartialEq {}
+
+impl<M: SpriteMaterial> PartialEq for SpriteMaterialKey<M>
+where
+    M::Data: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.sprite_key == other.sprite_key && self.bind_group_data == other.bind_group_data
+    }
+}
+
+impl<M: SpriteMaterial> Clone for SpriteMaterialKey<M>
+where
+    M::Data: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            sprite_key: self.sprite_key,
+            bind_group_data: self.bind_group_data.clone(),
+        }
+    }
+}
+
+impl<M: SpriteMaterial> Hash for SpriteMaterialKey<M>
+where
+    M::Data: Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.sprite_key.hash(state);
+        self.bind_group_data.hash(state);
+    }
+}
+
+impl<M: SpriteMaterial> Clone for SpriteMaterialPipeline<M> {
+    fn clone(&self) -> Self {
+        Self {
+            sprite_pipeline: self.sprite_pipeline.clone(),
+            sprite_material_layout: self.sprite_material_layout.clone(),
+            vertex_shader: self.vertex_shader.clone(),
+            fragment_shader: self.fragment_shader.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M: SpriteMaterial> SpecializedRenderPipeline for SpriteMaterialPipeline<M>
+where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    type Key = SpriteMaterialKey<M>;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        let mut descriptor = self.sprite_pipeline.specialize(key.sprite_key);
+        if let Some(vertex_shader) = &self.vertex_shader {
+            descriptor.vertex.shader = vertex_shader.clone();
+        }
+
+        if let Some(fragment_shader) = &self.fragment_shader {
+            descriptor.fragment.as_mut().unwrap().shader = fragment_shader.clone();
+        }
+        descriptor.layout.push(self.sprite_material_layout.clone());
+        descriptor.label = Some("sprite_material_pipeline".into());
+
+        M::specialize(&mut descriptor, key);
+        descriptor
+    }
+}
+
+impl<M: SpriteMaterial> FromWorld for SpriteMaterialPipeline<M> {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        let render_device = world.resource::<RenderDevice>();
+        let sprite_material_layout = M::bind_group_layout(render_device);
+
+        SpriteMaterialPipeline {
+            sprite_pipeline: world.resource::<SpritePipeline>().clone(),
+            sprite_material_layout,
+            vertex_shader: match M::vertex_shader() {
+                ShaderRef::Default => None,
+                ShaderRef::Handle(handle) => Some(handle),
+                ShaderRef::Path(path) => Some(asset_server.load(path)),
+            },
+            fragment_shader: match M::fragment_shader() {
+                ShaderRef::Default => None,
+                ShaderRef::Handle(handle) => Some(handle),
+                ShaderRef::Path(path) => Some(asset_server.load(path)),
+            },
+            marker: PhantomData,
+        }
+    }
+}
+
+/// [`RenderCommand`] for rendering sprites with a [`SpriteMaterial`].
+pub type DrawSpriteMaterial<M> = (
+    SetItemPipeline,
+    SetSpriteViewBindGroup<0>,
+    SetSpriteTextureBindGroup<1>,
+    SetSpriteMaterialBindGroup<M, 2>,
+    DrawSpriteBatch,
+);
+
+pub struct SetSpriteMaterialBindGroup<M: SpriteMaterial, const I: usize>(PhantomData<M>);
+impl<P: PhaseItem, M: SpriteMaterial, const I: usize> RenderCommand<P>
+    for SetSpriteMaterialBindGroup<M, I>
+{
+    type Param = SRes<RenderAssets<PreparedSpriteMaterial<M>>>;
+    type ViewQuery = ();
+    type ItemQuery = Read<SpriteBatch>;
+
+    #[inline]
+    fn render<'w>(
+        _item: &P,
+        _view: (),
+        batch: Option<&'_ SpriteBatch>,
+        materials: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let materials = materials.into_inner();
+        let Some(material_id) = batch
+            .and_then(|batch| batch.material)
+            .and_then(|id| id.try_typed::<M>().ok())
+        else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(material) = materials.get(material_id) else {
+            return RenderCommandResult::Skip;
+        };
+        pass.set_bind_group(I, &material.bind_group, &[]);
+        RenderCommandResult::Success
+    }
+}
+
+pub fn queue_sprite_materials<M: SpriteMaterial>(
+    mut view_entities: Local<FixedBitSet>,
+    draw_functions: Res<DrawFunctions<Transparent2d>>,
+    material_pipeline: Res<SpriteMaterialPipeline<M>>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<SpriteMaterialPipeline<M>>>,
+    pipeline_cache: Res<PipelineCache>,
+    render_materials: Res<RenderAssets<PreparedSpriteMaterial<M>>>,
+    extracted_sprites: Res<ExtractedSprites>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent2d>>,
+    views: Query<(
+        &RenderVisibleEntities,
+        &ExtractedView,
+        &Msaa,
+        Option<&Tonemapping>,
+        Option<&DebandDither>,
+    )>,
+) where
+    M::Data: PartialEq + Eq + Hash + Clone,
+{
+    let draw_sprite_material_function = draw_functions.read().id::<DrawSpriteMaterial<M>>();
+
+    for (visible_entities, view, msaa, tonemapping, dither) in &views {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view.retained_view_entity)
+        else {
+            continue;
+        };
+
+        let view_key = SpritePipelineKey::from_view(view, msaa, tonemapping, dither);
+
+        view_entities.clear();
+        view_entities.extend(
+            visible_entities
+                .iter::<Sprite>()
+                .map(|(_, e)| e.index() as usize),
+        );
+
+        for ((entity, main_entity), extracted_sprite) in extracted_sprites.sprites.iter() {
+            let Some(material_id) = extracted_sprite
+                .material
+                .and_then(|id| id.try_typed::<M>().ok())
+            else {
+                continue;
+            };
+
+            let index = extracted_sprite.original_entity.unwrap_or(*entity).index();
+            if !view_entities.contains(index as usize) {
+                continue;
+            }
+
+            let Some(material) = render_materials.get(material_id) else {
+                continue;
+            };
+
+            let pipeline = pipelines.specialize(
+                &pipeline_cache,
+                &material_pipeline,
+                SpriteMaterialKey {
+                    sprite_key: view_key,
+                    bind_group_data: material.key.clone(),
+                },
+            );
+
+            transparent_phase.add(Transparent2d {
+                draw_function: draw_sprite_material_function,
+                pipeline,
+                entity: (*entity, *main_entity),
+                sort_key: extracted_sprite.sort_key,
+                // batch_range and dynamic_offset will be calculated in prepare_sprites
+                batch_range: 0..0,
+                extra_index: PhaseItemExtraIndex::None,
+                indexed: true,
+            });
+        }
+    }
+}
+
+/// Data prepared for a [`SpriteMaterial`] instance.
+pub struct PreparedSpriteMaterial<T: SpriteMaterial> {
+    pub bindings: BindingResources,
+    pub bind_group: BindGroup,
+    pub key: T::Data,
+}
+
+impl<M: SpriteMaterial> RenderAsset for PreparedSpriteMaterial<M> {
+    type SourceAsset = M;
+
+    type Param = (
+        SRes<RenderDevice>,
+        SRes<SpriteMaterialPipeline<M>>,
+        M::Param,
+    );
+
+    fn prepare_asset(
+        material: Self::SourceAsset,
+        _: AssetId<Self::SourceAsset>,
+        (render_device, pipeline, material_param): &mut SystemParamItem<Self::Param>,
+    ) -> Result<Self, PrepareAssetError<Self::SourceAsset>> {
+        match material.as_bind_group(
+            &pipeline.sprite_material_layout,
+            render_device,
+            material_param,
+        ) {
+            Ok(prepared) => Ok(PreparedSpriteMaterial {
+                bindings: prepared.bindings,
+                bind_group: prepared.bind_group,
+                key: prepared.data,
+            }),
+            Err(AsBindGroupError::RetryNextUpdate) => {
+                Err(PrepareAssetError::RetryNextUpdate(material))
+            }
+            Err(other) => Err(PrepareAssetError::AsBindGroupError(other)),
+        }
+    }
+}
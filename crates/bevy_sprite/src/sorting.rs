@@ -0,0 +1,64 @@
+use bevy_core_pipeline::core_2d::Transparent2dSortKey;
+use bevy_ecs::{component::Component, query::Has, query::QueryData, reflect::ReflectComponent};
+use bevy_math::FloatOrd;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::GlobalTransform;
+
+/// The sorting layer a 2D entity is drawn in.
+///
+/// Entities in higher layers are always drawn on top of entities in lower
+/// layers, regardless of their `z` translation. Entities without this component
+/// are in layer `0`.
+///
+/// Sorting layers apply to sprites, 2D text and 2D meshes drawn in the
+/// [`Transparent2d`](bevy_core_pipeline::core_2d::Transparent2d) phase. Opaque and
+/// alpha masked 2D meshes are depth tested instead, and ignore this component.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SortingLayer(pub i32);
+
+/// The order of a 2D entity within its [`SortingLayer`].
+///
+/// Within a layer, entities with a higher order are drawn on top of entities
+/// with a lower order. Entities with the same layer and order are sorted by
+/// their `z` translation, or by their `y` translation when [`YSort`] is present.
+/// Entities without this component have an order of `0`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct OrderInLayer(pub i32);
+
+/// Sorts a 2D entity by its `y` translation instead of its `z` translation.
+///
+/// Entities lower on the screen are drawn on top of entities higher on the
+/// screen, which is the usual ordering for top-down and isometric games. This
+/// only affects sorting within the entity's [`SortingLayer`] and [`OrderInLayer`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct YSort;
+
+/// Query data for the components which control how a 2D entity is sorted.
+#[derive(QueryData)]
+pub struct Sorting2d {
+    layer: Option<&'static SortingLayer>,
+    order: Option<&'static OrderInLayer>,
+    y_sort: Has<YSort>,
+}
+
+impl Sorting2dItem<'_> {
+    /// Computes the [`Transparent2dSortKey`] of an entity at `transform`.
+    ///
+    /// `depth_bias` is added to the depth, after `y` sorting is applied.
+    pub fn sort_key(&self, transform: &GlobalTransform, depth_bias: f32) -> Transparent2dSortKey {
+        let translation = transform.translation();
+        let depth = if self.y_sort {
+            -translation.y
+        } else {
+            translation.z
+        };
+        Transparent2dSortKey {
+            layer: self.layer.map_or(0, |layer| layer.0),
+            order: self.order.map_or(0, |order| order.0),
+            depth: FloatOrd(depth + depth_bias),
+        }
+    }
+}
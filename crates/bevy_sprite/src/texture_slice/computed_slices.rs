@@ -2,6 +2,7 @@ use crate::{ExtractedSprite, Sprite, SpriteImageMode, TextureAtlasLayout};
 
 use super::TextureSlice;
 use bevy_asset::{AssetEvent, Assets};
+use bevy_core_pipeline::core_2d::Transparent2dSortKey;
 use bevy_ecs::prelude::*;
 use bevy_image::Image;
 use bevy_math::{Rect, Vec2};
@@ -22,13 +23,14 @@ impl ComputedTextureSlices {
     /// * `transform` - the sprite entity global transform
     /// * `original_entity` - the sprite entity
     /// * `sprite` - The sprite component
-    /// * `handle` - The sprite texture handle
+    /// * `sort_key` - The sort key shared by all slices of the sprite
     #[must_use]
     pub(crate) fn extract_sprites<'a>(
         &'a self,
         transform: &'a GlobalTransform,
         original_entity: Entity,
         sprite: &'a Sprite,
+        sort_key: Transparent2dSortKey,
     ) -> impl ExactSizeIterator<Item = ExtractedSprite> + 'a {
         let mut flip = Vec2::ONE;
         let [mut flip_x, mut flip_y] = [false; 2];
@@ -54,6 +56,8 @@ impl ComputedTextureSlices {
                 image_handle_id: sprite.image.id(),
                 anchor: Self::redepend_anchor_from_sprite_to_slice(sprite, slice),
                 scaling_mode: sprite.image_mode.scale(),
+                sort_key,
+                material: None,
             }
         })
     }
@@ -38,6 +38,7 @@ mod font_atlas;
 mod font_atlas_set;
 mod font_loader;
 mod glyph;
+mod measure;
 mod pipeline;
 mod text;
 mod text2d;
@@ -50,6 +51,7 @@ pub use font_atlas::*;
 pub use font_atlas_set::*;
 pub use font_loader::*;
 pub use glyph::*;
+pub use measure::*;
 pub use pipeline::*;
 pub use text::*;
 pub use text2d::*;
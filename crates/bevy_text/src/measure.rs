@@ -0,0 +1,142 @@
+use bevy_asset::Assets;
+use bevy_ecs::system::{Local, Res, ResMut, SystemParam};
+use bevy_math::Vec2;
+
+use crate::{
+    ComputedTextBlock, CosmicFontSystem, Font, LineBreak, TextBounds, TextError, TextFont,
+    TextPipeline,
+};
+
+/// A [`SystemParam`] for measuring text without spawning any entities.
+///
+/// This lays the text out with the same font system as text entities, so the measured size
+/// matches the size the text will have once spawned. This is useful to pre-compute the size of
+/// tooltips, or other layout which depends on the size of text.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_text::{LineBreak, TextBounds, TextFont, TextMeasurer};
+/// fn measure_tooltip(mut measurer: TextMeasurer) {
+///     let font = TextFont::from_font_size(16.0);
+///     let bounds = TextBounds::new_horizontal(200.0);
+///     match measurer.measure("Hello, world!", &font, LineBreak::WordBoundary, bounds) {
+///         Ok(size) => println!("the tooltip will be {} by {} pixels", size.x, size.y),
+///         Err(error) => println!("failed to measure the tooltip: {error}"),
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(measure_tooltip);
+/// ```
+#[derive(SystemParam)]
+pub struct TextMeasurer<'w, 's> {
+    fonts: Res<'w, Assets<Font>>,
+    pipeline: ResMut<'w, TextPipeline>,
+    font_system: ResMut<'w, CosmicFontSystem>,
+    computed: Local<'s, ComputedTextBlock>,
+}
+
+impl TextMeasurer<'_, '_> {
+    /// Measures the size of `text` in `font`, wrapped with `linebreak` to fit within `bounds`.
+    ///
+    /// Both `bounds` and the returned size are in logical pixels.
+    ///
+    /// Returns [`TextError::NoSuchFont`] if the font hasn't loaded yet.
+    pub fn measure(
+        &mut self,
+        text: &str,
+        font: &TextFont,
+        linebreak: LineBreak,
+        bounds: TextBounds,
+    ) -> Result<Vec2, TextError> {
+        self.measure_scaled(text, font, linebreak, bounds, 1.0)
+    }
+
+    /// Measures the size of `text` in `font` as it would be laid out at `scale_factor`, wrapped
+    /// with `linebreak` to fit within `bounds`.
+    ///
+    /// Both `bounds` and the returned size are in logical pixels.
+    ///
+    /// Returns [`TextError::NoSuchFont`] if the font hasn't loaded yet.
+    pub fn measure_scaled(
+        &mut self,
+        text: &str,
+        font: &TextFont,
+        linebreak: LineBreak,
+        bounds: TextBounds,
+        scale_factor: f32,
+    ) -> Result<Vec2, TextError> {
+        let physical_bounds = TextBounds {
+            width: bounds.width.map(|width| width * scale_factor),
+            height: bounds.height.map(|height| height * scale_factor),
+        };
+        let size = self.pipeline.measure_text(
+            &self.fonts,
+            text,
+            font,
+            linebreak,
+            physical_bounds,
+            scale_factor.into(),
+            &mut self.computed,
+            &mut self.font_system,
+        )?;
+        Ok(size / scale_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{load_internal_binary_asset, Handle};
+    use bevy_ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn measure_text_without_entities() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Font>>()
+            .init_resource::<TextPipeline>()
+            .init_resource::<CosmicFontSystem>();
+
+        load_internal_binary_asset!(
+            app,
+            Handle::default(),
+            "FiraMono-subset.ttf",
+            |bytes: &[u8], _path: String| { Font::try_from_bytes(bytes.to_vec()).unwrap() }
+        );
+
+        let (line, wrapped, scaled) = app
+            .world_mut()
+            .run_system_once(|mut measurer: TextMeasurer| {
+                let font = TextFont::default();
+                let text = "Sample text which is long enough to wrap.";
+                let line = measurer
+                    .measure(text, &font, LineBreak::WordBoundary, TextBounds::UNBOUNDED)
+                    .unwrap();
+                let wrapped = measurer
+                    .measure(
+                        text,
+                        &font,
+                        LineBreak::WordBoundary,
+                        TextBounds::new_horizontal(line.x / 2.0),
+                    )
+                    .unwrap();
+                let scaled = measurer
+                    .measure_scaled(
+                        text,
+                        &font,
+                        LineBreak::WordBoundary,
+                        TextBounds::UNBOUNDED,
+                        2.0,
+                    )
+                    .unwrap();
+                (line, wrapped, scaled)
+            })
+            .unwrap();
+
+        assert!(line.x > 0.0 && line.y > 0.0);
+        assert!(wrapped.x < line.x);
+        assert!(wrapped.y > line.y);
+        assert!((scaled - line).abs().max_element() <= 1.0);
+    }
+}
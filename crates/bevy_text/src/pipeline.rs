@@ -392,6 +392,36 @@ impl TextPipeline {
         })
     }
 
+    /// Measures a single span of text without a corresponding entity.
+    ///
+    /// The text is laid out in `computed`, which is used as scratch space. Both `bounds` and the
+    /// returned size are in physical pixels. See [`TextMeasurer`](crate::TextMeasurer) for a
+    /// more convenient way to measure text from a system.
+    pub fn measure_text(
+        &mut self,
+        fonts: &Assets<Font>,
+        text: &str,
+        text_font: &TextFont,
+        linebreak: LineBreak,
+        bounds: TextBounds,
+        scale_factor: f64,
+        computed: &mut ComputedTextBlock,
+        font_system: &mut CosmicFontSystem,
+    ) -> Result<Vec2, TextError> {
+        self.update_buffer(
+            fonts,
+            [(Entity::PLACEHOLDER, 0, text, text_font, Color::WHITE)].into_iter(),
+            linebreak,
+            JustifyText::Left,
+            bounds,
+            scale_factor,
+            computed,
+            font_system,
+        )?;
+
+        Ok(buffer_dimensions(&computed.buffer))
+    }
+
     /// Returns the [`cosmic_text::fontdb::ID`] for a given [`Font`] asset.
     pub fn get_font_id(&self, asset_id: AssetId<Font>) -> Option<cosmic_text::fontdb::ID> {
         self.map_handle_to_font_id
@@ -26,7 +26,7 @@ use bevy_render::{
     view::{NoFrustumCulling, ViewVisibility},
     Extract,
 };
-use bevy_sprite::{Anchor, ExtractedSprite, ExtractedSprites, Sprite};
+use bevy_sprite::{Anchor, ExtractedSprite, ExtractedSprites, Sorting2d, Sprite};
 use bevy_transform::components::Transform;
 use bevy_transform::prelude::GlobalTransform;
 use bevy_window::{PrimaryWindow, Window};
@@ -141,6 +141,7 @@ pub fn extract_text2d_sprite(
             &TextBounds,
             &Anchor,
             &GlobalTransform,
+            Sorting2d,
         )>,
     >,
     text_styles: Extract<Query<(&TextFont, &TextColor)>>,
@@ -160,12 +161,15 @@ pub fn extract_text2d_sprite(
         text_bounds,
         anchor,
         global_transform,
+        sorting,
     ) in text2d_query.iter()
     {
         if !view_visibility.get() {
             continue;
         }
 
+        let sort_key = sorting.sort_key(global_transform, 0.0);
+
         let size = Vec2::new(
             text_bounds.width.unwrap_or(text_layout_info.size.x),
             text_bounds.height.unwrap_or(text_layout_info.size.y),
@@ -214,6 +218,8 @@ pub fn extract_text2d_sprite(
                     anchor: Anchor::Center.as_vec(),
                     original_entity: Some(original_entity),
                     scaling_mode: None,
+                    sort_key,
+                    material: None,
                 },
             );
         }
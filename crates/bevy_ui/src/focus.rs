@@ -220,9 +220,10 @@ pub fn ui_focus_system(
     let mut hovered_nodes = ui_stack
         .uinodes
         .iter()
+        .enumerate()
         // reverse the iterator to traverse the tree from closest nodes to furthest
         .rev()
-        .filter_map(|entity| {
+        .filter_map(|(stack_index, entity)| {
             let Ok(node) = node_query.get_mut(*entity) else {
                 return None;
             };
@@ -273,7 +274,9 @@ pub fn ui_focus_system(
                 normalized: relative_cursor_position,
             };
 
-            let contains_cursor = relative_cursor_position_component.mouse_over()
+            // Nodes covered by an open modal can't be hovered or pressed
+            let contains_cursor = !ui_stack.is_blocked(stack_index)
+                && relative_cursor_position_component.mouse_over()
                 && cursor_position.is_some_and(|point| {
                     pick_rounded_rect(
                         *point - node_rect.center(),
@@ -1,7 +1,7 @@
 use crate::{
     experimental::{UiChildren, UiRootNodes},
-    BorderRadius, ComputedNode, ContentSize, DefaultUiCamera, Display, LayoutConfig, Node, Outline,
-    OverflowAxis, ScrollPosition, UiScale, UiTargetCamera, Val,
+    BorderRadius, ComputedNode, ContentSize, DefaultUiCamera, Display, LayoutConfig,
+    LayoutRounding, Node, Outline, OverflowAxis, ScrollPosition, UiScale, UiTargetCamera, Val,
 };
 use bevy_ecs::{
     entity::{hash_map::EntityHashMap, hash_set::EntityHashSet},
@@ -107,6 +107,7 @@ pub fn ui_layout_system(
         Ref<Node>,
         Option<&mut ContentSize>,
         Option<&UiTargetCamera>,
+        Option<&LayoutRounding>,
     )>,
     computed_node_query: Query<(Entity, Option<Ref<ChildOf>>), With<ComputedNode>>,
     ui_children: UiChildren,
@@ -120,7 +121,6 @@ pub fn ui_layout_system(
         Option<&Outline>,
         Option<&ScrollPosition>,
     )>,
-
     mut buffer_query: Query<&mut ComputedTextBlock>,
     mut font_system: ResMut<CosmicFontSystem>,
 ) {
@@ -161,7 +161,7 @@ pub fn ui_layout_system(
 
     node_query
         .iter_many(root_nodes.iter())
-        .for_each(|(entity, _, _, target_camera)| {
+        .for_each(|(entity, _, _, target_camera, _)| {
             match camera_with_default(target_camera) {
                 Some(camera_entity) => {
                     let Ok((_, camera)) = cameras.get(camera_entity) else {
@@ -200,7 +200,7 @@ pub fn ui_layout_system(
     // Sync Node and ContentSize to Taffy for all nodes
     node_query
         .iter_mut()
-        .for_each(|(entity, node, content_size, target_camera)| {
+        .for_each(|(entity, node, content_size, target_camera, _)| {
             if let Some(camera) =
                 camera_with_default(target_camera).and_then(|c| camera_layout_info.get(&c))
             {
@@ -286,10 +286,16 @@ with UI components as a child of an entity without UI components, your UI layout
         ui_surface.compute_camera_layout(camera_id, camera.size, text_buffers, &mut font_system);
 
         for root in &camera.root_nodes {
+            let rounding = node_query
+                .get(*root)
+                .ok()
+                .and_then(|(.., rounding)| rounding.copied())
+                .unwrap_or_default();
             update_uinode_geometry_recursive(
                 &mut commands,
                 *root,
                 &mut ui_surface,
+                rounding,
                 true,
                 None,
                 &mut node_transform_query,
@@ -297,6 +303,7 @@ with UI components as a child of an entity without UI components, your UI layout
                 inverse_target_scale_factor,
                 Vec2::ZERO,
                 Vec2::ZERO,
+                Vec2::ZERO,
             );
         }
 
@@ -309,6 +316,7 @@ with UI components as a child of an entity without UI components, your UI layout
         commands: &mut Commands,
         entity: Entity,
         ui_surface: &mut UiSurface,
+        rounding: LayoutRounding,
         inherited_use_rounding: bool,
         root_size: Option<Vec2>,
         node_transform_query: &mut Query<(
@@ -324,6 +332,7 @@ with UI components as a child of an entity without UI components, your UI layout
         inverse_target_scale_factor: f32,
         parent_size: Vec2,
         parent_scroll_position: Vec2,
+        parent_position: Vec2,
     ) {
         if let Ok((
             mut node,
@@ -339,10 +348,26 @@ with UI components as a child of an entity without UI components, your UI layout
                 .map(|layout_config| layout_config.use_rounding)
                 .unwrap_or(inherited_use_rounding);
 
-            let Ok((layout, unrounded_size)) = ui_surface.get_layout(entity, use_rounding) else {
+            let node_rounding = if use_rounding {
+                rounding
+            } else {
+                LayoutRounding::None
+            };
+
+            let Ok((mut layout, unrounded_size)) =
+                ui_surface.get_layout(entity, node_rounding == LayoutRounding::PhysicalPixels)
+            else {
                 return;
             };
 
+            // The unrounded position of the node relative to its root. Only used when rounding to
+            // logical pixels, where the layout we get back from taffy is never rounded.
+            let position = parent_position + Vec2::new(layout.location.x, layout.location.y);
+
+            if node_rounding == LayoutRounding::LogicalPixels {
+                round_layout(&mut layout, position, inverse_target_scale_factor.recip());
+            }
+
             let layout_size = Vec2::new(layout.size.width, layout.size.height);
 
             let layout_location = Vec2::new(layout.location.x, layout.location.y);
@@ -450,6 +475,7 @@ with UI components as a child of an entity without UI components, your UI layout
                     commands,
                     child_uinode,
                     ui_surface,
+                    rounding,
                     use_rounding,
                     Some(viewport_size),
                     node_transform_query,
@@ -457,12 +483,38 @@ with UI components as a child of an entity without UI components, your UI layout
                     inverse_target_scale_factor,
                     layout_size,
                     physical_scroll_position,
+                    position,
                 );
             }
         }
     }
 }
 
+/// Rounds `layout` to a grid with cells `grid_size` physical pixels wide, matching taffy's own
+/// rounding. `position` is the unrounded position of the node relative to its root, so that the
+/// edges of adjacent nodes are rounded to the same grid lines.
+fn round_layout(layout: &mut taffy::Layout, position: Vec2, grid_size: f32) {
+    let round = |value: f32| (value / grid_size).round() * grid_size;
+    let end = position + Vec2::new(layout.size.width, layout.size.height);
+    let content_end = position + Vec2::new(layout.content_size.width, layout.content_size.height);
+
+    let round_rect = |rect: taffy::Rect<f32>| taffy::Rect {
+        left: round(position.x + rect.left) - round(position.x),
+        right: round(end.x) - round(end.x - rect.right),
+        top: round(position.y + rect.top) - round(position.y),
+        bottom: round(end.y) - round(end.y - rect.bottom),
+    };
+
+    layout.location.x = round(layout.location.x);
+    layout.location.y = round(layout.location.y);
+    layout.size.width = round(end.x) - round(position.x);
+    layout.size.height = round(end.y) - round(position.y);
+    layout.content_size.width = round(content_end.x) - round(position.x);
+    layout.content_size.height = round(content_end.y) - round(position.y);
+    layout.border = round_rect(layout.border);
+    layout.padding = round_rect(layout.padding);
+}
+
 #[cfg(test)]
 mod tests {
     use taffy::TraversePartialTree;
@@ -1137,6 +1189,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ui_logical_rounding_test() {
+        let (mut world, mut ui_schedule) = setup_ui_test_world();
+        world.resource_mut::<UiScale>().0 = 1.5;
+
+        let parent = world
+            .spawn((
+                Node {
+                    margin: UiRect::all(Val::Px(4.3)),
+                    ..default()
+                },
+                LayoutRounding::LogicalPixels,
+            ))
+            .with_children(|commands| {
+                for _ in 0..3 {
+                    commands.spawn(Node {
+                        width: Val::Px(10.3),
+                        height: Val::Px(10.3),
+                        ..default()
+                    });
+                }
+            })
+            .id();
+
+        ui_schedule.run(&mut world);
+
+        let is_logical_pixel = |value: f32| {
+            let logical = value / 1.5;
+            (logical - logical.round()).abs() < 0.001
+        };
+
+        let children = world
+            .entity(parent)
+            .get::<Children>()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<Entity>>();
+        let mut width_sum = 0.;
+        for child in children {
+            let size = world.get::<ComputedNode>(child).unwrap().size;
+            assert!(is_logical_pixel(size.x), "{size}");
+            assert!(is_logical_pixel(size.y), "{size}");
+            width_sum += size.x;
+        }
+
+        let parent_width = world.get::<ComputedNode>(parent).unwrap().size.x;
+        assert!((width_sum - parent_width).abs() < 0.001);
+    }
+
     #[test]
     fn no_camera_ui() {
         let mut world = World::new();
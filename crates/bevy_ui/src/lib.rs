@@ -17,6 +17,8 @@ pub mod widget;
 
 #[cfg(feature = "bevy_ui_picking_backend")]
 pub mod picking_backend;
+#[cfg(feature = "bevy_ui_picking_backend")]
+pub mod tooltip;
 
 use bevy_derive::{Deref, DerefMut};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
@@ -27,6 +29,7 @@ pub mod experimental;
 mod focus;
 mod geometry;
 mod layout;
+mod modal;
 mod render;
 mod stack;
 mod ui_node;
@@ -35,6 +38,7 @@ pub use focus::*;
 pub use geometry::*;
 pub use layout::*;
 pub use measurement::*;
+pub use modal::*;
 pub use render::*;
 pub use ui_material::*;
 pub use ui_node::*;
@@ -49,7 +53,7 @@ pub mod prelude {
     #[cfg(feature = "bevy_ui_debug")]
     pub use crate::render::UiDebugOptions;
     #[doc(hidden)]
-    pub use crate::widget::{Text, TextUiReader, TextUiWriter};
+    pub use crate::widget::{Text, TextPositioning, TextUiReader, TextUiWriter};
     #[doc(hidden)]
     pub use {
         crate::{
@@ -57,7 +61,7 @@ pub mod prelude {
             ui_material::*,
             ui_node::*,
             widget::{Button, ImageNode, Label},
-            Interaction, MaterialNode, UiMaterialPlugin, UiScale,
+            Interaction, MaterialNode, Modal, UiMaterialPlugin, UiScale,
         },
         // `bevy_sprite` re-exports for texture slicing
         bevy_sprite::{BorderRect, SliceScaleMode, SpriteImageMode, TextureSlicer},
@@ -66,13 +70,15 @@ pub mod prelude {
 
 use bevy_app::{prelude::*, Animation};
 use bevy_ecs::prelude::*;
-use bevy_input::InputSystem;
+use bevy_input::{keyboard::KeyCode, ButtonInput, InputSystem};
 use bevy_render::{camera::CameraUpdateSystem, RenderApp};
 use bevy_transform::TransformSystem;
 use layout::ui_surface::UiSurface;
 use stack::ui_stack_system;
 pub use stack::UiStack;
-use update::{update_clipping_system, update_target_camera_system};
+use update::{
+    resize_render_target_images_system, update_clipping_system, update_target_camera_system,
+};
 
 /// The basic plugin for Bevy UI
 pub struct UiPlugin {
@@ -144,6 +150,9 @@ impl Plugin for UiPlugin {
         app.init_resource::<UiSurface>()
             .init_resource::<UiScale>()
             .init_resource::<UiStack>()
+            .init_resource::<ModalStack>()
+            .add_event::<ModalOpened>()
+            .add_event::<ModalClosed>()
             .register_type::<BackgroundColor>()
             .register_type::<CalculatedClip>()
             .register_type::<ComputedNode>()
@@ -153,7 +162,10 @@ impl Plugin for UiPlugin {
             .register_type::<Node>()
             .register_type::<RelativeCursorPosition>()
             .register_type::<ScrollPosition>()
+            .register_type::<LayoutRounding>()
+            .register_type::<Modal>()
             .register_type::<UiTargetCamera>()
+            .register_type::<RenderTargetAutoResize>()
             .register_type::<ImageNode>()
             .register_type::<ImageNodeSize>()
             .register_type::<UiRect>()
@@ -179,7 +191,12 @@ impl Plugin for UiPlugin {
             )
             .add_systems(
                 PreUpdate,
-                ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
+                (
+                    ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
+                    dismiss_modal_on_escape
+                        .after(InputSystem)
+                        .run_if(resource_exists::<ButtonInput<KeyCode>>),
+                ),
             );
 
         let ui_layout_system_config = ui_layout_system
@@ -195,6 +212,7 @@ impl Plugin for UiPlugin {
             PostUpdate,
             (
                 update_target_camera_system.in_set(UiSystem::Prepare),
+                update_modal_stack.in_set(UiSystem::Prepare),
                 ui_layout_system_config,
                 ui_stack_system
                     .in_set(UiSystem::Stack)
@@ -204,6 +222,11 @@ impl Plugin for UiPlugin {
                     .in_set(AmbiguousWithTextSystem),
                 update_clipping_system.after(TransformSystem::TransformPropagate),
                 // Potential conflicts: `Assets<Image>`
+                // Text only ever inserts new images, and never modifies render target images.
+                resize_render_target_images_system
+                    .in_set(UiSystem::PostLayout)
+                    .in_set(AmbiguousWithTextSystem),
+                // Potential conflicts: `Assets<Image>`
                 // They run independently since `widget::image_node_system` will only ever observe
                 // its own ImageNode, and `widget::text_system` & `bevy_text::update_text2d_layout`
                 // will never modify a pre-existing `Image` asset.
@@ -217,7 +240,7 @@ impl Plugin for UiPlugin {
 
         #[cfg(feature = "bevy_ui_picking_backend")]
         if self.add_picking {
-            app.add_plugins(picking_backend::UiPickingPlugin);
+            app.add_plugins((picking_backend::UiPickingPlugin, tooltip::TooltipPlugin));
         }
 
         if !self.enable_rendering {
@@ -244,12 +267,13 @@ impl Plugin for UiPlugin {
 }
 
 fn build_text_interop(app: &mut App) {
-    use crate::widget::TextNodeFlags;
+    use crate::widget::{TextNodeFlags, TextPositioning};
     use bevy_text::TextLayoutInfo;
     use widget::Text;
 
     app.register_type::<TextLayoutInfo>()
         .register_type::<TextNodeFlags>()
+        .register_type::<TextPositioning>()
         .register_type::<Text>();
 
     app.add_systems(
@@ -0,0 +1,139 @@
+//! Modal layers, which block interaction with the UI beneath them while they're open.
+
+use crate::GlobalZIndex;
+use bevy_ecs::prelude::*;
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Marks a UI node as a modal layer, such as a dialog or a pause menu.
+///
+/// A modal is open for as long as this component exists. While any modal is open, UI nodes
+/// drawn beneath the most recently opened one can't be hovered or pressed, neither through
+/// [`Interaction`](crate::Interaction) nor through picking. Nodes drawn above it, such as
+/// nodes with a greater [`GlobalZIndex`], are unaffected.
+///
+/// Like a node with a [`GlobalZIndex`], a modal is always a root of the [`UiStack`], even if it
+/// has a parent. Open modals are drawn above all other nodes with the same [`GlobalZIndex`],
+/// in the order they were opened, so a modal opened from another modal appears on top of it.
+///
+/// When the escape key is pressed, [`ModalDismissRequested`] is triggered on the topmost modal.
+///
+/// [`UiStack`]: crate::UiStack
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(GlobalZIndex)]
+pub struct Modal {
+    /// Whether to despawn this modal and its descendants when the escape key is pressed while
+    /// it's the topmost modal.
+    ///
+    /// Defaults to `true`.
+    pub close_on_escape: bool,
+}
+
+impl Default for Modal {
+    fn default() -> Self {
+        Self {
+            close_on_escape: true,
+        }
+    }
+}
+
+/// The open [`Modal`]s, in the order they were opened.
+///
+/// Updated in [`UiSystem::Prepare`](crate::UiSystem::Prepare).
+#[derive(Resource, Debug, Default)]
+pub struct ModalStack {
+    modals: Vec<Entity>,
+}
+
+impl ModalStack {
+    /// Returns the most recently opened modal, which is the only one that can be interacted
+    /// with.
+    pub fn top(&self) -> Option<Entity> {
+        self.modals.last().copied()
+    }
+
+    /// Returns the open modals, from the first to the most recently opened.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Entity> + '_ {
+        self.modals.iter().copied()
+    }
+
+    /// Returns the position of `entity` in the stack, or `None` if it isn't an open modal.
+    pub fn position(&self, entity: Entity) -> Option<usize> {
+        self.modals.iter().position(|modal| *modal == entity)
+    }
+
+    /// Returns `true` if any modal is open.
+    pub fn is_open(&self) -> bool {
+        !self.modals.is_empty()
+    }
+}
+
+/// Sent when a [`Modal`] opens.
+#[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModalOpened {
+    /// The modal entity.
+    pub entity: Entity,
+}
+
+/// Sent when a [`Modal`] closes, because its component was removed or it was despawned.
+#[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModalClosed {
+    /// The modal entity.
+    pub entity: Entity,
+}
+
+/// Triggered on the topmost [`Modal`] when the escape key is pressed.
+///
+/// This is triggered whether or not the modal [closes on escape](Modal::close_on_escape), so
+/// observers can use it to e.g. cancel a dialog or ask for confirmation instead.
+#[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ModalDismissRequested;
+
+/// Keeps the [`ModalStack`] up to date, and sends [`ModalOpened`] and [`ModalClosed`] events.
+pub fn update_modal_stack(
+    mut modal_stack: ResMut<ModalStack>,
+    added_modals: Query<Entity, Added<Modal>>,
+    mut removed_modals: RemovedComponents<Modal>,
+    mut opened_events: EventWriter<ModalOpened>,
+    mut closed_events: EventWriter<ModalClosed>,
+) {
+    for entity in removed_modals.read() {
+        if let Some(index) = modal_stack.position(entity) {
+            modal_stack.modals.remove(index);
+            closed_events.send(ModalClosed { entity });
+        }
+    }
+
+    for entity in &added_modals {
+        if modal_stack.position(entity).is_none() {
+            modal_stack.modals.push(entity);
+            opened_events.send(ModalOpened { entity });
+        }
+    }
+}
+
+/// Triggers [`ModalDismissRequested`] on the topmost [`Modal`] when the escape key is pressed,
+/// and despawns it if it [closes on escape](Modal::close_on_escape).
+pub fn dismiss_modal_on_escape(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    modal_stack: Res<ModalStack>,
+    modal_query: Query<&Modal>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    let Some(entity) = modal_stack.top() else {
+        return;
+    };
+    let Ok(modal) = modal_query.get(entity) else {
+        return;
+    };
+
+    commands.trigger_targets(ModalDismissRequested, entity);
+    if modal.close_on_escape {
+        commands.entity(entity).despawn();
+    }
+}
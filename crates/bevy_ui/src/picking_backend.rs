@@ -112,12 +112,18 @@ pub fn ui_picking(
     // prepare an iterator that contains all the nodes that have the cursor in their rect,
     // from the top node to the bottom one. this will also reset the interaction to `None`
     // for all nodes encountered that are no longer hovered.
-    for node_entity in ui_stack
+    for (stack_index, node_entity) in ui_stack
         .uinodes
         .iter()
+        .enumerate()
         // reverse the iterator to traverse the tree from closest nodes to furthest
         .rev()
     {
+        // Nodes covered by an open modal can't be picked
+        if ui_stack.is_blocked(stack_index) {
+            continue;
+        }
+
         let Ok(node) = node_query.get(*node_entity) else {
             continue;
         };
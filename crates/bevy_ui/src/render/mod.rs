@@ -7,7 +7,7 @@ pub mod ui_texture_slice_pipeline;
 #[cfg(feature = "bevy_ui_debug")]
 mod debug_overlay;
 
-use crate::widget::ImageNode;
+use crate::widget::{ImageNode, TextPositioning};
 use crate::{
     BackgroundColor, BorderColor, BoxShadowSamples, CalculatedClip, ComputedNode, DefaultUiCamera,
     Outline, ResolvedBorderRadius, UiAntiAlias, UiTargetCamera,
@@ -662,7 +662,7 @@ pub fn extract_ui_camera_view(
                             UI_CAMERA_FAR + UI_CAMERA_TRANSFORM_OFFSET,
                         ),
                         clip_from_world: None,
-                        hdr: camera.hdr,
+                        hdr: camera.is_hdr(),
                         viewport: UVec4::from((
                             physical_viewport_rect.min,
                             physical_viewport_rect.size(),
@@ -709,6 +709,7 @@ pub fn extract_text_sections(
             Option<&UiTargetCamera>,
             &ComputedTextBlock,
             &TextLayoutInfo,
+            Option<&TextPositioning>,
         )>,
     >,
     text_styles: Extract<Query<&TextColor>>,
@@ -727,6 +728,7 @@ pub fn extract_text_sections(
         camera,
         computed_block,
         text_layout_info,
+        text_positioning,
     ) in &uinode_query
     {
         // Skip if not visible or if size is set to zero (e.g. when a parent is set to `Display::None`)
@@ -738,9 +740,15 @@ pub fn extract_text_sections(
             continue;
         };
 
-        let transform = global_transform.affine()
+        let mut transform = global_transform.affine()
             * bevy_math::Affine3A::from_translation((-0.5 * uinode.size()).extend(0.));
 
+        if text_positioning.copied().unwrap_or_default() == TextPositioning::PixelAligned {
+            // Glyph positions are already whole physical pixels relative to the text block
+            transform.translation.x = transform.translation.x.round();
+            transform.translation.y = transform.translation.y.round();
+        }
+
         let mut color = LinearRgba::WHITE;
         let mut current_span = usize::MAX;
         for (
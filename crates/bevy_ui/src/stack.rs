@@ -5,7 +5,7 @@ use bevy_platform_support::collections::HashSet;
 
 use crate::{
     experimental::{UiChildren, UiRootNodes},
-    ComputedNode, GlobalZIndex, ZIndex,
+    ComputedNode, GlobalZIndex, ModalStack, ZIndex,
 };
 
 /// The current UI stack, which contains all UI nodes ordered by their depth (back-to-front).
@@ -16,6 +16,19 @@ use crate::{
 pub struct UiStack {
     /// List of UI nodes ordered from back-to-front
     pub uinodes: Vec<Entity>,
+    /// The index in [`uinodes`](Self::uinodes) of the topmost open [`Modal`](crate::Modal), if any.
+    ///
+    /// Nodes before this index are covered by the modal, and can't be interacted with.
+    pub modal_index: Option<usize>,
+}
+
+impl UiStack {
+    /// Returns `true` if the node at `index` in [`uinodes`](Self::uinodes) is covered by an
+    /// open [`Modal`](crate::Modal).
+    pub fn is_blocked(&self, index: usize) -> bool {
+        self.modal_index
+            .is_some_and(|modal_index| index < modal_index)
+    }
 }
 
 #[derive(Default)]
@@ -36,13 +49,15 @@ impl ChildBufferCache {
 /// Generates the render stack for UI nodes.
 ///
 /// Create a list of root nodes from parentless entities and entities with a `GlobalZIndex` component.
-/// Then build the `UiStack` from a walk of the existing layout trees starting from each root node,
+/// Roots with the same `GlobalZIndex` are ordered by when they were opened if they're a `Modal`,
+/// and then by their `ZIndex`. Then build the `UiStack` from a walk of the existing layout trees starting from each root node,
 /// filtering branches by `Without<GlobalZIndex>`so that we don't revisit nodes.
 pub fn ui_stack_system(
     mut cache: Local<ChildBufferCache>,
-    mut root_nodes: Local<Vec<(Entity, (i32, i32))>>,
+    mut root_nodes: Local<Vec<(Entity, (i32, usize, i32))>>,
     mut visited_root_nodes: Local<HashSet<Entity>>,
     mut ui_stack: ResMut<UiStack>,
+    modal_stack: Res<ModalStack>,
     ui_root_nodes: UiRootNodes,
     root_node_query: Query<(Entity, Option<&GlobalZIndex>, Option<&ZIndex>)>,
     zindex_global_node_query: Query<(Entity, &GlobalZIndex, Option<&ZIndex>), With<ComputedNode>>,
@@ -51,13 +66,18 @@ pub fn ui_stack_system(
     mut update_query: Query<&mut ComputedNode>,
 ) {
     ui_stack.uinodes.clear();
+    ui_stack.modal_index = None;
     visited_root_nodes.clear();
 
+    // Open modals are stacked above the other roots with the same `GlobalZIndex`
+    let modal_rank = |id| modal_stack.position(id).map_or(0, |position| position + 1);
+
     for (id, maybe_global_zindex, maybe_zindex) in root_node_query.iter_many(ui_root_nodes.iter()) {
         root_nodes.push((
             id,
             (
                 maybe_global_zindex.map(|zindex| zindex.0).unwrap_or(0),
+                modal_rank(id),
                 maybe_zindex.map(|zindex| zindex.0).unwrap_or(0),
             ),
         ));
@@ -73,6 +93,7 @@ pub fn ui_stack_system(
             id,
             (
                 global_zindex.0,
+                modal_rank(id),
                 maybe_zindex.map(|zindex| zindex.0).unwrap_or(0),
             ),
         ));
@@ -81,6 +102,9 @@ pub fn ui_stack_system(
     root_nodes.sort_by_key(|(_, z)| *z);
 
     for (root_entity, _) in root_nodes.drain(..) {
+        if modal_stack.top() == Some(root_entity) {
+            ui_stack.modal_index = Some(ui_stack.uinodes.len());
+        }
         update_uistack_recursive(
             &mut cache,
             root_entity,
@@ -128,12 +152,16 @@ fn update_uistack_recursive(
 mod tests {
     use bevy_ecs::{
         component::Component,
-        schedule::Schedule,
+        event::Events,
+        schedule::{IntoSystemConfigs, Schedule},
         system::Commands,
         world::{CommandQueue, World},
     };
 
-    use crate::{GlobalZIndex, Node, UiStack, ZIndex};
+    use crate::{
+        update_modal_stack, GlobalZIndex, Modal, ModalClosed, ModalOpened, ModalStack, Node,
+        UiStack, ZIndex,
+    };
 
     use super::ui_stack_system;
 
@@ -178,6 +206,7 @@ mod tests {
     fn test_ui_stack_system() {
         let mut world = World::default();
         world.init_resource::<UiStack>();
+        world.init_resource::<ModalStack>();
 
         let mut queue = CommandQueue::default();
         let mut commands = Commands::new(&mut queue, &world);
@@ -262,6 +291,7 @@ mod tests {
     fn test_with_equal_global_zindex_zindex_decides_order() {
         let mut world = World::default();
         world.init_resource::<UiStack>();
+        world.init_resource::<ModalStack>();
 
         let mut queue = CommandQueue::default();
         let mut commands = Commands::new(&mut queue, &world);
@@ -306,4 +336,48 @@ mod tests {
 
         assert_eq!(actual_result, expected_result);
     }
+
+    #[test]
+    fn test_modals_are_stacked_in_order_and_block_lower_nodes() {
+        let mut world = World::default();
+        world.init_resource::<UiStack>();
+        world.init_resource::<ModalStack>();
+        world.init_resource::<Events<ModalOpened>>();
+        world.init_resource::<Events<ModalClosed>>();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((update_modal_stack, ui_stack_system).chain());
+
+        let root = world.spawn(node_without_zindex("0")).id();
+        world.spawn((node_without_zindex("1"), Modal::default()));
+        world.spawn(node_with_global_zindex("2", 1));
+        schedule.run(&mut world);
+
+        // A modal opened later is stacked above the first one, even though it has a parent
+        let nested_modal = world
+            .spawn((node_without_zindex("3"), Modal::default()))
+            .id();
+        world.entity_mut(root).add_child(nested_modal);
+        schedule.run(&mut world);
+
+        let mut query = world.query::<&Label>();
+        let ui_stack = world.resource::<UiStack>();
+        let actual_result = ui_stack
+            .uinodes
+            .iter()
+            .map(|entity| query.get(&world, *entity).unwrap().clone())
+            .collect::<Vec<_>>();
+
+        let expected_result = vec![
+            (Label("0")),
+            (Label("1")),
+            (Label("3")),
+            (Label("2")), // GlobalZIndex(1)
+        ];
+
+        assert_eq!(actual_result, expected_result);
+        assert_eq!(ui_stack.modal_index, Some(2));
+        assert!(ui_stack.is_blocked(1));
+        assert!(!ui_stack.is_blocked(3));
+    }
 }
@@ -0,0 +1,436 @@
+//! Tooltips which appear while the pointer hovers over a UI node.
+//!
+//! Add a [`Tooltip`] to a UI node to spawn a tooltip next to it once a pointer has hovered over
+//! the node (or any of its descendants) for a short delay. The tooltip is despawned as soon as
+//! the pointer leaves the node, or when the UI is scrolled.
+//!
+//! Hovering is detected using [`bevy_picking`], so this requires the picking plugins to be added.
+
+use crate::{
+    ComputedNode, DefaultUiCamera, GlobalZIndex, Node, PositionType, ScrollPosition, UiSystem,
+    UiTargetCamera, Val,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, system::EntityCommands};
+use bevy_input::mouse::MouseWheel;
+use bevy_math::{FloatOrd, Rect, Vec2};
+use bevy_picking::{hover::HoverMap, pointer::PointerId, PickSet, Pickable};
+use bevy_platform_support::{collections::HashMap, sync::Arc};
+use bevy_render::{
+    camera::{Camera, CameraUpdateSystem},
+    view::Visibility,
+};
+use bevy_time::Time;
+use bevy_transform::components::GlobalTransform;
+use core::{fmt, time::Duration};
+
+/// A plugin which shows [`Tooltip`]s for hovered UI nodes.
+///
+/// This is added by [`UiPlugin`](crate::UiPlugin) along with the UI picking backend.
+#[derive(Default)]
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipSettings>()
+            .add_systems(
+                PreUpdate,
+                update_tooltips
+                    .in_set(PickSet::PostHover)
+                    .run_if(resource_exists::<HoverMap>),
+            )
+            .add_systems(
+                PostUpdate,
+                place_tooltips
+                    .after(CameraUpdateSystem)
+                    .before(UiSystem::Layout),
+            );
+    }
+}
+
+/// Global settings for [`Tooltip`]s.
+#[derive(Resource, Clone, Debug)]
+pub struct TooltipSettings {
+    /// How long a node must be hovered before its tooltip appears, unless overridden by
+    /// [`Tooltip::delay`].
+    ///
+    /// Defaults to 500 milliseconds.
+    pub delay: Duration,
+    /// The gap between a tooltip and the node it belongs to, in logical pixels.
+    ///
+    /// Defaults to 4.
+    pub gap: f32,
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            gap: 4.,
+        }
+    }
+}
+
+/// Which side of its node a [`Tooltip`] appears on.
+///
+/// If the tooltip wouldn't fit on the screen on this side, it's placed on the opposite side
+/// instead, and it's always moved along the side to keep it on the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TooltipPlacement {
+    /// Above the node, centered horizontally.
+    Above,
+    /// Below the node, centered horizontally.
+    #[default]
+    Below,
+    /// To the left of the node, centered vertically.
+    Left,
+    /// To the right of the node, centered vertically.
+    Right,
+}
+
+impl TooltipPlacement {
+    /// Returns the placement on the opposite side of the node.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Above => Self::Below,
+            Self::Below => Self::Above,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Shows a tooltip while a pointer hovers over this UI node.
+///
+/// When the tooltip appears, an absolutely positioned root [`Node`] with an [`ActiveTooltip`]
+/// component is spawned, and passed to the content builder to add the tooltip's contents.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_color::Color;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::prelude::*;
+/// # use bevy_ui::tooltip::{Tooltip, TooltipPlacement};
+/// # use core::time::Duration;
+/// fn spawn_button(mut commands: Commands) {
+///     commands.spawn((
+///         Button,
+///         Tooltip::new(|tooltip| {
+///             tooltip
+///                 .insert(BackgroundColor(Color::BLACK))
+///                 .with_child(Text::new("Saves the game"));
+///         })
+///         .with_placement(TooltipPlacement::Above)
+///         .with_delay(Duration::from_millis(250)),
+///     ));
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_button);
+/// ```
+#[derive(Component, Clone)]
+pub struct Tooltip {
+    content: Arc<dyn Fn(&mut EntityCommands) + Send + Sync>,
+    /// Which side of the node the tooltip appears on.
+    pub placement: TooltipPlacement,
+    /// How long the node must be hovered before the tooltip appears.
+    ///
+    /// If `None`, [`TooltipSettings::delay`] is used.
+    pub delay: Option<Duration>,
+}
+
+impl Tooltip {
+    /// Creates a tooltip whose contents are added by `content`.
+    pub fn new(content: impl Fn(&mut EntityCommands) + Send + Sync + 'static) -> Self {
+        Self {
+            content: Arc::new(content),
+            placement: TooltipPlacement::default(),
+            delay: None,
+        }
+    }
+
+    /// Returns this tooltip with the given placement.
+    pub fn with_placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Returns this tooltip with the given hover delay.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+impl fmt::Debug for Tooltip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tooltip")
+            .field("placement", &self.placement)
+            .field("delay", &self.delay)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Marks the root node of a tooltip which is currently shown.
+///
+/// The tooltip stays hidden until it has been laid out and placed next to its target.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ActiveTooltip {
+    /// The entity with the [`Tooltip`] that this tooltip belongs to.
+    pub target: Entity,
+}
+
+/// The tooltip state of a single pointer, used by [`update_tooltips`].
+pub struct TooltipHover {
+    target: Entity,
+    hovered_for: Duration,
+    popup: Option<Entity>,
+    /// Set when the tooltip was dismissed, so that it doesn't appear again until the pointer
+    /// leaves the target.
+    dismissed: bool,
+}
+
+/// Spawns and despawns tooltips as pointers hover over nodes with a [`Tooltip`].
+pub fn update_tooltips(
+    mut commands: Commands,
+    mut hovers: Local<HashMap<PointerId, TooltipHover>>,
+    hover_map: Res<HoverMap>,
+    time: Res<Time>,
+    settings: Res<TooltipSettings>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    scrolled_nodes: Query<(), Changed<ScrollPosition>>,
+    tooltips: Query<(&Tooltip, Option<&UiTargetCamera>)>,
+    parents: Query<&ChildOf>,
+) {
+    let scrolled = mouse_wheel.read().count() > 0 || !scrolled_nodes.is_empty();
+
+    // Find the nearest node with a tooltip to the topmost entity hovered by each pointer
+    let hovered_targets: HashMap<PointerId, Entity> = hover_map
+        .iter()
+        .filter_map(|(pointer, hits)| {
+            let (hovered, _) = hits.iter().min_by_key(|(_, hit)| FloatOrd(hit.depth))?;
+            let target = core::iter::once(*hovered)
+                .chain(parents.iter_ancestors(*hovered))
+                .find(|entity| tooltips.contains(*entity))?;
+            Some((*pointer, target))
+        })
+        .collect();
+
+    hovers.retain(|pointer, hover| {
+        let still_hovered = hovered_targets.get(pointer) == Some(&hover.target);
+        if !still_hovered {
+            if let Some(popup) = hover.popup {
+                commands.entity(popup).try_despawn();
+            }
+        }
+        still_hovered
+    });
+
+    for (pointer, target) in hovered_targets {
+        let hover = hovers.entry(pointer).or_insert(TooltipHover {
+            target,
+            hovered_for: Duration::ZERO,
+            popup: None,
+            dismissed: false,
+        });
+
+        if scrolled {
+            if let Some(popup) = hover.popup.take() {
+                commands.entity(popup).try_despawn();
+            }
+            hover.dismissed = true;
+        }
+        if hover.dismissed || hover.popup.is_some() {
+            continue;
+        }
+
+        let Ok((tooltip, target_camera)) = tooltips.get(target) else {
+            continue;
+        };
+
+        hover.hovered_for += time.delta();
+        if hover.hovered_for < tooltip.delay.unwrap_or(settings.delay) {
+            continue;
+        }
+
+        let mut popup = commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            ActiveTooltip { target },
+            GlobalZIndex(i32::MAX),
+            Visibility::Hidden,
+        ));
+        if let Some(target_camera) = target_camera {
+            popup.insert(target_camera.clone());
+        }
+        (tooltip.content)(&mut popup);
+        // Hovering the tooltip itself must not count as leaving its target, so none of its
+        // contents can be picked.
+        popup.queue(ignore_picking_recursive);
+        hover.popup = Some(popup.id());
+    }
+}
+
+/// Makes an entity and all of its descendants ignored by picking.
+fn ignore_picking_recursive(mut entity: EntityWorldMut) {
+    entity.insert(Pickable::IGNORE);
+    let children = entity
+        .get::<Children>()
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    entity.world_scope(|world| {
+        for child in children {
+            if let Ok(child) = world.get_entity_mut(child) {
+                ignore_picking_recursive(child);
+            }
+        }
+    });
+}
+
+/// Positions each [`ActiveTooltip`] next to its target, and shows it once it has a size.
+///
+/// This uses the sizes from the previous layout, so tooltips appear one frame after they are
+/// spawned.
+pub fn place_tooltips(
+    mut popups: Query<(
+        &ActiveTooltip,
+        &ComputedNode,
+        &mut Node,
+        &mut Visibility,
+        Option<&UiTargetCamera>,
+    )>,
+    targets: Query<(&Tooltip, &ComputedNode, &GlobalTransform)>,
+    cameras: Query<&Camera>,
+    default_ui_camera: DefaultUiCamera,
+    settings: Res<TooltipSettings>,
+) {
+    for (popup, popup_node, mut node, mut visibility, target_camera) in &mut popups {
+        let Ok((tooltip, target_node, target_transform)) = targets.get(popup.target) else {
+            continue;
+        };
+        if popup_node.is_empty() {
+            continue;
+        }
+        let Some(viewport_size) = target_camera
+            .map(UiTargetCamera::entity)
+            .or(default_ui_camera.get())
+            .and_then(|camera| cameras.get(camera).ok())
+            .and_then(Camera::physical_viewport_size)
+        else {
+            continue;
+        };
+
+        // Tooltips are placed in physical pixels, then converted back to logical pixels
+        let inverse_scale_factor = popup_node.inverse_scale_factor();
+        let target_rect = Rect::from_center_size(
+            target_transform.translation().truncate(),
+            target_node.size(),
+        );
+        let position = place_tooltip(
+            target_rect,
+            popup_node.size(),
+            viewport_size.as_vec2(),
+            tooltip.placement,
+            settings.gap / inverse_scale_factor,
+        ) * inverse_scale_factor;
+
+        if node.left != Val::Px(position.x) || node.top != Val::Px(position.y) {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+/// Returns the top left corner of a tooltip of `size` placed next to `target`, keeping it within
+/// the viewport.
+fn place_tooltip(
+    target: Rect,
+    size: Vec2,
+    viewport_size: Vec2,
+    placement: TooltipPlacement,
+    gap: f32,
+) -> Vec2 {
+    let fits = |placement| match placement {
+        TooltipPlacement::Above => target.min.y - gap - size.y >= 0.,
+        TooltipPlacement::Below => target.max.y + gap + size.y <= viewport_size.y,
+        TooltipPlacement::Left => target.min.x - gap - size.x >= 0.,
+        TooltipPlacement::Right => target.max.x + gap + size.x <= viewport_size.x,
+    };
+    let placement = if !fits(placement) && fits(placement.opposite()) {
+        placement.opposite()
+    } else {
+        placement
+    };
+
+    let centered = target.center() - 0.5 * size;
+    let position = match placement {
+        TooltipPlacement::Above => Vec2::new(centered.x, target.min.y - gap - size.y),
+        TooltipPlacement::Below => Vec2::new(centered.x, target.max.y + gap),
+        TooltipPlacement::Left => Vec2::new(target.min.x - gap - size.x, centered.y),
+        TooltipPlacement::Right => Vec2::new(target.max.x + gap, centered.y),
+    };
+    position.clamp(Vec2::ZERO, (viewport_size - size).max(Vec2::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Vec2 = Vec2::new(800., 600.);
+    const SIZE: Vec2 = Vec2::new(100., 20.);
+
+    #[test]
+    fn tooltip_is_placed_on_preferred_side() {
+        let target = Rect::new(350., 290., 450., 310.);
+        assert_eq!(
+            place_tooltip(target, SIZE, VIEWPORT, TooltipPlacement::Below, 4.),
+            Vec2::new(350., 314.)
+        );
+        assert_eq!(
+            place_tooltip(target, SIZE, VIEWPORT, TooltipPlacement::Above, 4.),
+            Vec2::new(350., 266.)
+        );
+        assert_eq!(
+            place_tooltip(target, SIZE, VIEWPORT, TooltipPlacement::Right, 4.),
+            Vec2::new(454., 290.)
+        );
+    }
+
+    #[test]
+    fn tooltip_avoids_screen_edges() {
+        // Flipped above the target, since there is no room below it
+        let bottom = Rect::new(350., 570., 450., 590.);
+        assert_eq!(
+            place_tooltip(bottom, SIZE, VIEWPORT, TooltipPlacement::Below, 4.),
+            Vec2::new(350., 546.)
+        );
+
+        // Moved right to stay on screen
+        let left_edge = Rect::new(0., 290., 20., 310.);
+        assert_eq!(
+            place_tooltip(left_edge, SIZE, VIEWPORT, TooltipPlacement::Below, 4.),
+            Vec2::new(0., 314.)
+        );
+    }
+
+    #[test]
+    fn tooltip_contents_ignore_picking() {
+        let mut world = World::new();
+        let popup = world.spawn_empty().id();
+        let mut commands = world.commands();
+        let mut popup_commands = commands.entity(popup);
+        popup_commands.with_children(|parent| {
+            parent.spawn_empty().with_child(());
+        });
+        popup_commands.queue(ignore_picking_recursive);
+        world.flush();
+
+        let mut pickables = world.query::<&Pickable>();
+        assert_eq!(pickables.iter(&world).count(), 3);
+        assert!(pickables
+            .iter(&world)
+            .all(|pickable| *pickable == Pickable::IGNORE));
+    }
+}
@@ -2593,6 +2593,32 @@ impl Default for LayoutConfig {
     }
 }
 
+/// Controls which pixel grid the layout of a UI tree is rounded to.
+///
+/// This component is read from root UI nodes only, and applies to the whole tree below them.
+/// Descendants with a [`LayoutConfig`] that disables rounding are never rounded.
+///
+/// Rounding to physical pixels gives the sharpest results, but when the scale factor isn't an
+/// integer (for example when rendering UI to a texture whose scale changes), node sizes in
+/// logical pixels change as the scale factor changes. Rounding to logical pixels keeps node
+/// sizes stable at the cost of edges which may fall between physical pixels.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum LayoutRounding {
+    /// Round node coordinates to the nearest physical pixel.
+    #[default]
+    PhysicalPixels,
+    /// Round node coordinates to the nearest logical pixel.
+    LogicalPixels,
+    /// Don't round node coordinates.
+    None,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::GridPlacement;
@@ -2643,6 +2669,46 @@ impl UiTargetCamera {
     }
 }
 
+/// Automatically resizes the [`Image`] which this root [`Node`] is rendered to, to fit the
+/// node's layout size.
+///
+/// The target camera of the node, set by [`UiTargetCamera`], must render to a
+/// [`RenderTarget::Image`]. The image is resized to the size of the node multiplied by
+/// [`scale_factor`], and the camera's target is updated to use that scale factor, so the
+/// UI is laid out at the same logical size but rendered at a higher or lower resolution.
+/// The image is resized whenever the node's size or the scale factor changes, so the scale
+/// factor can be animated, such as to render the UI more sharply as it moves closer to
+/// the viewer.
+///
+/// The size of the node shouldn't depend on the size of the image, such as when using
+/// [`Val::Percent`] or [`Val::Vw`], since the image is sized to fit the node.
+///
+/// Setting this component on a non-root node will have no effect.
+///
+/// [`Image`]: bevy_image::Image
+/// [`scale_factor`]: Self::scale_factor
+#[derive(Component, Clone, Copy, Debug, Reflect, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct RenderTargetAutoResize {
+    /// The number of physical pixels in the image for each logical pixel of the node.
+    ///
+    /// Defaults to `1.0`.
+    pub scale_factor: f32,
+}
+
+impl RenderTargetAutoResize {
+    /// Resizes the image to the node's size multiplied by the given scale factor.
+    pub const fn with_scale_factor(scale_factor: f32) -> Self {
+        Self { scale_factor }
+    }
+}
+
+impl Default for RenderTargetAutoResize {
+    fn default() -> Self {
+        Self { scale_factor: 1.0 }
+    }
+}
+
 /// Marker used to identify default cameras, they will have priority over the [`PrimaryWindow`] camera.
 ///
 /// This is useful if the [`PrimaryWindow`] has two cameras, one of them used
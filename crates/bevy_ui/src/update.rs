@@ -2,19 +2,28 @@
 
 use crate::{
     experimental::{UiChildren, UiRootNodes},
-    CalculatedClip, Display, Node, OverflowAxis, UiTargetCamera,
+    CalculatedClip, DefaultUiCamera, Display, Node, OverflowAxis, RenderTargetAutoResize, UiScale,
+    UiTargetCamera,
 };
 
 use super::ComputedNode;
+use bevy_asset::Assets;
 use bevy_ecs::{
     entity::Entity,
     query::{Changed, With},
-    system::{Commands, Query},
+    system::{Commands, Query, Res, ResMut},
 };
-use bevy_math::Rect;
+use bevy_image::Image;
+use bevy_math::{FloatOrd, Rect, UVec2};
 use bevy_platform_support::collections::HashSet;
+use bevy_render::{
+    camera::{Camera, ImageRenderTarget, RenderTarget},
+    render_resource::Extent3d,
+};
 use bevy_sprite::BorderRect;
 use bevy_transform::components::GlobalTransform;
+use bevy_utils::once;
+use tracing::warn;
 
 /// Updates clipping for all nodes
 pub fn update_clipping_system(
@@ -216,3 +225,67 @@ fn update_children_target_camera(
         );
     }
 }
+
+/// Resizes the images targeted by root nodes with [`RenderTargetAutoResize`] to fit the nodes.
+pub fn resize_render_target_images_system(
+    ui_scale: Res<UiScale>,
+    root_nodes: UiRootNodes,
+    node_query: Query<(
+        Entity,
+        &ComputedNode,
+        &RenderTargetAutoResize,
+        Option<&UiTargetCamera>,
+    )>,
+    default_ui_camera: DefaultUiCamera,
+    mut camera_query: Query<&mut Camera>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let default_camera = default_ui_camera.get();
+    for (entity, node, auto_resize, target_camera) in node_query.iter_many(root_nodes.iter()) {
+        let Some(mut camera) = target_camera
+            .map(UiTargetCamera::entity)
+            .or(default_camera)
+            .and_then(|camera| camera_query.get_mut(camera).ok())
+        else {
+            continue;
+        };
+
+        let RenderTarget::Image(image_target) = &camera.target else {
+            once!(warn!(
+                "Root UI node {entity} has a `RenderTargetAutoResize`, but its camera doesn't render to an image"
+            ));
+            continue;
+        };
+
+        let scale_factor = auto_resize.scale_factor;
+        // The node's size in logical pixels of the render target, which is unaffected by the
+        // target's scale factor
+        let logical_size = node.size() * node.inverse_scale_factor() * ui_scale.0;
+        let physical_size = (logical_size * scale_factor)
+            .ceil()
+            .as_uvec2()
+            .max(UVec2::ONE);
+
+        let image_target = image_target.clone();
+        let handle = image_target.handle.clone();
+        if image_target.scale_factor != FloatOrd(scale_factor) {
+            camera.target = RenderTarget::Image(ImageRenderTarget {
+                scale_factor: FloatOrd(scale_factor),
+                ..image_target
+            });
+        }
+
+        if images
+            .get(&handle)
+            .is_some_and(|image| image.size() != physical_size)
+        {
+            if let Some(image) = images.get_mut(&handle) {
+                image.resize(Extent3d {
+                    width: physical_size.x,
+                    height: physical_size.y,
+                    depth_or_array_layers: 1,
+                });
+            }
+        }
+    }
+}
@@ -124,6 +124,26 @@ impl From<String> for Text {
     }
 }
 
+/// Controls how the glyphs of a UI [`Text`] node are positioned on screen.
+///
+/// Glyphs are always laid out on whole physical pixels relative to the text node, so by default
+/// the node's own position is snapped to the nearest physical pixel before drawing, keeping
+/// glyphs crisp even when the node's layout isn't rounded (see [`LayoutRounding`]). This can
+/// make text visibly step from pixel to pixel when it's animated or its scale factor changes.
+///
+/// [`LayoutRounding`]: crate::LayoutRounding
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub enum TextPositioning {
+    /// Snap the text to the nearest physical pixel.
+    #[default]
+    PixelAligned,
+    /// Draw the text at its exact position, which may fall between physical pixels.
+    ///
+    /// Gives smoother motion, but the text may look blurry while it's between pixels.
+    Subpixel,
+}
+
 /// UI alias for [`TextReader`].
 pub type TextUiReader<'w, 's> = TextReader<'w, 's, Text>;
 
@@ -177,6 +177,22 @@ pub struct Window {
     pub name: Option<String>,
     /// How the alpha channel of textures should be handled while compositing.
     pub composite_alpha_mode: CompositeAlphaMode,
+    /// Requests HDR output for this window, if the display supports it.
+    ///
+    /// When set, the window's surface uses an extended-range (scRGB) format if it's available,
+    /// and the tonemapping of HDR cameras rendering to this window maps highlights up to the
+    /// configured brightness instead of clipping them to SDR white. Otherwise, the window falls
+    /// back to SDR output.
+    ///
+    /// The brightness mapping is done by the tonemapping pass, so it only applies to cameras with
+    /// HDR enabled and a tonemapping method other than `Tonemapping::None`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - Requires the surface to support the `Rgba16Float` format, which is typically only the
+    ///   case on Windows when HDR is enabled in the display settings.
+    /// - HDR10 (PQ) output isn't supported yet.
+    pub hdr_output: Option<HdrOutput>,
     /// The limits of the window's logical size
     /// (found in its [`resolution`](WindowResolution)) when resizing.
     pub resize_constraints: WindowResizeConstraints,
@@ -431,6 +447,13 @@ pub struct Window {
     ///
     /// [`WindowAttributesExtIOS::with_prefers_status_bar_hidden`]: https://docs.rs/winit/latest/x86_64-apple-darwin/winit/platform/ios/trait.WindowAttributesExtIOS.html#tymethod.with_prefers_status_bar_hidden
     pub prefers_status_bar_hidden: bool,
+    /// The progress indicator shown on the window's taskbar button,
+    /// e.g. for long-running tasks like downloads.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - Only supported on Windows.
+    pub taskbar_progress: TaskbarProgress,
 }
 
 impl Default for Window {
@@ -462,6 +485,7 @@ impl Default for Window {
             skip_taskbar: false,
             clip_children: true,
             desired_maximum_frame_latency: None,
+            hdr_output: None,
             recognize_pinch_gesture: false,
             recognize_rotation_gesture: false,
             recognize_doubletap_gesture: false,
@@ -475,6 +499,7 @@ impl Default for Window {
             titlebar_show_buttons: true,
             prefers_home_indicator_hidden: false,
             prefers_status_bar_hidden: false,
+            taskbar_progress: TaskbarProgress::None,
         }
     }
 }
@@ -510,6 +535,30 @@ impl Window {
         self.internal.drag_resize_request = Some(direction);
     }
 
+    /// Calling this will request the user's attention, e.g. by flashing the window's taskbar
+    /// button or bouncing its dock icon.
+    ///
+    /// The request is cancelled automatically once the window receives focus.
+    /// Has no effect if the window is already focused.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital:** Unsupported.
+    /// - **macOS:** [`UserAttention::Critical`] bounces the dock icon until the application
+    ///   is focused, while [`UserAttention::Informational`] bounces it once.
+    /// - **X11:** Both types request attention in the same way.
+    /// - **Wayland:** Requires the `xdg_activation_v1` protocol, and both types request
+    ///   attention in the same way.
+    pub fn request_attention(&mut self, attention: UserAttention) {
+        self.internal.attention_request = Some(Some(attention));
+    }
+
+    /// Calling this will cancel a previous [`Window::request_attention`] before the window
+    /// receives focus.
+    pub fn cancel_attention_request(&mut self) {
+        self.internal.attention_request = Some(None);
+    }
+
     /// The window's client area width in logical pixels.
     ///
     /// See [`WindowResolution`] for an explanation about logical/physical sizes.
@@ -1073,6 +1122,8 @@ pub struct InternalWindowState {
     drag_move_request: bool,
     /// If this is `Some` then the next frame we will ask to drag-resize the window.
     drag_resize_request: Option<CompassOctant>,
+    /// If this is `Some` then the next frame we will ask to request or cancel user attention.
+    attention_request: Option<Option<UserAttention>>,
     /// Unscaled cursor position.
     physical_cursor_position: Option<DVec2>,
 }
@@ -1097,6 +1148,13 @@ impl InternalWindowState {
     pub fn take_resize_request(&mut self) -> Option<CompassOctant> {
         self.drag_resize_request.take()
     }
+
+    /// Consumes the current attention request, if it exists. This should only be called by window backends.
+    ///
+    /// `Some(None)` means that a previous attention request should be cancelled.
+    pub fn take_attention_request(&mut self) -> Option<Option<UserAttention>> {
+        self.attention_request.take()
+    }
 }
 
 /// References a screen monitor.
@@ -1262,6 +1320,41 @@ pub enum CompositeAlphaMode {
     Inherit = 4,
 }
 
+/// HDR output settings for a [`Window`], see [`Window::hdr_output`].
+///
+/// Brightness values are in nits (candela per square meter).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct HdrOutput {
+    /// The brightness of SDR white, such as UI and diffuse white surfaces.
+    ///
+    /// Users often expect this to match their operating system's SDR content brightness.
+    /// Defaults to 203 nits, as recommended by ITU-R BT.2408.
+    pub paper_white: f32,
+    /// The maximum brightness of the display, which highlights are mapped to.
+    ///
+    /// Defaults to 1000 nits.
+    pub peak_brightness: f32,
+}
+
+impl Default for HdrOutput {
+    fn default() -> Self {
+        Self {
+            paper_white: 203.0,
+            peak_brightness: 1000.0,
+        }
+    }
+}
+
 /// Defines the way a [`Window`] is displayed.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
@@ -1310,6 +1403,58 @@ pub enum WindowMode {
     Fullscreen(MonitorSelection),
 }
 
+/// The kind of attention to request with [`Window::request_attention`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum UserAttention {
+    /// Requests attention until the window is focused, for events that need the user to act.
+    ///
+    /// On Windows, this flashes both the window and its taskbar button until it is focused.
+    Critical,
+    /// Requests attention briefly, for events the user may want to know about.
+    ///
+    /// On Windows, this flashes the taskbar button until the window is focused.
+    #[default]
+    Informational,
+}
+
+/// The progress indicator shown on a [`Window`]'s taskbar button.
+///
+/// Progress values are fractions between `0.0` and `1.0`, and are clamped to that range.
+///
+/// ## Platform-specific
+///
+/// - Only supported on Windows.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum TaskbarProgress {
+    /// No progress is shown.
+    #[default]
+    None,
+    /// Progress is shown as ongoing, without a known amount of completion.
+    Indeterminate,
+    /// Progress is shown normally, with the given amount of completion.
+    Normal(f32),
+    /// Progress is shown as paused, with the given amount of completion.
+    Paused(f32),
+    /// Progress is shown as failed, with the given amount of completion.
+    Error(f32),
+}
+
 /// Specifies where a [`Window`] should appear relative to other overlapping windows (on top or under) .
 ///
 /// Levels are groups of windows with respect to their z-position.
@@ -0,0 +1,366 @@
+//! Access to the system clipboard, for copying and pasting text and images.
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    event::{Event, EventWriter},
+    resource::Resource,
+    system::{NonSendMut, ResMut},
+};
+use thiserror::Error;
+
+#[cfg(target_arch = "wasm32")]
+use crossbeam_channel::{Receiver, Sender};
+
+pub(crate) struct ClipboardPlugin;
+
+impl Plugin for ClipboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Clipboard>()
+            .init_non_send_resource::<ClipboardBackend>()
+            .add_event::<ClipboardRead>()
+            .add_event::<ClipboardWritten>()
+            .add_systems(PreUpdate, process_clipboard_requests);
+    }
+}
+
+/// The system clipboard.
+///
+/// Reading from and writing to the clipboard happens asynchronously: each method returns a
+/// [`ClipboardRequest`] straight away, and the outcome is later sent as a [`ClipboardRead`] or
+/// [`ClipboardWritten`] event with the same request.
+/// Requests are processed in [`PreUpdate`], in the order they were made.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::{keyboard::KeyCode, ButtonInput};
+/// # use bevy_winit::clipboard::{Clipboard, ClipboardContent, ClipboardRead};
+/// fn copy_and_paste(
+///     keys: Res<ButtonInput<KeyCode>>,
+///     mut clipboard: ResMut<Clipboard>,
+///     mut clipboard_reads: EventReader<ClipboardRead>,
+/// ) {
+///     if keys.just_pressed(KeyCode::KeyC) {
+///         clipboard.set_text("Hello, clipboard!");
+///     }
+///     if keys.just_pressed(KeyCode::KeyV) {
+///         clipboard.fetch_text();
+///     }
+///
+///     for read in clipboard_reads.read() {
+///         if let Ok(ClipboardContent::Text(text)) = &read.result {
+///             println!("Pasted {text}");
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Platform-specific
+///
+/// - **Android / iOS:** Unsupported, so every request fails with
+///   [`ClipboardError::Unsupported`].
+/// - **Web:** Only text is supported. Browsers may also refuse access unless the page is
+///   focused, or ask the user for permission to read the clipboard.
+#[derive(Resource, Debug, Default)]
+pub struct Clipboard {
+    next_request: u64,
+    pending: Vec<(ClipboardRequest, ClipboardOperation)>,
+}
+
+impl Clipboard {
+    /// Requests the text on the clipboard, which is sent as a [`ClipboardRead`] event.
+    pub fn fetch_text(&mut self) -> ClipboardRequest {
+        self.push(ClipboardOperation::ReadText)
+    }
+
+    /// Requests the image on the clipboard, which is sent as a [`ClipboardRead`] event.
+    pub fn fetch_image(&mut self) -> ClipboardRequest {
+        self.push(ClipboardOperation::ReadImage)
+    }
+
+    /// Places text on the clipboard, replacing its contents.
+    ///
+    /// Once done, a [`ClipboardWritten`] event is sent.
+    pub fn set_text(&mut self, text: impl Into<String>) -> ClipboardRequest {
+        self.push(ClipboardOperation::WriteText(text.into()))
+    }
+
+    /// Places an image on the clipboard, replacing its contents.
+    ///
+    /// Once done, a [`ClipboardWritten`] event is sent.
+    pub fn set_image(&mut self, image: ClipboardImage) -> ClipboardRequest {
+        self.push(ClipboardOperation::WriteImage(image))
+    }
+
+    fn push(&mut self, operation: ClipboardOperation) -> ClipboardRequest {
+        let request = ClipboardRequest(self.next_request);
+        self.next_request += 1;
+        self.pending.push((request, operation));
+        request
+    }
+}
+
+/// Identifies a request made through the [`Clipboard`], so that its outcome can be matched up
+/// with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClipboardRequest(u64);
+
+#[derive(Debug)]
+enum ClipboardOperation {
+    ReadText,
+    ReadImage,
+    WriteText(String),
+    WriteImage(ClipboardImage),
+}
+
+/// Data which can be stored on the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardContent {
+    /// Text.
+    Text(String),
+    /// An image.
+    Image(ClipboardImage),
+}
+
+/// An image stored on the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    /// The pixels of the image, as rgba8 in row-major order.
+    pub data: Vec<u8>,
+}
+
+/// Sent when a read requested with [`Clipboard::fetch_text`] or [`Clipboard::fetch_image`]
+/// completes.
+#[derive(Event, Debug, Clone)]
+pub struct ClipboardRead {
+    /// The request which this is the outcome of.
+    pub request: ClipboardRequest,
+    /// The contents of the clipboard, or why they couldn't be read.
+    pub result: Result<ClipboardContent, ClipboardError>,
+}
+
+/// Sent when a write requested with [`Clipboard::set_text`] or [`Clipboard::set_image`]
+/// completes.
+#[derive(Event, Debug, Clone)]
+pub struct ClipboardWritten {
+    /// The request which this is the outcome of.
+    pub request: ClipboardRequest,
+    /// Whether the write succeeded.
+    pub result: Result<(), ClipboardError>,
+}
+
+/// An error that occurs when accessing the [`Clipboard`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The clipboard, or the requested kind of data, isn't supported on this platform.
+    #[error("the clipboard isn't supported on this platform")]
+    Unsupported,
+    /// The clipboard doesn't contain data of the requested kind.
+    #[error("the clipboard doesn't contain data of the requested kind")]
+    ContentNotAvailable,
+    /// The platform failed to access the clipboard.
+    #[error("failed to access the clipboard: {0}")]
+    Backend(String),
+}
+
+enum ClipboardOutcome {
+    Read(Result<ClipboardContent, ClipboardError>),
+    Written(Result<(), ClipboardError>),
+}
+
+/// The platform's clipboard, which some platforms only allow accessing from the main thread.
+struct ClipboardBackend {
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    clipboard: Result<arboard::Clipboard, ClipboardError>,
+    #[cfg(target_arch = "wasm32")]
+    sender: Sender<(ClipboardRequest, ClipboardOutcome)>,
+    #[cfg(target_arch = "wasm32")]
+    receiver: Receiver<(ClipboardRequest, ClipboardOutcome)>,
+}
+
+impl Default for ClipboardBackend {
+    fn default() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        let (sender, receiver) = crossbeam_channel::unbounded();
+
+        Self {
+            #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+            clipboard: arboard::Clipboard::new().map_err(convert_arboard_error),
+            #[cfg(target_arch = "wasm32")]
+            sender,
+            #[cfg(target_arch = "wasm32")]
+            receiver,
+        }
+    }
+}
+
+impl ClipboardBackend {
+    /// Starts `operation`, returning its outcome if it completed immediately.
+    #[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+    fn start(
+        &mut self,
+        _request: ClipboardRequest,
+        operation: ClipboardOperation,
+    ) -> Option<ClipboardOutcome> {
+        let clipboard = match &mut self.clipboard {
+            Ok(clipboard) => clipboard,
+            Err(err) => {
+                return Some(match operation {
+                    ClipboardOperation::ReadText | ClipboardOperation::ReadImage => {
+                        ClipboardOutcome::Read(Err(err.clone()))
+                    }
+                    ClipboardOperation::WriteText(_) | ClipboardOperation::WriteImage(_) => {
+                        ClipboardOutcome::Written(Err(err.clone()))
+                    }
+                });
+            }
+        };
+
+        Some(match operation {
+            ClipboardOperation::ReadText => ClipboardOutcome::Read(
+                clipboard
+                    .get_text()
+                    .map(ClipboardContent::Text)
+                    .map_err(convert_arboard_error),
+            ),
+            ClipboardOperation::ReadImage => ClipboardOutcome::Read(
+                clipboard
+                    .get_image()
+                    .map(|image| {
+                        ClipboardContent::Image(ClipboardImage {
+                            width: image.width as u32,
+                            height: image.height as u32,
+                            data: image.bytes.into_owned(),
+                        })
+                    })
+                    .map_err(convert_arboard_error),
+            ),
+            ClipboardOperation::WriteText(text) => {
+                ClipboardOutcome::Written(clipboard.set_text(text).map_err(convert_arboard_error))
+            }
+            ClipboardOperation::WriteImage(image) => ClipboardOutcome::Written(
+                clipboard
+                    .set_image(arboard::ImageData {
+                        width: image.width as usize,
+                        height: image.height as usize,
+                        bytes: image.data.into(),
+                    })
+                    .map_err(convert_arboard_error),
+            ),
+        })
+    }
+
+    /// Starts `operation`, returning its outcome if it completed immediately.
+    #[cfg(target_arch = "wasm32")]
+    fn start(
+        &mut self,
+        request: ClipboardRequest,
+        operation: ClipboardOperation,
+    ) -> Option<ClipboardOutcome> {
+        use wasm_bindgen_futures::JsFuture;
+
+        let Some(window) = web_sys::window() else {
+            return Some(unsupported(&operation));
+        };
+        let clipboard = window.navigator().clipboard();
+        let sender = self.sender.clone();
+
+        match operation {
+            ClipboardOperation::ReadText => {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = JsFuture::from(clipboard.read_text())
+                        .await
+                        .map(|text| ClipboardContent::Text(text.as_string().unwrap_or_default()))
+                        .map_err(|err| ClipboardError::Backend(format!("{err:?}")));
+                    // The receiver lives as long as the app, so this can only fail on exit.
+                    let _ = sender.send((request, ClipboardOutcome::Read(result)));
+                });
+                None
+            }
+            ClipboardOperation::WriteText(text) => {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = JsFuture::from(clipboard.write_text(&text))
+                        .await
+                        .map(|_| ())
+                        .map_err(|err| ClipboardError::Backend(format!("{err:?}")));
+                    let _ = sender.send((request, ClipboardOutcome::Written(result)));
+                });
+                None
+            }
+            ClipboardOperation::ReadImage | ClipboardOperation::WriteImage(_) => {
+                Some(unsupported(&operation))
+            }
+        }
+    }
+
+    /// Starts `operation`, returning its outcome if it completed immediately.
+    #[cfg(any(target_os = "android", target_os = "ios"))]
+    fn start(
+        &mut self,
+        _request: ClipboardRequest,
+        operation: ClipboardOperation,
+    ) -> Option<ClipboardOutcome> {
+        Some(unsupported(&operation))
+    }
+
+    /// Returns the outcomes of operations which have completed since the last call.
+    fn completed(&mut self) -> impl Iterator<Item = (ClipboardRequest, ClipboardOutcome)> + '_ {
+        #[cfg(target_arch = "wasm32")]
+        return self.receiver.try_iter();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        core::iter::empty()
+    }
+}
+
+#[cfg(any(target_arch = "wasm32", target_os = "android", target_os = "ios"))]
+fn unsupported(operation: &ClipboardOperation) -> ClipboardOutcome {
+    match operation {
+        ClipboardOperation::ReadText | ClipboardOperation::ReadImage => {
+            ClipboardOutcome::Read(Err(ClipboardError::Unsupported))
+        }
+        ClipboardOperation::WriteText(_) | ClipboardOperation::WriteImage(_) => {
+            ClipboardOutcome::Written(Err(ClipboardError::Unsupported))
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "wasm32", target_os = "android", target_os = "ios")))]
+fn convert_arboard_error(err: arboard::Error) -> ClipboardError {
+    match err {
+        arboard::Error::ContentNotAvailable => ClipboardError::ContentNotAvailable,
+        arboard::Error::ClipboardNotSupported => ClipboardError::Unsupported,
+        err => ClipboardError::Backend(err.to_string()),
+    }
+}
+
+fn process_clipboard_requests(
+    mut clipboard: ResMut<Clipboard>,
+    mut backend: NonSendMut<ClipboardBackend>,
+    mut read_events: EventWriter<ClipboardRead>,
+    mut written_events: EventWriter<ClipboardWritten>,
+) {
+    let mut send = |request, outcome| match outcome {
+        ClipboardOutcome::Read(result) => {
+            read_events.send(ClipboardRead { request, result });
+        }
+        ClipboardOutcome::Written(result) => {
+            written_events.send(ClipboardWritten { request, result });
+        }
+    };
+
+    for (request, operation) in clipboard.pending.drain(..) {
+        if let Some(outcome) = backend.start(request, operation) {
+            send(request, outcome);
+        }
+    }
+
+    for (request, outcome) in backend.completed() {
+        send(request, outcome);
+    }
+}
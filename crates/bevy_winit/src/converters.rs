@@ -7,7 +7,7 @@ use bevy_input::{
 };
 use bevy_math::{CompassOctant, Vec2};
 use bevy_window::SystemCursorIcon;
-use bevy_window::{EnabledButtons, WindowLevel, WindowTheme};
+use bevy_window::{EnabledButtons, UserAttention, WindowLevel, WindowTheme};
 use winit::keyboard::{Key, NamedKey, NativeKey};
 
 pub fn convert_keyboard_input(
@@ -678,6 +678,13 @@ pub fn convert_window_level(window_level: WindowLevel) -> winit::window::WindowL
     }
 }
 
+pub fn convert_user_attention(attention: UserAttention) -> winit::window::UserAttentionType {
+    match attention {
+        UserAttention::Critical => winit::window::UserAttentionType::Critical,
+        UserAttention::Informational => winit::window::UserAttentionType::Informational,
+    }
+}
+
 pub fn convert_winit_theme(theme: winit::window::Theme) -> WindowTheme {
     match theme {
         winit::window::Theme::Light => WindowTheme::Light,
@@ -0,0 +1,131 @@
+//! Components to set the icon of winit windows
+
+use bevy_app::{App, Last, Plugin};
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    event::EventReader,
+    query::With,
+    reflect::ReflectComponent,
+    removal_detection::RemovedComponents,
+    system::{Local, NonSend, Query, Res},
+    world::Ref,
+};
+use bevy_image::Image;
+use bevy_platform_support::collections::HashSet;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_window::Window;
+use tracing::warn;
+use wgpu_types::TextureFormat;
+use winit::window::Icon;
+
+use crate::WinitWindows;
+
+pub(crate) struct WindowIconPlugin;
+
+impl Plugin for WindowIconPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WindowIcon>()
+            .add_systems(Last, update_window_icons);
+    }
+}
+
+/// Insert into a window entity to set the icon for that window, which is shown e.g. in its
+/// title bar and taskbar button.
+///
+/// The icon is updated whenever the image changes, and removed when this component is removed.
+///
+/// ## Platform-specific
+///
+/// - **iOS / Android / Web / Wayland / macOS:** Unsupported. On macOS, the application icon
+///   is set through the application bundle instead.
+#[derive(Component, Debug, Clone, Default, Reflect, PartialEq, Eq)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct WindowIcon {
+    /// Handle to the image to use as the icon.
+    ///
+    /// Images in formats other than rgba8 are converted if possible. PNG images work well for this.
+    pub handle: Handle<Image>,
+}
+
+impl From<Handle<Image>> for WindowIcon {
+    fn from(handle: Handle<Image>) -> Self {
+        Self { handle }
+    }
+}
+
+fn update_window_icons(
+    windows: Query<(Entity, Ref<WindowIcon>), With<Window>>,
+    mut removed_icons: RemovedComponents<WindowIcon>,
+    mut image_events: EventReader<AssetEvent<Image>>,
+    images: Res<Assets<Image>>,
+    winit_windows: NonSend<WinitWindows>,
+    mut queue: Local<HashSet<Entity>>,
+) {
+    let modified_images = image_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    for (entity, icon) in windows.iter() {
+        if !(queue.remove(&entity)
+            || icon.is_changed()
+            || modified_images.contains(&icon.handle.id()))
+        {
+            continue;
+        }
+
+        let Some(winit_window) = winit_windows.get_window(entity) else {
+            // The window hasn't been created yet.
+            queue.insert(entity);
+            continue;
+        };
+
+        let Some(image) = images.get(&icon.handle) else {
+            warn!(
+                "Window icon image {:?} is not loaded yet and couldn't be used. Trying again next frame.",
+                icon.handle
+            );
+            queue.insert(entity);
+            continue;
+        };
+
+        let Some(rgba) = extract_rgba_pixels(image) else {
+            warn!(
+                "Window icon image {:?} not accepted because its format can't be converted to rgba8",
+                icon.handle
+            );
+            continue;
+        };
+
+        match Icon::from_rgba(rgba, image.width(), image.height()) {
+            Ok(icon) => winit_window.set_window_icon(Some(icon)),
+            Err(err) => warn!("Window icon image {:?} is invalid: {err}", icon.handle),
+        }
+    }
+
+    for entity in removed_icons.read() {
+        if windows.contains(entity) {
+            continue;
+        }
+        queue.remove(&entity);
+        if let Some(winit_window) = winit_windows.get_window(entity) {
+            winit_window.set_window_icon(None);
+        }
+    }
+}
+
+/// Returns the `image` data as a `Vec<u8>`, converting it to rgba8 if needed.
+fn extract_rgba_pixels(image: &Image) -> Option<Vec<u8>> {
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => Some(image.data.clone()),
+        _ => image
+            .convert(TextureFormat::Rgba8UnormSrgb)
+            .map(|image| image.data),
+    }
+}
@@ -43,12 +43,18 @@ use crate::{
 };
 
 pub mod accessibility;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
 mod converters;
 pub mod cursor;
 #[cfg(feature = "custom_cursor")]
 mod custom_cursor;
+#[cfg(feature = "window_icon")]
+pub mod icon;
 mod state;
 mod system;
+#[cfg(target_os = "windows")]
+mod taskbar;
 mod winit_config;
 mod winit_monitors;
 mod winit_windows;
@@ -139,6 +145,10 @@ impl<T: Event> Plugin for WinitPlugin<T> {
 
         app.add_plugins(AccessKitPlugin);
         app.add_plugins(cursor::CursorPlugin);
+        #[cfg(feature = "window_icon")]
+        app.add_plugins(icon::WindowIconPlugin);
+        #[cfg(feature = "clipboard")]
+        app.add_plugins(clipboard::ClipboardPlugin);
 
         let event_loop = event_loop_builder
             .build()
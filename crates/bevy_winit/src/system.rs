@@ -7,6 +7,8 @@ use bevy_ecs::{
     system::{Local, NonSendMut, Query, SystemParamItem},
 };
 use bevy_input::keyboard::KeyboardFocusLost;
+#[cfg(target_os = "windows")]
+use bevy_window::TaskbarProgress;
 use bevy_window::{
     ClosingWindow, Monitor, PrimaryMonitor, RawHandleWrapper, VideoMode, Window, WindowClosed,
     WindowClosing, WindowCreated, WindowFocused, WindowMode, WindowResized, WindowWrapper,
@@ -28,8 +30,8 @@ use winit::platform::web::WindowExtWebSys;
 
 use crate::{
     converters::{
-        convert_enabled_buttons, convert_resize_direction, convert_window_level,
-        convert_window_theme, convert_winit_theme,
+        convert_enabled_buttons, convert_resize_direction, convert_user_attention,
+        convert_window_level, convert_window_theme, convert_winit_theme,
     },
     get_best_videomode, get_fitting_videomode, select_monitor,
     state::react_to_resize,
@@ -91,6 +93,11 @@ pub fn create_windows<F: QueryFilter + 'static>(
             }
         }
 
+        #[cfg(target_os = "windows")]
+        if window.taskbar_progress != TaskbarProgress::None {
+            crate::taskbar::set_taskbar_progress(winit_window, window.taskbar_progress);
+        }
+
         #[cfg(target_arch = "wasm32")]
         {
             if window.fit_canvas_to_parent {
@@ -474,6 +481,10 @@ pub(crate) fn changed_windows(
             }
         }
 
+        if let Some(attention) = window.internal.take_attention_request() {
+            winit_window.request_user_attention(attention.map(convert_user_attention));
+        }
+
         if window.focused != cache.window.focused && window.focused {
             winit_window.focus_window();
         }
@@ -515,6 +526,11 @@ pub(crate) fn changed_windows(
             winit_window.set_visible(window.visible);
         }
 
+        #[cfg(target_os = "windows")]
+        if window.taskbar_progress != cache.window.taskbar_progress {
+            crate::taskbar::set_taskbar_progress(winit_window, window.taskbar_progress);
+        }
+
         #[cfg(target_os = "ios")]
         {
             if window.recognize_pinch_gesture != cache.window.recognize_pinch_gesture {
@@ -0,0 +1,55 @@
+//! Taskbar progress indicators, which `winit` doesn't support directly.
+
+use bevy_window::TaskbarProgress;
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use tracing::warn;
+use windows::Win32::{
+    Foundation::HWND,
+    System::Com::{CoCreateInstance, CLSCTX_INPROC_SERVER},
+    UI::Shell::{
+        ITaskbarList3, TaskbarList, TBPFLAG, TBPF_ERROR, TBPF_INDETERMINATE, TBPF_NOPROGRESS,
+        TBPF_NORMAL, TBPF_PAUSED,
+    },
+};
+use winit::window::Window as WinitWindow;
+
+/// The resolution of progress values passed to the taskbar.
+const PROGRESS_TOTAL: u64 = 10_000;
+
+/// Shows `progress` on the taskbar button of `winit_window`.
+pub(crate) fn set_taskbar_progress(winit_window: &WinitWindow, progress: TaskbarProgress) {
+    let Ok(handle) = winit_window.window_handle() else {
+        return;
+    };
+    let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+        return;
+    };
+    let hwnd = HWND(handle.hwnd.get() as *mut _);
+
+    let (state, completed) = match progress {
+        TaskbarProgress::None => (TBPF_NOPROGRESS, None),
+        TaskbarProgress::Indeterminate => (TBPF_INDETERMINATE, None),
+        TaskbarProgress::Normal(completed) => (TBPF_NORMAL, Some(completed)),
+        TaskbarProgress::Paused(completed) => (TBPF_PAUSED, Some(completed)),
+        TaskbarProgress::Error(completed) => (TBPF_ERROR, Some(completed)),
+    };
+
+    if let Err(err) = set_progress(hwnd, state, completed) {
+        warn!("Failed to set the taskbar progress: {err}");
+    }
+}
+
+fn set_progress(hwnd: HWND, state: TBPFLAG, completed: Option<f32>) -> windows::core::Result<()> {
+    // SAFETY: `hwnd` is a valid window handle for the duration of the call. If COM isn't
+    // initialized on this thread, which winit does when drag and drop is enabled,
+    // `CoCreateInstance` returns an error.
+    unsafe {
+        let taskbar: ITaskbarList3 = CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER)?;
+        taskbar.HrInit()?;
+        if let Some(completed) = completed {
+            let completed = (completed.clamp(0.0, 1.0) * PROGRESS_TOTAL as f32) as u64;
+            taskbar.SetProgressValue(hwnd, completed, PROGRESS_TOTAL)?;
+        }
+        taskbar.SetProgressState(hwnd, state)
+    }
+}
@@ -0,0 +1,50 @@
+//! Draws a large number of 2D quads from a single entity using [`Mesh2dInstances`].
+//!
+//! Every instance has its own transform and color, but they all share one mesh and material,
+//! so they are extracted and drawn together instead of as one entity each.
+
+use bevy::{prelude::*, sprite::Mesh2dInstance};
+
+const INSTANCE_COUNT: usize = 100_000;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .add_systems(Update, spin_instances)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(Camera2d);
+
+    commands.spawn((
+        Mesh2d(meshes.add(Rectangle::new(2.0, 2.0))),
+        MeshMaterial2d(materials.add(Color::WHITE)),
+        (0..INSTANCE_COUNT)
+            .map(|i| {
+                let t = i as f32 / INSTANCE_COUNT as f32;
+                Mesh2dInstance::default().with_color(Color::hsl(t * 360.0, 0.8, 0.6))
+            })
+            .collect::<Mesh2dInstances>(),
+    ));
+}
+
+/// Moves all instances along a spiral, updating the whole buffer in one go.
+fn spin_instances(time: Res<Time>, mut instances: Query<&mut Mesh2dInstances>) {
+    let elapsed = time.elapsed_secs();
+    for mut instances in &mut instances {
+        let count = instances.len() as f32;
+        for (i, instance) in instances.iter_mut().enumerate() {
+            let t = i as f32 / count;
+            let angle = t * 200.0 + elapsed * (0.2 + t);
+            let radius = 20.0 + t * 320.0;
+            instance.transform.translation =
+                Vec3::new(ops::cos(angle) * radius, ops::sin(angle) * radius, 0.0);
+        }
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! Whenever possible, prefer using Bevy's change detection or Events for reacting to component changes.
 //! Events generally offer better performance and more flexible integration into Bevy's systems.
-//! Hooks are useful to enforce correctness but have limitations (only one hook per component,
-//! less ergonomic than events).
+//! Hooks are useful to enforce correctness but have limitations (they run immediately and can only
+//! defer further changes to the world, less ergonomic than events).
 //!
 //! Here are some cases where components hooks might be necessary:
 //!
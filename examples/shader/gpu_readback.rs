@@ -5,7 +5,7 @@ use bevy::{
     prelude::*,
     render::{
         extract_resource::{ExtractResource, ExtractResourcePlugin},
-        gpu_readback::{Readback, ReadbackComplete},
+        gpu_readback::{Readback, ReadbackComplete, TypedReadbackComplete},
         render_asset::{RenderAssetUsages, RenderAssets},
         render_graph::{self, RenderGraph, RenderLabel},
         render_resource::{
@@ -101,14 +101,13 @@ fn setup(
     // Spawn the readback components. For each frame, the data will be read back from the GPU
     // asynchronously and trigger the `ReadbackComplete` event on this entity. Despawn the entity
     // to stop reading back the data.
-    commands.spawn(Readback::buffer(buffer.clone())).observe(
-        |trigger: Trigger<ReadbackComplete>| {
-            // This matches the type which was used to create the `ShaderStorageBuffer` above,
-            // and is a convenient way to interpret the data.
-            let data: Vec<u32> = trigger.event().to_shader_type();
-            info!("Buffer {:?}", data);
-        },
-    );
+    // The type passed to `buffer_typed` matches the type which was used to create the
+    // `ShaderStorageBuffer` above, so the data is delivered already decoded.
+    commands
+        .spawn(Readback::buffer_typed::<Vec<u32>>(buffer.clone()))
+        .observe(|trigger: Trigger<TypedReadbackComplete<Vec<u32>>>| {
+            info!("Buffer {:?}", trigger.event().0);
+        });
     // This is just a simple way to pass the buffer handle to the render app for our compute node
     commands.insert_resource(ReadbackBuffer(buffer));
 
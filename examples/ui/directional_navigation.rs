@@ -391,6 +391,8 @@ fn interact_with_focused_button(
                             bevy_render::camera::ImageRenderTarget {
                                 handle: Handle::default(),
                                 scale_factor: FloatOrd(1.0),
+                                msaa: None,
+                                hdr: None,
                             },
                         ),
                         position: Vec2::ZERO,
@@ -1,4 +1,6 @@
 //! Shows how to render UI to a texture. Useful for displaying UI in 3D space.
+//!
+//! Press space to change the resolution the UI is rendered at.
 
 use std::f32::consts::PI;
 
@@ -16,7 +18,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_systems(Startup, setup)
-        .add_systems(Update, rotator_system)
+        .add_systems(Update, (rotator_system, scale_factor_system))
         .run();
 }
 
@@ -66,9 +68,9 @@ fn setup(
     commands
         .spawn((
             Node {
-                // Cover the whole image
-                width: Val::Percent(100.),
-                height: Val::Percent(100.),
+                // The image is resized to fit the node
+                width: Val::Px(512.),
+                height: Val::Px(512.),
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
@@ -76,6 +78,7 @@ fn setup(
             },
             BackgroundColor(GOLD.into()),
             UiTargetCamera(texture_camera),
+            RenderTargetAutoResize::default(),
         ))
         .with_children(|parent| {
             parent.spawn((
@@ -123,3 +126,20 @@ fn rotator_system(time: Res<Time>, mut query: Query<&mut Transform, With<Cube>>)
         transform.rotate_y(0.7 * time.delta_secs() * ROTATION_SPEED);
     }
 }
+
+const SCALE_FACTORS: [f32; 3] = [1.0, 0.25, 2.0];
+
+fn scale_factor_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut RenderTargetAutoResize>,
+    mut index: Local<usize>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    *index = (*index + 1) % SCALE_FACTORS.len();
+    for mut auto_resize in &mut query {
+        auto_resize.scale_factor = SCALE_FACTORS[*index];
+    }
+}